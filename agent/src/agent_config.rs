@@ -19,6 +19,7 @@ use common::std_extensions::UnreachableOption;
 use grpc::security::read_pem_file;
 
 use serde::Deserialize;
+use std::collections::HashMap;
 use std::fmt;
 use std::fs::read_to_string;
 use std::path::PathBuf;
@@ -75,6 +76,8 @@ pub struct AgentConfig {
     pub run_folder: String,
     #[serde(default)]
     pub insecure: bool,
+    #[serde(default)]
+    pub labels: HashMap<String, String>,
     ca_pem: Option<String>,
     crt_pem: Option<String>,
     key_pem: Option<String>,
@@ -91,6 +94,7 @@ impl Default for AgentConfig {
             server_url: get_default_url(),
             run_folder: get_default_run_folder(),
             insecure: bool::default(),
+            labels: HashMap::new(),
             ca_pem: None,
             crt_pem: None,
             key_pem: None,
@@ -158,6 +162,9 @@ impl AgentConfig {
             self.insecure = insecure;
         }
 
+        // [impl->swdd~agent-announces-labels~1]
+        self.labels.extend(args.labels.iter().cloned());
+
         if let Some(ca_pem_path) = &args.ca_pem {
             self.ca_pem = Some(ca_pem_path.to_owned());
             let ca_pem_content = read_pem_file(ca_pem_path, false).unwrap_or_default();
@@ -186,6 +193,7 @@ impl AgentConfig {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
     use std::io::Write;
     use std::path::PathBuf;
     use tempfile::NamedTempFile;
@@ -198,6 +206,8 @@ mod tests {
     use super::{AgentConfig, CONFIG_VERSION};
 
     const AGENT_NAME: &str = "agent_1";
+    const LABEL_KEY: &str = "arch";
+    const LABEL_VALUE: &str = "arm64";
     const CA_PEM_PATH: &str = "some_path_to_ca_pem/ca.pem";
     const CRT_PEM_PATH: &str = "some_path_to_crt_pem/crt.pem";
     const KEY_PEM_PATH: &str = "some_path_to_key_pem/key.pem";
@@ -264,6 +274,7 @@ mod tests {
     }
 
     // [utest->swdd~agent-loads-config-file~1]
+    // [utest->swdd~agent-announces-labels~1]
     #[test]
     fn utest_agent_config_update_with_args() {
         let mut agent_config = AgentConfig::default();
@@ -276,6 +287,7 @@ mod tests {
             ca_pem: Some(CA_PEM_PATH.to_string()),
             crt_pem: Some(CRT_PEM_PATH.to_string()),
             key_pem: Some(KEY_PEM_PATH.to_string()),
+            labels: vec![(LABEL_KEY.to_string(), LABEL_VALUE.to_string())],
         };
 
         agent_config.update_with_args(&args);
@@ -287,6 +299,10 @@ mod tests {
         assert_eq!(agent_config.ca_pem, Some(CA_PEM_PATH.to_string()));
         assert_eq!(agent_config.crt_pem, Some(CRT_PEM_PATH.to_string()));
         assert_eq!(agent_config.key_pem, Some(KEY_PEM_PATH.to_string()));
+        assert_eq!(
+            agent_config.labels,
+            HashMap::from([(LABEL_KEY.to_string(), LABEL_VALUE.to_string())])
+        );
     }
 
     // [utest->swdd~agent-loads-config-file~1]
@@ -315,6 +331,7 @@ mod tests {
             ca_pem: None,
             crt_pem: None,
             key_pem: None,
+            labels: vec![],
         };
 
         agent_config.update_with_args(&args);
@@ -334,6 +351,7 @@ mod tests {
     }
 
     // [utest->swdd~agent-loads-config-file~1]
+    // [utest->swdd~agent-announces-labels~1]
     #[test]
     fn utest_agent_config_from_file_successful() {
         let agent_config_content = format!(
@@ -346,6 +364,9 @@ mod tests {
         ca_pem_content = '''{CA_PEM_CONTENT}'''
         crt_pem_content = '''{CRT_PEM_CONTENT}'''
         key_pem_content = '''{KEY_PEM_CONTENT}'''
+
+        [labels]
+        {LABEL_KEY} = '{LABEL_VALUE}'
         #"
         );
 
@@ -374,5 +395,9 @@ mod tests {
             agent_config.key_pem_content,
             Some(KEY_PEM_CONTENT.to_string())
         );
+        assert_eq!(
+            agent_config.labels,
+            HashMap::from([(LABEL_KEY.to_string(), LABEL_VALUE.to_string())])
+        );
     }
 }
@@ -14,7 +14,7 @@
 use common::{
     commands::AgentLoadStatus,
     from_server_interface::{FromServer, FromServerReceiver},
-    objects::WorkloadState,
+    objects::{WorkloadMetrics, WorkloadState},
     std_extensions::{GracefulExitResult, IllegalStateResult},
     to_server_interface::{ToServerInterface, ToServerSender},
 };
@@ -27,8 +27,12 @@ use crate::runtime_manager::RuntimeManager;
 #[cfg_attr(test, mockall_double::double)]
 use crate::workload_log_facade::WorkloadLogFacade;
 use crate::{subscription_store::SubscriptionStore, workload_state::WorkloadStateReceiver};
+use std::collections::HashMap;
 
 const RESOURCE_MEASUREMENT_INTERVAL_TICK: std::time::Duration = tokio::time::Duration::from_secs(2);
+const DEPENDENCY_TIMEOUT_CHECK_INTERVAL_TICK: std::time::Duration =
+    tokio::time::Duration::from_secs(1);
+const WORKLOAD_METRICS_INTERVAL_TICK: std::time::Duration = tokio::time::Duration::from_secs(10);
 
 #[cfg_attr(test, mockall_double::double)]
 use crate::resource_monitor::ResourceMonitor;
@@ -46,6 +50,7 @@ pub struct AgentManager {
     workload_state_store: WorkloadStateStore,
     res_monitor: ResourceMonitor,
     subscription_store: SynchronizedSubscriptionStore,
+    workload_metrics: HashMap<String, WorkloadMetrics>,
 }
 
 impl AgentManager {
@@ -65,6 +70,7 @@ impl AgentManager {
             workload_state_store: WorkloadStateStore::new(),
             res_monitor: ResourceMonitor::new(),
             subscription_store: Default::default(),
+            workload_metrics: HashMap::new(),
         }
     }
 
@@ -72,6 +78,14 @@ impl AgentManager {
         log::info!("Awaiting commands from the server ...");
 
         let mut interval = tokio::time::interval(RESOURCE_MEASUREMENT_INTERVAL_TICK);
+        let mut dependency_timeout_interval = tokio::time::interval_at(
+            tokio::time::Instant::now() + DEPENDENCY_TIMEOUT_CHECK_INTERVAL_TICK,
+            DEPENDENCY_TIMEOUT_CHECK_INTERVAL_TICK,
+        );
+        let mut workload_metrics_interval = tokio::time::interval_at(
+            tokio::time::Instant::now() + WORKLOAD_METRICS_INTERVAL_TICK,
+            WORKLOAD_METRICS_INTERVAL_TICK,
+        );
 
         loop {
             tokio::select! {
@@ -96,6 +110,16 @@ impl AgentManager {
                 _ = interval.tick() => {
                     self.measure_and_forward_resource_availability().await;
                 }
+                // [impl->swdd~agent-checks-dependency-timeouts-periodically~1]
+                _ = dependency_timeout_interval.tick() => {
+                    self.runtime_manager
+                        .update_workloads_on_fulfilled_dependencies(&self.workload_state_store)
+                        .await;
+                }
+                // [impl->swdd~agent-collects-workload-metrics-periodically~1]
+                _ = workload_metrics_interval.tick() => {
+                    self.collect_workload_metrics().await;
+                }
             }
         }
     }
@@ -268,10 +292,22 @@ impl AgentManager {
                 agent_name: self.agent_name.clone(),
                 cpu_usage,
                 free_memory,
+                workload_metrics: self.workload_metrics.clone(),
             })
             .await
             .unwrap_or_illegal_state();
     }
+
+    // [impl->swdd~agent-collects-workload-metrics-periodically~1]
+    async fn collect_workload_metrics(&mut self) {
+        self.workload_metrics = self.runtime_manager.collect_workload_metrics().await;
+
+        log::trace!(
+            "Agent '{}' collected metrics of {} workloads.",
+            self.agent_name,
+            self.workload_metrics.len()
+        );
+    }
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -296,9 +332,13 @@ mod tests {
     use common::{
         commands::UpdateWorkloadState,
         from_server_interface::{FromServer, FromServerInterface},
-        objects::{CpuUsage, ExecutionState, FreeMemory, generate_test_workload_spec_with_param},
+        objects::{
+            CpuUsage, ExecutionState, FreeMemory, WorkloadMetrics,
+            generate_test_workload_spec_with_param,
+        },
         to_server_interface::ToServer,
     };
+    use std::collections::HashMap;
 
     use crate::subscription_store::generate_test_subscription_entry;
 
@@ -677,6 +717,119 @@ mod tests {
         assert!(join!(handle).0.is_ok());
     }
 
+    // [utest->swdd~agent-collects-workload-metrics-periodically~1]
+    #[tokio::test]
+    async fn utest_agent_manager_sends_collected_workload_metrics_with_load_status() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let mock_wl_state_store = MockWorkloadStateStore::default();
+        mock_parameter_storage_new_returns(mock_wl_state_store);
+
+        let (_to_manager, manager_receiver) = channel(BUFFER_SIZE);
+        let (to_server, mut server_receiver) = channel(BUFFER_SIZE);
+        let (_workload_state_sender, workload_state_receiver) = channel(BUFFER_SIZE);
+
+        let workload_metrics = HashMap::from([(
+            WORKLOAD_1_NAME.to_string(),
+            WorkloadMetrics {
+                cpu_usage: Some(20),
+                memory_usage: Some(2048),
+                restart_count: 1,
+            },
+        )]);
+
+        let mut mock_runtime_manager = RuntimeManager::default();
+        let collected_metrics = workload_metrics.clone();
+        mock_runtime_manager
+            .expect_collect_workload_metrics()
+            .once()
+            .return_once(move || collected_metrics);
+
+        let mock_resource_monitor_context = MockResourceMonitor::new_context();
+        mock_resource_monitor_context
+            .expect()
+            .once()
+            .return_once(|| {
+                let mut mock_resource_monitor = MockResourceMonitor::default();
+                mock_resource_monitor
+                    .expect_sample_resource_usage()
+                    .returning(|| (CpuUsage::new(50.0), FreeMemory { free_memory: 1024 }));
+                mock_resource_monitor
+            });
+
+        let mut agent_manager = AgentManager::new(
+            AGENT_NAME.to_string(),
+            manager_receiver,
+            mock_runtime_manager,
+            to_server,
+            workload_state_receiver,
+        );
+
+        agent_manager.collect_workload_metrics().await;
+        agent_manager
+            .measure_and_forward_resource_availability()
+            .await;
+
+        let result = server_receiver.recv().await.unwrap();
+        if let ToServer::AgentLoadStatus(load_status) = result {
+            assert_eq!(load_status.workload_metrics, workload_metrics);
+        } else {
+            panic!("Expected AgentLoadStatus, got something else");
+        }
+    }
+
+    // [utest->swdd~agent-checks-dependency-timeouts-periodically~1]
+    #[tokio::test]
+    async fn utest_agent_manager_checks_dependency_timeouts_periodically() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let mock_wl_state_store = MockWorkloadStateStore::default();
+        mock_parameter_storage_new_returns(mock_wl_state_store);
+
+        let (to_manager, manager_receiver) = channel(BUFFER_SIZE);
+        let (to_server, _server_receiver) = channel(BUFFER_SIZE);
+        let (_workload_state_sender, workload_state_receiver) = channel(BUFFER_SIZE);
+        let mut mock_runtime_manager = RuntimeManager::default();
+        mock_runtime_manager
+            .expect_update_workloads_on_fulfilled_dependencies()
+            .once()
+            .return_const(());
+
+        let mock_resource_monitor_context = MockResourceMonitor::new_context();
+        mock_resource_monitor_context
+            .expect()
+            .once()
+            .return_once(|| {
+                let mut mock_resource_monitor = MockResourceMonitor::default();
+                mock_resource_monitor
+                    .expect_sample_resource_usage()
+                    .returning(|| (CpuUsage::new(50.0), FreeMemory { free_memory: 1024 }));
+                mock_resource_monitor
+            });
+
+        let mut agent_manager = AgentManager::new(
+            AGENT_NAME.to_string(),
+            manager_receiver,
+            mock_runtime_manager,
+            to_server,
+            workload_state_receiver,
+        );
+
+        let handle = tokio::spawn(async move { agent_manager.start().await });
+
+        tokio::time::sleep(
+            super::DEPENDENCY_TIMEOUT_CHECK_INTERVAL_TICK + std::time::Duration::from_millis(200),
+        )
+        .await;
+
+        to_manager.stop().await.unwrap();
+        assert!(join!(handle).0.is_ok());
+    }
+
     // [utest->swdd~agent-handles-logs-requests-from-server~1]
     #[tokio::test]
     async fn utest_agent_manager_request_logs() {
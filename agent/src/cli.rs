@@ -13,6 +13,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use clap::{ArgAction, Parser};
+use std::error::Error;
 
 // [impl->swdd~agent-supports-cli-argument-for-insecure-communication~1]
 // [impl->swdd~agent-supports-pem-file-paths-as-cli-arguments~1]
@@ -48,6 +49,17 @@ pub struct Arguments {
     #[clap(long = "key_pem", env = "ANKAGENT_KEY_PEM")]
     /// Path to agent key pem file.
     pub key_pem: Option<String>,
+    #[clap(short = 'l', long = "label", value_parser = parse_label)]
+    /// A label announced to the server, formatted as "--label key1=value1 --label key2=value2".
+    /// Workloads can select the agent by its labels instead of its name.
+    pub labels: Vec<(String, String)>,
+}
+
+fn parse_label(s: &str) -> Result<(String, String), Box<dyn Error + Send + Sync + 'static>> {
+    let (key, value) = s
+        .split_once('=')
+        .ok_or_else(|| format!("invalid KEY=value: no `=` found in `{s}`"))?;
+    Ok((key.to_owned(), value.to_owned()))
 }
 
 pub fn parse() -> Arguments {
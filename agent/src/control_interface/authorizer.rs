@@ -28,6 +28,9 @@ use rules::{LogRule, StateRule};
 #[cfg(test)]
 use mockall::mock;
 
+// Revisions contain complete desired states, so reading them is the same as reading the desired state.
+const REVISIONS_REQUEST_FIELD_MASK: &str = "desiredState";
+
 use crate::control_interface::authorizer::path_pattern::PathPattern;
 
 #[derive(Clone, Default, Debug, PartialEq)]
@@ -120,6 +123,22 @@ impl Authorizer {
             }
             // [impl->swdd~agent-authorizing-logs-cancel-always-allowed~1]
             common::commands::RequestContent::LogsCancelRequest => true,
+            // [impl->swdd~agent-authorizing-revisions-request-as-desired-state-read~1]
+            common::commands::RequestContent::RevisionsRequest(_) => Self::check_state_rules(
+                &request.request_id,
+                &vec![REVISIONS_REQUEST_FIELD_MASK.to_string()],
+                &self.state_allow_read,
+                &self.state_deny_read,
+            ),
+            // [impl->swdd~agent-authorizing-events-subscribe-request-as-state-read~1]
+            common::commands::RequestContent::EventsSubscribeRequest(r) => Self::check_state_rules(
+                &request.request_id,
+                &r.field_masks,
+                &self.state_allow_read,
+                &self.state_deny_read,
+            ),
+            // [impl->swdd~agent-authorizing-events-cancel-always-allowed~1]
+            common::commands::RequestContent::EventsCancelRequest => true,
         }
     }
 
@@ -241,7 +260,10 @@ impl From<&ControlInterfaceAccess> for Authorizer {
 #[cfg(test)]
 mod test {
     use common::{
-        commands::{CompleteStateRequest, LogsRequest, Request, UpdateStateRequest},
+        commands::{
+            CompleteStateRequest, EventsSubscribeRequest, LogsRequest, Request, RevisionsRequest,
+            UpdateStateRequest,
+        },
         objects::{self, AccessRightsRule, ControlInterfaceAccess, WorkloadInstanceName},
     };
     use std::sync::Arc;
@@ -352,6 +374,8 @@ mod test {
                 UpdateStateRequest {
                     state: Default::default(),
                     update_mask: vec![],
+                    dry_run: false,
+                    expected_revision: None,
                 },
             )),
         };
@@ -424,6 +448,8 @@ mod test {
                 UpdateStateRequest {
                     update_mask: vec![MATCHING_PATH.into()],
                     state: Default::default(),
+                    dry_run: false,
+                    expected_revision: None,
                 },
             )),
         };
@@ -477,6 +503,8 @@ mod test {
                 UpdateStateRequest {
                     update_mask: vec![MATCHING_PATH.into(), MATCHING_PATH_2.into()],
                     state: Default::default(),
+                    dry_run: false,
+                    expected_revision: None,
                 },
             )),
         };
@@ -505,6 +533,8 @@ mod test {
                 UpdateStateRequest {
                     update_mask: vec![MATCHING_PATH.into(), NON_MATCHING_PATH.into()],
                     state: Default::default(),
+                    dry_run: false,
+                    expected_revision: None,
                 },
             )),
         };
@@ -620,6 +650,73 @@ mod test {
         assert!(authorizer.authorize(&request));
     }
 
+    // [utest->swdd~agent-authorizing-revisions-request-as-desired-state-read~1]
+    #[test]
+    fn utest_revisions_request() {
+        let request = Request {
+            request_id: "".into(),
+            request_content: common::commands::RequestContent::RevisionsRequest(RevisionsRequest {
+                revision: None,
+            }),
+        };
+
+        let authorizer = Authorizer::default();
+        assert!(!authorizer.authorize(&request));
+        let authorizer =
+            create_authorizer(&[RuleType::StateAllowRead(vec!["desiredState".into()])]);
+        assert!(authorizer.authorize(&request));
+        let authorizer = create_authorizer(&[RuleType::StateAllowRead(vec!["*".into()])]);
+        assert!(authorizer.authorize(&request));
+        let authorizer = create_authorizer(&[RuleType::StateAllowWrite(vec!["*".into()])]);
+        assert!(!authorizer.authorize(&request));
+        let authorizer = create_authorizer(&[RuleType::StateAllowRead(vec![
+            "desiredState.workloads.w1".into(),
+        ])]);
+        assert!(!authorizer.authorize(&request));
+        let authorizer = create_authorizer(&[
+            RuleType::StateAllowRead(vec!["*".into()]),
+            RuleType::StateDenyRead(vec!["desiredState.workloads.w1".into()]),
+        ]);
+        assert!(!authorizer.authorize(&request));
+    }
+
+    // [utest->swdd~agent-authorizing-events-subscribe-request-as-state-read~1]
+    #[test]
+    fn utest_events_subscribe_request() {
+        let request = Request {
+            request_id: "".into(),
+            request_content: common::commands::RequestContent::EventsSubscribeRequest(
+                EventsSubscribeRequest {
+                    field_masks: vec![MATCHING_PATH.into()],
+                },
+            ),
+        };
+
+        let authorizer = Authorizer::default();
+        assert!(!authorizer.authorize(&request));
+        let authorizer = create_authorizer(&[RuleType::StateAllowRead(vec![MATCHING_PATH.into()])]);
+        assert!(authorizer.authorize(&request));
+        let authorizer =
+            create_authorizer(&[RuleType::StateAllowWrite(vec![MATCHING_PATH.into()])]);
+        assert!(!authorizer.authorize(&request));
+        let authorizer = create_authorizer(&[
+            RuleType::StateAllowRead(vec![MATCHING_PATH.into()]),
+            RuleType::StateDenyRead(vec![MATCHING_PATH.into()]),
+        ]);
+        assert!(!authorizer.authorize(&request));
+    }
+
+    // [utest->swdd~agent-authorizing-events-cancel-always-allowed~1]
+    #[test]
+    fn utest_events_cancel_request() {
+        let request = Request {
+            request_id: "".into(),
+            request_content: common::commands::RequestContent::EventsCancelRequest,
+        };
+
+        assert!(Authorizer::default().authorize(&request));
+    }
+
     // [utest->swdd~agent-authorizing-request-operations~2]
     #[test]
     fn utest_authorizer_from_control_interface_access() {
@@ -54,7 +54,7 @@ impl PathPattern for AllowPathPattern {
             return (false, String::new());
         }
 
-        match_rule_with_path(self, other)
+        match_rule_with_path(self, other, false)
     }
 }
 
@@ -81,8 +81,11 @@ impl PathPattern for DenyPathPattern {
         &self.sections
     }
 
+    // A wildcard in the requested path can be expanded to any value and is therefore
+    // matched by every section of a deny rule.
+    // [impl->swdd~agent-authorizing-deny-rules-match-wildcards-in-paths~1]
     fn matches(&self, other: &Path) -> (bool, PathPatternMatchReason) {
-        match_rule_with_path(self, other)
+        match_rule_with_path(self, other, true)
     }
 }
 
@@ -136,14 +139,18 @@ impl Display for PathPatternSection {
     }
 }
 
-fn match_rule_with_path(rule: &impl PathPattern, other: &Path) -> (bool, PathPatternMatchReason) {
+fn match_rule_with_path(
+    rule: &impl PathPattern,
+    other: &Path,
+    wildcard_in_path_matches: bool,
+) -> (bool, PathPatternMatchReason) {
     // [impl->swdd~agent-authorizing-rules-without-segments-never-match~1]
     if rule.sections().is_empty() {
         return (false, "Empty filter masks in rules never match.".into());
     }
 
     for (a, b) in rule.sections().iter().zip(other.sections.iter()) {
-        if !a.matches(b) {
+        if !(a.matches(b) || wildcard_in_path_matches && b == WILDCARD_SYMBOL) {
             return (false, String::new());
         }
     }
@@ -305,6 +312,25 @@ mod tests {
         assert!(p.matches(&"some.pre.fix.test".into()).0);
     }
 
+    // [utest->swdd~agent-authorizing-deny-rules-match-wildcards-in-paths~1]
+    #[test]
+    fn utest_deny_path_pattern_matches_wildcard_in_path() {
+        let p = DenyPathPattern::from("agents.agent_A");
+
+        assert!(p.matches(&"agents.*".into()).0);
+        assert!(p.matches(&"agents.*.labels".into()).0);
+        assert!(p.matches(&"*.agent_A".into()).0);
+        assert!(!p.matches(&"*.agent_B".into()).0);
+    }
+
+    // [utest->swdd~agent-authorizing-matching-allow-rules~1]
+    #[test]
+    fn utest_allow_path_pattern_does_not_match_wildcard_in_path() {
+        let p = AllowPathPattern::from("agents.agent_A");
+
+        assert!(!p.matches(&"agents.*.labels".into()).0);
+    }
+
     // [utest->swdd~agent-authorizing-rules-without-segments-never-match~1]
     #[test]
     fn utest_empty_deny_path_pattern_does_not_match() {
@@ -121,11 +121,14 @@ impl ControlInterfaceTask {
                         let forward_result = self.forward_from_server(response).await;
                         if let Err(DeliveryError::NoReader(response)) = forward_result {
                             // [impl->swdd~agent-handles-control-interface-workload-gone~1]
-                            log::info!("Could not forward the response with Id: '{}'. Stopping log collection.", response.request_id);
+                            log::info!("Could not forward the response with Id: '{}'. Stopping log collection or event subscription.", response.request_id);
                             match response.response_content {
                                 Some(ank_base::response::ResponseContent::LogEntriesResponse(_))=> {
                                     let _ =self.to_server_sender.logs_cancel_request(commands::Request::prefix_id(&self.request_id_prefix, &response.request_id)).await;
                                 }
+                                Some(ank_base::response::ResponseContent::EventResponse(_))=> {
+                                    let _ =self.to_server_sender.events_cancel_request(commands::Request::prefix_id(&self.request_id_prefix, &response.request_id)).await;
+                                }
                                 unexpected => {
                                     log::warn!("Unexpected response content: '{unexpected:?}'");
                                 },
@@ -155,6 +158,7 @@ impl ControlInterfaceTask {
                                         request_id: request.request_id,
                                         response_content: Some(ank_base::response::ResponseContent::Error(ank_base::Error {
                                             message: "Access denied".into(),
+                                            ..Default::default()
                                         })),
                                     };
                                     let _ = self.forward_from_server(error).await;
@@ -541,6 +545,101 @@ mod tests {
         );
     }
 
+    // [utest->swdd~agent-handles-control-interface-workload-gone~1]
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn utest_control_interface_task_run_delivery_of_event_fails() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let response = ank_base::Response {
+            request_id: REQUEST_ID.into(),
+            response_content: Some(ank_base::response::ResponseContent::EventResponse(
+                Default::default(),
+            )),
+        };
+
+        let test_command_binary = control_api::FromAnkaios {
+            from_ankaios_enum: Some(control_api::from_ankaios::FromAnkaiosEnum::Response(
+                Box::new(response.clone()),
+            )),
+        }
+        .encode_length_delimited_to_vec();
+
+        let mut input_stream_mock = MockInputPipe::default();
+
+        let mut mockall_seq = Sequence::new();
+
+        let workload_hello_binary = prepare_workload_hello_binary_message(common::ANKAIOS_VERSION);
+        input_stream_mock
+            .expect_read_protobuf_data()
+            .once()
+            .in_sequence(&mut mockall_seq)
+            .return_once(move || Box::pin(async { Ok(workload_hello_binary) }));
+
+        input_stream_mock
+            .expect_read_protobuf_data()
+            .once()
+            .in_sequence(&mut mockall_seq)
+            .return_once(|| {
+                Box::pin(async {
+                    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+                    Err(Error::other("error"))
+                })
+            });
+
+        let mut output_stream_mock = MockOutputPipe::default();
+
+        output_stream_mock
+            .expect_write_all()
+            .with(predicate::eq(prepare_control_interface_accepted_message()))
+            .once()
+            .returning(|_| Ok(()));
+
+        output_stream_mock
+            .expect_write_all()
+            .with(predicate::eq(test_command_binary))
+            .once()
+            .returning(|_| {
+                Err(OutputPipeError::ReceiverGone(Error::new(
+                    std::io::ErrorKind::BrokenPipe,
+                    "error",
+                )))
+            });
+
+        let (input_pipe_sender, from_server_receiver) = mpsc::channel(1);
+        let (output_pipe_sender, mut output_pipe_receiver) = mpsc::channel(1);
+        let request_id_prefix = "prefix@";
+
+        let authorizer = MockAuthorizer::default();
+
+        let control_interface_task = ControlInterfaceTask::new(
+            output_stream_mock,
+            input_stream_mock,
+            from_server_receiver,
+            output_pipe_sender,
+            request_id_prefix.to_owned(),
+            Arc::new(authorizer),
+        );
+
+        // send a response to the _input_pipe_sender
+        let _ = input_pipe_sender
+            .event_response(REQUEST_ID.into(), ank_base::EventResponse::default())
+            .await;
+
+        tokio::spawn(async { control_interface_task.run().await });
+
+        let mut expected_events_cancel_request = commands::Request {
+            request_id: response.request_id,
+            request_content: commands::RequestContent::EventsCancelRequest,
+        };
+        expected_events_cancel_request.prefix_request_id(request_id_prefix);
+        assert_eq!(
+            output_pipe_receiver.recv().await,
+            Some(ToServer::Request(expected_events_cancel_request))
+        );
+    }
+
     // [utest->swdd~agent-listens-for-requests-from-pipe~1]
     // [utest->swdd~agent-ensures-control-interface-input-pipe-read~1]
     // [utest->swdd~agent-checks-request-for-authorization~1]
@@ -593,6 +692,7 @@ mod tests {
             response_content: Some(ank_base::response::ResponseContent::Error(
                 ank_base::Error {
                     message: "Access denied".into(),
+                    ..Default::default()
                 },
             )),
         };
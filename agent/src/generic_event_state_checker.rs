@@ -0,0 +1,287 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use std::{str::FromStr, sync::Arc, time::Duration};
+use tokio::{task::JoinHandle, time};
+
+use crate::{
+    health_probes::HealthProbes,
+    runtime_connectors::{RuntimeStateGetter, StateChecker},
+    workload_state::{WorkloadStateSender, WorkloadStateSenderInterface},
+};
+use common::objects::{ExecutionState, ExecutionStateEnum, WorkloadSpec};
+
+// [impl->swdd~event-state-checker-falls-back-to-polling~1]
+const STATUS_CHECK_INTERVAL_MS: u64 = 500;
+// [impl->swdd~agent-provides-generic-event-state-checker-implementation~1]
+const EVENT_RESYNC_INTERVAL_MS: u64 = 10000;
+
+#[derive(Debug)]
+pub struct GenericEventStateChecker {
+    workload_name: String,
+    task_handle: JoinHandle<()>,
+}
+
+#[async_trait]
+impl<WorkloadId> StateChecker<WorkloadId> for GenericEventStateChecker
+where
+    WorkloadId: ToString + FromStr + Clone + Send + Sync + 'static,
+{
+    // [impl->swdd~agent-provides-generic-event-state-checker-implementation~1]
+    fn start_checker(
+        workload_spec: &WorkloadSpec,
+        workload_id: WorkloadId,
+        workload_state_sender: WorkloadStateSender,
+        state_getter: impl RuntimeStateGetter<WorkloadId>,
+    ) -> Self {
+        let workload_spec = workload_spec.clone();
+        let workload_name = workload_spec.instance_name.workload_name().to_owned();
+        let task_handle = tokio::spawn(async move {
+            let state_getter = Arc::new(state_getter);
+            // Subscribing before the first check ensures that no state change is missed.
+            let mut state_changes = state_getter.subscribe_state_changes();
+            let raw_workload_id = workload_id.to_string();
+            let has_probes = workload_spec
+                .probes
+                .as_ref()
+                .is_some_and(|probes| probes.liveness.is_some() || probes.readiness.is_some());
+            let mut last_state = ExecutionState::unknown("Never received an execution state.");
+            let mut health_probes = HealthProbes::new(workload_spec.probes.clone());
+            loop {
+                let mut current_state = state_getter.get_state(&workload_id).await;
+
+                // [impl->swdd~generic-state-checker-executes-health-probes~1]
+                if current_state.is_running() {
+                    health_probes.start(&state_getter, &workload_id);
+                    current_state = health_probes.check();
+                } else {
+                    health_probes.stop();
+                }
+
+                if current_state != last_state {
+                    log::debug!(
+                        "The workload {} has changed its state to {:?}",
+                        workload_spec.instance_name.workload_name(),
+                        current_state
+                    );
+                    last_state = current_state.clone();
+
+                    // [impl->swdd~generic-state-checker-sends-workload-state~2]
+                    workload_state_sender
+                        .report_workload_execution_state(
+                            &workload_spec.instance_name,
+                            current_state,
+                        )
+                        .await;
+
+                    if last_state.state == ExecutionStateEnum::Removed {
+                        break;
+                    }
+                }
+
+                // Health probes need to be executed periodically, so they are still polled.
+                match state_changes.as_mut() {
+                    Some(subscription) if subscription.is_connected() && !has_probes => {
+                        subscription
+                            .wait_for_state_change(
+                                &raw_workload_id,
+                                Duration::from_millis(EVENT_RESYNC_INTERVAL_MS),
+                            )
+                            .await;
+                    }
+                    // [impl->swdd~event-state-checker-falls-back-to-polling~1]
+                    _ => time::sleep(Duration::from_millis(STATUS_CHECK_INTERVAL_MS)).await,
+                }
+            }
+        });
+
+        GenericEventStateChecker {
+            workload_name,
+            task_handle,
+        }
+    }
+
+    async fn stop_checker(self) {
+        drop(self);
+    }
+}
+
+impl Drop for GenericEventStateChecker {
+    fn drop(&mut self) {
+        self.task_handle.abort();
+        log::trace!("Over and out for workload '{}'", self.workload_name);
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use common::objects::{ExecutionState, generate_test_workload_spec_with_param};
+    use tokio::sync::{broadcast, watch};
+
+    use crate::{
+        generic_event_state_checker::GenericEventStateChecker,
+        runtime_connectors::{MockRuntimeStateGetter, StateChangeSubscription, StateChecker},
+    };
+
+    const RUNTIME_NAME: &str = "runtime1";
+    const AGENT_NAME: &str = "agent_x";
+    const WORKLOAD_1_NAME: &str = "workload1";
+    const WORKLOAD_ID: &str = "some strange Id";
+
+    // [utest->swdd~agent-provides-generic-event-state-checker-implementation~1]
+    #[tokio::test]
+    async fn utest_generic_event_state_checker_checks_state_on_event() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let (events_tx, events_rx) = broadcast::channel(10);
+        let (_connected_tx, connected_rx) = watch::channel(true);
+        let subscription =
+            std::sync::Mutex::new(Some(StateChangeSubscription::new(events_rx, connected_rx)));
+
+        let mut mock_runtime_getter = MockRuntimeStateGetter::default();
+        mock_runtime_getter
+            .expect_subscribe_state_changes()
+            .once()
+            .returning(move || subscription.lock().unwrap().take());
+        mock_runtime_getter
+            .expect_get_state()
+            .once()
+            .returning(|_: &String| Box::pin(async { ExecutionState::running() }));
+        mock_runtime_getter
+            .expect_get_state()
+            .once()
+            .returning(|_: &String| Box::pin(async { ExecutionState::succeeded() }));
+
+        let (state_sender, mut state_receiver) = tokio::sync::mpsc::channel(20);
+
+        let workload_spec = generate_test_workload_spec_with_param(
+            AGENT_NAME.to_string(),
+            WORKLOAD_1_NAME.to_string(),
+            RUNTIME_NAME.to_string(),
+        );
+
+        let event_state_checker = GenericEventStateChecker::start_checker(
+            &workload_spec,
+            WORKLOAD_ID.to_string(),
+            state_sender.clone(),
+            mock_runtime_getter,
+        );
+
+        let state_update_1 = state_receiver.recv().await.unwrap();
+        assert_eq!(
+            state_update_1,
+            common::objects::generate_test_workload_state_with_workload_spec(
+                &workload_spec,
+                ExecutionState::running(),
+            )
+        );
+
+        // No further state check without an event for the workload
+        events_tx.send("other workload".to_string()).unwrap();
+        tokio::time::sleep(Duration::from_millis(700)).await;
+        assert!(state_receiver.try_recv().is_err());
+
+        events_tx.send(WORKLOAD_ID.to_string()).unwrap();
+        let state_update_2 = tokio::time::timeout(Duration::from_secs(1), state_receiver.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            state_update_2,
+            common::objects::generate_test_workload_state_with_workload_spec(
+                &workload_spec,
+                ExecutionState::succeeded(),
+            )
+        );
+
+        <GenericEventStateChecker as StateChecker<String>>::stop_checker::<'_>(event_state_checker)
+            .await;
+    }
+
+    // [utest->swdd~event-state-checker-falls-back-to-polling~1]
+    #[tokio::test]
+    async fn utest_generic_event_state_checker_polls_without_event_stream() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let (_events_tx, events_rx) = broadcast::channel::<String>(10);
+        let (_connected_tx, connected_rx) = watch::channel(false);
+        let subscription =
+            std::sync::Mutex::new(Some(StateChangeSubscription::new(events_rx, connected_rx)));
+
+        let mut mock_runtime_getter = MockRuntimeStateGetter::default();
+        mock_runtime_getter
+            .expect_subscribe_state_changes()
+            .once()
+            .returning(move || subscription.lock().unwrap().take());
+        mock_runtime_getter
+            .expect_get_state()
+            .once()
+            .returning(|_: &String| Box::pin(async { ExecutionState::running() }));
+        mock_runtime_getter
+            .expect_get_state()
+            .returning(|_: &String| Box::pin(async { ExecutionState::succeeded() }));
+
+        let (state_sender, mut state_receiver) = tokio::sync::mpsc::channel(20);
+
+        let workload_spec = generate_test_workload_spec_with_param(
+            AGENT_NAME.to_string(),
+            WORKLOAD_1_NAME.to_string(),
+            RUNTIME_NAME.to_string(),
+        );
+
+        let event_state_checker = GenericEventStateChecker::start_checker(
+            &workload_spec,
+            WORKLOAD_ID.to_string(),
+            state_sender.clone(),
+            mock_runtime_getter,
+        );
+
+        tokio::time::sleep(Duration::from_millis(700)).await;
+
+        <GenericEventStateChecker as StateChecker<String>>::stop_checker::<'_>(event_state_checker)
+            .await;
+
+        let state_update_1 = state_receiver.recv().await.unwrap();
+        assert_eq!(
+            state_update_1,
+            common::objects::generate_test_workload_state_with_workload_spec(
+                &workload_spec,
+                ExecutionState::running(),
+            )
+        );
+        let state_update_2 = state_receiver.recv().await.unwrap();
+        assert_eq!(
+            state_update_2,
+            common::objects::generate_test_workload_state_with_workload_spec(
+                &workload_spec,
+                ExecutionState::succeeded(),
+            )
+        );
+    }
+}
@@ -13,10 +13,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use async_trait::async_trait;
-use std::{str::FromStr, time::Duration};
+use std::{str::FromStr, sync::Arc, time::Duration};
 use tokio::{task::JoinHandle, time};
 
 use crate::{
+    health_probes::HealthProbes,
     runtime_connectors::{RuntimeStateGetter, StateChecker},
     workload_state::{WorkloadStateSender, WorkloadStateSenderInterface},
 };
@@ -46,11 +47,21 @@ where
         let workload_spec = workload_spec.clone();
         let workload_name = workload_spec.instance_name.workload_name().to_owned();
         let task_handle = tokio::spawn(async move {
+            let state_getter = Arc::new(state_getter);
             let mut last_state = ExecutionState::unknown("Never received an execution state.");
             let mut interval = time::interval(Duration::from_millis(STATUS_CHECK_INTERVAL_MS));
+            let mut health_probes = HealthProbes::new(workload_spec.probes.clone());
             loop {
                 interval.tick().await;
-                let current_state = state_getter.get_state(&workload_id).await;
+                let mut current_state = state_getter.get_state(&workload_id).await;
+
+                // [impl->swdd~generic-state-checker-executes-health-probes~1]
+                if current_state.is_running() {
+                    health_probes.start(&state_getter, &workload_id);
+                    current_state = health_probes.check();
+                } else {
+                    health_probes.stop();
+                }
 
                 if current_state != last_state {
                     log::debug!(
@@ -105,7 +116,10 @@ impl Drop for GenericPollingStateChecker {
 mod tests {
     use std::time::Duration;
 
-    use common::{objects::generate_test_workload_spec_with_param, objects::ExecutionState};
+    use common::{
+        objects::generate_test_workload_spec_with_param,
+        objects::{ExecProbe, ExecutionState, Probe, Probes},
+    };
 
     use crate::{
         generic_polling_state_checker::GenericPollingStateChecker,
@@ -162,4 +176,72 @@ mod tests {
         let state_update_1 = state_receiver.recv().await.unwrap();
         assert_eq!(state_update_1, expected_state);
     }
+
+    // [utest->swdd~generic-state-checker-executes-health-probes~1]
+    #[tokio::test]
+    async fn utest_generic_polling_state_checker_reports_failing_liveness_probe() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let mut mock_runtime_getter = MockRuntimeStateGetter::default();
+
+        mock_runtime_getter
+            .expect_get_state()
+            .returning(|_: &String| Box::pin(async { ExecutionState::running() }));
+        mock_runtime_getter
+            .expect_exec_command()
+            .returning(|_: &String, _| Box::pin(async { Err("exit code 1".to_string()) }));
+
+        let (state_sender, mut state_receiver) = tokio::sync::mpsc::channel(20);
+
+        let mut workload_spec = generate_test_workload_spec_with_param(
+            AGENT_NAME.to_string(),
+            WORKLOAD_1_NAME.to_string(),
+            RUNTIME_NAME.to_string(),
+        );
+        workload_spec.probes = Some(Probes {
+            liveness: Some(Probe {
+                exec: Some(ExecProbe {
+                    command: vec!["check".to_string()],
+                }),
+                failure_threshold: 1,
+                ..Default::default()
+            }),
+            readiness: None,
+        });
+
+        let generic_state_state_checker = GenericPollingStateChecker::start_checker(
+            &workload_spec,
+            WORKLOAD_ID.to_string(),
+            state_sender.clone(),
+            mock_runtime_getter,
+        );
+
+        tokio::time::sleep(Duration::from_millis(700)).await;
+
+        <GenericPollingStateChecker as StateChecker<String>>::stop_checker::<'_>(
+            generic_state_state_checker,
+        )
+        .await;
+
+        // The probe runs in its own task, so its result is reported with the next poll
+        let state_update_1 = state_receiver.recv().await.unwrap();
+        assert_eq!(
+            state_update_1,
+            common::objects::generate_test_workload_state_with_workload_spec(
+                &workload_spec,
+                ExecutionState::running(),
+            )
+        );
+
+        let state_update_2 = state_receiver.recv().await.unwrap();
+        assert_eq!(
+            state_update_2,
+            common::objects::generate_test_workload_state_with_workload_spec(
+                &workload_spec,
+                ExecutionState::running_unhealthy("Liveness probe failed: exit code 1"),
+            )
+        );
+    }
 }
@@ -0,0 +1,406 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{str::FromStr, sync::Arc, time::Duration};
+
+use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+    net::TcpStream,
+    sync::watch,
+    task::JoinHandle,
+    time::{self, MissedTickBehavior},
+};
+
+use crate::runtime_connectors::RuntimeStateGetter;
+use common::objects::{ExecutionState, Probe, Probes};
+
+#[derive(Debug, Clone, Default)]
+struct ProbeStatus {
+    consecutive_failures: u32,
+    last_error: String,
+    succeeded_once: bool,
+}
+
+// [impl->swdd~agent-executes-health-probes~2]
+// A probe runs in its own task, so a slow probe does not delay the state checks of the workload.
+#[derive(Debug)]
+struct ProbeTask {
+    failure_threshold: u32,
+    status: watch::Receiver<ProbeStatus>,
+    task_handle: JoinHandle<()>,
+}
+
+impl ProbeTask {
+    fn start<WorkloadId>(
+        probe: Probe,
+        state_getter: Arc<impl RuntimeStateGetter<WorkloadId>>,
+        workload_id: WorkloadId,
+    ) -> Self
+    where
+        WorkloadId: ToString + FromStr + Clone + Send + Sync + 'static,
+    {
+        let (status_sender, status) = watch::channel(ProbeStatus::default());
+        let failure_threshold = probe.failure_threshold;
+        let task_handle = tokio::spawn(async move {
+            time::sleep(Duration::from_secs(probe.initial_delay_seconds.into())).await;
+
+            let mut interval = time::interval(Duration::from_secs(probe.period_seconds.into()));
+            interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            loop {
+                interval.tick().await;
+                let probe_result = execute_probe(&probe, state_getter.as_ref(), &workload_id).await;
+                status_sender.send_modify(|status| match probe_result {
+                    Ok(()) => {
+                        status.consecutive_failures = 0;
+                        status.succeeded_once = true;
+                    }
+                    Err(err) => {
+                        status.consecutive_failures += 1;
+                        status.last_error = err;
+                    }
+                });
+            }
+        });
+
+        ProbeTask {
+            failure_threshold,
+            status,
+            task_handle,
+        }
+    }
+
+    fn failed_with(&self) -> Option<String> {
+        let status = self.status.borrow();
+        (status.consecutive_failures >= self.failure_threshold).then(|| status.last_error.clone())
+    }
+
+    fn succeeded_once(&self) -> bool {
+        self.status.borrow().succeeded_once
+    }
+}
+
+impl Drop for ProbeTask {
+    fn drop(&mut self) {
+        self.task_handle.abort();
+    }
+}
+
+// [impl->swdd~agent-executes-health-probes~2]
+#[derive(Debug, Default)]
+pub struct HealthProbes {
+    probes: Probes,
+    liveness: Option<ProbeTask>,
+    readiness: Option<ProbeTask>,
+}
+
+impl HealthProbes {
+    pub fn new(probes: Option<Probes>) -> Self {
+        HealthProbes {
+            probes: probes.unwrap_or_default(),
+            ..Default::default()
+        }
+    }
+
+    // The probes start over each time the workload enters the running state.
+    pub fn start<WorkloadId>(
+        &mut self,
+        state_getter: &Arc<impl RuntimeStateGetter<WorkloadId>>,
+        workload_id: &WorkloadId,
+    ) where
+        WorkloadId: ToString + FromStr + Clone + Send + Sync + 'static,
+    {
+        if self.liveness.is_none() {
+            self.liveness =
+                self.probes.liveness.clone().map(|probe| {
+                    ProbeTask::start(probe, state_getter.clone(), workload_id.clone())
+                });
+        }
+        if self.readiness.is_none() {
+            self.readiness =
+                self.probes.readiness.clone().map(|probe| {
+                    ProbeTask::start(probe, state_getter.clone(), workload_id.clone())
+                });
+        }
+    }
+
+    pub fn stop(&mut self) {
+        self.liveness = None;
+        self.readiness = None;
+    }
+
+    // [impl->swdd~agent-executes-health-probes~2]
+    // [impl->swdd~agent-health-probes-set-running-substate~1]
+    pub fn check(&self) -> ExecutionState {
+        if let Some(last_error) = self.liveness.as_ref().and_then(ProbeTask::failed_with) {
+            return ExecutionState::running_unhealthy(format!(
+                "Liveness probe failed: {last_error}"
+            ));
+        }
+        match self.readiness.as_ref() {
+            Some(readiness) => match readiness.failed_with() {
+                Some(last_error) => ExecutionState::running_not_ready(format!(
+                    "Readiness probe failed: {last_error}"
+                )),
+                None if !readiness.succeeded_once() => {
+                    ExecutionState::running_not_ready("Readiness probe did not succeed yet")
+                }
+                None => ExecutionState::running(),
+            },
+            None => ExecutionState::running(),
+        }
+    }
+}
+
+async fn execute_probe<WorkloadId>(
+    probe: &Probe,
+    state_getter: &impl RuntimeStateGetter<WorkloadId>,
+    workload_id: &WorkloadId,
+) -> Result<(), String>
+where
+    WorkloadId: ToString + FromStr + Clone + Send + Sync + 'static,
+{
+    let timeout = Duration::from_secs(probe.timeout_seconds.into());
+    let probe_result = if let Some(exec) = &probe.exec {
+        time::timeout(
+            timeout,
+            state_getter.exec_command(workload_id, &exec.command),
+        )
+        .await
+    } else if let Some(tcp_socket) = &probe.tcp_socket {
+        time::timeout(timeout, async {
+            let host = state_getter.get_probe_host(workload_id).await?;
+            connect(&host, tcp_socket.port).await.map(|_| ())
+        })
+        .await
+    } else if let Some(http_get) = &probe.http_get {
+        time::timeout(timeout, async {
+            let host = state_getter.get_probe_host(workload_id).await?;
+            http_get_status(&host, http_get.port, &http_get.path).await
+        })
+        .await
+    } else {
+        Ok(Err("No probe action defined".to_string()))
+    };
+
+    probe_result.unwrap_or_else(|_| {
+        Err(format!(
+            "No response within {} seconds",
+            probe.timeout_seconds
+        ))
+    })
+}
+
+async fn connect(host: &str, port: u32) -> Result<TcpStream, String> {
+    let port = u16::try_from(port).map_err(|_| format!("Invalid port '{port}'"))?;
+    TcpStream::connect((host, port))
+        .await
+        .map_err(|err| format!("Could not connect to '{host}:{port}': '{err}'"))
+}
+
+// A response status between 200 and 399 counts as success.
+async fn http_get_status(host: &str, port: u32, path: &str) -> Result<(), String> {
+    let mut stream = connect(host, port).await?;
+    stream
+        .write_all(format!("GET {path} HTTP/1.0\r\nHost: {host}\r\n\r\n").as_bytes())
+        .await
+        .map_err(|err| format!("Could not send request: '{err}'"))?;
+
+    let mut status_line = String::new();
+    BufReader::new(stream)
+        .read_line(&mut status_line)
+        .await
+        .map_err(|err| format!("Could not read response: '{err}'"))?;
+
+    let status = status_line
+        .split_whitespace()
+        .nth(1)
+        .and_then(|status| status.parse::<u16>().ok())
+        .ok_or_else(|| format!("Received invalid response '{}'", status_line.trim()))?;
+    if (200..400).contains(&status) {
+        Ok(())
+    } else {
+        Err(format!("Received response status '{status}'"))
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, time::Duration};
+
+    use tokio::{io::AsyncWriteExt, net::TcpListener, time};
+
+    use super::HealthProbes;
+    use crate::runtime_connectors::MockRuntimeStateGetter;
+    use common::objects::{ExecProbe, ExecutionState, HttpGetProbe, Probe, Probes};
+
+    const WORKLOAD_ID: &str = "workload_id";
+    const PERIOD_SECONDS: u32 = 10;
+
+    fn exec_probe(failure_threshold: u32) -> Probe {
+        Probe {
+            exec: Some(ExecProbe {
+                command: vec!["check".to_string()],
+            }),
+            period_seconds: PERIOD_SECONDS,
+            failure_threshold,
+            ..Default::default()
+        }
+    }
+
+    // [utest->swdd~agent-executes-health-probes~2]
+    // [utest->swdd~agent-health-probes-set-running-substate~1]
+    #[tokio::test(start_paused = true)]
+    async fn utest_health_probes_failing_liveness_reports_unhealthy_after_threshold() {
+        let mut mock_state_getter = MockRuntimeStateGetter::default();
+        mock_state_getter
+            .expect_exec_command()
+            .times(2)
+            .returning(|_: &String, _| Box::pin(async { Err("exit code 1".to_string()) }));
+
+        let mut health_probes = HealthProbes::new(Some(Probes {
+            liveness: Some(exec_probe(2)),
+            readiness: None,
+        }));
+        health_probes.start(&Arc::new(mock_state_getter), &WORKLOAD_ID.to_string());
+
+        // the first probe failed, the second one is not due before the period has passed
+        time::sleep(Duration::from_secs(1)).await;
+        assert_eq!(health_probes.check(), ExecutionState::running());
+
+        time::sleep(Duration::from_secs(PERIOD_SECONDS.into())).await;
+        assert_eq!(
+            health_probes.check(),
+            ExecutionState::running_unhealthy("Liveness probe failed: exit code 1")
+        );
+    }
+
+    // [utest->swdd~agent-executes-health-probes~2]
+    // [utest->swdd~agent-health-probes-set-running-substate~1]
+    #[tokio::test(start_paused = true)]
+    async fn utest_health_probes_readiness_reports_not_ready_until_success() {
+        let mut mock_state_getter = MockRuntimeStateGetter::default();
+        let mut sequence = mockall::Sequence::new();
+        mock_state_getter
+            .expect_exec_command()
+            .once()
+            .in_sequence(&mut sequence)
+            .returning(|_: &String, _| Box::pin(async { Err("exit code 1".to_string()) }));
+        mock_state_getter
+            .expect_exec_command()
+            .once()
+            .in_sequence(&mut sequence)
+            .returning(|_: &String, _| Box::pin(async { Ok(()) }));
+
+        let mut health_probes = HealthProbes::new(Some(Probes {
+            liveness: None,
+            readiness: Some(Probe {
+                initial_delay_seconds: 5,
+                ..exec_probe(3)
+            }),
+        }));
+        health_probes.start(&Arc::new(mock_state_getter), &WORKLOAD_ID.to_string());
+
+        let not_ready = ExecutionState::running_not_ready("Readiness probe did not succeed yet");
+        assert_eq!(health_probes.check(), not_ready);
+
+        time::sleep(Duration::from_secs(6)).await;
+        assert_eq!(health_probes.check(), not_ready);
+
+        time::sleep(Duration::from_secs(PERIOD_SECONDS.into())).await;
+        assert_eq!(health_probes.check(), ExecutionState::running());
+    }
+
+    // [utest->swdd~agent-executes-health-probes~2]
+    #[tokio::test(start_paused = true)]
+    async fn utest_health_probes_count_probe_without_response_as_failed() {
+        let mut mock_state_getter = MockRuntimeStateGetter::default();
+        mock_state_getter
+            .expect_exec_command()
+            .returning(|_: &String, _| Box::pin(std::future::pending()));
+
+        let mut health_probes = HealthProbes::new(Some(Probes {
+            liveness: Some(Probe {
+                timeout_seconds: 1,
+                ..exec_probe(1)
+            }),
+            readiness: None,
+        }));
+        health_probes.start(&Arc::new(mock_state_getter), &WORKLOAD_ID.to_string());
+
+        // the pending probe does not block the check of the state
+        assert_eq!(health_probes.check(), ExecutionState::running());
+
+        time::sleep(Duration::from_secs(2)).await;
+        assert_eq!(
+            health_probes.check(),
+            ExecutionState::running_unhealthy(
+                "Liveness probe failed: No response within 1 seconds"
+            )
+        );
+
+        health_probes.stop();
+        assert_eq!(health_probes.check(), ExecutionState::running());
+    }
+
+    // [utest->swdd~agent-executes-health-probes~2]
+    #[tokio::test]
+    async fn utest_health_probes_http_get_succeeds_on_ok_status() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            stream
+                .write_all(b"HTTP/1.0 204 No Content\r\n\r\n")
+                .await
+                .unwrap();
+        });
+
+        let mut mock_state_getter = MockRuntimeStateGetter::default();
+        mock_state_getter
+            .expect_get_probe_host()
+            .once()
+            .returning(|_: &String| Box::pin(async { Ok("127.0.0.1".to_string()) }));
+
+        let mut health_probes = HealthProbes::new(Some(Probes {
+            liveness: None,
+            readiness: Some(Probe {
+                http_get: Some(HttpGetProbe {
+                    port: port.into(),
+                    path: "/healthz".to_string(),
+                }),
+                failure_threshold: 1,
+                ..Default::default()
+            }),
+        }));
+        health_probes.start(&Arc::new(mock_state_getter), &WORKLOAD_ID.to_string());
+
+        let mut state = health_probes.check();
+        for _ in 0..50 {
+            if state == ExecutionState::running() {
+                break;
+            }
+            time::sleep(Duration::from_millis(20)).await;
+            state = health_probes.check();
+        }
+        assert_eq!(state, ExecutionState::running());
+    }
+}
@@ -15,6 +15,7 @@
 use common::communications_client::CommunicationsClient;
 use common::objects::{AgentName, STR_RE_AGENT, WorkloadState};
 use common::to_server_interface::ToServer;
+use generic_event_state_checker::GenericEventStateChecker;
 use generic_polling_state_checker::GenericPollingStateChecker;
 use grpc::security::TLSConfig;
 use regex::Regex;
@@ -30,7 +31,9 @@ mod runtime_connectors;
 pub mod test_helper;
 mod workload_operation;
 
+mod generic_event_state_checker;
 mod generic_polling_state_checker;
+mod health_probes;
 mod resource_monitor;
 mod runtime_manager;
 mod subscription_store;
@@ -51,11 +54,14 @@ use agent_manager::AgentManager;
 
 #[cfg_attr(test, mockall_double::double)]
 use crate::runtime_manager::RuntimeManager;
+#[cfg(feature = "wasm")]
+use runtime_connectors::wasm::{WasmRuntime, WasmWorkloadId};
 use runtime_connectors::{
     GenericRuntimeFacade, RuntimeConnector, RuntimeFacade,
-    containerd::{ContainerdRuntime, ContainerdWorkloadId},
+    containerd::{ContainerCliTool, ContainerdRuntime, ContainerdWorkloadId},
     podman::{PodmanRuntime, PodmanWorkloadId},
     podman_kube::{PodmanKubeRuntime, PodmanKubeWorkloadId},
+    process::{ProcessRuntime, ProcessWorkloadId},
 };
 
 const BUFFER_SIZE: usize = 20;
@@ -126,10 +132,11 @@ async fn main() {
         .unwrap_or_exit("Error encountered while checking agent name!");
 
     log::debug!(
-        "Starting the Ankaios agent with \n\tname: '{}', \n\tserver url: '{}', \n\trun directory: '{}'",
+        "Starting the Ankaios agent with \n\tname: '{}', \n\tserver url: '{}', \n\trun directory: '{}', \n\tlabels: '{:?}'",
         agent_config.name,
         agent_config.server_url,
         agent_config.run_folder,
+        agent_config.labels,
     );
 
     // [impl->swdd~agent-uses-async-channels~1]
@@ -150,7 +157,7 @@ async fn main() {
     let podman_runtime_name = podman_runtime.name();
     let podman_facade = Box::new(GenericRuntimeFacade::<
         PodmanWorkloadId,
-        GenericPollingStateChecker,
+        GenericEventStateChecker,
     >::new(podman_runtime, run_directory.get_path()));
     let mut runtime_facade_map: HashMap<String, Box<dyn RuntimeFacade>> = HashMap::new();
     runtime_facade_map.insert(podman_runtime_name, podman_facade);
@@ -165,14 +172,44 @@ async fn main() {
     runtime_facade_map.insert(podman_kube_runtime_name, podman_kube_facade);
 
     // [impl->swdd~agent-supports-containerd~1]
-    let containerd_runtime = Box::new(ContainerdRuntime {});
+    let containerd_runtime = Box::new(ContainerdRuntime::new(ContainerCliTool::Nerdctl));
     let containerd_runtime_name = containerd_runtime.name();
     let containerd_facade = Box::new(GenericRuntimeFacade::<
         ContainerdWorkloadId,
-        GenericPollingStateChecker,
+        GenericEventStateChecker,
     >::new(containerd_runtime, run_directory.get_path()));
     runtime_facade_map.insert(containerd_runtime_name, containerd_facade);
 
+    // [impl->swdd~agent-supports-docker~1]
+    let docker_runtime = Box::new(ContainerdRuntime::new(ContainerCliTool::Docker));
+    let docker_runtime_name = docker_runtime.name();
+    let docker_facade = Box::new(GenericRuntimeFacade::<
+        ContainerdWorkloadId,
+        GenericEventStateChecker,
+    >::new(docker_runtime, run_directory.get_path()));
+    runtime_facade_map.insert(docker_runtime_name, docker_facade);
+
+    // [impl->swdd~agent-supports-process-runtime~1]
+    let process_runtime = Box::new(ProcessRuntime::new(run_directory.get_path()));
+    let process_runtime_name = process_runtime.name();
+    let process_facade = Box::new(GenericRuntimeFacade::<
+        ProcessWorkloadId,
+        GenericPollingStateChecker,
+    >::new(process_runtime, run_directory.get_path()));
+    runtime_facade_map.insert(process_runtime_name, process_facade);
+
+    // [impl->swdd~agent-supports-wasm-runtime~1]
+    #[cfg(feature = "wasm")]
+    {
+        let wasm_runtime = Box::new(WasmRuntime::new(run_directory.get_path()));
+        let wasm_runtime_name = wasm_runtime.name();
+        let wasm_facade = Box::new(GenericRuntimeFacade::<
+            WasmWorkloadId,
+            GenericPollingStateChecker,
+        >::new(wasm_runtime, run_directory.get_path()));
+        runtime_facade_map.insert(wasm_runtime_name, wasm_facade);
+    }
+
     // The RuntimeManager currently directly gets the server ToServerInterface, but it shall get the agent manager interface
     // This is needed to be able to filter/authorize the commands towards the Ankaios server
     // The pipe connecting the workload to Ankaios must be in the runtime adapter
@@ -205,6 +242,8 @@ async fn main() {
 
     let mut communications_client = GRPCCommunicationsClient::new_agent_communication(
         agent_config.name.clone(),
+        // [impl->swdd~agent-announces-labels~1]
+        agent_config.labels,
         agent_config.server_url,
         // [impl->swdd~agent-fails-on-missing-file-paths-and-insecure-cli-arguments~1]
         tls_config.unwrap_or_exit("Missing certificate file"),
@@ -0,0 +1,59 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use common::objects::ResourceLimits;
+
+// Podman, nerdctl and docker share the options of their run command for the resource limits.
+pub fn to_command_options(resource_limits: &ResourceLimits) -> Vec<String> {
+    [
+        resource_limits.cpus().map(|cpus| format!("--cpus={cpus}")),
+        resource_limits
+            .memory_limit
+            .map(|memory| format!("--memory={memory}")),
+        resource_limits
+            .pids_limit
+            .map(|pids| format!("--pids-limit={pids}")),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::to_command_options;
+    use common::objects::ResourceLimits;
+
+    // [utest->swdd~podman-create-workload-applies-resource-limits~1]
+    // [utest->swdd~containerd-create-workload-applies-resource-limits~1]
+    #[test]
+    fn utest_to_command_options_skips_unset_limits() {
+        assert!(to_command_options(&ResourceLimits::default()).is_empty());
+        assert_eq!(
+            to_command_options(&ResourceLimits {
+                cpu_quota: Some(250),
+                ..Default::default()
+            }),
+            vec!["--cpus=0.250".to_string()]
+        );
+    }
+}
@@ -0,0 +1,126 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use common::objects::WorkloadMetrics;
+
+const MEMORY_UNITS: [(&str, u64); 10] = [
+    ("B", 1),
+    ("kB", 1000),
+    ("KB", 1000),
+    ("KiB", 1 << 10),
+    ("MB", 1000 * 1000),
+    ("MiB", 1 << 20),
+    ("GB", 1000 * 1000 * 1000),
+    ("GiB", 1 << 30),
+    ("TB", 1000 * 1000 * 1000 * 1000),
+    ("TiB", 1 << 40),
+];
+
+// The container CLIs report the stats as human readable strings,
+// e.g. "12.34%" for the CPU usage and "1.5MiB / 7.7GiB" for the memory usage.
+// [impl->swdd~agent-parses-container-stats~1]
+pub fn to_workload_metrics(cpu_percentage: &str, memory_usage: &str) -> WorkloadMetrics {
+    WorkloadMetrics {
+        cpu_usage: parse_cpu_percentage(cpu_percentage),
+        memory_usage: parse_memory_usage(memory_usage),
+        ..Default::default()
+    }
+}
+
+fn parse_cpu_percentage(cpu_percentage: &str) -> Option<u32> {
+    let percentage = cpu_percentage.trim().trim_end_matches('%').parse::<f64>();
+    match percentage {
+        Ok(percentage) if percentage.is_finite() && percentage >= 0.0 => {
+            Some(percentage.round() as u32)
+        }
+        _ => {
+            log::debug!("Could not parse the CPU usage '{cpu_percentage}'.");
+            None
+        }
+    }
+}
+
+fn parse_memory_usage(memory_usage: &str) -> Option<u64> {
+    // Only the used memory is of interest and not the memory limit after the '/'.
+    let used_memory = memory_usage.split('/').next().unwrap_or_default().trim();
+    let unit_position = used_memory
+        .find(|c: char| c.is_ascii_alphabetic())
+        .unwrap_or(used_memory.len());
+    let (value, unit) = used_memory.split_at(unit_position);
+
+    let value = value.trim().parse::<f64>().ok();
+    let factor = MEMORY_UNITS
+        .iter()
+        .find(|(unit_name, _)| *unit_name == unit.trim())
+        .map(|(_, factor)| *factor);
+
+    match (value, factor) {
+        (Some(value), Some(factor)) if value.is_finite() && value >= 0.0 => {
+            Some((value * factor as f64).round() as u64)
+        }
+        _ => {
+            log::debug!("Could not parse the memory usage '{memory_usage}'.");
+            None
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_cpu_percentage, parse_memory_usage, to_workload_metrics};
+    use common::objects::WorkloadMetrics;
+
+    // [utest->swdd~agent-parses-container-stats~1]
+    #[test]
+    fn utest_parse_cpu_percentage() {
+        assert_eq!(parse_cpu_percentage("0.52%"), Some(1));
+        assert_eq!(parse_cpu_percentage("12.34%"), Some(12));
+        assert_eq!(parse_cpu_percentage("250.00%"), Some(250));
+        assert_eq!(parse_cpu_percentage("--"), None);
+        assert_eq!(parse_cpu_percentage("-1%"), None);
+    }
+
+    // [utest->swdd~agent-parses-container-stats~1]
+    #[test]
+    fn utest_parse_memory_usage() {
+        assert_eq!(parse_memory_usage("512B / 16GB"), Some(512));
+        assert_eq!(parse_memory_usage("1.5kB / 16GB"), Some(1500));
+        assert_eq!(parse_memory_usage("1.5MB / 16.4GB"), Some(1_500_000));
+        assert_eq!(parse_memory_usage("1.5MiB / 7.7GiB"), Some(1_572_864));
+        assert_eq!(parse_memory_usage("2GiB"), Some(2_147_483_648));
+        assert_eq!(parse_memory_usage("-- / --"), None);
+        assert_eq!(parse_memory_usage("1.5XB / 16GB"), None);
+    }
+
+    // [utest->swdd~agent-parses-container-stats~1]
+    #[test]
+    fn utest_to_workload_metrics_keeps_unparsable_values_empty() {
+        assert_eq!(
+            to_workload_metrics("3.7%", "--"),
+            WorkloadMetrics {
+                cpu_usage: Some(4),
+                memory_usage: None,
+                restart_count: 0,
+            }
+        );
+    }
+}
@@ -12,7 +12,7 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
-use common::objects::ExecutionState;
+use common::objects::{ExecutionState, WorkloadMetrics};
 #[cfg(test)]
 use mockall::automock;
 use serde::{Deserialize, Deserializer, Serialize};
@@ -20,20 +20,56 @@ use std::{
     collections::HashMap,
     ops::Deref,
     path::PathBuf,
-    sync::Arc,
+    sync::{Arc, OnceLock},
     time::{Duration, Instant},
 };
 use tokio::sync::Mutex;
 
 #[cfg_attr(test, mockall_double::double)]
 use crate::runtime_connectors::cli_command::CliCommand;
+use crate::runtime_connectors::container_stats;
+use crate::runtime_connectors::{RuntimeEventSource, StateChangeEvents, StateChangeSubscription};
 
 const NERDCTL_CMD: &str = "nerdctl";
+const DOCKER_CMD: &str = "docker";
 const API_PIPES_MOUNT_POINT: &str = "/run/ankaios/control_interface";
 const NERDCTL_PS_CACHE_MAX_AGE: Duration = Duration::from_millis(1000);
 
+// nerdctl provides a docker compatible command line interface, thus the same
+// implementation is used for both command line tools.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ContainerCliTool {
+    #[default]
+    Nerdctl,
+    Docker,
+}
+
+impl ContainerCliTool {
+    pub fn command(self) -> &'static str {
+        match self {
+            ContainerCliTool::Nerdctl => NERDCTL_CMD,
+            ContainerCliTool::Docker => DOCKER_CMD,
+        }
+    }
+
+    fn ps_cache(self) -> &'static TimedContainerCliPsResult {
+        match self {
+            ContainerCliTool::Nerdctl => &LAST_PS_RESULT,
+            ContainerCliTool::Docker => &LAST_DOCKER_PS_RESULT,
+        }
+    }
+
+    #[cfg_attr(test, allow(dead_code))]
+    fn state_change_events(self) -> &'static OnceLock<StateChangeEvents> {
+        match self {
+            ContainerCliTool::Nerdctl => &STATE_CHANGE_EVENTS,
+            ContainerCliTool::Docker => &DOCKER_STATE_CHANGE_EVENTS,
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq)]
-pub struct NerdctlRunConfig {
+pub struct ContainerRunConfig {
     pub general_options: Vec<String>,
     pub command_options: Vec<String>,
     pub image: String,
@@ -41,14 +77,14 @@ pub struct NerdctlRunConfig {
 }
 
 #[derive(Debug, PartialEq, Eq)]
-pub struct NerdctlStartConfig {
+pub struct ContainerStartConfig {
     pub general_options: Vec<String>,
     pub container_id: String,
 }
 
 // [impl->swdd~containerd-state-getter-maps-state~1]
-impl From<NerdctlContainerInfo> for ExecutionState {
-    fn from(value: NerdctlContainerInfo) -> Self {
+impl From<ContainerCliContainerInfo> for ExecutionState {
+    fn from(value: ContainerCliContainerInfo) -> Self {
         match value.state.status.to_lowercase().as_str() {
             "created" => ExecutionState::starting(value.state.status),
             "exited" if value.state.exit_code == 0 => ExecutionState::succeeded(),
@@ -70,66 +106,79 @@ impl From<NerdctlContainerInfo> for ExecutionState {
     }
 }
 
-struct NerdctlPsCache {
+struct ContainerCliPsCache {
     last_update: Instant,
-    cache: Arc<NerdctlPsResult>,
+    cache: Arc<ContainerCliPsResult>,
 }
 
-struct TimedNerdctlPsResult(Mutex<Option<NerdctlPsCache>>);
+struct TimedContainerCliPsResult {
+    cli: ContainerCliTool,
+    ps_result: Mutex<Option<ContainerCliPsCache>>,
+}
+
+impl TimedContainerCliPsResult {
+    const fn new(cli: ContainerCliTool) -> Self {
+        Self {
+            cli,
+            ps_result: Mutex::const_new(None),
+        }
+    }
 
-impl TimedNerdctlPsResult {
     async fn reset(&self) {
         *self.lock().await = None;
     }
 
     // [impl->swdd~containerd-container-state-cache-refresh~1]
-    async fn get(&self) -> Arc<NerdctlPsResult> {
+    async fn get(&self) -> Arc<ContainerCliPsResult> {
         let mut guard = self.lock().await;
 
         if let Some(value) = &mut *guard {
             if value.last_update.elapsed() > NERDCTL_PS_CACHE_MAX_AGE {
-                *value = Self::new_inner().await;
+                *value = self.new_inner().await;
             }
             value.cache.clone()
         } else {
-            let ps_result = Self::new_inner().await;
+            let ps_result = self.new_inner().await;
             let result = ps_result.cache.clone();
             *guard = Some(ps_result);
             result
         }
     }
 
-    async fn new_inner() -> NerdctlPsCache {
-        let mut res = NerdctlCli::list_states_internal().await;
+    async fn new_inner(&self) -> ContainerCliPsCache {
+        let mut res = ContainerCli::list_states_internal(self.cli).await;
 
         if res.is_err() {
             // This is a workaround for the known issue in nerdctl (nerdctl ps sometimes fails).
-            log::trace!("'nerdctl ps' has returned error - let's retry it.");
-            res = NerdctlCli::list_states_internal().await;
+            log::trace!(
+                "'{} ps' has returned error - let's retry it.",
+                self.cli.command()
+            );
+            res = ContainerCli::list_states_internal(self.cli).await;
         }
-        NerdctlPsCache {
+        ContainerCliPsCache {
             last_update: Instant::now(),
             cache: Arc::new(res.into()),
         }
     }
 }
 
-impl Deref for TimedNerdctlPsResult {
-    type Target = Mutex<Option<NerdctlPsCache>>;
+impl Deref for TimedContainerCliPsResult {
+    type Target = Mutex<Option<ContainerCliPsCache>>;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.ps_result
     }
 }
 
 // [impl->swdd~containerd-nerdctlcli-container-state-cache-all-containers~1]
 #[derive(Debug)]
-struct NerdctlPsResult {
+struct ContainerCliPsResult {
     container_states: Result<HashMap<String, ExecutionState>, String>,
 }
 
-impl From<Result<Vec<NerdctlContainerInfo>, String>> for NerdctlPsResult {
-    fn from(value: Result<Vec<NerdctlContainerInfo>, String>) -> Self {
+impl From<Result<Vec<ContainerCliContainerInfo>, String>> for ContainerCliPsResult {
+    fn from(value: Result<Vec<ContainerCliContainerInfo>, String>) -> Self {
         match value {
             Ok(container_infos) => {
                 let mut container_states = HashMap::new();
@@ -151,28 +200,88 @@ impl From<Result<Vec<NerdctlContainerInfo>, String>> for NerdctlPsResult {
     }
 }
 
-static LAST_PS_RESULT: TimedNerdctlPsResult = TimedNerdctlPsResult(Mutex::const_new(Option::None));
+static LAST_PS_RESULT: TimedContainerCliPsResult =
+    TimedContainerCliPsResult::new(ContainerCliTool::Nerdctl);
+static LAST_DOCKER_PS_RESULT: TimedContainerCliPsResult =
+    TimedContainerCliPsResult::new(ContainerCliTool::Docker);
+
+#[cfg_attr(test, allow(dead_code))]
+static STATE_CHANGE_EVENTS: OnceLock<StateChangeEvents> = OnceLock::new();
+#[cfg_attr(test, allow(dead_code))]
+static DOCKER_STATE_CHANGE_EVENTS: OnceLock<StateChangeEvents> = OnceLock::new();
+
+// docker names the field of the id in lower case.
+#[derive(Deserialize)]
+struct ContainerCliEvent {
+    #[serde(rename = "ID", alias = "id", default)]
+    id: String,
+    #[serde(rename = "Event", default)]
+    event: String,
+}
+
+#[derive(Deserialize)]
+struct ContainerdEventPayload {
+    container_id: Option<String>,
+    id: Option<String>,
+}
+
+struct ContainerCliEventSource(ContainerCliTool);
+
+#[async_trait::async_trait]
+impl RuntimeEventSource for ContainerCliEventSource {
+    // [impl->swdd~containerd-nerdctlcli-listens-for-container-events~1]
+    fn events_command(&self) -> (&'static str, Vec<&'static str>) {
+        (self.0.command(), vec!["events", "--format={{json .}}"])
+    }
+
+    // Older nerdctl versions only provide the container id inside the raw containerd event.
+    fn parse_workload_id(&self, event: &str) -> Option<String> {
+        let event = serde_json::from_str::<ContainerCliEvent>(event).ok()?;
+        if !event.id.is_empty() {
+            return Some(event.id);
+        }
+        let payload = serde_json::from_str::<ContainerdEventPayload>(&event.event).ok()?;
+        payload
+            .container_id
+            .or(payload.id)
+            .filter(|id| !id.is_empty())
+    }
 
-pub struct NerdctlCli {}
+    // [impl->swdd~containerd-nerdctlcli-container-events-reset-cache~1]
+    async fn on_state_change(&self) {
+        self.0.ps_cache().reset().await;
+    }
+}
+
+pub struct ContainerCli {}
 
 #[cfg_attr(test, automock)]
-impl NerdctlCli {
-    pub async fn reset_ps_cache() {
-        LAST_PS_RESULT.reset().await;
+impl ContainerCli {
+    pub async fn reset_ps_cache(cli: ContainerCliTool) {
+        cli.ps_cache().reset().await;
+    }
+
+    // [impl->swdd~containerd-nerdctlcli-listens-for-container-events~1]
+    #[cfg_attr(test, allow(dead_code))]
+    pub fn subscribe_state_changes(cli: ContainerCliTool) -> StateChangeSubscription {
+        cli.state_change_events()
+            .get_or_init(|| StateChangeEvents::start(ContainerCliEventSource(cli)))
+            .subscribe()
     }
 
     // [impl->swdd~containerd-nerdctlcli-lists-workloads-by-label~1]
     pub async fn list_container_ids_by_label(
+        cli: ContainerCliTool,
         key: &str,
         value: &str,
     ) -> Result<Vec<String>, String> {
         log::trace!("Listing workload ids for: {key}='{value}'",);
-        let output = Self::filter_container_ids_by_label(key, value).await?;
+        let output = Self::filter_container_ids_by_label(cli, key, value).await?;
 
         let mut container_ids = Vec::new();
         for line in output.lines().filter(|l| !l.trim().is_empty()) {
-            let container_id: NerdctlContainerId = serde_json::from_str(line)
-                .map_err(|err| format!("Could not parse nerdctl output: '{err}'"))?;
+            let container_id: ContainerCliContainerId = serde_json::from_str(line)
+                .map_err(|err| format!("Could not parse {} output: '{err}'", cli.command()))?;
             container_ids.push(container_id.id);
         }
 
@@ -181,16 +290,17 @@ impl NerdctlCli {
 
     // [impl->swdd~containerd-nerdctlcli-list-workload-names-by-label~1]
     pub async fn list_workload_names_by_label(
+        cli: ContainerCliTool,
         key: &str,
         value: &str,
     ) -> Result<Vec<String>, String> {
         log::trace!("Listing workload names for: '{key}'='{value}'",);
-        let output = Self::filter_container_ids_by_label(key, value).await?;
+        let output = Self::filter_container_ids_by_label(cli, key, value).await?;
 
         let mut names = Vec::new();
         for line in output.lines().filter(|l| !l.trim().is_empty()) {
-            let mut container_labels: NerdctlContainerLabels = serde_json::from_str(line)
-                .map_err(|err| format!("Could not parse nerdctl output: '{err}'"))?;
+            let mut container_labels: ContainerCliContainerLabels = serde_json::from_str(line)
+                .map_err(|err| format!("Could not parse {} output: '{err}'", cli.command()))?;
             if let Some(name) = container_labels.labels.remove("name") {
                 names.push(name);
             }
@@ -200,7 +310,8 @@ impl NerdctlCli {
     }
 
     pub async fn nerdctl_run(
-        mut run_config: NerdctlRunConfig,
+        cli: ContainerCliTool,
+        mut run_config: ContainerRunConfig,
         workload_name: &str,
         agent: &str,
         control_interface_path: Option<PathBuf>,
@@ -263,7 +374,7 @@ impl NerdctlCli {
         args.append(&mut run_config.command_args);
 
         log::debug!("The args are: '{args:?}'");
-        let id = CliCommand::new(NERDCTL_CMD)
+        let id = CliCommand::new(cli.command())
             .args(&args.iter().map(|x| &**x).collect::<Vec<&str>>())
             .exec()
             .await?
@@ -273,7 +384,8 @@ impl NerdctlCli {
     }
 
     pub async fn nerdctl_start(
-        start_config: NerdctlStartConfig,
+        cli: ContainerCliTool,
+        start_config: ContainerStartConfig,
         workload_name: &str,
     ) -> Result<String, String> {
         log::debug!(
@@ -288,7 +400,7 @@ impl NerdctlCli {
 
         args.push(start_config.container_id);
 
-        let id = CliCommand::new(NERDCTL_CMD)
+        let id = CliCommand::new(cli.command())
             .args(&args.iter().map(|x| &**x).collect::<Vec<&str>>())
             .exec()
             .await?
@@ -298,8 +410,11 @@ impl NerdctlCli {
     }
 
     // [impl->swdd~containerd-nerdctlcli-uses-container-state-cache~1]
-    pub async fn list_states_by_id(workload_id: &str) -> Result<Option<ExecutionState>, String> {
-        let ps_result = LAST_PS_RESULT.get().await;
+    pub async fn list_states_by_id(
+        cli: ContainerCliTool,
+        workload_id: &str,
+    ) -> Result<Option<ExecutionState>, String> {
+        let ps_result = cli.ps_cache().get().await;
         let all_containers_states = ps_result
             .as_ref()
             .container_states
@@ -310,8 +425,73 @@ impl NerdctlCli {
             .map(ToOwned::to_owned))
     }
 
-    async fn filter_container_ids_by_label(key: &str, value: &str) -> Result<String, String> {
-        CliCommand::new(NERDCTL_CMD)
+    // [impl->swdd~containerd-provides-workload-metrics~1]
+    pub async fn get_container_stats(
+        cli: ContainerCliTool,
+        workload_id: &str,
+    ) -> Result<WorkloadMetrics, String> {
+        let output = CliCommand::new(cli.command())
+            .args(&[
+                "stats",
+                "--no-stream",
+                "--no-trunc",
+                "--format=json",
+                workload_id,
+            ])
+            .exec()
+            .await?;
+
+        let stats: ContainerCliContainerStats = output
+            .lines()
+            .find(|line| !line.trim().is_empty())
+            .ok_or_else(|| format!("No stats found for container '{workload_id}'."))
+            .and_then(|line| {
+                serde_json::from_str(line).map_err(|err| {
+                    format!("Could not parse {} stats output: '{err}'", cli.command())
+                })
+            })?;
+
+        Ok(container_stats::to_workload_metrics(
+            &stats.cpu_perc,
+            &stats.mem_usage,
+        ))
+    }
+
+    // [impl->swdd~containerd-state-getter-executes-probe-commands~1]
+    pub async fn exec_command(
+        cli: ContainerCliTool,
+        workload_id: &str,
+        command: &[String],
+    ) -> Result<(), String> {
+        let mut args = vec!["exec", workload_id];
+        args.extend(command.iter().map(String::as_str));
+        CliCommand::new(cli.command()).args(&args).exec().await?;
+        Ok(())
+    }
+
+    // [impl->swdd~containerd-state-getter-provides-probe-host~1]
+    // A container in the host network has no address of its own.
+    pub async fn get_container_address(
+        cli: ContainerCliTool,
+        workload_id: &str,
+    ) -> Result<Option<String>, String> {
+        let output = CliCommand::new(cli.command())
+            .args(&[
+                "inspect",
+                "--format={{range .NetworkSettings.Networks}}{{.IPAddress}} {{end}}",
+                workload_id,
+            ])
+            .exec()
+            .await?;
+        Ok(output.split_whitespace().next().map(str::to_owned))
+    }
+
+    async fn filter_container_ids_by_label(
+        cli: ContainerCliTool,
+        key: &str,
+        value: &str,
+    ) -> Result<String, String> {
+        CliCommand::new(cli.command())
             .args(&[
                 "ps",
                 "--all",
@@ -324,21 +504,23 @@ impl NerdctlCli {
             .await
     }
 
-    async fn list_states_internal() -> Result<Vec<NerdctlContainerInfo>, String> {
-        let output = CliCommand::new(NERDCTL_CMD)
+    async fn list_states_internal(
+        cli: ContainerCliTool,
+    ) -> Result<Vec<ContainerCliContainerInfo>, String> {
+        let output = CliCommand::new(cli.command())
             .args(&["ps", "--all", "--no-trunc", "--format=json"])
             .exec()
             .await?;
 
         let mut container_ids = Vec::new();
         for line in output.lines().filter(|l| !l.trim().is_empty()) {
-            let container_id: NerdctlContainerId = serde_json::from_str(line)
-                .map_err(|err| format!("Could not parse nerdctl ps output: '{err}'"))?;
+            let container_id: ContainerCliContainerId = serde_json::from_str(line)
+                .map_err(|err| format!("Could not parse {} ps output: '{err}'", cli.command()))?;
             container_ids.push(container_id.id);
         }
 
         if container_ids.is_empty() {
-            log::warn!("No containers found.");
+            log::debug!("No containers found.");
             return Ok(Vec::default());
         }
 
@@ -346,45 +528,52 @@ impl NerdctlCli {
             .chain(container_ids.iter().map(String::as_str))
             .collect();
 
-        let output = CliCommand::new(NERDCTL_CMD)
+        let output = CliCommand::new(cli.command())
             .args(&inspect_args)
             .exec()
             .await?;
 
-        let container_info: Vec<NerdctlContainerInfo> =
-            serde_json::from_str(&output).map_err(|err| {
-                format!("Could not parse nerdctl inspect output: '{err}', output: {output}")
+        let container_info: Vec<ContainerCliContainerInfo> = serde_json::from_str(&output)
+            .map_err(|err| {
+                format!(
+                    "Could not parse {} inspect output: '{err}', output: {output}",
+                    cli.command()
+                )
             })?;
 
         Ok(container_info)
     }
 
     // [impl->swdd~containerd-nerdctlcli-removes-workloads-by-id~1]
-    pub async fn remove_workloads_by_id(workload_id: &str) -> Result<(), String> {
+    pub async fn remove_workloads_by_id(
+        cli: ContainerCliTool,
+        workload_id: &str,
+    ) -> Result<(), String> {
         /* nerdctl does not support '-d' and '--rm' flags specified together
         (https://github.com/containerd/nerdctl/issues/3698) and no 'ignore' flag. */
 
+        // docker reports "No such container", nerdctl "no such container".
         const CONTAINER_NOT_EXISTING: &str = "no such container";
 
-        match CliCommand::new(NERDCTL_CMD)
+        match CliCommand::new(cli.command())
             .args(&["stop", workload_id])
             .exec()
             .await
         {
             Ok(_) => {}
-            Err(err) if err.contains(CONTAINER_NOT_EXISTING) => {
+            Err(err) if err.to_lowercase().contains(CONTAINER_NOT_EXISTING) => {
                 log::debug!("Tried to stop container with id '{workload_id}' that does not exist.");
             }
             Err(err) => return Err(err),
         }
 
-        match CliCommand::new(NERDCTL_CMD)
+        match CliCommand::new(cli.command())
             .args(&["rm", workload_id])
             .exec()
             .await
         {
             Ok(_) => Ok(()),
-            Err(err) if err.contains(CONTAINER_NOT_EXISTING) => {
+            Err(err) if err.to_lowercase().contains(CONTAINER_NOT_EXISTING) => {
                 log::debug!(
                     "Tried to remove container with id '{workload_id}' that does not exist."
                 );
@@ -396,31 +585,39 @@ impl NerdctlCli {
 }
 
 #[derive(Deserialize, Serialize, Clone, Debug)]
-struct NerdctlContainerInfo {
+struct ContainerCliContainerInfo {
     #[serde(rename = "State")]
-    state: NerdctlContainerState,
+    state: ContainerCliContainerState,
     #[serde(flatten)]
-    id: NerdctlContainerId,
+    id: ContainerCliContainerId,
+}
+
+#[derive(Debug, Deserialize)]
+struct ContainerCliContainerStats {
+    #[serde(rename = "CPUPerc")]
+    cpu_perc: String,
+    #[serde(rename = "MemUsage")]
+    mem_usage: String,
 }
 
 #[derive(Debug, Deserialize, Default, Clone, Serialize)]
-struct NerdctlContainerId {
+struct ContainerCliContainerId {
     #[serde(rename = "ID", alias = "Id")]
     id: String,
 }
 
 #[derive(Debug, Deserialize, Default, Clone, Serialize)]
-struct NerdctlContainerLabels {
+struct ContainerCliContainerLabels {
     #[serde(rename = "Labels", deserialize_with = "parse_labels")]
     labels: HashMap<String, String>,
 }
 
 #[derive(Debug, Deserialize, Default, Clone, Serialize)]
-struct NerdctlContainerState {
+struct ContainerCliContainerState {
     #[serde(rename = "Status")]
     status: String,
     #[serde(rename = "ExitCode")]
-    exit_code: u8,
+    exit_code: i32,
 }
 
 fn parse_labels<'a, D>(deserializer: D) -> Result<HashMap<String, String>, D::Error>
@@ -448,10 +645,11 @@ where
 // [utest->swdd~containerd-uses-nerdctl-cli~1]
 #[cfg(test)]
 mod tests {
-    use super::{NERDCTL_CMD, NerdctlCli, NerdctlPsCache};
+    use super::{ContainerCli, ContainerCliPsCache, ContainerCliTool, DOCKER_CMD, NERDCTL_CMD};
+    use crate::runtime_connectors::RuntimeEventSource;
 
     use crate::test_helper::MOCKALL_CONTEXT_SYNC;
-    use common::objects::ExecutionState;
+    use common::objects::{ExecutionState, WorkloadMetrics};
     use serde::Serialize;
     use std::collections::HashMap;
     use std::sync::Arc;
@@ -478,17 +676,22 @@ mod tests {
                     "--format=json",
                 ])
                 .exec_returns(Ok([
-                    TestNerdctlContainerId {
+                    TestContainerCliContainerId {
                         id: "result1".into(),
                     },
-                    TestNerdctlContainerId {
+                    TestContainerCliContainerId {
                         id: "result2".into(),
                     },
                 ]
                 .to_json())),
         );
 
-        let res = NerdctlCli::list_container_ids_by_label("name", "test_agent").await;
+        let res = ContainerCli::list_container_ids_by_label(
+            ContainerCliTool::Nerdctl,
+            "name",
+            "test_agent",
+        )
+        .await;
         assert_eq!(res, Ok(vec!["result1".to_owned(), "result2".to_owned()]));
     }
 
@@ -512,7 +715,12 @@ mod tests {
                 .exec_returns(Err(SAMPLE_ERROR_MESSAGE.into())),
         );
 
-        let res = NerdctlCli::list_container_ids_by_label("name", "test_agent").await;
+        let res = ContainerCli::list_container_ids_by_label(
+            ContainerCliTool::Nerdctl,
+            "name",
+            "test_agent",
+        )
+        .await;
         assert!(matches!(res, Err(msg) if msg == SAMPLE_ERROR_MESSAGE));
     }
 
@@ -536,7 +744,12 @@ mod tests {
                 .exec_returns(Ok("non-json response from nerdctl".into())),
         );
 
-        let res = NerdctlCli::list_container_ids_by_label("name", "test_agent").await;
+        let res = ContainerCli::list_container_ids_by_label(
+            ContainerCliTool::Nerdctl,
+            "name",
+            "test_agent",
+        )
+        .await;
         assert!(matches!(res, Err(msg) if msg.contains("Could not parse nerdctl output")));
     }
 
@@ -557,13 +770,18 @@ mod tests {
                     "label=name=test_agent",
                     "--format=json",
                 ])
-                .exec_returns(Ok(TestNerdctlContainerLabels {
+                .exec_returns(Ok(TestContainerCliContainerLabels {
                     labels: HashMap::from([("name".to_owned(), "workload_name".to_owned())]),
                 }
                 .to_string())),
         );
 
-        let res = NerdctlCli::list_workload_names_by_label("name", "test_agent").await;
+        let res = ContainerCli::list_workload_names_by_label(
+            ContainerCliTool::Nerdctl,
+            "name",
+            "test_agent",
+        )
+        .await;
         assert_eq!(res, Ok(vec!["workload_name".into()]));
     }
 
@@ -584,10 +802,15 @@ mod tests {
                     "label=name=test_agent",
                     "--format=json",
                 ])
-                .exec_returns(Ok(TestNerdctlContainerLabels::default().to_string())),
+                .exec_returns(Ok(TestContainerCliContainerLabels::default().to_string())),
         );
 
-        let res = NerdctlCli::list_workload_names_by_label("name", "test_agent").await;
+        let res = ContainerCli::list_workload_names_by_label(
+            ContainerCliTool::Nerdctl,
+            "name",
+            "test_agent",
+        )
+        .await;
         assert_eq!(res, Ok(Vec::default()));
     }
 
@@ -611,7 +834,12 @@ mod tests {
                 .exec_returns(Err("simulated error".to_string())),
         );
 
-        let res = NerdctlCli::list_workload_names_by_label("name", "test_agent").await;
+        let res = ContainerCli::list_workload_names_by_label(
+            ContainerCliTool::Nerdctl,
+            "name",
+            "test_agent",
+        )
+        .await;
         assert_eq!(res, Err("simulated error".to_string()));
     }
 
@@ -635,7 +863,12 @@ mod tests {
                 .exec_returns(Ok("non-json response from nerdctl".to_string())),
         );
 
-        let res = NerdctlCli::list_workload_names_by_label("name", "test_agent").await;
+        let res = ContainerCli::list_workload_names_by_label(
+            ContainerCliTool::Nerdctl,
+            "name",
+            "test_agent",
+        )
+        .await;
         assert!(matches!(res, Err(msg) if msg.starts_with("Could not parse nerdctl output") ));
     }
 
@@ -663,13 +896,14 @@ mod tests {
                 .exec_returns(Ok(WORKLOAD_ID.to_owned())),
         );
 
-        let run_config = super::NerdctlRunConfig {
+        let run_config = super::ContainerRunConfig {
             general_options: Vec::new(),
             command_options: Vec::new(),
             image: "alpine:latest".into(),
             command_args: Vec::new(),
         };
-        let res = NerdctlCli::nerdctl_run(
+        let res = ContainerCli::nerdctl_run(
+            ContainerCliTool::Nerdctl,
             run_config,
             "test_workload_name",
             "test_agent",
@@ -700,13 +934,14 @@ mod tests {
                 .exec_returns(Err(SAMPLE_ERROR_MESSAGE.into())),
         );
 
-        let run_config = super::NerdctlRunConfig {
+        let run_config = super::ContainerRunConfig {
             general_options: Vec::new(),
             command_options: Vec::new(),
             image: "alpine:latest".into(),
             command_args: Vec::new(),
         };
-        let res = NerdctlCli::nerdctl_run(
+        let res = ContainerCli::nerdctl_run(
+            ContainerCliTool::Nerdctl,
             run_config,
             "test_workload_name",
             "test_agent",
@@ -750,13 +985,14 @@ mod tests {
                 .exec_returns(Ok(WORKLOAD_ID.to_owned())),
         );
 
-        let run_config = super::NerdctlRunConfig {
+        let run_config = super::ContainerRunConfig {
             general_options: vec!["--remote".into()],
             command_options: vec!["--network=host".into(), "--name".into(), "myCont".into()],
             image: "alpine:latest".into(),
             command_args: vec!["sh".into()],
         };
-        let res = NerdctlCli::nerdctl_run(
+        let res = ContainerCli::nerdctl_run(
+            ContainerCliTool::Nerdctl,
             run_config,
             "test_workload_name",
             "test_agent",
@@ -779,11 +1015,16 @@ mod tests {
                 .exec_returns(Ok(WORKLOAD_ID.to_owned())),
         );
 
-        let start_config = super::NerdctlStartConfig {
+        let start_config = super::ContainerStartConfig {
             general_options: vec!["--remote".into()],
             container_id: WORKLOAD_ID.into(),
         };
-        let res = NerdctlCli::nerdctl_start(start_config, "test_workload_name").await;
+        let res = ContainerCli::nerdctl_start(
+            ContainerCliTool::Nerdctl,
+            start_config,
+            "test_workload_name",
+        )
+        .await;
         assert_eq!(res, Ok(WORKLOAD_ID.to_owned()));
     }
 
@@ -802,11 +1043,16 @@ mod tests {
                 .exec_returns(Err(SAMPLE_ERROR_MESSAGE.into())),
         );
 
-        let start_config = super::NerdctlStartConfig {
+        let start_config = super::ContainerStartConfig {
             general_options: Vec::default(),
             container_id: ID.into(),
         };
-        let res = NerdctlCli::nerdctl_start(start_config, "test_workload_name").await;
+        let res = ContainerCli::nerdctl_start(
+            ContainerCliTool::Nerdctl,
+            start_config,
+            "test_workload_name",
+        )
+        .await;
         assert_eq!(res, Err(SAMPLE_ERROR_MESSAGE.to_string()));
     }
 
@@ -817,9 +1063,9 @@ mod tests {
     async fn utest_list_states_by_id_created() {
         let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
         super::CliCommand::reset();
-        NerdctlCli::reset_ps_cache().await;
+        ContainerCli::reset_ps_cache(ContainerCliTool::Nerdctl).await;
 
-        let container_id = TestNerdctlContainerId {
+        let container_id = TestContainerCliContainerId {
             id: WORKLOAD_ID.to_owned(),
         };
 
@@ -834,9 +1080,9 @@ mod tests {
             NERDCTL_CMD,
             super::CliCommand::default()
                 .expect_args(&["inspect", WORKLOAD_ID])
-                .exec_returns(Ok([TestNerdctlContainerInfo {
+                .exec_returns(Ok([TestContainerCliContainerInfo {
                     id: container_id,
-                    state: TestNerdctlContainerState {
+                    state: TestContainerCliContainerState {
                         status: "created".to_owned(),
                         ..Default::default()
                     },
@@ -844,7 +1090,7 @@ mod tests {
                 .to_json())),
         );
 
-        let res = NerdctlCli::list_states_by_id(WORKLOAD_ID).await;
+        let res = ContainerCli::list_states_by_id(ContainerCliTool::Nerdctl, WORKLOAD_ID).await;
         assert_eq!(res, Ok(Some(ExecutionState::starting("created"))));
     }
 
@@ -857,7 +1103,7 @@ mod tests {
         super::CliCommand::reset();
         *super::LAST_PS_RESULT.lock().await = None;
 
-        let container_id = TestNerdctlContainerId {
+        let container_id = TestContainerCliContainerId {
             id: WORKLOAD_ID.to_owned(),
         };
 
@@ -872,9 +1118,9 @@ mod tests {
             NERDCTL_CMD,
             super::CliCommand::default()
                 .expect_args(&["inspect", WORKLOAD_ID])
-                .exec_returns(Ok([TestNerdctlContainerInfo {
+                .exec_returns(Ok([TestContainerCliContainerInfo {
                     id: container_id,
-                    state: TestNerdctlContainerState {
+                    state: TestContainerCliContainerState {
                         status: "exited".to_owned(),
                         exit_code: 0,
                     },
@@ -882,7 +1128,7 @@ mod tests {
                 .to_json())),
         );
 
-        let res = NerdctlCli::list_states_by_id(WORKLOAD_ID).await;
+        let res = ContainerCli::list_states_by_id(ContainerCliTool::Nerdctl, WORKLOAD_ID).await;
         assert_eq!(res, Ok(Some(ExecutionState::succeeded())));
     }
 
@@ -894,7 +1140,7 @@ mod tests {
         super::CliCommand::reset();
         *super::LAST_PS_RESULT.lock().await = None;
 
-        let container_id = TestNerdctlContainerId {
+        let container_id = TestContainerCliContainerId {
             id: WORKLOAD_ID.to_owned(),
         };
 
@@ -909,9 +1155,9 @@ mod tests {
             NERDCTL_CMD,
             super::CliCommand::default()
                 .expect_args(&["inspect", WORKLOAD_ID])
-                .exec_returns(Ok([TestNerdctlContainerInfo {
+                .exec_returns(Ok([TestContainerCliContainerInfo {
                     id: container_id,
-                    state: TestNerdctlContainerState {
+                    state: TestContainerCliContainerState {
                         status: "exited".to_owned(),
                         exit_code: 1,
                     },
@@ -919,7 +1165,7 @@ mod tests {
                 .to_json())),
         );
 
-        let res = NerdctlCli::list_states_by_id(WORKLOAD_ID).await;
+        let res = ContainerCli::list_states_by_id(ContainerCliTool::Nerdctl, WORKLOAD_ID).await;
         assert_eq!(res, Ok(Some(ExecutionState::failed("Exit code: '1'"))));
     }
 
@@ -932,7 +1178,7 @@ mod tests {
         super::CliCommand::reset();
         *super::LAST_PS_RESULT.lock().await = None;
 
-        let container_id = TestNerdctlContainerId {
+        let container_id = TestContainerCliContainerId {
             id: WORKLOAD_ID.to_owned(),
         };
 
@@ -947,9 +1193,9 @@ mod tests {
             NERDCTL_CMD,
             super::CliCommand::default()
                 .expect_args(&["inspect", WORKLOAD_ID])
-                .exec_returns(Ok([TestNerdctlContainerInfo {
+                .exec_returns(Ok([TestContainerCliContainerInfo {
                     id: container_id,
-                    state: TestNerdctlContainerState {
+                    state: TestContainerCliContainerState {
                         status: "running".to_owned(),
                         ..Default::default()
                     },
@@ -957,7 +1203,7 @@ mod tests {
                 .to_json())),
         );
 
-        let res = NerdctlCli::list_states_by_id(WORKLOAD_ID).await;
+        let res = ContainerCli::list_states_by_id(ContainerCliTool::Nerdctl, WORKLOAD_ID).await;
         assert_eq!(res, Ok(Some(ExecutionState::running())));
     }
 
@@ -968,9 +1214,9 @@ mod tests {
     async fn utest_list_states_by_id_removing() {
         let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
         super::CliCommand::reset();
-        NerdctlCli::reset_ps_cache().await;
+        ContainerCli::reset_ps_cache(ContainerCliTool::Nerdctl).await;
 
-        let container_id = TestNerdctlContainerId {
+        let container_id = TestContainerCliContainerId {
             id: WORKLOAD_ID.to_owned(),
         };
         super::CliCommand::new_expect(
@@ -984,9 +1230,9 @@ mod tests {
             NERDCTL_CMD,
             super::CliCommand::default()
                 .expect_args(&["inspect", WORKLOAD_ID])
-                .exec_returns(Ok([TestNerdctlContainerInfo {
+                .exec_returns(Ok([TestContainerCliContainerInfo {
                     id: container_id,
-                    state: TestNerdctlContainerState {
+                    state: TestContainerCliContainerState {
                         status: "removing".to_owned(),
                         ..Default::default()
                     },
@@ -994,7 +1240,7 @@ mod tests {
                 .to_json())),
         );
 
-        let res = NerdctlCli::list_states_by_id(WORKLOAD_ID).await;
+        let res = ContainerCli::list_states_by_id(ContainerCliTool::Nerdctl, WORKLOAD_ID).await;
         assert_eq!(res, Ok(Some(ExecutionState::stopping("removing"))));
     }
 
@@ -1005,9 +1251,9 @@ mod tests {
     async fn utest_list_states_by_id_paused() {
         let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
         super::CliCommand::reset();
-        NerdctlCli::reset_ps_cache().await;
+        ContainerCli::reset_ps_cache(ContainerCliTool::Nerdctl).await;
 
-        let container_id = TestNerdctlContainerId {
+        let container_id = TestContainerCliContainerId {
             id: WORKLOAD_ID.to_owned(),
         };
         super::CliCommand::new_expect(
@@ -1017,7 +1263,7 @@ mod tests {
                 .exec_returns(Ok(container_id.clone().to_json())),
         );
 
-        let container_state = TestNerdctlContainerState {
+        let container_state = TestContainerCliContainerState {
             status: "paused".to_owned(),
             ..Default::default()
         };
@@ -1027,14 +1273,14 @@ mod tests {
             NERDCTL_CMD,
             super::CliCommand::default()
                 .expect_args(&["inspect", WORKLOAD_ID])
-                .exec_returns(Ok([TestNerdctlContainerInfo {
+                .exec_returns(Ok([TestContainerCliContainerInfo {
                     id: container_id,
                     state: container_state,
                 }]
                 .to_json())),
         );
 
-        let res = NerdctlCli::list_states_by_id(WORKLOAD_ID).await;
+        let res = ContainerCli::list_states_by_id(ContainerCliTool::Nerdctl, WORKLOAD_ID).await;
         assert_eq!(
             res,
             Ok(Some(ExecutionState::unknown(expected_container_status)))
@@ -1048,9 +1294,9 @@ mod tests {
     async fn utest_list_states_by_id_restarting() {
         let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
         super::CliCommand::reset();
-        NerdctlCli::reset_ps_cache().await;
+        ContainerCli::reset_ps_cache(ContainerCliTool::Nerdctl).await;
 
-        let container_id = TestNerdctlContainerId {
+        let container_id = TestContainerCliContainerId {
             id: WORKLOAD_ID.to_owned(),
         };
         super::CliCommand::new_expect(
@@ -1064,9 +1310,9 @@ mod tests {
             NERDCTL_CMD,
             super::CliCommand::default()
                 .expect_args(&["inspect", WORKLOAD_ID])
-                .exec_returns(Ok([TestNerdctlContainerInfo {
+                .exec_returns(Ok([TestContainerCliContainerInfo {
                     id: container_id,
-                    state: TestNerdctlContainerState {
+                    state: TestContainerCliContainerState {
                         status: "restarting".to_owned(),
                         ..Default::default()
                     },
@@ -1074,7 +1320,7 @@ mod tests {
                 .to_json())),
         );
 
-        let res = NerdctlCli::list_states_by_id(WORKLOAD_ID).await;
+        let res = ContainerCli::list_states_by_id(ContainerCliTool::Nerdctl, WORKLOAD_ID).await;
         assert_eq!(res, Ok(Some(ExecutionState::starting("restarting"))));
     }
 
@@ -1085,9 +1331,9 @@ mod tests {
     async fn utest_list_states_by_id_dead() {
         let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
         super::CliCommand::reset();
-        NerdctlCli::reset_ps_cache().await;
+        ContainerCli::reset_ps_cache(ContainerCliTool::Nerdctl).await;
 
-        let container_id = TestNerdctlContainerId {
+        let container_id = TestContainerCliContainerId {
             id: WORKLOAD_ID.to_owned(),
         };
         super::CliCommand::new_expect(
@@ -1101,9 +1347,9 @@ mod tests {
             NERDCTL_CMD,
             super::CliCommand::default()
                 .expect_args(&["inspect", WORKLOAD_ID])
-                .exec_returns(Ok([TestNerdctlContainerInfo {
+                .exec_returns(Ok([TestContainerCliContainerInfo {
                     id: container_id,
-                    state: TestNerdctlContainerState {
+                    state: TestContainerCliContainerState {
                         status: "dead".to_owned(),
                         exit_code: 1,
                     },
@@ -1111,7 +1357,7 @@ mod tests {
                 .to_json())),
         );
 
-        let res = NerdctlCli::list_states_by_id(WORKLOAD_ID).await;
+        let res = ContainerCli::list_states_by_id(ContainerCliTool::Nerdctl, WORKLOAD_ID).await;
         assert_eq!(res, Ok(Some(ExecutionState::failed("Exit code: '1'"))));
     }
 
@@ -1124,7 +1370,7 @@ mod tests {
         super::CliCommand::reset();
         *super::LAST_PS_RESULT.lock().await = None;
 
-        let container_id = TestNerdctlContainerId {
+        let container_id = TestContainerCliContainerId {
             id: WORKLOAD_ID.to_owned(),
         };
 
@@ -1139,9 +1385,9 @@ mod tests {
             NERDCTL_CMD,
             super::CliCommand::default()
                 .expect_args(&["inspect", WORKLOAD_ID])
-                .exec_returns(Ok([TestNerdctlContainerInfo {
+                .exec_returns(Ok([TestContainerCliContainerInfo {
                     id: container_id,
-                    state: TestNerdctlContainerState {
+                    state: TestContainerCliContainerState {
                         status: "unknown".to_owned(),
                         ..Default::default()
                     },
@@ -1149,7 +1395,7 @@ mod tests {
                 .to_json())),
         );
 
-        let res = NerdctlCli::list_states_by_id(WORKLOAD_ID).await;
+        let res = ContainerCli::list_states_by_id(ContainerCliTool::Nerdctl, WORKLOAD_ID).await;
         assert_eq!(res, Ok(Some(ExecutionState::unknown("unknown"))));
     }
 
@@ -1163,11 +1409,11 @@ mod tests {
         let mock_cli_command = super::CliCommand::default()
             .expect_args(&["ps", "--all", "--no-trunc", "--format=json"])
             .exec_returns(Err("simulated error".to_string()));
-        // NerdctlCli retries the command when the command fails -> we have to mock the command twice.
+        // ContainerCli retries the command when the command fails -> we have to mock the command twice.
         super::CliCommand::new_expect(NERDCTL_CMD, mock_cli_command.clone());
         super::CliCommand::new_expect(NERDCTL_CMD, mock_cli_command);
 
-        let res = NerdctlCli::list_states_by_id(WORKLOAD_ID).await;
+        let res = ContainerCli::list_states_by_id(ContainerCliTool::Nerdctl, WORKLOAD_ID).await;
         assert_eq!(res, Err("simulated error".to_string()));
     }
 
@@ -1186,7 +1432,7 @@ mod tests {
                 .exec_returns(Err("simulated error".to_string())),
         );
 
-        let container_id = TestNerdctlContainerId {
+        let container_id = TestContainerCliContainerId {
             id: WORKLOAD_ID.to_owned(),
         };
 
@@ -1201,9 +1447,9 @@ mod tests {
             NERDCTL_CMD,
             super::CliCommand::default()
                 .expect_args(&["inspect", WORKLOAD_ID])
-                .exec_returns(Ok([TestNerdctlContainerInfo {
+                .exec_returns(Ok([TestContainerCliContainerInfo {
                     id: container_id,
-                    state: TestNerdctlContainerState {
+                    state: TestContainerCliContainerState {
                         status: "running".to_owned(),
                         ..Default::default()
                     },
@@ -1211,7 +1457,7 @@ mod tests {
                 .to_json())),
         );
 
-        let res = NerdctlCli::list_states_by_id(WORKLOAD_ID).await;
+        let res = ContainerCli::list_states_by_id(ContainerCliTool::Nerdctl, WORKLOAD_ID).await;
         assert_eq!(res, Ok(Some(ExecutionState::running())));
     }
 
@@ -1225,16 +1471,16 @@ mod tests {
 
         let old_time_stamp = time::Instant::now() - Duration::from_secs(10);
 
-        *super::LAST_PS_RESULT.lock().await = Some(NerdctlPsCache {
+        *super::LAST_PS_RESULT.lock().await = Some(ContainerCliPsCache {
             last_update: old_time_stamp,
-            cache: Arc::new(super::NerdctlPsResult {
+            cache: Arc::new(super::ContainerCliPsResult {
                 container_states: Ok([(WORKLOAD_ID.into(), ExecutionState::failed("Some error"))]
                     .into_iter()
                     .collect()),
             }),
         });
 
-        let container_id = TestNerdctlContainerId {
+        let container_id = TestContainerCliContainerId {
             id: WORKLOAD_ID.to_owned(),
         };
 
@@ -1249,9 +1495,9 @@ mod tests {
             NERDCTL_CMD,
             super::CliCommand::default()
                 .expect_args(&["inspect", WORKLOAD_ID])
-                .exec_returns(Ok([TestNerdctlContainerInfo {
+                .exec_returns(Ok([TestContainerCliContainerInfo {
                     id: container_id,
-                    state: TestNerdctlContainerState {
+                    state: TestContainerCliContainerState {
                         status: "running".to_owned(),
                         ..Default::default()
                     },
@@ -1259,7 +1505,7 @@ mod tests {
                 .to_json())),
         );
 
-        let res = NerdctlCli::list_states_by_id(WORKLOAD_ID).await;
+        let res = ContainerCli::list_states_by_id(ContainerCliTool::Nerdctl, WORKLOAD_ID).await;
         assert_eq!(res, Ok(Some(ExecutionState::running())));
     }
 
@@ -1273,11 +1519,11 @@ mod tests {
         let mock_cli_command = super::CliCommand::default()
             .expect_args(&["ps", "--all", "--no-trunc", "--format=json"])
             .exec_returns(Ok("non-json response from nerdctl".to_string()));
-        // NerdctlCli retries the command when the command fails -> we have to mock the command twice.
+        // ContainerCli retries the command when the command fails -> we have to mock the command twice.
         super::CliCommand::new_expect(NERDCTL_CMD, mock_cli_command.clone());
         super::CliCommand::new_expect(NERDCTL_CMD, mock_cli_command);
 
-        let res = NerdctlCli::list_states_by_id(WORKLOAD_ID).await;
+        let res = ContainerCli::list_states_by_id(ContainerCliTool::Nerdctl, WORKLOAD_ID).await;
         assert!(matches!(res, Err(msg) if msg.starts_with("Could not parse nerdctl ps output") ));
     }
 
@@ -1296,7 +1542,7 @@ mod tests {
                 .exec_returns(Ok("non-json response from nerdctl".to_string())),
         );
 
-        let container_id = TestNerdctlContainerId {
+        let container_id = TestContainerCliContainerId {
             id: WORKLOAD_ID.into(),
         };
 
@@ -1311,9 +1557,9 @@ mod tests {
             NERDCTL_CMD,
             super::CliCommand::default()
                 .expect_args(&["inspect", WORKLOAD_ID])
-                .exec_returns(Ok([TestNerdctlContainerInfo {
+                .exec_returns(Ok([TestContainerCliContainerInfo {
                     id: container_id,
-                    state: TestNerdctlContainerState {
+                    state: TestContainerCliContainerState {
                         status: "running".into(),
                         ..Default::default()
                     },
@@ -1321,10 +1567,74 @@ mod tests {
                 .to_json())),
         );
 
-        let res = NerdctlCli::list_states_by_id(WORKLOAD_ID).await;
+        let res = ContainerCli::list_states_by_id(ContainerCliTool::Nerdctl, WORKLOAD_ID).await;
         assert_eq!(res, Ok(Some(ExecutionState::running())));
     }
 
+    // [utest->swdd~containerd-state-getter-executes-probe-commands~1]
+    #[tokio::test]
+    async fn utest_exec_command() {
+        let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
+        super::CliCommand::reset();
+
+        super::CliCommand::new_expect(
+            DOCKER_CMD,
+            super::CliCommand::default()
+                .expect_args(&["exec", WORKLOAD_ID, "cat", "/tmp/healthy"])
+                .exec_returns(Ok("".into())),
+        );
+        super::CliCommand::new_expect(
+            DOCKER_CMD,
+            super::CliCommand::default()
+                .expect_args(&["exec", WORKLOAD_ID, "cat", "/tmp/healthy"])
+                .exec_returns(Err("simulated error".to_string())),
+        );
+
+        let command = ["cat".to_string(), "/tmp/healthy".to_string()];
+        assert_eq!(
+            ContainerCli::exec_command(ContainerCliTool::Docker, WORKLOAD_ID, &command).await,
+            Ok(())
+        );
+        assert_eq!(
+            ContainerCli::exec_command(ContainerCliTool::Docker, WORKLOAD_ID, &command).await,
+            Err("simulated error".to_string())
+        );
+    }
+
+    // [utest->swdd~containerd-state-getter-provides-probe-host~1]
+    #[tokio::test]
+    async fn utest_get_container_address() {
+        let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
+        super::CliCommand::reset();
+
+        let inspect_args = [
+            "inspect",
+            "--format={{range .NetworkSettings.Networks}}{{.IPAddress}} {{end}}",
+            WORKLOAD_ID,
+        ];
+        super::CliCommand::new_expect(
+            NERDCTL_CMD,
+            super::CliCommand::default()
+                .expect_args(&inspect_args)
+                .exec_returns(Ok("10.4.0.7 \n".into())),
+        );
+        super::CliCommand::new_expect(
+            NERDCTL_CMD,
+            super::CliCommand::default()
+                .expect_args(&inspect_args)
+                .exec_returns(Ok(" \n".into())),
+        );
+
+        assert_eq!(
+            ContainerCli::get_container_address(ContainerCliTool::Nerdctl, WORKLOAD_ID).await,
+            Ok(Some("10.4.0.7".to_string()))
+        );
+        assert_eq!(
+            ContainerCli::get_container_address(ContainerCliTool::Nerdctl, WORKLOAD_ID).await,
+            Ok(None)
+        );
+    }
+
     // [utest->swdd~containerd-nerdctlcli-removes-workloads-by-id~1]
     #[tokio::test]
     async fn utest_remove_workloads_by_id_stop_failed() {
@@ -1339,7 +1649,7 @@ mod tests {
         );
 
         assert_eq!(
-            NerdctlCli::remove_workloads_by_id(WORKLOAD_ID).await,
+            ContainerCli::remove_workloads_by_id(ContainerCliTool::Nerdctl, WORKLOAD_ID).await,
             Err("simulated error".to_string())
         );
     }
@@ -1365,7 +1675,7 @@ mod tests {
         );
 
         assert!(
-            NerdctlCli::remove_workloads_by_id(WORKLOAD_ID)
+            ContainerCli::remove_workloads_by_id(ContainerCliTool::Nerdctl, WORKLOAD_ID)
                 .await
                 .is_ok(),
             "Expected to ignore the failed stop of non-existing container."
@@ -1393,7 +1703,7 @@ mod tests {
         );
 
         assert_eq!(
-            NerdctlCli::remove_workloads_by_id(WORKLOAD_ID).await,
+            ContainerCli::remove_workloads_by_id(ContainerCliTool::Nerdctl, WORKLOAD_ID).await,
             Err("simulated error".to_string())
         );
     }
@@ -1419,7 +1729,7 @@ mod tests {
         );
 
         assert!(
-            NerdctlCli::remove_workloads_by_id(WORKLOAD_ID)
+            ContainerCli::remove_workloads_by_id(ContainerCliTool::Nerdctl, WORKLOAD_ID)
                 .await
                 .is_ok(),
             "Expected to ignore the failed remove of non-existing container."
@@ -1446,17 +1756,141 @@ mod tests {
                 .exec_returns(Ok("".to_owned())),
         );
 
-        let res = NerdctlCli::remove_workloads_by_id(WORKLOAD_ID).await;
+        let res =
+            ContainerCli::remove_workloads_by_id(ContainerCliTool::Nerdctl, WORKLOAD_ID).await;
         assert_eq!(res, Ok(()));
     }
 
+    // [utest->swdd~containerd-provides-workload-metrics~1]
+    #[tokio::test]
+    async fn utest_get_container_stats_success() {
+        let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
+        super::CliCommand::reset();
+
+        super::CliCommand::new_expect(
+            NERDCTL_CMD,
+            super::CliCommand::default()
+                .expect_args(&[
+                    "stats",
+                    "--no-stream",
+                    "--no-trunc",
+                    "--format=json",
+                    WORKLOAD_ID,
+                ])
+                .exec_returns(Ok(
+                    r#"{"ID":"test_id","CPUPerc":"0.52%","MemUsage":"1.5MiB / 7.7GiB"}"#.to_owned(),
+                )),
+        );
+
+        let res = ContainerCli::get_container_stats(ContainerCliTool::Nerdctl, WORKLOAD_ID).await;
+        assert_eq!(
+            res,
+            Ok(WorkloadMetrics {
+                cpu_usage: Some(1),
+                memory_usage: Some(1_572_864),
+                restart_count: 0,
+            })
+        );
+    }
+
+    // [utest->swdd~containerd-provides-workload-metrics~1]
+    #[tokio::test]
+    async fn utest_get_container_stats_failed() {
+        let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
+        super::CliCommand::reset();
+
+        super::CliCommand::new_expect(
+            NERDCTL_CMD,
+            super::CliCommand::default()
+                .expect_args(&[
+                    "stats",
+                    "--no-stream",
+                    "--no-trunc",
+                    "--format=json",
+                    WORKLOAD_ID,
+                ])
+                .exec_returns(Err("simulated error".to_owned())),
+        );
+
+        assert_eq!(
+            ContainerCli::get_container_stats(ContainerCliTool::Nerdctl, WORKLOAD_ID).await,
+            Err("simulated error".to_owned())
+        );
+    }
+
+    // [utest->swdd~docker-uses-docker-cli~1]
+    #[tokio::test]
+    async fn utest_list_states_by_id_uses_docker_cli() {
+        let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
+        super::CliCommand::reset();
+        ContainerCli::reset_ps_cache(ContainerCliTool::Docker).await;
+
+        let container_id = TestContainerCliContainerId {
+            id: WORKLOAD_ID.into(),
+        };
+
+        super::CliCommand::new_expect(
+            DOCKER_CMD,
+            super::CliCommand::default()
+                .expect_args(&["ps", "--all", "--no-trunc", "--format=json"])
+                .exec_returns(Ok(container_id.clone().to_json())),
+        );
+
+        super::CliCommand::new_expect(
+            DOCKER_CMD,
+            super::CliCommand::default()
+                .expect_args(&["inspect", WORKLOAD_ID])
+                .exec_returns(Ok([TestContainerCliContainerInfo {
+                    id: container_id,
+                    state: TestContainerCliContainerState {
+                        status: "exited".into(),
+                        exit_code: 137,
+                    },
+                }]
+                .to_json())),
+        );
+
+        let res = ContainerCli::list_states_by_id(ContainerCliTool::Docker, WORKLOAD_ID).await;
+        assert_eq!(res, Ok(Some(ExecutionState::failed("Exit code: '137'"))));
+    }
+
+    // [utest->swdd~docker-uses-docker-cli~1]
+    // [utest->swdd~containerd-nerdctlcli-removes-workloads-by-id~1]
+    #[tokio::test]
+    async fn utest_remove_workloads_by_id_ignores_non_existing_docker_container() {
+        let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
+        super::CliCommand::reset();
+
+        super::CliCommand::new_expect(
+            DOCKER_CMD,
+            super::CliCommand::default()
+                .expect_args(&["stop", WORKLOAD_ID])
+                .exec_returns(Err(format!(
+                    "Error response from daemon: No such container: {WORKLOAD_ID}"
+                ))),
+        );
+        super::CliCommand::new_expect(
+            DOCKER_CMD,
+            super::CliCommand::default()
+                .expect_args(&["rm", WORKLOAD_ID])
+                .exec_returns(Err(format!(
+                    "Error response from daemon: No such container: {WORKLOAD_ID}"
+                ))),
+        );
+
+        assert_eq!(
+            ContainerCli::remove_workloads_by_id(ContainerCliTool::Docker, WORKLOAD_ID).await,
+            Ok(())
+        );
+    }
+
     #[derive(Debug, Default, Clone, Serialize)]
-    struct TestNerdctlContainerLabels {
+    struct TestContainerCliContainerLabels {
         #[serde(rename = "Labels")]
         labels: HashMap<String, String>,
     }
 
-    impl std::fmt::Display for TestNerdctlContainerLabels {
+    impl std::fmt::Display for TestContainerCliContainerLabels {
         fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
             let labels = self.labels.iter().fold(String::new(), |acc, (key, value)| {
                 format!("{acc},{key}={value}")
@@ -1469,26 +1903,26 @@ mod tests {
     }
 
     #[derive(Debug, Default, Clone, Serialize)]
-    struct TestNerdctlContainerState {
+    struct TestContainerCliContainerState {
         #[serde(rename = "Status")]
         status: String,
         #[serde(rename = "ExitCode")]
-        exit_code: u8,
+        exit_code: i32,
     }
 
     #[derive(Debug, Default, Clone, Serialize)]
-    struct TestNerdctlContainerId {
+    struct TestContainerCliContainerId {
         #[serde(rename = "ID", alias = "Id")]
         id: String,
     }
 
-    impl ToJson for TestNerdctlContainerId {
+    impl ToJson for TestContainerCliContainerId {
         fn to_json(&self) -> String {
             serde_json::to_string(self).unwrap()
         }
     }
 
-    impl ToJson for [TestNerdctlContainerId] {
+    impl ToJson for [TestContainerCliContainerId] {
         fn to_json(&self) -> String {
             self.iter()
                 .map(|id| id.to_json())
@@ -1497,15 +1931,60 @@ mod tests {
         }
     }
 
+    // [utest->swdd~containerd-nerdctlcli-listens-for-container-events~1]
+    #[test]
+    fn utest_nerdctl_event_source_parses_container_id() {
+        let event_source = super::ContainerCliEventSource(ContainerCliTool::Nerdctl);
+
+        assert_eq!(
+            event_source.parse_workload_id(r#"{"ID":"test_id","Topic":"/tasks/exit","Event":""}"#),
+            Some(WORKLOAD_ID.to_string())
+        );
+        assert_eq!(
+            event_source.parse_workload_id(
+                r#"{"ID":"","Topic":"/tasks/exit","Event":"{\"container_id\":\"test_id\",\"pid\":42}"}"#
+            ),
+            Some(WORKLOAD_ID.to_string())
+        );
+        assert_eq!(
+            event_source.parse_workload_id(
+                r#"{"ID":"","Topic":"/containers/delete","Event":"{\"id\":\"test_id\"}"}"#
+            ),
+            Some(WORKLOAD_ID.to_string())
+        );
+        assert_eq!(
+            event_source.parse_workload_id(
+                r#"{"ID":"","Topic":"/images/create","Event":"{\"name\":\"alpine\"}"}"#
+            ),
+            None
+        );
+        assert_eq!(event_source.parse_workload_id("no json"), None);
+    }
+
+    // [utest->swdd~docker-uses-docker-cli~1]
+    // [utest->swdd~containerd-nerdctlcli-listens-for-container-events~1]
+    #[test]
+    fn utest_docker_event_source_parses_container_id() {
+        let event_source = super::ContainerCliEventSource(ContainerCliTool::Docker);
+
+        assert_eq!(event_source.events_command().0, DOCKER_CMD);
+        assert_eq!(
+            event_source.parse_workload_id(
+                r#"{"status":"die","id":"test_id","Type":"container","Action":"die","Actor":{"ID":"test_id"}}"#
+            ),
+            Some(WORKLOAD_ID.to_string())
+        );
+    }
+
     #[derive(Serialize, Clone, Debug, Default)]
-    struct TestNerdctlContainerInfo {
+    struct TestContainerCliContainerInfo {
         #[serde(rename = "State")]
-        state: TestNerdctlContainerState,
+        state: TestContainerCliContainerState,
         #[serde(flatten)]
-        id: TestNerdctlContainerId,
+        id: TestContainerCliContainerId,
     }
 
-    impl ToJson for [TestNerdctlContainerInfo] {
+    impl ToJson for [TestContainerCliContainerInfo] {
         fn to_json(&self) -> String {
             serde_json::to_string(self).unwrap()
         }
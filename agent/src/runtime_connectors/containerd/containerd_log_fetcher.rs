@@ -23,8 +23,7 @@ use crate::runtime_connectors::runtime_connector::LogRequestOptions;
 
 use super::super::log_fetcher::{GetOutputStreams, StreamTrait};
 use super::ContainerdWorkloadId;
-
-const NERDCTL_CMD: &str = "nerdctl";
+use super::container_cli::ContainerCliTool;
 
 // [impl->swdd~containerd-log-fetching-collects-logs~1]
 
@@ -38,9 +37,15 @@ pub struct ContainerdLogFetcher {
 }
 
 impl ContainerdLogFetcher {
-    pub fn new(workload_id: &ContainerdWorkloadId, options: &LogRequestOptions) -> Self {
-        let mut args = Vec::with_capacity(9);
+    pub fn new(
+        cli: ContainerCliTool,
+        workload_id: &ContainerdWorkloadId,
+        options: &LogRequestOptions,
+    ) -> Self {
+        let mut args = Vec::with_capacity(10);
         args.push("logs");
+        // [impl->swdd~agent-log-fetching-provides-timestamp-and-stream~1]
+        args.push("--timestamps");
         if options.follow {
             args.push("-f")
         }
@@ -59,7 +64,7 @@ impl ContainerdLogFetcher {
             args.push(_tail.as_str());
         }
         args.push(&workload_id.id);
-        let cmd = Command::new(NERDCTL_CMD)
+        let cmd = Command::new(cli.command())
             .args(args)
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
@@ -116,6 +121,10 @@ impl GetOutputStreams for ContainerdLogFetcher {
         #[cfg(test)]
         return (self.stdout.take(), self.stderr.take());
     }
+
+    fn prefixes_timestamps(&self) -> bool {
+        true
+    }
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -132,7 +141,7 @@ mod tests {
     use std::sync::Mutex;
     use tokio::io::Empty;
 
-    use super::{ContainerdLogFetcher, NERDCTL_CMD};
+    use super::{ContainerCliTool, ContainerdLogFetcher};
     use crate::runtime_connectors::{
         LogRequestOptions, containerd::ContainerdWorkloadId, log_fetcher::GetOutputStreams,
     };
@@ -213,6 +222,7 @@ mod tests {
         let _guard = TEST_LOCK.lock().unwrap();
         *CAN_SPAWN.lock().unwrap() = true;
         let mut log_fetcher = ContainerdLogFetcher::new(
+            ContainerCliTool::Nerdctl,
             &ContainerdWorkloadId {
                 id: WORKLOAD_ID.into(),
             },
@@ -233,7 +243,7 @@ mod tests {
                 stdout_option: Some(_),
                 stderr_option: Some(_)
 
-            }) if cmd == NERDCTL_CMD && *args == vec!["logs".to_string(), WORKLOAD_ID.to_string()]
+            }) if cmd == "nerdctl" && *args == vec!["logs".to_string(), "--timestamps".to_string(), WORKLOAD_ID.to_string()]
         ));
         let (child_stdout, child_stderr) = log_fetcher.get_output_streams();
         assert!(child_stdout.is_none());
@@ -245,6 +255,7 @@ mod tests {
         let _guard = TEST_LOCK.lock().unwrap();
         *CAN_SPAWN.lock().unwrap() = true;
         let mut log_fetcher = ContainerdLogFetcher::new(
+            ContainerCliTool::Nerdctl,
             &ContainerdWorkloadId {
                 id: WORKLOAD_ID.into(),
             },
@@ -264,7 +275,7 @@ mod tests {
                 args,
                 stdout_option: Some(_),
                 stderr_option: Some(_),
-            }) if cmd == NERDCTL_CMD && *args == vec!["logs".to_string(), "-f".to_string(), "--since".to_string(), "since".to_string(), "--until".to_string(), "until".to_string(), "--tail".to_string(), "10".to_string(), WORKLOAD_ID.to_string(), ]
+            }) if cmd == "nerdctl" && *args == vec!["logs".to_string(), "--timestamps".to_string(), "-f".to_string(), "--since".to_string(), "since".to_string(), "--until".to_string(), "until".to_string(), "--tail".to_string(), "10".to_string(), WORKLOAD_ID.to_string(), ]
         ));
         let (child_stdout, child_stderr) = log_fetcher.get_output_streams();
         assert!(child_stdout.is_none());
@@ -276,6 +287,7 @@ mod tests {
         let _guard = TEST_LOCK.lock().unwrap();
         *CAN_SPAWN.lock().unwrap() = false;
         let log_fetcher = ContainerdLogFetcher::new(
+            ContainerCliTool::Nerdctl,
             &ContainerdWorkloadId {
                 id: WORKLOAD_ID.into(),
             },
@@ -298,6 +310,7 @@ mod tests {
 
         *CAN_KILL.lock().unwrap() = true;
         let log_fetcher = ContainerdLogFetcher::new(
+            ContainerCliTool::Nerdctl,
             &ContainerdWorkloadId {
                 id: WORKLOAD_ID.into(),
             },
@@ -321,6 +334,7 @@ mod tests {
         *CAN_SPAWN.lock().unwrap() = true;
         *CAN_KILL.lock().unwrap() = false;
         let log_fetcher = ContainerdLogFetcher::new(
+            ContainerCliTool::Nerdctl,
             &ContainerdWorkloadId {
                 id: WORKLOAD_ID.into(),
             },
@@ -17,16 +17,19 @@ use std::{collections::HashMap, fmt::Display, path::PathBuf, str::FromStr};
 use async_trait::async_trait;
 
 use common::{
-    objects::{AgentName, ExecutionState, WorkloadInstanceName, WorkloadSpec},
+    objects::{AgentName, ExecutionState, WorkloadInstanceName, WorkloadMetrics, WorkloadSpec},
     std_extensions::UnreachableOption,
 };
 
 use crate::{
-    generic_polling_state_checker::GenericPollingStateChecker,
+    generic_event_state_checker::GenericEventStateChecker,
     runtime_connectors::{
-        ReusableWorkloadState, RuntimeConnector, RuntimeError, RuntimeStateGetter, StateChecker,
-        containerd::nerdctl_cli::NerdctlStartConfig, generic_log_fetcher::GenericLogFetcher,
-        log_fetcher::LogFetcher, runtime_connector::LogRequestOptions,
+        ReusableWorkloadState, RuntimeConnector, RuntimeError, RuntimeStateGetter,
+        StateChangeSubscription, StateChecker,
+        containerd::container_cli::{ContainerCliTool, ContainerStartConfig},
+        generic_log_fetcher::GenericLogFetcher,
+        log_fetcher::LogFetcher,
+        runtime_connector::LogRequestOptions,
     },
     workload_state::WorkloadStateSender,
 };
@@ -36,17 +39,24 @@ use mockall_double::double;
 
 // [impl->swdd~containerd-uses-nerdctl-cli~1]
 #[cfg_attr(test, double)]
-use crate::runtime_connectors::containerd::nerdctl_cli::NerdctlCli;
+use crate::runtime_connectors::containerd::container_cli::ContainerCli;
 
 use super::containerd_runtime_config::ContainerdRuntimeConfig;
 
 pub const CONTAINERD_RUNTIME_NAME: &str = "containerd";
+pub const DOCKER_RUNTIME_NAME: &str = "docker";
 
-#[derive(Debug, Clone)]
-pub struct ContainerdRuntime {}
+// The docker runtime uses the same implementation as the containerd runtime,
+// but with the docker instead of the nerdctl command line tool.
+#[derive(Debug, Clone, Default)]
+pub struct ContainerdRuntime {
+    cli: ContainerCliTool,
+}
 
-#[derive(Debug, Clone)]
-pub struct ContainerdStateGetter {}
+#[derive(Debug, Clone, Default)]
+pub struct ContainerdStateGetter {
+    cli: ContainerCliTool,
+}
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct ContainerdWorkloadId {
@@ -75,23 +85,24 @@ impl RuntimeStateGetter<ContainerdWorkloadId> for ContainerdStateGetter {
         // [impl->swdd~containerd-state-getter-returns-unknown-state~1]
         // [impl->swdd~containerd-state-getter-uses-nerdctlcli~1]
         // [impl->swdd~containerd-state-getter-returns-lost-state~1]
-        let exec_state = match NerdctlCli::list_states_by_id(workload_id.id.as_str()).await {
-            Ok(state) => {
-                if let Some(state) = state {
-                    state
-                } else {
-                    ExecutionState::lost()
+        let exec_state =
+            match ContainerCli::list_states_by_id(self.cli, workload_id.id.as_str()).await {
+                Ok(state) => {
+                    if let Some(state) = state {
+                        state
+                    } else {
+                        ExecutionState::lost()
+                    }
                 }
-            }
-            Err(err) => {
-                log::warn!(
-                    "Could not get state of workload '{}': '{}'. Returning unknown.",
-                    workload_id.id,
-                    err
-                );
-                ExecutionState::unknown("Error getting state from Nerdctl.")
-            }
-        };
+                Err(err) => {
+                    log::warn!(
+                        "Could not get state of workload '{}': '{}'. Returning unknown.",
+                        workload_id.id,
+                        err
+                    );
+                    ExecutionState::unknown("Error getting state from Nerdctl.")
+                }
+            };
 
         log::trace!(
             "Returning the state '{}' for the workload '{}'",
@@ -100,9 +111,36 @@ impl RuntimeStateGetter<ContainerdWorkloadId> for ContainerdStateGetter {
         );
         exec_state
     }
+
+    // [impl->swdd~containerd-state-getter-executes-probe-commands~1]
+    async fn exec_command(
+        &self,
+        workload_id: &ContainerdWorkloadId,
+        command: &[String],
+    ) -> Result<(), String> {
+        ContainerCli::exec_command(self.cli, workload_id.id.as_str(), command).await
+    }
+
+    // [impl->swdd~containerd-state-getter-provides-probe-host~1]
+    async fn get_probe_host(&self, workload_id: &ContainerdWorkloadId) -> Result<String, String> {
+        Ok(
+            ContainerCli::get_container_address(self.cli, workload_id.id.as_str())
+                .await?
+                .unwrap_or_else(|| "127.0.0.1".to_string()),
+        )
+    }
+
+    // [impl->swdd~containerd-state-getter-provides-state-change-events~1]
+    fn subscribe_state_changes(&self) -> Option<StateChangeSubscription> {
+        Some(ContainerCli::subscribe_state_changes(self.cli))
+    }
 }
 
 impl ContainerdRuntime {
+    pub fn new(cli: ContainerCliTool) -> Self {
+        Self { cli }
+    }
+
     async fn sample_workload_states(
         &self,
         workload_instance_names: &Vec<WorkloadInstanceName>,
@@ -110,7 +148,7 @@ impl ContainerdRuntime {
         let mut workload_states = Vec::<ReusableWorkloadState>::default();
         for instance_name in workload_instance_names {
             let workload_id = &self.get_workload_id(instance_name).await?.id;
-            match NerdctlCli::list_states_by_id(workload_id).await {
+            match ContainerCli::list_states_by_id(self.cli, workload_id).await {
                 Ok(Some(execution_state)) => workload_states.push(ReusableWorkloadState::new(
                     instance_name.clone(),
                     execution_state,
@@ -130,10 +168,14 @@ impl ContainerdRuntime {
 
 #[async_trait]
 // [impl->swdd~containerd-implements-runtime-connector~1]
-impl RuntimeConnector<ContainerdWorkloadId, GenericPollingStateChecker> for ContainerdRuntime {
+impl RuntimeConnector<ContainerdWorkloadId, GenericEventStateChecker> for ContainerdRuntime {
     // [impl->swdd~containerd-name-returns-containerd~1]
+    // [impl->swdd~docker-name-returns-docker~1]
     fn name(&self) -> String {
-        CONTAINERD_RUNTIME_NAME.to_string()
+        match self.cli {
+            ContainerCliTool::Nerdctl => CONTAINERD_RUNTIME_NAME.to_string(),
+            ContainerCliTool::Docker => DOCKER_RUNTIME_NAME.to_string(),
+        }
     }
 
     async fn get_reusable_workloads(
@@ -141,7 +183,7 @@ impl RuntimeConnector<ContainerdWorkloadId, GenericPollingStateChecker> for Cont
         agent_name: &AgentName,
     ) -> Result<Vec<ReusableWorkloadState>, RuntimeError> {
         // [impl->swdd~containerd-list-of-existing-workloads-uses-labels~1]
-        let res = NerdctlCli::list_workload_names_by_label("agent", agent_name.get())
+        let res = ContainerCli::list_workload_names_by_label(self.cli, "agent", agent_name.get())
             .await
             .map_err(|err| RuntimeError::List(err.to_string()))?;
 
@@ -164,21 +206,26 @@ impl RuntimeConnector<ContainerdWorkloadId, GenericPollingStateChecker> for Cont
         control_interface_path: Option<PathBuf>,
         update_state_tx: WorkloadStateSender,
         workload_file_path_mappings: HashMap<PathBuf, PathBuf>,
-    ) -> Result<(ContainerdWorkloadId, GenericPollingStateChecker), RuntimeError> {
+    ) -> Result<(ContainerdWorkloadId, GenericEventStateChecker), RuntimeError> {
         let workload_cfg =
             ContainerdRuntimeConfig::try_from(&workload_spec).map_err(RuntimeError::Unsupported)?;
 
         let cli_result = match reusable_workload_id {
             Some(workload_id) => {
-                let start_config = NerdctlStartConfig {
+                let start_config = ContainerStartConfig {
                     general_options: workload_cfg.general_options,
                     container_id: workload_id.id,
                 };
-                NerdctlCli::nerdctl_start(start_config, &workload_spec.instance_name.to_string())
-                    .await
+                ContainerCli::nerdctl_start(
+                    self.cli,
+                    start_config,
+                    &workload_spec.instance_name.to_string(),
+                )
+                .await
             }
             None => {
-                NerdctlCli::nerdctl_run(
+                ContainerCli::nerdctl_run(
+                    self.cli,
                     workload_cfg.into(),
                     &workload_spec.instance_name.to_string(),
                     workload_spec.instance_name.agent_name(),
@@ -208,8 +255,11 @@ impl RuntimeConnector<ContainerdWorkloadId, GenericPollingStateChecker> for Cont
             Err(err) => {
                 // [impl->swdd~containerd-create-workload-deletes-failed-container~1]
                 log::debug!("Creating/starting container failed, cleaning up. Error: '{err}'");
-                match NerdctlCli::remove_workloads_by_id(&workload_spec.instance_name.to_string())
-                    .await
+                match ContainerCli::remove_workloads_by_id(
+                    self.cli,
+                    &workload_spec.instance_name.to_string(),
+                )
+                .await
                 {
                     Ok(()) => log::debug!("The broken container has been deleted successfully"),
                     Err(e) => {
@@ -228,10 +278,13 @@ impl RuntimeConnector<ContainerdWorkloadId, GenericPollingStateChecker> for Cont
         instance_name: &WorkloadInstanceName,
     ) -> Result<ContainerdWorkloadId, RuntimeError> {
         // [impl->swdd~containerd-get-workload-id-uses-label~1]
-        let res =
-            NerdctlCli::list_container_ids_by_label("name", instance_name.to_string().as_str())
-                .await
-                .map_err(|err| RuntimeError::List(err.to_string()))?;
+        let res = ContainerCli::list_container_ids_by_label(
+            self.cli,
+            "name",
+            instance_name.to_string().as_str(),
+        )
+        .await
+        .map_err(|err| RuntimeError::List(err.to_string()))?;
 
         const LENGTH_FOR_VALID_ID: usize = 1;
 
@@ -253,20 +306,20 @@ impl RuntimeConnector<ContainerdWorkloadId, GenericPollingStateChecker> for Cont
         workload_id: &ContainerdWorkloadId,
         workload_spec: WorkloadSpec,
         update_state_tx: WorkloadStateSender,
-    ) -> Result<GenericPollingStateChecker, RuntimeError> {
+    ) -> Result<GenericEventStateChecker, RuntimeError> {
         // [impl->swdd~containerd-state-getter-reset-cache~1]
-        NerdctlCli::reset_ps_cache().await;
+        ContainerCli::reset_ps_cache(self.cli).await;
 
         log::debug!(
             "Starting the checker for the workload '{}' with internal id '{}'",
             workload_spec.instance_name,
             workload_id.id
         );
-        let checker = GenericPollingStateChecker::start_checker(
+        let checker = GenericEventStateChecker::start_checker(
             &workload_spec,
             workload_id.clone(),
             update_state_tx,
-            ContainerdStateGetter {},
+            ContainerdStateGetter { cli: self.cli },
         );
         Ok(checker)
     }
@@ -276,8 +329,11 @@ impl RuntimeConnector<ContainerdWorkloadId, GenericPollingStateChecker> for Cont
         workload_id: ContainerdWorkloadId,
         options: &LogRequestOptions,
     ) -> Result<Box<dyn LogFetcher + Send>, RuntimeError> {
-        let nerdctl_log_fetcher =
-            super::containerd_log_fetcher::ContainerdLogFetcher::new(&workload_id, options);
+        let nerdctl_log_fetcher = super::containerd_log_fetcher::ContainerdLogFetcher::new(
+            self.cli,
+            &workload_id,
+            options,
+        );
         let log_fetcher = GenericLogFetcher::new(nerdctl_log_fetcher);
         Ok(Box::new(log_fetcher))
     }
@@ -288,10 +344,20 @@ impl RuntimeConnector<ContainerdWorkloadId, GenericPollingStateChecker> for Cont
         workload_id: &ContainerdWorkloadId,
     ) -> Result<(), RuntimeError> {
         log::debug!("Deleting workload with id '{}'", workload_id.id);
-        NerdctlCli::remove_workloads_by_id(&workload_id.id)
+        ContainerCli::remove_workloads_by_id(self.cli, &workload_id.id)
             .await
             .map_err(|err| RuntimeError::Delete(err.to_string()))
     }
+
+    // [impl->swdd~containerd-provides-workload-metrics~1]
+    async fn get_workload_metrics(
+        &self,
+        workload_id: &ContainerdWorkloadId,
+    ) -> Result<WorkloadMetrics, RuntimeError> {
+        ContainerCli::get_container_stats(self.cli, &workload_id.id)
+            .await
+            .map_err(RuntimeError::CollectMetrics)
+    }
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -309,15 +375,20 @@ mod tests {
     use std::str::FromStr;
 
     use common::objects::{
-        AgentName, ExecutionState, WorkloadInstanceName, generate_test_workload_spec_with_param,
+        AgentName, ExecutionState, WorkloadInstanceName, WorkloadMetrics,
+        generate_test_workload_spec_with_param,
     };
     use mockall::Sequence;
 
+    use super::ContainerCli;
     use super::ContainerdRuntime;
-    use super::NerdctlCli;
-    use super::{CONTAINERD_RUNTIME_NAME, ContainerdStateGetter, ContainerdWorkloadId};
+    use super::{
+        CONTAINERD_RUNTIME_NAME, ContainerCliTool, ContainerdStateGetter, ContainerdWorkloadId,
+    };
     use crate::runtime_connectors::LogRequestOptions;
-    use crate::runtime_connectors::{RuntimeConnector, RuntimeError, RuntimeStateGetter};
+    use crate::runtime_connectors::{
+        RuntimeConnector, RuntimeError, RuntimeStateGetter, StateChangeSubscription,
+    };
     use crate::test_helper::MOCKALL_CONTEXT_SYNC;
 
     const BUFFER_SIZE: usize = 20;
@@ -328,17 +399,47 @@ mod tests {
     // [utest->swdd~containerd-name-returns-containerd~1]
     #[test]
     fn utest_name_containerd() {
-        let containerd_runtime = ContainerdRuntime {};
+        let containerd_runtime = ContainerdRuntime::default();
         assert_eq!(containerd_runtime.name(), "containerd".to_string());
     }
 
+    // [utest->swdd~docker-name-returns-docker~1]
+    #[test]
+    fn utest_name_docker() {
+        let docker_runtime = ContainerdRuntime::new(ContainerCliTool::Docker);
+        assert_eq!(docker_runtime.name(), "docker".to_string());
+    }
+
+    // [utest->swdd~docker-uses-docker-cli~1]
+    #[tokio::test]
+    async fn utest_docker_runtime_uses_docker_cli() {
+        let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
+
+        let context = ContainerCli::remove_workloads_by_id_context();
+        context
+            .expect()
+            .with(
+                mockall::predicate::eq(ContainerCliTool::Docker),
+                mockall::predicate::eq("test_id"),
+            )
+            .return_const(Ok(()));
+
+        let docker_runtime = ContainerdRuntime::new(ContainerCliTool::Docker);
+        let res = docker_runtime
+            .delete_workload(&ContainerdWorkloadId {
+                id: "test_id".into(),
+            })
+            .await;
+        assert_eq!(res, Ok(()));
+    }
+
     // [utest->swdd~containerd-list-of-existing-workloads-uses-labels~1]
     #[tokio::test]
     async fn utest_get_reusable_workloads_success() {
         let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
 
         let list_workload_names_by_label_context =
-            NerdctlCli::list_workload_names_by_label_context();
+            ContainerCli::list_workload_names_by_label_context();
         list_workload_names_by_label_context
             .expect()
             .return_const(Ok(vec![
@@ -347,22 +448,23 @@ mod tests {
                 "container2.hash.dummy_agent".to_string(),
             ]));
 
-        let list_container_ids_by_label_context = NerdctlCli::list_container_ids_by_label_context();
+        let list_container_ids_by_label_context =
+            ContainerCli::list_container_ids_by_label_context();
         list_container_ids_by_label_context
             .expect()
             .return_const(Ok(vec!["container1.hash.dummy_agent".to_string()]));
 
-        let list_states_by_id_context = NerdctlCli::list_states_by_id_context();
+        let list_states_by_id_context = ContainerCli::list_states_by_id_context();
         list_states_by_id_context
             .expect()
             .return_const(Ok(Some(ExecutionState::initial())));
 
-        let list_states_by_id_context = NerdctlCli::list_states_by_id_context();
+        let list_states_by_id_context = ContainerCli::list_states_by_id_context();
         list_states_by_id_context
             .expect()
             .return_const(Ok(Some(ExecutionState::initial())));
 
-        let containerd_runtime = ContainerdRuntime {};
+        let containerd_runtime = ContainerdRuntime::default();
         let agent_name = AgentName::from("dummy_agent");
         let res = containerd_runtime
             .get_reusable_workloads(&agent_name)
@@ -384,10 +486,10 @@ mod tests {
     async fn utest_get_reusable_running_workloads_empty_list() {
         let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
 
-        let context = NerdctlCli::list_workload_names_by_label_context();
+        let context = ContainerCli::list_workload_names_by_label_context();
         context.expect().return_const(Ok(Vec::new()));
 
-        let containerd_runtime = ContainerdRuntime {};
+        let containerd_runtime = ContainerdRuntime::default();
         let agent_name = AgentName::from("different_agent");
         let res = containerd_runtime
             .get_reusable_workloads(&agent_name)
@@ -401,12 +503,12 @@ mod tests {
     async fn utest_get_reusable_running_workloads_failed() {
         let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
 
-        let context = NerdctlCli::list_workload_names_by_label_context();
+        let context = ContainerCli::list_workload_names_by_label_context();
         context
             .expect()
             .return_const(Err("Simulated error".to_string()));
 
-        let containerd_runtime = ContainerdRuntime {};
+        let containerd_runtime = ContainerdRuntime::default();
         let agent_name = AgentName::from("dummy_agent");
 
         assert_eq!(
@@ -422,10 +524,10 @@ mod tests {
     async fn utest_create_workload_success() {
         let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
 
-        let run_context = NerdctlCli::nerdctl_run_context();
+        let run_context = ContainerCli::nerdctl_run_context();
         run_context.expect().return_const(Ok("test_id".into()));
 
-        let resest_cache_context = NerdctlCli::reset_ps_cache_context();
+        let resest_cache_context = ContainerCli::reset_ps_cache_context();
         resest_cache_context.expect().return_const(());
 
         let workload_spec = generate_test_workload_spec_with_param(
@@ -435,7 +537,7 @@ mod tests {
         );
         let (state_change_tx, _state_change_rx) = tokio::sync::mpsc::channel(BUFFER_SIZE);
 
-        let containerd_runtime = ContainerdRuntime {};
+        let containerd_runtime = ContainerdRuntime::default();
         let res = containerd_runtime
             .create_workload(
                 workload_spec,
@@ -459,12 +561,12 @@ mod tests {
 
         let reusable_workload_id = "test_id";
 
-        let start_context = NerdctlCli::nerdctl_start_context();
+        let start_context = ContainerCli::nerdctl_start_context();
         start_context
             .expect()
-            .returning(|start_config, _| Ok(start_config.container_id));
+            .returning(|_, start_config, _| Ok(start_config.container_id));
 
-        let resest_cache_context = NerdctlCli::reset_ps_cache_context();
+        let resest_cache_context = ContainerCli::reset_ps_cache_context();
         resest_cache_context.expect().return_const(());
 
         let workload_spec = generate_test_workload_spec_with_param(
@@ -474,7 +576,7 @@ mod tests {
         );
         let (state_change_tx, _state_change_rx) = tokio::sync::mpsc::channel(BUFFER_SIZE);
 
-        let containerd_runtime = ContainerdRuntime {};
+        let containerd_runtime = ContainerdRuntime::default();
         let res = containerd_runtime
             .create_workload(
                 workload_spec,
@@ -496,19 +598,26 @@ mod tests {
     async fn utest_state_getter_resets_cache() {
         let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
 
-        let run_context = NerdctlCli::nerdctl_run_context();
+        let run_context = ContainerCli::nerdctl_run_context();
         run_context.expect().return_const(Ok("test_id".into()));
 
+        let subscribe_context = ContainerCli::subscribe_state_changes_context();
+        subscribe_context.expect().returning(|_| {
+            let (_events_tx, events_rx) = tokio::sync::broadcast::channel(1);
+            let (_connected_tx, connected_rx) = tokio::sync::watch::channel(false);
+            StateChangeSubscription::new(events_rx, connected_rx)
+        });
+
         let mut seq = Sequence::new();
 
-        let resest_cache_context = NerdctlCli::reset_ps_cache_context();
+        let resest_cache_context = ContainerCli::reset_ps_cache_context();
         resest_cache_context
             .expect()
             .once()
             .return_const(())
             .in_sequence(&mut seq);
 
-        let list_states_context = NerdctlCli::list_states_by_id_context();
+        let list_states_context = ContainerCli::list_states_by_id_context();
         list_states_context
             .expect()
             .once()
@@ -522,7 +631,7 @@ mod tests {
         );
         let (state_change_tx, mut state_change_rx) = tokio::sync::mpsc::channel(BUFFER_SIZE);
 
-        let containerd_runtime = ContainerdRuntime {};
+        let containerd_runtime = ContainerdRuntime::default();
         let res = containerd_runtime
             .create_workload(
                 workload_spec,
@@ -543,12 +652,12 @@ mod tests {
     async fn utest_state_getter_uses_nerdctl_cli() {
         let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
 
-        let list_states_context = NerdctlCli::list_states_by_id_context();
+        let list_states_context = ContainerCli::list_states_by_id_context();
         list_states_context
             .expect()
             .return_const(Ok(Some(ExecutionState::running())));
 
-        let state_getter = ContainerdStateGetter {};
+        let state_getter = ContainerdStateGetter::default();
         let execution_state = state_getter
             .get_state(&ContainerdWorkloadId {
                 id: "test_workload_id".into(),
@@ -563,13 +672,13 @@ mod tests {
     async fn utest_create_workload_run_failed_cleanup_success() {
         let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
 
-        let run_context = NerdctlCli::nerdctl_run_context();
+        let run_context = ContainerCli::nerdctl_run_context();
         run_context
             .expect()
             .return_const(Err("nerdctl run failed".into()));
 
         // Workload creation fails, but deleting the broken container succeeded
-        let delete_context = NerdctlCli::remove_workloads_by_id_context();
+        let delete_context = ContainerCli::remove_workloads_by_id_context();
         delete_context.expect().return_const(Ok(()));
 
         let workload_spec = generate_test_workload_spec_with_param(
@@ -579,7 +688,7 @@ mod tests {
         );
         let (state_change_tx, _state_change_rx) = tokio::sync::mpsc::channel(BUFFER_SIZE);
 
-        let containerd_runtime = ContainerdRuntime {};
+        let containerd_runtime = ContainerdRuntime::default();
         let res = containerd_runtime
             .create_workload(
                 workload_spec,
@@ -598,13 +707,13 @@ mod tests {
     async fn utest_create_workload_run_failed_cleanup_failed() {
         let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
 
-        let run_context = NerdctlCli::nerdctl_run_context();
+        let run_context = ContainerCli::nerdctl_run_context();
         run_context
             .expect()
             .return_const(Err("nerdctl run failed".into()));
 
         // Workload creation fails, deleting the broken container failed
-        let delete_context = NerdctlCli::remove_workloads_by_id_context();
+        let delete_context = ContainerCli::remove_workloads_by_id_context();
         delete_context
             .expect()
             .return_const(Err("simulated error".into()));
@@ -616,7 +725,7 @@ mod tests {
         );
         let (state_change_tx, _state_change_rx) = tokio::sync::mpsc::channel(BUFFER_SIZE);
 
-        let containerd_runtime = ContainerdRuntime {};
+        let containerd_runtime = ContainerdRuntime::default();
         let res = containerd_runtime
             .create_workload(
                 workload_spec,
@@ -643,7 +752,7 @@ mod tests {
 
         let (state_change_tx, _state_change_rx) = tokio::sync::mpsc::channel(BUFFER_SIZE);
 
-        let containerd_runtime = ContainerdRuntime {};
+        let containerd_runtime = ContainerdRuntime::default();
         let res = containerd_runtime
             .create_workload(
                 workload_spec,
@@ -662,14 +771,14 @@ mod tests {
     async fn utest_get_workload_id_workload_found() {
         let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
 
-        let context = NerdctlCli::list_container_ids_by_label_context();
+        let context = ContainerCli::list_container_ids_by_label_context();
         context
             .expect()
             .return_const(Ok(vec!["test_workload_id".to_string()]));
 
         let workload_name = "container1.hash.dummy_agent".try_into().unwrap();
 
-        let containerd_runtime = ContainerdRuntime {};
+        let containerd_runtime = ContainerdRuntime::default();
         let res = containerd_runtime.get_workload_id(&workload_name).await;
 
         assert_eq!(
@@ -684,12 +793,12 @@ mod tests {
     async fn utest_get_workload_id_no_workload_found() {
         let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
 
-        let context = NerdctlCli::list_container_ids_by_label_context();
+        let context = ContainerCli::list_container_ids_by_label_context();
         context.expect().return_const(Ok(Vec::new()));
 
         let workload_name = "container1.hash.dummy_agent".try_into().unwrap();
 
-        let containerd_runtime = ContainerdRuntime {};
+        let containerd_runtime = ContainerdRuntime::default();
         let res = containerd_runtime.get_workload_id(&workload_name).await;
 
         assert_eq!(
@@ -704,12 +813,12 @@ mod tests {
     async fn utest_get_workload_id_failed() {
         let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
 
-        let context = NerdctlCli::list_container_ids_by_label_context();
+        let context = ContainerCli::list_container_ids_by_label_context();
         context.expect().return_const(Err("simulated error".into()));
 
         let workload_name = "container1.hash.dummy_agent".try_into().unwrap();
 
-        let containerd_runtime = ContainerdRuntime {};
+        let containerd_runtime = ContainerdRuntime::default();
         let res = containerd_runtime.get_workload_id(&workload_name).await;
 
         assert_eq!(res, Err(RuntimeError::List("simulated error".to_owned())))
@@ -720,7 +829,7 @@ mod tests {
     async fn utest_get_state_returns_state() {
         let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
 
-        let context = NerdctlCli::list_states_by_id_context();
+        let context = ContainerCli::list_states_by_id_context();
         context
             .expect()
             .return_const(Ok(Some(ExecutionState::running())));
@@ -728,7 +837,7 @@ mod tests {
         let workload_id = ContainerdWorkloadId {
             id: "test_id".into(),
         };
-        let checker = ContainerdStateGetter {};
+        let checker = ContainerdStateGetter::default();
         let res = checker.get_state(&workload_id).await;
         assert_eq!(res, ExecutionState::running());
     }
@@ -738,13 +847,13 @@ mod tests {
     async fn utest_get_state_returns_lost_on_missing_state() {
         let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
 
-        let context = NerdctlCli::list_states_by_id_context();
+        let context = ContainerCli::list_states_by_id_context();
         context.expect().return_const(Ok(None));
 
         let workload_id = ContainerdWorkloadId {
             id: "test_id".into(),
         };
-        let checker = ContainerdStateGetter {};
+        let checker = ContainerdStateGetter::default();
         let res = checker.get_state(&workload_id).await;
         assert_eq!(res, ExecutionState::lost())
     }
@@ -754,13 +863,13 @@ mod tests {
     async fn utest_get_state_returns_error() {
         let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
 
-        let context = NerdctlCli::list_states_by_id_context();
+        let context = ContainerCli::list_states_by_id_context();
         context.expect().return_const(Err("simulated error".into()));
 
         let workload_id = ContainerdWorkloadId {
             id: "test_id".into(),
         };
-        let checker = ContainerdStateGetter {};
+        let checker = ContainerdStateGetter::default();
         let res = checker.get_state(&workload_id).await;
         assert_eq!(
             res,
@@ -768,19 +877,36 @@ mod tests {
         );
     }
 
+    // [utest->swdd~containerd-state-getter-provides-state-change-events~1]
+    #[tokio::test]
+    async fn utest_subscribe_state_changes_uses_runtime_events() {
+        let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
+
+        let context = ContainerCli::subscribe_state_changes_context();
+        context.expect().once().returning(|_| {
+            let (_events_tx, events_rx) = tokio::sync::broadcast::channel(1);
+            let (_connected_tx, connected_rx) = tokio::sync::watch::channel(true);
+            StateChangeSubscription::new(events_rx, connected_rx)
+        });
+
+        let state_getter = ContainerdStateGetter::default();
+        let subscription = state_getter.subscribe_state_changes().unwrap();
+        assert!(subscription.is_connected());
+    }
+
     // [utest->swdd~containerd-delete-workload-stops-and-removes-workload~1]
     #[tokio::test]
     async fn utest_delete_workload_succeeds() {
         let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
 
-        let context = NerdctlCli::remove_workloads_by_id_context();
+        let context = ContainerCli::remove_workloads_by_id_context();
         context.expect().return_const(Ok(()));
 
         let workload_id = ContainerdWorkloadId {
             id: "test_id".into(),
         };
 
-        let containerd_runtime = ContainerdRuntime {};
+        let containerd_runtime = ContainerdRuntime::default();
         let res = containerd_runtime.delete_workload(&workload_id).await;
         assert_eq!(res, Ok(()));
     }
@@ -790,18 +916,67 @@ mod tests {
     async fn utest_delete_workload_fails() {
         let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
 
-        let context = NerdctlCli::remove_workloads_by_id_context();
+        let context = ContainerCli::remove_workloads_by_id_context();
         context.expect().return_const(Err("simulated error".into()));
 
         let workload_id = ContainerdWorkloadId {
             id: "test_id".into(),
         };
 
-        let containerd_runtime = ContainerdRuntime {};
+        let containerd_runtime = ContainerdRuntime::default();
         let res = containerd_runtime.delete_workload(&workload_id).await;
         assert_eq!(res, Err(RuntimeError::Delete("simulated error".into())));
     }
 
+    // [utest->swdd~containerd-provides-workload-metrics~1]
+    #[tokio::test]
+    async fn utest_get_workload_metrics_succeeds() {
+        let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
+
+        let workload_metrics = WorkloadMetrics {
+            cpu_usage: Some(12),
+            memory_usage: Some(1024),
+            restart_count: 0,
+        };
+
+        let context = ContainerCli::get_container_stats_context();
+        context
+            .expect()
+            .with(
+                mockall::predicate::eq(ContainerCliTool::Nerdctl),
+                mockall::predicate::eq("test_id"),
+            )
+            .return_const(Ok(workload_metrics.clone()));
+
+        let workload_id = ContainerdWorkloadId {
+            id: "test_id".into(),
+        };
+
+        let containerd_runtime = ContainerdRuntime::default();
+        let res = containerd_runtime.get_workload_metrics(&workload_id).await;
+        assert_eq!(res, Ok(workload_metrics));
+    }
+
+    // [utest->swdd~containerd-provides-workload-metrics~1]
+    #[tokio::test]
+    async fn utest_get_workload_metrics_fails() {
+        let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
+
+        let context = ContainerCli::get_container_stats_context();
+        context.expect().return_const(Err("simulated error".into()));
+
+        let workload_id = ContainerdWorkloadId {
+            id: "test_id".into(),
+        };
+
+        let containerd_runtime = ContainerdRuntime::default();
+        let res = containerd_runtime.get_workload_metrics(&workload_id).await;
+        assert_eq!(
+            res,
+            Err(RuntimeError::CollectMetrics("simulated error".into()))
+        );
+    }
+
     #[tokio::test]
     async fn utest_get_log_fetcher() {
         let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
@@ -817,7 +992,7 @@ mod tests {
             tail: None,
         };
 
-        let containerd_runtime = ContainerdRuntime {};
+        let containerd_runtime = ContainerdRuntime::default();
         let res = containerd_runtime.get_log_fetcher(workload_id, &log_request);
         assert!(res.is_ok());
     }
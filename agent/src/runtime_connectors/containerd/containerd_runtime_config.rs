@@ -14,10 +14,14 @@
 
 use common::objects::WorkloadSpec;
 
-use super::nerdctl_cli::NerdctlRunConfig;
+use crate::runtime_connectors::container_resource_limits;
 
-use super::containerd_runtime::CONTAINERD_RUNTIME_NAME;
+use super::container_cli::ContainerRunConfig;
 
+use super::containerd_runtime::{CONTAINERD_RUNTIME_NAME, DOCKER_RUNTIME_NAME};
+
+// The runtime config of the containerd and the docker runtime, as both pass it to the same docker
+// compatible run command.
 #[derive(Debug, serde::Deserialize, Eq, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct ContainerdRuntimeConfig {
@@ -30,9 +34,9 @@ pub struct ContainerdRuntimeConfig {
     pub command_args: Vec<String>,
 }
 
-impl From<ContainerdRuntimeConfig> for NerdctlRunConfig {
+impl From<ContainerdRuntimeConfig> for ContainerRunConfig {
     fn from(value: ContainerdRuntimeConfig) -> Self {
-        NerdctlRunConfig {
+        ContainerRunConfig {
             general_options: value.general_options,
             command_options: value.command_options,
             image: value.image,
@@ -44,16 +48,27 @@ impl From<ContainerdRuntimeConfig> for NerdctlRunConfig {
 impl TryFrom<&WorkloadSpec> for ContainerdRuntimeConfig {
     type Error = String;
     fn try_from(workload_spec: &WorkloadSpec) -> Result<Self, Self::Error> {
-        if CONTAINERD_RUNTIME_NAME != workload_spec.runtime {
+        if ![CONTAINERD_RUNTIME_NAME, DOCKER_RUNTIME_NAME].contains(&workload_spec.runtime.as_str())
+        {
             return Err(format!(
                 "Received a spec for the wrong runtime: '{}'",
                 workload_spec.runtime
             ));
         }
-        match serde_yaml::from_str(workload_spec.runtime_config.as_str()) {
-            Ok(workload_cfg) => Ok(workload_cfg),
-            Err(e) => Err(e.to_string()),
+        let mut workload_cfg: ContainerdRuntimeConfig =
+            serde_yaml::from_str(workload_spec.runtime_config.as_str())
+                .map_err(|e| e.to_string())?;
+
+        // The typed resource limits are appended to take precedence over the command options.
+        // [impl->swdd~containerd-create-workload-applies-resource-limits~1]
+        if let Some(resource_limits) = &workload_spec.resources {
+            workload_cfg
+                .command_options
+                .extend(container_resource_limits::to_command_options(
+                    resource_limits,
+                ));
         }
+        Ok(workload_cfg)
     }
 }
 
@@ -67,9 +82,9 @@ impl TryFrom<&WorkloadSpec> for ContainerdRuntimeConfig {
 
 #[cfg(test)]
 mod tests {
-    use common::objects::generate_test_workload_spec_with_param;
+    use common::objects::{ResourceLimits, generate_test_workload_spec_with_param};
 
-    use super::{ContainerdRuntimeConfig, NerdctlRunConfig};
+    use super::{ContainerRunConfig, ContainerdRuntimeConfig};
     use crate::runtime_connectors::containerd::containerd_runtime::CONTAINERD_RUNTIME_NAME;
 
     const DIFFERENT_RUNTIME_NAME: &str = "different-runtime-name";
@@ -123,6 +138,35 @@ mod tests {
         );
     }
 
+    // [utest->swdd~containerd-create-workload-applies-resource-limits~1]
+    #[test]
+    fn utest_containerd_config_appends_resource_limits_to_command_options() {
+        let mut workload_spec = generate_test_workload_spec_with_param(
+            AGENT_NAME.to_string(),
+            WORKLOAD_1_NAME.to_string(),
+            CONTAINERD_RUNTIME_NAME.to_string(),
+        );
+        workload_spec.runtime_config =
+            "commandOptions: [\"--network=host\"]\nimage: alpine:latest\n".to_string();
+        workload_spec.resources = Some(ResourceLimits {
+            cpu_quota: Some(1500),
+            memory_limit: Some(268435456),
+            pids_limit: Some(100),
+        });
+
+        assert_eq!(
+            ContainerdRuntimeConfig::try_from(&workload_spec)
+                .unwrap()
+                .command_options,
+            vec![
+                "--network=host".to_string(),
+                "--cpus=1.500".to_string(),
+                "--memory=268435456".to_string(),
+                "--pids-limit=100".to_string(),
+            ]
+        );
+    }
+
     #[test]
     fn utest_containerd_config_to_containerd_run_config() {
         let containerd_runtime_config = ContainerdRuntimeConfig {
@@ -132,7 +176,7 @@ mod tests {
             command_args: vec!["bash".to_string(), "bar".to_string()],
         };
 
-        let containerd_run_config = NerdctlRunConfig {
+        let containerd_run_config = ContainerRunConfig {
             general_options: vec!["1".to_string(), "42".to_string()],
             command_options: vec!["--network=host".to_string(), "foo".to_string()],
             image: "alpine:latest".to_string(),
@@ -140,7 +184,7 @@ mod tests {
         };
 
         assert_eq!(
-            NerdctlRunConfig::from(containerd_runtime_config),
+            ContainerRunConfig::from(containerd_runtime_config),
             containerd_run_config
         );
     }
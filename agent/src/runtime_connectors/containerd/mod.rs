@@ -12,8 +12,9 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
+mod container_cli;
 pub(crate) mod containerd_log_fetcher;
 mod containerd_runtime;
 mod containerd_runtime_config;
-mod nerdctl_cli;
+pub use container_cli::ContainerCliTool;
 pub use containerd_runtime::{ContainerdRuntime, ContainerdWorkloadId};
@@ -0,0 +1,191 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{path::Path, process::Stdio};
+
+use tokio::process::{Child, Command};
+
+use crate::runtime_connectors::runtime_connector::LogRequestOptions;
+
+use super::log_fetcher::{GetOutputStreams, StreamTrait};
+
+const TAIL_CMD: &str = "tail";
+
+// [impl->swdd~agent-file-log-fetching-collects-logs~1]
+
+#[derive(Debug)]
+pub struct FileLogFetcher {
+    stdout_child: Option<Child>,
+    stderr_child: Option<Child>,
+}
+
+impl FileLogFetcher {
+    pub fn new(stdout_log: &Path, stderr_log: &Path, options: &LogRequestOptions) -> Self {
+        if options.since.is_some() || options.until.is_some() {
+            log::warn!(
+                "Log files do not contain timestamps, ignoring the 'since' and 'until' options."
+            );
+        }
+
+        Self {
+            stdout_child: Self::spawn_tail(stdout_log, options),
+            stderr_child: Self::spawn_tail(stderr_log, options),
+        }
+    }
+
+    fn spawn_tail(log_file: &Path, options: &LogRequestOptions) -> Option<Child> {
+        let mut args = Vec::with_capacity(4);
+        if options.follow {
+            args.push("-F".to_string());
+        }
+        args.push("-n".to_string());
+        args.push(match options.tail {
+            Some(tail) if tail >= 0 => tail.to_string(),
+            _ => "+1".to_string(),
+        });
+        args.push(log_file.to_string_lossy().to_string());
+
+        match Command::new(TAIL_CMD)
+            .args(args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+        {
+            Ok(child) => Some(child),
+            Err(err) => {
+                log::warn!(
+                    "Can not collect logs from '{}': '{err}'",
+                    log_file.display()
+                );
+                None
+            }
+        }
+    }
+}
+
+impl Drop for FileLogFetcher {
+    fn drop(&mut self) {
+        for child in [&mut self.stdout_child, &mut self.stderr_child]
+            .into_iter()
+            .flatten()
+        {
+            if let Err(err) = child.start_kill() {
+                log::warn!("Could not stop log collection: '{err}'");
+            }
+        }
+    }
+}
+
+impl GetOutputStreams for FileLogFetcher {
+    type OutputStream = Box<dyn StreamTrait>;
+    type ErrStream = Box<dyn StreamTrait>;
+
+    fn get_output_streams(&mut self) -> (Option<Self::OutputStream>, Option<Self::ErrStream>) {
+        (
+            self.stdout_child
+                .as_mut()
+                .and_then(|child| child.stdout.take())
+                .map(|stdout| Box::new(stdout) as Box<dyn StreamTrait>),
+            self.stderr_child
+                .as_mut()
+                .and_then(|child| child.stdout.take())
+                .map(|stderr| Box::new(stderr) as Box<dyn StreamTrait>),
+        )
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+// [utest->swdd~agent-file-log-fetching-collects-logs~1]
+#[cfg(test)]
+mod tests {
+    use super::FileLogFetcher;
+    use api::ank_base::LogStream;
+    use crate::runtime_connectors::{
+        LogRequestOptions,
+        generic_log_fetcher::GenericLogFetcher,
+        log_fetcher::{LogFetcher, NextLinesResult},
+    };
+
+    async fn collect_all_lines(
+        log_fetcher: &mut GenericLogFetcher<FileLogFetcher>,
+    ) -> (Vec<String>, Vec<String>) {
+        let mut stdout_lines = Vec::new();
+        let mut stderr_lines = Vec::new();
+        while let NextLinesResult::Lines(lines) = log_fetcher.next_lines().await {
+            for line in lines {
+                match line.stream {
+                    LogStream::Stdout => stdout_lines.push(line.message),
+                    _ => stderr_lines.push(line.message),
+                }
+            }
+        }
+        (stdout_lines, stderr_lines)
+    }
+
+    #[tokio::test]
+    async fn utest_file_log_fetcher_reads_stdout_and_stderr_logs() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let stdout_log = tmpdir.path().join("stdout.log");
+        let stderr_log = tmpdir.path().join("stderr.log");
+        std::fs::write(&stdout_log, "out 1\nout 2\nout 3\n").unwrap();
+        std::fs::write(&stderr_log, "err 1\n").unwrap();
+
+        let mut log_fetcher = GenericLogFetcher::new(FileLogFetcher::new(
+            &stdout_log,
+            &stderr_log,
+            &LogRequestOptions {
+                follow: false,
+                tail: None,
+                since: None,
+                until: None,
+            },
+        ));
+
+        let (stdout_lines, stderr_lines) = collect_all_lines(&mut log_fetcher).await;
+        assert_eq!(stdout_lines, vec!["out 1", "out 2", "out 3"]);
+        assert_eq!(stderr_lines, vec!["err 1"]);
+    }
+
+    #[tokio::test]
+    async fn utest_file_log_fetcher_respects_tail() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let stdout_log = tmpdir.path().join("stdout.log");
+        let stderr_log = tmpdir.path().join("stderr.log");
+        std::fs::write(&stdout_log, "out 1\nout 2\nout 3\n").unwrap();
+        std::fs::write(&stderr_log, "").unwrap();
+
+        let mut log_fetcher = GenericLogFetcher::new(FileLogFetcher::new(
+            &stdout_log,
+            &stderr_log,
+            &LogRequestOptions {
+                follow: false,
+                tail: Some(2),
+                since: None,
+                until: None,
+            },
+        ));
+
+        let (stdout_lines, stderr_lines) = collect_all_lines(&mut log_fetcher).await;
+        assert_eq!(stdout_lines, vec!["out 2", "out 3"]);
+        assert!(stderr_lines.is_empty());
+    }
+}
@@ -12,19 +12,36 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
+use api::ank_base::LogStream;
 use async_trait::async_trait;
 use bytes::BytesMut;
+use common::helpers::format_unix_timestamp;
 use tokio::{
     io::{AsyncRead, AsyncReadExt},
     select,
 };
 
-use super::log_fetcher::{GetOutputStreams, LogFetcher, NextLinesResult};
+use super::log_fetcher::{GetOutputStreams, LogFetcher, LogLine, NextLinesResult};
 
 // [impl->swdd~agent-log-fetching-collects-logs~1]
 
 const LINE_FEED: u8 = 0x0A;
 
+#[cfg(not(test))]
+fn current_timestamp() -> String {
+    format_unix_timestamp(
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default(),
+    )
+}
+
+#[cfg(test)]
+fn current_timestamp() -> String {
+    format_unix_timestamp(test::TIMESTAMP)
+}
+
 #[derive(Debug)]
 pub struct GenericSingleLogFetcher<T: AsyncRead + std::fmt::Debug> {
     reader: T,
@@ -38,6 +55,7 @@ where
 {
     stdout: Option<GenericSingleLogFetcher<T::OutputStream>>,
     stderr: Option<GenericSingleLogFetcher<T::ErrStream>>,
+    prefixed_timestamps: bool,
     _streams: T,
 }
 
@@ -47,9 +65,48 @@ impl<T: GetOutputStreams> GenericLogFetcher<T> {
         Self {
             stdout: stdout.map(GenericSingleLogFetcher::new),
             stderr: stderr.map(GenericSingleLogFetcher::new),
+            prefixed_timestamps: streams.prefixes_timestamps(),
             _streams: streams,
         }
     }
+
+    // [impl->swdd~agent-log-fetching-provides-timestamp-and-stream~1]
+    fn to_log_lines(&self, stream: LogStream, lines: Vec<String>) -> NextLinesResult {
+        let receive_timestamp = current_timestamp();
+        NextLinesResult::Lines(
+            lines
+                .into_iter()
+                .map(|line| {
+                    let (timestamp, message) = if self.prefixed_timestamps {
+                        split_prefixed_timestamp(&line)
+                            .map(|(timestamp, message)| (timestamp.to_owned(), message.to_owned()))
+                            .unwrap_or_else(|| (receive_timestamp.clone(), line))
+                    } else {
+                        (receive_timestamp.clone(), line)
+                    };
+                    LogLine {
+                        stream,
+                        timestamp,
+                        message,
+                    }
+                })
+                .collect(),
+        )
+    }
+}
+
+// The runtimes write the timestamp in front of the message, e.g. '2024-03-01T12:30:00.123456789Z message'.
+fn split_prefixed_timestamp(line: &str) -> Option<(&str, &str)> {
+    let (timestamp, message) = line.split_once(' ').unwrap_or((line, ""));
+    let bytes = timestamp.as_bytes();
+    let is_rfc3339 = bytes.len() >= 20
+        && bytes[..4].iter().all(u8::is_ascii_digit)
+        && bytes[4] == b'-'
+        && bytes[7] == b'-'
+        && bytes[10] == b'T'
+        && bytes[13] == b':'
+        && bytes[16] == b':';
+    is_rfc3339.then_some((timestamp, message))
 }
 
 #[async_trait]
@@ -61,14 +118,14 @@ impl<T: GetOutputStreams + std::fmt::Debug + Send + 'static> LogFetcher for Gene
                     select! {
                         lines = stdout.next_lines() => {
                             if let Some(lines) = lines {
-                                return NextLinesResult::Stdout(lines);
+                                return self.to_log_lines(LogStream::Stdout, lines);
                             } else {
                                 self.stdout = None;
                             }
                         }
                         lines = stderr.next_lines() => {
                             if let Some(lines) = lines {
-                                return NextLinesResult::Stderr(lines);
+                                return self.to_log_lines(LogStream::Stderr, lines);
                             } else {
                                 self.stderr = None;
                             }
@@ -77,14 +134,14 @@ impl<T: GetOutputStreams + std::fmt::Debug + Send + 'static> LogFetcher for Gene
                 }
                 (Some(stdout), None) => {
                     if let Some(lines) = stdout.next_lines().await {
-                        return NextLinesResult::Stdout(lines);
+                        return self.to_log_lines(LogStream::Stdout, lines);
                     } else {
                         return NextLinesResult::EoF;
                     }
                 }
                 (None, Some(stderr)) => {
                     if let Some(lines) = stderr.next_lines().await {
-                        return NextLinesResult::Stderr(lines);
+                        return self.to_log_lines(LogStream::Stderr, lines);
                     } else {
                         return NextLinesResult::EoF;
                     }
@@ -162,12 +219,14 @@ fn convert_to_string(vec: impl Into<Vec<u8>>) -> String {
 pub mod test {
     use std::{collections::VecDeque, vec};
 
+    use api::ank_base::LogStream;
+    use common::helpers::format_unix_timestamp;
     use tokio::io::AsyncRead;
 
     use super::NextLinesResult;
     use crate::runtime_connectors::{
         generic_log_fetcher::{GenericLogFetcher, GenericSingleLogFetcher},
-        log_fetcher::{LogFetcher, StreamTrait},
+        log_fetcher::{GetOutputStreams, LogFetcher, LogLine, StreamTrait},
         podman::{PodmanWorkloadId, podman_log_fetcher::PodmanLogFetcher},
         runtime_connector::LogRequestOptions,
     };
@@ -179,6 +238,9 @@ pub mod test {
     const LINE_5: &str = "fifth line";
     const STDOUT_LINE: &str = "line_from_stdout";
     const STDERR_LINE: &str = "line_from_stderr";
+    const RUNTIME_TIMESTAMP: &str = "2024-03-01T12:30:00.123456789Z";
+
+    pub const TIMESTAMP: u64 = 1_700_000_000;
 
     #[derive(Debug)]
     pub(crate) struct MockRead {
@@ -209,7 +271,11 @@ pub mod test {
         ) -> std::task::Poll<std::io::Result<()>> {
             let element = self.data.pop_front();
             match element {
-                Some(MockReadDataEntry::Data(data)) => {
+                Some(MockReadDataEntry::Data(mut data)) => {
+                    if data.len() > buf.remaining() {
+                        let rest = data.split_off(buf.remaining());
+                        self.data.push_front(MockReadDataEntry::Data(rest));
+                    }
                     buf.put_slice(&data);
                     std::task::Poll::Ready(std::io::Result::Ok(()))
                 }
@@ -338,7 +404,7 @@ pub mod test {
         let mut generic_log_fetcher = create_generic_log_fetcher(Some(Box::new(stdout)), None);
         assert!(matches!(
             generic_log_fetcher.next_lines().await,
-            NextLinesResult::Stdout(lines) if lines == vec![STDOUT_LINE.to_string()]
+            NextLinesResult::Lines(lines) if lines == vec![log_line(LogStream::Stdout, STDOUT_LINE)]
         ));
         assert!(matches!(
             generic_log_fetcher.next_lines().await,
@@ -356,7 +422,7 @@ pub mod test {
         let mut generic_log_fetcher = create_generic_log_fetcher(None, Some(Box::new(stderr)));
         assert!(matches!(
             generic_log_fetcher.next_lines().await,
-            NextLinesResult::Stderr(lines) if lines == vec![STDERR_LINE.to_string()]
+            NextLinesResult::Lines(lines) if lines == vec![log_line(LogStream::Stderr, STDERR_LINE)]
         ));
         assert!(matches!(
             generic_log_fetcher.next_lines().await,
@@ -382,12 +448,12 @@ pub mod test {
         for _ in 0..2 {
             let line = generic_log_fetcher.next_lines().await;
             match line {
-                NextLinesResult::Stdout(lines) => {
-                    assert_eq!(lines, vec![STDOUT_LINE.to_string()]);
+                NextLinesResult::Lines(lines) if lines[0].stream == LogStream::Stdout => {
+                    assert_eq!(lines, vec![log_line(LogStream::Stdout, STDOUT_LINE)]);
                     lines_from_stdout += 1;
                 }
-                NextLinesResult::Stderr(lines) => {
-                    assert_eq!(lines, vec![STDERR_LINE.to_string()]);
+                NextLinesResult::Lines(lines) => {
+                    assert_eq!(lines, vec![log_line(LogStream::Stderr, STDERR_LINE)]);
                     lines_from_stderr += 1;
                 }
                 NextLinesResult::EoF => {
@@ -406,6 +472,84 @@ pub mod test {
         ));
     }
 
+    // [utest->swdd~agent-log-fetching-provides-timestamp-and-stream~1]
+    #[tokio::test]
+    async fn utest_generic_log_fetcher_uses_timestamps_prefixed_by_runtime() {
+        let stdout = MockRead {
+            data: vec![
+                MockReadDataEntry::data(&format!("{RUNTIME_TIMESTAMP} {STDOUT_LINE}\n")),
+                MockReadDataEntry::data(&format!("{RUNTIME_TIMESTAMP} \n{STDOUT_LINE}\n")),
+            ]
+            .into(),
+        };
+
+        let mut generic_log_fetcher = create_generic_log_fetcher(Some(Box::new(stdout)), None);
+        assert!(matches!(
+            generic_log_fetcher.next_lines().await,
+            NextLinesResult::Lines(lines) if lines == vec![
+                LogLine {
+                    stream: LogStream::Stdout,
+                    timestamp: RUNTIME_TIMESTAMP.to_string(),
+                    message: STDOUT_LINE.to_string(),
+                },
+            ]
+        ));
+        assert!(matches!(
+            generic_log_fetcher.next_lines().await,
+            NextLinesResult::Lines(lines) if lines == vec![
+                LogLine {
+                    stream: LogStream::Stdout,
+                    timestamp: RUNTIME_TIMESTAMP.to_string(),
+                    message: String::new(),
+                },
+                log_line(LogStream::Stdout, STDOUT_LINE),
+            ]
+        ));
+    }
+
+    // [utest->swdd~agent-log-fetching-provides-timestamp-and-stream~1]
+    #[tokio::test]
+    async fn utest_generic_log_fetcher_keeps_timestamp_like_text_without_runtime_timestamps() {
+        let stdout = MockRead {
+            data: vec![MockReadDataEntry::data(&format!(
+                "{RUNTIME_TIMESTAMP} {STDOUT_LINE}\n"
+            ))]
+            .into(),
+        };
+
+        let mut generic_log_fetcher = GenericLogFetcher::new(MockOutputStreams {
+            stdout: Some(Box::new(stdout)),
+        });
+        assert!(matches!(
+            generic_log_fetcher.next_lines().await,
+            NextLinesResult::Lines(lines) if lines == vec![
+                log_line(LogStream::Stdout, &format!("{RUNTIME_TIMESTAMP} {STDOUT_LINE}")),
+            ]
+        ));
+    }
+
+    #[derive(Debug)]
+    struct MockOutputStreams {
+        stdout: Option<Box<dyn StreamTrait>>,
+    }
+
+    impl GetOutputStreams for MockOutputStreams {
+        type OutputStream = Box<dyn StreamTrait>;
+        type ErrStream = Box<dyn StreamTrait>;
+
+        fn get_output_streams(&mut self) -> (Option<Self::OutputStream>, Option<Self::ErrStream>) {
+            (self.stdout.take(), None)
+        }
+    }
+
+    fn log_line(stream: LogStream, message: &str) -> LogLine {
+        LogLine {
+            stream,
+            timestamp: format_unix_timestamp(TIMESTAMP),
+            message: message.to_string(),
+        }
+    }
+
     fn create_generic_log_fetcher(
         stdout: Option<Box<dyn StreamTrait>>,
         stderr: Option<Box<dyn StreamTrait>>,
@@ -14,24 +14,26 @@
 
 use tokio::sync::{mpsc, watch};
 
+use super::log_fetcher::LogLine;
+
 #[cfg(test)]
 use mockall::automock;
 
 // [impl->swdd~agent-log-fetching-collects-logs~1]
 
 pub struct Receiver {
-    log_line_receiver: mpsc::Receiver<Vec<String>>,
+    log_line_receiver: mpsc::Receiver<Vec<LogLine>>,
     receiver_dropped_sink: watch::Sender<bool>,
 }
 
 #[cfg_attr(test, automock)]
 impl Receiver {
-    pub async fn read_log_lines(&mut self) -> Option<Vec<String>> {
+    pub async fn read_log_lines(&mut self) -> Option<Vec<LogLine>> {
         self.log_line_receiver.recv().await
     }
 
     #[cfg(test)]
-    pub fn take_log_line_receiver(&mut self) -> mpsc::Receiver<Vec<String>> {
+    pub fn take_log_line_receiver(&mut self) -> mpsc::Receiver<Vec<LogLine>> {
         let (_, new_receiver) = mpsc::channel(1);
         std::mem::replace(&mut self.log_line_receiver, new_receiver)
     }
@@ -43,7 +45,7 @@ impl Drop for Receiver {
     }
 }
 
-struct NoCloneableSender(mpsc::Sender<Vec<String>>); // Sender should not be cloned as the drop is used to indicate to the receiver the stop of log responses.
+struct NoCloneableSender(mpsc::Sender<Vec<LogLine>>); // Sender should not be cloned as the drop is used to indicate to the receiver the stop of log responses.
 
 pub struct Sender {
     log_line_sender: NoCloneableSender,
@@ -53,8 +55,8 @@ pub struct Sender {
 impl Sender {
     pub async fn send_log_lines(
         &self,
-        log_lines: Vec<String>,
-    ) -> Result<(), mpsc::error::SendError<Vec<String>>> {
+        log_lines: Vec<LogLine>,
+    ) -> Result<(), mpsc::error::SendError<Vec<LogLine>>> {
         self.log_line_sender.0.send(log_lines).await
     }
 
@@ -91,7 +93,10 @@ pub fn channel() -> (Sender, Receiver) {
 mod tests {
     use std::time::Duration;
 
+    use api::ank_base::LogStream;
+
     use super::channel;
+    use crate::runtime_connectors::log_fetcher::LogLine;
 
     const LINE1: [&str; 2] = ["line 1", "line 2"];
     const LINE2: [&str; 1] = ["line 3"];
@@ -153,7 +158,14 @@ mod tests {
         .unwrap();
     }
 
-    fn into_vec<const N: usize>(array: [&str; N]) -> Vec<String> {
-        array.into_iter().map(str::to_string).collect()
+    fn into_vec<const N: usize>(array: [&str; N]) -> Vec<LogLine> {
+        array
+            .into_iter()
+            .map(|message| LogLine {
+                stream: LogStream::Stdout,
+                timestamp: "2024-03-01T12:30:00Z".into(),
+                message: message.into(),
+            })
+            .collect()
     }
 }
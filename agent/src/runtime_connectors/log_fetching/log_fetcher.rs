@@ -12,6 +12,7 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
+use api::ank_base::LogStream;
 use async_trait::async_trait;
 #[cfg(test)]
 use mockall::automock;
@@ -21,10 +22,17 @@ use super::log_channel;
 
 // [impl->swdd~agent-log-fetching-collects-logs~1]
 
+// [impl->swdd~agent-log-fetching-provides-timestamp-and-stream~1]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogLine {
+    pub stream: LogStream,
+    pub timestamp: String,
+    pub message: String,
+}
+
 #[derive(Clone)]
 pub enum NextLinesResult {
-    Stdout(Vec<String>),
-    Stderr(Vec<String>),
+    Lines(Vec<LogLine>),
     EoF,
 }
 
@@ -41,6 +49,11 @@ pub trait GetOutputStreams {
     type OutputStream: StreamTrait;
     type ErrStream: StreamTrait;
     fn get_output_streams(&mut self) -> (Option<Self::OutputStream>, Option<Self::ErrStream>);
+
+    // Runtimes which are asked for timestamps prefix every log line with an RFC3339 timestamp.
+    fn prefixes_timestamps(&self) -> bool {
+        false
+    }
 }
 
 pub async fn run(mut log_fetcher: Box<dyn LogFetcher>, mut sender: log_channel::Sender) {
@@ -48,17 +61,10 @@ pub async fn run(mut log_fetcher: Box<dyn LogFetcher>, mut sender: log_channel::
         select! {
             lines = log_fetcher.next_lines() => {
                 match lines{
-                    NextLinesResult::Stdout(lines) => {
-                        let res = sender.send_log_lines(lines).await;
-                        if let Err(err) = res {
-                            log::warn!("Could not forward stdout log lines: {:?}", err.0);
-                            break;
-                        }
-                    }
-                    NextLinesResult::Stderr(lines) => {
+                    NextLinesResult::Lines(lines) => {
                         let res = sender.send_log_lines(lines).await;
                         if let Err(err) = res {
-                            log::warn!("Could not forward stderr log lines: {:?}", err.0);
+                            log::warn!("Could not forward log lines: {:?}", err.0);
                             break;
                         }
                     }
@@ -89,43 +95,35 @@ pub async fn run(mut log_fetcher: Box<dyn LogFetcher>, mut sender: log_channel::
 mod tests {
     use std::{collections::VecDeque, sync::Arc, time::Duration};
 
+    use api::ank_base::LogStream;
     use async_trait::async_trait;
     use tokio::{sync::Semaphore, time::timeout};
 
     use crate::runtime_connectors::log_channel;
 
-    use super::{LogFetcher, NextLinesResult};
+    use super::{LogFetcher, LogLine, NextLinesResult};
 
     const LINES_1: [&str; 3] = ["line 1 1", "line 1 2", "line 1 3"];
     const LINES_2: [&str; 2] = ["line 2 1", "line 2 2"];
     const LINES_3: [&str; 4] = ["line 3 1", "line 3 2", "line 3 3", "line 3 4"];
 
-    const TIMEOUT: Duration = Duration::from_millis(10);
+    const TIMESTAMP: &str = "2024-03-01T12:30:00Z";
 
-    #[derive(Debug)]
-    enum NextLineType {
-        Stdout,
-        Stderr,
-    }
+    const TIMEOUT: Duration = Duration::from_millis(10);
 
     #[derive(Debug)]
     struct MockLogFetcher {
-        mock_data: VecDeque<Vec<String>>,
+        mock_data: VecDeque<Vec<LogLine>>,
         limited: bool,
         semaphore: Arc<Semaphore>,
-        line_type: NextLineType,
     }
 
     impl MockLogFetcher {
-        fn new<'a>(data: &'a [&'a [&'a str]], limited: bool, line_type: NextLineType) -> Self {
+        fn new<'a>(data: &'a [&'a [&'a str]], limited: bool, stream: LogStream) -> Self {
             Self {
-                mock_data: data
-                    .iter()
-                    .map(|x| x.iter().map(|y| y.to_string()).collect())
-                    .collect(),
+                mock_data: data.iter().map(|x| log_lines(x, stream)).collect(),
                 semaphore: Arc::new(Semaphore::new(0)),
                 limited,
-                line_type,
             }
         }
 
@@ -140,28 +138,33 @@ mod tests {
             self.semaphore.acquire().await.unwrap().forget();
             if self.limited {
                 match self.mock_data.pop_front() {
-                    Some(res) => match self.line_type {
-                        NextLineType::Stdout => NextLinesResult::Stdout(res),
-                        NextLineType::Stderr => NextLinesResult::Stderr(res),
-                    },
+                    Some(res) => NextLinesResult::Lines(res),
                     None => NextLinesResult::EoF,
                 }
             } else {
                 let res = self.mock_data.pop_front().unwrap();
                 self.mock_data.push_back(res.clone());
-                match self.line_type {
-                    NextLineType::Stdout => NextLinesResult::Stdout(res),
-                    NextLineType::Stderr => NextLinesResult::Stderr(res),
-                }
+                NextLinesResult::Lines(res)
             }
         }
     }
 
+    fn log_lines(lines: &[&str], stream: LogStream) -> Vec<LogLine> {
+        lines
+            .iter()
+            .map(|line| LogLine {
+                stream,
+                timestamp: TIMESTAMP.into(),
+                message: line.to_string(),
+            })
+            .collect()
+    }
+
     // [utest->swdd~agent-log-fetching-collects-logs~1]
     #[tokio::test]
     async fn utest_log_fetcher_read_all_lines() {
         let log_fetcher =
-            MockLogFetcher::new(&[&LINES_1, &LINES_2, &LINES_3], true, NextLineType::Stdout);
+            MockLogFetcher::new(&[&LINES_1, &LINES_2, &LINES_3], true, LogStream::Stdout);
         let sem = log_fetcher.semaphore();
         sem.add_permits(4);
 
@@ -170,15 +173,15 @@ mod tests {
 
         assert_eq!(
             timeout(TIMEOUT, receiver.read_log_lines()).await,
-            Ok(Some(LINES_1.iter().map(|&x| x.into()).collect()))
+            Ok(Some(log_lines(&LINES_1, LogStream::Stdout)))
         );
         assert_eq!(
             timeout(TIMEOUT, receiver.read_log_lines()).await,
-            Ok(Some(LINES_2.iter().map(|&x| x.into()).collect()))
+            Ok(Some(log_lines(&LINES_2, LogStream::Stdout)))
         );
         assert_eq!(
             timeout(TIMEOUT, receiver.read_log_lines()).await,
-            Ok(Some(LINES_3.iter().map(|&x| x.into()).collect()))
+            Ok(Some(log_lines(&LINES_3, LogStream::Stdout)))
         );
         assert_eq!(timeout(TIMEOUT, receiver.read_log_lines()).await, Ok(None));
         timeout(TIMEOUT, jh).await.unwrap().unwrap();
@@ -188,7 +191,7 @@ mod tests {
     #[tokio::test]
     async fn utest_log_fetcher_cannot_send_message() {
         let log_fetcher =
-            MockLogFetcher::new(&[&LINES_1, &LINES_2, &LINES_3], false, NextLineType::Stdout);
+            MockLogFetcher::new(&[&LINES_1, &LINES_2, &LINES_3], false, LogStream::Stdout);
         let sem = log_fetcher.semaphore();
         sem.add_permits(4);
 
@@ -197,11 +200,11 @@ mod tests {
 
         assert_eq!(
             timeout(TIMEOUT, receiver.read_log_lines()).await,
-            Ok(Some(LINES_1.iter().map(|&x| x.into()).collect()))
+            Ok(Some(log_lines(&LINES_1, LogStream::Stdout)))
         );
         assert_eq!(
             timeout(TIMEOUT, receiver.read_log_lines()).await,
-            Ok(Some(LINES_2.iter().map(|&x| x.into()).collect()))
+            Ok(Some(log_lines(&LINES_2, LogStream::Stdout)))
         );
         receiver.take_log_line_receiver();
         timeout(TIMEOUT, jh).await.unwrap().unwrap();
@@ -211,7 +214,7 @@ mod tests {
     #[tokio::test]
     async fn utest_log_fetcher_informed_about_receiver_dropped() {
         let log_fetcher =
-            MockLogFetcher::new(&[&LINES_1, &LINES_2, &LINES_3], false, NextLineType::Stdout);
+            MockLogFetcher::new(&[&LINES_1, &LINES_2, &LINES_3], false, LogStream::Stdout);
         let sem = log_fetcher.semaphore();
         sem.add_permits(2);
 
@@ -220,11 +223,11 @@ mod tests {
 
         assert_eq!(
             timeout(TIMEOUT, receiver.read_log_lines()).await,
-            Ok(Some(LINES_1.iter().map(|&x| x.into()).collect()))
+            Ok(Some(log_lines(&LINES_1, LogStream::Stdout)))
         );
         assert_eq!(
             timeout(TIMEOUT, receiver.read_log_lines()).await,
-            Ok(Some(LINES_2.iter().map(|&x| x.into()).collect()))
+            Ok(Some(log_lines(&LINES_2, LogStream::Stdout)))
         );
         drop(receiver);
         timeout(TIMEOUT, jh).await.unwrap().unwrap();
@@ -234,7 +237,7 @@ mod tests {
     #[tokio::test]
     async fn utest_log_fetcher_stderr_read_all_lines() {
         let log_fetcher =
-            MockLogFetcher::new(&[&LINES_1, &LINES_2, &LINES_3], true, NextLineType::Stderr);
+            MockLogFetcher::new(&[&LINES_1, &LINES_2, &LINES_3], true, LogStream::Stderr);
         let sem = log_fetcher.semaphore();
 
         sem.add_permits(4);
@@ -242,15 +245,15 @@ mod tests {
         let jh = tokio::spawn(super::run(Box::new(log_fetcher), sender));
         assert_eq!(
             timeout(TIMEOUT, receiver.read_log_lines()).await,
-            Ok(Some(LINES_1.iter().map(|&x| x.into()).collect()))
+            Ok(Some(log_lines(&LINES_1, LogStream::Stderr)))
         );
         assert_eq!(
             timeout(TIMEOUT, receiver.read_log_lines()).await,
-            Ok(Some(LINES_2.iter().map(|&x| x.into()).collect()))
+            Ok(Some(log_lines(&LINES_2, LogStream::Stderr)))
         );
         assert_eq!(
             timeout(TIMEOUT, receiver.read_log_lines()).await,
-            Ok(Some(LINES_3.iter().map(|&x| x.into()).collect()))
+            Ok(Some(log_lines(&LINES_3, LogStream::Stderr)))
         );
         assert_eq!(timeout(TIMEOUT, receiver.read_log_lines()).await, Ok(None));
         timeout(TIMEOUT, jh).await.unwrap().unwrap();
@@ -260,7 +263,7 @@ mod tests {
     #[tokio::test]
     async fn utest_log_fetcher_stderr_cannot_send_message() {
         let log_fetcher =
-            MockLogFetcher::new(&[&LINES_1, &LINES_2, &LINES_3], false, NextLineType::Stderr);
+            MockLogFetcher::new(&[&LINES_1, &LINES_2, &LINES_3], false, LogStream::Stderr);
         let sem = log_fetcher.semaphore();
         sem.add_permits(4);
 
@@ -269,11 +272,11 @@ mod tests {
 
         assert_eq!(
             timeout(TIMEOUT, receiver.read_log_lines()).await,
-            Ok(Some(LINES_1.iter().map(|&x| x.into()).collect()))
+            Ok(Some(log_lines(&LINES_1, LogStream::Stderr)))
         );
         assert_eq!(
             timeout(TIMEOUT, receiver.read_log_lines()).await,
-            Ok(Some(LINES_2.iter().map(|&x| x.into()).collect()))
+            Ok(Some(log_lines(&LINES_2, LogStream::Stderr)))
         );
         receiver.take_log_line_receiver();
         timeout(TIMEOUT, jh).await.unwrap().unwrap();
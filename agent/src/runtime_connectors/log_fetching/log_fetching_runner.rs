@@ -63,6 +63,7 @@ impl Drop for LogFetchingRunner {
 
 #[cfg(test)]
 mod tests {
+    use api::ank_base::LogStream;
     use lazy_static::lazy_static;
     use std::{
         future::Future,
@@ -71,7 +72,7 @@ mod tests {
     use tokio::{self};
 
     use crate::runtime_connectors::{
-        log_fetcher::{MockLogFetcher, NextLinesResult},
+        log_fetcher::{LogLine, MockLogFetcher, NextLinesResult},
         log_fetching_runner::LogFetchingRunner,
     };
 
@@ -87,6 +88,7 @@ mod tests {
     const FETCHER_2_LINE_2: &str = "fetcher 2: line 2";
     const FETCHER_2_LINE_3: &str = "fetcher 2: line 3";
     const FETCHER_2_LINE_4: &str = "fetcher 2: line 4";
+    const TIMESTAMP: &str = "2024-03-01T12:30:00Z";
 
     // [utest->swdd~agent-log-fetching-runs-log-fetchers~1]
     #[tokio::test]
@@ -112,24 +114,24 @@ mod tests {
         assert_eq!(receivers.len(), 2);
         assert_eq!(
             receivers[0].read_log_lines().await,
-            Some(vec![FETCHER_1_LINE_1.into(), FETCHER_1_LINE_2.into()])
+            Some(log_lines(&[FETCHER_1_LINE_1, FETCHER_1_LINE_2]))
         );
         assert_eq!(
             receivers[0].read_log_lines().await,
-            Some(vec![FETCHER_1_LINE_3.into()])
+            Some(log_lines(&[FETCHER_1_LINE_3]))
         );
         assert_eq!(receivers[0].read_log_lines().await, None);
         assert_eq!(
             receivers[1].read_log_lines().await,
-            Some(vec![FETCHER_2_LINE_1.into()])
+            Some(log_lines(&[FETCHER_2_LINE_1]))
         );
         assert_eq!(
             receivers[1].read_log_lines().await,
-            Some(vec![
-                FETCHER_2_LINE_2.into(),
-                FETCHER_2_LINE_3.into(),
-                FETCHER_2_LINE_4.into()
-            ])
+            Some(log_lines(&[
+                FETCHER_2_LINE_2,
+                FETCHER_2_LINE_3,
+                FETCHER_2_LINE_4
+            ]))
         );
         assert_eq!(receivers[1].read_log_lines().await, None);
     }
@@ -163,11 +165,11 @@ mod tests {
     fn create_mock_log_fetcher(lines: &[&[&str]]) -> MockLogFetcher {
         let mut log_fetcher = MockLogFetcher::new();
         for &line_package in lines {
-            let line_package = line_package.iter().map(|s| s.to_string()).collect();
+            let line_package = log_lines(line_package);
             log_fetcher
                 .expect_next_lines()
                 .once()
-                .return_once(move || NextLinesResult::Stdout(line_package));
+                .return_once(move || NextLinesResult::Lines(line_package));
         }
         log_fetcher
             .expect_next_lines()
@@ -176,6 +178,17 @@ mod tests {
         log_fetcher
     }
 
+    fn log_lines(lines: &[&str]) -> Vec<LogLine> {
+        lines
+            .iter()
+            .map(|line| LogLine {
+                stream: LogStream::Stdout,
+                timestamp: TIMESTAMP.into(),
+                message: line.to_string(),
+            })
+            .collect()
+    }
+
     pub fn spawn<F>(future: F) -> JoinHandle<F::Output>
     where
         F: Future + Send + 'static,
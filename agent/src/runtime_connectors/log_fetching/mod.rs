@@ -12,6 +12,7 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod file_log_fetcher;
 pub mod generic_log_fetcher;
 pub mod log_channel;
 pub mod log_fetcher;
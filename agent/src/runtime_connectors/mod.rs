@@ -14,6 +14,10 @@
 
 mod cli_command;
 
+mod container_resource_limits;
+
+mod container_stats;
+
 mod podman_cli;
 
 pub(crate) mod podman;
@@ -22,6 +26,11 @@ pub(crate) mod podman_kube;
 
 pub(crate) mod containerd;
 
+pub(crate) mod process;
+
+#[cfg(feature = "wasm")]
+pub(crate) mod wasm;
+
 pub(crate) mod dummy_state_checker;
 pub(crate) mod unsupported_runtime;
 
@@ -47,5 +56,10 @@ pub use state_checker::{RuntimeStateGetter, StateChecker};
 #[cfg(test)]
 pub use state_checker::MockRuntimeStateGetter;
 
+mod state_change_events;
+pub use state_change_events::{RuntimeEventSource, StateChangeEvents, StateChangeSubscription};
+
 mod log_fetching;
-pub use log_fetching::{generic_log_fetcher, log_channel, log_fetcher, log_fetching_runner};
+pub use log_fetching::{
+    file_log_fetcher, generic_log_fetcher, log_channel, log_fetcher, log_fetching_runner,
+};
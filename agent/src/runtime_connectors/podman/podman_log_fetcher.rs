@@ -37,8 +37,10 @@ pub struct PodmanLogFetcher {
 
 impl PodmanLogFetcher {
     pub fn new(workload_id: &PodmanWorkloadId, options: &LogRequestOptions) -> Self {
-        let mut args = Vec::with_capacity(9);
+        let mut args = Vec::with_capacity(10);
         args.push("logs");
+        // [impl->swdd~agent-log-fetching-provides-timestamp-and-stream~1]
+        args.push("--timestamps");
         if options.follow {
             args.push("-f")
         }
@@ -124,6 +126,10 @@ impl GetOutputStreams for PodmanLogFetcher {
         #[cfg(test)]
         return (self.stdout.take(), self.stderr.take());
     }
+
+    fn prefixes_timestamps(&self) -> bool {
+        true
+    }
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -241,7 +247,7 @@ mod tests {
                 stdout_option: Some(_),
                 stderr_option: Some(_)
 
-            }) if cmd == "podman" && *args == vec!["logs".to_string(), WORKLOAD_ID.to_string()]
+            }) if cmd == "podman" && *args == vec!["logs".to_string(), "--timestamps".to_string(), WORKLOAD_ID.to_string()]
         ));
         let (child_stdout, child_stderr) = log_fetcher.get_output_streams();
         assert!(child_stdout.is_none());
@@ -272,7 +278,7 @@ mod tests {
                 args,
                 stdout_option: Some(_),
                 stderr_option: Some(_),
-            }) if cmd == "podman" && *args == vec!["logs".to_string(), "-f".to_string(), "--since".to_string(), "since".to_string(), "--until".to_string(), "until".to_string(), "--tail".to_string(), "10".to_string(), WORKLOAD_ID.to_string(), ]
+            }) if cmd == "podman" && *args == vec!["logs".to_string(), "--timestamps".to_string(), "-f".to_string(), "--since".to_string(), "since".to_string(), "--until".to_string(), "until".to_string(), "--tail".to_string(), "10".to_string(), WORKLOAD_ID.to_string(), ]
         ));
         let (child_stdout, child_stderr) = log_fetcher.get_output_streams();
         assert!(child_stdout.is_none());
@@ -17,16 +17,17 @@ use std::{collections::HashMap, fmt::Display, path::PathBuf, str::FromStr};
 use async_trait::async_trait;
 
 use common::{
-    objects::{AgentName, ExecutionState, WorkloadInstanceName, WorkloadSpec},
+    objects::{AgentName, ExecutionState, WorkloadInstanceName, WorkloadMetrics, WorkloadSpec},
     std_extensions::UnreachableOption,
 };
 
 use crate::{
-    generic_polling_state_checker::GenericPollingStateChecker,
+    generic_event_state_checker::GenericEventStateChecker,
     runtime_connectors::{
-        ReusableWorkloadState, RuntimeConnector, RuntimeError, RuntimeStateGetter, StateChecker,
-        generic_log_fetcher::GenericLogFetcher, log_fetcher::LogFetcher,
-        podman_cli::PodmanStartConfig, runtime_connector::LogRequestOptions,
+        ReusableWorkloadState, RuntimeConnector, RuntimeError, RuntimeStateGetter,
+        StateChangeSubscription, StateChecker, generic_log_fetcher::GenericLogFetcher,
+        log_fetcher::LogFetcher, podman_cli::PodmanStartConfig,
+        runtime_connector::LogRequestOptions,
     },
     workload_state::WorkloadStateSender,
 };
@@ -100,6 +101,27 @@ impl RuntimeStateGetter<PodmanWorkloadId> for PodmanStateGetter {
         );
         exec_state
     }
+
+    // [impl->swdd~podman-state-getter-executes-probe-commands~1]
+    async fn exec_command(
+        &self,
+        workload_id: &PodmanWorkloadId,
+        command: &[String],
+    ) -> Result<(), String> {
+        PodmanCli::exec_command(workload_id.id.as_str(), command).await
+    }
+
+    // [impl->swdd~podman-state-getter-provides-probe-host~1]
+    async fn get_probe_host(&self, workload_id: &PodmanWorkloadId) -> Result<String, String> {
+        Ok(PodmanCli::get_container_address(workload_id.id.as_str())
+            .await?
+            .unwrap_or_else(|| "127.0.0.1".to_string()))
+    }
+
+    // [impl->swdd~podman-state-getter-provides-state-change-events~1]
+    fn subscribe_state_changes(&self) -> Option<StateChangeSubscription> {
+        Some(PodmanCli::subscribe_state_changes())
+    }
 }
 
 impl PodmanRuntime {
@@ -130,7 +152,7 @@ impl PodmanRuntime {
 
 #[async_trait]
 // [impl->swdd~podman-implements-runtime-connector~1]
-impl RuntimeConnector<PodmanWorkloadId, GenericPollingStateChecker> for PodmanRuntime {
+impl RuntimeConnector<PodmanWorkloadId, GenericEventStateChecker> for PodmanRuntime {
     // [impl->swdd~podman-name-returns-podman~1]
     fn name(&self) -> String {
         PODMAN_RUNTIME_NAME.to_string()
@@ -164,7 +186,7 @@ impl RuntimeConnector<PodmanWorkloadId, GenericPollingStateChecker> for PodmanRu
         control_interface_path: Option<PathBuf>,
         update_state_tx: WorkloadStateSender,
         workload_file_path_mappings: HashMap<PathBuf, PathBuf>,
-    ) -> Result<(PodmanWorkloadId, GenericPollingStateChecker), RuntimeError> {
+    ) -> Result<(PodmanWorkloadId, GenericEventStateChecker), RuntimeError> {
         let workload_cfg =
             PodmanRuntimeConfig::try_from(&workload_spec).map_err(RuntimeError::Unsupported)?;
 
@@ -252,7 +274,7 @@ impl RuntimeConnector<PodmanWorkloadId, GenericPollingStateChecker> for PodmanRu
         workload_id: &PodmanWorkloadId,
         workload_spec: WorkloadSpec,
         update_state_tx: WorkloadStateSender,
-    ) -> Result<GenericPollingStateChecker, RuntimeError> {
+    ) -> Result<GenericEventStateChecker, RuntimeError> {
         // [impl->swdd~podman-state-getter-reset-cache~1]
         PodmanCli::reset_ps_cache().await;
 
@@ -261,7 +283,7 @@ impl RuntimeConnector<PodmanWorkloadId, GenericPollingStateChecker> for PodmanRu
             workload_spec.instance_name,
             workload_id.id
         );
-        let checker = GenericPollingStateChecker::start_checker(
+        let checker = GenericEventStateChecker::start_checker(
             &workload_spec,
             workload_id.clone(),
             update_state_tx,
@@ -288,6 +310,16 @@ impl RuntimeConnector<PodmanWorkloadId, GenericPollingStateChecker> for PodmanRu
             .await
             .map_err(|err| RuntimeError::Delete(err.to_string()))
     }
+
+    // [impl->swdd~podman-provides-workload-metrics~1]
+    async fn get_workload_metrics(
+        &self,
+        workload_id: &PodmanWorkloadId,
+    ) -> Result<WorkloadMetrics, RuntimeError> {
+        PodmanCli::get_container_stats(&workload_id.id)
+            .await
+            .map_err(RuntimeError::CollectMetrics)
+    }
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -305,14 +337,17 @@ mod tests {
     use std::str::FromStr;
 
     use common::objects::{
-        AgentName, ExecutionState, WorkloadInstanceName, generate_test_workload_spec_with_param,
+        AgentName, ExecutionState, WorkloadInstanceName, WorkloadMetrics,
+        generate_test_workload_spec_with_param,
     };
     use mockall::Sequence;
 
     use super::PodmanCli;
     use super::PodmanRuntime;
     use super::{PODMAN_RUNTIME_NAME, PodmanStateGetter, PodmanWorkloadId};
-    use crate::runtime_connectors::{RuntimeConnector, RuntimeError, RuntimeStateGetter};
+    use crate::runtime_connectors::{
+        RuntimeConnector, RuntimeError, RuntimeStateGetter, StateChangeSubscription,
+    };
     use crate::test_helper::MOCKALL_CONTEXT_SYNC;
 
     const BUFFER_SIZE: usize = 20;
@@ -494,6 +529,13 @@ mod tests {
         let run_context = PodmanCli::podman_run_context();
         run_context.expect().return_const(Ok("test_id".into()));
 
+        let subscribe_context = PodmanCli::subscribe_state_changes_context();
+        subscribe_context.expect().returning(|| {
+            let (_events_tx, events_rx) = tokio::sync::broadcast::channel(1);
+            let (_connected_tx, connected_rx) = tokio::sync::watch::channel(false);
+            StateChangeSubscription::new(events_rx, connected_rx)
+        });
+
         let mut seq = Sequence::new();
 
         let resest_cache_context = PodmanCli::reset_ps_cache_context();
@@ -762,6 +804,23 @@ mod tests {
         );
     }
 
+    // [utest->swdd~podman-state-getter-provides-state-change-events~1]
+    #[tokio::test]
+    async fn utest_subscribe_state_changes_uses_runtime_events() {
+        let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
+
+        let context = PodmanCli::subscribe_state_changes_context();
+        context.expect().once().returning(|| {
+            let (_events_tx, events_rx) = tokio::sync::broadcast::channel(1);
+            let (_connected_tx, connected_rx) = tokio::sync::watch::channel(true);
+            StateChangeSubscription::new(events_rx, connected_rx)
+        });
+
+        let state_getter = PodmanStateGetter {};
+        let subscription = state_getter.subscribe_state_changes().unwrap();
+        assert!(subscription.is_connected());
+    }
+
     // [utest->swdd~podman-delete-workload-stops-and-removes-workload~1]
     #[tokio::test]
     async fn utest_delete_workload_succeeds() {
@@ -794,4 +853,50 @@ mod tests {
         let res = podman_runtime.delete_workload(&workload_id).await;
         assert_eq!(res, Err(RuntimeError::Delete("simulated error".into())));
     }
+
+    // [utest->swdd~podman-provides-workload-metrics~1]
+    #[tokio::test]
+    async fn utest_get_workload_metrics_succeeds() {
+        let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
+
+        let workload_metrics = WorkloadMetrics {
+            cpu_usage: Some(12),
+            memory_usage: Some(1024),
+            restart_count: 0,
+        };
+
+        let context = PodmanCli::get_container_stats_context();
+        context
+            .expect()
+            .with(mockall::predicate::eq("test_id"))
+            .return_const(Ok(workload_metrics.clone()));
+
+        let workload_id = PodmanWorkloadId {
+            id: "test_id".into(),
+        };
+
+        let podman_runtime = PodmanRuntime {};
+        let res = podman_runtime.get_workload_metrics(&workload_id).await;
+        assert_eq!(res, Ok(workload_metrics));
+    }
+
+    // [utest->swdd~podman-provides-workload-metrics~1]
+    #[tokio::test]
+    async fn utest_get_workload_metrics_fails() {
+        let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
+
+        let context = PodmanCli::get_container_stats_context();
+        context.expect().return_const(Err("simulated error".into()));
+
+        let workload_id = PodmanWorkloadId {
+            id: "test_id".into(),
+        };
+
+        let podman_runtime = PodmanRuntime {};
+        let res = podman_runtime.get_workload_metrics(&workload_id).await;
+        assert_eq!(
+            res,
+            Err(RuntimeError::CollectMetrics("simulated error".into()))
+        );
+    }
 }
@@ -14,6 +14,7 @@
 
 use common::objects::WorkloadSpec;
 
+use crate::runtime_connectors::container_resource_limits;
 use crate::runtime_connectors::podman_cli::PodmanRunConfig;
 
 use super::podman_runtime::PODMAN_RUNTIME_NAME;
@@ -50,10 +51,20 @@ impl TryFrom<&WorkloadSpec> for PodmanRuntimeConfig {
                 workload_spec.runtime
             ));
         }
-        match serde_yaml::from_str(workload_spec.runtime_config.as_str()) {
-            Ok(workload_cfg) => Ok(workload_cfg),
-            Err(e) => Err(e.to_string()),
+        let mut workload_cfg: PodmanRuntimeConfig =
+            serde_yaml::from_str(workload_spec.runtime_config.as_str())
+                .map_err(|e| e.to_string())?;
+
+        // The typed resource limits are appended to take precedence over the command options.
+        // [impl->swdd~podman-create-workload-applies-resource-limits~1]
+        if let Some(resource_limits) = &workload_spec.resources {
+            workload_cfg
+                .command_options
+                .extend(container_resource_limits::to_command_options(
+                    resource_limits,
+                ));
         }
+        Ok(workload_cfg)
     }
 }
 
@@ -67,7 +78,7 @@ impl TryFrom<&WorkloadSpec> for PodmanRuntimeConfig {
 
 #[cfg(test)]
 mod tests {
-    use common::objects::generate_test_workload_spec_with_param;
+    use common::objects::{ResourceLimits, generate_test_workload_spec_with_param};
 
     use super::PodmanRuntimeConfig;
     use crate::runtime_connectors::{
@@ -125,6 +136,35 @@ mod tests {
         );
     }
 
+    // [utest->swdd~podman-create-workload-applies-resource-limits~1]
+    #[test]
+    fn utest_podman_config_appends_resource_limits_to_command_options() {
+        let mut workload_spec = generate_test_workload_spec_with_param(
+            AGENT_NAME.to_string(),
+            WORKLOAD_1_NAME.to_string(),
+            PODMAN_RUNTIME_NAME.to_string(),
+        );
+        workload_spec.runtime_config =
+            "commandOptions: [\"--network=host\"]\nimage: alpine:latest\n".to_string();
+        workload_spec.resources = Some(ResourceLimits {
+            cpu_quota: Some(1500),
+            memory_limit: Some(268435456),
+            pids_limit: Some(100),
+        });
+
+        assert_eq!(
+            PodmanRuntimeConfig::try_from(&workload_spec)
+                .unwrap()
+                .command_options,
+            vec![
+                "--network=host".to_string(),
+                "--cpus=1.500".to_string(),
+                "--memory=268435456".to_string(),
+                "--pids-limit=100".to_string(),
+            ]
+        );
+    }
+
     #[test]
     fn utest_podman_config_to_podman_run_config() {
         let podman_runtime_config = PodmanRuntimeConfig {
@@ -13,7 +13,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use base64::Engine;
-use common::objects::ExecutionState;
+use common::objects::{ExecutionState, WorkloadMetrics};
 #[cfg(test)]
 use mockall::automock;
 use serde::{Deserialize, Deserializer, Serialize};
@@ -21,13 +21,15 @@ use std::{
     collections::HashMap,
     ops::Deref,
     path::PathBuf,
-    sync::Arc,
+    sync::{Arc, OnceLock},
     time::{Duration, Instant},
 };
 use tokio::sync::Mutex;
 
 #[cfg_attr(test, mockall_double::double)]
 use crate::runtime_connectors::cli_command::CliCommand;
+use crate::runtime_connectors::container_stats;
+use crate::runtime_connectors::{RuntimeEventSource, StateChangeEvents, StateChangeSubscription};
 
 const PODMAN_CMD: &str = "podman";
 const PODMAN_PS_CACHE_MAX_AGE: Duration = Duration::from_millis(1000);
@@ -194,6 +196,40 @@ impl From<Result<Vec<PodmanContainerInfo>, String>> for PodmanPsResult {
 
 static LAST_PS_RESULT: TimedPodmanPsResult = TimedPodmanPsResult(Mutex::const_new(Option::None));
 
+#[cfg_attr(test, allow(dead_code))]
+static STATE_CHANGE_EVENTS: OnceLock<StateChangeEvents> = OnceLock::new();
+
+#[derive(Deserialize)]
+struct PodmanEvent {
+    #[serde(rename = "ID")]
+    id: String,
+}
+
+struct PodmanEventSource;
+
+#[async_trait::async_trait]
+impl RuntimeEventSource for PodmanEventSource {
+    // [impl->swdd~podmancli-listens-for-container-events~1]
+    fn events_command(&self) -> (&'static str, Vec<&'static str>) {
+        (
+            PODMAN_CMD,
+            vec!["events", "--filter", "type=container", "--format=json"],
+        )
+    }
+
+    fn parse_workload_id(&self, event: &str) -> Option<String> {
+        serde_json::from_str::<PodmanEvent>(event)
+            .ok()
+            .map(|event| event.id)
+            .filter(|id| !id.is_empty())
+    }
+
+    // [impl->swdd~podmancli-container-events-reset-cache~1]
+    async fn on_state_change(&self) {
+        LAST_PS_RESULT.reset().await;
+    }
+}
+
 pub struct PodmanCli {}
 
 #[cfg_attr(test, automock)]
@@ -202,6 +238,14 @@ impl PodmanCli {
         LAST_PS_RESULT.reset().await;
     }
 
+    // [impl->swdd~podmancli-listens-for-container-events~1]
+    #[cfg_attr(test, allow(dead_code))]
+    pub fn subscribe_state_changes() -> StateChangeSubscription {
+        STATE_CHANGE_EVENTS
+            .get_or_init(|| StateChangeEvents::start(PodmanEventSource))
+            .subscribe()
+    }
+
     pub async fn play_kube(
         general_options: &[String],
         play_options: &[String],
@@ -505,6 +549,48 @@ impl PodmanCli {
         Ok(())
     }
 
+    // [impl->swdd~podman-state-getter-executes-probe-commands~1]
+    pub async fn exec_command(workload_id: &str, command: &[String]) -> Result<(), String> {
+        let mut args = vec!["exec", workload_id];
+        args.extend(command.iter().map(|x| x as &str));
+        CliCommand::new(PODMAN_CMD).args(&args).exec().await?;
+        Ok(())
+    }
+
+    // [impl->swdd~podman-state-getter-provides-probe-host~1]
+    // A container in the host network has no address of its own.
+    pub async fn get_container_address(workload_id: &str) -> Result<Option<String>, String> {
+        let output = CliCommand::new(PODMAN_CMD)
+            .args(&[
+                "inspect",
+                "--format={{range .NetworkSettings.Networks}}{{.IPAddress}} {{end}}",
+                workload_id,
+            ])
+            .exec()
+            .await?;
+        Ok(output.split_whitespace().next().map(str::to_owned))
+    }
+
+    // [impl->swdd~podman-provides-workload-metrics~1]
+    pub async fn get_container_stats(workload_id: &str) -> Result<WorkloadMetrics, String> {
+        let output = CliCommand::new(PODMAN_CMD)
+            .args(&["stats", "--no-stream", "--format=json", workload_id])
+            .exec()
+            .await?;
+
+        let stats: Vec<PodmanContainerStats> = serde_json::from_str(&output)
+            .map_err(|err| format!("Could not parse podman stats output: '{err}'"))?;
+        let stats = stats
+            .into_iter()
+            .next()
+            .ok_or_else(|| format!("No stats found for container '{workload_id}'."))?;
+
+        Ok(container_stats::to_workload_metrics(
+            &stats.cpu_percent,
+            &stats.mem_usage,
+        ))
+    }
+
     pub async fn remove_workloads_by_id(workload_id: &str) -> Result<(), String> {
         // Containers may have "--rm" flag -> it can happen, that they already do not exist.
         let args = vec!["stop", "--ignore", workload_id];
@@ -526,6 +612,12 @@ struct DataLabel {
     data: String,
 }
 
+#[derive(Deserialize, Debug)]
+struct PodmanContainerStats {
+    cpu_percent: String,
+    mem_usage: String,
+}
+
 #[derive(Deserialize, Serialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 struct PodmanContainerInfo {
@@ -560,10 +652,11 @@ where
 #[cfg(test)]
 mod tests {
     use super::{ContainerState, PodmanCli, PodmanPsCache};
+    use crate::runtime_connectors::RuntimeEventSource;
 
     use super::PodmanContainerInfo;
     use crate::test_helper::MOCKALL_CONTEXT_SYNC;
-    use common::objects::ExecutionState;
+    use common::objects::{ExecutionState, WorkloadMetrics};
     use common::test_utils::serialize_as_map;
     use serde::Serialize;
     use std::collections::HashMap;
@@ -2114,6 +2207,57 @@ mod tests {
         assert!(matches!(res, Err(msg) if msg == SAMPLE_ERROR_MESSAGE));
     }
 
+    // [utest->swdd~podman-state-getter-executes-probe-commands~1]
+    #[tokio::test]
+    async fn utest_exec_command_success() {
+        let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
+        super::CliCommand::reset();
+
+        super::CliCommand::new_expect(
+            "podman",
+            super::CliCommand::default()
+                .expect_args(&["exec", "test_id", "cat", "/tmp/healthy"])
+                .exec_returns(Ok("".into())),
+        );
+
+        let res =
+            PodmanCli::exec_command("test_id", &["cat".to_string(), "/tmp/healthy".to_string()])
+                .await;
+
+        assert!(matches!(res, Ok(..)));
+    }
+
+    // [utest->swdd~podman-state-getter-provides-probe-host~1]
+    #[tokio::test]
+    async fn utest_get_container_address() {
+        let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
+        super::CliCommand::reset();
+
+        let inspect_args = [
+            "inspect",
+            "--format={{range .NetworkSettings.Networks}}{{.IPAddress}} {{end}}",
+            "test_id",
+        ];
+        super::CliCommand::new_expect(
+            "podman",
+            super::CliCommand::default()
+                .expect_args(&inspect_args)
+                .exec_returns(Ok("10.88.0.5 \n".into())),
+        );
+        super::CliCommand::new_expect(
+            "podman",
+            super::CliCommand::default()
+                .expect_args(&inspect_args)
+                .exec_returns(Ok(" \n".into())),
+        );
+
+        assert_eq!(
+            PodmanCli::get_container_address("test_id").await,
+            Ok(Some("10.88.0.5".to_string()))
+        );
+        assert_eq!(PodmanCli::get_container_address("test_id").await, Ok(None));
+    }
+
     #[tokio::test]
     async fn utest_remove_workloads_by_id_stop_failed() {
         let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
@@ -2180,6 +2324,65 @@ mod tests {
         assert_eq!(res, Ok(()));
     }
 
+    // [utest->swdd~podman-provides-workload-metrics~1]
+    #[tokio::test]
+    async fn utest_get_container_stats_success() {
+        let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
+        super::CliCommand::reset();
+
+        super::CliCommand::new_expect(
+            "podman",
+            super::CliCommand::default()
+                .expect_args(&["stats", "--no-stream", "--format=json", "test_id"])
+                .exec_returns(Ok(
+                    r#"[{"id":"test_id","cpu_percent":"12.34%","mem_usage":"1.5MB / 16.4GB"}]"#
+                        .to_string(),
+                )),
+        );
+
+        let res = PodmanCli::get_container_stats("test_id").await;
+        assert_eq!(
+            res,
+            Ok(WorkloadMetrics {
+                cpu_usage: Some(12),
+                memory_usage: Some(1_500_000),
+                restart_count: 0,
+            })
+        );
+    }
+
+    // [utest->swdd~podman-provides-workload-metrics~1]
+    #[tokio::test]
+    async fn utest_get_container_stats_no_stats() {
+        let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
+        super::CliCommand::reset();
+
+        super::CliCommand::new_expect(
+            "podman",
+            super::CliCommand::default()
+                .expect_args(&["stats", "--no-stream", "--format=json", "test_id"])
+                .exec_returns(Ok("[]".to_string())),
+        );
+
+        let res = PodmanCli::get_container_stats("test_id").await;
+        assert!(res.is_err());
+    }
+
+    // [utest->swdd~podmancli-listens-for-container-events~1]
+    #[test]
+    fn utest_podman_event_source_parses_container_id() {
+        let event_source = super::PodmanEventSource;
+
+        assert_eq!(
+            event_source.parse_workload_id(
+                r#"{"ID":"test_id","Image":"alpine","Name":"test","Status":"died","Type":"container"}"#
+            ),
+            Some("test_id".to_string())
+        );
+        assert_eq!(event_source.parse_workload_id(r#"{"ID":""}"#), None);
+        assert_eq!(event_source.parse_workload_id("no json"), None);
+    }
+
     #[derive(Serialize, Clone, Default)]
     #[serde(rename_all = "PascalCase")]
     struct TestPodmanContainerInfo<'a> {
@@ -409,6 +409,22 @@ impl RuntimeConnector<PodmanKubeWorkloadId, GenericPollingStateChecker> for Podm
             ));
         }
 
+        // [impl->swdd~podman-kube-rejects-health-probes~1]
+        if workload_spec.probes.is_some() {
+            return Err(RuntimeError::Unsupported(
+                "Health probes are not supported for podman-kube runtime. Use the probes of the containers in the manifest instead."
+                    .to_string(),
+            ));
+        }
+
+        // [impl->swdd~podman-kube-rejects-resource-limits~1]
+        if workload_spec.resources.is_some() {
+            return Err(RuntimeError::Unsupported(
+                "Resource limits are not supported for podman-kube runtime. Set them in the manifest instead."
+                    .to_string(),
+            ));
+        }
+
         let mut workload_config =
             PodmanKubeRuntimeConfig::try_from(&workload_spec).map_err(RuntimeError::Unsupported)?;
 
@@ -695,7 +711,9 @@ mod tests {
 
     use std::fmt::Display;
 
-    use common::objects::{ExecutionState, WorkloadInstanceName};
+    use common::objects::{
+        ExecutionState, Probe, Probes, ResourceLimits, TcpSocketProbe, WorkloadInstanceName,
+    };
     use mockall::{lazy_static, predicate::eq};
 
     use super::PodmanCli;
@@ -1065,6 +1083,59 @@ mod tests {
         );
     }
 
+    // [utest->swdd~podman-kube-rejects-resource-limits~1]
+    #[tokio::test]
+    async fn utest_create_workload_unsupported_resource_limits_error() {
+        let runtime = PodmanKubeRuntime {};
+
+        let mut workload_spec = generate_test_workload_spec_with_param(
+            SAMPLE_AGENT.to_string(),
+            SAMPLE_WORKLOAD_1.to_string(),
+            PODMAN_KUBE_RUNTIME_NAME.to_string(),
+        );
+        workload_spec.resources = Some(ResourceLimits {
+            cpu_quota: Some(500),
+            ..Default::default()
+        });
+
+        let (sender, _) = tokio::sync::mpsc::channel(1);
+        let result = runtime
+            .create_workload(workload_spec, None, None, sender, Default::default())
+            .await;
+        assert!(
+            matches!(&result, Err(RuntimeError::Unsupported(_))),
+            "Expected 'RuntimeError::Unsupported', Got: {result:?}"
+        );
+    }
+
+    // [utest->swdd~podman-kube-rejects-health-probes~1]
+    #[tokio::test]
+    async fn utest_create_workload_unsupported_health_probes_error() {
+        let runtime = PodmanKubeRuntime {};
+
+        let mut workload_spec = generate_test_workload_spec_with_param(
+            SAMPLE_AGENT.to_string(),
+            SAMPLE_WORKLOAD_1.to_string(),
+            PODMAN_KUBE_RUNTIME_NAME.to_string(),
+        );
+        workload_spec.probes = Some(Probes {
+            liveness: Some(Probe {
+                tcp_socket: Some(TcpSocketProbe { port: 8080 }),
+                ..Default::default()
+            }),
+            readiness: None,
+        });
+
+        let (sender, _) = tokio::sync::mpsc::channel(1);
+        let result = runtime
+            .create_workload(workload_spec, None, None, sender, Default::default())
+            .await;
+        assert!(
+            matches!(&result, Err(RuntimeError::Unsupported(_))),
+            "Expected 'RuntimeError::Unsupported', Got: {result:?}"
+        );
+    }
+
     // [utest->swdd~podman-kube-state-getter-reset-cache~1]
     #[tokio::test]
     async fn utest_state_getter_resets_cache() {
@@ -0,0 +1,924 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    process::Stdio,
+    str::FromStr,
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use nix::{
+    errno::Errno,
+    sys::signal::{Signal, killpg},
+    unistd::Pid,
+};
+use tokio::process::Command;
+
+use common::objects::{AgentName, ExecutionState, WorkloadInstanceName, WorkloadSpec};
+
+use crate::{
+    generic_polling_state_checker::GenericPollingStateChecker,
+    runtime_connectors::{
+        ReusableWorkloadState, RuntimeConnector, RuntimeError, RuntimeStateGetter, StateChecker,
+        file_log_fetcher::FileLogFetcher, generic_log_fetcher::GenericLogFetcher,
+        log_fetcher::LogFetcher, runtime_connector::LogRequestOptions,
+    },
+    workload_state::WorkloadStateSender,
+};
+
+use super::process_runtime_config::ProcessRuntimeConfig;
+
+pub const PROCESS_RUNTIME_NAME: &str = "process";
+
+const PROCESS_RUNTIME_FOLDER: &str = "process";
+const PID_FILE: &str = "pid";
+const EXIT_CODE_FILE: &str = "exit_code";
+const STDOUT_LOG_FILE: &str = "stdout.log";
+const STDERR_LOG_FILE: &str = "stderr.log";
+
+const SHELL: &str = "/bin/sh";
+const PROCESS_WRAPPER_NAME: &str = "ank-process-wrapper";
+// The wrapper waits for the workload process and persists its exit code, such that the
+// exit code is also available to an agent which did not spawn the process itself.
+const PROCESS_WRAPPER_SCRIPT: &str = r#"exit_code_file="$1"; shift; "$@"; exit_code=$?; echo "$exit_code" > "$exit_code_file.tmp" && mv "$exit_code_file.tmp" "$exit_code_file""#;
+
+const PROCESS_STOP_TIMEOUT: Duration = Duration::from_secs(10);
+const PROCESS_STOP_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+#[derive(Debug, Clone)]
+pub struct ProcessRuntime {
+    process_folder: PathBuf,
+}
+
+#[derive(Debug, Clone)]
+pub struct ProcessStateGetter {
+    workload_folder: PathBuf,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProcessWorkloadId {
+    pub id: String,
+}
+
+impl Display for ProcessWorkloadId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.id.to_owned())
+    }
+}
+
+impl FromStr for ProcessWorkloadId {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(ProcessWorkloadId { id: s.to_string() })
+    }
+}
+
+#[async_trait]
+// [impl->swdd~process-implements-runtime-state-getter~1]
+impl RuntimeStateGetter<ProcessWorkloadId> for ProcessStateGetter {
+    async fn get_state(&self, workload_id: &ProcessWorkloadId) -> ExecutionState {
+        log::trace!("Getting the state for the workload '{}'", workload_id.id);
+
+        let exec_state = read_execution_state(&self.workload_folder).await;
+
+        log::trace!(
+            "Returning the state '{}' for the workload '{}'",
+            exec_state,
+            workload_id.id
+        );
+        exec_state
+    }
+
+    // [impl->swdd~process-state-getter-executes-probe-commands~1]
+    // The process shares the host with the agent, so the command is executed on the host.
+    async fn exec_command(
+        &self,
+        _workload_id: &ProcessWorkloadId,
+        command: &[String],
+    ) -> Result<(), String> {
+        let (program, args) = command
+            .split_first()
+            .ok_or_else(|| "The probe command is empty".to_string())?;
+        let status = Command::new(program)
+            .args(args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .kill_on_drop(true)
+            .status()
+            .await
+            .map_err(|err| format!("Could not execute '{program}': '{err}'"))?;
+        if status.success() {
+            Ok(())
+        } else {
+            Err(format!("The command '{program}' failed with '{status}'"))
+        }
+    }
+}
+
+// [impl->swdd~process-state-getter-maps-state~1]
+async fn read_execution_state(workload_folder: &Path) -> ExecutionState {
+    match tokio::fs::read_to_string(workload_folder.join(EXIT_CODE_FILE)).await {
+        Ok(exit_code) => match exit_code.trim().parse::<i32>() {
+            Ok(0) => ExecutionState::succeeded(),
+            Ok(exit_code) => ExecutionState::failed(format!("Exit code: '{exit_code}'")),
+            Err(err) => {
+                log::warn!("Could not parse the exit code '{exit_code}': '{err}'");
+                ExecutionState::unknown("Error parsing the exit code of the process.")
+            }
+        },
+        Err(err) if err.kind() == ErrorKind::NotFound => match read_pid_file(workload_folder).await
+        {
+            Ok(process) if process.is_alive() => ExecutionState::running(),
+            Ok(_) => ExecutionState::lost(),
+            Err(err) => {
+                log::warn!("Could not read the pid file: '{err}'");
+                ExecutionState::lost()
+            }
+        },
+        Err(err) => {
+            log::warn!("Could not read the exit code file: '{err}'");
+            ExecutionState::unknown("Error reading the exit code of the process.")
+        }
+    }
+}
+
+// The start time of the process is stored next to its pid, such that a reused pid
+// is not mistaken for the process of the workload.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct ProcessIdentity {
+    pid: i32,
+    start_time: u64,
+}
+
+impl ProcessIdentity {
+    fn of(pid: i32) -> Result<Self, String> {
+        let start_time = ProcStat::read(pid)
+            .map(|stat| stat.start_time)
+            .ok_or_else(|| format!("Could not read the start time of process '{pid}'"))?;
+        Ok(Self { pid, start_time })
+    }
+
+    // A zombie process has already terminated and is only waiting to be reaped.
+    fn is_alive(&self) -> bool {
+        ProcStat::read(self.pid).is_some_and(|stat| {
+            stat.start_time == self.start_time && stat.state != 'Z' && stat.state != 'X'
+        })
+    }
+
+    // The pid of the process group leader is not reused as long as the process group exists.
+    // Thus, a group without its leader still belongs to the workload.
+    fn owns_process_group(&self) -> bool {
+        ProcStat::read(self.pid).is_none_or(|stat| stat.start_time == self.start_time)
+    }
+}
+
+impl Display for ProcessIdentity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.pid, self.start_time)
+    }
+}
+
+impl FromStr for ProcessIdentity {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid_pid_file = || format!("Invalid pid file content '{}'", s.trim());
+        let (pid, start_time) = s.trim().split_once(' ').ok_or_else(invalid_pid_file)?;
+        Ok(Self {
+            pid: pid.parse().map_err(|_| invalid_pid_file())?,
+            start_time: start_time.parse().map_err(|_| invalid_pid_file())?,
+        })
+    }
+}
+
+struct ProcStat {
+    state: char,
+    start_time: u64,
+}
+
+impl ProcStat {
+    // See proc_pid_stat(5): the state is the 3rd and the start time the 22nd field.
+    fn read(pid: i32) -> Option<Self> {
+        let stat = std::fs::read_to_string(format!("/proc/{pid}/stat")).ok()?;
+        let (_, fields) = stat.rsplit_once(')')?;
+        let mut fields = fields.split_whitespace();
+        let state = fields.next()?.chars().next()?;
+        let start_time = fields.nth(18)?.parse().ok()?;
+        Some(Self { state, start_time })
+    }
+}
+
+async fn read_pid_file(workload_folder: &Path) -> Result<ProcessIdentity, String> {
+    tokio::fs::read_to_string(workload_folder.join(PID_FILE))
+        .await
+        .map_err(|err| err.to_string())?
+        .parse()
+}
+
+impl ProcessRuntime {
+    pub fn new(run_folder: PathBuf) -> Self {
+        Self {
+            process_folder: run_folder.join(PROCESS_RUNTIME_FOLDER),
+        }
+    }
+
+    fn workload_folder(&self, workload_id: &ProcessWorkloadId) -> PathBuf {
+        self.process_folder.join(&workload_id.id)
+    }
+
+    // [impl->swdd~process-create-workload-spawns-process~1]
+    async fn spawn_process(
+        &self,
+        workload_folder: &Path,
+        workload_cfg: ProcessRuntimeConfig,
+    ) -> Result<ProcessIdentity, String> {
+        tokio::fs::create_dir_all(workload_folder)
+            .await
+            .map_err(|err| format!("Could not create the workload folder: '{err}'"))?;
+
+        let exit_code_file = workload_folder.join(EXIT_CODE_FILE);
+        match tokio::fs::remove_file(&exit_code_file).await {
+            Ok(()) => {}
+            Err(err) if err.kind() == ErrorKind::NotFound => {}
+            Err(err) => return Err(format!("Could not remove the old exit code: '{err}'")),
+        }
+
+        let stdout_log = open_log_file(&workload_folder.join(STDOUT_LOG_FILE))?;
+        let stderr_log = open_log_file(&workload_folder.join(STDERR_LOG_FILE))?;
+
+        let mut command = Command::new(SHELL);
+        command
+            .arg("-c")
+            .arg(PROCESS_WRAPPER_SCRIPT)
+            .arg(PROCESS_WRAPPER_NAME)
+            .arg(&exit_code_file)
+            .arg(&workload_cfg.command)
+            .args(&workload_cfg.args)
+            .envs(&workload_cfg.env)
+            .stdin(Stdio::null())
+            .stdout(stdout_log)
+            .stderr(stderr_log)
+            // A dedicated process group allows stopping the workload together with the wrapper.
+            .process_group(0);
+        if let Some(working_dir) = &workload_cfg.working_dir {
+            command.current_dir(working_dir);
+        }
+
+        let child = command
+            .spawn()
+            .map_err(|err| format!("Could not spawn '{}': '{err}'", workload_cfg.command))?;
+        let pid = child
+            .id()
+            .ok_or_else(|| format!("Could not get the pid of '{}'", workload_cfg.command))?
+            as i32;
+        // The child is not awaited, so it is not reaped before its start time is read.
+        let process = ProcessIdentity::of(pid)?;
+
+        // [impl->swdd~process-create-workload-writes-pid-file~1]
+        tokio::fs::write(workload_folder.join(PID_FILE), process.to_string())
+            .await
+            .map_err(|err| format!("Could not write the pid file: '{err}'"))?;
+
+        Ok(process)
+    }
+}
+
+fn open_log_file(path: &Path) -> Result<std::fs::File, String> {
+    std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|err| format!("Could not open the log file '{}': '{err}'", path.display()))
+}
+
+#[async_trait]
+// [impl->swdd~process-implements-runtime-connector~1]
+impl RuntimeConnector<ProcessWorkloadId, GenericPollingStateChecker> for ProcessRuntime {
+    // [impl->swdd~process-name-returns-process~1]
+    fn name(&self) -> String {
+        PROCESS_RUNTIME_NAME.to_string()
+    }
+
+    // [impl->swdd~process-list-of-existing-workloads-uses-pid-files~1]
+    async fn get_reusable_workloads(
+        &self,
+        agent_name: &AgentName,
+    ) -> Result<Vec<ReusableWorkloadState>, RuntimeError> {
+        let mut entries = match tokio::fs::read_dir(&self.process_folder).await {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(RuntimeError::List(err.to_string())),
+        };
+
+        let mut workload_states = Vec::<ReusableWorkloadState>::default();
+        while let Some(entry) = entries
+            .next_entry()
+            .await
+            .map_err(|err| RuntimeError::List(err.to_string()))?
+        {
+            let workload_id = entry.file_name().to_string_lossy().to_string();
+            let Ok(instance_name) = WorkloadInstanceName::try_from(workload_id.as_str()) else {
+                log::debug!("Ignoring unexpected entry '{workload_id}' in the process folder");
+                continue;
+            };
+            if instance_name.agent_name() != agent_name.get()
+                || !entry.path().join(PID_FILE).exists()
+            {
+                continue;
+            }
+
+            let execution_state = read_execution_state(&entry.path()).await;
+            workload_states.push(ReusableWorkloadState::new(
+                instance_name,
+                execution_state,
+                Some(workload_id),
+            ));
+        }
+
+        log::debug!(
+            "Found {} reusable workload(s): '{:?}'",
+            workload_states.len(),
+            &workload_states
+        );
+
+        Ok(workload_states)
+    }
+
+    // [impl->swdd~process-create-workload-spawns-process~1]
+    // [impl->swdd~process-create-workload-adopts-running-process~1]
+    async fn create_workload(
+        &self,
+        workload_spec: WorkloadSpec,
+        reusable_workload_id: Option<ProcessWorkloadId>,
+        control_interface_path: Option<PathBuf>,
+        update_state_tx: WorkloadStateSender,
+        workload_file_path_mappings: HashMap<PathBuf, PathBuf>,
+    ) -> Result<(ProcessWorkloadId, GenericPollingStateChecker), RuntimeError> {
+        let workload_cfg =
+            ProcessRuntimeConfig::try_from(&workload_spec).map_err(RuntimeError::Unsupported)?;
+
+        if control_interface_path.is_some() || !workload_file_path_mappings.is_empty() {
+            log::warn!(
+                "The process runtime does not mount the control interface or workload files into '{}'.",
+                workload_spec.instance_name
+            );
+        }
+
+        // [impl->swdd~process-rejects-resource-limits~1]
+        if workload_spec.resources.is_some() {
+            return Err(RuntimeError::Unsupported(
+                "Resource limits are not supported for process runtime.".to_string(),
+            ));
+        }
+
+        let workload_id = ProcessWorkloadId {
+            id: workload_spec.instance_name.to_string(),
+        };
+        let workload_folder = self.workload_folder(&workload_id);
+
+        let is_running = match &reusable_workload_id {
+            Some(_) => read_execution_state(&workload_folder).await == ExecutionState::running(),
+            None => false,
+        };
+
+        if is_running {
+            log::debug!(
+                "Adopting the still running process of workload '{}'",
+                workload_spec.instance_name
+            );
+        } else {
+            let process = self
+                .spawn_process(&workload_folder, workload_cfg)
+                .await
+                .map_err(RuntimeError::Create)?;
+            log::debug!(
+                "The workload '{}' has been created with pid '{}'",
+                workload_spec.instance_name,
+                process.pid
+            );
+        }
+
+        let state_checker = self
+            .start_checker(&workload_id, workload_spec, update_state_tx)
+            .await?;
+
+        // [impl->swdd~process-create-workload-returns-workload-id~1]
+        Ok((workload_id, state_checker))
+    }
+
+    async fn get_workload_id(
+        &self,
+        instance_name: &WorkloadInstanceName,
+    ) -> Result<ProcessWorkloadId, RuntimeError> {
+        let workload_id = ProcessWorkloadId {
+            id: instance_name.to_string(),
+        };
+        if self.workload_folder(&workload_id).join(PID_FILE).exists() {
+            Ok(workload_id)
+        } else {
+            Err(RuntimeError::List(format!(
+                "No process found for workload '{instance_name}'"
+            )))
+        }
+    }
+
+    // [impl->swdd~process-start-checker-starts-process-state-checker~1]
+    async fn start_checker(
+        &self,
+        workload_id: &ProcessWorkloadId,
+        workload_spec: WorkloadSpec,
+        update_state_tx: WorkloadStateSender,
+    ) -> Result<GenericPollingStateChecker, RuntimeError> {
+        log::debug!(
+            "Starting the checker for the workload '{}' with internal id '{}'",
+            workload_spec.instance_name,
+            workload_id.id
+        );
+        let checker = GenericPollingStateChecker::start_checker(
+            &workload_spec,
+            workload_id.clone(),
+            update_state_tx,
+            ProcessStateGetter {
+                workload_folder: self.workload_folder(workload_id),
+            },
+        );
+        Ok(checker)
+    }
+
+    fn get_log_fetcher(
+        &self,
+        workload_id: ProcessWorkloadId,
+        options: &LogRequestOptions,
+    ) -> Result<Box<dyn LogFetcher + Send>, RuntimeError> {
+        let workload_folder = self.workload_folder(&workload_id);
+        let file_log_fetcher = FileLogFetcher::new(
+            &workload_folder.join(STDOUT_LOG_FILE),
+            &workload_folder.join(STDERR_LOG_FILE),
+            options,
+        );
+        let log_fetcher = GenericLogFetcher::new(file_log_fetcher);
+        Ok(Box::new(log_fetcher))
+    }
+
+    // [impl->swdd~process-delete-workload-stops-and-removes-workload~1]
+    async fn delete_workload(&self, workload_id: &ProcessWorkloadId) -> Result<(), RuntimeError> {
+        log::debug!("Deleting workload with id '{}'", workload_id.id);
+        let workload_folder = self.workload_folder(workload_id);
+
+        match read_pid_file(&workload_folder).await {
+            Ok(process) => stop_process_group(process)
+                .await
+                .map_err(RuntimeError::Delete)?,
+            Err(err) => log::debug!("No process to stop for '{}': '{err}'", workload_id.id),
+        }
+
+        match tokio::fs::remove_dir_all(&workload_folder).await {
+            Ok(()) => Ok(()),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(RuntimeError::Delete(err.to_string())),
+        }
+    }
+}
+
+async fn stop_process_group(process: ProcessIdentity) -> Result<(), String> {
+    let pid = process.pid;
+    if !process.owns_process_group() {
+        log::debug!("The pid '{pid}' has been reused by another process, nothing to stop");
+        return Ok(());
+    }
+    if !is_process_group_alive(pid)? {
+        return Ok(());
+    }
+
+    send_signal_to_group(pid, Signal::SIGTERM)?;
+    let mut waited = Duration::ZERO;
+    while is_process_group_alive(pid)? {
+        if waited >= PROCESS_STOP_TIMEOUT {
+            log::debug!("Process group '{pid}' did not stop in time, killing it");
+            return send_signal_to_group(pid, Signal::SIGKILL);
+        }
+        tokio::time::sleep(PROCESS_STOP_POLL_INTERVAL).await;
+        waited += PROCESS_STOP_POLL_INTERVAL;
+    }
+    Ok(())
+}
+
+// Sending no signal only checks if any process of the group still exists.
+fn is_process_group_alive(pid: i32) -> Result<bool, String> {
+    match killpg(Pid::from_raw(pid), None) {
+        Ok(()) => Ok(true),
+        Err(Errno::ESRCH) => Ok(false),
+        Err(err) => Err(format!("Could not check process group '{pid}': '{err}'")),
+    }
+}
+
+fn send_signal_to_group(pid: i32, signal: Signal) -> Result<(), String> {
+    match killpg(Pid::from_raw(pid), signal) {
+        Ok(()) | Err(Errno::ESRCH) => Ok(()),
+        Err(err) => Err(format!(
+            "Could not send '{signal}' to process '{pid}': '{err}'"
+        )),
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+// [utest->swdd~agent-functions-required-by-runtime-connector~1]
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, os::unix::process::CommandExt, path::Path, time::Duration};
+
+    use common::objects::{
+        AgentName, ExecutionState, ResourceLimits, WorkloadInstanceName,
+        generate_test_workload_spec_with_param,
+    };
+
+    use super::{
+        EXIT_CODE_FILE, PID_FILE, PROCESS_RUNTIME_FOLDER, PROCESS_RUNTIME_NAME, ProcessIdentity,
+        ProcessRuntime, ProcessStateGetter, ProcessWorkloadId, STDOUT_LOG_FILE,
+    };
+    use crate::runtime_connectors::{RuntimeConnector, RuntimeError, RuntimeStateGetter};
+
+    const BUFFER_SIZE: usize = 20;
+
+    const AGENT_NAME: &str = "agent_x";
+    const WORKLOAD_1_NAME: &str = "workload1";
+    const WORKLOAD_ID: &str =
+        "workload1.b79606fb3afea5bd1609ed40b622142f1c98125abcfe89a76a661b0e8e343910.agent_x";
+
+    // Pid which is never a running process as it is above the maximum pid on Linux
+    const NOT_RUNNING_PROCESS: ProcessIdentity = ProcessIdentity {
+        pid: i32::MAX,
+        start_time: 0,
+    };
+
+    fn prepare_workload_folder(
+        run_folder: &Path,
+        process: ProcessIdentity,
+        exit_code: Option<&str>,
+    ) {
+        let workload_folder = run_folder.join(PROCESS_RUNTIME_FOLDER).join(WORKLOAD_ID);
+        std::fs::create_dir_all(&workload_folder).unwrap();
+        std::fs::write(workload_folder.join(PID_FILE), process.to_string()).unwrap();
+        if let Some(exit_code) = exit_code {
+            std::fs::write(workload_folder.join(EXIT_CODE_FILE), exit_code).unwrap();
+        }
+    }
+
+    async fn get_state(run_folder: &Path) -> ExecutionState {
+        ProcessStateGetter {
+            workload_folder: run_folder.join(PROCESS_RUNTIME_FOLDER).join(WORKLOAD_ID),
+        }
+        .get_state(&ProcessWorkloadId {
+            id: WORKLOAD_ID.to_string(),
+        })
+        .await
+    }
+
+    // [utest->swdd~process-name-returns-process~1]
+    #[test]
+    fn utest_name_process() {
+        let process_runtime = ProcessRuntime::new("/tmp/run".into());
+        assert_eq!(process_runtime.name(), "process".to_string());
+    }
+
+    // [utest->swdd~process-state-getter-maps-state~1]
+    #[tokio::test]
+    async fn utest_state_getter_maps_exit_codes() {
+        let tmpdir = tempfile::tempdir().unwrap();
+
+        prepare_workload_folder(tmpdir.path(), NOT_RUNNING_PROCESS, Some("0\n"));
+        assert_eq!(get_state(tmpdir.path()).await, ExecutionState::succeeded());
+
+        prepare_workload_folder(tmpdir.path(), NOT_RUNNING_PROCESS, Some("3\n"));
+        assert_eq!(
+            get_state(tmpdir.path()).await,
+            ExecutionState::failed("Exit code: '3'")
+        );
+
+        prepare_workload_folder(tmpdir.path(), NOT_RUNNING_PROCESS, Some("garbage"));
+        assert_eq!(
+            get_state(tmpdir.path()).await,
+            ExecutionState::unknown("Error parsing the exit code of the process.")
+        );
+    }
+
+    // [utest->swdd~process-state-getter-executes-probe-commands~1]
+    #[tokio::test]
+    async fn utest_state_getter_executes_probe_command_on_host() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let state_getter = ProcessStateGetter {
+            workload_folder: tmpdir.path().to_path_buf(),
+        };
+        let workload_id = ProcessWorkloadId {
+            id: WORKLOAD_ID.to_string(),
+        };
+        let command =
+            |script: &str| vec!["/bin/sh".to_string(), "-c".to_string(), script.to_string()];
+
+        assert_eq!(
+            state_getter
+                .exec_command(&workload_id, &command("exit 0"))
+                .await,
+            Ok(())
+        );
+        assert!(
+            state_getter
+                .exec_command(&workload_id, &command("exit 1"))
+                .await
+                .is_err()
+        );
+        assert!(state_getter.exec_command(&workload_id, &[]).await.is_err());
+    }
+
+    // [utest->swdd~process-state-getter-maps-state~1]
+    #[tokio::test]
+    async fn utest_state_getter_maps_pid_liveness() {
+        let tmpdir = tempfile::tempdir().unwrap();
+
+        let own_process = ProcessIdentity::of(std::process::id() as i32).unwrap();
+        prepare_workload_folder(tmpdir.path(), own_process, None);
+        assert_eq!(get_state(tmpdir.path()).await, ExecutionState::running());
+
+        prepare_workload_folder(tmpdir.path(), NOT_RUNNING_PROCESS, None);
+        assert_eq!(get_state(tmpdir.path()).await, ExecutionState::lost());
+    }
+
+    // [utest->swdd~process-state-getter-maps-state~1]
+    #[tokio::test]
+    async fn utest_state_getter_detects_reused_pid() {
+        let tmpdir = tempfile::tempdir().unwrap();
+
+        let own_process = ProcessIdentity::of(std::process::id() as i32).unwrap();
+        prepare_workload_folder(
+            tmpdir.path(),
+            ProcessIdentity {
+                pid: own_process.pid,
+                start_time: own_process.start_time + 1,
+            },
+            None,
+        );
+        assert_eq!(get_state(tmpdir.path()).await, ExecutionState::lost());
+    }
+
+    // [utest->swdd~process-list-of-existing-workloads-uses-pid-files~1]
+    #[tokio::test]
+    async fn utest_get_reusable_workloads_reads_pid_files() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        prepare_workload_folder(tmpdir.path(), NOT_RUNNING_PROCESS, Some("0"));
+        std::fs::create_dir_all(
+            tmpdir
+                .path()
+                .join(PROCESS_RUNTIME_FOLDER)
+                .join("workload2.1234.other_agent"),
+        )
+        .unwrap();
+
+        let process_runtime = ProcessRuntime::new(tmpdir.path().to_path_buf());
+        let res = process_runtime
+            .get_reusable_workloads(&AgentName::from(AGENT_NAME))
+            .await
+            .unwrap();
+
+        assert_eq!(res.len(), 1);
+        assert_eq!(
+            res[0].workload_state.instance_name,
+            WorkloadInstanceName::try_from(WORKLOAD_ID).unwrap()
+        );
+        assert_eq!(
+            res[0].workload_state.execution_state,
+            ExecutionState::succeeded()
+        );
+        assert_eq!(res[0].workload_id, Some(WORKLOAD_ID.to_string()));
+    }
+
+    // [utest->swdd~process-list-of-existing-workloads-uses-pid-files~1]
+    #[tokio::test]
+    async fn utest_get_reusable_workloads_empty_without_process_folder() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let process_runtime = ProcessRuntime::new(tmpdir.path().to_path_buf());
+
+        assert!(
+            process_runtime
+                .get_reusable_workloads(&AgentName::from(AGENT_NAME))
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    // [utest->swdd~process-create-workload-spawns-process~1]
+    // [utest->swdd~process-create-workload-writes-pid-file~1]
+    // [utest->swdd~process-create-workload-returns-workload-id~1]
+    // [utest->swdd~process-delete-workload-stops-and-removes-workload~1]
+    #[tokio::test]
+    async fn utest_create_and_delete_workload_success() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let process_runtime = ProcessRuntime::new(tmpdir.path().to_path_buf());
+
+        let mut workload_spec = generate_test_workload_spec_with_param(
+            AGENT_NAME.to_string(),
+            WORKLOAD_1_NAME.to_string(),
+            PROCESS_RUNTIME_NAME.to_string(),
+        );
+        workload_spec.runtime_config =
+            "command: /bin/sh\nargs: [\"-c\", \"echo $GREETING; exit 3\"]\nenv:\n  GREETING: hello"
+                .to_string();
+
+        let (state_sender, _state_receiver) = tokio::sync::mpsc::channel(BUFFER_SIZE);
+        let (workload_id, _checker) = process_runtime
+            .create_workload(
+                workload_spec.clone(),
+                None,
+                None,
+                state_sender,
+                HashMap::default(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(workload_id.id, workload_spec.instance_name.to_string());
+        let workload_folder = tmpdir
+            .path()
+            .join(PROCESS_RUNTIME_FOLDER)
+            .join(&workload_id.id);
+        assert!(workload_folder.join(PID_FILE).exists());
+
+        let mut retries = 50;
+        while !workload_folder.join(EXIT_CODE_FILE).exists() && retries > 0 {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            retries -= 1;
+        }
+        assert_eq!(
+            super::read_execution_state(&workload_folder).await,
+            ExecutionState::failed("Exit code: '3'")
+        );
+        assert_eq!(
+            std::fs::read_to_string(workload_folder.join(STDOUT_LOG_FILE)).unwrap(),
+            "hello\n"
+        );
+
+        process_runtime.delete_workload(&workload_id).await.unwrap();
+        assert!(!workload_folder.exists());
+    }
+
+    // [utest->swdd~process-delete-workload-stops-and-removes-workload~1]
+    #[tokio::test]
+    async fn utest_delete_workload_does_not_stop_process_with_reused_pid() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let process_runtime = ProcessRuntime::new(tmpdir.path().to_path_buf());
+
+        let mut unrelated_process = std::process::Command::new("sleep")
+            .arg("30")
+            .process_group(0)
+            .spawn()
+            .unwrap();
+        let unrelated_identity = ProcessIdentity::of(unrelated_process.id() as i32).unwrap();
+        prepare_workload_folder(
+            tmpdir.path(),
+            ProcessIdentity {
+                pid: unrelated_identity.pid,
+                start_time: unrelated_identity.start_time + 1,
+            },
+            None,
+        );
+
+        process_runtime
+            .delete_workload(&ProcessWorkloadId {
+                id: WORKLOAD_ID.to_string(),
+            })
+            .await
+            .unwrap();
+
+        assert!(unrelated_identity.is_alive());
+        unrelated_process.kill().unwrap();
+        unrelated_process.wait().unwrap();
+    }
+
+    // [utest->swdd~process-delete-workload-stops-and-removes-workload~1]
+    #[tokio::test]
+    async fn utest_delete_workload_stops_whole_process_group() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let process_runtime = ProcessRuntime::new(tmpdir.path().to_path_buf());
+
+        let mut workload_spec = generate_test_workload_spec_with_param(
+            AGENT_NAME.to_string(),
+            WORKLOAD_1_NAME.to_string(),
+            PROCESS_RUNTIME_NAME.to_string(),
+        );
+        // The workload leaves a background process behind when it is terminated
+        workload_spec.runtime_config =
+            "command: /bin/sh\nargs: [\"-c\", \"sleep 30 & wait\"]".to_string();
+
+        let (state_sender, _state_receiver) = tokio::sync::mpsc::channel(BUFFER_SIZE);
+        let (workload_id, _checker) = process_runtime
+            .create_workload(workload_spec, None, None, state_sender, HashMap::default())
+            .await
+            .unwrap();
+        let workload_folder = tmpdir
+            .path()
+            .join(PROCESS_RUNTIME_FOLDER)
+            .join(&workload_id.id);
+        let process = super::read_pid_file(&workload_folder).await.unwrap();
+
+        process_runtime.delete_workload(&workload_id).await.unwrap();
+
+        assert!(!super::is_process_group_alive(process.pid).unwrap());
+        assert!(!workload_folder.exists());
+    }
+
+    #[tokio::test]
+    async fn utest_create_workload_parsing_failed() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let process_runtime = ProcessRuntime::new(tmpdir.path().to_path_buf());
+
+        let mut workload_spec = generate_test_workload_spec_with_param(
+            AGENT_NAME.to_string(),
+            WORKLOAD_1_NAME.to_string(),
+            PROCESS_RUNTIME_NAME.to_string(),
+        );
+        workload_spec.runtime_config = "args: [\"no command\"]".to_string();
+
+        let (state_sender, _state_receiver) = tokio::sync::mpsc::channel(BUFFER_SIZE);
+        let res = process_runtime
+            .create_workload(workload_spec, None, None, state_sender, HashMap::default())
+            .await;
+
+        assert!(matches!(res, Err(RuntimeError::Unsupported(_))));
+    }
+
+    // [utest->swdd~process-rejects-resource-limits~1]
+    #[tokio::test]
+    async fn utest_create_workload_resource_limits_unsupported() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let process_runtime = ProcessRuntime::new(tmpdir.path().to_path_buf());
+
+        let mut workload_spec = generate_test_workload_spec_with_param(
+            AGENT_NAME.to_string(),
+            WORKLOAD_1_NAME.to_string(),
+            PROCESS_RUNTIME_NAME.to_string(),
+        );
+        workload_spec.runtime_config = "command: /bin/true".to_string();
+        workload_spec.resources = Some(ResourceLimits {
+            memory_limit: Some(268435456),
+            ..Default::default()
+        });
+
+        let (state_sender, _state_receiver) = tokio::sync::mpsc::channel(BUFFER_SIZE);
+        let res = process_runtime
+            .create_workload(workload_spec, None, None, state_sender, HashMap::default())
+            .await;
+
+        assert!(matches!(res, Err(RuntimeError::Unsupported(_))));
+        assert!(!tmpdir.path().join(PROCESS_RUNTIME_FOLDER).exists());
+    }
+
+    #[tokio::test]
+    async fn utest_get_workload_id_no_workload_found() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let process_runtime = ProcessRuntime::new(tmpdir.path().to_path_buf());
+
+        let res = process_runtime
+            .get_workload_id(&WorkloadInstanceName::try_from(WORKLOAD_ID).unwrap())
+            .await;
+
+        assert!(matches!(res, Err(RuntimeError::List(_))));
+    }
+
+    // [utest->swdd~agent-runtime-connector-provides-workload-metrics~1]
+    #[tokio::test]
+    async fn utest_get_workload_metrics_unsupported() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let process_runtime = ProcessRuntime::new(tmpdir.path().to_path_buf());
+
+        let res = process_runtime
+            .get_workload_metrics(&ProcessWorkloadId {
+                id: WORKLOAD_ID.to_string(),
+            })
+            .await;
+
+        assert!(matches!(res, Err(RuntimeError::Unsupported(_))));
+    }
+}
@@ -0,0 +1,157 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use common::objects::WorkloadSpec;
+
+use super::process_runtime::PROCESS_RUNTIME_NAME;
+
+// [impl->swdd~process-runtime-config~1]
+#[derive(Debug, serde::Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessRuntimeConfig {
+    pub command: String,
+    #[serde(default)]
+    pub args: Vec<String>,
+    #[serde(default)]
+    pub env: HashMap<String, String>,
+    #[serde(default, alias = "working_dir")]
+    pub working_dir: Option<String>,
+}
+
+impl TryFrom<&WorkloadSpec> for ProcessRuntimeConfig {
+    type Error = String;
+    fn try_from(workload_spec: &WorkloadSpec) -> Result<Self, Self::Error> {
+        if PROCESS_RUNTIME_NAME != workload_spec.runtime {
+            return Err(format!(
+                "Received a spec for the wrong runtime: '{}'",
+                workload_spec.runtime
+            ));
+        }
+        let workload_cfg: ProcessRuntimeConfig =
+            serde_yaml::from_str(workload_spec.runtime_config.as_str())
+                .map_err(|err| err.to_string())?;
+        if workload_cfg.command.is_empty() {
+            return Err("The command of a process workload must not be empty".to_string());
+        }
+        Ok(workload_cfg)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use common::objects::generate_test_workload_spec_with_param;
+
+    use super::ProcessRuntimeConfig;
+    use crate::runtime_connectors::process::process_runtime::PROCESS_RUNTIME_NAME;
+
+    const DIFFERENT_RUNTIME_NAME: &str = "different-runtime-name";
+    const AGENT_NAME: &str = "agent_x";
+    const WORKLOAD_1_NAME: &str = "workload1";
+
+    // [utest->swdd~process-runtime-config~1]
+    #[test]
+    fn utest_process_config_success() {
+        let mut workload_spec = generate_test_workload_spec_with_param(
+            AGENT_NAME.to_string(),
+            WORKLOAD_1_NAME.to_string(),
+            PROCESS_RUNTIME_NAME.to_string(),
+        );
+
+        workload_spec.runtime_config = r#"
+command: /usr/bin/my_app
+args: ["--verbose", "--port", "8080"]
+env:
+  LOG_LEVEL: debug
+workingDir: /opt/my_app
+"#
+        .to_string();
+
+        assert_eq!(
+            ProcessRuntimeConfig::try_from(&workload_spec),
+            Ok(ProcessRuntimeConfig {
+                command: "/usr/bin/my_app".to_string(),
+                args: vec![
+                    "--verbose".to_string(),
+                    "--port".to_string(),
+                    "8080".to_string()
+                ],
+                env: HashMap::from([("LOG_LEVEL".to_string(), "debug".to_string())]),
+                working_dir: Some("/opt/my_app".to_string()),
+            })
+        );
+    }
+
+    // [utest->swdd~process-runtime-config~1]
+    #[test]
+    fn utest_process_config_only_command() {
+        let mut workload_spec = generate_test_workload_spec_with_param(
+            AGENT_NAME.to_string(),
+            WORKLOAD_1_NAME.to_string(),
+            PROCESS_RUNTIME_NAME.to_string(),
+        );
+
+        workload_spec.runtime_config = "command: sleep".to_string();
+
+        assert_eq!(
+            ProcessRuntimeConfig::try_from(&workload_spec),
+            Ok(ProcessRuntimeConfig {
+                command: "sleep".to_string(),
+                args: Vec::new(),
+                env: HashMap::new(),
+                working_dir: None,
+            })
+        );
+    }
+
+    // [utest->swdd~process-runtime-config~1]
+    #[test]
+    fn utest_process_config_failure_missing_or_empty_command() {
+        let mut workload_spec = generate_test_workload_spec_with_param(
+            AGENT_NAME.to_string(),
+            WORKLOAD_1_NAME.to_string(),
+            PROCESS_RUNTIME_NAME.to_string(),
+        );
+
+        workload_spec.runtime_config = "args: [\"--verbose\"]".to_string();
+        assert!(ProcessRuntimeConfig::try_from(&workload_spec).is_err());
+
+        workload_spec.runtime_config = "command: \"\"".to_string();
+        assert!(ProcessRuntimeConfig::try_from(&workload_spec).is_err());
+    }
+
+    #[test]
+    fn utest_process_config_failure_wrong_runtime() {
+        let mut workload_spec = generate_test_workload_spec_with_param(
+            AGENT_NAME.to_string(),
+            WORKLOAD_1_NAME.to_string(),
+            DIFFERENT_RUNTIME_NAME.to_string(),
+        );
+
+        workload_spec.runtime_config = "command: sleep".to_string();
+
+        assert!(ProcessRuntimeConfig::try_from(&workload_spec).is_err());
+    }
+}
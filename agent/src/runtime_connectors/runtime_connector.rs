@@ -18,7 +18,10 @@ use async_trait::async_trait;
 
 use common::{
     commands::LogsRequest,
-    objects::{AgentName, ExecutionState, WorkloadInstanceName, WorkloadSpec, WorkloadState},
+    objects::{
+        AgentName, ExecutionState, WorkloadInstanceName, WorkloadMetrics, WorkloadSpec,
+        WorkloadState,
+    },
 };
 
 use crate::{runtime_connectors::StateChecker, workload_state::WorkloadStateSender};
@@ -31,6 +34,7 @@ pub enum RuntimeError {
     Delete(String),
     List(String),
     CollectLog(String),
+    CollectMetrics(String),
     Unsupported(String),
 }
 
@@ -59,6 +63,9 @@ impl Display for RuntimeError {
             RuntimeError::CollectLog(msg) => {
                 write!(f, "{msg}")
             }
+            RuntimeError::CollectMetrics(msg) => {
+                write!(f, "{msg}")
+            }
             RuntimeError::Unsupported(msg) => {
                 write!(f, "{msg}")
             }
@@ -145,6 +152,17 @@ where
     ) -> Result<Box<dyn LogFetcher + Send>, RuntimeError>;
 
     async fn delete_workload(&self, workload_id: &WorkloadId) -> Result<(), RuntimeError>;
+
+    // [impl->swdd~agent-runtime-connector-provides-workload-metrics~1]
+    async fn get_workload_metrics(
+        &self,
+        _workload_id: &WorkloadId,
+    ) -> Result<WorkloadMetrics, RuntimeError> {
+        Err(RuntimeError::Unsupported(format!(
+            "The {} runtime does not provide resource usage metrics.",
+            self.name()
+        )))
+    }
 }
 
 pub trait OwnableRuntime<WorkloadId, StChecker>: RuntimeConnector<WorkloadId, StChecker>
@@ -183,7 +201,9 @@ pub mod test {
     };
 
     use async_trait::async_trait;
-    use common::objects::{AgentName, ExecutionState, WorkloadInstanceName, WorkloadSpec};
+    use common::objects::{
+        AgentName, ExecutionState, WorkloadInstanceName, WorkloadMetrics, WorkloadSpec,
+    };
 
     use crate::{
         runtime_connectors::{
@@ -265,6 +285,7 @@ pub mod test {
             LogRequestOptions,
             Result<Box<dyn LogFetcher + Send>, RuntimeError>,
         ),
+        GetWorkloadMetrics(String, Result<WorkloadMetrics, RuntimeError>),
     }
 
     #[derive(Debug)]
@@ -495,5 +516,24 @@ pub mod test {
                 }
             }
         }
+
+        async fn get_workload_metrics(
+            &self,
+            workload_id: &String,
+        ) -> Result<WorkloadMetrics, RuntimeError> {
+            match self.get_expected_call() {
+                RuntimeCall::GetWorkloadMetrics(expected_workload_id, result)
+                    if expected_workload_id == *workload_id =>
+                {
+                    return result;
+                }
+                expected_call => {
+                    self.unexpected_call();
+                    panic!(
+                        "Unexpected get_workload_metrics call. Expected: '{expected_call:?}'\n\nGot: {workload_id:?}"
+                    );
+                }
+            }
+        }
     }
 }
@@ -0,0 +1,276 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{process::Stdio, time::Duration};
+
+use async_trait::async_trait;
+use tokio::{
+    io::{AsyncBufReadExt, BufReader},
+    process::Command,
+    sync::{broadcast, watch},
+};
+
+const EVENT_BUFFER_SIZE: usize = 1024;
+const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+
+// [impl->swdd~agent-state-change-events-listens-once-per-runtime~1]
+#[async_trait]
+pub trait RuntimeEventSource: Send + Sync + 'static {
+    fn events_command(&self) -> (&'static str, Vec<&'static str>);
+
+    fn parse_workload_id(&self, event: &str) -> Option<String>;
+
+    async fn on_state_change(&self) {}
+}
+
+#[derive(Debug, Clone)]
+pub struct StateChangeEvents {
+    events_tx: broadcast::Sender<String>,
+    connected_rx: watch::Receiver<bool>,
+}
+
+#[derive(Debug)]
+pub struct StateChangeSubscription {
+    events_rx: broadcast::Receiver<String>,
+    connected_rx: watch::Receiver<bool>,
+}
+
+impl StateChangeEvents {
+    pub fn start(source: impl RuntimeEventSource) -> Self {
+        let (events_tx, _) = broadcast::channel(EVENT_BUFFER_SIZE);
+        let (connected_tx, connected_rx) = watch::channel(false);
+        tokio::spawn(listen_for_events(source, events_tx.clone(), connected_tx));
+        Self {
+            events_tx,
+            connected_rx,
+        }
+    }
+
+    pub fn subscribe(&self) -> StateChangeSubscription {
+        StateChangeSubscription::new(self.events_tx.subscribe(), self.connected_rx.clone())
+    }
+}
+
+impl StateChangeSubscription {
+    pub fn new(
+        events_rx: broadcast::Receiver<String>,
+        connected_rx: watch::Receiver<bool>,
+    ) -> Self {
+        Self {
+            events_rx,
+            connected_rx,
+        }
+    }
+
+    pub fn is_connected(&self) -> bool {
+        *self.connected_rx.borrow()
+    }
+
+    // [impl->swdd~agent-state-change-subscription-waits-for-workload-event~1]
+    pub async fn wait_for_state_change(&mut self, workload_id: &str, max_wait_time: Duration) {
+        let timeout = tokio::time::sleep(max_wait_time);
+        tokio::pin!(timeout);
+        loop {
+            tokio::select! {
+                _ = &mut timeout => return,
+                event = self.events_rx.recv() => match event {
+                    Ok(changed_workload_id) if changed_workload_id == workload_id => return,
+                    Ok(_) => {}
+                    // Missed events could belong to this workload.
+                    Err(broadcast::error::RecvError::Lagged(_)) => return,
+                    Err(broadcast::error::RecvError::Closed) => {
+                        timeout.await;
+                        return;
+                    }
+                },
+                changed = self.connected_rx.changed() => {
+                    if changed.is_err() || !*self.connected_rx.borrow_and_update() {
+                        return;
+                    }
+                }
+            }
+        }
+    }
+}
+
+async fn listen_for_events(
+    source: impl RuntimeEventSource,
+    events_tx: broadcast::Sender<String>,
+    connected_tx: watch::Sender<bool>,
+) {
+    let (command, args) = source.events_command();
+    loop {
+        match Command::new(command)
+            .args(&args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .kill_on_drop(true)
+            .spawn()
+        {
+            Ok(mut child) => {
+                if let Some(stdout) = child.stdout.take() {
+                    log::debug!("Listening for state change events using '{command}'");
+                    connected_tx.send_replace(true);
+                    let mut lines = BufReader::new(stdout).lines();
+                    while let Ok(Some(line)) = lines.next_line().await {
+                        if let Some(workload_id) = source.parse_workload_id(&line) {
+                            log::trace!("Received state change event for '{workload_id}'");
+                            source.on_state_change().await;
+                            // Sending only fails if there are no subscribers.
+                            let _ = events_tx.send(workload_id);
+                        }
+                    }
+                }
+                let _ = child.kill().await;
+            }
+            Err(err) => {
+                log::debug!("Could not start '{command}' for state change events: '{err}'");
+            }
+        }
+
+        // [impl->swdd~agent-state-change-events-reconnects~1]
+        if connected_tx.send_replace(false) {
+            log::warn!(
+                "The state change events of '{command}' are not available, falling back to polling."
+            );
+        }
+        tokio::time::sleep(RECONNECT_DELAY).await;
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tokio::sync::{broadcast, watch};
+
+    use super::{RuntimeEventSource, StateChangeEvents, StateChangeSubscription};
+
+    const WORKLOAD_ID: &str = "workload_id_1";
+    const OTHER_WORKLOAD_ID: &str = "workload_id_2";
+
+    struct TestEventSource;
+
+    impl RuntimeEventSource for TestEventSource {
+        fn events_command(&self) -> (&'static str, Vec<&'static str>) {
+            (
+                "/bin/sh",
+                vec![
+                    "-c",
+                    "sleep 0.2; echo 'event workload_id_2'; echo 'garbage'; echo 'event workload_id_1'",
+                ],
+            )
+        }
+
+        fn parse_workload_id(&self, event: &str) -> Option<String> {
+            event.strip_prefix("event ").map(ToString::to_string)
+        }
+    }
+
+    // [utest->swdd~agent-state-change-events-listens-once-per-runtime~1]
+    // [utest->swdd~agent-state-change-events-reconnects~1]
+    #[tokio::test]
+    async fn utest_state_change_events_forwards_parsed_events() {
+        let state_change_events = StateChangeEvents::start(TestEventSource);
+        let mut subscription = state_change_events.subscribe();
+
+        assert_eq!(
+            tokio::time::timeout(Duration::from_secs(5), subscription.events_rx.recv())
+                .await
+                .unwrap()
+                .unwrap(),
+            OTHER_WORKLOAD_ID
+        );
+        assert_eq!(
+            tokio::time::timeout(Duration::from_secs(5), subscription.events_rx.recv())
+                .await
+                .unwrap()
+                .unwrap(),
+            WORKLOAD_ID
+        );
+
+        tokio::time::timeout(
+            Duration::from_secs(5),
+            subscription.connected_rx.wait_for(|connected| !connected),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+        assert!(!subscription.is_connected());
+    }
+
+    // [utest->swdd~agent-state-change-subscription-waits-for-workload-event~1]
+    #[tokio::test]
+    async fn utest_state_change_subscription_waits_for_event_of_workload() {
+        let (events_tx, events_rx) = broadcast::channel(10);
+        let (_connected_tx, connected_rx) = watch::channel(true);
+        let mut subscription = StateChangeSubscription::new(events_rx, connected_rx);
+
+        events_tx.send(OTHER_WORKLOAD_ID.to_string()).unwrap();
+        events_tx.send(WORKLOAD_ID.to_string()).unwrap();
+
+        tokio::time::timeout(
+            Duration::from_secs(1),
+            subscription.wait_for_state_change(WORKLOAD_ID, Duration::from_secs(10)),
+        )
+        .await
+        .unwrap();
+    }
+
+    // [utest->swdd~agent-state-change-subscription-waits-for-workload-event~1]
+    #[tokio::test]
+    async fn utest_state_change_subscription_ignores_events_of_other_workloads() {
+        let (events_tx, events_rx) = broadcast::channel(10);
+        let (_connected_tx, connected_rx) = watch::channel(true);
+        let mut subscription = StateChangeSubscription::new(events_rx, connected_rx);
+
+        events_tx.send(OTHER_WORKLOAD_ID.to_string()).unwrap();
+
+        assert!(
+            tokio::time::timeout(
+                Duration::from_millis(200),
+                subscription.wait_for_state_change(WORKLOAD_ID, Duration::from_secs(10)),
+            )
+            .await
+            .is_err()
+        );
+    }
+
+    // [utest->swdd~agent-state-change-subscription-waits-for-workload-event~1]
+    #[tokio::test]
+    async fn utest_state_change_subscription_returns_on_disconnect() {
+        let (_events_tx, events_rx) = broadcast::channel::<String>(10);
+        let (connected_tx, connected_rx) = watch::channel(true);
+        let mut subscription = StateChangeSubscription::new(events_rx, connected_rx);
+
+        connected_tx.send_replace(false);
+
+        tokio::time::timeout(
+            Duration::from_secs(1),
+            subscription.wait_for_state_change(WORKLOAD_ID, Duration::from_secs(10)),
+        )
+        .await
+        .unwrap();
+        assert!(!subscription.is_connected());
+    }
+}
@@ -21,7 +21,10 @@ use common::objects::{ExecutionState, WorkloadSpec};
 #[cfg(test)]
 use mockall::automock;
 
-use crate::workload_state::WorkloadStateSender;
+use crate::{
+    runtime_connectors::state_change_events::StateChangeSubscription,
+    workload_state::WorkloadStateSender,
+};
 
 // [impl->swdd~agent-general-runtime-state-getter-interface~1]
 #[async_trait]
@@ -32,6 +35,26 @@ where
 {
     // [impl->swdd~allowed-workload-states~2]
     async fn get_state(&self, workload_id: &WorkloadId) -> ExecutionState;
+
+    // [impl->swdd~agent-executes-health-probes~2]
+    async fn exec_command(
+        &self,
+        _workload_id: &WorkloadId,
+        _command: &[String],
+    ) -> Result<(), String> {
+        Err("Exec probes are not supported by the runtime".to_string())
+    }
+
+    // [impl->swdd~agent-executes-health-probes~2]
+    // Workloads without an own network address share the network of the host.
+    async fn get_probe_host(&self, _workload_id: &WorkloadId) -> Result<String, String> {
+        Ok("127.0.0.1".to_string())
+    }
+
+    // [impl->swdd~agent-runtime-state-getter-provides-state-change-events~1]
+    fn subscribe_state_changes(&self) -> Option<StateChangeSubscription> {
+        None
+    }
 }
 
 // [impl->swdd~agent-general-state-checker-interface~1]
@@ -0,0 +1,751 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    collections::{BTreeSet, HashMap},
+    fmt::Display,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use tokio::task::JoinHandle;
+use wasmtime::{Config, Engine, Linker, Module, Store, Trap};
+use wasmtime_wasi::{
+    DirPerms, FilePerms, I32Exit, WasiCtxBuilder,
+    cli::OutputFile,
+    p1::{self, WasiP1Ctx},
+};
+
+use common::objects::{AgentName, ExecutionState, Probes, WorkloadInstanceName, WorkloadSpec};
+
+use crate::{
+    generic_polling_state_checker::GenericPollingStateChecker,
+    runtime_connectors::{
+        ReusableWorkloadState, RuntimeConnector, RuntimeError, RuntimeStateGetter, StateChecker,
+        file_log_fetcher::FileLogFetcher, generic_log_fetcher::GenericLogFetcher,
+        log_fetcher::LogFetcher, runtime_connector::LogRequestOptions,
+    },
+    workload_state::WorkloadStateSender,
+};
+
+use super::wasm_runtime_config::{WasmModuleSource, WasmRuntimeConfig};
+
+pub const WASM_RUNTIME_NAME: &str = "wasm";
+
+const WASM_RUNTIME_FOLDER: &str = "wasm";
+const STDOUT_LOG_FILE: &str = "stdout.log";
+const STDERR_LOG_FILE: &str = "stderr.log";
+
+const WASI_ENTRY_POINT: &str = "_start";
+const WASM_STOP_TIMEOUT: Duration = Duration::from_secs(10);
+
+struct WasmInstance {
+    engine: Engine,
+    state: Arc<Mutex<ExecutionState>>,
+    task: JoinHandle<()>,
+}
+
+type WasmInstances = Arc<Mutex<HashMap<String, WasmInstance>>>;
+
+// The modules are executed inside the agent process. All clones of the runtime
+// share the running instances.
+#[derive(Clone)]
+pub struct WasmRuntime {
+    wasm_folder: PathBuf,
+    instances: WasmInstances,
+}
+
+#[derive(Clone)]
+pub struct WasmStateGetter {
+    instances: WasmInstances,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct WasmWorkloadId {
+    pub id: String,
+}
+
+impl Display for WasmWorkloadId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.id.to_owned())
+    }
+}
+
+impl FromStr for WasmWorkloadId {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(WasmWorkloadId { id: s.to_string() })
+    }
+}
+
+#[async_trait]
+// [impl->swdd~wasm-implements-runtime-state-getter~1]
+impl RuntimeStateGetter<WasmWorkloadId> for WasmStateGetter {
+    async fn get_state(&self, workload_id: &WasmWorkloadId) -> ExecutionState {
+        log::trace!("Getting the state for the workload '{}'", workload_id.id);
+
+        // [impl->swdd~wasm-state-getter-returns-lost-state~1]
+        let exec_state = self
+            .instances
+            .lock()
+            .unwrap()
+            .get(&workload_id.id)
+            .map(|instance| instance.state.lock().unwrap().clone())
+            .unwrap_or_else(ExecutionState::lost);
+
+        log::trace!(
+            "Returning the state '{}' for the workload '{}'",
+            exec_state,
+            workload_id.id
+        );
+        exec_state
+    }
+}
+
+// [impl->swdd~wasm-state-getter-maps-state~1]
+fn map_module_result(result: wasmtime::Result<()>) -> ExecutionState {
+    let Err(err) = result else {
+        return ExecutionState::succeeded();
+    };
+
+    if let Some(I32Exit(exit_code)) = err.downcast_ref::<I32Exit>() {
+        return match exit_code {
+            0 => ExecutionState::succeeded(),
+            exit_code => ExecutionState::failed(format!("Exit code: '{exit_code}'")),
+        };
+    }
+
+    match err.downcast_ref::<Trap>() {
+        Some(Trap::Interrupt) => ExecutionState::failed("Interrupted"),
+        Some(trap) => ExecutionState::failed(format!("Trap: '{trap}'")),
+        None => ExecutionState::failed(format!("{err:#}")),
+    }
+}
+
+fn run_module(engine: &Engine, module: &Module, mut store: Store<WasiP1Ctx>) -> ExecutionState {
+    let mut linker: Linker<WasiP1Ctx> = Linker::new(engine);
+    if let Err(err) = p1::add_to_linker_sync(&mut linker, |ctx| ctx) {
+        return ExecutionState::failed(format!("Could not link WASI: '{err}'"));
+    }
+
+    let result = linker
+        .instantiate(&mut store, module)
+        .and_then(|instance| instance.get_typed_func::<(), ()>(&mut store, WASI_ENTRY_POINT))
+        .and_then(|start| start.call(&mut store, ()));
+    map_module_result(result)
+}
+
+// WASI only supports preopening directories, thus the folders containing the workload files
+// are preopened at the folders of the mount points.
+fn preopened_dirs(
+    workload_file_path_mappings: &HashMap<PathBuf, PathBuf>,
+) -> BTreeSet<(PathBuf, String)> {
+    workload_file_path_mappings
+        .iter()
+        .filter_map(|(host_file_path, mount_point)| {
+            Some((
+                host_file_path.parent()?.to_path_buf(),
+                mount_point.parent()?.to_string_lossy().to_string(),
+            ))
+        })
+        .collect()
+}
+
+fn open_log_file(path: &Path) -> Result<std::fs::File, String> {
+    std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|err| format!("Could not open the log file '{}': '{err}'", path.display()))
+}
+
+impl WasmRuntime {
+    pub fn new(run_folder: PathBuf) -> Self {
+        Self {
+            wasm_folder: run_folder.join(WASM_RUNTIME_FOLDER),
+            instances: Default::default(),
+        }
+    }
+
+    fn workload_folder(&self, workload_id: &WasmWorkloadId) -> PathBuf {
+        self.wasm_folder.join(&workload_id.id)
+    }
+
+    // [impl->swdd~wasm-create-workload-runs-module~1]
+    async fn start_module(
+        &self,
+        workload_folder: &Path,
+        program_name: &str,
+        workload_cfg: WasmRuntimeConfig,
+        workload_file_path_mappings: &HashMap<PathBuf, PathBuf>,
+    ) -> Result<WasmInstance, String> {
+        let module_source = workload_cfg.module_source()?;
+
+        tokio::fs::create_dir_all(workload_folder)
+            .await
+            .map_err(|err| format!("Could not create the workload folder: '{err}'"))?;
+        let stdout_log = open_log_file(&workload_folder.join(STDOUT_LOG_FILE))?;
+        let stderr_log = open_log_file(&workload_folder.join(STDERR_LOG_FILE))?;
+
+        // Every module gets its own engine, such that incrementing the epoch only stops this module.
+        let mut config = Config::new();
+        config.epoch_interruption(true);
+        let engine = Engine::new(&config)
+            .map_err(|err| format!("Could not create the WebAssembly engine: '{err}'"))?;
+
+        let compile_engine = engine.clone();
+        let module = tokio::task::spawn_blocking(move || match module_source {
+            WasmModuleSource::File(path) => Module::from_file(&compile_engine, path),
+            WasmModuleSource::Binary(binary) => Module::new(&compile_engine, binary),
+        })
+        .await
+        .map_err(|err| format!("Could not load the WebAssembly module: '{err}'"))?
+        .map_err(|err| format!("Could not load the WebAssembly module: '{err:#}'"))?;
+
+        let mut wasi = WasiCtxBuilder::new();
+        wasi.arg(program_name)
+            .args(workload_cfg.args.as_slice())
+            .stdout(OutputFile::new(stdout_log))
+            .stderr(OutputFile::new(stderr_log));
+        for (key, value) in &workload_cfg.env {
+            wasi.env(key, value);
+        }
+        // [impl->swdd~wasm-create-workload-preopens-workload-files~1]
+        for (host_dir, guest_dir) in preopened_dirs(workload_file_path_mappings) {
+            wasi.preopened_dir(&host_dir, &guest_dir, DirPerms::READ, FilePerms::READ)
+                .map_err(|err| {
+                    format!(
+                        "Could not preopen '{}' at '{guest_dir}': '{err}'",
+                        host_dir.display()
+                    )
+                })?;
+        }
+        let mut store = Store::new(&engine, wasi.build_p1());
+        // The epoch of the engine is only incremented to stop the module. The deadline is set
+        // before the module is started, such that a module stopped right after its start
+        // does not miss the increment.
+        store.set_epoch_deadline(1);
+
+        let state = Arc::new(Mutex::new(ExecutionState::running()));
+        let run_engine = engine.clone();
+        let run_state = state.clone();
+        let task = tokio::task::spawn_blocking(move || {
+            let exec_state = run_module(&run_engine, &module, store);
+            *run_state.lock().unwrap() = exec_state;
+        });
+
+        Ok(WasmInstance {
+            engine,
+            state,
+            task,
+        })
+    }
+}
+
+#[async_trait]
+// [impl->swdd~wasm-implements-runtime-connector~1]
+impl RuntimeConnector<WasmWorkloadId, GenericPollingStateChecker> for WasmRuntime {
+    // [impl->swdd~wasm-name-returns-wasm~1]
+    fn name(&self) -> String {
+        WASM_RUNTIME_NAME.to_string()
+    }
+
+    // [impl->swdd~wasm-list-of-existing-workloads-is-empty~1]
+    async fn get_reusable_workloads(
+        &self,
+        _agent_name: &AgentName,
+    ) -> Result<Vec<ReusableWorkloadState>, RuntimeError> {
+        // The modules are running inside the agent process and do not survive a restart of the agent.
+        // Only the leftover log files of the previous agent run are removed.
+        match tokio::fs::remove_dir_all(&self.wasm_folder).await {
+            Ok(()) => Ok(Vec::new()),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(Vec::new()),
+            Err(err) => Err(RuntimeError::List(err.to_string())),
+        }
+    }
+
+    // [impl->swdd~wasm-create-workload-runs-module~1]
+    async fn create_workload(
+        &self,
+        workload_spec: WorkloadSpec,
+        _reusable_workload_id: Option<WasmWorkloadId>,
+        control_interface_path: Option<PathBuf>,
+        update_state_tx: WorkloadStateSender,
+        workload_file_path_mappings: HashMap<PathBuf, PathBuf>,
+    ) -> Result<(WasmWorkloadId, GenericPollingStateChecker), RuntimeError> {
+        let workload_cfg =
+            WasmRuntimeConfig::try_from(&workload_spec).map_err(RuntimeError::Unsupported)?;
+
+        // [impl->swdd~wasm-rejects-exec-probes~1]
+        if workload_spec
+            .probes
+            .as_ref()
+            .is_some_and(Probes::has_exec_probe)
+        {
+            return Err(RuntimeError::Unsupported(
+                "Exec probes are not supported for wasm runtime. Use a tcpSocket or httpGet probe instead."
+                    .to_string(),
+            ));
+        }
+
+        if control_interface_path.is_some() {
+            log::warn!(
+                "The wasm runtime does not provide the control interface to '{}'.",
+                workload_spec.instance_name
+            );
+        }
+
+        // [impl->swdd~wasm-rejects-resource-limits~1]
+        if workload_spec.resources.is_some() {
+            return Err(RuntimeError::Unsupported(
+                "Resource limits are not supported for wasm runtime.".to_string(),
+            ));
+        }
+
+        let workload_id = WasmWorkloadId {
+            id: workload_spec.instance_name.to_string(),
+        };
+        let instance = self
+            .start_module(
+                &self.workload_folder(&workload_id),
+                workload_spec.instance_name.workload_name(),
+                workload_cfg,
+                &workload_file_path_mappings,
+            )
+            .await
+            .map_err(RuntimeError::Create)?;
+
+        log::debug!(
+            "The workload '{}' has been created",
+            workload_spec.instance_name
+        );
+        self.instances
+            .lock()
+            .unwrap()
+            .insert(workload_id.id.clone(), instance);
+
+        let state_checker = self
+            .start_checker(&workload_id, workload_spec, update_state_tx)
+            .await?;
+
+        // [impl->swdd~wasm-create-workload-returns-workload-id~1]
+        Ok((workload_id, state_checker))
+    }
+
+    async fn get_workload_id(
+        &self,
+        instance_name: &WorkloadInstanceName,
+    ) -> Result<WasmWorkloadId, RuntimeError> {
+        let id = instance_name.to_string();
+        if self.instances.lock().unwrap().contains_key(&id) {
+            Ok(WasmWorkloadId { id })
+        } else {
+            Err(RuntimeError::List(format!(
+                "No module found for workload '{instance_name}'"
+            )))
+        }
+    }
+
+    // [impl->swdd~wasm-start-checker-starts-wasm-state-checker~1]
+    async fn start_checker(
+        &self,
+        workload_id: &WasmWorkloadId,
+        workload_spec: WorkloadSpec,
+        update_state_tx: WorkloadStateSender,
+    ) -> Result<GenericPollingStateChecker, RuntimeError> {
+        log::debug!(
+            "Starting the checker for the workload '{}' with internal id '{}'",
+            workload_spec.instance_name,
+            workload_id.id
+        );
+        let checker = GenericPollingStateChecker::start_checker(
+            &workload_spec,
+            workload_id.clone(),
+            update_state_tx,
+            WasmStateGetter {
+                instances: self.instances.clone(),
+            },
+        );
+        Ok(checker)
+    }
+
+    fn get_log_fetcher(
+        &self,
+        workload_id: WasmWorkloadId,
+        options: &LogRequestOptions,
+    ) -> Result<Box<dyn LogFetcher + Send>, RuntimeError> {
+        let workload_folder = self.workload_folder(&workload_id);
+        let file_log_fetcher = FileLogFetcher::new(
+            &workload_folder.join(STDOUT_LOG_FILE),
+            &workload_folder.join(STDERR_LOG_FILE),
+            options,
+        );
+        let log_fetcher = GenericLogFetcher::new(file_log_fetcher);
+        Ok(Box::new(log_fetcher))
+    }
+
+    // [impl->swdd~wasm-delete-workload-stops-and-removes-workload~1]
+    async fn delete_workload(&self, workload_id: &WasmWorkloadId) -> Result<(), RuntimeError> {
+        log::debug!("Deleting workload with id '{}'", workload_id.id);
+        let instance = self.instances.lock().unwrap().remove(&workload_id.id);
+
+        if let Some(instance) = instance {
+            instance.engine.increment_epoch();
+            if tokio::time::timeout(WASM_STOP_TIMEOUT, instance.task)
+                .await
+                .is_err()
+            {
+                log::warn!(
+                    "The module of workload '{}' did not stop within {} seconds",
+                    workload_id.id,
+                    WASM_STOP_TIMEOUT.as_secs()
+                );
+            }
+        }
+
+        match tokio::fs::remove_dir_all(self.workload_folder(workload_id)).await {
+            Ok(()) => Ok(()),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(RuntimeError::Delete(err.to_string())),
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+// [utest->swdd~agent-functions-required-by-runtime-connector~1]
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::{BTreeSet, HashMap},
+        path::PathBuf,
+        time::Duration,
+    };
+
+    use common::objects::{
+        AgentName, ExecProbe, ExecutionState, Probe, Probes, ResourceLimits, WorkloadInstanceName,
+        WorkloadSpec, generate_test_workload_spec_with_param,
+    };
+    use wasmtime::Trap;
+    use wasmtime_wasi::I32Exit;
+
+    use super::{
+        WASM_RUNTIME_FOLDER, WASM_RUNTIME_NAME, WasmRuntime, WasmStateGetter, WasmWorkloadId,
+        map_module_result, preopened_dirs,
+    };
+    use crate::runtime_connectors::{RuntimeConnector, RuntimeError, RuntimeStateGetter};
+
+    const BUFFER_SIZE: usize = 20;
+
+    const AGENT_NAME: &str = "agent_x";
+    const WORKLOAD_1_NAME: &str = "workload1";
+
+    const EXIT_3_MODULE: &str = r#"(module
+  (import "wasi_snapshot_preview1" "proc_exit" (func $proc_exit (param i32)))
+  (memory (export "memory") 1)
+  (func (export "_start") (call $proc_exit (i32.const 3))))"#;
+
+    const ENDLESS_LOOP_MODULE: &str = r#"(module
+  (memory (export "memory") 1)
+  (func (export "_start") (loop $endless (br $endless))))"#;
+
+    fn generate_wasm_workload_spec(module_path: &std::path::Path) -> WorkloadSpec {
+        let mut workload_spec = generate_test_workload_spec_with_param(
+            AGENT_NAME.to_string(),
+            WORKLOAD_1_NAME.to_string(),
+            WASM_RUNTIME_NAME.to_string(),
+        );
+        workload_spec.runtime_config = format!("module: {}", module_path.display());
+        workload_spec
+    }
+
+    async fn wait_for_state(
+        wasm_runtime: &WasmRuntime,
+        workload_id: &WasmWorkloadId,
+        expected_state: ExecutionState,
+    ) {
+        let state_getter = WasmStateGetter {
+            instances: wasm_runtime.instances.clone(),
+        };
+        let mut retries = 100;
+        while state_getter.get_state(workload_id).await != expected_state && retries > 0 {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            retries -= 1;
+        }
+        assert_eq!(state_getter.get_state(workload_id).await, expected_state);
+    }
+
+    // [utest->swdd~wasm-name-returns-wasm~1]
+    #[test]
+    fn utest_name_wasm() {
+        let wasm_runtime = WasmRuntime::new("/tmp/run".into());
+        assert_eq!(wasm_runtime.name(), "wasm".to_string());
+    }
+
+    // [utest->swdd~wasm-state-getter-maps-state~1]
+    #[test]
+    fn utest_map_module_result() {
+        assert_eq!(map_module_result(Ok(())), ExecutionState::succeeded());
+        assert_eq!(
+            map_module_result(Err(I32Exit(0).into())),
+            ExecutionState::succeeded()
+        );
+        assert_eq!(
+            map_module_result(Err(I32Exit(3).into())),
+            ExecutionState::failed("Exit code: '3'")
+        );
+        assert_eq!(
+            map_module_result(Err(Trap::Interrupt.into())),
+            ExecutionState::failed("Interrupted")
+        );
+        assert_eq!(
+            map_module_result(Err(wasmtime::Error::msg("some error"))),
+            ExecutionState::failed("some error")
+        );
+    }
+
+    // [utest->swdd~wasm-state-getter-returns-lost-state~1]
+    #[tokio::test]
+    async fn utest_state_getter_returns_lost_for_unknown_workload() {
+        let wasm_runtime = WasmRuntime::new("/tmp/run".into());
+        let state_getter = WasmStateGetter {
+            instances: wasm_runtime.instances.clone(),
+        };
+
+        assert_eq!(
+            state_getter
+                .get_state(&WasmWorkloadId {
+                    id: "unknown".to_string()
+                })
+                .await,
+            ExecutionState::lost()
+        );
+    }
+
+    // [utest->swdd~wasm-create-workload-preopens-workload-files~1]
+    #[test]
+    fn utest_preopened_dirs_use_folders_of_workload_files() {
+        let workload_file_path_mappings = HashMap::from([
+            (
+                PathBuf::from("/run/files/some/path/test.conf"),
+                PathBuf::from("/some/path/test.conf"),
+            ),
+            (
+                PathBuf::from("/run/files/some/path/other.conf"),
+                PathBuf::from("/some/path/other.conf"),
+            ),
+            (PathBuf::from("/run/files/hello"), PathBuf::from("/hello")),
+        ]);
+
+        assert_eq!(
+            preopened_dirs(&workload_file_path_mappings),
+            BTreeSet::from([
+                (PathBuf::from("/run/files"), "/".to_string()),
+                (
+                    PathBuf::from("/run/files/some/path"),
+                    "/some/path".to_string()
+                ),
+            ])
+        );
+    }
+
+    // [utest->swdd~wasm-list-of-existing-workloads-is-empty~1]
+    #[tokio::test]
+    async fn utest_get_reusable_workloads_removes_leftovers() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let leftover_folder = tmpdir
+            .path()
+            .join(WASM_RUNTIME_FOLDER)
+            .join("workload1.1234.agent_x");
+        std::fs::create_dir_all(&leftover_folder).unwrap();
+
+        let wasm_runtime = WasmRuntime::new(tmpdir.path().to_path_buf());
+        let res = wasm_runtime
+            .get_reusable_workloads(&AgentName::from(AGENT_NAME))
+            .await
+            .unwrap();
+
+        assert!(res.is_empty());
+        assert!(!leftover_folder.exists());
+    }
+
+    // [utest->swdd~wasm-create-workload-runs-module~1]
+    // [utest->swdd~wasm-create-workload-returns-workload-id~1]
+    #[tokio::test]
+    async fn utest_create_workload_runs_module_until_exit() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let module_path = tmpdir.path().join("exit.wat");
+        std::fs::write(&module_path, EXIT_3_MODULE).unwrap();
+        let wasm_runtime = WasmRuntime::new(tmpdir.path().to_path_buf());
+        let workload_spec = generate_wasm_workload_spec(&module_path);
+
+        let (state_sender, _state_receiver) = tokio::sync::mpsc::channel(BUFFER_SIZE);
+        let (workload_id, _checker) = wasm_runtime
+            .create_workload(
+                workload_spec.clone(),
+                None,
+                None,
+                state_sender,
+                HashMap::default(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(workload_id.id, workload_spec.instance_name.to_string());
+        wait_for_state(
+            &wasm_runtime,
+            &workload_id,
+            ExecutionState::failed("Exit code: '3'"),
+        )
+        .await;
+        assert_eq!(
+            wasm_runtime
+                .get_workload_id(&workload_spec.instance_name)
+                .await,
+            Ok(workload_id)
+        );
+    }
+
+    // [utest->swdd~wasm-delete-workload-stops-and-removes-workload~1]
+    #[tokio::test]
+    async fn utest_delete_workload_stops_running_module() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let module_path = tmpdir.path().join("endless.wat");
+        std::fs::write(&module_path, ENDLESS_LOOP_MODULE).unwrap();
+        let wasm_runtime = WasmRuntime::new(tmpdir.path().to_path_buf());
+        let workload_spec = generate_wasm_workload_spec(&module_path);
+
+        let (state_sender, _state_receiver) = tokio::sync::mpsc::channel(BUFFER_SIZE);
+        let (workload_id, _checker) = wasm_runtime
+            .create_workload(
+                workload_spec.clone(),
+                None,
+                None,
+                state_sender,
+                HashMap::default(),
+            )
+            .await
+            .unwrap();
+        wait_for_state(&wasm_runtime, &workload_id, ExecutionState::running()).await;
+
+        tokio::time::timeout(
+            Duration::from_secs(5),
+            wasm_runtime.delete_workload(&workload_id),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+
+        assert!(matches!(
+            wasm_runtime
+                .get_workload_id(&workload_spec.instance_name)
+                .await,
+            Err(RuntimeError::List(_))
+        ));
+        assert!(
+            !tmpdir
+                .path()
+                .join(WASM_RUNTIME_FOLDER)
+                .join(&workload_id.id)
+                .exists()
+        );
+    }
+
+    #[tokio::test]
+    async fn utest_create_workload_invalid_module_fails() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let module_path = tmpdir.path().join("invalid.wasm");
+        std::fs::write(&module_path, "no module").unwrap();
+        let wasm_runtime = WasmRuntime::new(tmpdir.path().to_path_buf());
+
+        let (state_sender, _state_receiver) = tokio::sync::mpsc::channel(BUFFER_SIZE);
+        let res = wasm_runtime
+            .create_workload(
+                generate_wasm_workload_spec(&module_path),
+                None,
+                None,
+                state_sender,
+                HashMap::default(),
+            )
+            .await;
+
+        assert!(matches!(res, Err(RuntimeError::Create(_))));
+    }
+
+    // [utest->swdd~wasm-rejects-resource-limits~1]
+    #[tokio::test]
+    async fn utest_create_workload_resource_limits_unsupported() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let wasm_runtime = WasmRuntime::new(tmpdir.path().to_path_buf());
+
+        let mut workload_spec = generate_wasm_workload_spec(&tmpdir.path().join("module.wasm"));
+        workload_spec.resources = Some(ResourceLimits {
+            memory_limit: Some(268435456),
+            ..Default::default()
+        });
+
+        let (state_sender, _state_receiver) = tokio::sync::mpsc::channel(BUFFER_SIZE);
+        let res = wasm_runtime
+            .create_workload(workload_spec, None, None, state_sender, HashMap::default())
+            .await;
+
+        assert!(matches!(res, Err(RuntimeError::Unsupported(_))));
+    }
+
+    // [utest->swdd~wasm-rejects-exec-probes~1]
+    #[tokio::test]
+    async fn utest_create_workload_exec_probe_unsupported() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let wasm_runtime = WasmRuntime::new(tmpdir.path().to_path_buf());
+
+        let mut workload_spec = generate_wasm_workload_spec(&tmpdir.path().join("module.wasm"));
+        workload_spec.probes = Some(Probes {
+            liveness: None,
+            readiness: Some(Probe {
+                exec: Some(ExecProbe {
+                    command: vec!["true".to_string()],
+                }),
+                ..Default::default()
+            }),
+        });
+
+        let (state_sender, _state_receiver) = tokio::sync::mpsc::channel(BUFFER_SIZE);
+        let res = wasm_runtime
+            .create_workload(workload_spec, None, None, state_sender, HashMap::default())
+            .await;
+
+        assert!(matches!(res, Err(RuntimeError::Unsupported(_))));
+    }
+
+    #[tokio::test]
+    async fn utest_get_workload_id_no_workload_found() {
+        let wasm_runtime = WasmRuntime::new("/tmp/run".into());
+
+        let res = wasm_runtime
+            .get_workload_id(&WorkloadInstanceName::try_from("workload1.1234.agent_x").unwrap())
+            .await;
+
+        assert!(matches!(res, Err(RuntimeError::List(_))));
+    }
+}
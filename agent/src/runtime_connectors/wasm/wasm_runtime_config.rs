@@ -0,0 +1,181 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::HashMap, path::PathBuf};
+
+use base64::{Engine, engine::general_purpose};
+
+use common::objects::WorkloadSpec;
+
+use super::wasm_runtime::WASM_RUNTIME_NAME;
+
+// [impl->swdd~wasm-runtime-config~1]
+#[derive(Debug, serde::Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WasmRuntimeConfig {
+    #[serde(default)]
+    pub module: Option<String>,
+    #[serde(default)]
+    pub module_base64: Option<String>,
+    #[serde(default)]
+    pub args: Vec<String>,
+    #[serde(default)]
+    pub env: HashMap<String, String>,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum WasmModuleSource {
+    File(PathBuf),
+    Binary(Vec<u8>),
+}
+
+impl WasmRuntimeConfig {
+    pub fn module_source(&self) -> Result<WasmModuleSource, String> {
+        match (&self.module, &self.module_base64) {
+            (Some(module), None) => Ok(WasmModuleSource::File(PathBuf::from(module))),
+            (None, Some(module_base64)) => general_purpose::STANDARD
+                .decode(module_base64.trim())
+                .map(WasmModuleSource::Binary)
+                .map_err(|err| format!("Invalid base64 content of the module: '{err}'")),
+            _ => Err("Exactly one of 'module' or 'moduleBase64' must be specified".to_string()),
+        }
+    }
+}
+
+impl TryFrom<&WorkloadSpec> for WasmRuntimeConfig {
+    type Error = String;
+    fn try_from(workload_spec: &WorkloadSpec) -> Result<Self, Self::Error> {
+        if WASM_RUNTIME_NAME != workload_spec.runtime {
+            return Err(format!(
+                "Received a spec for the wrong runtime: '{}'",
+                workload_spec.runtime
+            ));
+        }
+        let workload_cfg: WasmRuntimeConfig =
+            serde_yaml::from_str(workload_spec.runtime_config.as_str())
+                .map_err(|err| err.to_string())?;
+        workload_cfg.module_source()?;
+        Ok(workload_cfg)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, path::PathBuf};
+
+    use common::objects::generate_test_workload_spec_with_param;
+
+    use super::{WasmModuleSource, WasmRuntimeConfig};
+    use crate::runtime_connectors::wasm::wasm_runtime::WASM_RUNTIME_NAME;
+
+    const DIFFERENT_RUNTIME_NAME: &str = "different-runtime-name";
+    const AGENT_NAME: &str = "agent_x";
+    const WORKLOAD_1_NAME: &str = "workload1";
+
+    // [utest->swdd~wasm-runtime-config~1]
+    #[test]
+    fn utest_wasm_config_with_module_path() {
+        let mut workload_spec = generate_test_workload_spec_with_param(
+            AGENT_NAME.to_string(),
+            WORKLOAD_1_NAME.to_string(),
+            WASM_RUNTIME_NAME.to_string(),
+        );
+
+        workload_spec.runtime_config = r#"
+module: /opt/functions/hello.wasm
+args: ["--name", "world"]
+env:
+  LOG_LEVEL: debug
+"#
+        .to_string();
+
+        let workload_cfg = WasmRuntimeConfig::try_from(&workload_spec).unwrap();
+        assert_eq!(
+            workload_cfg,
+            WasmRuntimeConfig {
+                module: Some("/opt/functions/hello.wasm".to_string()),
+                module_base64: None,
+                args: vec!["--name".to_string(), "world".to_string()],
+                env: HashMap::from([("LOG_LEVEL".to_string(), "debug".to_string())]),
+            }
+        );
+        assert_eq!(
+            workload_cfg.module_source(),
+            Ok(WasmModuleSource::File(PathBuf::from(
+                "/opt/functions/hello.wasm"
+            )))
+        );
+    }
+
+    // [utest->swdd~wasm-runtime-config~1]
+    #[test]
+    fn utest_wasm_config_with_module_base64() {
+        let mut workload_spec = generate_test_workload_spec_with_param(
+            AGENT_NAME.to_string(),
+            WORKLOAD_1_NAME.to_string(),
+            WASM_RUNTIME_NAME.to_string(),
+        );
+
+        workload_spec.runtime_config = "moduleBase64: AGFzbQEAAAA=".to_string();
+
+        let workload_cfg = WasmRuntimeConfig::try_from(&workload_spec).unwrap();
+        assert_eq!(
+            workload_cfg.module_source(),
+            Ok(WasmModuleSource::Binary(vec![
+                0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00
+            ]))
+        );
+    }
+
+    // [utest->swdd~wasm-runtime-config~1]
+    #[test]
+    fn utest_wasm_config_failure_invalid_module_source() {
+        let mut workload_spec = generate_test_workload_spec_with_param(
+            AGENT_NAME.to_string(),
+            WORKLOAD_1_NAME.to_string(),
+            WASM_RUNTIME_NAME.to_string(),
+        );
+
+        workload_spec.runtime_config = "args: [\"--verbose\"]".to_string();
+        assert!(WasmRuntimeConfig::try_from(&workload_spec).is_err());
+
+        workload_spec.runtime_config =
+            "module: /opt/functions/hello.wasm\nmoduleBase64: AGFzbQEAAAA=".to_string();
+        assert!(WasmRuntimeConfig::try_from(&workload_spec).is_err());
+
+        workload_spec.runtime_config = "moduleBase64: \"not base64!\"".to_string();
+        assert!(WasmRuntimeConfig::try_from(&workload_spec).is_err());
+    }
+
+    #[test]
+    fn utest_wasm_config_failure_wrong_runtime() {
+        let mut workload_spec = generate_test_workload_spec_with_param(
+            AGENT_NAME.to_string(),
+            WORKLOAD_1_NAME.to_string(),
+            DIFFERENT_RUNTIME_NAME.to_string(),
+        );
+
+        workload_spec.runtime_config = "module: /opt/functions/hello.wasm".to_string();
+
+        assert!(WasmRuntimeConfig::try_from(&workload_spec).is_err());
+    }
+}
@@ -12,7 +12,7 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
-use std::{collections::HashMap, path::PathBuf};
+use std::{collections::HashMap, path::PathBuf, time::Duration};
 
 #[cfg_attr(test, mockall_double::double)]
 use crate::control_interface::authorizer::Authorizer;
@@ -22,11 +22,11 @@ use api::ank_base;
 use common::{
     commands::LogsRequest,
     objects::{
-        AgentName, DeletedWorkload, ExecutionState, WorkloadInstanceName, WorkloadSpec,
-        WorkloadState,
+        AgentName, DeletedWorkload, ExecutionState, WorkloadInstanceName, WorkloadMetrics,
+        WorkloadSpec, WorkloadState,
     },
     request_id_prepending::detach_prefix_from_request_id,
-    to_server_interface::ToServerSender,
+    to_server_interface::{ToServerInterface, ToServerSender},
 };
 
 #[cfg_attr(test, mockall_double::double)]
@@ -58,6 +58,8 @@ use crate::workload::Workload;
 #[cfg(test)]
 use mockall::automock;
 
+const WORKLOAD_METRICS_TIMEOUT: Duration = Duration::from_secs(1);
+
 fn flatten(
     mut runtime_workload_map: HashMap<String, HashMap<String, WorkloadSpec>>,
 ) -> Vec<ReusableWorkloadSpec> {
@@ -192,14 +194,35 @@ impl RuntimeManager {
     pub async fn forward_response(&mut self, mut response: ank_base::Response) {
         // [impl->swdd~agent-uses-id-prefix-forward-control-interface-response-correct-workload~1]
         // [impl->swdd~agent-remove-id-prefix-forwarding-control-interface-response~1]
+        let prefixed_request_id = response.request_id.clone();
         let (workload_name, request_id) = detach_prefix_from_request_id(&response.request_id);
-        if let Some(workload) = self.workloads.get_mut(&workload_name) {
+        let is_event = matches!(
+            response.response_content,
+            Some(ank_base::response::ResponseContent::EventResponse(_))
+        );
+        let forwarded = if let Some(workload) = self.workloads.get_mut(&workload_name) {
             response.request_id = request_id;
-            if let Err(err) = workload.forward_response(response).await {
-                log::warn!("Could not forward response to workload '{workload_name}': '{err}'");
-            }
+            workload
+                .forward_response(response)
+                .await
+                .inspect_err(|err| {
+                    log::warn!("Could not forward response to workload '{workload_name}': '{err}'")
+                })
+                .is_ok()
         } else {
             log::warn!("Could not forward response for unknown workload: '{workload_name}'");
+            false
+        };
+
+        // [impl->swdd~agent-cancels-event-subscriptions-without-control-interface~1]
+        if !forwarded && is_event {
+            log::info!(
+                "Canceling the event subscription '{prefixed_request_id}' as the control interface of workload '{workload_name}' is gone."
+            );
+            let _ = self
+                .control_interface_tx
+                .events_cancel_request(prefixed_request_id)
+                .await;
         }
     }
 
@@ -651,6 +674,38 @@ impl RuntimeManager {
 
         res
     }
+
+    // [impl->swdd~agent-runtime-manager-collects-workload-metrics~1]
+    pub async fn collect_workload_metrics(&self) -> HashMap<String, WorkloadMetrics> {
+        let collected_metrics = self
+            .workloads
+            .iter()
+            .map(|(workload_name, workload)| async move {
+                match tokio::time::timeout(WORKLOAD_METRICS_TIMEOUT, workload.collect_metrics())
+                    .await
+                {
+                    Ok(Ok(workload_metrics)) => Some((workload_name.clone(), workload_metrics)),
+                    Ok(Err(err)) => {
+                        log::debug!(
+                            "Could not collect the metrics of workload '{workload_name}': '{err}'"
+                        );
+                        None
+                    }
+                    Err(_) => {
+                        log::debug!(
+                            "Timeout while collecting the metrics of workload '{workload_name}'."
+                        );
+                        None
+                    }
+                }
+            });
+
+        futures_util::future::join_all(collected_metrics)
+            .await
+            .into_iter()
+            .flatten()
+            .collect()
+    }
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -684,9 +739,9 @@ mod tests {
     use crate::workload_state::workload_state_store::MockWorkloadStateStore;
     use ank_base::response::ResponseContent;
     use api::ank_base::Files;
-    use common::commands::LogsRequest;
+    use common::commands::{self, LogsRequest};
     use common::objects::{
-        self, AddCondition, WorkloadInstanceNameBuilder, WorkloadState,
+        self, AddCondition, WorkloadInstanceNameBuilder, WorkloadMetrics, WorkloadState,
         generate_test_control_interface_access,
         generate_test_workload_spec_with_control_interface_access,
         generate_test_workload_spec_with_dependencies, generate_test_workload_spec_with_param,
@@ -695,7 +750,7 @@ mod tests {
         self, generate_test_complete_state, generate_test_deleted_workload,
         generate_test_deleted_workload_with_dependencies,
     };
-    use common::to_server_interface::ToServerReceiver;
+    use common::to_server_interface::{ToServer, ToServerReceiver};
     use mockall::{Sequence, predicate};
     use std::collections::HashMap;
     use std::error::Error;
@@ -2349,6 +2404,14 @@ mod tests {
                                 configs: Some(ank_base::ConfigMappings {
                                     configs: Default::default()}),
                                 files: Some(Files::default()),
+                                agent_selector: None,
+                                resource_requests: None,
+                                agents: None,
+                                update_strategy: None,
+                                probes: None,
+                                restart_backoff: None,
+                                dependency_timeouts: None,
+                                resources: None,
                             })];
         let mut complete_state = test_utils::generate_test_proto_complete_state(&workloads);
         complete_state.workload_states = Some(ank_base::WorkloadStatesMap {
@@ -2380,6 +2443,7 @@ mod tests {
                 objects::AgentAttributes {
                     cpu_usage: Some(objects::CpuUsage { cpu_usage: 42 }),
                     free_memory: Some(objects::FreeMemory { free_memory: 42 }),
+                    labels: HashMap::new(),
                 }
                 .into(),
             )]),
@@ -2459,6 +2523,87 @@ mod tests {
             .await;
     }
 
+    // [utest->swdd~agent-cancels-event-subscriptions-without-control-interface~1]
+    #[tokio::test]
+    async fn utest_forward_event_of_unknown_workload_cancels_event_subscription() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let mock_workload_scheduler_context = MockWorkloadScheduler::new_context();
+        mock_workload_scheduler_context
+            .expect()
+            .once()
+            .return_once(|_| MockWorkloadScheduler::default());
+
+        let (mut server_receiver, mut runtime_manager, _wl_state_receiver) =
+            RuntimeManagerBuilder::default().build();
+
+        runtime_manager
+            .forward_response(ank_base::Response {
+                request_id: format!("{WORKLOAD_1_NAME}@{REQUEST_ID}"),
+                response_content: Some(ResponseContent::EventResponse(
+                    ank_base::EventResponse::default(),
+                )),
+            })
+            .await;
+
+        assert_eq!(
+            server_receiver.try_recv(),
+            Ok(ToServer::Request(commands::Request {
+                request_id: format!("{WORKLOAD_1_NAME}@{REQUEST_ID}"),
+                request_content: commands::RequestContent::EventsCancelRequest,
+            }))
+        );
+    }
+
+    // [utest->swdd~agent-cancels-event-subscriptions-without-control-interface~1]
+    #[tokio::test]
+    async fn utest_forward_event_without_control_interface_cancels_event_subscription() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let mock_workload_scheduler_context = MockWorkloadScheduler::new_context();
+        mock_workload_scheduler_context
+            .expect()
+            .once()
+            .return_once(|_| MockWorkloadScheduler::default());
+
+        let (mut server_receiver, mut runtime_manager, _wl_state_receiver) =
+            RuntimeManagerBuilder::default().build();
+
+        let mut mock_workload = MockWorkload::default();
+        mock_workload
+            .expect_forward_response()
+            .once()
+            .return_once(|_| {
+                Err(WorkloadError::CompleteState(
+                    "control interface not available".to_string(),
+                ))
+            });
+        runtime_manager
+            .workloads
+            .insert(WORKLOAD_1_NAME.to_string(), mock_workload);
+
+        runtime_manager
+            .forward_response(ank_base::Response {
+                request_id: format!("{WORKLOAD_1_NAME}@{REQUEST_ID}"),
+                response_content: Some(ResponseContent::EventResponse(
+                    ank_base::EventResponse::default(),
+                )),
+            })
+            .await;
+
+        assert_eq!(
+            server_receiver.try_recv(),
+            Ok(ToServer::Request(commands::Request {
+                request_id: format!("{WORKLOAD_1_NAME}@{REQUEST_ID}"),
+                request_content: commands::RequestContent::EventsCancelRequest,
+            }))
+        );
+    }
+
     // [utest->swdd~agent-handles-workloads-with-fulfilled-dependencies~1]
     #[tokio::test]
     async fn utest_update_workload_state_create_workload_with_fulfilled_dependencies() {
@@ -3009,6 +3154,56 @@ mod tests {
         );
     }
 
+    // [utest->swdd~agent-runtime-manager-collects-workload-metrics~1]
+    #[tokio::test]
+    async fn utest_collect_workload_metrics_skips_failed_workloads() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+        let _from_authorizer_context = setup_from_authorizer();
+
+        let mock_workload_scheduler_context = MockWorkloadScheduler::new_context();
+        mock_workload_scheduler_context
+            .expect()
+            .once()
+            .return_once(|_| MockWorkloadScheduler::default());
+
+        let (_server_receiver, mut runtime_manager, _wl_state_receiver) =
+            RuntimeManagerBuilder::default().build();
+
+        let workload_1_metrics = WorkloadMetrics {
+            cpu_usage: Some(5),
+            memory_usage: Some(1024),
+            restart_count: 2,
+        };
+
+        let mut workload_1_mock = MockWorkload::default();
+        let returned_metrics = workload_1_metrics.clone();
+        workload_1_mock
+            .expect_collect_metrics()
+            .once()
+            .return_once(move || Ok(returned_metrics));
+        let mut workload_2_mock = MockWorkload::default();
+        workload_2_mock
+            .expect_collect_metrics()
+            .once()
+            .return_once(|| Err(Box::new(MockError())));
+
+        runtime_manager
+            .workloads
+            .insert(WORKLOAD_1_NAME.to_string(), workload_1_mock);
+        runtime_manager
+            .workloads
+            .insert(WORKLOAD_2_NAME.to_string(), workload_2_mock);
+
+        let workload_metrics = runtime_manager.collect_workload_metrics().await;
+
+        assert_eq!(
+            workload_metrics,
+            HashMap::from([(WORKLOAD_1_NAME.to_string(), workload_1_metrics)])
+        );
+    }
+
     fn setup_from_authorizer() -> Box<dyn Any> {
         let authorizer_from_context_mock = MockAuthorizer::from_context();
         authorizer_from_context_mock
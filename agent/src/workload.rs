@@ -42,7 +42,7 @@ use api::ank_base;
 
 use common::{
     from_server_interface::FromServer,
-    objects::{WorkloadInstanceName, WorkloadSpec},
+    objects::{WorkloadInstanceName, WorkloadMetrics, WorkloadSpec},
 };
 
 #[cfg(test)]
@@ -72,9 +72,11 @@ pub enum WorkloadCommand {
     Delete,
     Update(Option<Box<WorkloadSpec>>, Option<ControlInterfacePath>),
     Retry(Box<WorkloadInstanceName>, RetryToken),
+    Restart(Box<WorkloadInstanceName>, RetryToken),
     Create,
     Resume,
     StartLogFetcher(LogRequestOptions, oneshot::Sender<Box<dyn LogFetcher>>),
+    CollectMetrics(oneshot::Sender<WorkloadMetrics>),
 }
 
 #[cfg(test)]
@@ -87,6 +89,7 @@ impl PartialEq for WorkloadCommand {
             (Self::Create, Self::Create) => true,
             (Self::Resume, Self::Resume) => true,
             (Self::StartLogFetcher(_, _), Self::StartLogFetcher(_, _)) => false,
+            (Self::CollectMetrics(_), Self::CollectMetrics(_)) => false,
             _ => false,
         }
     }
@@ -228,6 +231,12 @@ impl Workload {
             .start_collecting_logs(log_request_options)
             .await
     }
+
+    // [impl->swdd~agent-workload-obj-collect-metrics-command~1]
+    #[cfg_attr(test, allow(dead_code))]
+    pub async fn collect_metrics(&self) -> Result<WorkloadMetrics, Box<dyn Error>> {
+        self.channel.collect_metrics().await
+    }
 }
 
 //////////////////////////////////////////////////////////////////////////////
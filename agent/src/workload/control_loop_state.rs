@@ -18,12 +18,20 @@ use crate::BUFFER_SIZE;
 use common::objects::{WorkloadInstanceName, WorkloadSpec, WorkloadState};
 use std::path::PathBuf;
 use std::str::FromStr;
+use std::time::Instant;
 
 use crate::control_interface::ControlInterfacePath;
 
 #[cfg_attr(test, mockall_double::double)]
 use super::retry_manager::RetryManager;
 
+// The previous instance of an at least once update, kept until the new instance is running.
+pub struct ReplacedWorkload<WorkloadId, StChecker> {
+    pub instance_name: WorkloadInstanceName,
+    pub workload_id: WorkloadId,
+    pub state_checker: Option<StChecker>,
+}
+
 pub struct ControlLoopState<WorkloadId, StChecker>
 where
     WorkloadId: ToString + FromStr + Clone + Send + Sync + 'static,
@@ -41,6 +49,10 @@ where
     pub command_receiver: WorkloadCommandReceiver,
     pub retry_sender: WorkloadCommandSender,
     pub retry_manager: RetryManager,
+    pub restart_count: u32,
+    // The time since which the workload is running without interruption.
+    pub running_since: Option<Instant>,
+    pub replaced_workload: Option<ReplacedWorkload<WorkloadId, StChecker>>,
 }
 
 impl<WorkloadId, StChecker> ControlLoopState<WorkloadId, StChecker>
@@ -163,6 +175,9 @@ where
                 .retry_sender
                 .ok_or_else(|| "WorkloadCommandSender is not set".to_string())?,
             retry_manager: Default::default(),
+            restart_count: 0,
+            running_since: None,
+            replaced_workload: None,
         })
     }
 }
@@ -304,6 +319,9 @@ mod tests {
             command_receiver: workload_command_receiver,
             retry_sender,
             retry_manager: Default::default(),
+            restart_count: 0,
+            running_since: None,
+            replaced_workload: None,
         };
 
         assert_eq!(
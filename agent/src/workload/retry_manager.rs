@@ -12,6 +12,7 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
+use common::objects::RestartBackoff;
 #[cfg(test)]
 use mockall::mock;
 #[cfg(not(test))]
@@ -43,6 +44,8 @@ pub struct RetryManager {
 #[derive(Debug)]
 pub struct RetryToken {
     counter: u32,
+    base_backoff_in_millis: u64,
+    backoff_max_in_millis: u64,
     valid: watch::Receiver<bool>,
 }
 
@@ -53,15 +56,39 @@ impl RetryManager {
     }
 
     pub fn new_token(&mut self) -> RetryToken {
+        // [impl->swdd~agent-workload-control-loop-reset-backoff-on-update~1]
+        self.new_token_with_backoff(0, BASE_BACKOFF_IN_MILLIS, BACKOFF_MAX_IN_MILLIS)
+    }
+
+    // [impl->swdd~agent-workload-control-loop-restarts-with-backoff~1]
+    pub fn new_restart_token(
+        &mut self,
+        restart_count: u32,
+        restart_backoff: &RestartBackoff,
+    ) -> RetryToken {
+        self.new_token_with_backoff(
+            restart_count,
+            restart_backoff.initial_delay_millis,
+            restart_backoff.max_delay_millis,
+        )
+    }
+
+    fn new_token_with_backoff(
+        &mut self,
+        counter: u32,
+        base_backoff_in_millis: u64,
+        backoff_max_in_millis: u64,
+    ) -> RetryToken {
         // [impl->swdd~agent-workload-control-loop-prevents-retries-on-other-workload-commands~2]
         self.invalidate();
 
         let (sender, receiver) = watch::channel(true);
         self.current_token_is_valid_sink = sender;
 
-        // [impl->swdd~agent-workload-control-loop-reset-backoff-on-update~1]
         RetryToken {
-            counter: 0,
+            counter,
+            base_backoff_in_millis,
+            backoff_max_in_millis,
             valid: receiver,
         }
     }
@@ -114,8 +141,9 @@ impl RetryToken {
 
     fn calc_backoff(&self) -> u64 {
         min(
-            2u64.pow(self.counter) * BASE_BACKOFF_IN_MILLIS,
-            BACKOFF_MAX_IN_MILLIS,
+            2u64.saturating_pow(self.counter)
+                .saturating_mul(self.base_backoff_in_millis),
+            self.backoff_max_in_millis,
         )
     }
 }
@@ -152,6 +180,7 @@ mock! {
     pub RetryManager {
         pub fn invalidate(&mut self);
         pub fn new_token(&mut self) -> MockRetryToken;
+        pub fn new_restart_token(&mut self, restart_count: u32, restart_backoff: &RestartBackoff) -> MockRetryToken;
     }
 }
 
@@ -179,6 +208,7 @@ mod tests {
     use tokio::sync::oneshot;
 
     use super::RetryToken;
+    use common::objects::RestartBackoff;
 
     use crate::test_helper::MOCKALL_CONTEXT_SYNC;
 
@@ -413,6 +443,42 @@ mod tests {
         assert_eq!(token.counter(), 1);
     }
 
+    // [utest->swdd~agent-workload-control-loop-restarts-with-backoff~1]
+    #[test]
+    fn utest_restart_token_uses_restart_backoff() {
+        let _lock = MOCKALL_CONTEXT_SYNC.get_lock();
+        reset_random();
+        let mut manager = super::RetryManager::default();
+        let restart_backoff = RestartBackoff {
+            initial_delay_millis: 100,
+            max_delay_millis: 300,
+            ..Default::default()
+        };
+
+        let waker = waker();
+        let mut context = Context::from_waker(&waker);
+
+        let token = manager.new_restart_token(1, &restart_backoff);
+        assert_eq!(token.counter(), 1);
+
+        let (callback, called) = create_callback();
+        let mut call_res = pin!(token.call_with_backoff(callback));
+        assert_eq!(call_res.as_mut().poll(&mut context), Poll::Pending);
+        assert!(called.borrow().is_none());
+        assert_sleep_for_millis(200 - RANDOM_OFFSET_1);
+
+        assert_eq!(call_res.as_mut().poll(&mut context), Poll::Pending);
+        assert_eq!(call_res.as_mut().poll(&mut context), Poll::Ready(()));
+        let token = called.borrow_mut().take().unwrap();
+        assert_eq!(token.counter(), 2);
+
+        let (callback, called) = create_callback();
+        let mut call_res = pin!(token.call_with_backoff(callback));
+        assert_eq!(call_res.as_mut().poll(&mut context), Poll::Pending);
+        assert!(called.borrow().is_none());
+        assert_sleep_for_millis(300 - RANDOM_OFFSET_2);
+    }
+
     // [utest->swdd~agent-workload-control-loop-prevents-retries-on-other-workload-commands~2]
     #[test]
     fn utest_invalidate_invalidates_token() {
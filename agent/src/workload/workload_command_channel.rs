@@ -16,7 +16,7 @@ use crate::{
     runtime_connectors::{log_fetcher::LogFetcher, LogRequestOptions},
     workload::WorkloadCommand,
 };
-use common::objects::{WorkloadInstanceName, WorkloadSpec};
+use common::objects::{WorkloadInstanceName, WorkloadMetrics, WorkloadSpec};
 #[cfg(test)]
 use mockall_double::double;
 use tokio::sync::{mpsc, oneshot};
@@ -68,6 +68,30 @@ impl WorkloadCommandSender {
         Ok(())
     }
 
+    pub async fn restart(
+        &self,
+        instance_name: WorkloadInstanceName,
+        retry_token: RetryToken,
+    ) -> Result<(), mpsc::error::SendError<WorkloadCommand>> {
+        let sender = self.sender.clone();
+
+        // [impl->swdd~agent-workload-control-loop-restarts-with-backoff~1]
+        tokio::spawn(retry_token.call_with_backoff(|retry_token| async move {
+            if sender
+                .send(WorkloadCommand::Restart(
+                    Box::new(instance_name),
+                    retry_token,
+                ))
+                .await
+                .is_err()
+            {
+                log::debug!("Could not send restart command");
+            };
+        }));
+
+        Ok(())
+    }
+
     pub async fn update(
         &self,
         workload_spec: Option<WorkloadSpec>,
@@ -103,6 +127,15 @@ impl WorkloadCommandSender {
             .await?;
         Ok(receiver.await?)
     }
+
+    // [impl->swdd~agent-workload-obj-collect-metrics-command~1]
+    pub async fn collect_metrics(&self) -> Result<WorkloadMetrics, Box<dyn std::error::Error>> {
+        let (sender, receiver) = oneshot::channel();
+        self.sender
+            .send(WorkloadCommand::CollectMetrics(sender))
+            .await?;
+        Ok(receiver.await?)
+    }
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -120,7 +153,9 @@ mod tests {
         workload::retry_manager::MockRetryToken,
     };
 
-    use super::{ControlInterfacePath, WorkloadCommand, WorkloadCommandSender, WorkloadSpec};
+    use super::{
+        ControlInterfacePath, WorkloadCommand, WorkloadCommandSender, WorkloadMetrics, WorkloadSpec,
+    };
     use common::objects::generate_test_workload_spec;
     use std::path::PathBuf;
     use tokio::sync::mpsc::Receiver;
@@ -179,6 +214,32 @@ mod tests {
         assert!(received_retry_token.has_been_called);
     }
 
+    // [utest->swdd~agent-workload-control-loop-restarts-with-backoff~1]
+    #[tokio::test]
+    async fn utest_send_restart() {
+        let (workload_command_sender, mut workload_command_receiver) = WorkloadCommandSender::new();
+        let retry_token = MockRetryToken {
+            valid: true,
+            has_been_called: false,
+        };
+
+        workload_command_sender
+            .restart(WORKLOAD_SPEC.instance_name.clone(), retry_token)
+            .await
+            .unwrap();
+
+        let workload_command = workload_command_receiver.recv().await.unwrap();
+
+        let WorkloadCommand::Restart(received_instance_name, received_retry_token) =
+            workload_command
+        else {
+            panic!("Expected WorkloadCommand::Restart");
+        };
+
+        assert_eq!(*received_instance_name, WORKLOAD_SPEC.instance_name);
+        assert!(received_retry_token.has_been_called);
+    }
+
     // [utest->swdd~agent-workload-control-loop-executes-create~4]
     #[tokio::test]
     async fn utest_send_update() {
@@ -279,6 +340,41 @@ mod tests {
         assert!(res.is_err());
     }
 
+    // [utest->swdd~agent-workload-obj-collect-metrics-command~1]
+    #[tokio::test]
+    async fn utest_collect_metrics_success() {
+        let (workload_command_sender, mut workload_command_receiver) = WorkloadCommandSender::new();
+
+        let jh = tokio::spawn(async move {
+            let Some(WorkloadCommand::CollectMetrics(result_sink)) =
+                workload_command_receiver.recv().await
+            else {
+                panic!("Did not receive CollectMetrics command")
+            };
+            result_sink
+                .send(WorkloadMetrics {
+                    restart_count: 1,
+                    ..Default::default()
+                })
+                .unwrap();
+        });
+
+        let res = workload_command_sender.collect_metrics().await;
+
+        assert_eq!(res.unwrap().restart_count, 1);
+        assert!(jh.await.is_ok());
+    }
+
+    // [utest->swdd~agent-workload-obj-collect-metrics-command~1]
+    #[tokio::test]
+    async fn utest_collect_metrics_receiver_gone() {
+        let (workload_command_sender, _) = WorkloadCommandSender::new();
+
+        let res = workload_command_sender.collect_metrics().await;
+
+        assert!(res.is_err());
+    }
+
     // [utest->swdd~agent-workload-obj-start-log-fetcher-command~1]
     fn listen_for_start_log_fetcher(
         mut receiver: Receiver<WorkloadCommand>,
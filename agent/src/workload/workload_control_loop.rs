@@ -16,15 +16,20 @@ use crate::control_interface::ControlInterfacePath;
 use crate::io_utils::FileSystemError;
 use crate::runtime_connectors::log_fetcher::LogFetcher;
 use crate::runtime_connectors::{LogRequestOptions, RuntimeError, StateChecker};
+use crate::workload::control_loop_state::ReplacedWorkload;
 use crate::workload::{ControlLoopState, WorkloadCommand};
 use crate::workload_files::WorkloadFilesBasePath;
 use crate::workload_state::{WorkloadStateSender, WorkloadStateSenderInterface};
-use common::objects::{ExecutionState, RestartPolicy, WorkloadInstanceName, WorkloadSpec};
+use common::objects::{
+    ExecutionState, RestartBackoff, RestartPolicy, UpdateOrdering, WorkloadInstanceName,
+    WorkloadMetrics, WorkloadSpec,
+};
 use common::std_extensions::IllegalStateResult;
 use futures_util::Future;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::time::{Duration, Instant};
 
 #[cfg_attr(test, mockall_double::double)]
 use crate::io_utils::filesystem_async;
@@ -58,19 +63,27 @@ impl WorkloadControlLoop {
                     // [impl->swdd~workload-control-loop-checks-workload-state-validity~1]
                     if Self::is_same_workload(control_loop_state.instance_name(), &new_workload_state.instance_name) {
 
+                        // [impl->swdd~agent-workload-control-loop-resets-restart-count-after-stable-run~1]
+                        Self::reset_restart_count_after_stable_run(&mut control_loop_state, &new_workload_state.execution_state);
+
                         /* forward immediately the new workload state to the agent manager
                         to avoid delays through the restart handling */
                         // [impl->swdd~workload-control-loop-sends-workload-states~2]
                         Self::send_workload_state_to_agent(
                             &control_loop_state.to_agent_workload_state_sender,
                             &new_workload_state.instance_name,
-                            new_workload_state.execution_state.clone(),
+                            Self::with_restart_count(new_workload_state.execution_state.clone(), control_loop_state.restart_count),
                         ).await;
 
-                        // [impl->swdd~workload-control-loop-restarts-workload-with-enabled-restart-policy~2]
+                        // [impl->swdd~agent-workload-control-loop-deletes-replaced-workload-when-running~1]
+                        if new_workload_state.execution_state.is_running() && control_loop_state.replaced_workload.is_some() {
+                            control_loop_state = Self::delete_replaced_workload_on_runtime(control_loop_state).await;
+                        }
+
+                        // [impl->swdd~workload-control-loop-restarts-workload-with-enabled-restart-policy~3]
                         if Self::restart_policy_matches_execution_state(&control_loop_state.workload_spec.restart_policy, &new_workload_state.execution_state) {
                             // [impl->swdd~workload-control-loop-handles-workload-restarts~2]
-                            control_loop_state = Self::restart_workload_on_runtime(control_loop_state).await;
+                            control_loop_state = Self::handle_restart(control_loop_state, new_workload_state.execution_state).await;
                         }
                     }
 
@@ -85,6 +98,8 @@ impl WorkloadControlLoop {
                             // [impl->swdd~agent-workload-control-loop-prevents-retries-on-other-workload-commands~2]
                             control_loop_state.retry_manager.invalidate();
 
+                            control_loop_state = Self::delete_replaced_workload_on_runtime(control_loop_state).await;
+
                             if let Some(new_control_loop_state) = Self::delete_workload_on_runtime(control_loop_state).await {
                                 control_loop_state = new_control_loop_state;
                             } else {
@@ -98,6 +113,9 @@ impl WorkloadControlLoop {
                             // [impl->swdd~agent-workload-control-loop-prevents-retries-on-other-workload-commands~2]
                             control_loop_state.retry_manager.invalidate();
 
+                            // [impl->swdd~agent-workload-control-loop-resets-restart-count-on-update~1]
+                            control_loop_state.restart_count = 0;
+
                             control_loop_state = Self::update_workload_on_runtime(
                                 control_loop_state,
                                 runtime_workload_config,
@@ -117,6 +135,16 @@ impl WorkloadControlLoop {
                             )
                             .await;
                         }
+                        // [impl->swdd~agent-workload-control-loop-restarts-with-backoff~1]
+                        Some(WorkloadCommand::Restart(_instance_name, retry_token)) => {
+                            log::debug!("Received WorkloadCommand::Restart.");
+
+                            if retry_token.is_valid() {
+                                control_loop_state = Self::restart_workload_on_runtime(control_loop_state).await;
+                            } else {
+                                log::debug!("Ignore outdated restart command");
+                            }
+                        }
                         // [impl->swdd~agent-workload-control-loop-executes-create~4]
                         Some(WorkloadCommand::Create) => {
                             log::debug!("Received WorkloadCommand::Create.");
@@ -153,6 +181,14 @@ impl WorkloadControlLoop {
                                 }
                             }
                         }
+                        // [impl->swdd~agent-workload-control-loop-collects-metrics~1]
+                        Some(WorkloadCommand::CollectMetrics(result_sink)) => {
+                            let workload_metrics = Self::collect_metrics(&control_loop_state).await;
+                            if result_sink.send(workload_metrics).is_err() {
+                                log::debug!("Could not return the metrics of workload '{}'.",
+                                    control_loop_state.instance_name().workload_name());
+                            }
+                        }
                         _ => {
                             log::warn!(
                                 "Could not wait for internal stop command for workload '{}'.",
@@ -176,8 +212,114 @@ impl WorkloadControlLoop {
             .await;
     }
 
+    // [impl->swdd~agent-workload-control-loop-shows-restart-count~1]
+    fn with_restart_count(
+        mut execution_state: ExecutionState,
+        restart_count: u32,
+    ) -> ExecutionState {
+        if restart_count > 0 {
+            execution_state.additional_info = if execution_state.additional_info.is_empty() {
+                format!("Restarts: {restart_count}")
+            } else {
+                format!(
+                    "{} (restarts: {restart_count})",
+                    execution_state.additional_info
+                )
+            };
+        }
+        execution_state
+    }
+
+    fn reset_restart_count_after_stable_run<WorkloadId, StChecker>(
+        control_loop_state: &mut ControlLoopState<WorkloadId, StChecker>,
+        execution_state: &ExecutionState,
+    ) where
+        WorkloadId: ToString + FromStr + Clone + Send + Sync + 'static,
+        StChecker: StateChecker<WorkloadId> + Send + Sync + 'static,
+    {
+        if execution_state.is_running() {
+            control_loop_state
+                .running_since
+                .get_or_insert_with(Instant::now);
+            return;
+        }
+
+        let Some(running_since) = control_loop_state.running_since.take() else {
+            return;
+        };
+
+        let reset_after_millis = control_loop_state
+            .workload_spec
+            .restart_backoff
+            .as_ref()
+            .map_or(
+                RestartBackoff::default().reset_after_millis,
+                |restart_backoff| restart_backoff.reset_after_millis,
+            );
+        if running_since.elapsed() >= Duration::from_millis(reset_after_millis) {
+            log::debug!(
+                "Reset the restarts of workload '{}' after a stable run",
+                control_loop_state.instance_name().workload_name()
+            );
+            control_loop_state.restart_count = 0;
+        }
+    }
+
+    async fn handle_restart<WorkloadId, StChecker>(
+        mut control_loop_state: ControlLoopState<WorkloadId, StChecker>,
+        execution_state: ExecutionState,
+    ) -> ControlLoopState<WorkloadId, StChecker>
+    where
+        WorkloadId: ToString + FromStr + Clone + Send + Sync + 'static,
+        StChecker: StateChecker<WorkloadId> + Send + Sync + 'static,
+    {
+        let Some(restart_backoff) = control_loop_state.workload_spec.restart_backoff.clone() else {
+            return Self::restart_workload_on_runtime(control_loop_state).await;
+        };
+
+        let restart_count = control_loop_state.restart_count;
+        let instance_name = control_loop_state.instance_name().clone();
+
+        // [impl->swdd~agent-workload-control-loop-stops-restarts-at-limit~1]
+        if restart_backoff.restart_limit_reached(restart_count) {
+            log::warn!(
+                "Workload '{}' reached its restart limit after {} restarts",
+                instance_name.workload_name(),
+                restart_count
+            );
+            Self::send_workload_state_to_agent(
+                &control_loop_state.to_agent_workload_state_sender,
+                &instance_name,
+                ExecutionState::crash_loop_no_restart(
+                    restart_count,
+                    execution_state.additional_info,
+                ),
+            )
+            .await;
+            return control_loop_state;
+        }
+
+        // [impl->swdd~agent-workload-control-loop-restarts-with-backoff~1]
+        Self::send_workload_state_to_agent(
+            &control_loop_state.to_agent_workload_state_sender,
+            &instance_name,
+            ExecutionState::crash_loop_backoff(restart_count + 1, execution_state.additional_info),
+        )
+        .await;
+
+        let retry_token = control_loop_state
+            .retry_manager
+            .new_restart_token(restart_count, &restart_backoff);
+        control_loop_state
+            .retry_sender
+            .restart(instance_name, retry_token)
+            .await
+            .unwrap_or_else(|err| log::info!("Could not send WorkloadCommand::Restart: '{err}'"));
+        control_loop_state
+    }
+
     async fn restart_workload_on_runtime<WorkloadId, StChecker>(
-        control_loop_state: ControlLoopState<WorkloadId, StChecker>,
+        mut control_loop_state: ControlLoopState<WorkloadId, StChecker>,
     ) -> ControlLoopState<WorkloadId, StChecker>
     where
         WorkloadId: ToString + FromStr + Clone + Send + Sync + 'static,
@@ -192,6 +334,7 @@ impl WorkloadControlLoop {
             control_loop_state.workload_spec.restart_policy,
         );
 
+        control_loop_state.restart_count += 1;
         let workload_spec = control_loop_state.workload_spec.clone();
         let control_interface_path = control_loop_state.control_interface_path.clone();
 
@@ -218,8 +361,14 @@ impl WorkloadControlLoop {
     ) -> bool {
         match restart_policy {
             RestartPolicy::Never => false,
-            RestartPolicy::OnFailure => execution_state.is_failed(),
-            RestartPolicy::Always => execution_state.is_failed() || execution_state.is_succeeded(),
+            RestartPolicy::OnFailure => {
+                execution_state.is_failed() || execution_state.is_unhealthy()
+            }
+            RestartPolicy::Always => {
+                execution_state.is_failed()
+                    || execution_state.is_unhealthy()
+                    || execution_state.is_succeeded()
+            }
         }
     }
 
@@ -465,13 +614,48 @@ impl WorkloadControlLoop {
     // [impl->swdd~agent-workload-control-loop-executes-update~3]
     async fn update_workload_on_runtime<WorkloadId, StChecker>(
         mut control_loop_state: ControlLoopState<WorkloadId, StChecker>,
-        new_workload_spec: Option<Box<WorkloadSpec>>,
+        mut new_workload_spec: Option<Box<WorkloadSpec>>,
         control_interface_path: Option<ControlInterfacePath>,
     ) -> ControlLoopState<WorkloadId, StChecker>
     where
         WorkloadId: ToString + FromStr + Clone + Send + Sync + 'static,
         StChecker: StateChecker<WorkloadId> + Send + Sync + 'static,
     {
+        // [impl->swdd~agent-workload-control-loop-restores-replaced-workload~1]
+        if let Some(new_spec) = new_workload_spec.take_if(|new_spec| {
+            control_loop_state
+                .replaced_workload
+                .as_ref()
+                .is_some_and(|replaced| replaced.instance_name == new_spec.instance_name)
+        }) {
+            return Self::restore_replaced_workload_on_runtime(
+                control_loop_state,
+                new_spec,
+                control_interface_path,
+            )
+            .await;
+        }
+
+        // the old instance of a previous at least once update which is not running yet is not needed anymore
+        control_loop_state = Self::delete_replaced_workload_on_runtime(control_loop_state).await;
+
+        // [impl->swdd~agent-workload-control-loop-executes-at-least-once-update~1]
+        if let Some(new_spec) = new_workload_spec.take_if(|new_spec| {
+            new_spec.update_ordering == UpdateOrdering::AtLeastOnce
+                && control_loop_state.workload_id.is_some()
+                && !Self::is_same_workload(
+                    control_loop_state.instance_name(),
+                    &new_spec.instance_name,
+                )
+        }) {
+            return Self::replace_workload_on_runtime(
+                control_loop_state,
+                new_spec,
+                control_interface_path,
+            )
+            .await;
+        }
+
         Self::send_workload_state_to_agent(
             &control_loop_state.to_agent_workload_state_sender,
             control_loop_state.instance_name(),
@@ -564,6 +748,179 @@ impl WorkloadControlLoop {
         control_loop_state
     }
 
+    // [impl->swdd~agent-workload-control-loop-executes-at-least-once-update~1]
+    async fn replace_workload_on_runtime<WorkloadId, StChecker>(
+        mut control_loop_state: ControlLoopState<WorkloadId, StChecker>,
+        new_workload_spec: Box<WorkloadSpec>,
+        control_interface_path: Option<ControlInterfacePath>,
+    ) -> ControlLoopState<WorkloadId, StChecker>
+    where
+        WorkloadId: ToString + FromStr + Clone + Send + Sync + 'static,
+        StChecker: StateChecker<WorkloadId> + Send + Sync + 'static,
+    {
+        if let Some(workload_id) = control_loop_state.workload_id.take() {
+            control_loop_state.replaced_workload = Some(ReplacedWorkload {
+                instance_name: control_loop_state.instance_name().clone(),
+                workload_id,
+                state_checker: control_loop_state.state_checker.take(),
+            });
+        }
+
+        log::debug!(
+            "Creating the new instance of workload '{}' before deleting the old one",
+            control_loop_state.instance_name().workload_name()
+        );
+        control_loop_state.workload_spec = *new_workload_spec;
+        control_loop_state.control_interface_path = control_interface_path;
+
+        Self::send_workload_state_to_agent(
+            &control_loop_state.to_agent_workload_state_sender,
+            control_loop_state.instance_name(),
+            ExecutionState::starting_triggered(),
+        )
+        .await;
+
+        let retry_token = control_loop_state.retry_manager.new_token();
+
+        Self::create_workload_on_runtime(
+            control_loop_state,
+            retry_token,
+            Self::send_retry_for_workload,
+        )
+        .await
+    }
+
+    // [impl->swdd~agent-workload-control-loop-restores-replaced-workload~1]
+    async fn restore_replaced_workload_on_runtime<WorkloadId, StChecker>(
+        mut control_loop_state: ControlLoopState<WorkloadId, StChecker>,
+        replaced_workload_spec: Box<WorkloadSpec>,
+        control_interface_path: Option<ControlInterfacePath>,
+    ) -> ControlLoopState<WorkloadId, StChecker>
+    where
+        WorkloadId: ToString + FromStr + Clone + Send + Sync + 'static,
+        StChecker: StateChecker<WorkloadId> + Send + Sync + 'static,
+    {
+        Self::send_workload_state_to_agent(
+            &control_loop_state.to_agent_workload_state_sender,
+            control_loop_state.instance_name(),
+            ExecutionState::stopping_requested(),
+        )
+        .await;
+
+        if let Some(new_id) = control_loop_state.workload_id.take() {
+            if let Err(err) = control_loop_state.runtime.delete_workload(&new_id).await {
+                Self::send_workload_state_to_agent(
+                    &control_loop_state.to_agent_workload_state_sender,
+                    control_loop_state.instance_name(),
+                    ExecutionState::delete_failed(err.to_string()),
+                )
+                .await;
+
+                log::warn!(
+                    "Could not restore the replaced instance of workload '{}': '{}'",
+                    control_loop_state.instance_name().workload_name(),
+                    err
+                );
+                control_loop_state.workload_id = Some(new_id);
+
+                return control_loop_state;
+            } else if let Some(new_checker) = control_loop_state.state_checker.take() {
+                new_checker.stop_checker().await;
+            }
+        }
+
+        Self::delete_folder(&WorkloadFilesBasePath::from((
+            &control_loop_state.run_folder,
+            control_loop_state.instance_name(),
+        )))
+        .await;
+        Self::delete_folder(
+            &control_loop_state
+                .instance_name()
+                .pipes_folder_name(&control_loop_state.run_folder),
+        )
+        .await;
+
+        Self::send_workload_state_to_agent(
+            &control_loop_state.to_agent_workload_state_sender,
+            control_loop_state.instance_name(),
+            ExecutionState::removed(),
+        )
+        .await;
+
+        if let Some(replaced_workload) = control_loop_state.replaced_workload.take() {
+            log::debug!(
+                "Restored the replaced instance of workload '{}'",
+                replaced_workload.instance_name.workload_name()
+            );
+            control_loop_state.workload_id = Some(replaced_workload.workload_id);
+            control_loop_state.state_checker = replaced_workload.state_checker;
+        }
+        control_loop_state.workload_spec = *replaced_workload_spec;
+        control_loop_state.control_interface_path = control_interface_path;
+        control_loop_state
+    }
+
+    // [impl->swdd~agent-workload-control-loop-deletes-replaced-workload-when-running~1]
+    async fn delete_replaced_workload_on_runtime<WorkloadId, StChecker>(
+        mut control_loop_state: ControlLoopState<WorkloadId, StChecker>,
+    ) -> ControlLoopState<WorkloadId, StChecker>
+    where
+        WorkloadId: ToString + FromStr + Clone + Send + Sync + 'static,
+        StChecker: StateChecker<WorkloadId> + Send + Sync + 'static,
+    {
+        let Some(replaced_workload) = control_loop_state.replaced_workload.take() else {
+            return control_loop_state;
+        };
+
+        if let Err(err) = control_loop_state
+            .runtime
+            .delete_workload(&replaced_workload.workload_id)
+            .await
+        {
+            Self::send_workload_state_to_agent(
+                &control_loop_state.to_agent_workload_state_sender,
+                &replaced_workload.instance_name,
+                ExecutionState::delete_failed(err.to_string()),
+            )
+            .await;
+
+            log::warn!(
+                "Could not delete the replaced instance of workload '{}': '{}'",
+                replaced_workload.instance_name.workload_name(),
+                err
+            );
+            control_loop_state.replaced_workload = Some(replaced_workload);
+
+            return control_loop_state;
+        }
+
+        if let Some(old_checker) = replaced_workload.state_checker {
+            old_checker.stop_checker().await;
+        }
+
+        Self::delete_folder(&WorkloadFilesBasePath::from((
+            &control_loop_state.run_folder,
+            &replaced_workload.instance_name,
+        )))
+        .await;
+        Self::delete_folder(
+            &replaced_workload
+                .instance_name
+                .pipes_folder_name(&control_loop_state.run_folder),
+        )
+        .await;
+
+        Self::send_workload_state_to_agent(
+            &control_loop_state.to_agent_workload_state_sender,
+            &replaced_workload.instance_name,
+            ExecutionState::removed(),
+        )
+        .await;
+
+        control_loop_state
+    }
+
     async fn retry_create_workload_on_runtime<WorkloadId, StChecker>(
         control_loop_state: ControlLoopState<WorkloadId, StChecker>,
         retry_token: RetryToken,
@@ -659,6 +1016,33 @@ impl WorkloadControlLoop {
             .runtime
             .get_log_fetcher(workload_id.clone(), log_request_options)
     }
+
+    // [impl->swdd~agent-workload-control-loop-collects-metrics~1]
+    async fn collect_metrics<WorkloadId, StChecker>(
+        control_loop_state: &ControlLoopState<WorkloadId, StChecker>,
+    ) -> WorkloadMetrics
+    where
+        WorkloadId: ToString + FromStr + Clone + Send + Sync + 'static,
+        StChecker: StateChecker<WorkloadId> + Send + Sync + 'static,
+    {
+        let mut workload_metrics = match &control_loop_state.workload_id {
+            Some(workload_id) => control_loop_state
+                .runtime
+                .get_workload_metrics(workload_id)
+                .await
+                .unwrap_or_else(|err| {
+                    log::trace!(
+                        "No resource usage for workload '{}': '{}'",
+                        control_loop_state.instance_name().workload_name(),
+                        err
+                    );
+                    WorkloadMetrics::default()
+                }),
+            None => WorkloadMetrics::default(),
+        };
+        workload_metrics.restart_count = control_loop_state.restart_count;
+        workload_metrics
+    }
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -706,7 +1090,10 @@ mod tests {
         generate_test_rendered_workload_files, generate_test_workload_spec_with_param,
         generate_test_workload_spec_with_rendered_files,
     };
-    use common::objects::{RestartPolicy, generate_test_workload_state_with_workload_spec};
+    use common::objects::{
+        RestartBackoff, RestartPolicy, UpdateOrdering, WorkloadMetrics, WorkloadState,
+        generate_test_workload_state_with_workload_spec,
+    };
 
     use tokio::{sync::mpsc, time::timeout};
 
@@ -757,11 +1144,280 @@ mod tests {
         let mut new_mock_state_checker = StubStateChecker::new();
         new_mock_state_checker.panic_if_not_stopped();
 
-        let old_workload_spec = generate_test_workload_spec_with_param(
+        let old_workload_spec = generate_test_workload_spec_with_param(
+            AGENT_NAME.to_string(),
+            WORKLOAD_1_NAME.to_string(),
+            RUNTIME_NAME.to_string(),
+        );
+
+        let mut new_workload_spec = old_workload_spec.clone();
+        new_workload_spec.runtime_config = "changed config".to_string();
+        new_workload_spec.instance_name = WorkloadInstanceName::builder()
+            .agent_name(old_workload_spec.instance_name.agent_name())
+            .workload_name(old_workload_spec.instance_name.workload_name())
+            .config(&new_workload_spec.runtime_config)
+            .build();
+
+        let mut runtime_mock = MockRuntimeConnector::new();
+        runtime_mock.expect(vec![
+            RuntimeCall::DeleteWorkload(OLD_WORKLOAD_ID.to_string(), Ok(())),
+            RuntimeCall::CreateWorkload(
+                new_workload_spec.clone(),
+                Some(PIPES_LOCATION.into()),
+                HashMap::default(),
+                Ok((WORKLOAD_ID.to_string(), new_mock_state_checker)),
+            ),
+            // Since we also send a delete command to exit the control loop properly, the new workload
+            // will also be deleted. This also tests if the new workload id was properly stored.
+            RuntimeCall::DeleteWorkload(WORKLOAD_ID.to_string(), Ok(())),
+        ]);
+
+        let mock_remove_dir = mock_filesystem_async::remove_dir_all_context();
+        mock_remove_dir.expect().returning(|_| Ok(()));
+
+        // Send the update command now. It will be buffered until the await receives it.
+        workload_command_sender
+            .update(
+                Some(new_workload_spec.clone()),
+                CONTROL_INTERFACE_PATH.clone(),
+            )
+            .await
+            .unwrap();
+        // Send also a delete command so that we can properly get out of the loop
+        workload_command_sender.delete().await.unwrap();
+
+        let old_instance_name = old_workload_spec.instance_name.clone();
+        let new_instance_name = new_workload_spec.instance_name.clone();
+
+        let mut control_loop_state = ControlLoopState::builder()
+            .workload_spec(old_workload_spec)
+            .workload_state_sender(state_change_tx)
+            .run_folder(RUN_FOLDER.into())
+            .runtime(Box::new(runtime_mock.clone()))
+            .workload_command_receiver(workload_command_receiver)
+            .retry_sender(workload_command_sender2)
+            .build()
+            .unwrap();
+
+        // [utest->swdd~agent-workload-control-loop-prevents-retries-on-other-workload-commands~2]
+        control_loop_state
+            .retry_manager
+            .expect_invalidate()
+            .times(2)
+            .return_const(());
+
+        let mock_retry_token = MockRetryToken {
+            valid: true,
+            has_been_called: false,
+        };
+        control_loop_state
+            .retry_manager
+            .expect_new_token()
+            .return_once(|| mock_retry_token);
+
+        control_loop_state.workload_id = Some(OLD_WORKLOAD_ID.to_string());
+        control_loop_state.state_checker = Some(old_mock_state_checker);
+
+        assert!(
+            timeout(
+                Duration::from_millis(200),
+                WorkloadControlLoop::run(control_loop_state)
+            )
+            .await
+            .is_ok()
+        );
+
+        assert_execution_state_sequence(
+            state_change_rx,
+            vec![
+                (&old_instance_name, ExecutionState::stopping_requested()),
+                (&old_instance_name, ExecutionState::removed()),
+                (&new_instance_name, ExecutionState::starting_triggered()),
+                (&new_instance_name, ExecutionState::stopping_requested()),
+                (&new_instance_name, ExecutionState::removed()),
+            ],
+        )
+        .await;
+
+        assert!(workload_command_receiver2.is_closed());
+        assert!(workload_command_receiver2.is_empty());
+        runtime_mock.assert_all_expectations();
+    }
+
+    // [utest->swdd~agent-workload-control-loop-executes-at-least-once-update~1]
+    // [utest->swdd~agent-workload-control-loop-deletes-replaced-workload-when-running~1]
+    #[tokio::test]
+    async fn utest_workload_obj_run_at_least_once_update_deletes_old_instance_when_new_is_running()
+    {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let (workload_command_sender, workload_command_receiver) = WorkloadCommandSender::new();
+        let (workload_command_sender2, workload_command_receiver2) = WorkloadCommandSender::new();
+        let (state_change_tx, mut state_change_rx) = mpsc::channel(TEST_EXEC_COMMAND_BUFFER_SIZE);
+
+        let mut old_mock_state_checker = StubStateChecker::new();
+        old_mock_state_checker.panic_if_not_stopped();
+
+        let mut new_mock_state_checker = StubStateChecker::new();
+        new_mock_state_checker.panic_if_not_stopped();
+
+        let old_workload_spec = generate_test_workload_spec_with_param(
+            AGENT_NAME.to_string(),
+            WORKLOAD_1_NAME.to_string(),
+            RUNTIME_NAME.to_string(),
+        );
+
+        let mut new_workload_spec = old_workload_spec.clone();
+        new_workload_spec.runtime_config = "changed config".to_string();
+        new_workload_spec.update_ordering = UpdateOrdering::AtLeastOnce;
+        new_workload_spec.instance_name = WorkloadInstanceName::builder()
+            .agent_name(old_workload_spec.instance_name.agent_name())
+            .workload_name(old_workload_spec.instance_name.workload_name())
+            .config(&new_workload_spec.runtime_config)
+            .build();
+
+        // The old instance is deleted only after the new one has been created and is running.
+        let mut runtime_mock = MockRuntimeConnector::new();
+        runtime_mock.expect(vec![
+            RuntimeCall::CreateWorkload(
+                new_workload_spec.clone(),
+                Some(PIPES_LOCATION.into()),
+                HashMap::default(),
+                Ok((WORKLOAD_ID.to_string(), new_mock_state_checker)),
+            ),
+            RuntimeCall::DeleteWorkload(OLD_WORKLOAD_ID.to_string(), Ok(())),
+            RuntimeCall::DeleteWorkload(WORKLOAD_ID.to_string(), Ok(())),
+        ]);
+
+        let mock_remove_dir = mock_filesystem_async::remove_dir_all_context();
+        mock_remove_dir.expect().returning(|_| Ok(()));
+
+        let old_instance_name = old_workload_spec.instance_name.clone();
+        let new_instance_name = new_workload_spec.instance_name.clone();
+
+        let mut control_loop_state = ControlLoopState::builder()
+            .workload_spec(old_workload_spec)
+            .workload_state_sender(state_change_tx)
+            .run_folder(RUN_FOLDER.into())
+            .runtime(Box::new(runtime_mock.clone()))
+            .workload_command_receiver(workload_command_receiver)
+            .retry_sender(workload_command_sender2)
+            .build()
+            .unwrap();
+
+        control_loop_state
+            .retry_manager
+            .expect_invalidate()
+            .times(2)
+            .return_const(());
+
+        let mock_retry_token = MockRetryToken {
+            valid: true,
+            has_been_called: false,
+        };
+        control_loop_state
+            .retry_manager
+            .expect_new_token()
+            .return_once(|| mock_retry_token);
+
+        control_loop_state.workload_id = Some(OLD_WORKLOAD_ID.to_string());
+        control_loop_state.state_checker = Some(old_mock_state_checker);
+        let state_checker_sender = control_loop_state
+            .state_checker_workload_state_sender
+            .clone();
+
+        let control_loop = tokio::spawn(WorkloadControlLoop::run(control_loop_state));
+
+        workload_command_sender
+            .update(
+                Some(new_workload_spec.clone()),
+                CONTROL_INTERFACE_PATH.clone(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            timeout(Duration::from_millis(200), state_change_rx.recv())
+                .await
+                .unwrap(),
+            Some(WorkloadState {
+                instance_name: new_instance_name.clone(),
+                execution_state: ExecutionState::starting_triggered(),
+            })
+        );
+
+        state_checker_sender
+            .report_workload_execution_state(&new_instance_name, ExecutionState::running())
+            .await;
+
+        assert_eq!(
+            timeout(Duration::from_millis(200), state_change_rx.recv())
+                .await
+                .unwrap(),
+            Some(WorkloadState {
+                instance_name: new_instance_name.clone(),
+                execution_state: ExecutionState::running(),
+            })
+        );
+        assert_eq!(
+            timeout(Duration::from_millis(200), state_change_rx.recv())
+                .await
+                .unwrap(),
+            Some(WorkloadState {
+                instance_name: old_instance_name.clone(),
+                execution_state: ExecutionState::removed(),
+            })
+        );
+
+        // Send a delete command so that we can properly get out of the loop
+        workload_command_sender.delete().await.unwrap();
+
+        assert!(
+            timeout(Duration::from_millis(200), control_loop)
+                .await
+                .is_ok()
+        );
+
+        assert_execution_state_sequence(
+            state_change_rx,
+            vec![
+                (&new_instance_name, ExecutionState::stopping_requested()),
+                (&new_instance_name, ExecutionState::removed()),
+            ],
+        )
+        .await;
+
+        assert!(workload_command_receiver2.is_closed());
+        assert!(workload_command_receiver2.is_empty());
+        runtime_mock.assert_all_expectations();
+    }
+
+    // [utest->swdd~agent-workload-control-loop-restores-replaced-workload~1]
+    #[tokio::test]
+    async fn utest_workload_obj_run_at_least_once_update_restores_old_instance_on_rollback() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let (workload_command_sender, workload_command_receiver) = WorkloadCommandSender::new();
+        let (workload_command_sender2, workload_command_receiver2) = WorkloadCommandSender::new();
+        let (state_change_tx, state_change_rx) = mpsc::channel(TEST_EXEC_COMMAND_BUFFER_SIZE);
+
+        // The restored old state checker is stopped by the delete command at the end.
+        let mut old_mock_state_checker = StubStateChecker::new();
+        old_mock_state_checker.panic_if_not_stopped();
+
+        let mut new_mock_state_checker = StubStateChecker::new();
+        new_mock_state_checker.panic_if_not_stopped();
+
+        let mut old_workload_spec = generate_test_workload_spec_with_param(
             AGENT_NAME.to_string(),
             WORKLOAD_1_NAME.to_string(),
             RUNTIME_NAME.to_string(),
         );
+        old_workload_spec.update_ordering = UpdateOrdering::AtLeastOnce;
 
         let mut new_workload_spec = old_workload_spec.clone();
         new_workload_spec.runtime_config = "changed config".to_string();
@@ -773,22 +1429,19 @@ mod tests {
 
         let mut runtime_mock = MockRuntimeConnector::new();
         runtime_mock.expect(vec![
-            RuntimeCall::DeleteWorkload(OLD_WORKLOAD_ID.to_string(), Ok(())),
             RuntimeCall::CreateWorkload(
                 new_workload_spec.clone(),
                 Some(PIPES_LOCATION.into()),
                 HashMap::default(),
                 Ok((WORKLOAD_ID.to_string(), new_mock_state_checker)),
             ),
-            // Since we also send a delete command to exit the control loop properly, the new workload
-            // will also be deleted. This also tests if the new workload id was properly stored.
             RuntimeCall::DeleteWorkload(WORKLOAD_ID.to_string(), Ok(())),
+            RuntimeCall::DeleteWorkload(OLD_WORKLOAD_ID.to_string(), Ok(())),
         ]);
 
         let mock_remove_dir = mock_filesystem_async::remove_dir_all_context();
         mock_remove_dir.expect().returning(|_| Ok(()));
 
-        // Send the update command now. It will be buffered until the await receives it.
         workload_command_sender
             .update(
                 Some(new_workload_spec.clone()),
@@ -796,6 +1449,14 @@ mod tests {
             )
             .await
             .unwrap();
+        // The rollback sends the old workload again before the new one is running
+        workload_command_sender
+            .update(
+                Some(old_workload_spec.clone()),
+                CONTROL_INTERFACE_PATH.clone(),
+            )
+            .await
+            .unwrap();
         // Send also a delete command so that we can properly get out of the loop
         workload_command_sender.delete().await.unwrap();
 
@@ -812,11 +1473,10 @@ mod tests {
             .build()
             .unwrap();
 
-        // [utest->swdd~agent-workload-control-loop-prevents-retries-on-other-workload-commands~2]
         control_loop_state
             .retry_manager
             .expect_invalidate()
-            .times(2)
+            .times(3)
             .return_const(());
 
         let mock_retry_token = MockRetryToken {
@@ -843,11 +1503,11 @@ mod tests {
         assert_execution_state_sequence(
             state_change_rx,
             vec![
-                (&old_instance_name, ExecutionState::stopping_requested()),
-                (&old_instance_name, ExecutionState::removed()),
                 (&new_instance_name, ExecutionState::starting_triggered()),
                 (&new_instance_name, ExecutionState::stopping_requested()),
                 (&new_instance_name, ExecutionState::removed()),
+                (&old_instance_name, ExecutionState::stopping_requested()),
+                (&old_instance_name, ExecutionState::removed()),
             ],
         )
         .await;
@@ -1768,7 +2428,256 @@ mod tests {
         );
 
         let mut runtime_mock = MockRuntimeConnector::new();
-        runtime_mock.expect(vec![]);
+        runtime_mock.expect(vec![]);
+        let mock_remove_dir = mock_filesystem_async::remove_dir_all_context();
+        mock_remove_dir.expect().returning(|_| Ok(()));
+
+        tokio::spawn(async move {
+            tokio::time::sleep(tokio::time::Duration::from_millis(70)).await;
+            workload_command_sender.delete().await.unwrap();
+        });
+
+        let mut control_loop_state = ControlLoopState::builder()
+            .workload_spec(workload_spec.clone())
+            .workload_state_sender(workload_state_forward_tx.clone())
+            .run_folder(RUN_FOLDER.into())
+            .control_interface_path(CONTROL_INTERFACE_PATH.clone())
+            .runtime(Box::new(runtime_mock.clone()))
+            .workload_command_receiver(workload_command_receiver)
+            .retry_sender(workload_command_sender2)
+            .build()
+            .unwrap();
+
+        control_loop_state
+            .retry_manager
+            .expect_invalidate()
+            .once()
+            .return_const(());
+
+        // clone the control loop state's internally created workload state sender used by the state checker
+        let state_checker_wl_state_sender = control_loop_state
+            .state_checker_workload_state_sender
+            .clone();
+
+        let workload_state = generate_test_workload_state_with_workload_spec(
+            &workload_spec,
+            ExecutionState::running(),
+        );
+
+        state_checker_wl_state_sender
+            .report_workload_execution_state(
+                &workload_state.instance_name,
+                workload_state.execution_state.clone(),
+            )
+            .await;
+
+        assert!(
+            timeout(
+                Duration::from_millis(100),
+                WorkloadControlLoop::run(control_loop_state)
+            )
+            .await
+            .is_ok()
+        );
+
+        assert_eq!(
+            Ok(Some(workload_state)),
+            timeout(Duration::from_millis(100), workload_state_forward_rx.recv()).await
+        );
+
+        assert!(workload_command_receiver2.is_closed());
+        assert!(workload_command_receiver2.is_empty());
+        runtime_mock.assert_all_expectations();
+    }
+
+    // [utest->swdd~workload-control-loop-receives-workload-states~1]
+    #[tokio::test]
+    #[should_panic]
+    async fn utest_panic_on_closed_workload_state_channel() {
+        let (workload_command_sender, workload_command_receiver) = WorkloadCommandSender::new();
+        let (workload_command_sender2, workload_command_receiver2) = WorkloadCommandSender::new();
+        let (workload_state_forward_tx, mut workload_state_forward_rx) =
+            mpsc::channel(TEST_EXEC_COMMAND_BUFFER_SIZE);
+
+        let workload_spec = generate_test_workload_spec_with_param(
+            AGENT_NAME.to_string(),
+            WORKLOAD_1_NAME.to_string(),
+            RUNTIME_NAME.to_string(),
+        );
+
+        let mut runtime_mock = MockRuntimeConnector::new();
+        runtime_mock.expect(vec![]);
+
+        tokio::spawn(async move {
+            tokio::time::sleep(tokio::time::Duration::from_millis(70)).await;
+            workload_command_sender.delete().await.unwrap();
+        });
+
+        let control_loop_state = ControlLoopState::builder()
+            .workload_spec(workload_spec.clone())
+            .workload_state_sender(workload_state_forward_tx.clone())
+            .run_folder(RUN_FOLDER.into())
+            .control_interface_path(CONTROL_INTERFACE_PATH.clone())
+            .runtime(Box::new(runtime_mock.clone()))
+            .workload_command_receiver(workload_command_receiver)
+            .retry_sender(workload_command_sender2)
+            .build()
+            .unwrap();
+
+        // close the channel to panic within the workload control loop
+        workload_state_forward_rx.close();
+
+        assert!(
+            timeout(
+                Duration::from_millis(100),
+                WorkloadControlLoop::run(control_loop_state)
+            )
+            .await
+            .is_ok()
+        );
+
+        assert!(workload_command_receiver2.is_closed());
+        assert!(workload_command_receiver2.is_empty());
+        runtime_mock.assert_all_expectations();
+    }
+
+    // [utest->swdd~workload-control-loop-restarts-workload-with-enabled-restart-policy~3]
+    // [utest->swdd~workload-control-loop-handles-workload-restarts~2]
+    // [utest->swdd~workload-control-loop-restarts-workloads-using-update~1]
+    #[tokio::test]
+    async fn utest_restart_workload() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let (workload_command_sender, workload_command_receiver) = WorkloadCommandSender::new();
+        let (workload_command_sender2, workload_command_receiver2) = WorkloadCommandSender::new();
+        let (workload_state_forward_tx, _workload_state_forward_rx) =
+            mpsc::channel(TEST_EXEC_COMMAND_BUFFER_SIZE);
+
+        let workload_spec = generate_test_workload_spec_with_param(
+            AGENT_NAME.to_string(),
+            WORKLOAD_1_NAME.to_string(),
+            RUNTIME_NAME.to_string(),
+        );
+
+        let mut old_mock_state_checker = StubStateChecker::new();
+        old_mock_state_checker.panic_if_not_stopped();
+
+        let mut new_mock_state_checker = StubStateChecker::new();
+        new_mock_state_checker.panic_if_not_stopped();
+
+        let mut runtime_mock = MockRuntimeConnector::new();
+        runtime_mock.expect(vec![
+            RuntimeCall::DeleteWorkload(WORKLOAD_ID.to_string(), Ok(())), // delete operation of the restarted workload
+            RuntimeCall::CreateWorkload(
+                workload_spec.clone(),
+                Some(PIPES_LOCATION.into()),
+                HashMap::default(),
+                Ok((WORKLOAD_ID_2.to_string(), new_mock_state_checker)),
+            ),
+            RuntimeCall::DeleteWorkload(WORKLOAD_ID_2.to_string(), Ok(())),
+        ]);
+
+        let mock_remove_dir = mock_filesystem_async::remove_dir_all_context();
+        mock_remove_dir.expect().returning(|_| Ok(()));
+
+        tokio::spawn(async move {
+            tokio::time::sleep(tokio::time::Duration::from_millis(70)).await;
+            workload_command_sender.delete().await.unwrap();
+        });
+
+        let mut control_loop_state = ControlLoopState::builder()
+            .workload_spec(workload_spec.clone())
+            .workload_state_sender(workload_state_forward_tx.clone())
+            .run_folder(RUN_FOLDER.into())
+            .control_interface_path(CONTROL_INTERFACE_PATH.clone())
+            .runtime(Box::new(runtime_mock.clone()))
+            .workload_command_receiver(workload_command_receiver)
+            .retry_sender(workload_command_sender2)
+            .build()
+            .unwrap();
+
+        control_loop_state.workload_id = Some(WORKLOAD_ID.into());
+        control_loop_state.state_checker = Some(old_mock_state_checker);
+
+        control_loop_state
+            .retry_manager
+            .expect_invalidate()
+            .once()
+            .return_const(());
+        let mock_retry_token = MockRetryToken {
+            valid: true,
+            has_been_called: false,
+        };
+        control_loop_state
+            .retry_manager
+            .expect_new_token()
+            .once()
+            .return_once(|| mock_retry_token);
+
+        // clone the control loop state's internally created workload state sender used by the state checker
+        let state_checker_wl_state_sender = control_loop_state
+            .state_checker_workload_state_sender
+            .clone();
+
+        let workload_state = generate_test_workload_state_with_workload_spec(
+            &workload_spec,
+            ExecutionState::succeeded(),
+        );
+
+        state_checker_wl_state_sender
+            .report_workload_execution_state(
+                &workload_state.instance_name,
+                workload_state.execution_state.clone(),
+            )
+            .await;
+
+        assert!(
+            timeout(
+                Duration::from_millis(100),
+                WorkloadControlLoop::run(control_loop_state)
+            )
+            .await
+            .is_ok()
+        );
+
+        assert!(workload_command_receiver2.is_closed());
+        assert!(workload_command_receiver2.is_empty());
+        runtime_mock.assert_all_expectations();
+    }
+
+    // [utest->swdd~agent-workload-control-loop-restarts-with-backoff~1]
+    #[tokio::test]
+    async fn utest_restart_workload_with_restart_backoff_sends_delayed_restart() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let (workload_command_sender, workload_command_receiver) = WorkloadCommandSender::new();
+        let (workload_command_sender2, mut workload_command_receiver2) =
+            WorkloadCommandSender::new();
+        let (workload_state_forward_tx, workload_state_forward_rx) =
+            mpsc::channel(TEST_EXEC_COMMAND_BUFFER_SIZE);
+
+        let mut workload_spec = generate_test_workload_spec_with_param(
+            AGENT_NAME.to_string(),
+            WORKLOAD_1_NAME.to_string(),
+            RUNTIME_NAME.to_string(),
+        );
+        workload_spec.restart_backoff = Some(RestartBackoff::default());
+
+        let mut old_mock_state_checker = StubStateChecker::new();
+        old_mock_state_checker.panic_if_not_stopped();
+
+        let mut runtime_mock = MockRuntimeConnector::new();
+        runtime_mock.expect(vec![RuntimeCall::DeleteWorkload(
+            WORKLOAD_ID.to_string(),
+            Ok(()),
+        )]);
+
         let mock_remove_dir = mock_filesystem_async::remove_dir_all_context();
         mock_remove_dir.expect().returning(|_| Ok(()));
 
@@ -1788,26 +2697,32 @@ mod tests {
             .build()
             .unwrap();
 
+        control_loop_state.workload_id = Some(WORKLOAD_ID.into());
+        control_loop_state.state_checker = Some(old_mock_state_checker);
+
         control_loop_state
             .retry_manager
             .expect_invalidate()
             .once()
             .return_const(());
+        control_loop_state
+            .retry_manager
+            .expect_new_restart_token()
+            .with(predicate::eq(0), predicate::eq(RestartBackoff::default()))
+            .once()
+            .return_once(|_, _| MockRetryToken {
+                valid: true,
+                has_been_called: false,
+            });
 
-        // clone the control loop state's internally created workload state sender used by the state checker
         let state_checker_wl_state_sender = control_loop_state
             .state_checker_workload_state_sender
             .clone();
 
-        let workload_state = generate_test_workload_state_with_workload_spec(
-            &workload_spec,
-            ExecutionState::running(),
-        );
-
         state_checker_wl_state_sender
             .report_workload_execution_state(
-                &workload_state.instance_name,
-                workload_state.execution_state.clone(),
+                &workload_spec.instance_name,
+                ExecutionState::failed("exit code 1"),
             )
             .await;
 
@@ -1820,40 +2735,77 @@ mod tests {
             .is_ok()
         );
 
-        assert_eq!(
-            Ok(Some(workload_state)),
-            timeout(Duration::from_millis(100), workload_state_forward_rx.recv()).await
-        );
+        assert_execution_state_sequence(
+            workload_state_forward_rx,
+            vec![
+                (
+                    &workload_spec.instance_name,
+                    ExecutionState::failed("exit code 1"),
+                ),
+                (
+                    &workload_spec.instance_name,
+                    ExecutionState::crash_loop_backoff(1, "exit code 1"),
+                ),
+                (
+                    &workload_spec.instance_name,
+                    ExecutionState::stopping_requested(),
+                ),
+                (&workload_spec.instance_name, ExecutionState::removed()),
+            ],
+        )
+        .await;
 
-        assert!(workload_command_receiver2.is_closed());
-        assert!(workload_command_receiver2.is_empty());
+        let Some(WorkloadCommand::Restart(received_instance_name, received_retry_token)) =
+            workload_command_receiver2.recv().await
+        else {
+            panic!("Expected WorkloadCommand::Restart")
+        };
+        assert_eq!(*received_instance_name, workload_spec.instance_name);
+        assert!(received_retry_token.has_been_called);
         runtime_mock.assert_all_expectations();
     }
 
-    // [utest->swdd~workload-control-loop-receives-workload-states~1]
+    // [utest->swdd~agent-workload-control-loop-stops-restarts-at-limit~1]
     #[tokio::test]
-    #[should_panic]
-    async fn utest_panic_on_closed_workload_state_channel() {
+    async fn utest_restart_workload_stops_at_restart_limit() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
         let (workload_command_sender, workload_command_receiver) = WorkloadCommandSender::new();
         let (workload_command_sender2, workload_command_receiver2) = WorkloadCommandSender::new();
-        let (workload_state_forward_tx, mut workload_state_forward_rx) =
+        let (workload_state_forward_tx, workload_state_forward_rx) =
             mpsc::channel(TEST_EXEC_COMMAND_BUFFER_SIZE);
 
-        let workload_spec = generate_test_workload_spec_with_param(
+        let mut workload_spec = generate_test_workload_spec_with_param(
             AGENT_NAME.to_string(),
             WORKLOAD_1_NAME.to_string(),
             RUNTIME_NAME.to_string(),
         );
+        workload_spec.restart_backoff = Some(RestartBackoff {
+            max_restarts: Some(2),
+            ..Default::default()
+        });
+
+        let mut old_mock_state_checker = StubStateChecker::new();
+        old_mock_state_checker.panic_if_not_stopped();
 
         let mut runtime_mock = MockRuntimeConnector::new();
-        runtime_mock.expect(vec![]);
+        runtime_mock.expect(vec![RuntimeCall::DeleteWorkload(
+            WORKLOAD_ID.to_string(),
+            Ok(()),
+        )]);
+
+        let mock_remove_dir = mock_filesystem_async::remove_dir_all_context();
+        mock_remove_dir.expect().returning(|_| Ok(()));
 
         tokio::spawn(async move {
             tokio::time::sleep(tokio::time::Duration::from_millis(70)).await;
             workload_command_sender.delete().await.unwrap();
         });
 
-        let control_loop_state = ControlLoopState::builder()
+        let mut control_loop_state = ControlLoopState::builder()
             .workload_spec(workload_spec.clone())
             .workload_state_sender(workload_state_forward_tx.clone())
             .run_folder(RUN_FOLDER.into())
@@ -1864,8 +2816,30 @@ mod tests {
             .build()
             .unwrap();
 
-        // close the channel to panic within the workload control loop
-        workload_state_forward_rx.close();
+        control_loop_state.workload_id = Some(WORKLOAD_ID.into());
+        control_loop_state.state_checker = Some(old_mock_state_checker);
+        control_loop_state.restart_count = 2;
+
+        control_loop_state
+            .retry_manager
+            .expect_invalidate()
+            .once()
+            .return_const(());
+        control_loop_state
+            .retry_manager
+            .expect_new_restart_token()
+            .never();
+
+        let state_checker_wl_state_sender = control_loop_state
+            .state_checker_workload_state_sender
+            .clone();
+
+        state_checker_wl_state_sender
+            .report_workload_execution_state(
+                &workload_spec.instance_name,
+                ExecutionState::failed("exit code 1"),
+            )
+            .await;
 
         assert!(
             timeout(
@@ -1876,58 +2850,68 @@ mod tests {
             .is_ok()
         );
 
-        assert!(workload_command_receiver2.is_closed());
+        assert_execution_state_sequence(
+            workload_state_forward_rx,
+            vec![
+                (
+                    &workload_spec.instance_name,
+                    ExecutionState::failed("exit code 1 (restarts: 2)"),
+                ),
+                (
+                    &workload_spec.instance_name,
+                    ExecutionState::crash_loop_no_restart(2, "exit code 1"),
+                ),
+                (
+                    &workload_spec.instance_name,
+                    ExecutionState::stopping_requested(),
+                ),
+                (&workload_spec.instance_name, ExecutionState::removed()),
+            ],
+        )
+        .await;
+
         assert!(workload_command_receiver2.is_empty());
         runtime_mock.assert_all_expectations();
     }
 
-    // [utest->swdd~workload-control-loop-restarts-workload-with-enabled-restart-policy~2]
-    // [utest->swdd~workload-control-loop-handles-workload-restarts~2]
-    // [utest->swdd~workload-control-loop-restarts-workloads-using-update~1]
+    // [utest->swdd~agent-workload-control-loop-resets-restart-count-after-stable-run~1]
     #[tokio::test]
-    async fn utest_restart_workload() {
+    async fn utest_restart_count_reset_after_stable_run() {
         let _ = env_logger::builder().is_test(true).try_init();
         let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
             .get_lock_async()
             .await;
 
         let (workload_command_sender, workload_command_receiver) = WorkloadCommandSender::new();
-        let (workload_command_sender2, workload_command_receiver2) = WorkloadCommandSender::new();
-        let (workload_state_forward_tx, _workload_state_forward_rx) =
+        let (workload_command_sender2, mut workload_command_receiver2) =
+            WorkloadCommandSender::new();
+        let (workload_state_forward_tx, workload_state_forward_rx) =
             mpsc::channel(TEST_EXEC_COMMAND_BUFFER_SIZE);
 
-        let workload_spec = generate_test_workload_spec_with_param(
+        let mut workload_spec = generate_test_workload_spec_with_param(
             AGENT_NAME.to_string(),
             WORKLOAD_1_NAME.to_string(),
             RUNTIME_NAME.to_string(),
         );
+        let restart_backoff = RestartBackoff {
+            max_restarts: Some(2),
+            reset_after_millis: 20,
+            ..Default::default()
+        };
+        workload_spec.restart_backoff = Some(restart_backoff.clone());
 
         let mut old_mock_state_checker = StubStateChecker::new();
         old_mock_state_checker.panic_if_not_stopped();
 
-        let mut new_mock_state_checker = StubStateChecker::new();
-        new_mock_state_checker.panic_if_not_stopped();
-
         let mut runtime_mock = MockRuntimeConnector::new();
-        runtime_mock.expect(vec![
-            RuntimeCall::DeleteWorkload(WORKLOAD_ID.to_string(), Ok(())), // delete operation of the restarted workload
-            RuntimeCall::CreateWorkload(
-                workload_spec.clone(),
-                Some(PIPES_LOCATION.into()),
-                HashMap::default(),
-                Ok((WORKLOAD_ID_2.to_string(), new_mock_state_checker)),
-            ),
-            RuntimeCall::DeleteWorkload(WORKLOAD_ID_2.to_string(), Ok(())),
-        ]);
+        runtime_mock.expect(vec![RuntimeCall::DeleteWorkload(
+            WORKLOAD_ID.to_string(),
+            Ok(()),
+        )]);
 
         let mock_remove_dir = mock_filesystem_async::remove_dir_all_context();
         mock_remove_dir.expect().returning(|_| Ok(()));
 
-        tokio::spawn(async move {
-            tokio::time::sleep(tokio::time::Duration::from_millis(70)).await;
-            workload_command_sender.delete().await.unwrap();
-        });
-
         let mut control_loop_state = ControlLoopState::builder()
             .workload_spec(workload_spec.clone())
             .workload_state_sender(workload_state_forward_tx.clone())
@@ -1941,54 +2925,105 @@ mod tests {
 
         control_loop_state.workload_id = Some(WORKLOAD_ID.into());
         control_loop_state.state_checker = Some(old_mock_state_checker);
+        control_loop_state.restart_count = 2;
 
         control_loop_state
             .retry_manager
             .expect_invalidate()
             .once()
             .return_const(());
-        let mock_retry_token = MockRetryToken {
-            valid: true,
-            has_been_called: false,
-        };
         control_loop_state
             .retry_manager
-            .expect_new_token()
+            .expect_new_restart_token()
+            .with(predicate::eq(0), predicate::eq(restart_backoff))
             .once()
-            .return_once(|| mock_retry_token);
+            .return_once(|_, _| MockRetryToken {
+                valid: true,
+                has_been_called: false,
+            });
 
-        // clone the control loop state's internally created workload state sender used by the state checker
         let state_checker_wl_state_sender = control_loop_state
             .state_checker_workload_state_sender
             .clone();
+        let instance_name = workload_spec.instance_name.clone();
 
-        let workload_state = generate_test_workload_state_with_workload_spec(
-            &workload_spec,
-            ExecutionState::succeeded(),
-        );
-
-        state_checker_wl_state_sender
-            .report_workload_execution_state(
-                &workload_state.instance_name,
-                workload_state.execution_state.clone(),
-            )
-            .await;
+        tokio::spawn(async move {
+            state_checker_wl_state_sender
+                .report_workload_execution_state(&instance_name, ExecutionState::running())
+                .await;
+            tokio::time::sleep(tokio::time::Duration::from_millis(40)).await;
+            state_checker_wl_state_sender
+                .report_workload_execution_state(
+                    &instance_name,
+                    ExecutionState::failed("exit code 1"),
+                )
+                .await;
+            tokio::time::sleep(tokio::time::Duration::from_millis(30)).await;
+            workload_command_sender.delete().await.unwrap();
+        });
 
         assert!(
             timeout(
-                Duration::from_millis(100),
+                Duration::from_millis(200),
                 WorkloadControlLoop::run(control_loop_state)
             )
             .await
             .is_ok()
         );
 
-        assert!(workload_command_receiver2.is_closed());
-        assert!(workload_command_receiver2.is_empty());
+        assert_execution_state_sequence(
+            workload_state_forward_rx,
+            vec![
+                (
+                    &workload_spec.instance_name,
+                    ExecutionState {
+                        additional_info: "Restarts: 2".to_string(),
+                        ..ExecutionState::running()
+                    },
+                ),
+                (
+                    &workload_spec.instance_name,
+                    ExecutionState::failed("exit code 1"),
+                ),
+                (
+                    &workload_spec.instance_name,
+                    ExecutionState::crash_loop_backoff(1, "exit code 1"),
+                ),
+                (
+                    &workload_spec.instance_name,
+                    ExecutionState::stopping_requested(),
+                ),
+                (&workload_spec.instance_name, ExecutionState::removed()),
+            ],
+        )
+        .await;
+
+        assert!(matches!(
+            workload_command_receiver2.recv().await,
+            Some(WorkloadCommand::Restart(_, _))
+        ));
         runtime_mock.assert_all_expectations();
     }
 
-    // [utest->swdd~workload-control-loop-restarts-workload-with-enabled-restart-policy~2]
+    // [utest->swdd~agent-workload-control-loop-shows-restart-count~1]
+    #[test]
+    fn utest_with_restart_count_extends_additional_info() {
+        assert_eq!(
+            WorkloadControlLoop::with_restart_count(ExecutionState::running(), 0),
+            ExecutionState::running()
+        );
+        assert_eq!(
+            WorkloadControlLoop::with_restart_count(ExecutionState::running(), 3).additional_info,
+            "Restarts: 3"
+        );
+        assert_eq!(
+            WorkloadControlLoop::with_restart_count(ExecutionState::failed("exit code 1"), 1)
+                .additional_info,
+            "exit code 1 (restarts: 1)"
+        );
+    }
+
+    // [utest->swdd~workload-control-loop-restarts-workload-with-enabled-restart-policy~3]
     #[test]
     fn utest_is_restart_allowed_never() {
         let restart_policy = RestartPolicy::Never;
@@ -2011,9 +3046,15 @@ mod tests {
                 &ExecutionState::failed("some error".to_owned())
             )
         );
+        assert!(
+            !WorkloadControlLoop::restart_policy_matches_execution_state(
+                &restart_policy,
+                &ExecutionState::running_unhealthy("probe failed")
+            )
+        );
     }
 
-    // [utest->swdd~workload-control-loop-restarts-workload-with-enabled-restart-policy~2]
+    // [utest->swdd~workload-control-loop-restarts-workload-with-enabled-restart-policy~3]
     #[test]
     fn utest_is_restart_allowed_on_failure() {
         let restart_policy = RestartPolicy::OnFailure;
@@ -2034,9 +3075,19 @@ mod tests {
                 &ExecutionState::succeeded()
             )
         );
+        assert!(WorkloadControlLoop::restart_policy_matches_execution_state(
+            &restart_policy,
+            &ExecutionState::running_unhealthy("probe failed")
+        ));
+        assert!(
+            !WorkloadControlLoop::restart_policy_matches_execution_state(
+                &restart_policy,
+                &ExecutionState::running_not_ready("probe failed")
+            )
+        );
     }
 
-    // [utest->swdd~workload-control-loop-restarts-workload-with-enabled-restart-policy~2]
+    // [utest->swdd~workload-control-loop-restarts-workload-with-enabled-restart-policy~3]
     #[test]
     fn utest_restart_policy_matches_execution_state_always() {
         let restart_policy = RestartPolicy::Always;
@@ -2055,10 +3106,14 @@ mod tests {
             &restart_policy,
             &ExecutionState::succeeded()
         ));
+        assert!(WorkloadControlLoop::restart_policy_matches_execution_state(
+            &restart_policy,
+            &ExecutionState::running_unhealthy("probe failed")
+        ));
     }
 
     // [utest->swdd~agent-sends-workload-states-of-its-workloads-to-server~2]
-    // [utest->swdd~workload-control-loop-restarts-workload-with-enabled-restart-policy~2]
+    // [utest->swdd~workload-control-loop-restarts-workload-with-enabled-restart-policy~3]
     // [utest->swdd~workload-control-loop-checks-workload-state-validity~1]
     #[test]
     fn utest_is_same_workload() {
@@ -3030,4 +4085,86 @@ mod tests {
             .unwrap();
         runtime_mock.assert_all_expectations();
     }
+
+    // [utest->swdd~agent-workload-control-loop-collects-metrics~1]
+    #[tokio::test]
+    async fn utest_collect_metrics_adds_restart_count_to_runtime_metrics() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let (workload_command_sender, workload_command_receiver) = WorkloadCommandSender::new();
+        let (workload_command_sender2, _) = WorkloadCommandSender::new();
+        let (state_change_tx, _state_change_rx) = mpsc::channel(TEST_EXEC_COMMAND_BUFFER_SIZE);
+
+        let workload_spec = generate_test_workload_spec_with_param(
+            AGENT_NAME.to_string(),
+            WORKLOAD_1_NAME.to_string(),
+            RUNTIME_NAME.to_string(),
+        );
+
+        let mut runtime_mock = MockRuntimeConnector::new();
+        runtime_mock.expect(vec![
+            RuntimeCall::GetWorkloadMetrics(
+                WORKLOAD_ID.into(),
+                Ok(WorkloadMetrics {
+                    cpu_usage: Some(5),
+                    memory_usage: Some(1024),
+                    restart_count: 0,
+                }),
+            ),
+            RuntimeCall::GetWorkloadMetrics(
+                WORKLOAD_ID.into(),
+                Err(RuntimeError::CollectMetrics("mock".into())),
+            ),
+        ]);
+
+        let mut control_loop_state = ControlLoopState::builder()
+            .workload_spec(workload_spec)
+            .workload_id(Some(WORKLOAD_ID.into()))
+            .run_folder(RUN_FOLDER.into())
+            .control_interface_path(CONTROL_INTERFACE_PATH.clone())
+            .workload_state_sender(state_change_tx)
+            .runtime(Box::new(runtime_mock.clone()))
+            .workload_command_receiver(workload_command_receiver)
+            .retry_sender(workload_command_sender2)
+            .build()
+            .unwrap();
+        control_loop_state.restart_count = 2;
+
+        let jh = tokio::spawn(async move {
+            let first_metrics = workload_command_sender.collect_metrics().await.unwrap();
+            let second_metrics = workload_command_sender.collect_metrics().await.unwrap();
+            (first_metrics, second_metrics)
+        });
+
+        timeout(
+            Duration::from_millis(150),
+            WorkloadControlLoop::run(control_loop_state),
+        )
+        .await
+        .unwrap();
+
+        let (first_metrics, second_metrics) = timeout(Duration::from_millis(100), jh)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            first_metrics,
+            WorkloadMetrics {
+                cpu_usage: Some(5),
+                memory_usage: Some(1024),
+                restart_count: 2,
+            }
+        );
+        assert_eq!(
+            second_metrics,
+            WorkloadMetrics {
+                restart_count: 2,
+                ..Default::default()
+            }
+        );
+        runtime_mock.assert_all_expectations();
+    }
 }
@@ -21,6 +21,7 @@ use futures_util::{StreamExt, stream::FuturesUnordered};
 use std::{future::Future, pin::Pin};
 
 use crate::agent_manager::SynchronizedSubscriptionStore;
+use crate::runtime_connectors::log_fetcher::LogLine;
 
 #[cfg(not(test))]
 use crate::runtime_connectors::log_channel::Receiver;
@@ -46,7 +47,7 @@ use crate::subscription_store::MockSubscriptionEntry as SubscriptionEntry;
 
 pub struct WorkloadLogFacade;
 
-type ContinuableResult = (WorkloadInstanceName, Receiver, Option<Vec<String>>);
+type ContinuableResult = (WorkloadInstanceName, Receiver, Option<Vec<LogLine>>);
 type UnorderedLogReceiverFutures =
     FuturesUnordered<Pin<Box<dyn Future<Output = ContinuableResult> + Send>>>;
 
@@ -135,9 +136,11 @@ impl WorkloadLogFacade {
                         ank_base::LogEntriesResponse {
                             log_entries: log_lines
                                 .into_iter()
-                                .map(|log_message| ank_base::LogEntry {
+                                .map(|log_line| ank_base::LogEntry {
                                     workload_name: Some(workload_instance_name.clone().into()),
-                                    message: log_message,
+                                    message: log_line.message,
+                                    timestamp: log_line.timestamp,
+                                    stream: log_line.stream.into(),
                                 })
                                 .collect(),
                         },
@@ -180,7 +183,7 @@ impl WorkloadLogFacade {
 #[cfg(test)]
 mod tests {
     use super::SynchronizedSubscriptionStore;
-    use crate::runtime_connectors::log_fetcher::MockLogFetcher;
+    use crate::runtime_connectors::log_fetcher::{LogLine, MockLogFetcher};
     use crate::runtime_manager::MockRuntimeManager;
     use crate::subscription_store::{MockJoinHandle, MockSubscriptionEntry, SubscriptionEntry};
     use crate::workload_log_facade::WorkloadLogFacade;
@@ -197,25 +200,39 @@ mod tests {
     const WORKLOAD_1_NAME: &str = "workload1";
     const WORKLOAD_2_NAME: &str = "workload2";
     const REQUEST_ID: &str = "request_id";
+    const TIMESTAMP: &str = "2024-03-01T12:30:00Z";
+
+    fn log_line(stream: ank_base::LogStream, message: &str) -> LogLine {
+        LogLine {
+            stream,
+            timestamp: TIMESTAMP.into(),
+            message: message.into(),
+        }
+    }
 
     async fn get_log_responses(
         num: usize,
         to_server: &mut mpsc::Receiver<ToServer>,
-    ) -> Option<HashMap<(String, String), Vec<String>>> {
-        let mut result: HashMap<(String, String), Vec<String>> = HashMap::new();
+    ) -> Option<HashMap<(String, String), Vec<LogLine>>> {
+        let mut result: HashMap<(String, String), Vec<LogLine>> = HashMap::new();
         let mut responses = 0;
         while responses != num {
             let candidate = to_server.recv().await?;
             if let ToServer::LogEntriesResponse(request_id, logs_response) = candidate {
                 responses += 1;
                 for entry in logs_response.log_entries {
+                    let stream = entry.stream();
                     result
                         .entry((
                             request_id.clone(),
                             entry.workload_name.unwrap().workload_name,
                         ))
                         .or_default()
-                        .push(entry.message);
+                        .push(LogLine {
+                            stream,
+                            timestamp: entry.timestamp,
+                            message: entry.message,
+                        });
                 }
             };
         }
@@ -234,7 +251,7 @@ mod tests {
 
     mock! {
         pub RuntimeConnectorReceiver {
-            pub async fn read_log_lines(&mut self) -> Option<Vec<String>>;
+            pub async fn read_log_lines(&mut self) -> Option<Vec<LogLine>>;
         }
     }
 
@@ -258,11 +275,16 @@ mod tests {
         mock_runtime_connector_receiver_1
             .expect_read_log_lines()
             .once()
-            .return_once(|| Some(vec!["rec1: line1".into(), "rec1: line2".into()]));
+            .return_once(|| {
+                Some(vec![
+                    log_line(ank_base::LogStream::Stdout, "rec1: line1"),
+                    log_line(ank_base::LogStream::Stdout, "rec1: line2"),
+                ])
+            });
         mock_runtime_connector_receiver_2
             .expect_read_log_lines()
             .once()
-            .return_once(|| Some(vec!["rec2: line1".into()]));
+            .return_once(|| Some(vec![log_line(ank_base::LogStream::Stderr, "rec2: line1")]));
         mock_runtime_connector_receiver_2
             .expect_read_log_lines()
             .once()
@@ -270,7 +292,7 @@ mod tests {
         mock_runtime_connector_receiver_1
             .expect_read_log_lines()
             .once()
-            .return_once(|| Some(vec!["rec1: line3".into()]));
+            .return_once(|| Some(vec![log_line(ank_base::LogStream::Stdout, "rec1: line3")]));
         mock_runtime_connector_receiver_1
             .expect_read_log_lines()
             .once()
@@ -361,9 +383,9 @@ mod tests {
                 .get(&(REQUEST_ID.into(), WORKLOAD_1_NAME.into()))
                 .unwrap(),
             &vec![
-                "rec1: line1".to_string(),
-                "rec1: line2".to_string(),
-                "rec1: line3".to_string(),
+                log_line(ank_base::LogStream::Stdout, "rec1: line1"),
+                log_line(ank_base::LogStream::Stdout, "rec1: line2"),
+                log_line(ank_base::LogStream::Stdout, "rec1: line3"),
             ]
         );
         assert!(log_responses.contains_key(&(REQUEST_ID.into(), WORKLOAD_2_NAME.into())));
@@ -371,7 +393,7 @@ mod tests {
             log_responses
                 .get(&(REQUEST_ID.into(), WORKLOAD_2_NAME.into()))
                 .unwrap(),
-            &vec!["rec2: line1".to_string(),]
+            &vec![log_line(ank_base::LogStream::Stderr, "rec2: line1")]
         );
 
         let log_responses = timeout(
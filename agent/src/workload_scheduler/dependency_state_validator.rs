@@ -36,7 +36,7 @@ impl DependencyStateValidator {
                 workload_state_db
                     .get_state_of_workload(dependency_name)
                     .is_some_and(|wl_state| {
-                        // [impl->swdd~execution-states-of-workload-dependencies-fulfill-add-conditions~1]
+                        // [impl->swdd~execution-states-of-workload-dependencies-fulfill-add-conditions~2]
                         add_condition.fulfilled_by(wl_state)
                     })
             })
@@ -91,7 +91,7 @@ mod tests {
     const RUNTIME: &str = "runtime";
 
     // [utest->swdd~workload-ready-to-create-on-fulfilled-dependencies~1]
-    // [utest->swdd~execution-states-of-workload-dependencies-fulfill-add-conditions~1]
+    // [utest->swdd~execution-states-of-workload-dependencies-fulfill-add-conditions~2]
     #[test]
     fn utest_create_fulfilled() {
         let workload_with_dependencies = generate_test_workload_spec_with_dependencies(
@@ -132,7 +132,7 @@ mod tests {
         ));
     }
 
-    // [utest->swdd~execution-states-of-workload-dependencies-fulfill-add-conditions~1]
+    // [utest->swdd~execution-states-of-workload-dependencies-fulfill-add-conditions~2]
     #[test]
     fn utest_create_fulfilled_no_workload_state_known() {
         let workload_with_dependencies = generate_test_workload_spec_with_dependencies(
@@ -151,7 +151,7 @@ mod tests {
     }
 
     // [utest->swdd~workload-ready-to-create-on-fulfilled-dependencies~1]
-    // [utest->swdd~execution-states-of-workload-dependencies-fulfill-add-conditions~1]
+    // [utest->swdd~execution-states-of-workload-dependencies-fulfill-add-conditions~2]
     #[test]
     fn utest_create_fulfilled_unfulfilled_execution_state() {
         let workload_with_dependencies = generate_test_workload_spec_with_dependencies(
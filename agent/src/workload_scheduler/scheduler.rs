@@ -18,8 +18,12 @@ use crate::{
     workload_operation::ReusableWorkloadSpec,
     workload_state::{WorkloadStateSender, WorkloadStateSenderInterface},
 };
-use common::objects::{DeletedWorkload, ExecutionState, WorkloadInstanceName, WorkloadSpec};
-use std::{collections::HashMap, fmt::Display};
+use common::objects::{
+    DeletedWorkload, ExecutionState, FulfilledBy, UpdateOrdering, WorkloadInstanceName,
+    WorkloadSpec,
+};
+use std::{collections::HashMap, fmt::Display, time::Duration};
+use tokio::time::Instant;
 
 use crate::workload_operation::WorkloadOperation;
 #[cfg_attr(test, mockall_double::double)]
@@ -41,6 +45,7 @@ type WorkloadOperationQueue = HashMap<String, PendingEntry>;
 pub struct WorkloadScheduler {
     queue: WorkloadOperationQueue,
     workload_state_sender: WorkloadStateSender,
+    waiting_to_start_since: HashMap<String, Instant>,
 }
 
 #[cfg_attr(test, automock)]
@@ -49,6 +54,7 @@ impl WorkloadScheduler {
         WorkloadScheduler {
             queue: WorkloadOperationQueue::new(),
             workload_state_sender: workload_state_tx,
+            waiting_to_start_since: HashMap::new(),
         }
     }
 
@@ -72,6 +78,12 @@ impl WorkloadScheduler {
         for workload_operation in new_workload_operations {
             match workload_operation {
                 WorkloadOperation::Create(new_workload_spec) => {
+                    self.waiting_to_start_since.remove(
+                        new_workload_spec
+                            .workload_spec
+                            .instance_name
+                            .workload_name(),
+                    );
                     ready_workload_operations.extend(
                         self.enqueue_pending_create(
                             new_workload_spec,
@@ -82,6 +94,8 @@ impl WorkloadScheduler {
                     );
                 }
                 WorkloadOperation::Update(new_workload_spec, deleted_workload) => {
+                    self.waiting_to_start_since
+                        .remove(new_workload_spec.instance_name.workload_name());
                     ready_workload_operations.extend(
                         // [impl->swdd~agent-enqueues-update-with-unfulfilled-delete~1]
                         // [impl->swdd~agent-enqueues-update-with-unfulfilled-delete~1]
@@ -95,6 +109,8 @@ impl WorkloadScheduler {
                     );
                 }
                 WorkloadOperation::Delete(deleted_workload) => {
+                    self.waiting_to_start_since
+                        .remove(deleted_workload.instance_name.workload_name());
                     ready_workload_operations.extend(
                         self.enqueue_pending_delete(
                             deleted_workload,
@@ -150,11 +166,16 @@ impl WorkloadScheduler {
                         &new_workload_spec,
                         workload_state_db,
                     ) {
+                        self.waiting_to_start_since
+                            .remove(new_workload_spec.instance_name.workload_name());
                         ready_workload_operations.push(WorkloadOperation::Update(
                             new_workload_spec,
                             deleted_workload,
                         ));
-                    } else {
+                    } else if !self
+                        .dependency_timed_out(&new_workload_spec, workload_state_db)
+                        .await
+                    {
                         self.put_on_queue(
                             new_workload_spec.instance_name.workload_name().to_owned(),
                             PendingEntry::UpdateCreate(new_workload_spec, deleted_workload),
@@ -196,25 +217,31 @@ impl WorkloadScheduler {
     ) -> Vec<WorkloadOperation> {
         let mut ready_workload_operations = Vec::new();
         // [impl->swdd~workload-ready-to-create-on-fulfilled-dependencies~1]
+        let workload_name = new_workload_spec
+            .workload_spec
+            .instance_name
+            .workload_name()
+            .to_owned();
         if DependencyStateValidator::create_fulfilled(
             &new_workload_spec.workload_spec,
             workload_state_db,
         ) {
+            self.waiting_to_start_since.remove(&workload_name);
             ready_workload_operations.push(WorkloadOperation::Create(new_workload_spec));
-        } else {
+        } else if !self
+            .dependency_timed_out(&new_workload_spec.workload_spec, workload_state_db)
+            .await
+        {
+            self.waiting_to_start_since
+                .entry(workload_name.clone())
+                .or_insert_with(Instant::now);
+
             if notify_on_new_entry {
                 self.report_pending_create_state(&new_workload_spec.workload_spec.instance_name)
                     .await;
             }
 
-            self.put_on_queue(
-                new_workload_spec
-                    .workload_spec
-                    .instance_name
-                    .workload_name()
-                    .to_owned(),
-                PendingEntry::Create(new_workload_spec),
-            );
+            self.put_on_queue(workload_name, PendingEntry::Create(new_workload_spec));
         }
 
         ready_workload_operations
@@ -247,6 +274,26 @@ impl WorkloadScheduler {
             return ready_workload_operations;
         }
 
+        // [impl->swdd~agent-enqueues-at-least-once-update-with-unfulfilled-create~1]
+        if delete_fulfilled && new_workload_spec.update_ordering == UpdateOrdering::AtLeastOnce {
+            /* With the update strategy at least once the old workload keeps running
+            until the create dependencies are fulfilled and the new workload is created. */
+            if notify_on_new_entry {
+                self.report_pending_create_state(&new_workload_spec.instance_name)
+                    .await;
+            }
+
+            self.waiting_to_start_since
+                .entry(new_workload_spec.instance_name.workload_name().to_owned())
+                .or_insert_with(Instant::now);
+
+            self.put_on_queue(
+                new_workload_spec.instance_name.workload_name().to_owned(),
+                PendingEntry::UpdateCreate(new_workload_spec, deleted_workload),
+            );
+            return ready_workload_operations;
+        }
+
         // [impl->swdd~agent-handles-update-with-fulfilled-delete~1]
         if delete_fulfilled {
             /* For an update with pending create dependencies but fulfilled delete dependencies
@@ -260,6 +307,10 @@ impl WorkloadScheduler {
             self.report_pending_create_state(&new_workload_spec.instance_name)
                 .await;
 
+            self.waiting_to_start_since
+                .entry(new_workload_spec.instance_name.workload_name().to_owned())
+                .or_insert_with(Instant::now);
+
             self.put_on_queue(
                 new_workload_spec.instance_name.workload_name().to_owned(),
                 PendingEntry::UpdateCreate(new_workload_spec, deleted_workload.clone()),
@@ -310,6 +361,55 @@ impl WorkloadScheduler {
         ready_workload_operations
     }
 
+    // [impl->swdd~agent-fails-workload-on-dependency-timeout~1]
+    async fn dependency_timed_out(
+        &mut self,
+        workload_spec: &WorkloadSpec,
+        workload_state_db: &WorkloadStateStore,
+    ) -> bool {
+        let workload_name = workload_spec.instance_name.workload_name();
+        let Some(waiting_time) = self
+            .waiting_to_start_since
+            .get(workload_name)
+            .map(Instant::elapsed)
+        else {
+            return false;
+        };
+
+        let Some((dependency_name, timeout)) =
+            workload_spec
+                .dependency_timeouts
+                .iter()
+                .find(|(dependency_name, timeout)| {
+                    waiting_time >= Duration::from_secs((**timeout).into())
+                        && !workload_spec
+                            .dependencies
+                            .get(*dependency_name)
+                            .is_some_and(|add_condition| {
+                                workload_state_db
+                                    .get_state_of_workload(dependency_name)
+                                    .is_some_and(|wl_state| add_condition.fulfilled_by(wl_state))
+                            })
+                })
+        else {
+            return false;
+        };
+
+        log::warn!(
+            "Workload '{workload_name}' is not started, its dependency '{dependency_name}' did not fulfill the add condition within {timeout} seconds."
+        );
+        self.waiting_to_start_since.remove(workload_name);
+        self.workload_state_sender
+            .report_workload_execution_state(
+                &workload_spec.instance_name,
+                ExecutionState::dependency_timeout(format!(
+                    "Dependency '{dependency_name}' did not fulfill its add condition within {timeout} seconds"
+                )),
+            )
+            .await;
+        true
+    }
+
     async fn report_pending_create_state(&self, instance_name: &WorkloadInstanceName) {
         self.workload_state_sender
             .report_workload_execution_state(instance_name, ExecutionState::waiting_to_start())
@@ -335,13 +435,14 @@ impl WorkloadScheduler {
 mod tests {
     use common::{
         objects::{
-            ExecutionState, WorkloadState, generate_test_workload_spec,
+            ExecutionState, UpdateOrdering, WorkloadState, generate_test_workload_spec,
             generate_test_workload_spec_with_param,
             generate_test_workload_state_with_workload_spec,
         },
         test_utils::generate_test_deleted_workload,
     };
-    use tokio::sync::mpsc::channel;
+    use std::{collections::HashMap, time::Duration};
+    use tokio::{sync::mpsc::channel, time::Instant};
 
     use super::WorkloadScheduler;
     use crate::{
@@ -357,6 +458,7 @@ mod tests {
     const AGENT_A: &str = "agent_A";
     const WORKLOAD_NAME_1: &str = "workload_1";
     const RUNTIME: &str = "runtime";
+    const DEPENDENCY_WORKLOAD_NAME: &str = "workload_A";
 
     // [utest->swdd~agent-handles-new-workload-operations~1]
     // [utest->swdd~agent-enqueues-unfulfilled-create~1]
@@ -853,6 +955,80 @@ mod tests {
         );
     }
 
+    // [utest->swdd~agent-enqueues-at-least-once-update-with-unfulfilled-create~1]
+    #[tokio::test]
+    async fn utest_enqueue_pending_update_create_at_least_once_without_delete() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+        let (workload_state_sender, mut workload_state_receiver) = channel(1);
+        let mut workload_scheduler = WorkloadScheduler::new(workload_state_sender);
+
+        let mock_dependency_state_validator_create_context =
+            MockDependencyStateValidator::create_fulfilled_context();
+        mock_dependency_state_validator_create_context
+            .expect()
+            .return_const(false);
+
+        let mock_dependency_state_validator_delete_context =
+            MockDependencyStateValidator::delete_fulfilled_context();
+        mock_dependency_state_validator_delete_context
+            .expect()
+            .return_const(true);
+
+        let mut pending_new_workload = generate_test_workload_spec_with_param(
+            AGENT_A.to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            RUNTIME.to_owned(),
+        );
+        pending_new_workload.update_ordering = UpdateOrdering::AtLeastOnce;
+
+        let ready_deleted_workload = generate_test_deleted_workload(
+            pending_new_workload.instance_name.agent_name().to_owned(),
+            pending_new_workload
+                .instance_name
+                .workload_name()
+                .to_owned(),
+        );
+
+        let workload_operations = vec![WorkloadOperation::Update(
+            pending_new_workload.clone(),
+            ready_deleted_workload.clone(),
+        )];
+
+        let ready_workload_operations = workload_scheduler
+            .enqueue_filtered_workload_operations(
+                workload_operations,
+                &MockWorkloadStateStore::default(),
+            )
+            .await;
+
+        assert!(ready_workload_operations.is_empty());
+        assert_eq!(
+            Some(&PendingEntry::UpdateCreate(
+                pending_new_workload.clone(),
+                ready_deleted_workload
+            )),
+            workload_scheduler
+                .queue
+                .get(pending_new_workload.instance_name.workload_name())
+        );
+
+        let expected_workload_state = WorkloadState {
+            instance_name: pending_new_workload.instance_name,
+            execution_state: ExecutionState::waiting_to_start(),
+        };
+
+        assert_eq!(
+            Ok(Some(expected_workload_state)),
+            tokio::time::timeout(
+                tokio::time::Duration::from_millis(100),
+                workload_state_receiver.recv()
+            )
+            .await
+        );
+    }
+
     // [utest->swdd~agent-handles-workloads-with-fulfilled-dependencies~1]
     #[tokio::test]
     async fn utest_no_enqueue_and_report_pending_state_on_fulfilled_update_at_most_once() {
@@ -1661,4 +1837,83 @@ mod tests {
 
         assert!(workload_scheduler.queue.is_empty());
     }
+
+    // [utest->swdd~agent-fails-workload-on-dependency-timeout~1]
+    #[tokio::test]
+    async fn utest_next_workload_operations_report_dependency_timeout_for_pending_create() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+        let (workload_state_sender, workload_state_receiver) = channel(2);
+        let mut workload_scheduler = WorkloadScheduler::new(workload_state_sender);
+
+        let mock_dependency_state_validator_create_context =
+            MockDependencyStateValidator::create_fulfilled_context();
+        mock_dependency_state_validator_create_context
+            .expect()
+            .return_const(false);
+
+        let mut pending_workload = generate_test_workload_spec_with_param(
+            AGENT_A.to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            RUNTIME.to_owned(),
+        );
+        pending_workload.dependency_timeouts =
+            HashMap::from([(DEPENDENCY_WORKLOAD_NAME.to_owned(), 10)]);
+        let instance_name = pending_workload.instance_name.clone();
+
+        let ready_workload_operations = workload_scheduler
+            .enqueue_filtered_workload_operations(
+                vec![WorkloadOperation::Create(ReusableWorkloadSpec::new(
+                    pending_workload,
+                    None,
+                ))],
+                &MockWorkloadStateStore::default(),
+            )
+            .await;
+        assert!(ready_workload_operations.is_empty());
+
+        let waiting_since_timeout = Instant::now().checked_sub(Duration::from_secs(11)).unwrap();
+        workload_scheduler
+            .waiting_to_start_since
+            .insert(WORKLOAD_NAME_1.to_owned(), waiting_since_timeout);
+
+        // the dependency with the timeout is running, only the other dependency is not fulfilled
+        let mut wl_state_store_mock = MockWorkloadStateStore::default();
+        wl_state_store_mock.states_storage.insert(
+            DEPENDENCY_WORKLOAD_NAME.to_owned(),
+            ExecutionState::running(),
+        );
+
+        assert!(
+            workload_scheduler
+                .next_workload_operations(&wl_state_store_mock)
+                .await
+                .is_empty()
+        );
+        assert!(workload_scheduler.queue.contains_key(WORKLOAD_NAME_1));
+
+        assert!(
+            workload_scheduler
+                .next_workload_operations(&MockWorkloadStateStore::default())
+                .await
+                .is_empty()
+        );
+        assert!(workload_scheduler.queue.is_empty());
+        assert!(workload_scheduler.waiting_to_start_since.is_empty());
+
+        assert_execution_state_sequence(
+            workload_state_receiver,
+            vec![
+                (&instance_name, ExecutionState::waiting_to_start()),
+                (
+                    &instance_name,
+                    ExecutionState::dependency_timeout(format!(
+                        "Dependency '{DEPENDENCY_WORKLOAD_NAME}' did not fulfill its add condition within 10 seconds"
+                    )),
+                ),
+            ],
+        )
+        .await;
+    }
 }
@@ -12,7 +12,7 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
-use common::objects::{ExecutionState, WorkloadState};
+use common::objects::{ExecutionState, WorkloadInstanceName, WorkloadState};
 use std::collections::HashMap;
 #[cfg(test)]
 use std::collections::VecDeque;
@@ -21,12 +21,14 @@ type WorkloadStates = HashMap<String, common::objects::ExecutionState>;
 
 pub struct WorkloadStateStore {
     states_storage: WorkloadStates,
+    instance_names: HashMap<String, WorkloadInstanceName>,
 }
 
 impl WorkloadStateStore {
     pub fn new() -> Self {
         Self {
             states_storage: HashMap::new(),
+            instance_names: HashMap::new(),
         }
     }
 
@@ -38,9 +40,17 @@ impl WorkloadStateStore {
         let workload_name = workload_state.instance_name.workload_name().to_owned();
         if !workload_state.execution_state.is_removed() {
             self.states_storage
-                .insert(workload_name, workload_state.execution_state);
-        } else {
+                .insert(workload_name.clone(), workload_state.execution_state);
+            self.instance_names
+                .insert(workload_name, workload_state.instance_name);
+        } else if self
+            .instance_names
+            .get(&workload_name)
+            .is_none_or(|instance_name| *instance_name == workload_state.instance_name)
+        {
+            // [impl->swdd~agent-keeps-workload-state-on-removed-replaced-instance~1]
             self.states_storage.remove(&workload_name);
+            self.instance_names.remove(&workload_name);
         }
     }
 }
@@ -97,7 +107,7 @@ impl Drop for MockWorkloadStateStore {
 #[cfg(test)]
 mod tests {
     use super::WorkloadStateStore;
-    use common::objects::ExecutionState;
+    use common::objects::{ExecutionState, WorkloadInstanceName};
 
     #[test]
     fn utest_update_storage_empty_storage_add_one() {
@@ -224,6 +234,39 @@ mod tests {
         );
     }
 
+    // [utest->swdd~agent-keeps-workload-state-on-removed-replaced-instance~1]
+    #[test]
+    fn utest_update_storage_removed_replaced_instance_keeps_state() {
+        let mut storage = WorkloadStateStore::new();
+
+        let old_instance = common::objects::generate_test_workload_state_with_agent(
+            "test_workload",
+            "test_agent",
+            ExecutionState::running(),
+        );
+        let mut new_instance = old_instance.clone();
+        new_instance.instance_name = WorkloadInstanceName::builder()
+            .workload_name("test_workload")
+            .agent_name("test_agent")
+            .config(&String::from("new config"))
+            .build();
+        storage.update_workload_state(new_instance.clone());
+
+        let mut removed_update = old_instance;
+        removed_update.execution_state = ExecutionState::removed();
+        storage.update_workload_state(removed_update);
+
+        assert_eq!(
+            storage.get_state_of_workload("test_workload"),
+            Some(&ExecutionState::running())
+        );
+
+        new_instance.execution_state = ExecutionState::removed();
+        storage.update_workload_state(new_instance);
+
+        assert!(storage.states_storage.is_empty());
+    }
+
     #[test]
     fn utest_get_state_of_workload() {
         let mut parameter_storage = WorkloadStateStore::new();
@@ -18,11 +18,11 @@ use common::std_extensions::UnreachableOption;
 use grpc::security::read_pem_file;
 use serde::de::{Error, MapAccess, Visitor};
 use serde::{Deserialize, Deserializer};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt;
-use std::fs::read_to_string;
-use std::path::PathBuf;
-use toml::{Value, from_str};
+use std::fs::{read_to_string, write};
+use std::path::{Path, PathBuf};
+use toml::{Table, Value, from_str};
 
 use common::std_extensions::GracefulExitResult;
 use once_cell::sync::Lazy;
@@ -30,6 +30,7 @@ use std::env;
 
 pub const CONFIG_VERSION: &str = "v1";
 pub const DEFAULT_CONFIG: &str = "default";
+pub const CURRENT_CONTEXT_KEY: &str = "current_context";
 pub const DEFAULT_RESPONSE_TIMEOUT: u64 = 3000;
 
 pub static DEFAULT_ANK_CONFIG_FILE_PATH: Lazy<String> = Lazy::new(|| {
@@ -43,6 +44,8 @@ pub enum ConversionErrors {
     ConflictingCertificates(String),
     InvalidAnkConfig(String),
     InvalidCertificate(String),
+    UnknownContext(String),
+    WriteFailed(String),
 }
 
 impl fmt::Display for ConversionErrors {
@@ -58,6 +61,10 @@ impl fmt::Display for ConversionErrors {
             ConversionErrors::InvalidCertificate(msg) => {
                 write!(f, "Certificate could not have been read due to: {msg}")
             }
+            ConversionErrors::UnknownContext(msg) => write!(f, "Unknown context: {msg}"),
+            ConversionErrors::WriteFailed(msg) => {
+                write!(f, "Ank Config could not have been written due to: {msg}")
+            }
         }
     }
 }
@@ -74,6 +81,7 @@ fn get_default_url() -> String {
 #[derive(Debug, PartialEq)]
 pub struct AnkConfig {
     pub version: String,
+    pub context: String,
     pub response_timeout: u64,
     pub verbose: bool,
     pub quiet: bool,
@@ -115,6 +123,7 @@ impl From<AnkConfigHelper> for AnkConfig {
     fn from(helper: AnkConfigHelper) -> Self {
         AnkConfig {
             version: helper.version,
+            context: DEFAULT_CONFIG.to_string(),
             response_timeout: helper.response_timeout,
             verbose: helper.verbose,
             quiet: helper.quiet,
@@ -131,8 +140,9 @@ impl From<AnkConfigHelper> for AnkConfig {
     }
 }
 
+// [impl->swdd~cli-selects-context~1]
 struct AnkConfigVisitor {
-    table_key: &'static str,
+    context: Option<String>,
 }
 
 impl<'de> Visitor<'de> for AnkConfigVisitor {
@@ -150,37 +160,43 @@ impl<'de> Visitor<'de> for AnkConfigVisitor {
 
         while let Some(key) = map.next_key::<String>()? {
             let value: Value = map.next_value()?;
-            match key.as_str() {
-                k if k == self.table_key => {
-                    if let Value::Table(inner) = value {
-                        merged.extend(inner.into_iter());
-                    } else {
-                        return Err(V::Error::custom(format!(
-                            "Expected '{}' to be a table",
-                            self.table_key
-                        )));
-                    }
-                }
-                _ => {
-                    merged.insert(key, value);
-                }
-            }
+            merged.insert(key, value);
         }
 
+        let context = match (self.context, merged.get(CURRENT_CONTEXT_KEY)) {
+            (Some(context), _) => context,
+            (None, Some(Value::String(current_context))) => current_context.clone(),
+            (None, Some(_)) => {
+                return Err(V::Error::custom(format!(
+                    "Expected '{CURRENT_CONTEXT_KEY}' to be a string"
+                )));
+            }
+            (None, None) => DEFAULT_CONFIG.to_string(),
+        };
+
+        let context_values = match merged.remove(&context) {
+            Some(Value::Table(inner)) => inner,
+            Some(_) => {
+                return Err(V::Error::custom(format!(
+                    "Expected '{context}' to be a table"
+                )));
+            }
+            None if context == DEFAULT_CONFIG => Table::new(),
+            None => {
+                return Err(V::Error::custom(format!("Context '{context}' not found")));
+            }
+        };
+
+        // The tables of the other contexts are not relevant and the values of the
+        // selected context take precedence over the values outside of any context.
+        merged.retain(|_, value| !value.is_table());
+        merged.extend(context_values);
+
         let deserializer = serde::de::value::MapDeserializer::new(merged.into_iter());
         let helper = AnkConfigHelper::deserialize(deserializer).map_err(V::Error::custom)?;
-        Ok(helper.into())
-    }
-}
-
-impl<'de> Deserialize<'de> for AnkConfig {
-    fn deserialize<D>(deserializer: D) -> Result<AnkConfig, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        deserializer.deserialize_map(AnkConfigVisitor {
-            table_key: DEFAULT_CONFIG,
-        })
+        let mut ank_config: AnkConfig = helper.into();
+        ank_config.context = context;
+        Ok(ank_config)
     }
 }
 
@@ -188,6 +204,7 @@ impl Default for AnkConfig {
     fn default() -> Self {
         AnkConfig {
             version: CONFIG_VERSION.to_string(),
+            context: DEFAULT_CONFIG.to_string(),
             response_timeout: get_default_response_timeout(),
             verbose: bool::default(),
             quiet: bool::default(),
@@ -206,10 +223,19 @@ impl Default for AnkConfig {
 
 impl AnkConfig {
     // [impl->swdd~cli-loads-config-file~1]
-    pub fn from_file(file_path: PathBuf) -> Result<AnkConfig, ConversionErrors> {
+    // [impl->swdd~cli-selects-context~1]
+    pub fn from_file(
+        file_path: PathBuf,
+        context: Option<&str>,
+    ) -> Result<AnkConfig, ConversionErrors> {
         let ank_config_content = read_to_string(file_path.to_str().unwrap_or_unreachable())
             .map_err(|err| ConversionErrors::InvalidAnkConfig(err.to_string()))?;
-        let mut ank_config: AnkConfig = from_str(&ank_config_content)
+        let mut ank_config = from_str::<Table>(&ank_config_content)
+            .and_then(|ank_config_table| {
+                ank_config_table.deserialize_map(AnkConfigVisitor {
+                    context: context.map(str::to_string),
+                })
+            })
             .map_err(|err| ConversionErrors::InvalidAnkConfig(err.to_string()))?;
 
         if ank_config.version != CONFIG_VERSION {
@@ -284,6 +310,126 @@ impl AnkConfig {
     }
 }
 
+#[derive(Debug, PartialEq, Eq)]
+pub struct AnkContexts {
+    pub current_context: String,
+    pub contexts: Vec<String>,
+}
+
+impl fmt::Display for AnkContexts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let lines: Vec<String> = self
+            .contexts
+            .iter()
+            .map(|context| {
+                let marker = if *context == self.current_context {
+                    '*'
+                } else {
+                    ' '
+                };
+                format!("{marker} {context}")
+            })
+            .collect();
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
+// [impl->swdd~cli-provides-contexts~1]
+pub fn get_contexts(file_path: &Path) -> Result<AnkContexts, ConversionErrors> {
+    // Without a config file only the default context with the default values exists.
+    if !file_path.try_exists().unwrap_or(false) {
+        return Ok(AnkContexts {
+            current_context: DEFAULT_CONFIG.to_string(),
+            contexts: vec![DEFAULT_CONFIG.to_string()],
+        });
+    }
+
+    let ank_config_content = read_to_string(file_path)
+        .map_err(|err| ConversionErrors::InvalidAnkConfig(err.to_string()))?;
+    contexts_from_content(&ank_config_content)
+}
+
+// [impl->swdd~cli-persists-current-context~1]
+pub fn use_context(file_path: &Path, context: &str) -> Result<(), ConversionErrors> {
+    let ank_config_content = read_to_string(file_path)
+        .map_err(|err| ConversionErrors::InvalidAnkConfig(err.to_string()))?;
+
+    let ank_contexts = contexts_from_content(&ank_config_content)?;
+    if !ank_contexts.contexts.iter().any(|known| known == context) {
+        return Err(ConversionErrors::UnknownContext(context.to_string()));
+    }
+
+    write(file_path, set_current_context(&ank_config_content, context))
+        .map_err(|err| ConversionErrors::WriteFailed(err.to_string()))
+}
+
+fn contexts_from_content(ank_config_content: &str) -> Result<AnkContexts, ConversionErrors> {
+    let ank_config_table = from_str::<Table>(ank_config_content)
+        .map_err(|err| ConversionErrors::InvalidAnkConfig(err.to_string()))?;
+
+    let current_context = ank_config_table
+        .get(CURRENT_CONTEXT_KEY)
+        .and_then(Value::as_str)
+        .unwrap_or(DEFAULT_CONFIG)
+        .to_string();
+
+    // The default context always exists, even if the config file does not contain it.
+    let mut contexts: BTreeSet<String> = ank_config_table
+        .iter()
+        .filter(|(_, value)| value.is_table())
+        .map(|(context, _)| context.clone())
+        .collect();
+    contexts.insert(DEFAULT_CONFIG.to_string());
+
+    Ok(AnkContexts {
+        current_context,
+        contexts: contexts.into_iter().collect(),
+    })
+}
+
+// The config file is edited line by line to keep the comments and the formatting of the user.
+fn set_current_context(ank_config_content: &str, context: &str) -> String {
+    let current_context_line = format!(
+        "{CURRENT_CONTEXT_KEY} = {}",
+        Value::String(context.to_string())
+    );
+
+    let mut lines: Vec<String> = ank_config_content.lines().map(str::to_string).collect();
+    let first_table_index = lines
+        .iter()
+        .position(|line| line.trim_start().starts_with('['))
+        .unwrap_or(lines.len());
+    let top_level_lines = &lines[..first_table_index];
+
+    if let Some(index) = top_level_lines
+        .iter()
+        .position(|line| is_current_context_line(line))
+    {
+        lines[index] = current_context_line;
+    } else {
+        let index = top_level_lines
+            .iter()
+            .rposition(|line| is_key_value_line(line))
+            .map_or(0, |index| index + 1);
+        lines.insert(index, current_context_line);
+    }
+
+    let mut new_ank_config_content = lines.join("\n");
+    new_ank_config_content.push('\n');
+    new_ank_config_content
+}
+
+fn is_current_context_line(line: &str) -> bool {
+    line.trim_start()
+        .strip_prefix(CURRENT_CONTEXT_KEY)
+        .is_some_and(|rest| rest.trim_start().starts_with('='))
+}
+
+fn is_key_value_line(line: &str) -> bool {
+    let line = line.trim_start();
+    !line.starts_with('#') && line.contains('=')
+}
+
 //////////////////////////////////////////////////////////////////////////////
 //                 ########  #######    #########  #########                //
 //                    ##     ##        ##             ##                    //
@@ -305,7 +451,10 @@ mod tests {
         cli::{AnkCli, Commands, GetArgs, GetCommands},
     };
 
-    use super::{AnkConfig, DEFAULT_ANK_CONFIG_FILE_PATH};
+    use super::{
+        AnkConfig, AnkContexts, DEFAULT_ANK_CONFIG_FILE_PATH, get_contexts, set_current_context,
+        use_context,
+    };
 
     const CA_PEM_PATH: &str = "some_path_to_ca_pem/ca.pem";
     const CRT_PEM_PATH: &str = "some_path_to_crt_pem/crt.pem";
@@ -317,6 +466,18 @@ mod tests {
     const KEY_PEM_CONTENT: &str = r"the content of the
         key.pem file is stored in here";
     const TEST_SERVER_URL: &str = r"https://127.0.0.1:25555";
+    const CONTEXTS_ANK_CONFIG_CONTENT: &str = r"# the config of the contexts
+version = 'v1'
+response_timeout = 3000
+
+[default]
+server_url = 'https://127.0.0.1:25551'
+
+# the lab server
+[lab]
+server_url = 'https://10.0.0.1:25551'
+response_timeout = 5000
+";
 
     // [utest->swdd~cli-loads-config-file~1]
     #[test]
@@ -349,7 +510,7 @@ mod tests {
         let mut tmp_config_file = NamedTempFile::new().unwrap();
         write!(tmp_config_file, "{ank_config_content}").unwrap();
 
-        let ank_config = AnkConfig::from_file(PathBuf::from(tmp_config_file.path()));
+        let ank_config = AnkConfig::from_file(PathBuf::from(tmp_config_file.path()), None);
 
         assert_eq!(
             ank_config,
@@ -372,7 +533,7 @@ mod tests {
         let mut tmp_config_file = NamedTempFile::new().unwrap();
         write!(tmp_config_file, "{ank_config_content}").unwrap();
 
-        let ank_config = AnkConfig::from_file(PathBuf::from(tmp_config_file.path()));
+        let ank_config = AnkConfig::from_file(PathBuf::from(tmp_config_file.path()), None);
 
         assert_eq!(
             ank_config,
@@ -395,6 +556,7 @@ mod tests {
             }),
             server_url: Some(TEST_SERVER_URL.to_string()),
             config_path: Some(DEFAULT_ANK_CONFIG_FILE_PATH.to_string()),
+            context: None,
             response_timeout_ms: Some(5000),
             insecure: Some(false),
             verbose: Some(true),
@@ -434,7 +596,8 @@ mod tests {
         let mut tmp_config_file = NamedTempFile::new().unwrap();
         write!(tmp_config_file, "{ank_config_content}").unwrap();
 
-        let mut ank_config = AnkConfig::from_file(PathBuf::from(tmp_config_file.path())).unwrap();
+        let mut ank_config =
+            AnkConfig::from_file(PathBuf::from(tmp_config_file.path()), None).unwrap();
         let args = AnkCli {
             command: Commands::Get(GetArgs {
                 command: Some(GetCommands::State {
@@ -444,6 +607,7 @@ mod tests {
             }),
             server_url: Some(DEFAULT_SERVER_ADDRESS.to_string()),
             config_path: Some(DEFAULT_ANK_CONFIG_FILE_PATH.to_string()),
+            context: None,
             response_timeout_ms: Some(5000),
             insecure: Some(false),
             verbose: Some(true),
@@ -483,7 +647,8 @@ mod tests {
         let mut tmp_config_file = NamedTempFile::new().unwrap();
         write!(tmp_config_file, "{ank_config_content}").unwrap();
 
-        let mut ank_config = AnkConfig::from_file(PathBuf::from(tmp_config_file.path())).unwrap();
+        let mut ank_config =
+            AnkConfig::from_file(PathBuf::from(tmp_config_file.path()), None).unwrap();
         let args = AnkCli {
             command: Commands::Get(GetArgs {
                 command: Some(GetCommands::State {
@@ -493,6 +658,7 @@ mod tests {
             }),
             server_url: Some(DEFAULT_SERVER_ADDRESS.to_string()),
             config_path: Some(DEFAULT_ANK_CONFIG_FILE_PATH.to_string()),
+            context: None,
             response_timeout_ms: Some(5000),
             insecure: None,
             verbose: None,
@@ -520,7 +686,7 @@ mod tests {
         let mut tmp_config_file = NamedTempFile::new().unwrap();
         write!(tmp_config_file, "{ank_config_content}").unwrap();
 
-        let ank_config = AnkConfig::from_file(PathBuf::from(tmp_config_file.path())).unwrap();
+        let ank_config = AnkConfig::from_file(PathBuf::from(tmp_config_file.path()), None).unwrap();
 
         assert_eq!(ank_config.server_url, get_default_url());
         assert!(!ank_config.insecure);
@@ -543,7 +709,7 @@ mod tests {
         let mut tmp_config_file = NamedTempFile::new().unwrap();
         write!(tmp_config_file, "{ank_config_content}").unwrap();
 
-        let ank_config = AnkConfig::from_file(PathBuf::from(tmp_config_file.path()));
+        let ank_config = AnkConfig::from_file(PathBuf::from(tmp_config_file.path()), None);
 
         assert!(ank_config.is_ok());
     }
@@ -570,7 +736,7 @@ mod tests {
         let mut tmp_config_file = NamedTempFile::new().unwrap();
         write!(tmp_config_file, "{ank_config_content}").unwrap();
 
-        let ank_config_res = AnkConfig::from_file(PathBuf::from(tmp_config_file.path()));
+        let ank_config_res = AnkConfig::from_file(PathBuf::from(tmp_config_file.path()), None);
 
         assert!(ank_config_res.is_ok());
 
@@ -592,4 +758,133 @@ mod tests {
             Some(KEY_PEM_CONTENT.to_string())
         );
     }
+
+    // [utest->swdd~cli-selects-context~1]
+    #[test]
+    fn utest_ank_config_from_file_selects_context() {
+        let mut tmp_config_file = NamedTempFile::new().unwrap();
+        write!(tmp_config_file, "{CONTEXTS_ANK_CONFIG_CONTENT}").unwrap();
+
+        let ank_config =
+            AnkConfig::from_file(PathBuf::from(tmp_config_file.path()), Some("lab")).unwrap();
+
+        assert_eq!(ank_config.context, "lab");
+        assert_eq!(ank_config.server_url, "https://10.0.0.1:25551");
+        assert_eq!(ank_config.response_timeout, 5000);
+    }
+
+    // [utest->swdd~cli-selects-context~1]
+    #[test]
+    fn utest_ank_config_from_file_uses_current_context() {
+        let mut tmp_config_file = NamedTempFile::new().unwrap();
+        write!(
+            tmp_config_file,
+            "{}",
+            set_current_context(CONTEXTS_ANK_CONFIG_CONTENT, "lab")
+        )
+        .unwrap();
+
+        let ank_config = AnkConfig::from_file(PathBuf::from(tmp_config_file.path()), None).unwrap();
+        assert_eq!(ank_config.context, "lab");
+        assert_eq!(ank_config.server_url, "https://10.0.0.1:25551");
+
+        let ank_config =
+            AnkConfig::from_file(PathBuf::from(tmp_config_file.path()), Some("default")).unwrap();
+        assert_eq!(ank_config.context, "default");
+        assert_eq!(ank_config.server_url, DEFAULT_SERVER_ADDRESS.to_string());
+        assert_eq!(ank_config.response_timeout, 3000);
+    }
+
+    // [utest->swdd~cli-selects-context~1]
+    #[test]
+    fn utest_ank_config_from_file_unknown_context() {
+        let mut tmp_config_file = NamedTempFile::new().unwrap();
+        write!(tmp_config_file, "{CONTEXTS_ANK_CONFIG_CONTENT}").unwrap();
+
+        let ank_config =
+            AnkConfig::from_file(PathBuf::from(tmp_config_file.path()), Some("vehicle"));
+
+        assert!(matches!(
+            ank_config,
+            Err(ConversionErrors::InvalidAnkConfig(msg)) if msg.contains("Context 'vehicle' not found")
+        ));
+    }
+
+    // [utest->swdd~cli-provides-contexts~1]
+    #[test]
+    fn utest_get_contexts() {
+        let mut tmp_config_file = NamedTempFile::new().unwrap();
+        write!(tmp_config_file, "version = 'v1'\n[lab]\n[hil]\n").unwrap();
+
+        let ank_contexts = get_contexts(tmp_config_file.path()).unwrap();
+
+        assert_eq!(
+            ank_contexts,
+            AnkContexts {
+                current_context: "default".to_string(),
+                contexts: vec!["default".to_string(), "hil".to_string(), "lab".to_string()],
+            }
+        );
+        assert_eq!(ank_contexts.to_string(), "* default\n  hil\n  lab");
+    }
+
+    // [utest->swdd~cli-provides-contexts~1]
+    #[test]
+    fn utest_get_contexts_without_config_file() {
+        let ank_contexts =
+            get_contexts(&PathBuf::from("/a/very/invalid/path/to/config/file")).unwrap();
+
+        assert_eq!(
+            ank_contexts,
+            AnkContexts {
+                current_context: "default".to_string(),
+                contexts: vec!["default".to_string()],
+            }
+        );
+    }
+
+    // [utest->swdd~cli-persists-current-context~1]
+    #[test]
+    fn utest_use_context_persists_current_context() {
+        let mut tmp_config_file = NamedTempFile::new().unwrap();
+        write!(tmp_config_file, "{CONTEXTS_ANK_CONFIG_CONTENT}").unwrap();
+
+        use_context(tmp_config_file.path(), "lab").unwrap();
+        assert_eq!(
+            std::fs::read_to_string(tmp_config_file.path()).unwrap(),
+            CONTEXTS_ANK_CONFIG_CONTENT.replace(
+                "response_timeout = 3000\n",
+                "response_timeout = 3000\ncurrent_context = \"lab\"\n"
+            )
+        );
+
+        use_context(tmp_config_file.path(), "default").unwrap();
+        let ank_contexts = get_contexts(tmp_config_file.path()).unwrap();
+        assert_eq!(ank_contexts.current_context, "default");
+    }
+
+    // [utest->swdd~cli-persists-current-context~1]
+    #[test]
+    fn utest_use_context_unknown_context() {
+        let mut tmp_config_file = NamedTempFile::new().unwrap();
+        write!(tmp_config_file, "{CONTEXTS_ANK_CONFIG_CONTENT}").unwrap();
+
+        assert_eq!(
+            use_context(tmp_config_file.path(), "vehicle"),
+            Err(ConversionErrors::UnknownContext("vehicle".to_string()))
+        );
+        assert_eq!(
+            std::fs::read_to_string(tmp_config_file.path()).unwrap(),
+            CONTEXTS_ANK_CONFIG_CONTENT
+        );
+    }
+
+    // [utest->swdd~cli-persists-current-context~1]
+    #[test]
+    fn utest_set_current_context_without_top_level_values() {
+        assert_eq!(
+            set_current_context("[lab]\nserver_url = 'https://10.0.0.1:25551'", "lab"),
+            "current_context = \"lab\"\n[lab]\nserver_url = 'https://10.0.0.1:25551'\n"
+        );
+    }
 }
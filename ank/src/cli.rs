@@ -153,6 +153,9 @@ pub struct AnkCli {
     /// The path to the server config file.
     /// The default path is $HOME/.config/ankaios/ank.conf
     pub config_path: Option<String>,
+    #[clap(long = "context", required = false, env = "ANK_CONTEXT")]
+    /// The context of the config file to use instead of the current context.
+    pub context: Option<String>,
     #[clap(short = 's', long = "server-url", required=false, env = ANK_SERVER_URL_ENV_KEY)]
     /// The url to Ankaios server.
     pub server_url: Option<String>,
@@ -196,7 +199,16 @@ pub enum Commands {
     #[command(arg_required_else_help = true)]
     Apply(ApplyArgs),
     #[command(arg_required_else_help = true)]
+    Diff(DiffArgs),
+    #[command(arg_required_else_help = true)]
+    Describe(DescribeArgs),
+    #[command(arg_required_else_help = true)]
     Logs(LogsArgs),
+    #[command(arg_required_else_help = true)]
+    Rollback(RollbackArgs),
+    Top(TopArgs),
+    #[command(arg_required_else_help = true)]
+    Config(ConfigArgs),
 }
 
 /// Retrieve information about the current Ankaios system
@@ -213,6 +225,41 @@ pub enum OutputFormat {
     Json,
 }
 
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, clap::ValueEnum)]
+pub enum WorkloadsOutputFormat {
+    Table,
+    Wide,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, clap::ValueEnum)]
+pub enum TopSortKey {
+    /// Sort by agent name
+    Name,
+    /// Sort by CPU usage, highest first
+    Cpu,
+    /// Sort by free memory, lowest first
+    Memory,
+    /// Sort by number of workloads, highest first
+    Workloads,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, clap::ValueEnum)]
+pub enum GraphOutputFormat {
+    /// Plain-text tree starting at the workloads no other workload depends on
+    Tree,
+    /// Graphviz DOT
+    Dot,
+    /// Mermaid flowchart
+    Mermaid,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, clap::ValueEnum)]
+pub enum EventsOutputFormat {
+    Text,
+    Json,
+    Yaml,
+}
+
 /// Get commands
 #[derive(Debug, Subcommand)]
 pub enum GetCommands {
@@ -229,6 +276,9 @@ pub enum GetCommands {
     /// For automation use "ank get state -o json" and process the workloadStates
     #[clap(visible_alias("workloads"), verbatim_doc_comment)]
     Workload {
+        /// Specify the output format, 'wide' additionally shows the resource limits
+        #[arg(short = 'o', value_enum, default_value_t = WorkloadsOutputFormat::Table)]
+        output_format: WorkloadsOutputFormat,
         /// Only workloads of the given agent shall be output
         #[arg(short = 'a', long = "agent", required = false)]
         agent_name: Option<String>,
@@ -250,6 +300,39 @@ pub enum GetCommands {
     /// For automation use "ank get state -o json" and process desiredState.configs
     #[clap(visible_alias("configs"), verbatim_doc_comment)]
     Config {},
+    /// Information about the revisions of the desired state kept by the Ankaios server
+    /// The desired state of a single revision is output if the revision number is given
+    #[clap(visible_alias("revisions"), verbatim_doc_comment)]
+    Revision {
+        /// The number of the revision to output [default: empty = list all revisions]
+        revision: Option<u64>,
+    },
+    /// Live feed of the changes in the Ankaios system until interrupted
+    /// Includes workload execution state transitions, agent connects and disconnects and edits of the desired state
+    /// The timestamp of an event is the time the CLI received the change
+    #[clap(visible_alias("events"), verbatim_doc_comment)]
+    Event {
+        /// Specify the output format
+        #[arg(short = 'o', value_enum, default_value_t = EventsOutputFormat::Text)]
+        output_format: EventsOutputFormat,
+        /// Only events of the given agent shall be output
+        #[arg(short = 'a', long = "agent", required = false)]
+        agent_name: Option<String>,
+        /// Only workload state transitions into the given state shall be output, e.g. 'Running' or 'Failed(ExecFailed)'
+        #[arg(short = 's', long = "state", required = false)]
+        state: Option<String>,
+        /// Only events of the given workload(s) shall be output [default: empty = all workloads]
+        #[arg(add = ArgValueCompleter::new(workload_completer))]
+        workload_name: Vec<String>,
+    },
+    /// Dependency graph of the workloads in the desired state
+    /// Nodes show the agent and current execution state, edges the add condition of the dependency
+    #[clap(verbatim_doc_comment)]
+    Graph {
+        /// Specify the output format
+        #[arg(short = 'o', value_enum, default_value_t = GraphOutputFormat::Tree)]
+        output_format: GraphOutputFormat,
+    },
 }
 
 /// Update the state of Ankaios system
@@ -271,6 +354,20 @@ pub enum SetCommands {
         /// A file containing the new State Object Description in yaml format
         #[arg(required = true, value_hint = ValueHint::FilePath)]
         state_object_file: String,
+        /// Only show the workload changes the update would cause without applying it
+        #[arg(long = "dry-run", default_value_t = false)]
+        dry_run: bool,
+        /// Only apply the update if the desired state is still at the given revision, see "ank get revisions"
+        /// Overrides a 'revision' given in the state object file
+        #[arg(long = "expected-revision", verbatim_doc_comment)]
+        expected_revision: Option<u64>,
+        /// Apply the update even if the desired state was changed in the meantime
+        #[arg(
+            long = "force",
+            default_value_t = false,
+            conflicts_with = "expected_revision"
+        )]
+        force: bool,
     },
 }
 
@@ -346,6 +443,51 @@ pub struct ApplyArgs {
     /// Delete mode activated
     #[arg(short)]
     pub delete_mode: bool,
+    /// Only show the workload changes the manifest(s) would cause without applying them
+    #[arg(long = "dry-run", default_value_t = false)]
+    pub dry_run: bool,
+    /// Only apply the manifest(s) if the desired state is still at the given revision, see "ank get revisions"
+    /// Overrides a 'revision' given in the manifest(s)
+    #[arg(long = "expected-revision", verbatim_doc_comment)]
+    pub expected_revision: Option<u64>,
+    /// Apply the manifest(s) even if the desired state was changed in the meantime
+    #[arg(
+        long = "force",
+        default_value_t = false,
+        conflicts_with = "expected_revision"
+    )]
+    pub force: bool,
+}
+
+/// Show the differences between Ankaios manifest(s) and the current desired state
+/// Exits with code 0 if there are no differences, 1 if differences exist and 2 on errors
+#[derive(clap::Args, Debug)]
+#[command(verbatim_doc_comment)]
+pub struct DiffArgs {
+    #[arg(value_name = "Ankaios manifest file(s) or '-' for stdin", value_hint = ValueHint::FilePath)]
+    pub manifest_files: Vec<String>,
+    /// Specify on which agent the Ankaios manifests would be applied.
+    /// If not specified, the agent(s) must be specified in the Ankaios manifest(s)
+    #[arg(long = "agent")]
+    pub agent_name: Option<String>,
+}
+
+/// Show detailed information about an object of the Ankaios system
+#[derive(clap::Args, Debug)]
+#[command(args_conflicts_with_subcommands = true)]
+pub struct DescribeArgs {
+    #[command(subcommand)]
+    pub command: Option<DescribeCommands>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum DescribeCommands {
+    /// Details of a workload including its rendered configuration and recent execution states
+    Workload {
+        /// The name of the workload to describe
+        #[arg(required = true, add = ArgValueCompleter::new(workload_completer))]
+        workload_name: String,
+    },
 }
 
 /// Fetch the logs of workloads
@@ -369,6 +511,63 @@ pub struct LogsArgs {
     /// Show logs before a specific TIMESTAMP in RFC3339 format
     #[arg(short = 'u', long = "until")]
     pub until: Option<String>,
+    /// Output the RFC3339 timestamp in front of the log line
+    #[arg(long = "timestamps", default_value_t = false)]
+    pub timestamps: bool,
+    /// Output only the log lines written to the standard output
+    #[arg(
+        long = "stdout-only",
+        default_value_t = false,
+        conflicts_with = "stderr_only"
+    )]
+    pub stdout_only: bool,
+    /// Output only the log lines written to the standard error
+    #[arg(long = "stderr-only", default_value_t = false)]
+    pub stderr_only: bool,
+}
+
+/// Roll back the desired state to a previous revision
+#[derive(clap::Args, Debug)]
+pub struct RollbackArgs {
+    /// The number of the revision to roll back to, see "ank get revisions"
+    #[arg(required = true)]
+    pub revision: u64,
+}
+
+/// Live view of the resource usage of the connected agents and their workloads
+/// For automation use "ank get state -o json agents workloadStates"
+#[derive(clap::Args, Debug)]
+#[command(verbatim_doc_comment)]
+pub struct TopArgs {
+    /// The refresh interval in seconds
+    #[arg(short = 'i', long = "interval", default_value_t = 2, value_parser = clap::value_parser!(u64).range(1..))]
+    pub interval_secs: u64,
+    /// Specify the sort order of the agents
+    #[arg(long = "sort", value_enum, default_value_t = TopSortKey::Name)]
+    pub sort_key: TopSortKey,
+    /// Output the current resource usage once and exit
+    #[arg(long = "once", default_value_t = false)]
+    pub once: bool,
+}
+
+/// Manage the contexts of the config file
+#[derive(clap::Args, Debug)]
+#[command(args_conflicts_with_subcommands = true)]
+pub struct ConfigArgs {
+    #[command(subcommand)]
+    pub command: Option<ConfigCommands>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ConfigCommands {
+    /// Set the current context used by the following commands
+    UseContext {
+        /// The name of the context as given in the config file
+        #[arg(required = true)]
+        context_name: String,
+    },
+    /// List the contexts of the config file and mark the current context
+    GetContexts,
 }
 
 fn parse_key_val<K, V>(s: &str) -> Result<(K, V), Box<dyn Error + Send + Sync + 'static>>
@@ -26,19 +26,29 @@ mod workload_table_row;
 use workload_table_row::WorkloadTableRow;
 mod agent_table_row;
 mod config_table_row;
+mod revision_table_row;
+mod top_table_row;
 mod wait_list_display;
 
 // CLI commands implemented in another files
 mod apply_manifests;
 mod delete_configs;
 mod delete_workloads;
+mod describe_workload;
+mod diff_manifests;
+mod dry_run;
 mod get_agents;
 mod get_configs;
+mod get_events;
+mod get_graph;
 mod get_logs;
+mod get_revisions;
 mod get_state;
 mod get_workloads;
+mod rollback;
 mod run_workload;
 mod set_state;
+mod top;
 
 use common::{
     communications_error::CommunicationMiddlewareError,
@@ -73,6 +83,8 @@ fn open_manifest(
 
 pub const DESIRED_STATE_CONFIGS: &str = "desiredState.configs";
 pub const DESIRED_STATE_WORKLOADS: &str = "desiredState.workloads";
+// The field of a state object file or a manifest with the revision of the desired state it is based on.
+const REVISION_FIELD: &str = "revision";
 
 pub fn get_input_sources(manifest_files: &[String]) -> Result<Vec<InputSourcePair>, String> {
     if let Some(first_arg) = manifest_files.first() {
@@ -200,31 +212,34 @@ impl CliCommands {
             .and_then(|desired_state| desired_state.workloads)
             .unwrap_or_default();
 
-        self.add_runtime_name_to_workload_infos(workload_infos, desired_state_workloads)
+        self.add_spec_details_to_workload_infos(workload_infos, desired_state_workloads)
     }
 
     // [impl->swdd~processes-complete-state-to-list-workloads~1]
-    fn add_runtime_name_to_workload_infos(
+    // [impl->swdd~cli-get-workloads-wide-output-shows-resource-limits~1]
+    fn add_spec_details_to_workload_infos(
         &self,
         mut workload_infos: WorkloadInfos,
         workloads: HashMap<String, FilteredWorkloadSpec>,
     ) -> WorkloadInfos {
         for (_, table_row) in workload_infos.get_mut() {
-            let runtime_name = workloads
+            let found_wl_spec = workloads
                 .iter()
                 .find(|&(wl_name, wl_spec)| {
                     *wl_name == table_row.name
-                        && wl_spec
-                            .agent
-                            .as_deref()
-                            .is_some_and(|x| x == table_row.agent)
+                        && wl_spec.runs_on_agent(&table_row.agent)
                         && wl_spec.runtime.as_ref().is_some()
                 })
-                // runtime is valid because the filter above has found one
-                .map(|(_, found_wl_spec)| found_wl_spec.runtime.as_ref().unwrap());
+                .map(|(_, found_wl_spec)| found_wl_spec);
 
-            if let Some(runtime) = runtime_name {
-                table_row.runtime.clone_from(runtime);
+            if let Some(found_wl_spec) = found_wl_spec {
+                // runtime is valid because the filter above has found one
+                table_row
+                    .runtime
+                    .clone_from(found_wl_spec.runtime.as_ref().unwrap());
+                if let Some(resources) = &found_wl_spec.resources {
+                    table_row.resources = resources.to_string();
+                }
             }
         }
         workload_infos
@@ -235,16 +250,28 @@ impl CliCommands {
         &mut self,
         new_state: CompleteState,
         update_mask: Vec<String>,
+        expected_revision: Option<u64>,
     ) -> Result<(), CliError> {
         /* to keep track of deleted not initially started workloads in the wait mode
         the current workloads before the update must be stored in an ordered map. Affects only user output.
-        The updated state is created directly, independent of fetching the current workloads. */
-        let current_workload_infos: BTreeMap<WorkloadInstanceName, WorkloadTableRow> =
-            self.get_workloads().await?.into_iter().collect();
+        The updated state is created directly, independent of fetching the current workloads.
+        Without the wait mode the current state is not needed. */
+        let mut current_workload_infos: BTreeMap<WorkloadInstanceName, WorkloadTableRow> =
+            BTreeMap::new();
+        if !self.no_wait {
+            let current_complete_state = self
+                .server_connection
+                .get_complete_state(&Vec::new())
+                .await?;
+            current_workload_infos = self
+                .transform_into_workload_infos(current_complete_state)
+                .into_iter()
+                .collect();
+        }
 
         let update_state_success = self
             .server_connection
-            .update_state(new_state, update_mask)
+            .update_state(new_state, update_mask, expected_revision)
             .await?;
 
         output_debug!("Got update success: {:?}", update_state_success);
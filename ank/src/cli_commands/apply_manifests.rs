@@ -12,7 +12,7 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
-use super::{CliCommands, InputSourcePair};
+use super::{CliCommands, InputSourcePair, REVISION_FIELD};
 use crate::cli_commands::State;
 use crate::cli_error::CliError;
 use crate::output;
@@ -58,7 +58,7 @@ pub fn parse_manifest(manifest: &mut InputSourcePair) -> Result<(Object, Vec<Pat
     Ok((obj, workload_paths.into_iter().collect()))
 }
 
-// [impl->swdd~cli-apply-ankaios-manifest-agent-name-overwrite~1]
+// [impl->swdd~cli-apply-ankaios-manifest-agent-name-overwrite~3]
 pub fn handle_agent_overwrite(
     filter_masks: &Vec<common::state_manipulation::Path>,
     cli_specified_agent_name: &Option<String>,
@@ -67,6 +67,11 @@ pub fn handle_agent_overwrite(
     for mask_path in filter_masks {
         if mask_path.parts().starts_with(&["workloads".into()]) {
             let workload_agent_mask: Path = format!("{}.agent", String::from(mask_path)).into();
+            let workload_agent_selector_mask: Path =
+                format!("{}.agentSelector", String::from(mask_path)).into();
+            let workload_resource_requests_mask: Path =
+                format!("{}.resourceRequests", String::from(mask_path)).into();
+            let workload_agents_mask: Path = format!("{}.agents", String::from(mask_path)).into();
             if let Some(agent_name) = cli_specified_agent_name {
                 // An agent name specified through cli -> do an agent name overwrite!
                 state_obj
@@ -75,9 +80,20 @@ pub fn handle_agent_overwrite(
                         serde_yaml::Value::String(agent_name.to_owned()),
                     )
                     .map_err(|_| "Could not find workload to update.".to_owned())?;
-            } else if state_obj.get(&workload_agent_mask).is_none() {
-                // No agent name specified through cli and inside workload configuration!
-                // [impl->swdd~cli-apply-ankaios-manifest-error-on-agent-name-absence~2]
+                for replaced_mask in [&workload_agent_selector_mask, &workload_agents_mask] {
+                    if state_obj.get(replaced_mask).is_some() {
+                        state_obj
+                            .remove(replaced_mask)
+                            .map_err(|_| "Could not find workload to update.".to_owned())?;
+                    }
+                }
+            } else if state_obj.get(&workload_agent_mask).is_none()
+                && state_obj.get(&workload_agents_mask).is_none()
+                && state_obj.get(&workload_agent_selector_mask).is_none()
+                && state_obj.get(&workload_resource_requests_mask).is_none()
+            {
+                // No agent name, agents, agent selector or resource requests specified through cli and inside workload configuration!
+                // [impl->swdd~cli-apply-ankaios-manifest-error-on-agent-name-absence~5]
                 return Err(
                     "No agent name specified -> use '--agent' option to specify!".to_owned(),
                 );
@@ -127,17 +143,42 @@ pub fn create_filter_masks_from_paths(
     filter_masks
 }
 
+// The state object, the filter masks and the revision the manifests are based on
+type ManifestsRequest = (CompleteState, Vec<String>, Option<u64>);
+
+// [impl->swdd~cli-sends-expected-revision-with-update-state~2]
+fn update_manifest_revision(revision: &mut Option<u64>, cur_obj: &Object) -> Result<(), String> {
+    let Some(manifest_revision) = cur_obj
+        .get(&REVISION_FIELD.into())
+        .and_then(serde_yaml::Value::as_u64)
+    else {
+        return Ok(());
+    };
+    match revision {
+        Some(revision) if *revision != manifest_revision => Err(format!(
+            "The manifests are based on different revisions '{revision}' and '{manifest_revision}'."
+        )),
+        _ => {
+            *revision = Some(manifest_revision);
+            Ok(())
+        }
+    }
+}
+
 // [impl->swdd~cli-apply-generates-state-object-from-ankaios-manifests~1]
 // [impl->swdd~cli-apply-generates-filter-masks-from-ankaios-manifests~1]
 pub fn generate_state_obj_and_filter_masks_from_manifests(
     manifests: &mut [InputSourcePair],
     apply_args: &ApplyArgs,
-) -> Result<Option<(CompleteState, Vec<String>)>, String> {
+) -> Result<Option<ManifestsRequest>, String> {
     let mut req_obj: Object = State::default().try_into().unwrap();
     let mut req_paths: Vec<common::state_manipulation::Path> = Vec::new();
+    let mut revision = None;
     for manifest in manifests.iter_mut() {
         let (cur_obj, mut cur_workload_paths) = parse_manifest(manifest)?;
 
+        update_manifest_revision(&mut revision, &cur_obj)?;
+
         update_request_obj(&mut req_obj, &cur_obj, &cur_workload_paths)?;
 
         req_paths.append(&mut cur_workload_paths);
@@ -165,7 +206,7 @@ pub fn generate_state_obj_and_filter_masks_from_manifests(
     };
     output_debug!("\nstate_obj:\n{:?}\n", complete_state_req_obj);
 
-    Ok(Some((complete_state_req_obj, filter_masks)))
+    Ok(Some((complete_state_req_obj, filter_masks, revision)))
 }
 
 impl CliCommands {
@@ -173,13 +214,29 @@ impl CliCommands {
     pub async fn apply_manifests(&mut self, apply_args: ApplyArgs) -> Result<(), CliError> {
         match get_input_sources(&apply_args.manifest_files) {
             Ok(mut manifests) => {
-                if let Some((complete_state_req_obj, filter_masks)) =
+                if let Some((complete_state_req_obj, filter_masks, manifest_revision)) =
                     generate_state_obj_and_filter_masks_from_manifests(&mut manifests, &apply_args)
                         .map_err(CliError::ExecutionError)?
                 {
+                    if apply_args.dry_run {
+                        // [impl->swdd~cli-requests-dry-run-update-state~1]
+                        return self
+                            .dry_run_update_state(complete_state_req_obj, filter_masks)
+                            .await;
+                    }
+                    // [impl->swdd~cli-sends-expected-revision-with-update-state~2]
+                    let expected_revision = if apply_args.force {
+                        None
+                    } else {
+                        apply_args.expected_revision.or(manifest_revision)
+                    };
                     // [impl->swdd~cli-apply-send-update-state~1]
-                    self.update_state_and_wait_for_complete(complete_state_req_obj, filter_masks)
-                        .await
+                    self.update_state_and_wait_for_complete(
+                        complete_state_req_obj,
+                        filter_masks,
+                        expected_revision,
+                    )
+                    .await
                 } else {
                     output!("Nothing to update.");
                     Ok(())
@@ -203,14 +260,14 @@ mod tests {
     use std::io::Read;
 
     use api::ank_base::{self, UpdateStateSuccess};
-    use mockall::predicate::eq;
+    use mockall::predicate::{always, eq};
 
     use common::{
         commands::UpdateWorkloadState,
         from_server_interface::FromServer,
         objects::{
-            self, CompleteState, ExecutionState, RunningSubstate, State, WorkloadState,
-            generate_test_workload_spec_with_param,
+            self, CompleteState, ExecutionState, ResourceRequests, RunningSubstate, State,
+            WorkloadState, generate_test_workload_spec_with_param,
         },
         state_manipulation::{Object, Path},
         test_utils,
@@ -375,7 +432,7 @@ mod tests {
         );
     }
 
-    // [utest->swdd~cli-apply-ankaios-manifest-agent-name-overwrite~1]
+    // [utest->swdd~cli-apply-ankaios-manifest-agent-name-overwrite~3]
     #[test]
     fn utest_handle_agent_overwrite_agent_name_provided_through_agent_flag() {
         let state = test_utils::generate_test_state_from_workloads(vec![
@@ -405,7 +462,7 @@ mod tests {
         );
     }
 
-    // [utest->swdd~cli-apply-ankaios-manifest-agent-name-overwrite~1]
+    // [utest->swdd~cli-apply-ankaios-manifest-agent-name-overwrite~3]
     #[test]
     fn utest_handle_agent_overwrite_one_agent_name_provided_in_workload_specs() {
         let state = test_utils::generate_test_state_from_workloads(vec![
@@ -427,7 +484,7 @@ mod tests {
         );
     }
 
-    // [utest->swdd~cli-apply-ankaios-manifest-agent-name-overwrite~1]
+    // [utest->swdd~cli-apply-ankaios-manifest-agent-name-overwrite~3]
     #[test]
     fn utest_handle_agent_overwrite_multiple_agent_names_provided_in_workload_specs() {
         let state = test_utils::generate_test_state_from_workloads(vec![
@@ -454,8 +511,8 @@ mod tests {
         );
     }
 
-    // [utest->swdd~cli-apply-ankaios-manifest-error-on-agent-name-absence~2]
-    // [utest->swdd~cli-apply-ankaios-manifest-agent-name-overwrite~1]
+    // [utest->swdd~cli-apply-ankaios-manifest-error-on-agent-name-absence~5]
+    // [utest->swdd~cli-apply-ankaios-manifest-agent-name-overwrite~3]
     #[test]
     fn utest_handle_agent_overwrite_no_agent_name_provided_at_all() {
         let state = test_utils::generate_test_state_from_workloads(vec![
@@ -476,7 +533,150 @@ mod tests {
         );
     }
 
-    // [utest->swdd~cli-apply-ankaios-manifest-agent-name-overwrite~1]
+    // [utest->swdd~cli-apply-ankaios-manifest-error-on-agent-name-absence~5]
+    #[test]
+    fn utest_handle_agent_overwrite_agent_selector_provided_instead_of_agent_name() {
+        let state = test_utils::generate_test_state_from_workloads(vec![
+            generate_test_workload_spec_with_param(
+                "agent_A".to_string(),
+                "wl1".to_string(),
+                "runtime_X".to_string(),
+            ),
+        ]);
+
+        let mut obj: Object = state.try_into().unwrap();
+
+        obj.remove(&"workloads.wl1.agent".into()).unwrap();
+        obj.set(
+            &"workloads.wl1.agentSelector".into(),
+            serde_yaml::from_str("arch: arm64").unwrap(),
+        )
+        .unwrap();
+
+        let result_state =
+            handle_agent_overwrite(&vec!["workloads.wl1".into()], &None, obj).unwrap();
+        let workload = result_state.workloads.get("wl1").unwrap();
+        assert!(workload.agent.is_empty());
+        assert_eq!(
+            workload.agent_selector,
+            [("arch".to_string(), "arm64".to_string())].into()
+        );
+    }
+
+    // [utest->swdd~cli-apply-ankaios-manifest-error-on-agent-name-absence~5]
+    #[test]
+    fn utest_handle_agent_overwrite_resource_requests_provided_instead_of_agent_name() {
+        let state = test_utils::generate_test_state_from_workloads(vec![
+            generate_test_workload_spec_with_param(
+                "agent_A".to_string(),
+                "wl1".to_string(),
+                "runtime_X".to_string(),
+            ),
+        ]);
+
+        let mut obj: Object = state.try_into().unwrap();
+
+        obj.remove(&"workloads.wl1.agent".into()).unwrap();
+        obj.set(
+            &"workloads.wl1.resourceRequests".into(),
+            serde_yaml::from_str("cpu: 20\nmemory: 1024").unwrap(),
+        )
+        .unwrap();
+
+        let result_state =
+            handle_agent_overwrite(&vec!["workloads.wl1".into()], &None, obj).unwrap();
+        let workload = result_state.workloads.get("wl1").unwrap();
+        assert!(workload.agent.is_empty());
+        assert_eq!(
+            workload.resource_requests,
+            Some(ResourceRequests {
+                cpu: 20,
+                memory: 1024
+            })
+        );
+    }
+
+    // [utest->swdd~cli-apply-ankaios-manifest-agent-name-overwrite~3]
+    #[test]
+    fn utest_handle_agent_overwrite_replaces_agent_selector() {
+        let state = test_utils::generate_test_state_from_workloads(vec![
+            generate_test_workload_spec_with_param(
+                "agent_A".to_string(),
+                "wl1".to_string(),
+                "runtime_X".to_string(),
+            ),
+        ]);
+
+        let expected_state = test_utils::generate_test_state_from_workloads(vec![
+            generate_test_workload_spec_with_param(
+                "overwritten_agent_name".to_string(),
+                "wl1".to_string(),
+                "runtime_X".to_string(),
+            ),
+        ]);
+
+        let mut obj: Object = state.try_into().unwrap();
+
+        obj.remove(&"workloads.wl1.agent".into()).unwrap();
+        obj.set(
+            &"workloads.wl1.agentSelector".into(),
+            serde_yaml::from_str("arch: arm64").unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            handle_agent_overwrite(
+                &vec!["workloads.wl1".into()],
+                &Some("overwritten_agent_name".to_string()),
+                obj,
+            )
+            .unwrap(),
+            expected_state
+        );
+    }
+
+    // [utest->swdd~cli-apply-ankaios-manifest-error-on-agent-name-absence~5]
+    // [utest->swdd~cli-apply-ankaios-manifest-agent-name-overwrite~3]
+    #[test]
+    fn utest_handle_agent_overwrite_agents_provided_instead_of_agent_name() {
+        let state = test_utils::generate_test_state_from_workloads(vec![
+            generate_test_workload_spec_with_param(
+                "agent_A".to_string(),
+                "wl1".to_string(),
+                "runtime_X".to_string(),
+            ),
+        ]);
+
+        let mut obj: Object = state.try_into().unwrap();
+
+        obj.remove(&"workloads.wl1.agent".into()).unwrap();
+        obj.set(
+            &"workloads.wl1.agents".into(),
+            serde_yaml::from_str("[agent_A, agent_B]").unwrap(),
+        )
+        .unwrap();
+
+        let result_state =
+            handle_agent_overwrite(&vec!["workloads.wl1".into()], &None, obj.clone()).unwrap();
+        let workload = result_state.workloads.get("wl1").unwrap();
+        assert!(workload.agent.is_empty());
+        assert_eq!(
+            workload.agents,
+            vec!["agent_A".to_string(), "agent_B".to_string()]
+        );
+
+        let result_state = handle_agent_overwrite(
+            &vec!["workloads.wl1".into()],
+            &Some("overwritten_agent_name".to_string()),
+            obj,
+        )
+        .unwrap();
+        let workload = result_state.workloads.get("wl1").unwrap();
+        assert_eq!(workload.agent, "overwritten_agent_name");
+        assert!(workload.agents.is_empty());
+    }
+
+    // [utest->swdd~cli-apply-ankaios-manifest-agent-name-overwrite~3]
     #[test]
     fn utest_handle_agent_overwrite_missing_agent_name() {
         let state = test_utils::generate_test_state_from_workloads(vec![
@@ -510,7 +710,7 @@ mod tests {
         );
     }
 
-    // [utest->swdd~cli-apply-ankaios-manifest-agent-name-overwrite~1]
+    // [utest->swdd~cli-apply-ankaios-manifest-agent-name-overwrite~3]
     #[test]
     fn utest_handle_agent_overwrite_considers_only_workloads() {
         let state = test_utils::generate_test_state_from_workloads(vec![
@@ -553,7 +753,9 @@ mod tests {
           runtime: podman
           agent: agent_A
           restartPolicy: ALWAYS
-          updateStrategy: AT_MOST_ONCE
+          updateStrategy:
+            rolling:
+              maxUnavailable: 1
           accessRights:
             allow: []
             deny: []
@@ -578,13 +780,20 @@ mod tests {
             vec![(manifest_file_name.to_string(), Box::new(manifest_content))];
 
         assert_eq!(
-            Ok(Some((expected_complete_state_obj, expected_filter_masks))),
+            Ok(Some((
+                expected_complete_state_obj,
+                expected_filter_masks,
+                None
+            ))),
             generate_state_obj_and_filter_masks_from_manifests(
                 &mut manifests[..],
                 &ApplyArgs {
                     agent_name: None,
                     manifest_files: vec![manifest_file_name.to_string()],
                     delete_mode: false,
+                    dry_run: false,
+                    expected_revision: None,
+                    force: false,
                 },
             )
         );
@@ -615,13 +824,20 @@ mod tests {
             vec![(manifest_file_name.to_string(), Box::new(manifest_content))];
 
         assert_eq!(
-            Ok(Some((expected_complete_state_obj, expected_filter_masks))),
+            Ok(Some((
+                expected_complete_state_obj,
+                expected_filter_masks,
+                None
+            ))),
             generate_state_obj_and_filter_masks_from_manifests(
                 &mut manifests[..],
                 &ApplyArgs {
                     agent_name: None,
                     manifest_files: vec![manifest_file_name.to_string()],
                     delete_mode: true,
+                    dry_run: false,
+                    expected_revision: None,
+                    force: false,
                 },
             )
         );
@@ -658,8 +874,9 @@ mod tests {
             .with(
                 eq(updated_state.clone()),
                 eq(vec!["desiredState.workloads.simple_manifest1".to_string()]),
+                eq(None),
             )
-            .return_once(|_, _| {
+            .return_once(|_, _, _| {
                 Ok(UpdateStateSuccess {
                     added_workloads: vec![],
                     deleted_workloads: vec!["name4.abc.agent_B".to_string()],
@@ -709,6 +926,9 @@ mod tests {
                 agent_name: None,
                 delete_mode: true,
                 manifest_files: vec!["manifest_yaml".to_string()],
+                dry_run: false,
+                expected_revision: None,
+                force: false,
             })
             .await;
         assert!(apply_result.is_ok());
@@ -745,8 +965,9 @@ mod tests {
             .with(
                 eq(updated_state.clone()),
                 eq(vec!["desiredState.workloads.simple_manifest1".to_string()]),
+                eq(None),
             )
-            .return_once(|_, _| {
+            .return_once(|_, _, _| {
                 Ok(UpdateStateSuccess {
                     added_workloads: vec!["simple_manifest1.abc.agent_B".to_string()],
                     deleted_workloads: vec![],
@@ -821,6 +1042,9 @@ mod tests {
                 agent_name: None,
                 delete_mode: false,
                 manifest_files: vec!["manifest_yaml".to_string()],
+                dry_run: false,
+                expected_revision: None,
+                force: false,
             })
             .await;
         assert!(apply_result.is_ok());
@@ -853,8 +1077,9 @@ mod tests {
             .with(
                 eq(updated_state.clone()),
                 eq(vec!["desiredState.configs.config_1".to_string()]),
+                eq(None),
             )
-            .return_once(|_, _| Ok(UpdateStateSuccess::default()));
+            .return_once(|_, _, _| Ok(UpdateStateSuccess::default()));
 
         mock_server_connection
             .expect_get_complete_state()
@@ -887,6 +1112,316 @@ mod tests {
                 agent_name: None,
                 delete_mode: false,
                 manifest_files: vec!["manifest_yaml".to_string()],
+                dry_run: false,
+                expected_revision: None,
+                force: false,
+            })
+            .await;
+        assert!(apply_result.is_ok());
+    }
+
+    // [utest->swdd~cli-sends-expected-revision-with-update-state~2]
+    #[tokio::test]
+    async fn utest_apply_manifests_sends_no_revision_by_default() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let manifest_content = io::Cursor::new(
+            b"apiVersion: \"v0.1\"\nworkloads: {}\nconfigs:\n  config_1: config_value_1",
+        );
+
+        let mut manifest_data = String::new();
+        let _ = manifest_content.clone().read_to_string(&mut manifest_data);
+
+        let updated_state = CompleteState {
+            desired_state: serde_yaml::from_str(&manifest_data).unwrap(),
+            ..Default::default()
+        };
+
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_update_state()
+            .with(
+                eq(updated_state.clone()),
+                eq(vec!["desiredState.configs.config_1".to_string()]),
+                eq(None),
+            )
+            .return_once(|_, _, _| Ok(UpdateStateSuccess::default()));
+        mock_server_connection.expect_get_complete_state().never();
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: true,
+            server_connection: mock_server_connection,
+        };
+
+        FAKE_GET_INPUT_SOURCE_MOCK_RESULT_LIST
+            .lock()
+            .unwrap()
+            .push_back(Ok(vec![(
+                "manifest.yml".to_string(),
+                Box::new(manifest_content),
+            )]));
+
+        let apply_result = cmd
+            .apply_manifests(ApplyArgs {
+                agent_name: None,
+                delete_mode: false,
+                manifest_files: vec!["manifest_yaml".to_string()],
+                dry_run: false,
+                expected_revision: None,
+                force: false,
+            })
+            .await;
+        assert!(apply_result.is_ok());
+    }
+
+    // [utest->swdd~cli-sends-expected-revision-with-update-state~2]
+    #[tokio::test]
+    async fn utest_apply_manifests_sends_given_expected_revision() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        const EXPECTED_REVISION: u64 = 4;
+
+        let manifest_content = io::Cursor::new(
+            b"apiVersion: \"v0.1\"\nworkloads: {}\nconfigs:\n  config_1: config_value_1",
+        );
+
+        let mut manifest_data = String::new();
+        let _ = manifest_content.clone().read_to_string(&mut manifest_data);
+
+        let updated_state = CompleteState {
+            desired_state: serde_yaml::from_str(&manifest_data).unwrap(),
+            ..Default::default()
+        };
+
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_update_state()
+            .with(
+                eq(updated_state.clone()),
+                eq(vec!["desiredState.configs.config_1".to_string()]),
+                eq(Some(EXPECTED_REVISION)),
+            )
+            .return_once(|_, _, _| Ok(UpdateStateSuccess::default()));
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: true,
+            server_connection: mock_server_connection,
+        };
+
+        FAKE_GET_INPUT_SOURCE_MOCK_RESULT_LIST
+            .lock()
+            .unwrap()
+            .push_back(Ok(vec![(
+                "manifest.yml".to_string(),
+                Box::new(manifest_content),
+            )]));
+
+        let apply_result = cmd
+            .apply_manifests(ApplyArgs {
+                agent_name: None,
+                delete_mode: false,
+                manifest_files: vec!["manifest_yaml".to_string()],
+                dry_run: false,
+                expected_revision: Some(EXPECTED_REVISION),
+                force: false,
+            })
+            .await;
+        assert!(apply_result.is_ok());
+    }
+
+    // [utest->swdd~cli-sends-expected-revision-with-update-state~2]
+    #[tokio::test]
+    async fn utest_apply_manifests_sends_revision_from_manifest() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        const MANIFEST_REVISION: u64 = 4;
+
+        let manifest_content = io::Cursor::new(
+            b"apiVersion: \"v0.1\"\nrevision: 4\nconfigs:\n  config_1: config_value_1",
+        );
+
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_update_state()
+            .with(
+                always(),
+                eq(vec!["desiredState.configs.config_1".to_string()]),
+                eq(Some(MANIFEST_REVISION)),
+            )
+            .return_once(|_, _, _| Ok(UpdateStateSuccess::default()));
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: true,
+            server_connection: mock_server_connection,
+        };
+
+        FAKE_GET_INPUT_SOURCE_MOCK_RESULT_LIST
+            .lock()
+            .unwrap()
+            .push_back(Ok(vec![(
+                "manifest.yml".to_string(),
+                Box::new(manifest_content),
+            )]));
+
+        let apply_result = cmd
+            .apply_manifests(ApplyArgs {
+                agent_name: None,
+                delete_mode: false,
+                manifest_files: vec!["manifest_yaml".to_string()],
+                dry_run: false,
+                expected_revision: None,
+                force: false,
+            })
+            .await;
+        assert!(apply_result.is_ok());
+    }
+
+    // [utest->swdd~cli-sends-expected-revision-with-update-state~2]
+    #[test]
+    fn utest_generate_state_obj_and_filter_masks_from_manifests_with_different_revisions() {
+        let mut manifests: Vec<InputSourcePair> = vec![
+            (
+                "manifest_1.yaml".to_string(),
+                Box::new(io::Cursor::new(
+                    b"apiVersion: \"v0.1\"\nrevision: 4\nconfigs:\n  config_1: value_1",
+                )),
+            ),
+            (
+                "manifest_2.yaml".to_string(),
+                Box::new(io::Cursor::new(
+                    b"apiVersion: \"v0.1\"\nrevision: 5\nconfigs:\n  config_2: value_2",
+                )),
+            ),
+        ];
+
+        assert!(
+            generate_state_obj_and_filter_masks_from_manifests(
+                &mut manifests[..],
+                &ApplyArgs {
+                    agent_name: None,
+                    manifest_files: vec![
+                        "manifest_1.yaml".to_string(),
+                        "manifest_2.yaml".to_string()
+                    ],
+                    delete_mode: false,
+                    dry_run: false,
+                    expected_revision: None,
+                    force: false,
+                },
+            )
+            .is_err()
+        );
+    }
+
+    // [utest->swdd~cli-sends-expected-revision-with-update-state~2]
+    #[tokio::test]
+    async fn utest_apply_manifests_force_sends_no_revision() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let manifest_content = io::Cursor::new(
+            b"apiVersion: \"v0.1\"\nrevision: 4\nconfigs:\n  config_1: config_value_1",
+        );
+
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_update_state()
+            .with(
+                always(),
+                eq(vec!["desiredState.configs.config_1".to_string()]),
+                eq(None),
+            )
+            .return_once(|_, _, _| Ok(UpdateStateSuccess::default()));
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: true,
+            server_connection: mock_server_connection,
+        };
+
+        FAKE_GET_INPUT_SOURCE_MOCK_RESULT_LIST
+            .lock()
+            .unwrap()
+            .push_back(Ok(vec![(
+                "manifest.yml".to_string(),
+                Box::new(manifest_content),
+            )]));
+
+        let apply_result = cmd
+            .apply_manifests(ApplyArgs {
+                agent_name: None,
+                delete_mode: false,
+                manifest_files: vec!["manifest_yaml".to_string()],
+                dry_run: false,
+                expected_revision: None,
+                force: true,
+            })
+            .await;
+        assert!(apply_result.is_ok());
+    }
+
+    // [utest->swdd~cli-requests-dry-run-update-state~1]
+    #[tokio::test]
+    async fn utest_apply_manifests_dry_run_does_not_update_state() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let manifest_content = io::Cursor::new(
+            b"apiVersion: \"v0.1\"\nworkloads: {}\nconfigs:\n  config_1: config_value_1",
+        );
+
+        let mut manifest_data = String::new();
+        let _ = manifest_content.clone().read_to_string(&mut manifest_data);
+
+        let updated_state = CompleteState {
+            desired_state: serde_yaml::from_str(&manifest_data).unwrap(),
+            ..Default::default()
+        };
+
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_dry_run_update_state()
+            .with(
+                eq(updated_state.clone()),
+                eq(vec!["desiredState.configs.config_1".to_string()]),
+            )
+            .return_once(|_, _| Ok(UpdateStateSuccess::default()));
+        mock_server_connection.expect_update_state().never();
+        mock_server_connection.expect_get_complete_state().never();
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        FAKE_GET_INPUT_SOURCE_MOCK_RESULT_LIST
+            .lock()
+            .unwrap()
+            .push_back(Ok(vec![(
+                "manifest.yml".to_string(),
+                Box::new(manifest_content),
+            )]));
+
+        let apply_result = cmd
+            .apply_manifests(ApplyArgs {
+                agent_name: None,
+                delete_mode: false,
+                manifest_files: vec!["manifest_yaml".to_string()],
+                dry_run: true,
+                expected_revision: None,
+                force: false,
             })
             .await;
         assert!(apply_result.is_ok());
@@ -934,6 +1469,9 @@ mod tests {
                 agent_name: None,
                 delete_mode: false,
                 manifest_files: vec!["manifest_yaml".to_string()],
+                dry_run: false,
+                expected_revision: None,
+                force: false,
             })
             .await;
         assert!(apply_result.is_ok());
@@ -968,8 +1506,9 @@ mod tests {
             .with(
                 eq(updated_state.clone()),
                 eq(vec!["desiredState.workloads.simple.manifest1".to_string()]),
+                eq(None),
             )
-            .return_once(|_, _| {
+            .return_once(|_, _, _| {
                 Ok(UpdateStateSuccess {
                     added_workloads: vec!["simple_manifest1.abc.agent_B".to_string()],
                     deleted_workloads: vec![],
@@ -1039,6 +1578,9 @@ mod tests {
                 agent_name: None,
                 delete_mode: false,
                 manifest_files: vec!["manifest_yaml".to_string()],
+                dry_run: false,
+                expected_revision: None,
+                force: false,
             })
             .await;
         assert!(apply_result.is_err());
@@ -65,15 +65,10 @@ where
 
         const NEWLINE: &str = "\n";
         let terminal_width = terminal_width();
-        let truncated_lines: Vec<&str> = default_table
+        // truncate on character boundaries as the table can contain multi-byte characters
+        let truncated_lines: Vec<String> = default_table
             .lines()
-            .map(|line| {
-                if line.len() > terminal_width {
-                    &line[..terminal_width]
-                } else {
-                    line
-                }
-            })
+            .map(|line| line.chars().take(terminal_width).collect())
             .collect();
         truncated_lines.join(NEWLINE)
     }
@@ -249,6 +244,26 @@ mod tests {
         assert_eq!(table_output, expected_table_output);
     }
 
+    // [utest->swdd~cli-table-provides-default-table-output~2]
+    #[test]
+    fn utest_create_default_table_truncates_multi_byte_characters() {
+        let table_rows = [TestRow {
+            col1: "a".to_string(),
+            col2: "b".to_string(),
+            col3: "█".repeat(100),
+        }];
+
+        let table = CliTable::new(&table_rows);
+        let table_output = table.create_default_table();
+        let expected_table_output = [
+            format!("COLUMN 1   COL2   ANOTHER COLUMN3{}", " ".repeat(47)),
+            format!("a          b      {}", "█".repeat(62)),
+        ]
+        .join("\n");
+
+        assert_eq!(table_output, expected_table_output);
+    }
+
     // [utest->swdd~cli-table-provides-default-table-output~2]
     #[test]
     fn utest_create_default_table_line_length_equal_to_terminal_length() {
@@ -0,0 +1,518 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use common::helpers::format_unix_timestamp;
+use common::objects::{ConfigItem, File, FileContent, WorkloadState};
+
+use super::CliCommands;
+use crate::cli_error::CliError;
+use crate::filtered_complete_state::{
+    FilteredCompleteState, FilteredExecutionStateTransition, FilteredRenderedWorkload,
+    FilteredWorkloadSpec,
+};
+use crate::output_debug;
+
+const NONE: &str = "<none>";
+const INDENT: &str = "  ";
+const LABEL_WIDTH: usize = 16;
+
+impl CliCommands {
+    // [impl->swdd~cli-provides-describe-workload~1]
+    pub async fn describe_workload(&mut self, workload_name: String) -> Result<String, CliError> {
+        let complete_state = self
+            .server_connection
+            .get_complete_state(&describe_workload_field_mask(&workload_name))
+            .await?;
+
+        output_debug!("Got complete state for describe: {:?}", complete_state);
+
+        describe_workload(&workload_name, complete_state)
+    }
+}
+
+fn describe_workload_field_mask(workload_name: &str) -> Vec<String> {
+    vec![
+        format!("desiredState.workloads.{workload_name}"),
+        "desiredState.configs".to_string(),
+        "workloadStates".to_string(),
+        format!("renderedWorkloads.{workload_name}"),
+        format!("workloadStateHistory.{workload_name}"),
+    ]
+}
+
+// [impl->swdd~cli-describe-workload-shows-details~1]
+fn describe_workload(
+    workload_name: &str,
+    complete_state: FilteredCompleteState,
+) -> Result<String, CliError> {
+    let (workload, configs) = complete_state
+        .desired_state
+        .and_then(|desired_state| {
+            let workload = desired_state.workloads?.remove(workload_name)?;
+            Some((workload, desired_state.configs.unwrap_or_default()))
+        })
+        .ok_or_else(|| {
+            CliError::ExecutionError(format!("Workload '{workload_name}' does not exist"))
+        })?;
+
+    let mut workload_states: Vec<WorkloadState> = complete_state
+        .workload_states
+        .map(Into::into)
+        .unwrap_or_default();
+    workload_states.sort_by(|left, right| left.instance_name.cmp(&right.instance_name));
+
+    let mut rendered_workloads = complete_state
+        .rendered_workloads
+        .and_then(|mut rendered_workloads| rendered_workloads.remove(workload_name))
+        .unwrap_or_default();
+    rendered_workloads.sort_by(|left, right| left.agent.cmp(&right.agent));
+
+    let transitions = complete_state
+        .workload_state_history
+        .and_then(|mut history| history.remove(workload_name))
+        .unwrap_or_default();
+
+    let mut lines = vec![
+        field("Name", workload_name),
+        field("Agent", &format_agents(&workload)),
+        field("Runtime", workload.runtime.as_deref().unwrap_or(NONE)),
+        field(
+            "Restart Policy",
+            &workload
+                .restart_policy
+                .as_ref()
+                .map_or(NONE.to_string(), ToString::to_string),
+        ),
+        field("Tags", &format_tags(&workload)),
+    ];
+
+    lines.extend(section(
+        "Runtime Config",
+        workload
+            .runtime_config
+            .as_deref()
+            .map(indent)
+            .unwrap_or_default(),
+    ));
+    lines.extend(section(
+        "Rendered Replicas",
+        format_rendered_workloads(&rendered_workloads),
+    ));
+    lines.extend(section("Configs", format_configs(&workload, &configs)?));
+    lines.extend(section(
+        "Dependencies",
+        format_dependencies(&workload, &workload_states)?,
+    ));
+    lines.extend(section(
+        "Control Interface Access",
+        format_control_interface_access(&workload)?,
+    ));
+    lines.extend(section(
+        "Execution States",
+        workload_states
+            .iter()
+            .filter(|state| state.instance_name.workload_name() == workload_name)
+            .map(|state| {
+                format!(
+                    "{INDENT}{}: {}",
+                    state.instance_name.agent_name(),
+                    state.execution_state
+                )
+            })
+            .collect(),
+    ));
+    lines.extend(section(
+        "Recent State Transitions",
+        format_transitions(&transitions),
+    ));
+
+    Ok(lines.join("\n"))
+}
+
+fn field(label: &str, value: &str) -> String {
+    format!("{:<LABEL_WIDTH$} {value}", format!("{label}:"))
+}
+
+fn section(title: &str, content: Vec<String>) -> Vec<String> {
+    if content.is_empty() {
+        return vec![field(title, NONE)];
+    }
+    std::iter::once(format!("{title}:"))
+        .chain(content)
+        .collect()
+}
+
+fn indent(text: &str) -> Vec<String> {
+    text.lines().map(|line| format!("{INDENT}{line}")).collect()
+}
+
+fn indent_all(lines: Vec<String>) -> Vec<String> {
+    lines
+        .into_iter()
+        .map(|line| format!("{INDENT}{line}"))
+        .collect()
+}
+
+fn format_key_values(key_values: &HashMap<String, String>) -> String {
+    let mut key_values: Vec<String> = key_values
+        .iter()
+        .map(|(key, value)| format!("{key}={value}"))
+        .collect();
+    key_values.sort();
+    key_values.join(", ")
+}
+
+pub fn format_agents(workload: &FilteredWorkloadSpec) -> String {
+    match (&workload.agent, &workload.agents, &workload.agent_selector) {
+        (Some(agent), _, _) if !agent.is_empty() => agent.clone(),
+        (_, Some(agents), _) if !agents.is_empty() => agents.join(", "),
+        (_, _, Some(agent_selector)) => {
+            format!("selected by {}", format_key_values(agent_selector))
+        }
+        _ => NONE.to_string(),
+    }
+}
+
+fn format_tags(workload: &FilteredWorkloadSpec) -> String {
+    match &workload.tags {
+        Some(tags) if !tags.is_empty() => tags
+            .iter()
+            .map(|tag| format!("{}={}", tag.key, tag.value))
+            .collect::<Vec<String>>()
+            .join(", "),
+        _ => NONE.to_string(),
+    }
+}
+
+// [impl->swdd~cli-describe-workload-shows-rendered-replicas~1]
+fn format_rendered_workloads(rendered_workloads: &[FilteredRenderedWorkload]) -> Vec<String> {
+    rendered_workloads
+        .iter()
+        .flat_map(|rendered_workload| {
+            let mut replica_lines =
+                section("Runtime Config", indent(&rendered_workload.runtime_config));
+            replica_lines.extend(section("Files", format_files(&rendered_workload.files)));
+            std::iter::once(format!("{INDENT}{}:", rendered_workload.agent))
+                .chain(indent_all(indent_all(replica_lines)))
+        })
+        .collect()
+}
+
+fn format_files(files: &[File]) -> Vec<String> {
+    files
+        .iter()
+        .flat_map(|file| match &file.file_content {
+            FileContent::Data(data) => std::iter::once(format!("{INDENT}{}:", file.mount_point))
+                .chain(indent_all(indent(&data.data)))
+                .collect::<Vec<String>>(),
+            FileContent::BinaryData(_) => {
+                vec![format!("{INDENT}{}: <binary data>", file.mount_point)]
+            }
+        })
+        .collect()
+}
+
+// The configs of a workload are given as alias to config key, the value of the config is
+// taken from the desired state to show what the workload is rendered with.
+fn format_configs(
+    workload: &FilteredWorkloadSpec,
+    configs: &HashMap<String, ConfigItem>,
+) -> Result<Vec<String>, CliError> {
+    let mut workload_configs: Vec<(&String, &String)> = workload.configs.iter().flatten().collect();
+    workload_configs.sort();
+
+    let mut lines = Vec::new();
+    for (alias, config_key) in workload_configs {
+        match configs.get(config_key) {
+            Some(config_item) => {
+                lines.push(format!("{INDENT}{alias} -> {config_key}:"));
+                lines.extend(indent_all(indent(&serde_yaml::to_string(config_item)?)));
+            }
+            None => lines.push(format!("{INDENT}{alias} -> {config_key}: <not found>")),
+        }
+    }
+    Ok(lines)
+}
+
+fn format_dependencies(
+    workload: &FilteredWorkloadSpec,
+    workload_states: &[WorkloadState],
+) -> Result<Vec<String>, CliError> {
+    let mut dependencies: Vec<_> = workload.dependencies.iter().flatten().collect();
+    dependencies.sort_by(|left, right| left.0.cmp(right.0));
+
+    let mut lines = Vec::new();
+    for (dependency_name, add_condition) in dependencies {
+        let dependency_states: Vec<String> = workload_states
+            .iter()
+            .filter(|state| state.instance_name.workload_name() == dependency_name.as_str())
+            .map(|state| {
+                format!(
+                    "{} on {}",
+                    state.execution_state,
+                    state.instance_name.agent_name()
+                )
+            })
+            .collect();
+        let dependency_states = if dependency_states.is_empty() {
+            NONE.to_string()
+        } else {
+            dependency_states.join(", ")
+        };
+        lines.push(format!(
+            "{INDENT}{dependency_name} ({}): {dependency_states}",
+            serde_yaml::to_string(add_condition)?.trim_end()
+        ));
+    }
+    Ok(lines)
+}
+
+fn format_control_interface_access(
+    workload: &FilteredWorkloadSpec,
+) -> Result<Vec<String>, CliError> {
+    match &workload.control_interface_access {
+        Some(access) if !access.allow_rules.is_empty() || !access.deny_rules.is_empty() => {
+            Ok(indent(&serde_yaml::to_string(access)?))
+        }
+        _ => Ok(Vec::new()),
+    }
+}
+
+fn format_transitions(transitions: &[FilteredExecutionStateTransition]) -> Vec<String> {
+    transitions
+        .iter()
+        .map(|transition| {
+            format!(
+                "{INDENT}{}  {}  {}",
+                format_unix_timestamp(transition.timestamp),
+                transition.agent,
+                transition.execution_state
+            )
+        })
+        .collect()
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::describe_workload_field_mask;
+    use crate::cli_commands::{CliCommands, server_connection::MockServerConnection};
+    use crate::filtered_complete_state::FilteredCompleteState;
+
+    use api::ank_base;
+    use common::objects::{
+        CompleteState, ConfigItem, ExecutionState, State, WorkloadStatesMap,
+        generate_test_workload_state_with_agent,
+    };
+    use mockall::predicate::eq;
+    use std::collections::HashMap;
+
+    const RESPONSE_TIMEOUT_MS: u64 = 3000;
+    const WORKLOAD_NAME: &str = "nginx";
+    const DEPENDENCY_NAME: &str = "database";
+    const AGENT_A: &str = "agent_A";
+    const AGENT_B: &str = "agent_B";
+    const TIMESTAMP: u64 = 60;
+
+    fn workload() -> ank_base::Workload {
+        ank_base::Workload {
+            agent: Some(AGENT_A.to_string()),
+            runtime: Some("podman".to_string()),
+            restart_policy: Some(ank_base::RestartPolicy::Always as i32),
+            tags: Some(ank_base::Tags {
+                tags: vec![ank_base::Tag {
+                    key: "owner".to_string(),
+                    value: "team".to_string(),
+                }],
+            }),
+            runtime_config: Some("image: nginx\nenv: {{port}}\n".to_string()),
+            configs: Some(ank_base::ConfigMappings {
+                configs: HashMap::from([
+                    ("port".to_string(), "web_port".to_string()),
+                    ("missing".to_string(), "unknown_config".to_string()),
+                ]),
+            }),
+            dependencies: Some(ank_base::Dependencies {
+                dependencies: HashMap::from([(
+                    DEPENDENCY_NAME.to_string(),
+                    ank_base::AddCondition::AddCondRunning as i32,
+                )]),
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn complete_state() -> ank_base::CompleteState {
+        let mut workload_states = WorkloadStatesMap::new();
+        workload_states.process_new_states(vec![
+            generate_test_workload_state_with_agent(
+                WORKLOAD_NAME,
+                AGENT_A,
+                ExecutionState::running(),
+            ),
+            generate_test_workload_state_with_agent(
+                DEPENDENCY_NAME,
+                AGENT_B,
+                ExecutionState::running(),
+            ),
+        ]);
+
+        let mut complete_state: ank_base::CompleteState = CompleteState {
+            desired_state: State {
+                configs: HashMap::from([(
+                    "web_port".to_string(),
+                    ConfigItem::String("8080".to_string()),
+                )]),
+                ..Default::default()
+            },
+            workload_states,
+            ..Default::default()
+        }
+        .into();
+        complete_state
+            .desired_state
+            .as_mut()
+            .unwrap()
+            .workloads
+            .get_or_insert_default()
+            .workloads
+            .insert(WORKLOAD_NAME.to_string(), workload());
+        complete_state.rendered_workloads = Some(Box::new(ank_base::RenderedWorkloadMap {
+            workloads: HashMap::from([(
+                WORKLOAD_NAME.to_string(),
+                ank_base::RenderedWorkloads {
+                    replicas: vec![ank_base::RenderedWorkload {
+                        agent: AGENT_A.to_string(),
+                        runtime_config: "image: nginx\nenv: 8080\n".to_string(),
+                        files: Some(ank_base::Files {
+                            files: vec![ank_base::File {
+                                mount_point: "/etc/port".to_string(),
+                                file_content: Some(ank_base::file::FileContent::Data(
+                                    "8080".to_string(),
+                                )),
+                            }],
+                        }),
+                    }],
+                },
+            )]),
+        }));
+        complete_state.workload_state_history = Some(Box::new(ank_base::WorkloadStateHistoryMap {
+            workloads: HashMap::from([(
+                WORKLOAD_NAME.to_string(),
+                ank_base::WorkloadStateHistory {
+                    transitions: vec![
+                        ank_base::ExecutionStateTransition {
+                            agent: AGENT_A.to_string(),
+                            execution_state: Some(ExecutionState::initial().into()),
+                            timestamp: 0,
+                        },
+                        ank_base::ExecutionStateTransition {
+                            agent: AGENT_A.to_string(),
+                            execution_state: Some(ExecutionState::running().into()),
+                            timestamp: TIMESTAMP,
+                        },
+                    ],
+                },
+            )]),
+        }));
+        complete_state
+    }
+
+    // [utest->swdd~cli-provides-describe-workload~1]
+    // [utest->swdd~cli-describe-workload-shows-details~1]
+    // [utest->swdd~cli-describe-workload-shows-rendered-replicas~1]
+    #[tokio::test]
+    async fn utest_describe_workload() {
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_complete_state()
+            .with(eq(describe_workload_field_mask(WORKLOAD_NAME)))
+            .return_once(|_| Ok(FilteredCompleteState::from(complete_state())));
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        let description = cmd
+            .describe_workload(WORKLOAD_NAME.to_string())
+            .await
+            .unwrap();
+
+        let expected_description = [
+            "Name:            nginx",
+            "Agent:           agent_A",
+            "Runtime:         podman",
+            "Restart Policy:  Always",
+            "Tags:            owner=team",
+            "Runtime Config:",
+            "  image: nginx",
+            "  env: {{port}}",
+            "Rendered Replicas:",
+            "  agent_A:",
+            "    Runtime Config:",
+            "      image: nginx",
+            "      env: 8080",
+            "    Files:",
+            "      /etc/port:",
+            "        8080",
+            "Configs:",
+            "  missing -> unknown_config: <not found>",
+            "  port -> web_port:",
+            "    '8080'",
+            "Dependencies:",
+            "  database (ADD_COND_RUNNING): Running(Ok) on agent_B",
+            "Control Interface Access: <none>",
+            "Execution States:",
+            "  agent_A: Running(Ok)",
+            "Recent State Transitions:",
+            "  1970-01-01T00:00:00Z  agent_A  Pending(Initial)",
+            "  1970-01-01T00:01:00Z  agent_A  Running(Ok)",
+        ]
+        .join("\n");
+
+        assert_eq!(description, expected_description);
+    }
+
+    // [utest->swdd~cli-provides-describe-workload~1]
+    #[tokio::test]
+    async fn utest_describe_workload_not_existing() {
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_complete_state()
+            .return_once(|_| Ok(FilteredCompleteState::default()));
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        assert!(
+            cmd.describe_workload(WORKLOAD_NAME.to_string())
+                .await
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,371 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use api::ank_base;
+use common::state_manipulation::{Object, Path};
+use crossterm::style::Stylize;
+
+use super::CliCommands;
+use super::apply_manifests::generate_state_obj_and_filter_masks_from_manifests;
+use crate::cli::{ApplyArgs, DiffArgs};
+use crate::cli_error::CliError;
+use crate::filtered_complete_state::FilteredCompleteState;
+use crate::log::interactive;
+use crate::output_debug;
+
+#[cfg(test)]
+use self::tests::get_input_sources_mock as get_input_sources;
+
+#[cfg(not(test))]
+use super::get_input_sources;
+
+#[derive(Debug, PartialEq, Eq)]
+enum DiffLine<'a> {
+    Unchanged(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+// The objects of a manifest are small, so the longest common subsequence of the lines
+// is calculated without any optimizations.
+fn diff_lines<'a>(current: &[&'a str], target: &[&'a str]) -> Vec<DiffLine<'a>> {
+    // lcs[i][j] is the length of the longest common subsequence of current[i..] and target[j..]
+    let mut lcs = vec![vec![0usize; target.len() + 1]; current.len() + 1];
+    for i in (0..current.len()).rev() {
+        for j in (0..target.len()).rev() {
+            lcs[i][j] = if current[i] == target[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut diff = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < current.len() && j < target.len() {
+        if current[i] == target[j] {
+            diff.push(DiffLine::Unchanged(current[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            diff.push(DiffLine::Removed(current[i]));
+            i += 1;
+        } else {
+            diff.push(DiffLine::Added(target[j]));
+            j += 1;
+        }
+    }
+    diff.extend(current[i..].iter().copied().map(DiffLine::Removed));
+    diff.extend(target[j..].iter().copied().map(DiffLine::Added));
+    diff
+}
+
+fn hunk_range(line_count: usize) -> String {
+    let start = if line_count == 0 { 0 } else { 1 };
+    format!("{start},{line_count}")
+}
+
+// [impl->swdd~cli-diff-outputs-unified-diff~1]
+fn format_diff(
+    path: &str,
+    current: Option<&str>,
+    target: Option<&str>,
+    colored: bool,
+) -> Option<String> {
+    if current == target {
+        return None;
+    }
+
+    let current_lines: Vec<&str> = current.map(|x| x.lines().collect()).unwrap_or_default();
+    let target_lines: Vec<&str> = target.map(|x| x.lines().collect()).unwrap_or_default();
+
+    let paint = |line: String, style: fn(String) -> String| {
+        if colored { style(line) } else { line }
+    };
+
+    let mut diff = vec![
+        paint(format!("--- {path} (current)"), |x| x.bold().to_string()),
+        paint(format!("+++ {path} (manifest)"), |x| x.bold().to_string()),
+        paint(
+            format!(
+                "@@ -{} +{} @@",
+                hunk_range(current_lines.len()),
+                hunk_range(target_lines.len())
+            ),
+            |x| x.cyan().to_string(),
+        ),
+    ];
+    diff.extend(
+        diff_lines(&current_lines, &target_lines)
+            .into_iter()
+            .map(|line| match line {
+                DiffLine::Unchanged(line) => format!(" {line}"),
+                DiffLine::Removed(line) => paint(format!("-{line}"), |x| x.red().to_string()),
+                DiffLine::Added(line) => paint(format!("+{line}"), |x| x.green().to_string()),
+            }),
+    );
+    Some(diff.join("\n"))
+}
+
+fn to_yaml_at_path(state: &Object, path: &str) -> Result<Option<String>, CliError> {
+    state
+        .get(&Path::from(path))
+        .map(serde_yaml::to_string)
+        .transpose()
+        .map_err(Into::into)
+}
+
+// [impl->swdd~cli-diff-compares-objects-of-manifests~1]
+fn create_diff(
+    current_state: &FilteredCompleteState,
+    target_state: &FilteredCompleteState,
+    filter_masks: &[String],
+    colored: bool,
+) -> Result<Option<String>, CliError> {
+    let current_state: Object = serde_yaml::to_value(current_state)?.into();
+    let target_state: Object = serde_yaml::to_value(target_state)?.into();
+
+    let mut diffs = Vec::new();
+    for filter_mask in filter_masks {
+        let current = to_yaml_at_path(&current_state, filter_mask)?;
+        let target = to_yaml_at_path(&target_state, filter_mask)?;
+        diffs.extend(format_diff(
+            filter_mask,
+            current.as_deref(),
+            target.as_deref(),
+            colored,
+        ));
+    }
+
+    Ok((!diffs.is_empty()).then(|| diffs.join("\n")))
+}
+
+impl CliCommands {
+    // [impl->swdd~cli-provides-diff-of-manifests~1]
+    pub async fn diff_manifests(
+        &mut self,
+        diff_args: DiffArgs,
+    ) -> Result<Option<String>, CliError> {
+        let mut manifests = get_input_sources(&diff_args.manifest_files)
+            .map_err(|err| CliError::ExecutionError(err.to_string()))?;
+
+        // The target state is generated the same way as for applying the manifests.
+        let apply_args = ApplyArgs {
+            manifest_files: diff_args.manifest_files,
+            agent_name: diff_args.agent_name,
+            delete_mode: false,
+            dry_run: false,
+            expected_revision: None,
+            force: false,
+        };
+        let Some((target_state, filter_masks, _)) =
+            generate_state_obj_and_filter_masks_from_manifests(&mut manifests, &apply_args)
+                .map_err(CliError::ExecutionError)?
+        else {
+            return Ok(None);
+        };
+
+        // [impl->swdd~cli-diff-compares-objects-of-manifests~1]
+        let current_state = self
+            .server_connection
+            .get_complete_state(&filter_masks)
+            .await?;
+        output_debug!("Got current state for the diff: {:?}", current_state);
+
+        // The target state takes the same conversions as the state sent by the server
+        // to avoid differences caused only by default values.
+        let target_state = FilteredCompleteState::from(ank_base::CompleteState::from(target_state));
+
+        create_diff(&current_state, &target_state, &filter_masks, interactive())
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use std::io;
+    use std::sync::Mutex;
+
+    use api::ank_base;
+    use common::objects::{CompleteState, State};
+    use mockall::predicate::eq;
+
+    use super::{DiffLine, diff_lines, format_diff};
+    use crate::cli::DiffArgs;
+    use crate::cli_commands::{
+        CliCommands, InputSourcePair, server_connection::MockServerConnection,
+    };
+
+    const RESPONSE_TIMEOUT_MS: u64 = 3000;
+    const WORKLOAD_FIELD_MASK: &str = "desiredState.workloads.nginx";
+    const CONFIG_FIELD_MASK: &str = "desiredState.configs.port";
+
+    static FAKE_GET_INPUT_SOURCE_MOCK_RESULT_LIST: Mutex<
+        VecDeque<Result<Vec<InputSourcePair>, String>>,
+    > = Mutex::new(VecDeque::new());
+
+    pub fn get_input_sources_mock(
+        _manifest_files: &[String],
+    ) -> Result<Vec<InputSourcePair>, String> {
+        FAKE_GET_INPUT_SOURCE_MOCK_RESULT_LIST
+            .lock()
+            .unwrap()
+            .pop_front()
+            .unwrap()
+    }
+
+    fn manifest(agent_name: &str) -> String {
+        format!(
+            r#"apiVersion: "v0.1"
+workloads:
+  nginx:
+    runtime: podman
+    agent: {agent_name}
+    runtimeConfig: |
+      image: docker.io/nginx:latest
+configs:
+  port: "8080"
+"#
+        )
+    }
+
+    fn server_state(manifest: &str) -> ank_base::CompleteState {
+        let desired_state: State = serde_yaml::from_str(manifest).unwrap();
+        CompleteState {
+            desired_state,
+            ..Default::default()
+        }
+        .into()
+    }
+
+    async fn diff_against_server_state(
+        manifest: String,
+        server_state: ank_base::CompleteState,
+    ) -> Option<String> {
+        FAKE_GET_INPUT_SOURCE_MOCK_RESULT_LIST
+            .lock()
+            .unwrap()
+            .push_back(Ok(vec![(
+                "manifest.yaml".to_string(),
+                Box::new(io::Cursor::new(manifest)),
+            )]));
+
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_complete_state()
+            .with(eq(vec![
+                CONFIG_FIELD_MASK.to_string(),
+                WORKLOAD_FIELD_MASK.to_string(),
+            ]))
+            .return_once(|_| Ok(server_state.into()));
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        cmd.diff_manifests(DiffArgs {
+            manifest_files: vec!["manifest.yaml".to_string()],
+            agent_name: None,
+        })
+        .await
+        .unwrap()
+    }
+
+    // [utest->swdd~cli-diff-outputs-unified-diff~1]
+    #[test]
+    fn utest_diff_lines() {
+        assert_eq!(
+            diff_lines(&["a", "b", "c"], &["a", "x", "c", "d"]),
+            vec![
+                DiffLine::Unchanged("a"),
+                DiffLine::Removed("b"),
+                DiffLine::Added("x"),
+                DiffLine::Unchanged("c"),
+                DiffLine::Added("d"),
+            ]
+        );
+    }
+
+    // [utest->swdd~cli-diff-outputs-unified-diff~1]
+    #[test]
+    fn utest_format_diff() {
+        assert_eq!(
+            format_diff(WORKLOAD_FIELD_MASK, Some("a\nb\n"), Some("a\nb\n"), false),
+            None
+        );
+        assert_eq!(
+            format_diff(WORKLOAD_FIELD_MASK, Some("a\nb\n"), Some("a\nc\n"), false),
+            Some(
+                [
+                    "--- desiredState.workloads.nginx (current)",
+                    "+++ desiredState.workloads.nginx (manifest)",
+                    "@@ -1,2 +1,2 @@",
+                    " a",
+                    "-b",
+                    "+c",
+                ]
+                .join("\n")
+            )
+        );
+        assert_eq!(
+            format_diff(CONFIG_FIELD_MASK, None, Some("'8080'\n"), false),
+            Some(
+                [
+                    "--- desiredState.configs.port (current)",
+                    "+++ desiredState.configs.port (manifest)",
+                    "@@ -0,0 +1,1 @@",
+                    "+'8080'",
+                ]
+                .join("\n")
+            )
+        );
+    }
+
+    // [utest->swdd~cli-provides-diff-of-manifests~1]
+    // [utest->swdd~cli-diff-compares-objects-of-manifests~1]
+    #[tokio::test]
+    async fn utest_diff_manifests_no_differences() {
+        let diff =
+            diff_against_server_state(manifest("agent_A"), server_state(&manifest("agent_A")))
+                .await;
+
+        assert_eq!(diff, None);
+    }
+
+    // [utest->swdd~cli-provides-diff-of-manifests~1]
+    // [utest->swdd~cli-diff-compares-objects-of-manifests~1]
+    #[tokio::test]
+    async fn utest_diff_manifests_changed_and_added_objects() {
+        let server_manifest = manifest("agent_B").replace("configs:\n  port: \"8080\"\n", "");
+        let diff = diff_against_server_state(manifest("agent_A"), server_state(&server_manifest))
+            .await
+            .unwrap();
+
+        assert!(diff.contains("--- desiredState.configs.port (current)\n"));
+        assert!(diff.contains("@@ -0,0 +1,1 @@\n+'8080'"));
+        assert!(diff.contains("--- desiredState.workloads.nginx (current)\n"));
+        assert!(diff.contains("\n-agent: agent_B\n+agent: agent_A\n"));
+    }
+}
@@ -0,0 +1,220 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use common::objects::{CompleteState, WorkloadInstanceName};
+
+use crate::{cli_error::CliError, output, output_debug};
+
+use super::{CliCommands, wait_list::ParsedUpdateStateSuccess};
+
+const ADD: &str = "add";
+const REPLACE: &str = "replace";
+const DELETE: &str = "delete";
+
+// [impl->swdd~cli-outputs-change-plan-of-dry-run~1]
+fn format_change_plan(update_state_success: &ParsedUpdateStateSuccess) -> String {
+    let is_replaced = |instance_name: &WorkloadInstanceName| {
+        update_state_success
+            .deleted_workloads
+            .iter()
+            .any(|deleted| deleted.workload_name() == instance_name.workload_name())
+    };
+
+    let mut changes: Vec<(&str, &WorkloadInstanceName)> = update_state_success
+        .added_workloads
+        .iter()
+        .map(|added| (if is_replaced(added) { REPLACE } else { ADD }, added))
+        .collect();
+    changes.extend(
+        update_state_success
+            .deleted_workloads
+            .iter()
+            .filter(|deleted| {
+                !update_state_success
+                    .added_workloads
+                    .iter()
+                    .any(|added| added.workload_name() == deleted.workload_name())
+            })
+            .map(|deleted| (DELETE, deleted)),
+    );
+
+    if changes.is_empty() {
+        return "Dry run: the update would not change any workloads.".to_string();
+    }
+
+    changes.sort_by(|(_, left), (_, right)| left.workload_name().cmp(right.workload_name()));
+
+    let mut change_plan = "Dry run: the update would cause the following changes:".to_string();
+    for (action, instance_name) in changes {
+        change_plan.push_str(&format!(
+            "\n  {action:<width$} workload '{}' on agent '{}'",
+            instance_name.workload_name(),
+            instance_name.agent_name(),
+            width = REPLACE.len()
+        ));
+    }
+    change_plan
+}
+
+impl CliCommands {
+    // [impl->swdd~cli-requests-dry-run-update-state~1]
+    pub(crate) async fn dry_run_update_state(
+        &mut self,
+        new_state: CompleteState,
+        update_mask: Vec<String>,
+    ) -> Result<(), CliError> {
+        let update_state_success = self
+            .server_connection
+            .dry_run_update_state(new_state, update_mask)
+            .await?;
+
+        output_debug!("Got dry run update success: {:?}", update_state_success);
+
+        let update_state_success = ParsedUpdateStateSuccess::try_from(update_state_success)
+            .map_err(|error| {
+                CliError::ExecutionError(format!(
+                    "Could not parse UpdateStateSuccess message: {error}"
+                ))
+            })?;
+
+        output!("{}", format_change_plan(&update_state_success));
+        Ok(())
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::format_change_plan;
+    use crate::cli_commands::{
+        CliCommands, server_connection::MockServerConnection, wait_list::ParsedUpdateStateSuccess,
+    };
+
+    use api::ank_base::UpdateStateSuccess;
+    use common::objects::{CompleteState, WorkloadInstanceName};
+    use mockall::predicate::eq;
+
+    const RESPONSE_TIMEOUT_MS: u64 = 3000;
+    const FIELD_MASK: &str = "desiredState.workloads.nginx";
+
+    fn instance_name(workload_name: &str, agent_name: &str, id: &str) -> WorkloadInstanceName {
+        WorkloadInstanceName::builder()
+            .workload_name(workload_name)
+            .agent_name(agent_name)
+            .id(id)
+            .build()
+    }
+
+    // [utest->swdd~cli-outputs-change-plan-of-dry-run~1]
+    #[test]
+    fn utest_format_change_plan_added_replaced_and_deleted_workloads() {
+        let update_state_success = ParsedUpdateStateSuccess {
+            added_workloads: vec![
+                instance_name("nginx", "agent_B", "2"),
+                instance_name("api", "agent_A", "3"),
+            ],
+            deleted_workloads: vec![
+                instance_name("nginx", "agent_A", "1"),
+                instance_name("sidecar", "agent_A", "4"),
+            ],
+        };
+
+        assert_eq!(
+            format_change_plan(&update_state_success),
+            "Dry run: the update would cause the following changes:\n  \
+             add     workload 'api' on agent 'agent_A'\n  \
+             replace workload 'nginx' on agent 'agent_B'\n  \
+             delete  workload 'sidecar' on agent 'agent_A'"
+        );
+    }
+
+    // [utest->swdd~cli-outputs-change-plan-of-dry-run~1]
+    #[test]
+    fn utest_format_change_plan_no_changes() {
+        let update_state_success = ParsedUpdateStateSuccess {
+            added_workloads: vec![],
+            deleted_workloads: vec![],
+        };
+
+        assert_eq!(
+            format_change_plan(&update_state_success),
+            "Dry run: the update would not change any workloads."
+        );
+    }
+
+    // [utest->swdd~cli-requests-dry-run-update-state~1]
+    #[tokio::test]
+    async fn utest_dry_run_update_state_does_not_wait_for_workloads() {
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_dry_run_update_state()
+            .with(
+                eq(CompleteState::default()),
+                eq(vec![FIELD_MASK.to_string()]),
+            )
+            .return_once(|_, _| {
+                Ok(UpdateStateSuccess {
+                    added_workloads: vec![instance_name("nginx", "agent_A", "1").to_string()],
+                    deleted_workloads: vec![],
+                })
+            });
+        mock_server_connection.expect_update_state().never();
+        mock_server_connection.expect_get_complete_state().never();
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        assert!(
+            cmd.dry_run_update_state(CompleteState::default(), vec![FIELD_MASK.to_string()])
+                .await
+                .is_ok()
+        );
+    }
+
+    // [utest->swdd~cli-requests-dry-run-update-state~1]
+    #[tokio::test]
+    async fn utest_dry_run_update_state_fails_on_invalid_response() {
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_dry_run_update_state()
+            .return_once(|_, _| {
+                Ok(UpdateStateSuccess {
+                    added_workloads: vec!["invalid_instance_name".to_string()],
+                    deleted_workloads: vec![],
+                })
+            });
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        assert!(
+            cmd.dry_run_update_state(CompleteState::default(), vec![])
+                .await
+                .is_err()
+        );
+    }
+}
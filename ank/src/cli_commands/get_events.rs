@@ -0,0 +1,582 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use common::{helpers::format_unix_timestamp, objects::WorkloadState};
+use serde::Serialize;
+
+use super::CliCommands;
+use crate::{
+    cli::EventsOutputFormat, cli_error::CliError, filtered_complete_state::FilteredCompleteState,
+    output, output_debug,
+};
+
+#[cfg(not(test))]
+fn current_timestamp() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+fn current_timestamp() -> u64 {
+    tests::TIMESTAMP
+}
+
+// The agents are not watched by the server by default and must be requested to detect connected agents.
+// They are requested as a whole, as the labels and the load are not present for every connected agent.
+const EVENT_FIELD_MASKS: [&str; 3] = ["desiredState", "workloadStates", "agents"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum ClusterEventType {
+    WorkloadStateChanged,
+    AgentConnected,
+    AgentDisconnected,
+    WorkloadAdded,
+    WorkloadUpdated,
+    WorkloadDeleted,
+    ConfigsChanged,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClusterEvent {
+    pub timestamp: String,
+    #[serde(rename = "type")]
+    pub event_type: ClusterEventType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub agent: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub workload: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_state: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revision: Option<u64>,
+}
+
+impl ClusterEvent {
+    fn new(event_type: ClusterEventType, timestamp: &str) -> Self {
+        ClusterEvent {
+            timestamp: timestamp.to_owned(),
+            event_type,
+            agent: None,
+            workload: None,
+            previous_state: None,
+            state: None,
+            revision: None,
+        }
+    }
+
+    // [impl->swdd~cli-filters-events~1]
+    fn matches(
+        &self,
+        agent_name: &Option<String>,
+        state: &Option<String>,
+        workload_names: &[String],
+    ) -> bool {
+        if agent_name.is_some() && &self.agent != agent_name {
+            return false;
+        }
+
+        if let Some(state) = state {
+            let Some(event_state) = &self.state else {
+                return false;
+            };
+            let event_state = event_state.to_lowercase();
+            let state = state.to_lowercase();
+            if event_state != state && !event_state.starts_with(&format!("{state}(")) {
+                return false;
+            }
+        }
+
+        if !workload_names.is_empty()
+            && !self
+                .workload
+                .as_ref()
+                .is_some_and(|workload| workload_names.contains(workload))
+        {
+            return false;
+        }
+
+        true
+    }
+
+    fn to_text(&self) -> String {
+        let mut text = format!("{} {:?}", self.timestamp, self.event_type);
+        if let Some(agent) = &self.agent {
+            text.push_str(&format!(" agent={agent}"));
+        }
+        if let Some(workload) = &self.workload {
+            text.push_str(&format!(" workload={workload}"));
+        }
+        if let Some(revision) = self.revision {
+            text.push_str(&format!(" revision={revision}"));
+        }
+        match (&self.previous_state, &self.state) {
+            (Some(previous_state), Some(state)) => {
+                text.push_str(&format!(" {previous_state} -> {state}"))
+            }
+            (None, Some(state)) => text.push_str(&format!(" -> {state}")),
+            _ => {}
+        }
+        text
+    }
+}
+
+impl CliCommands {
+    // [impl->swdd~cli-provides-events~1]
+    pub async fn get_events(
+        &mut self,
+        output_format: EventsOutputFormat,
+        agent_name: Option<String>,
+        state: Option<String>,
+        workload_names: Vec<String>,
+    ) -> Result<(), CliError> {
+        // [impl->swdd~cli-subscribes-for-events~3]
+        let request_id = self
+            .server_connection
+            .subscribe_events(EVENT_FIELD_MASKS.map(String::from).to_vec())
+            .await?;
+
+        // The first event contains the current state and is only used as a base for the following changes.
+        let mut last_state: Option<FilteredCompleteState> = None;
+        while let Some(event) = self.server_connection.read_next_event(&request_id).await? {
+            output_debug!("Got event: {:?}", event);
+            let new_state: FilteredCompleteState = event.complete_state.unwrap_or_default().into();
+
+            if let Some(last_state) = &last_state {
+                // The events carry no time of the change, so the time of receiving them is used.
+                let timestamp = format_unix_timestamp(current_timestamp());
+                for cluster_event in collect_cluster_events(last_state, &new_state, &timestamp)
+                    .into_iter()
+                    .filter(|cluster_event| {
+                        cluster_event.matches(&agent_name, &state, &workload_names)
+                    })
+                {
+                    output!("{}", format_cluster_event(&cluster_event, output_format)?);
+                }
+            }
+            last_state = Some(new_state);
+        }
+        Ok(())
+    }
+}
+
+// [impl->swdd~cli-provides-events~1]
+fn format_cluster_event(
+    cluster_event: &ClusterEvent,
+    output_format: EventsOutputFormat,
+) -> Result<String, CliError> {
+    Ok(match output_format {
+        EventsOutputFormat::Text => cluster_event.to_text(),
+        // one event per line to allow processing the feed line by line
+        EventsOutputFormat::Json => serde_json::to_string(cluster_event)?,
+        EventsOutputFormat::Yaml => format!("---\n{}", serde_yaml::to_string(cluster_event)?)
+            .trim_end()
+            .to_owned(),
+    })
+}
+
+// [impl->swdd~cli-derives-events-from-state-changes~1]
+fn collect_cluster_events(
+    last_state: &FilteredCompleteState,
+    new_state: &FilteredCompleteState,
+    timestamp: &str,
+) -> Vec<ClusterEvent> {
+    let mut cluster_events = collect_desired_state_events(last_state, new_state, timestamp);
+    cluster_events.extend(collect_agent_events(last_state, new_state, timestamp));
+    cluster_events.extend(collect_workload_state_events(
+        last_state, new_state, timestamp,
+    ));
+    cluster_events
+}
+
+fn collect_desired_state_events(
+    last_state: &FilteredCompleteState,
+    new_state: &FilteredCompleteState,
+    timestamp: &str,
+) -> Vec<ClusterEvent> {
+    let last_workloads = last_state
+        .desired_state
+        .as_ref()
+        .and_then(|desired_state| desired_state.workloads.clone())
+        .unwrap_or_default();
+    let new_workloads = new_state
+        .desired_state
+        .as_ref()
+        .and_then(|desired_state| desired_state.workloads.clone())
+        .unwrap_or_default();
+
+    let workload_names: BTreeSet<&String> =
+        last_workloads.keys().chain(new_workloads.keys()).collect();
+
+    let mut cluster_events = Vec::new();
+    for workload_name in workload_names {
+        let (event_type, workload_spec) = match (
+            last_workloads.get(workload_name),
+            new_workloads.get(workload_name),
+        ) {
+            (None, Some(new_spec)) => (ClusterEventType::WorkloadAdded, new_spec),
+            (Some(last_spec), None) => (ClusterEventType::WorkloadDeleted, last_spec),
+            (Some(last_spec), Some(new_spec)) if last_spec != new_spec => {
+                (ClusterEventType::WorkloadUpdated, new_spec)
+            }
+            _ => continue,
+        };
+        let mut cluster_event = ClusterEvent::new(event_type, timestamp);
+        cluster_event.agent = workload_spec.agent.clone();
+        cluster_event.workload = Some(workload_name.to_owned());
+        cluster_event.revision = new_state.revision;
+        cluster_events.push(cluster_event);
+    }
+
+    let last_configs = last_state
+        .desired_state
+        .as_ref()
+        .and_then(|desired_state| desired_state.configs.as_ref());
+    let new_configs = new_state
+        .desired_state
+        .as_ref()
+        .and_then(|desired_state| desired_state.configs.as_ref());
+    if last_configs != new_configs {
+        let mut cluster_event = ClusterEvent::new(ClusterEventType::ConfigsChanged, timestamp);
+        cluster_event.revision = new_state.revision;
+        cluster_events.push(cluster_event);
+    }
+
+    cluster_events
+}
+
+fn collect_agent_events(
+    last_state: &FilteredCompleteState,
+    new_state: &FilteredCompleteState,
+    timestamp: &str,
+) -> Vec<ClusterEvent> {
+    let agent_names = |state: &FilteredCompleteState| -> BTreeSet<String> {
+        state
+            .agents
+            .as_ref()
+            .and_then(|agent_map| agent_map.agents.as_ref())
+            .map(|agents| agents.keys().cloned().collect())
+            .unwrap_or_default()
+    };
+    let last_agents = agent_names(last_state);
+    let new_agents = agent_names(new_state);
+
+    let connected = new_agents
+        .difference(&last_agents)
+        .map(|agent| (ClusterEventType::AgentConnected, agent));
+    let disconnected = last_agents
+        .difference(&new_agents)
+        .map(|agent| (ClusterEventType::AgentDisconnected, agent));
+
+    connected
+        .chain(disconnected)
+        .map(|(event_type, agent)| {
+            let mut cluster_event = ClusterEvent::new(event_type, timestamp);
+            cluster_event.agent = Some(agent.to_owned());
+            cluster_event
+        })
+        .collect()
+}
+
+fn collect_workload_state_events(
+    last_state: &FilteredCompleteState,
+    new_state: &FilteredCompleteState,
+    timestamp: &str,
+) -> Vec<ClusterEvent> {
+    let execution_states = |state: &FilteredCompleteState| -> BTreeMap<String, WorkloadState> {
+        Vec::<WorkloadState>::from(state.workload_states.clone().unwrap_or_default())
+            .into_iter()
+            .map(|workload_state| (workload_state.instance_name.to_string(), workload_state))
+            .collect()
+    };
+    let last_execution_states = execution_states(last_state);
+
+    execution_states(new_state)
+        .into_values()
+        .filter_map(|workload_state| {
+            let previous_state = last_execution_states
+                .get(&workload_state.instance_name.to_string())
+                .map(|last_workload_state| &last_workload_state.execution_state);
+            // a change of the additional info alone, e.g. the restart count, is no state transition
+            if previous_state.map(|execution_state| &execution_state.state)
+                == Some(&workload_state.execution_state.state)
+            {
+                return None;
+            }
+
+            let mut cluster_event =
+                ClusterEvent::new(ClusterEventType::WorkloadStateChanged, timestamp);
+            cluster_event.agent = Some(workload_state.instance_name.agent_name().to_owned());
+            cluster_event.workload = Some(workload_state.instance_name.workload_name().to_owned());
+            cluster_event.previous_state =
+                previous_state.map(|execution_state| execution_state.state.to_string());
+            cluster_event.state = Some(workload_state.execution_state.state.to_string());
+            Some(cluster_event)
+        })
+        .collect()
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{ClusterEvent, ClusterEventType, collect_cluster_events, format_cluster_event};
+    use crate::{
+        cli::EventsOutputFormat,
+        cli_commands::{CliCommands, server_connection::MockServerConnection},
+        filtered_complete_state::FilteredCompleteState,
+    };
+    use api::ank_base;
+    use common::objects::{
+        CompleteState, ExecutionState, State, generate_test_stored_workload_spec,
+        generate_test_workload_states_map_with_data,
+    };
+    use mockall::predicate;
+
+    pub const TIMESTAMP: u64 = 60;
+    const TIMESTAMP_STR: &str = "1970-01-01T00:01:00Z";
+    const RESPONSE_TIMEOUT_MS: u64 = 3000;
+    const AGENT_A: &str = "agent_A";
+    const AGENT_B: &str = "agent_B";
+    const WORKLOAD_NAME_1: &str = "workload_1";
+    const WORKLOAD_NAME_2: &str = "workload_2";
+    const INSTANCE_ID: &str = "id_1";
+    const RUNTIME: &str = "runtime";
+    const REQUEST_ID: &str = "request_id";
+
+    fn complete_state(
+        workload_names: &[&str],
+        execution_state: ExecutionState,
+    ) -> ank_base::CompleteState {
+        let mut desired_state = State::default();
+        for workload_name in workload_names {
+            desired_state.workloads.insert(
+                workload_name.to_string(),
+                generate_test_stored_workload_spec(AGENT_A, RUNTIME),
+            );
+        }
+        let mut complete_state: ank_base::CompleteState = CompleteState {
+            desired_state,
+            workload_states: generate_test_workload_states_map_with_data(
+                AGENT_A,
+                WORKLOAD_NAME_1,
+                INSTANCE_ID,
+                execution_state,
+            ),
+            ..Default::default()
+        }
+        .into();
+        complete_state.revision = Some(workload_names.len() as u64);
+        complete_state
+    }
+
+    fn workload_state_event(previous_state: Option<&str>, state: &str) -> ClusterEvent {
+        ClusterEvent {
+            agent: Some(AGENT_A.into()),
+            workload: Some(WORKLOAD_NAME_1.into()),
+            previous_state: previous_state.map(String::from),
+            state: Some(state.into()),
+            ..ClusterEvent::new(ClusterEventType::WorkloadStateChanged, TIMESTAMP_STR)
+        }
+    }
+
+    // [utest->swdd~cli-derives-events-from-state-changes~1]
+    #[test]
+    fn utest_collect_cluster_events_workload_state_changed() {
+        let last_state: FilteredCompleteState =
+            complete_state(&[WORKLOAD_NAME_1], ExecutionState::starting("")).into();
+        let new_state: FilteredCompleteState =
+            complete_state(&[WORKLOAD_NAME_1], ExecutionState::running()).into();
+
+        assert_eq!(
+            collect_cluster_events(&last_state, &new_state, TIMESTAMP_STR),
+            vec![workload_state_event(
+                Some("Pending(Starting)"),
+                "Running(Ok)"
+            )]
+        );
+        assert!(collect_cluster_events(&new_state, &new_state, TIMESTAMP_STR).is_empty());
+    }
+
+    // [utest->swdd~cli-derives-events-from-state-changes~1]
+    #[test]
+    fn utest_collect_cluster_events_ignores_changed_additional_info() {
+        let last_state: FilteredCompleteState = complete_state(
+            &[WORKLOAD_NAME_1],
+            ExecutionState::starting("pulling image"),
+        )
+        .into();
+        let new_state: FilteredCompleteState = complete_state(
+            &[WORKLOAD_NAME_1],
+            ExecutionState::starting("creating container"),
+        )
+        .into();
+
+        assert!(collect_cluster_events(&last_state, &new_state, TIMESTAMP_STR).is_empty());
+    }
+
+    // [utest->swdd~cli-derives-events-from-state-changes~1]
+    #[test]
+    fn utest_collect_cluster_events_desired_state_changed() {
+        let last_state: FilteredCompleteState =
+            complete_state(&[WORKLOAD_NAME_1], ExecutionState::running()).into();
+        let new_state: FilteredCompleteState =
+            complete_state(&[WORKLOAD_NAME_2], ExecutionState::running()).into();
+
+        assert_eq!(
+            collect_cluster_events(&last_state, &new_state, TIMESTAMP_STR),
+            vec![
+                ClusterEvent {
+                    agent: Some(AGENT_A.into()),
+                    workload: Some(WORKLOAD_NAME_1.into()),
+                    revision: Some(1),
+                    ..ClusterEvent::new(ClusterEventType::WorkloadDeleted, TIMESTAMP_STR)
+                },
+                ClusterEvent {
+                    agent: Some(AGENT_A.into()),
+                    workload: Some(WORKLOAD_NAME_2.into()),
+                    revision: Some(1),
+                    ..ClusterEvent::new(ClusterEventType::WorkloadAdded, TIMESTAMP_STR)
+                },
+            ]
+        );
+    }
+
+    // [utest->swdd~cli-derives-events-from-state-changes~1]
+    #[test]
+    fn utest_collect_cluster_events_agents_changed() {
+        let mut last_state = complete_state(&[], ExecutionState::running());
+        last_state.agents = Some(ank_base::AgentMap {
+            agents: [(AGENT_A.to_owned(), ank_base::AgentAttributes::default())].into(),
+        });
+        let mut new_state = last_state.clone();
+        new_state.agents = Some(ank_base::AgentMap {
+            agents: [(AGENT_B.to_owned(), ank_base::AgentAttributes::default())].into(),
+        });
+
+        assert_eq!(
+            collect_cluster_events(&last_state.into(), &new_state.into(), TIMESTAMP_STR),
+            vec![
+                ClusterEvent {
+                    agent: Some(AGENT_B.into()),
+                    ..ClusterEvent::new(ClusterEventType::AgentConnected, TIMESTAMP_STR)
+                },
+                ClusterEvent {
+                    agent: Some(AGENT_A.into()),
+                    ..ClusterEvent::new(ClusterEventType::AgentDisconnected, TIMESTAMP_STR)
+                },
+            ]
+        );
+    }
+
+    // [utest->swdd~cli-filters-events~1]
+    #[test]
+    fn utest_cluster_event_matches_filters() {
+        let event = workload_state_event(Some("Pending(Starting)"), "Running(Ok)");
+
+        assert!(event.matches(&None, &None, &[]));
+        assert!(event.matches(&Some(AGENT_A.into()), &None, &[]));
+        assert!(!event.matches(&Some(AGENT_B.into()), &None, &[]));
+        assert!(event.matches(&None, &Some("running".into()), &[]));
+        assert!(event.matches(&None, &Some("Running(Ok)".into()), &[]));
+        assert!(!event.matches(&None, &Some("Failed".into()), &[]));
+        assert!(event.matches(&None, &None, &[WORKLOAD_NAME_1.into()]));
+        assert!(!event.matches(&None, &None, &[WORKLOAD_NAME_2.into()]));
+
+        let agent_event = ClusterEvent {
+            agent: Some(AGENT_A.into()),
+            ..ClusterEvent::new(ClusterEventType::AgentConnected, TIMESTAMP_STR)
+        };
+        assert!(!agent_event.matches(&None, &Some("running".into()), &[]));
+        assert!(!agent_event.matches(&None, &None, &[WORKLOAD_NAME_1.into()]));
+    }
+
+    // [utest->swdd~cli-provides-events~1]
+    #[test]
+    fn utest_format_cluster_event() {
+        let event = workload_state_event(Some("Pending(Starting)"), "Running(Ok)");
+
+        assert_eq!(
+            format_cluster_event(&event, EventsOutputFormat::Text).unwrap(),
+            "1970-01-01T00:01:00Z WorkloadStateChanged agent=agent_A workload=workload_1 Pending(Starting) -> Running(Ok)"
+        );
+        assert_eq!(
+            format_cluster_event(&event, EventsOutputFormat::Json).unwrap(),
+            r#"{"timestamp":"1970-01-01T00:01:00Z","type":"WorkloadStateChanged","agent":"agent_A","workload":"workload_1","previousState":"Pending(Starting)","state":"Running(Ok)"}"#
+        );
+        let yaml_output = format_cluster_event(&event, EventsOutputFormat::Yaml).unwrap();
+        assert!(yaml_output.starts_with("---\n"));
+        let yaml_value: serde_yaml::Value = serde_yaml::from_str(&yaml_output).unwrap();
+        assert_eq!(yaml_value["type"], "WorkloadStateChanged");
+        assert_eq!(yaml_value["state"], "Running(Ok)");
+    }
+
+    // [utest->swdd~cli-provides-events~1]
+    // [utest->swdd~cli-subscribes-for-events~3]
+    #[tokio::test]
+    async fn utest_get_events_until_interrupted() {
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_subscribe_events()
+            .with(predicate::eq(vec![
+                "desiredState".to_string(),
+                "workloadStates".to_string(),
+                "agents".to_string(),
+            ]))
+            .once()
+            .return_once(|_| Ok(REQUEST_ID.to_owned()));
+        let mut seq = mockall::Sequence::new();
+        for execution_state in [ExecutionState::starting(""), ExecutionState::running()] {
+            mock_server_connection
+                .expect_read_next_event()
+                .withf(|request_id| request_id == REQUEST_ID)
+                .once()
+                .in_sequence(&mut seq)
+                .return_once(move |_| {
+                    Ok(Some(ank_base::EventResponse {
+                        complete_state: Some(complete_state(&[WORKLOAD_NAME_1], execution_state)),
+                        changed_field_masks: vec![],
+                    }))
+                });
+        }
+        mock_server_connection
+            .expect_read_next_event()
+            .once()
+            .in_sequence(&mut seq)
+            .return_once(|_| Ok(None));
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        let result = cmd
+            .get_events(EventsOutputFormat::Text, None, None, vec![])
+            .await;
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,481 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{BTreeMap, HashSet};
+
+use common::objects::WorkloadState;
+
+use super::CliCommands;
+use super::DESIRED_STATE_WORKLOADS;
+use super::describe_workload::format_agents;
+use crate::cli::GraphOutputFormat;
+use crate::cli_error::CliError;
+use crate::filtered_complete_state::FilteredCompleteState;
+use crate::output_debug;
+
+const WORKLOAD_STATES: &str = "workloadStates";
+const NOT_IN_DESIRED_STATE: &str = "<not in desired state>";
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct GraphNode {
+    agent: String,
+    execution_states: Vec<String>,
+}
+
+impl GraphNode {
+    fn annotations(&self) -> Vec<&str> {
+        std::iter::once(self.agent.as_str())
+            .chain(self.execution_states.iter().map(String::as_str))
+            .collect()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct GraphEdge {
+    workload_name: String,
+    dependency_name: String,
+    add_condition: String,
+}
+
+// The edges point from a workload to the workloads it depends on.
+// Nodes and edges are sorted by name to ensure a consistent output.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct DependencyGraph {
+    nodes: BTreeMap<String, GraphNode>,
+    edges: Vec<GraphEdge>,
+}
+
+impl CliCommands {
+    // [impl->swdd~cli-provides-dependency-graph~1]
+    pub async fn get_graph(
+        &mut self,
+        output_format: GraphOutputFormat,
+    ) -> Result<String, CliError> {
+        let filtered_complete_state = self
+            .server_connection
+            .get_complete_state(&[
+                DESIRED_STATE_WORKLOADS.to_string(),
+                WORKLOAD_STATES.to_string(),
+            ])
+            .await?;
+
+        let graph = build_dependency_graph(filtered_complete_state)?;
+        output_debug!("Built dependency graph: {:?}", graph);
+
+        Ok(match output_format {
+            GraphOutputFormat::Tree => to_tree(&graph),
+            GraphOutputFormat::Dot => to_dot(&graph),
+            GraphOutputFormat::Mermaid => to_mermaid(&graph),
+        })
+    }
+}
+
+// [impl->swdd~cli-builds-dependency-graph-from-desired-state~1]
+fn build_dependency_graph(
+    filtered_complete_state: FilteredCompleteState,
+) -> Result<DependencyGraph, CliError> {
+    let workloads = filtered_complete_state
+        .desired_state
+        .and_then(|desired_state| desired_state.workloads)
+        .unwrap_or_default();
+    let mut workload_states: Vec<WorkloadState> = filtered_complete_state
+        .workload_states
+        .map(Into::into)
+        .unwrap_or_default();
+    workload_states.sort_by(|left, right| left.instance_name.cmp(&right.instance_name));
+
+    let mut graph = DependencyGraph::default();
+    for (workload_name, workload) in &workloads {
+        let execution_states = workload_states
+            .iter()
+            .filter(|state| state.instance_name.workload_name() == workload_name.as_str())
+            .map(|state| state.execution_state.to_string())
+            .collect();
+        graph.nodes.insert(
+            workload_name.clone(),
+            GraphNode {
+                agent: format_agents(workload),
+                execution_states,
+            },
+        );
+
+        for (dependency_name, add_condition) in workload.dependencies.iter().flatten() {
+            graph.edges.push(GraphEdge {
+                workload_name: workload_name.clone(),
+                dependency_name: dependency_name.clone(),
+                add_condition: serde_yaml::to_string(add_condition)?.trim_end().to_string(),
+            });
+        }
+    }
+
+    // a dependency can reference a workload which is not part of the desired state
+    for edge in &graph.edges {
+        if !graph.nodes.contains_key(&edge.dependency_name) {
+            graph.nodes.insert(
+                edge.dependency_name.clone(),
+                GraphNode {
+                    agent: NOT_IN_DESIRED_STATE.to_string(),
+                    execution_states: Vec::new(),
+                },
+            );
+        }
+    }
+
+    graph.edges.sort_by(|left, right| {
+        (&left.workload_name, &left.dependency_name)
+            .cmp(&(&right.workload_name, &right.dependency_name))
+    });
+    Ok(graph)
+}
+
+// [impl->swdd~cli-outputs-dependency-graph-as-tree~1]
+fn to_tree(graph: &DependencyGraph) -> String {
+    let dependency_names: HashSet<&str> = graph
+        .edges
+        .iter()
+        .map(|edge| edge.dependency_name.as_str())
+        .collect();
+
+    let mut lines = Vec::new();
+    let mut printed = HashSet::new();
+    let roots = graph
+        .nodes
+        .keys()
+        .filter(|workload_name| !dependency_names.contains(workload_name.as_str()));
+    for root in roots {
+        lines.push(tree_node_text(graph, root));
+        printed.insert(root.as_str());
+        add_tree_children(
+            graph,
+            root,
+            "",
+            &mut vec![root.as_str()],
+            &mut printed,
+            &mut lines,
+        );
+    }
+
+    // workloads in a dependency cycle have no root, this is rejected by the server but kept
+    // here to never hide a workload
+    for workload_name in graph.nodes.keys() {
+        if !printed.contains(workload_name.as_str()) {
+            lines.push(tree_node_text(graph, workload_name));
+            printed.insert(workload_name.as_str());
+            add_tree_children(
+                graph,
+                workload_name,
+                "",
+                &mut vec![workload_name.as_str()],
+                &mut printed,
+                &mut lines,
+            );
+        }
+    }
+
+    lines.join("\n")
+}
+
+fn add_tree_children<'a>(
+    graph: &'a DependencyGraph,
+    workload_name: &str,
+    prefix: &str,
+    path: &mut Vec<&'a str>,
+    printed: &mut HashSet<&'a str>,
+    lines: &mut Vec<String>,
+) {
+    let edges: Vec<&GraphEdge> = graph
+        .edges
+        .iter()
+        .filter(|edge| edge.workload_name == workload_name)
+        .collect();
+
+    for (index, edge) in edges.iter().copied().enumerate() {
+        let is_last = index + 1 == edges.len();
+        let (branch, child_prefix) = if is_last {
+            ("└── ", "    ")
+        } else {
+            ("├── ", "│   ")
+        };
+        let dependency_name = edge.dependency_name.as_str();
+        printed.insert(dependency_name);
+
+        if path.contains(&dependency_name) {
+            lines.push(format!(
+                "{prefix}{branch}{dependency_name} [{}] (cycle)",
+                edge.add_condition
+            ));
+            continue;
+        }
+
+        lines.push(format!(
+            "{prefix}{branch}{} [{}]",
+            tree_node_text(graph, dependency_name),
+            edge.add_condition
+        ));
+        path.push(dependency_name);
+        add_tree_children(
+            graph,
+            dependency_name,
+            &format!("{prefix}{child_prefix}"),
+            path,
+            printed,
+            lines,
+        );
+        path.pop();
+    }
+}
+
+fn tree_node_text(graph: &DependencyGraph, workload_name: &str) -> String {
+    match graph.nodes.get(workload_name) {
+        Some(node) => format!("{workload_name} ({})", node.annotations().join(", ")),
+        None => workload_name.to_string(),
+    }
+}
+
+// [impl->swdd~cli-outputs-dependency-graph-as-dot~1]
+fn to_dot(graph: &DependencyGraph) -> String {
+    let escape = |text: &str| text.replace('\\', "\\\\").replace('"', "\\\"");
+
+    let mut lines = vec!["digraph dependencies {".to_string()];
+    for (workload_name, node) in &graph.nodes {
+        let label = std::iter::once(workload_name.as_str())
+            .chain(node.annotations())
+            .map(escape)
+            .collect::<Vec<String>>()
+            .join("\\n");
+        lines.push(format!(
+            "  \"{}\" [label=\"{label}\"];",
+            escape(workload_name)
+        ));
+    }
+    for edge in &graph.edges {
+        lines.push(format!(
+            "  \"{}\" -> \"{}\" [label=\"{}\"];",
+            escape(&edge.workload_name),
+            escape(&edge.dependency_name),
+            escape(&edge.add_condition)
+        ));
+    }
+    lines.push("}".to_string());
+    lines.join("\n")
+}
+
+// The workload names are not used as Mermaid node ids, as the '-' of a name can be mistaken
+// for an edge.
+// [impl->swdd~cli-outputs-dependency-graph-as-mermaid~1]
+fn to_mermaid(graph: &DependencyGraph) -> String {
+    let escape = |text: &str| {
+        text.replace('"', "#quot;")
+            .replace('<', "#lt;")
+            .replace('>', "#gt;")
+    };
+    let node_ids: BTreeMap<&str, String> = graph
+        .nodes
+        .keys()
+        .enumerate()
+        .map(|(index, workload_name)| (workload_name.as_str(), format!("n{index}")))
+        .collect();
+
+    let mut lines = vec!["graph TD".to_string()];
+    for (workload_name, node) in &graph.nodes {
+        let label = std::iter::once(workload_name.as_str())
+            .chain(node.annotations())
+            .map(escape)
+            .collect::<Vec<String>>()
+            .join("<br/>");
+        lines.push(format!(
+            "  {}[\"{label}\"]",
+            node_ids[workload_name.as_str()]
+        ));
+    }
+    for edge in &graph.edges {
+        lines.push(format!(
+            "  {} -->|{}| {}",
+            node_ids[edge.workload_name.as_str()],
+            escape(&edge.add_condition),
+            node_ids[edge.dependency_name.as_str()]
+        ));
+    }
+    lines.join("\n")
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::GraphOutputFormat;
+    use crate::cli_commands::{CliCommands, server_connection::MockServerConnection};
+    use crate::filtered_complete_state::FilteredCompleteState;
+
+    use api::ank_base;
+    use common::objects::{
+        CompleteState, ExecutionState, WorkloadStatesMap, generate_test_workload_state_with_agent,
+    };
+    use mockall::predicate::eq;
+    use std::collections::HashMap;
+
+    const RESPONSE_TIMEOUT_MS: u64 = 3000;
+    const AGENT_A: &str = "agent_A";
+    const AGENT_B: &str = "agent_B";
+
+    fn workload(
+        agent: &str,
+        dependencies: &[(&str, ank_base::AddCondition)],
+    ) -> ank_base::Workload {
+        ank_base::Workload {
+            agent: Some(agent.to_string()),
+            dependencies: Some(ank_base::Dependencies {
+                dependencies: dependencies
+                    .iter()
+                    .map(|(name, condition)| (name.to_string(), *condition as i32))
+                    .collect(),
+            }),
+            ..Default::default()
+        }
+    }
+
+    // frontend -> backend -> database, frontend -> cache (not in the desired state)
+    fn complete_state() -> FilteredCompleteState {
+        let mut workload_states = WorkloadStatesMap::new();
+        workload_states.process_new_states(vec![
+            generate_test_workload_state_with_agent("frontend", AGENT_A, ExecutionState::initial()),
+            generate_test_workload_state_with_agent("backend", AGENT_A, ExecutionState::running()),
+            generate_test_workload_state_with_agent("database", AGENT_B, ExecutionState::running()),
+        ]);
+
+        let mut complete_state: ank_base::CompleteState = CompleteState {
+            workload_states,
+            ..Default::default()
+        }
+        .into();
+        complete_state
+            .desired_state
+            .as_mut()
+            .unwrap()
+            .workloads
+            .get_or_insert_default()
+            .workloads = HashMap::from([
+            (
+                "frontend".to_string(),
+                workload(
+                    AGENT_A,
+                    &[
+                        ("backend", ank_base::AddCondition::AddCondRunning),
+                        ("cache", ank_base::AddCondition::AddCondRunning),
+                    ],
+                ),
+            ),
+            (
+                "backend".to_string(),
+                workload(
+                    AGENT_A,
+                    &[("database", ank_base::AddCondition::AddCondSucceeded)],
+                ),
+            ),
+            ("database".to_string(), workload(AGENT_B, &[])),
+        ]);
+        complete_state.into()
+    }
+
+    fn cli_commands() -> CliCommands {
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_complete_state()
+            .with(eq(vec![
+                "desiredState.workloads".to_string(),
+                "workloadStates".to_string(),
+            ]))
+            .return_once(|_| Ok(complete_state()));
+
+        CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        }
+    }
+
+    // [utest->swdd~cli-provides-dependency-graph~1]
+    // [utest->swdd~cli-builds-dependency-graph-from-desired-state~1]
+    // [utest->swdd~cli-outputs-dependency-graph-as-tree~1]
+    #[tokio::test]
+    async fn utest_get_graph_as_tree() {
+        let mut cmd = cli_commands();
+
+        let expected_output = [
+            "frontend (agent_A, Pending(Initial))",
+            "├── backend (agent_A, Running(Ok)) [ADD_COND_RUNNING]",
+            "│   └── database (agent_B, Running(Ok)) [ADD_COND_SUCCEEDED]",
+            "└── cache (<not in desired state>) [ADD_COND_RUNNING]",
+        ]
+        .join("\n");
+
+        assert_eq!(
+            cmd.get_graph(GraphOutputFormat::Tree).await.unwrap(),
+            expected_output
+        );
+    }
+
+    // [utest->swdd~cli-outputs-dependency-graph-as-dot~1]
+    #[tokio::test]
+    async fn utest_get_graph_as_dot() {
+        let mut cmd = cli_commands();
+
+        let expected_output = [
+            "digraph dependencies {",
+            "  \"backend\" [label=\"backend\\nagent_A\\nRunning(Ok)\"];",
+            "  \"cache\" [label=\"cache\\n<not in desired state>\"];",
+            "  \"database\" [label=\"database\\nagent_B\\nRunning(Ok)\"];",
+            "  \"frontend\" [label=\"frontend\\nagent_A\\nPending(Initial)\"];",
+            "  \"backend\" -> \"database\" [label=\"ADD_COND_SUCCEEDED\"];",
+            "  \"frontend\" -> \"backend\" [label=\"ADD_COND_RUNNING\"];",
+            "  \"frontend\" -> \"cache\" [label=\"ADD_COND_RUNNING\"];",
+            "}",
+        ]
+        .join("\n");
+
+        assert_eq!(
+            cmd.get_graph(GraphOutputFormat::Dot).await.unwrap(),
+            expected_output
+        );
+    }
+
+    // [utest->swdd~cli-outputs-dependency-graph-as-mermaid~1]
+    #[tokio::test]
+    async fn utest_get_graph_as_mermaid() {
+        let mut cmd = cli_commands();
+
+        let expected_output = [
+            "graph TD",
+            "  n0[\"backend<br/>agent_A<br/>Running(Ok)\"]",
+            "  n1[\"cache<br/>#lt;not in desired state#gt;\"]",
+            "  n2[\"database<br/>agent_B<br/>Running(Ok)\"]",
+            "  n3[\"frontend<br/>agent_A<br/>Pending(Initial)\"]",
+            "  n0 -->|ADD_COND_SUCCEEDED| n2",
+            "  n3 -->|ADD_COND_RUNNING| n0",
+            "  n3 -->|ADD_COND_RUNNING| n1",
+        ]
+        .join("\n");
+
+        assert_eq!(
+            cmd.get_graph(GraphOutputFormat::Mermaid).await.unwrap(),
+            expected_output
+        );
+    }
+}
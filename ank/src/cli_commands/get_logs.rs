@@ -151,6 +151,9 @@ mod tests {
             since: None,
             until: None,
             output_names: false,
+            timestamps: false,
+            stdout_only: false,
+            stderr_only: false,
         };
 
         mock_server_connection
@@ -198,6 +201,9 @@ mod tests {
             since: None,
             until: None,
             output_names: false,
+            timestamps: false,
+            stdout_only: false,
+            stderr_only: false,
         };
 
         mock_server_connection.expect_stream_logs().never();
@@ -244,6 +250,9 @@ mod tests {
             since: None,
             until: None,
             output_names: false,
+            timestamps: false,
+            stdout_only: false,
+            stderr_only: false,
         };
 
         mock_server_connection
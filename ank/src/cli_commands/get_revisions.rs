@@ -0,0 +1,233 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use super::CliCommands;
+use crate::{
+    cli_commands::{cli_table::CliTable, revision_table_row::RevisionTableRow},
+    cli_error::CliError,
+    output_debug,
+};
+use api::ank_base;
+use common::helpers::format_unix_timestamp;
+
+const ADDED_PREFIX: &str = "+";
+const UPDATED_PREFIX: &str = "~";
+const DELETED_PREFIX: &str = "-";
+const ROLLED_BACK_PREFIX: &str = "!";
+
+impl CliCommands {
+    // [impl->swdd~cli-provides-list-of-revisions~2]
+    pub async fn get_revisions(&mut self) -> Result<String, CliError> {
+        let revisions = self.server_connection.get_revisions(None).await?;
+
+        output_debug!("Got revisions: {:?}", revisions);
+
+        let table_rows: Vec<RevisionTableRow> = revisions
+            .into_iter()
+            .map(transform_into_table_row)
+            .collect();
+
+        Ok(CliTable::new(&table_rows)
+            .table_with_wrapped_column_to_remaining_terminal_width(RevisionTableRow::CHANGES_POS)
+            .unwrap_or_else(|_err| CliTable::new(&table_rows).create_default_table()))
+    }
+
+    // [impl->swdd~cli-provides-single-revision~1]
+    pub async fn get_revision(&mut self, revision: u64) -> Result<String, CliError> {
+        let revision = self.fetch_revision(revision).await?;
+
+        output_debug!("Got revision: {:?}", revision);
+
+        Ok(serde_yaml::to_string(&revision)?)
+    }
+
+    pub(crate) async fn fetch_revision(
+        &mut self,
+        revision: u64,
+    ) -> Result<ank_base::Revision, CliError> {
+        self.server_connection
+            .get_revisions(Some(revision))
+            .await?
+            .into_iter()
+            .find(|received_revision| received_revision.revision == revision)
+            .ok_or_else(|| {
+                CliError::ExecutionError(format!("Revision '{revision}' was not provided"))
+            })
+    }
+}
+
+fn transform_into_table_row(revision: ank_base::Revision) -> RevisionTableRow {
+    let changes: Vec<String> = revision
+        .added_workloads
+        .iter()
+        .map(|name| format!("{ADDED_PREFIX}{name}"))
+        .chain(
+            revision
+                .updated_workloads
+                .iter()
+                .map(|name| format!("{UPDATED_PREFIX}{name}")),
+        )
+        .chain(
+            revision
+                .deleted_workloads
+                .iter()
+                .map(|name| format!("{DELETED_PREFIX}{name}")),
+        )
+        .chain(
+            revision
+                .rolled_back_workloads
+                .iter()
+                .map(|name| format!("{ROLLED_BACK_PREFIX}{name}")),
+        )
+        .collect();
+
+    RevisionTableRow {
+        revision: revision.revision,
+        created: format_unix_timestamp(revision.timestamp),
+        requester: revision.requester,
+        update_mask: revision.update_mask.join(", "),
+        changes: changes.join(", "),
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use crate::cli_commands::{
+        CliCommands,
+        server_connection::{MockServerConnection, ServerConnectionError},
+    };
+
+    use api::ank_base;
+    use mockall::predicate::eq;
+
+    const RESPONSE_TIMEOUT_MS: u64 = 3000;
+
+    fn test_revisions() -> Vec<ank_base::Revision> {
+        vec![
+            ank_base::Revision {
+                revision: 1,
+                timestamp: 0,
+                requester: "startup".to_string(),
+                added_workloads: vec!["nginx".to_string()],
+                rolled_back_workloads: vec!["nginx".to_string()],
+                ..Default::default()
+            },
+            ank_base::Revision {
+                revision: 2,
+                timestamp: 60,
+                requester: "cli".to_string(),
+                update_mask: vec!["desiredState".to_string()],
+                added_workloads: vec!["db".to_string()],
+                updated_workloads: vec!["nginx".to_string()],
+                deleted_workloads: vec!["old".to_string()],
+                ..Default::default()
+            },
+        ]
+    }
+
+    // [utest->swdd~cli-provides-list-of-revisions~2]
+    #[tokio::test]
+    async fn utest_get_revisions() {
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_revisions()
+            .with(eq(None))
+            .return_once(|_| Ok(test_revisions()));
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        let table_output = cmd.get_revisions().await.unwrap();
+
+        let expected_table_output = [
+            "REVISION   CREATED                REQUESTER   UPDATE MASK    CHANGES          ",
+            "1          1970-01-01T00:00:00Z   startup                    +nginx, !nginx   ",
+            "2          1970-01-01T00:01:00Z   cli         desiredState   +db, ~nginx, -old",
+        ]
+        .join("\n");
+
+        assert_eq!(table_output, expected_table_output);
+    }
+
+    // [utest->swdd~cli-provides-list-of-revisions~2]
+    #[tokio::test]
+    async fn utest_get_revisions_fails() {
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_revisions()
+            .return_once(|_| {
+                Err(ServerConnectionError::ExecutionError(
+                    "connection error".to_string(),
+                ))
+            });
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        assert!(cmd.get_revisions().await.is_err());
+    }
+
+    // [utest->swdd~cli-provides-single-revision~1]
+    #[tokio::test]
+    async fn utest_get_revision() {
+        let revision = test_revisions().remove(1);
+        let expected_output = serde_yaml::to_string(&revision).unwrap();
+
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_revisions()
+            .with(eq(Some(2)))
+            .return_once(|_| Ok(vec![revision]));
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        assert_eq!(cmd.get_revision(2).await, Ok(expected_output));
+    }
+
+    // [utest->swdd~cli-provides-single-revision~1]
+    #[tokio::test]
+    async fn utest_get_revision_not_provided() {
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_revisions()
+            .with(eq(Some(3)))
+            .return_once(|_| Ok(vec![]));
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        assert!(cmd.get_revision(3).await.is_err());
+    }
+}
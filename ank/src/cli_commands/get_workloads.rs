@@ -17,8 +17,9 @@ use crate::{cli_error::CliError, output_debug};
 use crate::output_update;
 
 use super::cli_table::CliTable;
-use super::workload_table_row::WorkloadTableRow;
+use super::workload_table_row::{WideWorkloadTableRow, WorkloadTableRow};
 use super::{CliCommands, WorkloadInfos};
+use crate::cli::WorkloadsOutputFormat;
 use common::commands::UpdateWorkloadState;
 
 use std::collections::BTreeMap;
@@ -34,6 +35,7 @@ impl CliCommands {
     // [impl->swdd~cli-provides-list-of-workloads~1]
     pub async fn get_workloads_table(
         &mut self,
+        output_format: WorkloadsOutputFormat,
         agent_name: Option<String>,
         state: Option<String>,
         workload_name: Vec<String>,
@@ -44,18 +46,13 @@ impl CliCommands {
         let table_rows: Vec<WorkloadTableRow> = workload_infos.into_iter().map(|x| x.1).collect();
 
         // [impl->swdd~cli-shall-present-workloads-as-table~1]
-        Ok(CliTable::new(&table_rows)
-            .table_with_wrapped_column_to_remaining_terminal_width(
-                WorkloadTableRow::ADDITIONAL_INFO_POS,
-            )
-            .unwrap_or_else(|_err| CliTable::new(&table_rows).create_default_table()))
-
-
+        Ok(create_workloads_table(table_rows.iter(), output_format))
     }
 
     // [impl->swdd~cli-get-workloads-with-watch~1]
     pub async fn watch_workloads(
         &mut self,
+        output_format: WorkloadsOutputFormat,
         agent_name: Option<String>,
         state: Option<String>,
         workload_name: Vec<String>,
@@ -68,7 +65,7 @@ impl CliCommands {
             .map(|(i_name, row)| (i_name.to_string(), row))
             .collect();
 
-        update_table(&workloads_table_data);
+        update_table(&workloads_table_data, output_format);
 
         loop {
             let update = self.server_connection.read_next_update_workload_state().await?;
@@ -82,7 +79,7 @@ impl CliCommands {
                 workloads_table_data,
             ).await?;
 
-            update_table(&workloads_table_data);
+            update_table(&workloads_table_data, output_format);
         }
     }
 
@@ -101,8 +98,8 @@ impl CliCommands {
         workload_infos.get_mut().retain(|wi| check_workload_filters(&wi.1, agent_name, state, workload_names));
 
         // The order of workloads in RequestCompleteState is not sable -> make sure that the user sees always the same order.
-        // [impl->swdd~cli-shall-sort-list-of-workloads~1]
-        workload_infos.get_mut().sort_by_key(|wi| wi.1.name.clone());
+        // [impl->swdd~cli-shall-sort-list-of-workloads~2]
+        workload_infos.get_mut().sort_by_key(|wi| (wi.1.name.clone(), wi.1.agent.clone()));
 
         output_debug!("The table after filtering:\n{:?}", workload_infos);
 
@@ -159,18 +156,46 @@ use {
 
 
 #[cfg(test)]
-fn update_table(table_data: &BTreeMap<String, WorkloadTableRow>) {
+fn update_table(
+    table_data: &BTreeMap<String, WorkloadTableRow>,
+    _output_format: WorkloadsOutputFormat,
+) {
         let mut test_out = TEST_TABLE_OUTPUT_DATA.lock().unwrap();
         *test_out = table_data.clone();
 }
 
 #[cfg(not(test))]
-fn update_table(table_data: &BTreeMap<String, WorkloadTableRow>) {
-        let rows: Vec<&WorkloadTableRow> = table_data.values().collect();
-        let table = CliTable::new(&rows)
-        .table_with_wrapped_column_to_remaining_terminal_width(WorkloadTableRow::ADDITIONAL_INFO_POS)
-        .unwrap_or_else(|_| CliTable::new(&rows).create_default_table());
-        output_update!("{}", table);
+fn update_table(
+    table_data: &BTreeMap<String, WorkloadTableRow>,
+    output_format: WorkloadsOutputFormat,
+) {
+        output_update!("{}", create_workloads_table(table_data.values(), output_format));
+}
+
+// [impl->swdd~cli-get-workloads-wide-output-shows-resource-limits~1]
+fn create_workloads_table<'a>(
+    rows: impl Iterator<Item = &'a WorkloadTableRow>,
+    output_format: WorkloadsOutputFormat,
+) -> String {
+    match output_format {
+        WorkloadsOutputFormat::Table => {
+            let rows: Vec<&WorkloadTableRow> = rows.collect();
+            CliTable::new(&rows)
+                .table_with_wrapped_column_to_remaining_terminal_width(
+                    WorkloadTableRow::ADDITIONAL_INFO_POS,
+                )
+                .unwrap_or_else(|_| CliTable::new(&rows).create_default_table())
+        }
+        WorkloadsOutputFormat::Wide => {
+            let rows: Vec<WideWorkloadTableRow> =
+                rows.map(|data| WideWorkloadTableRow { data }).collect();
+            CliTable::new(&rows)
+                .table_with_wrapped_column_to_remaining_terminal_width(
+                    WideWorkloadTableRow::ADDITIONAL_INFO_POS,
+                )
+                .unwrap_or_else(|_| CliTable::new(&rows).create_default_table())
+        }
+    }
 }
 
 // [impl->swdd~cli-shall-filter-list-of-workloads~1]
@@ -222,6 +247,7 @@ mod tests {
         test_utils,
     };
     use mockall::{predicate::eq, Sequence};
+    use crate::cli::WorkloadsOutputFormat;
     use crate::cli_commands::{
         get_workloads::TEST_TABLE_OUTPUT_DATA,
         server_connection::{MockServerConnection, ServerConnectionError},
@@ -252,7 +278,7 @@ mod tests {
             server_connection: mock_server_connection,
         };
 
-        let cmd_text = cmd.get_workloads_table(None, None, Vec::new()).await;
+        let cmd_text = cmd.get_workloads_table(WorkloadsOutputFormat::Table, None, None, Vec::new()).await;
         assert!(cmd_text.is_ok());
 
         let expected_table_output =
@@ -266,7 +292,7 @@ mod tests {
     // [utest->swdd~cli-blocks-until-ankaios-server-responds-list-workloads~1]
     // [utest->swdd~cli-shall-present-list-of-workloads~1]
     // [utest->swdd~cli-shall-present-workloads-as-table~1]
-    // [utest->swdd~cli-shall-sort-list-of-workloads~1]
+    // [utest->swdd~cli-shall-sort-list-of-workloads~2]
     #[tokio::test]
     async fn utest_get_workloads_no_filtering() {
         let test_data = test_utils::generate_test_complete_state(vec![
@@ -299,7 +325,7 @@ mod tests {
             server_connection: mock_server_connection,
         };
 
-        let cmd_text = cmd.get_workloads_table(None, None, Vec::new()).await;
+        let cmd_text = cmd.get_workloads_table(WorkloadsOutputFormat::Table, None, None, Vec::new()).await;
         assert!(cmd_text.is_ok());
 
         let expected_table_output = [
@@ -313,6 +339,106 @@ mod tests {
         assert_eq!(cmd_text.unwrap(), expected_table_output);
     }
 
+    // [utest->swdd~cli-get-workloads-wide-output-shows-resource-limits~1]
+    #[tokio::test]
+    async fn utest_get_workloads_wide_output_shows_resource_limits() {
+        let mut workload_with_limits = generate_test_workload_spec_with_param(
+            "agent_A".to_string(),
+            "name1".to_string(),
+            "runtime".to_string(),
+        );
+        workload_with_limits.resources = Some(objects::ResourceLimits {
+            cpu_quota: Some(500),
+            memory_limit: None,
+            pids_limit: Some(64),
+        });
+        let test_data = test_utils::generate_test_complete_state(vec![
+            workload_with_limits,
+            generate_test_workload_spec_with_param(
+                "agent_B".to_string(),
+                "name2".to_string(),
+                "runtime".to_string(),
+            ),
+        ]);
+
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_complete_state()
+            .with(eq(vec![]))
+            .return_once(|_| Ok((ank_base::CompleteState::from(test_data)).into()));
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        let cmd_text = cmd
+            .get_workloads_table(WorkloadsOutputFormat::Wide, None, None, Vec::new())
+            .await;
+        assert!(cmd_text.is_ok());
+
+        let expected_table_output = [
+            "WORKLOAD NAME   AGENT     RUNTIME   EXECUTION STATE   RESOURCES            ADDIT",
+            "name1           agent_A   runtime   Running(Ok)       cpus=0.500 pids=64        ",
+            "name2           agent_B   runtime   Running(Ok)                                 ",
+        ]
+        .join("\n");
+
+        assert_eq!(cmd_text.unwrap(), expected_table_output);
+    }
+
+    // [utest->swdd~cli-shall-sort-list-of-workloads~2]
+    // [utest->swdd~processes-complete-state-to-list-workloads~1]
+    #[tokio::test]
+    async fn utest_get_workloads_groups_replicas() {
+        let mut test_data = test_utils::generate_test_complete_state(vec![
+            generate_test_workload_spec_with_param(
+                "agent_B".to_string(),
+                "name1".to_string(),
+                "runtime".to_string(),
+            ),
+            generate_test_workload_spec_with_param(
+                "agent_A".to_string(),
+                "name2".to_string(),
+                "runtime".to_string(),
+            ),
+            generate_test_workload_spec_with_param(
+                "agent_A".to_string(),
+                "name1".to_string(),
+                "runtime".to_string(),
+            ),
+        ]);
+        let replicated_workload = test_data.desired_state.workloads.get_mut("name1").unwrap();
+        replicated_workload.agent.clear();
+        replicated_workload.agents = vec!["agent_A".to_string(), "agent_B".to_string()];
+
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_complete_state()
+            .with(eq(vec![]))
+            .return_once(|_| Ok((ank_base::CompleteState::from(test_data)).into()));
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        let cmd_text = cmd.get_workloads_table(WorkloadsOutputFormat::Table, None, None, Vec::new()).await;
+        assert!(cmd_text.is_ok());
+
+        let expected_table_output = [
+            "WORKLOAD NAME   AGENT     RUNTIME   EXECUTION STATE   ADDITIONAL INFO",
+            "name1           agent_A   runtime   Running(Ok)                      ",
+            "name1           agent_B   runtime   Running(Ok)                      ",
+            "name2           agent_A   runtime   Running(Ok)                      ",
+        ]
+        .join("\n");
+
+        assert_eq!(cmd_text.unwrap(), expected_table_output);
+    }
+
     // [utest->swdd~cli-shall-filter-list-of-workloads~1]
     #[tokio::test]
     async fn utest_get_workloads_filter_workload_name() {
@@ -346,7 +472,7 @@ mod tests {
         };
 
         let cmd_text = cmd
-            .get_workloads_table(None, None, vec!["name1".to_string()])
+            .get_workloads_table(WorkloadsOutputFormat::Table, None, None, vec!["name1".to_string()])
             .await;
         assert!(cmd_text.is_ok());
 
@@ -391,7 +517,12 @@ mod tests {
             server_connection: mock_server_connection,
         };
         let cmd_text = cmd
-            .get_workloads_table(Some("agent_B".to_string()), None, Vec::new())
+            .get_workloads_table(
+                WorkloadsOutputFormat::Table,
+                Some("agent_B".to_string()),
+                None,
+                Vec::new(),
+            )
             .await;
         assert!(cmd_text.is_ok());
 
@@ -437,7 +568,12 @@ mod tests {
             server_connection: mock_server_connection,
         };
         let cmd_text = cmd
-            .get_workloads_table(None, Some("Failed".to_string()), Vec::new())
+            .get_workloads_table(
+                WorkloadsOutputFormat::Table,
+                None,
+                Some("Failed".to_string()),
+                Vec::new(),
+            )
             .await;
         assert!(cmd_text.is_ok());
 
@@ -471,7 +607,7 @@ mod tests {
             server_connection: mock_server_connection,
         };
 
-        let cmd_text = cmd.get_workloads_table(None, None, Vec::new()).await;
+        let cmd_text = cmd.get_workloads_table(WorkloadsOutputFormat::Table, None, None, Vec::new()).await;
         assert!(cmd_text.is_ok());
 
         let expected_table_output = [
@@ -503,7 +639,7 @@ mod tests {
              server_connection: mock_server_connection,
          };
 
-         let result = cmd.watch_workloads(None,None,Vec::new(),).await;
+         let result = cmd.watch_workloads(WorkloadsOutputFormat::Table, None,None,Vec::new(),).await;
 
          assert!(result.is_err());
      }
@@ -576,7 +712,7 @@ mod tests {
         };
 
         let result = cmd
-            .watch_workloads(Some("agent_A".to_string()), None, vec![])
+            .watch_workloads(WorkloadsOutputFormat::Table, Some("agent_A".to_string()), None, vec![])
             .await;
         assert!(result.is_err());
 
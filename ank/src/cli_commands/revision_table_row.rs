@@ -0,0 +1,34 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use tabled::Tabled;
+
+#[derive(Debug, Tabled, Clone)]
+#[tabled(rename_all = "UPPERCASE")]
+pub struct RevisionTableRow {
+    #[tabled(rename = "REVISION")]
+    pub revision: u64,
+    #[tabled(rename = "CREATED")]
+    pub created: String,
+    #[tabled(rename = "REQUESTER")]
+    pub requester: String,
+    #[tabled(rename = "UPDATE MASK")]
+    pub update_mask: String,
+    #[tabled(rename = "CHANGES")]
+    pub changes: String,
+}
+
+impl RevisionTableRow {
+    pub const CHANGES_POS: usize = 4;
+}
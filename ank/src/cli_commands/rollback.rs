@@ -0,0 +1,167 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use common::objects::{CompleteState, State};
+
+use crate::{cli_error::CliError, output_debug};
+
+use super::CliCommands;
+
+const DESIRED_STATE: &str = "desiredState";
+
+impl CliCommands {
+    // [impl->swdd~cli-provides-rollback-to-revision~1]
+    pub async fn rollback(&mut self, revision: u64) -> Result<(), CliError> {
+        let revision = self.fetch_revision(revision).await?;
+
+        let desired_state: State = revision
+            .desired_state
+            .ok_or_else(|| {
+                CliError::ExecutionError(format!(
+                    "Revision '{}' does not contain a desired state",
+                    revision.revision
+                ))
+            })?
+            .try_into()
+            .map_err(|err| {
+                CliError::ExecutionError(format!(
+                    "Could not convert the desired state of revision '{}': '{err}'",
+                    revision.revision
+                ))
+            })?;
+
+        output_debug!(
+            "Rolling back to revision '{}' with desired state {:?}",
+            revision.revision,
+            desired_state
+        );
+
+        // The old desired state replaces the complete current one and takes the normal update path,
+        // so the server validates and renders it like any other update.
+        self.update_state_and_wait_for_complete(
+            CompleteState {
+                desired_state,
+                ..Default::default()
+            },
+            vec![DESIRED_STATE.to_string()],
+            None,
+        )
+        .await
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        cli_commands::{CliCommands, server_connection::MockServerConnection},
+        filtered_complete_state::FilteredCompleteState,
+    };
+
+    use api::ank_base::{self, UpdateStateSuccess};
+    use common::objects::{CompleteState, State, generate_test_stored_workload_spec};
+    use mockall::predicate::eq;
+
+    const RESPONSE_TIMEOUT_MS: u64 = 3000;
+    const REVISION: u64 = 3;
+
+    fn revision_desired_state() -> State {
+        State {
+            workloads: [(
+                "nginx".to_string(),
+                generate_test_stored_workload_spec("agent_A", "podman"),
+            )]
+            .into(),
+            ..Default::default()
+        }
+    }
+
+    // [utest->swdd~cli-provides-rollback-to-revision~1]
+    #[tokio::test]
+    async fn utest_rollback_reapplies_desired_state_of_revision() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_revisions()
+            .with(eq(Some(REVISION)))
+            .return_once(|_| {
+                Ok(vec![ank_base::Revision {
+                    revision: REVISION,
+                    desired_state: Some(revision_desired_state().into()),
+                    ..Default::default()
+                }])
+            });
+        mock_server_connection
+            .expect_get_complete_state()
+            .once()
+            .returning(|_| Ok(FilteredCompleteState::default()));
+        mock_server_connection
+            .expect_update_state()
+            .with(
+                eq(CompleteState {
+                    desired_state: revision_desired_state(),
+                    ..Default::default()
+                }),
+                eq(vec!["desiredState".to_string()]),
+                eq(None),
+            )
+            .return_once(|_, _, _| {
+                Ok(UpdateStateSuccess {
+                    added_workloads: vec![],
+                    deleted_workloads: vec![],
+                })
+            });
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        assert!(cmd.rollback(REVISION).await.is_ok());
+    }
+
+    // [utest->swdd~cli-provides-rollback-to-revision~1]
+    #[tokio::test]
+    async fn utest_rollback_fails_on_revision_without_desired_state() {
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_revisions()
+            .with(eq(Some(REVISION)))
+            .return_once(|_| {
+                Ok(vec![ank_base::Revision {
+                    revision: REVISION,
+                    ..Default::default()
+                }])
+            });
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        assert!(cmd.rollback(REVISION).await.is_err());
+    }
+}
@@ -21,7 +21,10 @@ use std::{collections::BTreeSet, mem::take, time::Duration};
 
 use api::ank_base::{self, LogsRequestAccepted};
 use common::{
-    commands::{CompleteStateRequest, LogsRequest, UpdateWorkloadState},
+    commands::{
+        CompleteStateRequest, EventsSubscribeRequest, LogsRequest, RevisionsRequest,
+        UpdateWorkloadState,
+    },
     communications_client::CommunicationsClient,
     communications_error::CommunicationMiddlewareError,
     from_server_interface::{FromServer, FromServerReceiver},
@@ -147,14 +150,43 @@ impl ServerConnection {
         &mut self,
         new_state: CompleteState,
         update_mask: Vec<String>,
+        expected_revision: Option<u64>,
     ) -> Result<ank_base::UpdateStateSuccess, ServerConnectionError> {
         let request_id = uuid::Uuid::new_v4().to_string();
         output_debug!("Sending the new state {:?}", new_state);
         self.to_server
-            .update_state(request_id.clone(), new_state, update_mask)
+            .update_state(
+                request_id.clone(),
+                new_state,
+                update_mask,
+                expected_revision,
+            )
+            .await
+            .map_err(|err| ServerConnectionError::ExecutionError(err.to_string()))?;
+
+        self.wait_for_update_state_success(request_id).await
+    }
+
+    // [impl->swdd~cli-requests-dry-run-update-state~1]
+    pub async fn dry_run_update_state(
+        &mut self,
+        new_state: CompleteState,
+        update_mask: Vec<String>,
+    ) -> Result<ank_base::UpdateStateSuccess, ServerConnectionError> {
+        let request_id = uuid::Uuid::new_v4().to_string();
+        output_debug!("Sending the new state {:?} for a dry run", new_state);
+        self.to_server
+            .dry_run_update_state(request_id.clone(), new_state, update_mask, None)
             .await
             .map_err(|err| ServerConnectionError::ExecutionError(err.to_string()))?;
 
+        self.wait_for_update_state_success(request_id).await
+    }
+
+    async fn wait_for_update_state_success(
+        &mut self,
+        request_id: String,
+    ) -> Result<ank_base::UpdateStateSuccess, ServerConnectionError> {
         let poll_update_state_success = async {
             loop {
                 let Some(server_message) = self.from_server.recv().await else {
@@ -175,10 +207,12 @@ impl ServerConnection {
                         request_id: received_request_id,
                         response_content: Some(ank_base::response::ResponseContent::Error(error)),
                     }) if received_request_id == request_id => {
-                        return Err(ServerConnectionError::ExecutionError(format!(
-                            "SetState failed with: '{}'",
-                            error.message
-                        )));
+                        let message = format!("SetState failed with: '{}'", error.message);
+                        // [impl->swdd~cli-reports-update-state-conflict~1]
+                        if error.code() == ank_base::ErrorCode::Conflict {
+                            return Err(ServerConnectionError::Conflict(message));
+                        }
+                        return Err(ServerConnectionError::ExecutionError(message));
                     }
                     message => {
                         // [impl->swdd~cli-stores-unexpected-message~1]
@@ -202,6 +236,57 @@ impl ServerConnection {
         }
     }
 
+    // [impl->swdd~cli-requests-revisions~1]
+    pub async fn get_revisions(
+        &mut self,
+        revision: Option<u64>,
+    ) -> Result<Vec<ank_base::Revision>, ServerConnectionError> {
+        output_debug!("get_revisions: revision={:?} ", revision);
+
+        let request_id = uuid::Uuid::new_v4().to_string();
+
+        self.to_server
+            .revisions_request(request_id.to_owned(), RevisionsRequest { revision })
+            .await
+            .map_err(|err| ServerConnectionError::ExecutionError(err.to_string()))?;
+
+        let poll_revisions_response = async {
+            loop {
+                let Some(server_message) = self.from_server.recv().await else {
+                    return Err(ServerConnectionError::ExecutionError(
+                        "Connection to server interrupted".into(),
+                    ));
+                };
+                match server_message {
+                    FromServer::Response(ank_base::Response {
+                        request_id: received_request_id,
+                        response_content:
+                            Some(ank_base::response::ResponseContent::Revisions(revisions)),
+                    }) if received_request_id == request_id => return Ok(revisions.revisions),
+                    FromServer::Response(ank_base::Response {
+                        request_id: received_request_id,
+                        response_content: Some(ank_base::response::ResponseContent::Error(error)),
+                    }) if received_request_id == request_id => {
+                        return Err(ServerConnectionError::ExecutionError(format!(
+                            "Failed to get revisions: '{}'",
+                            error.message
+                        )));
+                    }
+                    message => {
+                        // [impl->swdd~cli-stores-unexpected-message~1]
+                        self.missed_from_server_messages.push(message);
+                    }
+                }
+            }
+        };
+        match tokio::time::timeout(WAIT_TIME_MS, poll_revisions_response).await {
+            Ok(result) => result,
+            Err(_) => Err(ServerConnectionError::ExecutionError(format!(
+                "Failed to get revisions in time (timeout={WAIT_TIME_MS:?})."
+            ))),
+        }
+    }
+
     pub async fn read_next_update_workload_state(
         &mut self,
     ) -> Result<UpdateWorkloadState, ServerConnectionError> {
@@ -226,6 +311,72 @@ impl ServerConnection {
         take(&mut self.missed_from_server_messages)
     }
 
+    // [impl->swdd~cli-subscribes-for-events~3]
+    pub async fn subscribe_events(
+        &mut self,
+        field_masks: Vec<String>,
+    ) -> Result<String, ServerConnectionError> {
+        output_debug!("subscribe_events: field_masks={:?} ", field_masks);
+
+        let request_id = uuid::Uuid::new_v4().to_string();
+
+        self.to_server
+            .events_subscribe_request(
+                request_id.to_owned(),
+                EventsSubscribeRequest { field_masks },
+            )
+            .await
+            .map_err(|err| ServerConnectionError::ExecutionError(err.to_string()))?;
+
+        Ok(request_id)
+    }
+
+    // [impl->swdd~cli-subscribes-for-events~3]
+    // [impl->swdd~cli-sends-events-cancel-request-upon-termination~1]
+    pub async fn read_next_event(
+        &mut self,
+        request_id: &str,
+    ) -> Result<Option<ank_base::EventResponse>, ServerConnectionError> {
+        loop {
+            tokio::select! {
+                _ = SignalHandler::wait_for_signals() => {
+                    self.to_server
+                        .events_cancel_request(request_id.to_owned()).await
+                        .map_err(|err| ServerConnectionError::ExecutionError(err.to_string()))?;
+
+                    output_debug!("EventsCancelRequest sent after receiving signal to stop.");
+                    break Ok(None);
+                }
+                server_message = self.from_server.recv() => {
+                    match server_message {
+                        Some(FromServer::Response(ank_base::Response {
+                            request_id: received_request_id,
+                            response_content:
+                                Some(ank_base::response::ResponseContent::EventResponse(event)),
+                        })) if received_request_id == request_id => break Ok(Some(event)),
+                        Some(FromServer::Response(ank_base::Response {
+                            request_id: received_request_id,
+                            response_content: Some(ank_base::response::ResponseContent::Error(error)),
+                        })) if received_request_id == request_id => {
+                            break Err(ServerConnectionError::ExecutionError(format!(
+                                "Server replied with error: '{}'",
+                                error.message
+                            )));
+                        }
+                        Some(unexpected_message) => {
+                            output_debug!("Ignore received unexpected message while waiting for events: {unexpected_message:?}");
+                            /* The unexpected message is not added to the queue of missed messages,
+                            because the subscription already delivers all changes of the complete state. */
+                        }
+                        None => break Err(ServerConnectionError::ExecutionError(
+                            "Connection to server interrupted while waiting for events.".to_string(),
+                        )),
+                    }
+                }
+            }
+        }
+    }
+
     // [impl->swdd~cli-streams-logs-from-the-server~1]
     pub async fn stream_logs(
         &mut self,
@@ -234,7 +385,7 @@ impl ServerConnection {
     ) -> Result<(), ServerConnectionError> {
         let request_id = uuid::Uuid::new_v4().to_string();
 
-        let output_workload_names = args.output_names;
+        let log_output_format = select_log_output_format(&instance_names, &args);
 
         self.send_logs_request_for_workloads(
             &request_id,
@@ -251,9 +402,7 @@ impl ServerConnection {
             logs_request_accepted_response.workload_names,
         )?;
 
-        let output_logs_fn = select_log_format_function(&instance_names, output_workload_names);
-
-        self.listen_for_workload_logs(request_id, instance_names, output_logs_fn)
+        self.listen_for_workload_logs(request_id, instance_names, log_output_format)
             .await
     }
 
@@ -359,7 +508,7 @@ impl ServerConnection {
         &mut self,
         request_id: String,
         mut instance_names: BTreeSet<WorkloadInstanceName>,
-        output_log_format_function: fn(Vec<ank_base::LogEntry>),
+        log_output_format: LogOutputFormat,
     ) -> Result<(), ServerConnectionError> {
         loop {
             tokio::select! {
@@ -381,7 +530,7 @@ impl ServerConnection {
 
                     match handle_server_log_response(&request_id, server_message)? {
                         LogStreamingState::Output(log_entries) => {
-                            output_log_format_function(log_entries.log_entries);
+                            log_output_format.output(log_entries.log_entries);
                         }
                         LogStreamingState::Continue => continue,
                         // [impl->swdd~cli-stops-log-output-for-specific-workloads~1]
@@ -451,14 +600,23 @@ fn handle_server_log_response(
 }
 
 // [impl->swdd~cli-outputs-logs-in-specific-format~1]
-fn select_log_format_function(
+// [impl->swdd~cli-outputs-log-timestamps-and-filters-streams~1]
+fn select_log_output_format(
     instance_names: &BTreeSet<WorkloadInstanceName>,
-    force_output_names: bool,
-) -> fn(Vec<ank_base::LogEntry>) {
-    if is_output_with_workload_names(instance_names, force_output_names) {
-        output_logs_with_workload_names
+    args: &LogsArgs,
+) -> LogOutputFormat {
+    let stream = if args.stdout_only {
+        Some(ank_base::LogStream::Stdout)
+    } else if args.stderr_only {
+        Some(ank_base::LogStream::Stderr)
     } else {
-        output_logs_without_workload_names
+        None
+    };
+
+    LogOutputFormat {
+        workload_names: is_output_with_workload_names(instance_names, args.output_names),
+        timestamps: args.timestamps,
+        stream,
     }
 }
 
@@ -478,28 +636,49 @@ enum LogStreamingState {
 #[derive(Debug, PartialEq)]
 pub enum ServerConnectionError {
     ExecutionError(String),
+    Conflict(String),
 }
 
-// [impl->swdd~cli-outputs-logs-in-specific-format~1]
-fn output_logs_with_workload_names(log_entries: Vec<ank_base::LogEntry>) {
-    log_entries.iter().for_each(|log_entry| {
-        let workload_instance_name = log_entry.workload_name.as_ref().unwrap_or_else(|| {
-            crate::output_and_error!(
-                "Failed to output log: workload name is not available inside log entry."
-            )
-        });
-
-        let workload_name = workload_instance_name.workload_name.as_str();
-        let formatted_log = format!("{} {}\n", workload_name, log_entry.message);
-        print_log(&formatted_log);
-    });
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct LogOutputFormat {
+    workload_names: bool,
+    timestamps: bool,
+    stream: Option<ank_base::LogStream>,
 }
 
-// [impl->swdd~cli-outputs-logs-in-specific-format~1]
-fn output_logs_without_workload_names(log_entries: Vec<ank_base::LogEntry>) {
-    log_entries.iter().for_each(|log_entry| {
-        print_log(&format!("{}\n", log_entry.message));
-    });
+impl LogOutputFormat {
+    // [impl->swdd~cli-outputs-logs-in-specific-format~1]
+    // [impl->swdd~cli-outputs-log-timestamps-and-filters-streams~1]
+    fn output(&self, log_entries: Vec<ank_base::LogEntry>) {
+        log_entries
+            .iter()
+            .filter(|log_entry| {
+                self.stream
+                    .is_none_or(|stream| log_entry.stream() == stream)
+            })
+            .for_each(|log_entry| print_log(&self.format(log_entry)));
+    }
+
+    fn format(&self, log_entry: &ank_base::LogEntry) -> String {
+        let mut formatted_log = String::new();
+        if self.workload_names {
+            let workload_instance_name = log_entry.workload_name.as_ref().unwrap_or_else(|| {
+                crate::output_and_error!(
+                    "Failed to output log: workload name is not available inside log entry."
+                )
+            });
+            formatted_log.push_str(&workload_instance_name.workload_name);
+            formatted_log.push(' ');
+        }
+        // log entries of older agents do not contain a timestamp
+        if self.timestamps && !log_entry.timestamp.is_empty() {
+            formatted_log.push_str(&log_entry.timestamp);
+            formatted_log.push(' ');
+        }
+        formatted_log.push_str(&log_entry.message);
+        formatted_log.push('\n');
+        formatted_log
+    }
 }
 
 #[cfg(not(test))]
@@ -556,7 +735,7 @@ mod tests {
     use crate::{
         cli::LogsArgs,
         cli_commands::server_connection::{
-            ServerConnectionError, TEST_LOG_OUTPUT_DATA, select_log_format_function,
+            ServerConnectionError, TEST_LOG_OUTPUT_DATA, select_log_output_format,
         },
         cli_signals::MockSignalHandler,
         test_helper::MOCKALL_CONTEXT_SYNC,
@@ -564,7 +743,10 @@ mod tests {
 
     use super::ank_base::{self, UpdateStateSuccess};
     use common::{
-        commands::{CompleteStateRequest, RequestContent, UpdateStateRequest, UpdateWorkloadState},
+        commands::{
+            CompleteStateRequest, EventsSubscribeRequest, Request, RequestContent,
+            RevisionsRequest, UpdateStateRequest, UpdateWorkloadState,
+        },
         from_server_interface::FromServer,
         objects::{
             CompleteState, ExecutionState, State, StoredWorkloadSpec, WorkloadInstanceName,
@@ -584,6 +766,7 @@ mod tests {
     const REQUEST: &str = "request";
     const OTHER_REQUEST: &str = "other_request";
     const FIELD_MASK: &str = "field_mask";
+    const EXPECTED_REVISION: u64 = 42;
     const ID: &str = "id";
 
     #[derive(Default)]
@@ -759,6 +942,14 @@ mod tests {
                             .into(),
                         }),
                         files: Some(generate_test_proto_workload_files()),
+                        agent_selector: None,
+                        resource_requests: None,
+                        agents: None,
+                        update_strategy: None,
+                        probes: None,
+                        restart_backoff: None,
+                        dependency_timeouts: None,
+                        resources: None,
                     },
                 )]),
             ),
@@ -791,6 +982,14 @@ mod tests {
                         .into()
                     }),
                     files: Some(generate_test_proto_workload_files()),
+                    agent_selector: None,
+                    resource_requests: None,
+                    agents: None,
+                    update_strategy: None,
+                    probes: None,
+                    restart_backoff: None,
+                    dependency_timeouts: None,
+                    resources: None,
                 },
             )])
             .into())
@@ -855,6 +1054,14 @@ mod tests {
                             .into(),
                         }),
                         files: Some(generate_test_proto_workload_files()),
+                        agent_selector: None,
+                        resource_requests: None,
+                        agents: None,
+                        update_strategy: None,
+                        probes: None,
+                        restart_backoff: None,
+                        dependency_timeouts: None,
+                        resources: None,
                     },
                 )]),
             )),
@@ -891,6 +1098,14 @@ mod tests {
                             .into(),
                         }),
                         files: Some(generate_test_proto_workload_files()),
+                        agent_selector: None,
+                        resource_requests: None,
+                        agents: None,
+                        update_strategy: None,
+                        probes: None,
+                        restart_backoff: None,
+                        dependency_timeouts: None,
+                        resources: None,
                     },
                 )]),
             ),
@@ -923,6 +1138,14 @@ mod tests {
                         .into()
                     }),
                     files: Some(generate_test_proto_workload_files()),
+                    agent_selector: None,
+                    resource_requests: None,
+                    agents: None,
+                    update_strategy: None,
+                    probes: None,
+                    restart_backoff: None,
+                    dependency_timeouts: None,
+                    resources: None,
                 },
             )])
             .into())
@@ -972,6 +1195,14 @@ mod tests {
                             .into(),
                         }),
                         files: Some(generate_test_proto_workload_files()),
+                        agent_selector: None,
+                        resource_requests: None,
+                        agents: None,
+                        update_strategy: None,
+                        probes: None,
+                        restart_backoff: None,
+                        dependency_timeouts: None,
+                        resources: None,
                     },
                 )]),
             ),
@@ -1004,6 +1235,14 @@ mod tests {
                         .into()
                     }),
                     files: Some(generate_test_proto_workload_files()),
+                    agent_selector: None,
+                    resource_requests: None,
+                    agents: None,
+                    update_strategy: None,
+                    probes: None,
+                    restart_backoff: None,
+                    dependency_timeouts: None,
+                    resources: None,
                 },
             )])
             .into())
@@ -1028,6 +1267,8 @@ mod tests {
             RequestContent::UpdateStateRequest(Box::new(UpdateStateRequest {
                 state: complete_state(WORKLOAD_NAME_1),
                 update_mask: vec![FIELD_MASK.into()],
+                dry_run: false,
+                expected_revision: None,
             })),
         );
         sim.will_send_response(
@@ -1037,7 +1278,11 @@ mod tests {
         let (checker, mut server_connection) = sim.create_server_connection();
 
         let result = server_connection
-            .update_state(complete_state(WORKLOAD_NAME_1), vec![FIELD_MASK.into()])
+            .update_state(
+                complete_state(WORKLOAD_NAME_1),
+                vec![FIELD_MASK.into()],
+                None,
+            )
             .await;
 
         assert!(result.is_ok());
@@ -1045,6 +1290,102 @@ mod tests {
         checker.check_communication();
     }
 
+    // [utest->swdd~cli-reports-update-state-conflict~1]
+    #[tokio::test]
+    async fn utest_update_state_fails_on_conflict() {
+        let mut sim = CommunicationSimulator::default();
+        sim.expect_receive_request(
+            REQUEST,
+            RequestContent::UpdateStateRequest(Box::new(UpdateStateRequest {
+                state: complete_state(WORKLOAD_NAME_1),
+                update_mask: vec![FIELD_MASK.into()],
+                dry_run: false,
+                expected_revision: Some(EXPECTED_REVISION),
+            })),
+        );
+        sim.will_send_response(
+            REQUEST,
+            ank_base::response::ResponseContent::Error(ank_base::Error {
+                message: "conflict".into(),
+                code: ank_base::ErrorCode::Conflict.into(),
+            }),
+        );
+        let (checker, mut server_connection) = sim.create_server_connection();
+
+        let result = server_connection
+            .update_state(
+                complete_state(WORKLOAD_NAME_1),
+                vec![FIELD_MASK.into()],
+                Some(EXPECTED_REVISION),
+            )
+            .await;
+
+        assert!(matches!(result, Err(ServerConnectionError::Conflict(_))));
+        checker.check_communication();
+    }
+
+    // [utest->swdd~cli-requests-dry-run-update-state~1]
+    #[tokio::test]
+    async fn utest_dry_run_update_state() {
+        let update_state_success = UpdateStateSuccess {
+            added_workloads: vec![WORKLOAD_NAME_1.into()],
+            deleted_workloads: vec![],
+        };
+        let mut sim = CommunicationSimulator::default();
+        sim.expect_receive_request(
+            REQUEST,
+            RequestContent::UpdateStateRequest(Box::new(UpdateStateRequest {
+                state: complete_state(WORKLOAD_NAME_1),
+                update_mask: vec![FIELD_MASK.into()],
+                dry_run: true,
+                expected_revision: None,
+            })),
+        );
+        sim.will_send_response(
+            REQUEST,
+            ank_base::response::ResponseContent::UpdateStateSuccess(update_state_success.clone()),
+        );
+        let (checker, mut server_connection) = sim.create_server_connection();
+
+        let result = server_connection
+            .dry_run_update_state(complete_state(WORKLOAD_NAME_1), vec![FIELD_MASK.into()])
+            .await;
+
+        assert_eq!(result.unwrap(), update_state_success);
+        checker.check_communication();
+    }
+
+    // [utest->swdd~cli-requests-dry-run-update-state~1]
+    #[tokio::test]
+    async fn utest_dry_run_update_state_fails_error_response() {
+        let mut sim = CommunicationSimulator::default();
+        sim.expect_receive_request(
+            REQUEST,
+            RequestContent::UpdateStateRequest(Box::new(UpdateStateRequest {
+                state: complete_state(WORKLOAD_NAME_1),
+                update_mask: vec![FIELD_MASK.into()],
+                dry_run: true,
+                expected_revision: None,
+            })),
+        );
+        sim.will_send_response(
+            REQUEST,
+            ank_base::response::ResponseContent::Error(ank_base::Error {
+                message: "".into(),
+                ..Default::default()
+            }),
+        );
+
+        let (checker, mut server_connection) = sim.create_server_connection();
+
+        let result = server_connection
+            .dry_run_update_state(complete_state(WORKLOAD_NAME_1), vec![FIELD_MASK.into()])
+            .await;
+
+        assert!(result.is_err());
+        checker.check_communication();
+    }
+
     #[tokio::test]
     async fn utest_update_state_fails_at_request() {
         let sim = CommunicationSimulator::default();
@@ -1054,7 +1395,11 @@ mod tests {
         server_connection.to_server = to_server;
 
         let result = server_connection
-            .update_state(complete_state(WORKLOAD_NAME_1), vec![FIELD_MASK.into()])
+            .update_state(
+                complete_state(WORKLOAD_NAME_1),
+                vec![FIELD_MASK.into()],
+                None,
+            )
             .await;
 
         assert!(result.is_err());
@@ -1068,13 +1413,19 @@ mod tests {
             RequestContent::UpdateStateRequest(Box::new(UpdateStateRequest {
                 state: complete_state(WORKLOAD_NAME_1),
                 update_mask: vec![FIELD_MASK.into()],
+                dry_run: false,
+                expected_revision: None,
             })),
         );
 
         let (_, mut server_connection) = sim.create_server_connection();
 
         let result = server_connection
-            .update_state(complete_state(WORKLOAD_NAME_1), vec![FIELD_MASK.into()])
+            .update_state(
+                complete_state(WORKLOAD_NAME_1),
+                vec![FIELD_MASK.into()],
+                None,
+            )
             .await;
 
         assert!(result.is_err());
@@ -1088,17 +1439,26 @@ mod tests {
             RequestContent::UpdateStateRequest(Box::new(UpdateStateRequest {
                 state: complete_state(WORKLOAD_NAME_1),
                 update_mask: vec![FIELD_MASK.into()],
+                dry_run: false,
+                expected_revision: None,
             })),
         );
         sim.will_send_response(
             REQUEST,
-            ank_base::response::ResponseContent::Error(ank_base::Error { message: "".into() }),
+            ank_base::response::ResponseContent::Error(ank_base::Error {
+                message: "".into(),
+                ..Default::default()
+            }),
         );
 
         let (checker, mut server_connection) = sim.create_server_connection();
 
         let result = server_connection
-            .update_state(complete_state(WORKLOAD_NAME_1), vec![FIELD_MASK.into()])
+            .update_state(
+                complete_state(WORKLOAD_NAME_1),
+                vec![FIELD_MASK.into()],
+                None,
+            )
             .await;
 
         assert!(result.is_err());
@@ -1113,6 +1473,8 @@ mod tests {
             RequestContent::UpdateStateRequest(Box::new(UpdateStateRequest {
                 state: complete_state(WORKLOAD_NAME_1),
                 update_mask: vec![FIELD_MASK.into()],
+                dry_run: false,
+                expected_revision: None,
             })),
         );
 
@@ -1121,7 +1483,11 @@ mod tests {
         server_connection.from_server = from_server;
 
         let result = server_connection
-            .update_state(complete_state(WORKLOAD_NAME_1), vec![FIELD_MASK.into()])
+            .update_state(
+                complete_state(WORKLOAD_NAME_1),
+                vec![FIELD_MASK.into()],
+                None,
+            )
             .await;
 
         assert!(result.is_err());
@@ -1158,6 +1524,14 @@ mod tests {
                             .into(),
                         }),
                         files: Some(generate_test_proto_workload_files()),
+                        agent_selector: None,
+                        resource_requests: None,
+                        agents: None,
+                        update_strategy: None,
+                        probes: None,
+                        restart_backoff: None,
+                        dependency_timeouts: None,
+                        resources: None,
                     },
                 )]),
             )),
@@ -1169,6 +1543,8 @@ mod tests {
             RequestContent::UpdateStateRequest(Box::new(UpdateStateRequest {
                 state: complete_state(WORKLOAD_NAME_1),
                 update_mask: vec![FIELD_MASK.into()],
+                dry_run: false,
+                expected_revision: None,
             })),
         );
         sim.will_send_message(other_response.clone());
@@ -1179,7 +1555,11 @@ mod tests {
         let (checker, mut server_connection) = sim.create_server_connection();
 
         let result = server_connection
-            .update_state(complete_state(WORKLOAD_NAME_1), vec![FIELD_MASK.into()])
+            .update_state(
+                complete_state(WORKLOAD_NAME_1),
+                vec![FIELD_MASK.into()],
+                None,
+            )
             .await;
 
         assert!(result.is_ok());
@@ -1208,6 +1588,8 @@ mod tests {
             RequestContent::UpdateStateRequest(Box::new(UpdateStateRequest {
                 state: complete_state(WORKLOAD_NAME_1),
                 update_mask: vec![FIELD_MASK.into()],
+                dry_run: false,
+                expected_revision: None,
             })),
         );
         sim.will_send_message(other_message.clone());
@@ -1218,7 +1600,11 @@ mod tests {
         let (checker, mut server_connection) = sim.create_server_connection();
 
         let result = server_connection
-            .update_state(complete_state(WORKLOAD_NAME_1), vec![FIELD_MASK.into()])
+            .update_state(
+                complete_state(WORKLOAD_NAME_1),
+                vec![FIELD_MASK.into()],
+                None,
+            )
             .await;
 
         assert!(result.is_ok());
@@ -1230,6 +1616,79 @@ mod tests {
         checker.check_communication();
     }
 
+    #[tokio::test]
+    async fn utest_get_revisions() {
+        let revisions = vec![ank_base::Revision {
+            revision: 1,
+            requester: OTHER_REQUEST.into(),
+            added_workloads: vec![WORKLOAD_NAME_1.into()],
+            ..Default::default()
+        }];
+
+        let mut sim = CommunicationSimulator::default();
+        sim.will_send_message(FromServer::UpdateWorkloadState(UpdateWorkloadState {
+            workload_states: vec![],
+        }));
+        sim.expect_receive_request(
+            REQUEST,
+            RequestContent::RevisionsRequest(RevisionsRequest { revision: None }),
+        );
+        sim.will_send_response(
+            REQUEST,
+            ank_base::response::ResponseContent::Revisions(ank_base::Revisions {
+                revisions: revisions.clone(),
+            }),
+        );
+        let (checker, mut server_connection) = sim.create_server_connection();
+
+        let result = server_connection.get_revisions(None).await;
+
+        assert_eq!(result, Ok(revisions));
+        assert_eq!(
+            server_connection.take_missed_from_server_messages(),
+            vec![FromServer::UpdateWorkloadState(UpdateWorkloadState {
+                workload_states: vec![],
+            })]
+        );
+        checker.check_communication();
+    }
+
+    #[tokio::test]
+    async fn utest_get_revisions_fails_error_response() {
+        let mut sim = CommunicationSimulator::default();
+        sim.expect_receive_request(
+            REQUEST,
+            RequestContent::RevisionsRequest(RevisionsRequest { revision: Some(42) }),
+        );
+        sim.will_send_response(
+            REQUEST,
+            ank_base::response::ResponseContent::Error(ank_base::Error {
+                message: "".into(),
+                ..Default::default()
+            }),
+        );
+        let (checker, mut server_connection) = sim.create_server_connection();
+
+        let result = server_connection.get_revisions(Some(42)).await;
+
+        assert!(result.is_err());
+        checker.check_communication();
+    }
+
+    #[tokio::test]
+    async fn utest_get_revisions_fails_no_response() {
+        let mut sim = CommunicationSimulator::default();
+        sim.expect_receive_request(
+            REQUEST,
+            RequestContent::RevisionsRequest(RevisionsRequest { revision: None }),
+        );
+        let (_checker, mut server_connection) = sim.create_server_connection();
+
+        let result = server_connection.get_revisions(None).await;
+
+        assert!(result.is_err());
+    }
+
     #[tokio::test]
     async fn utest_read_next_update_workload_state() {
         let update_workload_state = UpdateWorkloadState {
@@ -1258,7 +1717,10 @@ mod tests {
         let other_message = FromServer::Response(ank_base::Response {
             request_id: REQUEST.into(),
             response_content: Some(ank_base::response::ResponseContent::Error(
-                ank_base::Error { message: "".into() },
+                ank_base::Error {
+                    message: "".into(),
+                    ..Default::default()
+                },
             )),
         });
         let update_workload_state = UpdateWorkloadState {
@@ -1297,6 +1759,113 @@ mod tests {
         checker.check_communication();
     }
 
+    // [utest->swdd~cli-subscribes-for-events~3]
+    #[tokio::test]
+    async fn utest_subscribe_events_and_read_next_event() {
+        let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
+        let event = ank_base::EventResponse {
+            complete_state: Some(ank_base::CompleteState::default()),
+            changed_field_masks: vec![FIELD_MASK.into()],
+        };
+
+        let mut sim = CommunicationSimulator::default();
+        sim.expect_receive_request(
+            REQUEST,
+            RequestContent::EventsSubscribeRequest(EventsSubscribeRequest {
+                field_masks: vec![FIELD_MASK.into()],
+            }),
+        );
+        sim.will_send_message(FromServer::UpdateWorkloadState(UpdateWorkloadState {
+            workload_states: vec![],
+        }));
+        sim.will_send_response(
+            REQUEST,
+            ank_base::response::ResponseContent::EventResponse(event.clone()),
+        );
+        let (checker, mut server_connection) = sim.create_server_connection();
+
+        let signal_handler_context = MockSignalHandler::wait_for_signals_context();
+        signal_handler_context
+            .expect()
+            .returning(|| Box::pin(std::future::pending()));
+
+        let request_id = server_connection
+            .subscribe_events(vec![FIELD_MASK.into()])
+            .await
+            .unwrap();
+        let result = server_connection.read_next_event(&request_id).await;
+
+        assert_eq!(result, Ok(Some(event)));
+        assert!(
+            server_connection
+                .take_missed_from_server_messages()
+                .is_empty()
+        );
+        checker.check_communication();
+    }
+
+    // [utest->swdd~cli-subscribes-for-events~3]
+    #[tokio::test]
+    async fn utest_read_next_event_fails_error_response() {
+        let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
+        let mut sim = CommunicationSimulator::default();
+        sim.expect_receive_request(
+            REQUEST,
+            RequestContent::EventsSubscribeRequest(EventsSubscribeRequest {
+                field_masks: vec![],
+            }),
+        );
+        sim.will_send_response(
+            REQUEST,
+            ank_base::response::ResponseContent::Error(ank_base::Error {
+                message: "".into(),
+                ..Default::default()
+            }),
+        );
+        let (checker, mut server_connection) = sim.create_server_connection();
+
+        let signal_handler_context = MockSignalHandler::wait_for_signals_context();
+        signal_handler_context
+            .expect()
+            .returning(|| Box::pin(std::future::pending()));
+
+        let request_id = server_connection.subscribe_events(vec![]).await.unwrap();
+        let result = server_connection.read_next_event(&request_id).await;
+
+        assert!(result.is_err());
+        checker.check_communication();
+    }
+
+    // [utest->swdd~cli-sends-events-cancel-request-upon-termination~1]
+    #[tokio::test]
+    async fn utest_read_next_event_sends_events_cancel_request_upon_signal() {
+        let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
+        let (_from_server, cli_receiver) = tokio::sync::mpsc::channel::<FromServer>(1);
+        let (to_server, mut server_receiver) = tokio::sync::mpsc::channel::<ToServer>(1);
+        let mut server_connection = ServerConnection {
+            to_server,
+            from_server: cli_receiver,
+            task: tokio::spawn(async {}),
+            missed_from_server_messages: Vec::new(),
+        };
+
+        let signal_handler_context = MockSignalHandler::wait_for_signals_context();
+        signal_handler_context
+            .expect()
+            .returning(|| Box::pin(std::future::ready(())));
+
+        let result = server_connection.read_next_event(REQUEST).await;
+
+        assert_eq!(result, Ok(None));
+        assert_eq!(
+            server_receiver.recv().await,
+            Some(ToServer::Request(Request {
+                request_id: REQUEST.into(),
+                request_content: RequestContent::EventsCancelRequest,
+            }))
+        );
+    }
+
     // [utest->swdd~cli-streams-logs-from-the-server~1]
     // [utest->swdd~cli-handles-log-responses-from-server~1]
     // [utest->swdd~cli-stops-log-output-for-specific-workloads~1]
@@ -1311,6 +1880,9 @@ mod tests {
             since: None,
             until: None,
             output_names: false,
+            timestamps: false,
+            stdout_only: false,
+            stderr_only: false,
         };
 
         let instance_name_1 = instance_name(WORKLOAD_NAME_1);
@@ -1348,10 +1920,12 @@ mod tests {
             ank_base::LogEntry {
                 workload_name: Some(instance_name_1.clone().into()),
                 message: "some log line".to_string(),
+                ..Default::default()
             },
             ank_base::LogEntry {
                 workload_name: Some(instance_name_2.clone().into()),
                 message: "another log line".to_string(),
+                ..Default::default()
             },
         ];
 
@@ -1416,6 +1990,9 @@ mod tests {
             since: None,
             until: None,
             output_names: false,
+            timestamps: false,
+            stdout_only: false,
+            stderr_only: false,
         };
 
         let instance_names_set = BTreeSet::from([instance_name(WORKLOAD_NAME_1)]);
@@ -1454,19 +2031,23 @@ mod tests {
             since: None,
             until: None,
             output_names: false,
+            timestamps: false,
+            stdout_only: false,
+            stderr_only: false,
         };
 
         let instance_names = BTreeSet::from([instance_name_1.clone()]);
 
-        let output_log_fn = select_log_format_function(&instance_names, log_args.output_names);
+        let log_output_format = select_log_output_format(&instance_names, &log_args);
 
         let log_message = "some log line";
         let log_entry = ank_base::LogEntry {
             workload_name: Some(instance_name_1.clone().into()),
             message: log_message.to_string(),
+            ..Default::default()
         };
 
-        output_log_fn(vec![log_entry]);
+        log_output_format.output(vec![log_entry]);
 
         let actual_log_data = TEST_LOG_OUTPUT_DATA.take();
         assert_eq!(actual_log_data, vec![format!("{log_message}\n")]);
@@ -1484,19 +2065,23 @@ mod tests {
             since: None,
             until: None,
             output_names: true,
+            timestamps: false,
+            stdout_only: false,
+            stderr_only: false,
         };
 
         let instance_names = BTreeSet::from([instance_name_1.clone()]);
 
-        let output_log_fn = select_log_format_function(&instance_names, log_args.output_names);
+        let log_output_format = select_log_output_format(&instance_names, &log_args);
 
         let log_message = "some log line";
         let log_entry = ank_base::LogEntry {
             workload_name: Some(instance_name_1.clone().into()),
             message: log_message.to_string(),
+            ..Default::default()
         };
 
-        output_log_fn(vec![log_entry]);
+        log_output_format.output(vec![log_entry]);
 
         let actual_log_data = TEST_LOG_OUTPUT_DATA.take();
         assert_eq!(
@@ -1509,6 +2094,106 @@ mod tests {
         );
     }
 
+    // [utest->swdd~cli-outputs-log-timestamps-and-filters-streams~1]
+    #[test]
+    fn utest_output_log_line_with_prefixed_timestamp() {
+        let _guard = MOCKALL_CONTEXT_SYNC.get_lock();
+        let instance_name_1 = instance_name(WORKLOAD_NAME_1);
+        let log_args = LogsArgs {
+            workload_name: vec![WORKLOAD_NAME_1.to_string()],
+            follow: false,
+            tail: -1,
+            since: None,
+            until: None,
+            output_names: true,
+            timestamps: true,
+            stdout_only: false,
+            stderr_only: false,
+        };
+
+        let instance_names = BTreeSet::from([instance_name_1.clone()]);
+
+        let log_output_format = select_log_output_format(&instance_names, &log_args);
+
+        let log_timestamp = "2024-03-01T12:30:00Z";
+        log_output_format.output(vec![
+            ank_base::LogEntry {
+                workload_name: Some(instance_name_1.clone().into()),
+                message: "line with timestamp".to_string(),
+                timestamp: log_timestamp.to_string(),
+                stream: ank_base::LogStream::Stdout.into(),
+            },
+            ank_base::LogEntry {
+                workload_name: Some(instance_name_1.clone().into()),
+                message: "line without timestamp".to_string(),
+                ..Default::default()
+            },
+        ]);
+
+        let actual_log_data = TEST_LOG_OUTPUT_DATA.take();
+        assert_eq!(
+            actual_log_data,
+            vec![
+                format!(
+                    "{} {log_timestamp} line with timestamp\n",
+                    instance_name_1.workload_name()
+                ),
+                format!(
+                    "{} line without timestamp\n",
+                    instance_name_1.workload_name()
+                ),
+            ]
+        );
+    }
+
+    // [utest->swdd~cli-outputs-log-timestamps-and-filters-streams~1]
+    #[test]
+    fn utest_output_log_lines_of_requested_stream_only() {
+        let _guard = MOCKALL_CONTEXT_SYNC.get_lock();
+        let instance_name_1 = instance_name(WORKLOAD_NAME_1);
+        let mut log_args = LogsArgs {
+            workload_name: vec![WORKLOAD_NAME_1.to_string()],
+            follow: false,
+            tail: -1,
+            since: None,
+            until: None,
+            output_names: false,
+            timestamps: false,
+            stdout_only: false,
+            stderr_only: true,
+        };
+
+        let instance_names = BTreeSet::from([instance_name_1.clone()]);
+        let log_entries = vec![
+            ank_base::LogEntry {
+                workload_name: Some(instance_name_1.clone().into()),
+                message: "stdout line".to_string(),
+                stream: ank_base::LogStream::Stdout.into(),
+                ..Default::default()
+            },
+            ank_base::LogEntry {
+                workload_name: Some(instance_name_1.clone().into()),
+                message: "stderr line".to_string(),
+                stream: ank_base::LogStream::Stderr.into(),
+                ..Default::default()
+            },
+        ];
+
+        select_log_output_format(&instance_names, &log_args).output(log_entries.clone());
+        assert_eq!(
+            TEST_LOG_OUTPUT_DATA.take(),
+            vec!["stderr line\n".to_string()]
+        );
+
+        log_args.stderr_only = false;
+        log_args.stdout_only = true;
+        select_log_output_format(&instance_names, &log_args).output(log_entries);
+        assert_eq!(
+            TEST_LOG_OUTPUT_DATA.take(),
+            vec!["stdout line\n".to_string()]
+        );
+    }
+
     // [utest->swdd~cli-streams-logs-from-the-server~1]
     // [utest->swdd~cli-handles-log-responses-from-server~1]
     #[tokio::test]
@@ -1521,6 +2206,9 @@ mod tests {
             since: None,
             until: None,
             output_names: false,
+            timestamps: false,
+            stdout_only: false,
+            stderr_only: false,
         };
 
         let mut sim = CommunicationSimulator::default();
@@ -1553,6 +2241,7 @@ mod tests {
             REQUEST,
             ank_base::response::ResponseContent::Error(ank_base::Error {
                 message: "log collection error.".to_string(),
+                ..Default::default()
             }),
         );
 
@@ -1592,6 +2281,9 @@ mod tests {
             since: None,
             until: None,
             output_names: false,
+            timestamps: false,
+            stdout_only: false,
+            stderr_only: false,
         };
 
         let instance_name_1 = instance_name(WORKLOAD_NAME_1);
@@ -1668,6 +2360,9 @@ mod tests {
             since: None,
             until: None,
             output_names: false,
+            timestamps: false,
+            stdout_only: false,
+            stderr_only: false,
         };
 
         let instance_name_1 = instance_name(WORKLOAD_NAME_1);
@@ -1727,6 +2422,9 @@ mod tests {
             since: None,
             until: None,
             output_names: false,
+            timestamps: false,
+            stdout_only: false,
+            stderr_only: false,
         };
 
         let instance_name_1 = instance_name(WORKLOAD_NAME_1);
@@ -1757,6 +2455,7 @@ mod tests {
         // error to stop the loop after the ignored message
         let error_response = ank_base::response::ResponseContent::Error(ank_base::Error {
             message: "connection interruption".to_string(),
+            ..Default::default()
         });
 
         sim.will_send_response(REQUEST, error_response);
@@ -1808,6 +2507,9 @@ mod tests {
             since: None,
             until: None,
             output_names: false,
+            timestamps: false,
+            stdout_only: false,
+            stderr_only: false,
         };
 
         let instance_name_1 = instance_name(WORKLOAD_NAME_1);
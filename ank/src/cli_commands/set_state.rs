@@ -26,7 +26,7 @@ use crate::{cli_error::CliError, output_debug};
 #[cfg(test)]
 use tests::read_to_string_mock as read_file_to_string;
 
-use super::CliCommands;
+use super::{CliCommands, REVISION_FIELD};
 
 fn create_state_with_default_workload_specs(update_mask: &[String]) -> CompleteState {
     let mut complete_state = CompleteState::default();
@@ -109,14 +109,31 @@ impl CliCommands {
         &mut self,
         object_field_mask: Vec<String>,
         state_object_file: String,
+        dry_run: bool,
+        expected_revision: Option<u64>,
+        force: bool,
     ) -> Result<(), CliError> {
         output_debug!(
-            "Got: object_field_mask={:?} state_object_file={:?}",
+            "Got: object_field_mask={:?} state_object_file={:?} dry_run={:?} expected_revision={:?} force={:?}",
             object_field_mask,
-            state_object_file
+            state_object_file,
+            dry_run,
+            expected_revision,
+            force
         );
 
         let temp_obj = process_inputs(io::stdin(), &state_object_file).await?;
+
+        // [impl->swdd~cli-sends-expected-revision-with-update-state~2]
+        let expected_revision = if force {
+            None
+        } else {
+            expected_revision.or_else(|| {
+                temp_obj
+                    .get(&REVISION_FIELD.into())
+                    .and_then(serde_yaml::Value::as_u64)
+            })
+        };
         let default_complete_state = create_state_with_default_workload_specs(&object_field_mask);
 
         // now overwrite with the values from the field mask
@@ -130,9 +147,20 @@ impl CliCommands {
             new_complete_state
         );
 
+        if dry_run {
+            // [impl->swdd~cli-requests-dry-run-update-state~1]
+            return self
+                .dry_run_update_state(new_complete_state, object_field_mask)
+                .await;
+        }
+
         // [impl->swdd~cli-blocks-until-ankaios-server-responds-set-desired-state~2]
-        self.update_state_and_wait_for_complete(new_complete_state, object_field_mask)
-            .await
+        self.update_state_and_wait_for_complete(
+            new_complete_state,
+            object_field_mask,
+            expected_revision,
+        )
+        .await
     }
 }
 
@@ -158,7 +186,7 @@ mod tests {
         objects::{CompleteState, RestartPolicy, State},
         state_manipulation::Object,
     };
-    use mockall::predicate::eq;
+    use mockall::predicate::{always, eq};
     use serde_yaml::Value;
     use std::{collections::HashMap, io::Cursor};
 
@@ -168,6 +196,8 @@ mod tests {
 
     const RESPONSE_TIMEOUT_MS: u64 = 3000;
 
+    const FILE_REVISION: u64 = 2;
+    const ARGUMENT_REVISION: u64 = 1;
     const SAMPLE_CONFIG: &str = r#"desiredState:
         workloads:
           nginx:
@@ -328,8 +358,150 @@ mod tests {
             .returning(|_| Ok(FilteredCompleteState::default()));
         mock_server_connection
             .expect_update_state()
+            .with(eq(updated_state), eq(update_mask.clone()), eq(None))
+            .return_once(|_, _, _| Ok(UpdateStateSuccess::default()));
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: true,
+            server_connection: mock_server_connection,
+        };
+
+        let set_state_result = cmd
+            .set_state(update_mask, state_object_file, false, None, false)
+            .await;
+        assert!(set_state_result.is_ok());
+    }
+
+    // [utest->swdd~cli-requests-dry-run-update-state~1]
+    #[tokio::test]
+    async fn utest_set_state_dry_run_ok() {
+        let update_mask = vec!["desiredState.workloads.nginx.restartPolicy".to_string()];
+        let state_object_file = SAMPLE_CONFIG.to_owned();
+
+        let workload_spec = StoredWorkloadSpec {
+            restart_policy: RestartPolicy::Always,
+            ..Default::default()
+        };
+        let updated_state = CompleteState {
+            desired_state: State {
+                workloads: HashMap::from([("nginx".to_string(), workload_spec)]),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_dry_run_update_state()
             .with(eq(updated_state), eq(update_mask.clone()))
             .return_once(|_, _| Ok(UpdateStateSuccess::default()));
+        mock_server_connection.expect_update_state().never();
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        let set_state_result = cmd
+            .set_state(update_mask, state_object_file, true, None, false)
+            .await;
+        assert!(set_state_result.is_ok());
+    }
+
+    // [utest->swdd~cli-sends-expected-revision-with-update-state~2]
+    #[tokio::test]
+    async fn utest_set_state_sends_no_revision_by_default() {
+        let update_mask = vec!["desiredState.workloads.nginx.restartPolicy".to_string()];
+
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection.expect_get_complete_state().never();
+        mock_server_connection
+            .expect_update_state()
+            .with(always(), eq(update_mask.clone()), eq(None))
+            .return_once(|_, _, _| Ok(UpdateStateSuccess::default()));
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: true,
+            server_connection: mock_server_connection,
+        };
+
+        let set_state_result = cmd
+            .set_state(update_mask, SAMPLE_CONFIG.to_owned(), false, None, false)
+            .await;
+        assert!(set_state_result.is_ok());
+    }
+
+    // [utest->swdd~cli-sends-expected-revision-with-update-state~2]
+    #[tokio::test]
+    async fn utest_set_state_sends_revision_from_state_object_file() {
+        let update_mask = vec!["desiredState.workloads.nginx.restartPolicy".to_string()];
+        let state_object_file = format!("revision: {FILE_REVISION}\n{SAMPLE_CONFIG}");
+
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_update_state()
+            .with(always(), eq(update_mask.clone()), eq(Some(FILE_REVISION)))
+            .return_once(|_, _, _| Ok(UpdateStateSuccess::default()));
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: true,
+            server_connection: mock_server_connection,
+        };
+
+        let set_state_result = cmd
+            .set_state(update_mask, state_object_file, false, None, false)
+            .await;
+        assert!(set_state_result.is_ok());
+    }
+
+    // [utest->swdd~cli-sends-expected-revision-with-update-state~2]
+    #[tokio::test]
+    async fn utest_set_state_expected_revision_argument_overrides_state_object_file() {
+        let update_mask = vec!["desiredState.workloads.nginx.restartPolicy".to_string()];
+        let state_object_file = format!("revision: {FILE_REVISION}\n{SAMPLE_CONFIG}");
+
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_update_state()
+            .with(
+                always(),
+                eq(update_mask.clone()),
+                eq(Some(ARGUMENT_REVISION)),
+            )
+            .return_once(|_, _, _| Ok(UpdateStateSuccess::default()));
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: true,
+            server_connection: mock_server_connection,
+        };
+
+        let set_state_result = cmd
+            .set_state(
+                update_mask,
+                state_object_file,
+                false,
+                Some(ARGUMENT_REVISION),
+                false,
+            )
+            .await;
+        assert!(set_state_result.is_ok());
+    }
+
+    // [utest->swdd~cli-sends-expected-revision-with-update-state~2]
+    #[tokio::test]
+    async fn utest_set_state_force_sends_no_revision() {
+        let update_mask = vec!["desiredState.workloads.nginx.restartPolicy".to_string()];
+        let state_object_file = format!("revision: {FILE_REVISION}\n{SAMPLE_CONFIG}");
+
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_update_state()
+            .with(always(), eq(update_mask.clone()), eq(None))
+            .return_once(|_, _, _| Ok(UpdateStateSuccess::default()));
 
         let mut cmd = CliCommands {
             _response_timeout_ms: RESPONSE_TIMEOUT_MS,
@@ -337,7 +509,9 @@ mod tests {
             server_connection: mock_server_connection,
         };
 
-        let set_state_result = cmd.set_state(update_mask, state_object_file).await;
+        let set_state_result = cmd
+            .set_state(update_mask, state_object_file, false, None, true)
+            .await;
         assert!(set_state_result.is_ok());
     }
 }
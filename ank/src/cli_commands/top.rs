@@ -0,0 +1,499 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use std::cmp::Reverse;
+use std::collections::{HashMap, VecDeque};
+use std::time::Duration;
+
+use common::objects::{ExecutionStateEnum, WorkloadStatesMap};
+
+use super::CliCommands;
+use super::cli_table::CliTable;
+use super::top_table_row::TopTableRow;
+use crate::{
+    cli::TopSortKey, cli_error::CliError, filtered_complete_state::FilteredAgentAttributes,
+    output_debug,
+};
+
+#[cfg(not(test))]
+use crate::output_update;
+
+const AGENTS_FIELD_MASK: &str = "agents";
+const WORKLOAD_STATES_FIELD_MASK: &str = "workloadStates";
+const CPU_HISTORY_LENGTH: usize = 20;
+const SPARKLINE_CHARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+// [impl->swdd~cli-top-shows-cpu-usage-history~1]
+#[derive(Debug, Default)]
+struct CpuUsageHistory(HashMap<String, VecDeque<u32>>);
+
+impl CpuUsageHistory {
+    fn update(&mut self, agents: &HashMap<String, FilteredAgentAttributes>) {
+        // the history of disconnected agents is dropped
+        self.0
+            .retain(|agent_name, _| agents.contains_key(agent_name));
+
+        for (agent_name, agent_attributes) in agents {
+            let Some(cpu_usage) = agent_attributes
+                .cpu_usage
+                .as_ref()
+                .and_then(|cpu_usage| cpu_usage.cpu_usage)
+            else {
+                continue;
+            };
+
+            let history = self.0.entry(agent_name.clone()).or_default();
+            if history.len() == CPU_HISTORY_LENGTH {
+                history.pop_front();
+            }
+            history.push_back(cpu_usage);
+        }
+    }
+
+    fn sparkline(&self, agent_name: &str) -> String {
+        self.0
+            .get(agent_name)
+            .map(|history| {
+                history
+                    .iter()
+                    .map(|cpu_usage| to_sparkline_char(*cpu_usage))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+fn to_sparkline_char(cpu_usage: u32) -> char {
+    let max_index = SPARKLINE_CHARS.len() - 1;
+    let index = (cpu_usage.min(100) as usize * max_index + 50) / 100;
+    SPARKLINE_CHARS[index]
+}
+
+impl CliCommands {
+    // [impl->swdd~cli-provides-top~1]
+    // [impl->swdd~cli-top-refreshes-periodically~1]
+    pub async fn top(&mut self, interval: Duration, sort_key: TopSortKey) -> Result<(), CliError> {
+        let mut cpu_usage_history = CpuUsageHistory::default();
+        let mut refresh_interval = tokio::time::interval(interval);
+
+        loop {
+            refresh_interval.tick().await;
+            let top_table = self
+                .create_top_table(&mut cpu_usage_history, sort_key)
+                .await?;
+            update_top_table(&top_table);
+        }
+    }
+
+    // [impl->swdd~cli-provides-top~1]
+    // [impl->swdd~cli-top-outputs-once~1]
+    pub async fn top_once(&mut self, sort_key: TopSortKey) -> Result<String, CliError> {
+        self.create_top_table(&mut CpuUsageHistory::default(), sort_key)
+            .await
+    }
+
+    async fn create_top_table(
+        &mut self,
+        cpu_usage_history: &mut CpuUsageHistory,
+        sort_key: TopSortKey,
+    ) -> Result<String, CliError> {
+        let filtered_complete_state = self
+            .server_connection
+            .get_complete_state(&[
+                AGENTS_FIELD_MASK.to_string(),
+                WORKLOAD_STATES_FIELD_MASK.to_string(),
+            ])
+            .await?;
+
+        let connected_agents = filtered_complete_state
+            .agents
+            .and_then(|agents| agents.agents)
+            .unwrap_or_default();
+        let workload_states_map = filtered_complete_state.workload_states.unwrap_or_default();
+
+        cpu_usage_history.update(&connected_agents);
+
+        let top_table_rows = transform_into_top_table_rows(
+            connected_agents,
+            &workload_states_map,
+            cpu_usage_history,
+            sort_key,
+        );
+
+        output_debug!("Got top table rows: {:?}", top_table_rows);
+
+        Ok(CliTable::new(&top_table_rows).create_default_table())
+    }
+}
+
+#[cfg(not(test))]
+fn update_top_table(top_table: &str) {
+    output_update!("{}", top_table);
+}
+
+#[cfg(test)]
+fn update_top_table(top_table: &str) {
+    *tests::TEST_TOP_TABLE_OUTPUT.lock().unwrap() = top_table.to_string();
+}
+
+// [impl->swdd~cli-top-counts-workload-states~1]
+// [impl->swdd~cli-top-sorts-agents~1]
+fn transform_into_top_table_rows(
+    connected_agents: HashMap<String, FilteredAgentAttributes>,
+    workload_states_map: &WorkloadStatesMap,
+    cpu_usage_history: &CpuUsageHistory,
+    sort_key: TopSortKey,
+) -> Vec<TopTableRow> {
+    let mut top_table_rows: Vec<TopTableRow> = connected_agents
+        .into_iter()
+        .map(|(agent_name, mut agent_attributes)| {
+            let mut row = TopTableRow {
+                cpu_usage: agent_attributes.get_cpu_usage_as_string(),
+                cpu_history: cpu_usage_history.sparkline(&agent_name),
+                free_memory: agent_attributes.get_free_memory_as_string(),
+                cpu_usage_value: agent_attributes
+                    .cpu_usage
+                    .and_then(|cpu_usage| cpu_usage.cpu_usage),
+                free_memory_value: agent_attributes
+                    .free_memory
+                    .and_then(|free_memory| free_memory.free_memory),
+                ..Default::default()
+            };
+
+            for workload_state in workload_states_map.get_workload_state_for_agent(&agent_name) {
+                row.workloads += 1;
+                match workload_state.execution_state.state {
+                    ExecutionStateEnum::Running(_) => row.running += 1,
+                    ExecutionStateEnum::Pending(_) => row.pending += 1,
+                    ExecutionStateEnum::Stopping(_) => row.stopping += 1,
+                    ExecutionStateEnum::Succeeded(_) => row.succeeded += 1,
+                    ExecutionStateEnum::Failed(_) => row.failed += 1,
+                    _ => {}
+                }
+            }
+
+            row.agent_name = agent_name;
+            row
+        })
+        .collect();
+
+    // sort by agent name first to ensure a consistent output for equal values
+    top_table_rows.sort_by(|a, b| a.agent_name.cmp(&b.agent_name));
+    match sort_key {
+        TopSortKey::Name => {}
+        TopSortKey::Cpu => top_table_rows.sort_by_key(|row| Reverse(row.cpu_usage_value)),
+        TopSortKey::Memory => {
+            top_table_rows.sort_by_key(|row| row.free_memory_value.unwrap_or(u64::MAX))
+        }
+        TopSortKey::Workloads => top_table_rows.sort_by_key(|row| Reverse(row.workloads)),
+    }
+    top_table_rows
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    use api::ank_base;
+    use common::objects::{
+        AgentAttributes, AgentMap, CpuUsage, ExecutionState, FreeMemory, WorkloadStatesMap,
+        generate_test_workload_state_with_agent,
+    };
+    use mockall::{Sequence, predicate::eq};
+
+    use super::{CpuUsageHistory, to_sparkline_char, transform_into_top_table_rows};
+    use crate::cli::TopSortKey;
+    use crate::cli_commands::{
+        CliCommands,
+        cli_table::CliTable,
+        server_connection::{MockServerConnection, ServerConnectionError},
+        top_table_row::TopTableRow,
+    };
+    use crate::filtered_complete_state::FilteredCompleteState;
+
+    pub static TEST_TOP_TABLE_OUTPUT: Mutex<String> = Mutex::new(String::new());
+
+    const RESPONSE_TIMEOUT_MS: u64 = 3000;
+    const AGENT_A_NAME: &str = "agent_A";
+    const AGENT_B_NAME: &str = "agent_B";
+    const WORKLOAD_NAME_1: &str = "workload_1";
+    const WORKLOAD_NAME_2: &str = "workload_2";
+    const WORKLOAD_NAME_3: &str = "workload_3";
+
+    fn top_field_mask() -> Vec<String> {
+        vec!["agents".to_string(), "workloadStates".to_string()]
+    }
+
+    fn generate_test_agent_map(agent_loads: &[(&str, f32, u64)]) -> AgentMap {
+        let mut agent_map = AgentMap::new();
+        for (agent_name, cpu_usage, free_memory) in agent_loads {
+            agent_map
+                .entry(agent_name.to_string())
+                .or_insert(AgentAttributes {
+                    cpu_usage: Some(CpuUsage::new(*cpu_usage)),
+                    free_memory: Some(FreeMemory {
+                        free_memory: *free_memory,
+                    }),
+                    ..Default::default()
+                });
+        }
+        agent_map
+    }
+
+    fn generate_test_filtered_complete_state(
+        agent_map: AgentMap,
+        workload_states_map: WorkloadStatesMap,
+    ) -> FilteredCompleteState {
+        let complete_state = common::objects::CompleteState {
+            agents: agent_map,
+            workload_states: workload_states_map,
+            ..Default::default()
+        };
+        ank_base::CompleteState::from(complete_state).into()
+    }
+
+    fn generate_test_workload_states_map() -> WorkloadStatesMap {
+        let mut workload_states_map = WorkloadStatesMap::new();
+        workload_states_map.process_new_states(vec![
+            generate_test_workload_state_with_agent(
+                WORKLOAD_NAME_1,
+                AGENT_A_NAME,
+                ExecutionState::running(),
+            ),
+            generate_test_workload_state_with_agent(
+                WORKLOAD_NAME_2,
+                AGENT_A_NAME,
+                ExecutionState::failed("some error"),
+            ),
+            generate_test_workload_state_with_agent(
+                WORKLOAD_NAME_3,
+                AGENT_B_NAME,
+                ExecutionState::initial(),
+            ),
+        ]);
+        workload_states_map
+    }
+
+    // [utest->swdd~cli-top-shows-cpu-usage-history~1]
+    #[test]
+    fn utest_to_sparkline_char() {
+        assert_eq!(to_sparkline_char(0), '▁');
+        assert_eq!(to_sparkline_char(50), '▅');
+        assert_eq!(to_sparkline_char(100), '█');
+        assert_eq!(to_sparkline_char(250), '█');
+    }
+
+    // [utest->swdd~cli-top-shows-cpu-usage-history~1]
+    #[test]
+    fn utest_cpu_usage_history_is_limited_and_drops_disconnected_agents() {
+        let mut cpu_usage_history = CpuUsageHistory::default();
+        let agents_a_and_b: FilteredCompleteState = generate_test_filtered_complete_state(
+            generate_test_agent_map(&[(AGENT_A_NAME, 0.0, 1024), (AGENT_B_NAME, 100.0, 1024)]),
+            WorkloadStatesMap::default(),
+        );
+        let agents_a_and_b = agents_a_and_b.agents.unwrap().agents.unwrap();
+
+        for _ in 0..(super::CPU_HISTORY_LENGTH + 5) {
+            cpu_usage_history.update(&agents_a_and_b);
+        }
+
+        assert_eq!(
+            cpu_usage_history.sparkline(AGENT_A_NAME),
+            "▁".repeat(super::CPU_HISTORY_LENGTH)
+        );
+        assert_eq!(
+            cpu_usage_history.sparkline(AGENT_B_NAME),
+            "█".repeat(super::CPU_HISTORY_LENGTH)
+        );
+
+        let only_agent_a = HashMap::from([(
+            AGENT_A_NAME.to_string(),
+            agents_a_and_b[AGENT_A_NAME].clone(),
+        )]);
+        cpu_usage_history.update(&only_agent_a);
+
+        assert_eq!(cpu_usage_history.sparkline(AGENT_B_NAME), "");
+    }
+
+    // [utest->swdd~cli-top-counts-workload-states~1]
+    // [utest->swdd~cli-top-sorts-agents~1]
+    #[test]
+    fn utest_transform_into_top_table_rows_counts_states_and_sorts() {
+        let filtered_complete_state = generate_test_filtered_complete_state(
+            generate_test_agent_map(&[(AGENT_A_NAME, 10.0, 4096), (AGENT_B_NAME, 60.0, 8192)]),
+            generate_test_workload_states_map(),
+        );
+        let connected_agents = filtered_complete_state.agents.unwrap().agents.unwrap();
+        let workload_states_map = filtered_complete_state.workload_states.unwrap();
+
+        let rows = transform_into_top_table_rows(
+            connected_agents.clone(),
+            &workload_states_map,
+            &CpuUsageHistory::default(),
+            TopSortKey::Name,
+        );
+
+        assert_eq!(
+            rows,
+            vec![
+                TopTableRow {
+                    agent_name: AGENT_A_NAME.to_string(),
+                    cpu_usage: "10%".to_string(),
+                    free_memory: "4096B".to_string(),
+                    workloads: 2,
+                    running: 1,
+                    failed: 1,
+                    cpu_usage_value: Some(10),
+                    free_memory_value: Some(4096),
+                    ..Default::default()
+                },
+                TopTableRow {
+                    agent_name: AGENT_B_NAME.to_string(),
+                    cpu_usage: "60%".to_string(),
+                    free_memory: "8192B".to_string(),
+                    workloads: 1,
+                    pending: 1,
+                    cpu_usage_value: Some(60),
+                    free_memory_value: Some(8192),
+                    ..Default::default()
+                },
+            ]
+        );
+
+        let sorted_agent_names = |sort_key| -> Vec<String> {
+            transform_into_top_table_rows(
+                connected_agents.clone(),
+                &workload_states_map,
+                &CpuUsageHistory::default(),
+                sort_key,
+            )
+            .into_iter()
+            .map(|row| row.agent_name)
+            .collect()
+        };
+
+        assert_eq!(
+            sorted_agent_names(TopSortKey::Cpu),
+            vec![AGENT_B_NAME, AGENT_A_NAME]
+        );
+        assert_eq!(
+            sorted_agent_names(TopSortKey::Memory),
+            vec![AGENT_A_NAME, AGENT_B_NAME]
+        );
+        assert_eq!(
+            sorted_agent_names(TopSortKey::Workloads),
+            vec![AGENT_A_NAME, AGENT_B_NAME]
+        );
+    }
+
+    // [utest->swdd~cli-provides-top~1]
+    // [utest->swdd~cli-top-outputs-once~1]
+    #[tokio::test]
+    async fn utest_top_once() {
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_complete_state()
+            .with(eq(top_field_mask()))
+            .once()
+            .return_once(|_| {
+                Ok(generate_test_filtered_complete_state(
+                    generate_test_agent_map(&[(AGENT_A_NAME, 42.0, 1024)]),
+                    generate_test_workload_states_map(),
+                ))
+            });
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        let expected_rows = [TopTableRow {
+            agent_name: AGENT_A_NAME.to_string(),
+            cpu_usage: "42%".to_string(),
+            cpu_history: "▄".to_string(),
+            free_memory: "1024B".to_string(),
+            workloads: 2,
+            running: 1,
+            failed: 1,
+            ..Default::default()
+        }];
+
+        assert_eq!(
+            cmd.top_once(TopSortKey::Name).await,
+            Ok(CliTable::new(&expected_rows).create_default_table())
+        );
+    }
+
+    // [utest->swdd~cli-top-refreshes-periodically~1]
+    // [utest->swdd~cli-top-shows-cpu-usage-history~1]
+    #[tokio::test]
+    async fn utest_top_refreshes_until_connection_error() {
+        let mut sequence = Sequence::new();
+        let mut mock_server_connection = MockServerConnection::default();
+        for cpu_usage in [0.0, 100.0] {
+            mock_server_connection
+                .expect_get_complete_state()
+                .with(eq(top_field_mask()))
+                .once()
+                .in_sequence(&mut sequence)
+                .return_once(move |_| {
+                    Ok(generate_test_filtered_complete_state(
+                        generate_test_agent_map(&[(AGENT_A_NAME, cpu_usage, 1024)]),
+                        WorkloadStatesMap::default(),
+                    ))
+                });
+        }
+        mock_server_connection
+            .expect_get_complete_state()
+            .once()
+            .in_sequence(&mut sequence)
+            .return_once(|_| {
+                Err(ServerConnectionError::ExecutionError(
+                    "connection error".to_string(),
+                ))
+            });
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        let result = cmd.top(Duration::from_millis(1), TopSortKey::Name).await;
+        assert!(result.is_err());
+
+        let expected_rows = [TopTableRow {
+            agent_name: AGENT_A_NAME.to_string(),
+            cpu_usage: "100%".to_string(),
+            cpu_history: "▁█".to_string(),
+            free_memory: "1024B".to_string(),
+            ..Default::default()
+        }];
+        assert_eq!(
+            *TEST_TOP_TABLE_OUTPUT.lock().unwrap(),
+            CliTable::new(&expected_rows).create_default_table()
+        );
+    }
+}
@@ -0,0 +1,45 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use tabled::Tabled;
+
+#[derive(Debug, Tabled, Clone, Default, PartialEq, Eq)]
+#[tabled(rename_all = "UPPERCASE")]
+pub struct TopTableRow {
+    #[tabled(rename = "NAME")]
+    pub agent_name: String,
+    #[tabled(rename = "CPU")]
+    pub cpu_usage: String,
+    #[tabled(rename = "CPU HISTORY")]
+    pub cpu_history: String,
+    #[tabled(rename = "FREE MEMORY")]
+    pub free_memory: String,
+    #[tabled(rename = "WORKLOADS")]
+    pub workloads: u32,
+    #[tabled(rename = "RUNNING")]
+    pub running: u32,
+    #[tabled(rename = "PENDING")]
+    pub pending: u32,
+    #[tabled(rename = "STOPPING")]
+    pub stopping: u32,
+    #[tabled(rename = "SUCCEEDED")]
+    pub succeeded: u32,
+    #[tabled(rename = "FAILED")]
+    pub failed: u32,
+    // the raw values are only used for sorting
+    #[tabled(skip)]
+    pub cpu_usage_value: Option<u32>,
+    #[tabled(skip)]
+    pub free_memory_value: Option<u64>,
+}
@@ -134,6 +134,7 @@ mod tests {
                     runtime: "runtime".into(),
                     execution_state: "execution_state".into(),
                     additional_info: "additional_info".into(),
+                    resources: String::new(),
                 },
             )]),
             not_completed: HashSet::from([workload_instance_name.clone()]),
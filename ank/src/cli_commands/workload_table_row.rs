@@ -25,6 +25,8 @@ pub struct WorkloadTableRow {
     pub execution_state: String,
     #[tabled(rename = "ADDITIONAL INFO")]
     pub additional_info: String,
+    #[tabled(skip)]
+    pub resources: String,
 }
 
 impl WorkloadTableRow {
@@ -60,6 +62,35 @@ impl Tabled for WorkloadTableRowWithSpinner<'_> {
     }
 }
 
+// [impl->swdd~cli-get-workloads-wide-output-shows-resource-limits~1]
+pub struct WideWorkloadTableRow<'a> {
+    pub data: &'a WorkloadTableRow,
+}
+
+impl WideWorkloadTableRow<'_> {
+    pub const RESOURCES_POS: usize = WorkloadTableRow::ADDITIONAL_INFO_POS;
+    pub const ADDITIONAL_INFO_POS: usize = WorkloadTableRow::ADDITIONAL_INFO_POS + 1;
+}
+
+impl Tabled for WideWorkloadTableRow<'_> {
+    const LENGTH: usize = WorkloadTableRow::LENGTH + 1;
+
+    fn fields(&self) -> Vec<std::borrow::Cow<'_, str>> {
+        let mut fields = self.data.fields();
+        fields.insert(
+            Self::RESOURCES_POS,
+            std::borrow::Cow::Borrowed(self.data.resources.as_str()),
+        );
+        fields
+    }
+
+    fn headers() -> Vec<std::borrow::Cow<'static, str>> {
+        let mut headers = WorkloadTableRow::headers();
+        headers.insert(Self::RESOURCES_POS, std::borrow::Cow::Borrowed("RESOURCES"));
+        headers
+    }
+}
+
 impl WorkloadTableRow {
     pub fn new(
         name: impl Into<String>,
@@ -74,6 +105,7 @@ impl WorkloadTableRow {
             runtime: runtime.into(),
             execution_state: execution_state.into(),
             additional_info: trim_and_replace_newlines(additional_info.into()),
+            resources: String::new(),
         }
     }
 
@@ -97,7 +129,7 @@ fn trim_and_replace_newlines(text: String) -> String {
 mod tests {
     use tabled::Table;
 
-    use super::{WorkloadTableRow, WorkloadTableRowWithSpinner};
+    use super::{WideWorkloadTableRow, WorkloadTableRow, WorkloadTableRowWithSpinner};
 
     // [utest->swdd~cli-shall-present-workloads-as-table~1]
     #[test]
@@ -108,6 +140,7 @@ mod tests {
             runtime: "runtime".into(),
             execution_state: "execution_state".into(),
             additional_info: "additional_info".into(),
+            resources: String::new(),
         };
         let table_rows_with_spinner = vec![WorkloadTableRowWithSpinner {
             data: &table_row,
@@ -121,6 +154,21 @@ mod tests {
         );
     }
 
+    // [utest->swdd~cli-get-workloads-wide-output-shows-resource-limits~1]
+    #[test]
+    fn utest_one_row_wide_table_shows_resources() {
+        let mut table_row =
+            WorkloadTableRow::new("workload", "agent", "runtime", "execution_state", "info");
+        table_row.resources = "cpus=0.500 pids=64".into();
+        let wide_table_rows = vec![WideWorkloadTableRow { data: &table_row }];
+        let mut table = Table::new(wide_table_rows);
+        let expected_table = " WORKLOAD NAME   AGENT   RUNTIME   EXECUTION STATE   RESOURCES            ADDITIONAL INFO \n workload        agent   runtime   execution_state   cpus=0.500 pids=64   info            ";
+        assert_eq!(
+            table.with(tabled::settings::Style::blank()).to_string(),
+            expected_table
+        );
+    }
+
     // [utest->swdd~cli-shall-present-workloads-as-table~1]
     #[test]
     fn utest_additional_info_msg_without_new_lines() {
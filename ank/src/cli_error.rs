@@ -57,6 +57,12 @@ impl From<server_connection::ServerConnectionError> for CliError {
             server_connection::ServerConnectionError::ExecutionError(message) => {
                 CliError::ExecutionError(message)
             }
+            // [impl->swdd~cli-reports-update-state-conflict~1]
+            server_connection::ServerConnectionError::Conflict(message) => {
+                CliError::ExecutionError(format!(
+                    "{message}. Use '--force' to apply the update regardless of the current revision"
+                ))
+            }
         }
     }
 }
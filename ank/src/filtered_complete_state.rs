@@ -18,8 +18,9 @@ use api::ank_base;
 use common::{
     helpers::serialize_to_ordered_map,
     objects::{
-        AddCondition, ConfigItem, ControlInterfaceAccess, File, RestartPolicy, Tag,
-        WorkloadStatesMap,
+        AddCondition, ConfigItem, ControlInterfaceAccess, ExecutionState, File, Probes,
+        ResourceLimits, ResourceRequests, RestartBackoff, RestartPolicy, Tag, UpdateStrategy,
+        WorkloadMetricsMap, WorkloadStatesMap,
     },
 };
 use serde::{Deserialize, Serialize, Serializer};
@@ -50,8 +51,20 @@ pub struct FilteredCompleteState {
     #[serde(default)]
     pub workload_states: Option<WorkloadStatesMap>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub workload_metrics: Option<WorkloadMetricsMap>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default, flatten)]
     pub agents: Option<FilteredAgentMap>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub revision: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, serialize_with = "serialize_option_to_ordered_map")]
+    pub rendered_workloads: Option<HashMap<String, Vec<FilteredRenderedWorkload>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, serialize_with = "serialize_option_to_ordered_map")]
+    pub workload_state_history: Option<HashMap<String, Vec<FilteredExecutionStateTransition>>>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
@@ -67,6 +80,23 @@ pub struct FilteredState {
     pub configs: Option<HashMap<String, ConfigItem>>,
 }
 
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FilteredRenderedWorkload {
+    pub agent: String,
+    pub runtime_config: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub files: Vec<File>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FilteredExecutionStateTransition {
+    pub agent: String,
+    pub execution_state: ExecutionState,
+    pub timestamp: u64,
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FilteredAgentMap {
@@ -96,6 +126,9 @@ pub struct FilteredAgentAttributes {
     pub cpu_usage: Option<FilteredCpuUsage>,
     #[serde(flatten, skip_serializing_if = "Option::is_none")]
     pub free_memory: Option<FilteredFreeMemory>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, serialize_with = "serialize_option_to_ordered_map")]
+    pub labels: Option<HashMap<String, String>>,
 }
 
 impl FilteredAgentAttributes {
@@ -130,13 +163,31 @@ pub struct FilteredWorkloadSpec {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub agent: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub agents: Option<Vec<String>>,
+    #[serde(serialize_with = "serialize_option_to_ordered_map")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub agent_selector: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_requests: Option<ResourceRequests>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resources: Option<ResourceLimits>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub update_strategy: Option<UpdateStrategy>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub probes: Option<Probes>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<Vec<Tag>>,
     #[serde(serialize_with = "serialize_option_to_ordered_map")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub dependencies: Option<HashMap<String, AddCondition>>,
+    #[serde(serialize_with = "serialize_option_to_ordered_map")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dependency_timeouts: Option<HashMap<String, u32>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub restart_policy: Option<RestartPolicy>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub restart_backoff: Option<RestartBackoff>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub runtime: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub runtime_config: Option<String>,
@@ -149,12 +200,37 @@ pub struct FilteredWorkloadSpec {
     pub files: Option<Vec<File>>,
 }
 
+impl FilteredWorkloadSpec {
+    // A workload without an agent is either replicated on a list of agents or placed by the server.
+    pub fn runs_on_agent(&self, agent_name: &str) -> bool {
+        match (&self.agent, &self.agents) {
+            (Some(agent), _) => agent == agent_name,
+            (None, Some(agents)) => agents.iter().any(|agent| agent == agent_name),
+            (None, None) => true,
+        }
+    }
+}
+
 impl From<ank_base::CompleteState> for FilteredCompleteState {
     fn from(value: ank_base::CompleteState) -> Self {
         FilteredCompleteState {
             desired_state: value.desired_state.map(Into::into),
             workload_states: value.workload_states.map(Into::into),
+            workload_metrics: value.workload_metrics.map(|x| (*x).into()),
             agents: value.agents.map(Into::into),
+            revision: value.revision,
+            rendered_workloads: value.rendered_workloads.map(|x| {
+                x.workloads
+                    .into_iter()
+                    .map(|(k, v)| (k, map_vec(v.replicas)))
+                    .collect()
+            }),
+            workload_state_history: value.workload_state_history.map(|x| {
+                x.workloads
+                    .into_iter()
+                    .map(|(k, v)| (k, map_vec(v.transitions)))
+                    .collect()
+            }),
         }
     }
 }
@@ -198,6 +274,16 @@ impl From<ank_base::Workload> for FilteredWorkloadSpec {
     fn from(value: ank_base::Workload) -> Self {
         FilteredWorkloadSpec {
             agent: value.agent,
+            agent_selector: value.agent_selector.map(|x| x.labels),
+            resource_requests: value.resource_requests.map(Into::into),
+            resources: value.resources.map(Into::into),
+            agents: value.agents.map(|x| x.agents),
+            update_strategy: value.update_strategy.map(|x| {
+                UpdateStrategy::try_from(x).unwrap_or_else(|error| {
+                    output_and_error!("Could not convert UpdateStrategy.\nError: '{error}'. Check the Ankaios component compatibility.")
+                })
+            }),
+            probes: value.probes.map(Into::into),
             tags: value.tags.map(|x| map_vec(x.tags)),
             dependencies: value.dependencies.map(|x| {
                 x.dependencies
@@ -207,11 +293,13 @@ impl From<ank_base::Workload> for FilteredWorkloadSpec {
                     })))
                     .collect()
             }),
+            dependency_timeouts: value.dependency_timeouts.map(|x| x.dependency_timeouts),
             restart_policy: value.restart_policy.map(|x| {
                 RestartPolicy::try_from(x).unwrap_or_else(|error| {
                     output_and_error!("Could not convert RestartPolicy.\nError: '{error}'. Check the Ankaios component compatibility.")
                 })
             }),
+            restart_backoff: value.restart_backoff.map(Into::into),
             runtime: value.runtime,
             runtime_config: value.runtime_config,
             control_interface_access: value
@@ -227,6 +315,28 @@ impl From<ank_base::Workload> for FilteredWorkloadSpec {
     }
 }
 
+impl From<ank_base::RenderedWorkload> for FilteredRenderedWorkload {
+    fn from(value: ank_base::RenderedWorkload) -> Self {
+        FilteredRenderedWorkload {
+            agent: value.agent,
+            runtime_config: value.runtime_config,
+            files: value.files.map(|files|files.files.into_iter().map(|file| file.try_into().unwrap_or_else(|error| {
+                output_and_error!("Could not convert files.\nError: '{error}'. Check the Ankaios component compatibility.")
+            })).collect()).unwrap_or_default(),
+        }
+    }
+}
+
+impl From<ank_base::ExecutionStateTransition> for FilteredExecutionStateTransition {
+    fn from(value: ank_base::ExecutionStateTransition) -> Self {
+        FilteredExecutionStateTransition {
+            agent: value.agent,
+            execution_state: value.execution_state.map(Into::into).unwrap_or_default(),
+            timestamp: value.timestamp,
+        }
+    }
+}
+
 impl From<ank_base::AgentMap> for FilteredAgentMap {
     fn from(value: ank_base::AgentMap) -> Self {
         FilteredAgentMap {
@@ -246,6 +356,7 @@ impl From<ank_base::AgentAttributes> for FilteredAgentAttributes {
         FilteredAgentAttributes {
             cpu_usage: value.cpu_usage.map(Into::into),
             free_memory: value.free_memory.map(Into::into),
+            labels: (!value.labels.is_empty()).then_some(value.labels),
         }
     }
 }
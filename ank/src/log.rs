@@ -29,6 +29,9 @@ use std::sync::OnceLock;
 
 pub static IS_VERBOSE: OnceLock<bool> = OnceLock::new();
 pub static IS_QUIET: OnceLock<bool> = OnceLock::new();
+pub static ERROR_EXIT_CODE: OnceLock<i32> = OnceLock::new();
+
+const DEFAULT_ERROR_EXIT_CODE: i32 = 1;
 
 static ROWS_PREV_MSG: Mutex<u16> = Mutex::new(0);
 
@@ -46,7 +49,8 @@ macro_rules! output_update {
 }
 
 // [impl->swdd~cli-use-proprietary-tracing~1]
-/// Prints the error message and immediately terminates the application with the exit code `1`.
+/// Prints the error message and immediately terminates the application with the exit code `1`
+/// or the exit code set in `ERROR_EXIT_CODE`.
 #[macro_export]
 macro_rules! output_and_error {
     ( $ ( $ arg : tt ) + ) => { $crate::log::output_and_error_fn ( format_args ! ( $ ( $ arg ) + ) ) }
@@ -75,7 +79,7 @@ macro_rules! output_warn {
 
 pub(crate) fn output_and_error_fn(args: fmt::Arguments<'_>) -> ! {
     eprintln!("{} {}", "error:".bold().red(), args);
-    exit(1);
+    exit(*ERROR_EXIT_CODE.get().unwrap_or(&DEFAULT_ERROR_EXIT_CODE));
 }
 
 pub(crate) fn output_and_exit_fn(args: fmt::Arguments<'_>) -> ! {
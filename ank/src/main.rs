@@ -18,12 +18,12 @@ mod ank_config;
 mod cli;
 mod cli_commands;
 mod cli_signals;
-use ank_config::{AnkConfig, DEFAULT_ANK_CONFIG_FILE_PATH};
+use ank_config::{AnkConfig, DEFAULT_ANK_CONFIG_FILE_PATH, DEFAULT_CONFIG};
 use cli_commands::CliCommands;
 use common::std_extensions::{GracefulExitResult, IllegalStateResult};
 use grpc::security::TLSConfig;
 
-use crate::log::{IS_QUIET, IS_VERBOSE};
+use crate::log::{ERROR_EXIT_CODE, IS_QUIET, IS_VERBOSE};
 mod cli_error;
 mod filtered_complete_state;
 mod log;
@@ -31,31 +31,89 @@ mod log;
 #[cfg(test)]
 pub mod test_helper;
 
+// The exit code of 'ank diff' if the manifests differ from the current desired state.
+const DIFF_EXIT_CODE: i32 = 1;
+// The exit code of 'ank diff' on errors, distinct from the exit code for differences.
+const DIFF_ERROR_EXIT_CODE: i32 = 2;
+
 // [impl->swdd~cli-loads-config-file~1]
-fn handle_ank_config(config_path: &Option<String>, default_path: &str) -> AnkConfig {
+// [impl->swdd~cli-selects-context~1]
+fn handle_ank_config(
+    config_path: &Option<String>,
+    context: &Option<String>,
+    default_path: &str,
+) -> AnkConfig {
     match config_path {
         Some(config_path) => {
             let config_path = PathBuf::from(config_path);
-            AnkConfig::from_file(config_path).unwrap_or_exit("Config file could not be parsed")
+            AnkConfig::from_file(config_path, context.as_deref()).unwrap_or_else(|err| {
+                output_and_error!("Config file could not be parsed: '{}'", err)
+            })
         }
         None => {
             let default_path = PathBuf::from(default_path.as_ref() as &std::path::Path);
-            if !default_path.try_exists().unwrap_or(false) {
+            let default_context_requested = context
+                .as_deref()
+                .is_none_or(|context| context == DEFAULT_CONFIG);
+            if !default_path.try_exists().unwrap_or(false) && default_context_requested {
                 AnkConfig::default()
             } else {
-                AnkConfig::from_file(default_path).expect("Config file could not be parsed")
+                AnkConfig::from_file(default_path, context.as_deref()).unwrap_or_else(|err| {
+                    output_and_error!("Config file could not be parsed: '{}'", err)
+                })
             }
         }
     }
 }
 
+// [impl->swdd~cli-provides-contexts~1]
+// [impl->swdd~cli-persists-current-context~1]
+fn handle_config_command(config_command: &cli::ConfigCommands, config_path: &str) -> ! {
+    let config_path = PathBuf::from(config_path);
+    match config_command {
+        cli::ConfigCommands::UseContext { context_name } => {
+            match ank_config::use_context(&config_path, context_name) {
+                Ok(()) => output_and_exit!("Switched to context '{}'.", context_name),
+                Err(error) => output_and_error!("Failed to switch the context: '{}'", error),
+            }
+        }
+        cli::ConfigCommands::GetContexts => match ank_config::get_contexts(&config_path) {
+            Ok(ank_contexts) => output_and_exit!("{}", ank_contexts),
+            Err(error) => output_and_error!("Failed to get the contexts: '{}'", error),
+        },
+    }
+}
+
 // [impl->swdd~cli-standalone-application~1]
 #[tokio::main]
 async fn main() {
     let args = cli::parse();
 
+    // [impl->swdd~cli-diff-exits-with-error-code-on-differences~2]
+    if matches!(args.command, cli::Commands::Diff(_)) {
+        ERROR_EXIT_CODE
+            .set(DIFF_ERROR_EXIT_CODE)
+            .unwrap_or_illegal_state();
+    }
+
+    // The contexts are managed without loading the config file, as the current context could be invalid.
+    if let cli::Commands::Config(config_args) = &args.command {
+        let config_path = args
+            .config_path
+            .as_deref()
+            .unwrap_or(&DEFAULT_ANK_CONFIG_FILE_PATH);
+        match &config_args.command {
+            Some(config_command) => handle_config_command(config_command, config_path),
+            None => unreachable!("Unreachable code."),
+        }
+    }
+
     // [impl->swdd~cli-loads-config-file~1]
-    let mut ank_config = handle_ank_config(&args.config_path, &DEFAULT_ANK_CONFIG_FILE_PATH);
+    let mut ank_config = handle_ank_config(
+        &args.config_path,
+        &args.context,
+        &DEFAULT_ANK_CONFIG_FILE_PATH,
+    );
     ank_config.update_with_args(&args);
 
     let cli_name = "ank-cli";
@@ -123,13 +181,15 @@ async fn main() {
 
             // [impl->swdd~cli-provides-list-of-workloads~1]
             Some(cli::GetCommands::Workload {
+                output_format,
                 workload_name,
                 agent_name,
                 state,
                 watch,
             }) => {
                 output_debug!(
-                    "Received get workload with workload_name='{:?}', agent_name='{:?}', state='{:?}', watch='{:?}'",
+                    "Received get workload with output_format='{:?}', workload_name='{:?}', agent_name='{:?}', state='{:?}', watch='{:?}'",
+                    output_format,
                     workload_name,
                     agent_name,
                     state,
@@ -138,13 +198,15 @@ async fn main() {
 
                 if watch {
                     // [impl->swdd~cli-get-workloads-with-watch~1]
-                    if let Err(error) = cmd.watch_workloads(agent_name, state, workload_name).await
+                    if let Err(error) = cmd
+                        .watch_workloads(output_format, agent_name, state, workload_name)
+                        .await
                     {
                         output_and_error!("Failed to watch workloads: '{}'", error);
                     }
                 } else {
                     match cmd
-                        .get_workloads_table(agent_name, state, workload_name)
+                        .get_workloads_table(output_format, agent_name, state, workload_name)
                         .await
                     {
                         Ok(out_text) => output_and_exit!("{}", out_text),
@@ -170,6 +232,51 @@ async fn main() {
                     Err(error) => output_and_error!("Failed to get configs: '{}'", error),
                 }
             }
+            // [impl->swdd~cli-provides-list-of-revisions~2]
+            // [impl->swdd~cli-provides-single-revision~1]
+            Some(cli::GetCommands::Revision { revision }) => {
+                output_debug!("Received get revision with revision='{:?}'.", revision);
+
+                let result = match revision {
+                    Some(revision) => cmd.get_revision(revision).await,
+                    None => cmd.get_revisions().await,
+                };
+                match result {
+                    Ok(out_text) => output_and_exit!("{}", out_text),
+                    Err(error) => output_and_error!("Failed to get revisions: '{}'", error),
+                }
+            }
+            // [impl->swdd~cli-provides-events~1]
+            Some(cli::GetCommands::Event {
+                output_format,
+                agent_name,
+                state,
+                workload_name,
+            }) => {
+                output_debug!(
+                    "Received get events with output_format='{:?}', agent_name='{:?}', state='{:?}', workload_name='{:?}'",
+                    output_format,
+                    agent_name,
+                    state,
+                    workload_name,
+                );
+
+                if let Err(error) = cmd
+                    .get_events(output_format, agent_name, state, workload_name)
+                    .await
+                {
+                    output_and_error!("Failed to get events: '{}'", error);
+                }
+            }
+            // [impl->swdd~cli-provides-dependency-graph~1]
+            Some(cli::GetCommands::Graph { output_format }) => {
+                output_debug!("Received get graph with output_format='{:?}'", output_format);
+
+                match cmd.get_graph(output_format).await {
+                    Ok(out_text) => output_and_exit!("{}", out_text),
+                    Err(error) => output_and_error!("Failed to get dependency graph: '{}'", error),
+                }
+            }
             None => unreachable!("Unreachable code."),
         },
         cli::Commands::Set(set_args) => match set_args.command {
@@ -177,15 +284,30 @@ async fn main() {
             Some(cli::SetCommands::State {
                 object_field_mask,
                 state_object_file,
+                dry_run,
+                expected_revision,
+                force,
             }) => {
                 output_debug!(
-                    "Received set with object_field_mask='{:?}' and state_object_file='{:?}'",
+                    "Received set with object_field_mask='{:?}', state_object_file='{:?}', dry_run='{}', expected_revision='{:?}' and force='{}'",
                     object_field_mask,
-                    state_object_file
+                    state_object_file,
+                    dry_run,
+                    expected_revision,
+                    force
                 );
 
                 // [impl->swdd~cli-blocks-until-ankaios-server-responds-set-desired-state~2]
-                if let Err(err) = cmd.set_state(object_field_mask, state_object_file).await {
+                if let Err(err) = cmd
+                    .set_state(
+                        object_field_mask,
+                        state_object_file,
+                        dry_run,
+                        expected_revision,
+                        force,
+                    )
+                    .await
+                {
                     output_and_error!("Failed to set state: '{}'", err)
                 }
             }
@@ -249,6 +371,30 @@ async fn main() {
                 output_and_error!("{}", err);
             }
         }
+        // [impl->swdd~cli-provides-diff-of-manifests~1]
+        cli::Commands::Diff(diff_args) => match cmd.diff_manifests(diff_args).await {
+            Ok(None) => output_and_exit!("No differences found."),
+            // [impl->swdd~cli-diff-exits-with-error-code-on-differences~2]
+            Ok(Some(diff)) => {
+                output!("{}", diff);
+                std::process::exit(DIFF_EXIT_CODE);
+            }
+            Err(err) => output_and_error!("Failed to diff manifests: '{}'", err),
+        },
+        cli::Commands::Describe(describe_args) => match describe_args.command {
+            // [impl->swdd~cli-provides-describe-workload~1]
+            Some(cli::DescribeCommands::Workload { workload_name }) => {
+                output_debug!(
+                    "Received describe workload with workload_name='{}'",
+                    workload_name
+                );
+                match cmd.describe_workload(workload_name).await {
+                    Ok(out_text) => output_and_exit!("{}", out_text),
+                    Err(error) => output_and_error!("Failed to describe workload: '{}'", error),
+                }
+            }
+            None => unreachable!("Unreachable code."),
+        },
         // [impl->swdd~cli-provides-workload-logs~1]
         cli::Commands::Logs(logs_args) => {
             cmd.get_logs_blocking(logs_args)
@@ -257,6 +403,40 @@ async fn main() {
                     output_and_error!("Failed to output logs: '{}'", err);
                 });
         }
+        // [impl->swdd~cli-provides-rollback-to-revision~1]
+        cli::Commands::Rollback(rollback_args) => {
+            output_debug!(
+                "Received rollback with revision='{}'",
+                rollback_args.revision
+            );
+            if let Err(error) = cmd.rollback(rollback_args.revision).await {
+                output_and_error!("Failed to roll back: '{}'", error);
+            }
+        }
+        // [impl->swdd~cli-provides-top~1]
+        cli::Commands::Top(top_args) => {
+            output_debug!(
+                "Received top with interval='{}', sort='{:?}', once='{}'",
+                top_args.interval_secs,
+                top_args.sort_key,
+                top_args.once
+            );
+            if top_args.once {
+                match cmd.top_once(top_args.sort_key).await {
+                    Ok(out_text) => output_and_exit!("{}", out_text),
+                    Err(error) => output_and_error!("Failed to get resource usage: '{}'", error),
+                }
+            } else if let Err(error) = cmd
+                .top(
+                    std::time::Duration::from_secs(top_args.interval_secs),
+                    top_args.sort_key,
+                )
+                .await
+            {
+                output_and_error!("Failed to show resource usage: '{}'", error);
+            }
+        }
+        cli::Commands::Config(_) => unreachable!("Config commands are handled before."),
     }
     cmd.shut_down().await;
 }
@@ -295,6 +475,7 @@ mod tests {
                     .unwrap()
                     .to_string(),
             ),
+            &None,
             &DEFAULT_ANK_CONFIG_FILE_PATH,
         );
 
@@ -306,15 +487,35 @@ mod tests {
         let mut file = tempfile::NamedTempFile::new().expect("Failed to create file");
         writeln!(file, "{VALID_ANK_CONFIG_CONTENT}").expect("Failed to write to file");
 
-        let ank_config = handle_ank_config(&None, file.path().to_str().unwrap());
+        let ank_config = handle_ank_config(&None, &None, file.path().to_str().unwrap());
 
         assert_eq!(ank_config.response_timeout, 2500);
     }
 
     #[test]
     fn utest_handle_ank_config_default() {
-        let ank_config = handle_ank_config(&None, "/a/very/invalid/path/to/config/file");
+        let ank_config = handle_ank_config(&None, &None, "/a/very/invalid/path/to/config/file");
 
         assert_eq!(ank_config, AnkConfig::default());
     }
+
+    // [utest->swdd~cli-selects-context~1]
+    #[test]
+    fn utest_handle_ank_config_context() {
+        let mut file = tempfile::NamedTempFile::new().expect("Failed to create file");
+        writeln!(
+            file,
+            "{VALID_ANK_CONFIG_CONTENT}\n[lab]\nresponse_timeout = 5000"
+        )
+        .expect("Failed to write to file");
+
+        let ank_config = handle_ank_config(
+            &None,
+            &Some("lab".to_string()),
+            file.path().to_str().unwrap(),
+        );
+
+        assert_eq!(ank_config.context, "lab");
+        assert_eq!(ank_config.response_timeout, 5000);
+    }
 }
@@ -17,6 +17,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .build_server(true)
         .boxed("Request.RequestContent.updateStateRequest")
         .boxed("FromAnkaios.FromAnkaiosEnum.response")
+        .boxed("CompleteState.workloadMetrics")
+        .boxed("CompleteState.renderedWorkloads")
+        .boxed("CompleteState.workloadStateHistory")
         .type_attribute(".", "#[derive(serde::Deserialize, serde::Serialize)]")
         .type_attribute(".", "#[serde(rename_all = \"camelCase\")]")
         .type_attribute(
@@ -39,7 +42,14 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .field_attribute("ExecutionsStatesForId.idStateMap", "#[serde(flatten)]")
         .field_attribute("WorkloadMap.workloads", "#[serde(flatten)]")
         .field_attribute("AgentMap.agents", "#[serde(flatten)]")
+        .field_attribute("WorkloadMetricsMap.agentMetricsMap", "#[serde(flatten)]")
+        .field_attribute(
+            "WorkloadMetricsOfAgent.wlNameMetricsMap",
+            "#[serde(flatten)]",
+        )
         .field_attribute("ConfigMap.configs", "#[serde(flatten)]")
+        .field_attribute("RenderedWorkloadMap.workloads", "#[serde(flatten)]")
+        .field_attribute("WorkloadStateHistoryMap.workloads", "#[serde(flatten)]")
         .field_attribute(
             "ControlInterfaceAccess.allowRules",
             "#[serde(with = \"serde_yaml::with::singleton_map_recursive\")]",
@@ -13,14 +13,17 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::objects::{
-    CompleteState, CpuUsage, DeletedWorkload, FreeMemory, WorkloadInstanceName, WorkloadSpec,
+    CompleteState, CpuUsage, DeletedWorkload, FreeMemory, WorkloadInstanceName, WorkloadMetrics,
+    WorkloadSpec,
 };
 use api::ank_base;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct AgentHello {
     pub agent_name: String,
+    pub labels: HashMap<String, String>,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -28,6 +31,7 @@ pub struct AgentLoadStatus {
     pub agent_name: String,
     pub cpu_usage: CpuUsage,
     pub free_memory: FreeMemory,
+    pub workload_metrics: HashMap<String, WorkloadMetrics>,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -83,6 +87,9 @@ pub enum RequestContent {
     UpdateStateRequest(Box<UpdateStateRequest>),
     LogsRequest(LogsRequest),
     LogsCancelRequest,
+    RevisionsRequest(RevisionsRequest),
+    EventsSubscribeRequest(EventsSubscribeRequest),
+    EventsCancelRequest,
 }
 
 impl From<RequestContent> for ank_base::request::RequestContent {
@@ -101,6 +108,19 @@ impl From<RequestContent> for ank_base::request::RequestContent {
             RequestContent::LogsCancelRequest => {
                 ank_base::request::RequestContent::LogsCancelRequest(ank_base::LogsCancelRequest {})
             }
+            RequestContent::RevisionsRequest(revisions_request) => {
+                ank_base::request::RequestContent::RevisionsRequest(revisions_request.into())
+            }
+            RequestContent::EventsSubscribeRequest(events_subscribe_request) => {
+                ank_base::request::RequestContent::EventsSubscribeRequest(
+                    events_subscribe_request.into(),
+                )
+            }
+            RequestContent::EventsCancelRequest => {
+                ank_base::request::RequestContent::EventsCancelRequest(
+                    ank_base::EventsCancelRequest {},
+                )
+            }
         }
     }
 }
@@ -122,6 +142,15 @@ impl TryFrom<ank_base::request::RequestContent> for RequestContent {
             ank_base::request::RequestContent::LogsCancelRequest(_logs_stop_request) => {
                 RequestContent::LogsCancelRequest
             }
+            ank_base::request::RequestContent::RevisionsRequest(revisions_request) => {
+                RequestContent::RevisionsRequest(revisions_request.into())
+            }
+            ank_base::request::RequestContent::EventsSubscribeRequest(events_subscribe_request) => {
+                RequestContent::EventsSubscribeRequest(events_subscribe_request.into())
+            }
+            ank_base::request::RequestContent::EventsCancelRequest(_events_cancel_request) => {
+                RequestContent::EventsCancelRequest
+            }
         })
     }
 }
@@ -188,6 +217,48 @@ impl From<ank_base::LogsCancelRequest> for LogsCancelRequest {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RevisionsRequest {
+    pub revision: Option<u64>,
+}
+
+impl From<RevisionsRequest> for ank_base::RevisionsRequest {
+    fn from(item: RevisionsRequest) -> Self {
+        ank_base::RevisionsRequest {
+            revision: item.revision,
+        }
+    }
+}
+
+impl From<ank_base::RevisionsRequest> for RevisionsRequest {
+    fn from(item: ank_base::RevisionsRequest) -> Self {
+        RevisionsRequest {
+            revision: item.revision,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventsSubscribeRequest {
+    pub field_masks: Vec<String>,
+}
+
+impl From<EventsSubscribeRequest> for ank_base::EventsSubscribeRequest {
+    fn from(item: EventsSubscribeRequest) -> Self {
+        ank_base::EventsSubscribeRequest {
+            field_masks: item.field_masks,
+        }
+    }
+}
+
+impl From<ank_base::EventsSubscribeRequest> for EventsSubscribeRequest {
+    fn from(item: ank_base::EventsSubscribeRequest) -> Self {
+        EventsSubscribeRequest {
+            field_masks: item.field_masks,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CompleteStateRequest {
     pub field_mask: Vec<String>,
@@ -213,6 +284,8 @@ impl From<ank_base::CompleteStateRequest> for CompleteStateRequest {
 pub struct UpdateStateRequest {
     pub state: CompleteState,
     pub update_mask: Vec<String>,
+    pub dry_run: bool,
+    pub expected_revision: Option<u64>,
 }
 
 impl From<UpdateStateRequest> for ank_base::UpdateStateRequest {
@@ -220,6 +293,8 @@ impl From<UpdateStateRequest> for ank_base::UpdateStateRequest {
         Self {
             new_state: Some(value.state.into()),
             update_mask: value.update_mask,
+            dry_run: value.dry_run,
+            expected_revision: value.expected_revision,
         }
     }
 }
@@ -231,6 +306,8 @@ impl TryFrom<ank_base::UpdateStateRequest> for UpdateStateRequest {
         Ok(UpdateStateRequest {
             state: item.new_state.unwrap_or_default().try_into()?,
             update_mask: item.update_mask,
+            dry_run: item.dry_run,
+            expected_revision: item.expected_revision,
         })
     }
 }
@@ -270,17 +347,18 @@ mod tests {
 
     mod ank_base {
         pub use api::ank_base::{
-            CompleteState, CompleteStateRequest, ConfigMappings, Dependencies, LogsCancelRequest,
-            LogsRequest, Request, RestartPolicy, State, Tag, Tags, UpdateStateRequest, Workload,
-            WorkloadInstanceName, WorkloadMap, request::RequestContent,
+            CompleteState, CompleteStateRequest, ConfigMappings, Dependencies, EventsCancelRequest,
+            EventsSubscribeRequest, LogsCancelRequest, LogsRequest, Request, RestartPolicy,
+            RevisionsRequest, State, Tag, Tags, UpdateStateRequest, Workload, WorkloadInstanceName,
+            WorkloadMap, request::RequestContent,
         };
     }
 
     mod ankaios {
         pub use crate::{
             commands::{
-                CompleteStateRequest, LogsCancelRequest, LogsRequest, Request, RequestContent,
-                UpdateStateRequest,
+                CompleteStateRequest, EventsSubscribeRequest, LogsCancelRequest, LogsRequest,
+                Request, RequestContent, RevisionsRequest, UpdateStateRequest,
             },
             objects::{
                 Base64Data, CompleteState, Data, ExecutionState, File, FileContent, RestartPolicy,
@@ -293,6 +371,7 @@ mod tests {
     const REQUEST_ID: &str = "request_id";
     const FIELD_1: &str = "field_1";
     const FIELD_2: &str = "field_2";
+    const EXPECTED_REVISION: u64 = 42;
     const AGENT_NAME: &str = "agent_1";
     const WORKLOAD_NAME_1: &str = "workload_name_1";
     const WORKLOAD_NAME_2: &str = "workload_name_2";
@@ -330,12 +409,16 @@ mod tests {
             ank_base::RequestContent::UpdateStateRequest(Box::new(ank_base::UpdateStateRequest {
                 new_state: complete_state!(ank_base).into(),
                 update_mask: vec![FIELD_1.into(), FIELD_2.into()],
+                dry_run: false,
+                expected_revision: Some(EXPECTED_REVISION),
             }))
         };
         (ankaios) => {
             ankaios::RequestContent::UpdateStateRequest(Box::new(ankaios::UpdateStateRequest {
                 state: complete_state!(ankaios),
                 update_mask: vec![FIELD_1.into(), FIELD_2.into()],
+                dry_run: false,
+                expected_revision: Some(EXPECTED_REVISION),
             }))
         };
     }
@@ -408,6 +491,68 @@ mod tests {
         };
     }
 
+    macro_rules! revisions_request {
+        (ank_base) => {
+            ank_base::Request {
+                request_id: REQUEST_ID.into(),
+                request_content: ank_base::RequestContent::RevisionsRequest(
+                    ank_base::RevisionsRequest { revision: Some(3) },
+                )
+                .into(),
+            }
+        };
+        (ankaios) => {
+            ankaios::Request {
+                request_id: REQUEST_ID.into(),
+                request_content: ankaios::RequestContent::RevisionsRequest(
+                    ankaios::RevisionsRequest { revision: Some(3) },
+                ),
+            }
+        };
+    }
+
+    macro_rules! events_subscribe_request {
+        (ank_base) => {
+            ank_base::Request {
+                request_id: REQUEST_ID.into(),
+                request_content: ank_base::RequestContent::EventsSubscribeRequest(
+                    ank_base::EventsSubscribeRequest {
+                        field_masks: vec![FIELD_1.into(), FIELD_2.into()],
+                    },
+                )
+                .into(),
+            }
+        };
+        (ankaios) => {
+            ankaios::Request {
+                request_id: REQUEST_ID.into(),
+                request_content: ankaios::RequestContent::EventsSubscribeRequest(
+                    ankaios::EventsSubscribeRequest {
+                        field_masks: vec![FIELD_1.into(), FIELD_2.into()],
+                    },
+                ),
+            }
+        };
+    }
+
+    macro_rules! events_cancel_request {
+        (ank_base) => {
+            ank_base::Request {
+                request_id: REQUEST_ID.into(),
+                request_content: ank_base::RequestContent::EventsCancelRequest(
+                    ank_base::EventsCancelRequest {},
+                )
+                .into(),
+            }
+        };
+        (ankaios) => {
+            ankaios::Request {
+                request_id: REQUEST_ID.into(),
+                request_content: ankaios::RequestContent::EventsCancelRequest,
+            }
+        };
+    }
+
     macro_rules! complete_state {
         (ankaios) => {
             ankaios::CompleteState {
@@ -419,6 +564,7 @@ mod tests {
                 .into(),
                 workload_states: workload_states_map!(ankaios),
                 agents: agent_map!(ankaios),
+                workload_metrics: Default::default(),
             }
         };
         (ank_base) => {
@@ -432,6 +578,10 @@ mod tests {
                 }),
                 workload_states: workload_states_map!(ank_base),
                 agents: agent_map!(ank_base),
+                revision: None,
+                workload_metrics: None,
+                rendered_workloads: None,
+                workload_state_history: None,
             }
         };
     }
@@ -459,6 +609,14 @@ mod tests {
                     .into(),
                 }),
                 files: Some(generate_test_proto_workload_files()),
+                agent_selector: None,
+                resource_requests: None,
+                agents: None,
+                update_strategy: None,
+                probes: None,
+                restart_backoff: None,
+                dependency_timeouts: None,
+                resources: None,
             }
         };
         (ankaios) => {
@@ -492,6 +650,14 @@ mod tests {
                         }),
                     },
                 ],
+                agent_selector: HashMap::new(),
+                resource_requests: None,
+                agents: vec![],
+                update_strategy: None,
+                probes: None,
+                restart_backoff: None,
+                dependency_timeouts: HashMap::new(),
+                resources: None,
             }
         };
     }
@@ -545,6 +711,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn utest_converts_from_proto_update_state_request_with_dry_run() {
+        let mut proto_request_update_state = update_state_request!(ank_base);
+        let mut ankaios_request_update_state = update_state_request!(ankaios);
+
+        let ank_base::RequestContent::UpdateStateRequest(proto_request_content) =
+            proto_request_update_state.request_content.as_mut().unwrap()
+        else {
+            unreachable!()
+        };
+        proto_request_content.dry_run = true;
+        let ankaios::RequestContent::UpdateStateRequest(ankaios_request_content) =
+            &mut ankaios_request_update_state.request_content
+        else {
+            unreachable!()
+        };
+        ankaios_request_content.dry_run = true;
+
+        assert_eq!(
+            ankaios::Request::try_from(proto_request_update_state).unwrap(),
+            ankaios_request_update_state
+        );
+    }
+
     #[test]
     fn utest_converts_from_proto_update_state_request_with_empty_states() {
         let mut proto_request_complete_state = update_state_request!(ank_base);
@@ -752,6 +942,66 @@ mod tests {
         );
     }
 
+    #[test]
+    fn utest_converts_from_proto_revisions_request() {
+        let proto_revisions_request = revisions_request!(ank_base);
+        let ankaios_revisions_request = revisions_request!(ankaios);
+        assert_eq!(
+            ankaios::Request::try_from(proto_revisions_request).unwrap(),
+            ankaios_revisions_request
+        );
+    }
+
+    #[test]
+    fn utest_converts_to_proto_revisions_request() {
+        let proto_revisions_request = revisions_request!(ank_base);
+        let ankaios_revisions_request = revisions_request!(ankaios);
+        assert_eq!(
+            ank_base::Request::from(ankaios_revisions_request),
+            proto_revisions_request
+        );
+    }
+
+    #[test]
+    fn utest_converts_from_proto_events_subscribe_request() {
+        let proto_events_subscribe_request = events_subscribe_request!(ank_base);
+        let ankaios_events_subscribe_request = events_subscribe_request!(ankaios);
+        assert_eq!(
+            ankaios::Request::try_from(proto_events_subscribe_request).unwrap(),
+            ankaios_events_subscribe_request
+        );
+    }
+
+    #[test]
+    fn utest_converts_to_proto_events_subscribe_request() {
+        let proto_events_subscribe_request = events_subscribe_request!(ank_base);
+        let ankaios_events_subscribe_request = events_subscribe_request!(ankaios);
+        assert_eq!(
+            ank_base::Request::from(ankaios_events_subscribe_request),
+            proto_events_subscribe_request
+        );
+    }
+
+    #[test]
+    fn utest_converts_from_proto_events_cancel_request() {
+        let proto_events_cancel_request = events_cancel_request!(ank_base);
+        let ankaios_events_cancel_request = events_cancel_request!(ankaios);
+        assert_eq!(
+            ankaios::Request::try_from(proto_events_cancel_request).unwrap(),
+            ankaios_events_cancel_request
+        );
+    }
+
+    #[test]
+    fn utest_converts_to_proto_events_cancel_request() {
+        let proto_events_cancel_request = events_cancel_request!(ank_base);
+        let ankaios_events_cancel_request = events_cancel_request!(ankaios);
+        assert_eq!(
+            ank_base::Request::from(ankaios_events_cancel_request),
+            proto_events_cancel_request
+        );
+    }
+
     #[test]
     fn utest_converts_from_proto_request_fails_empty_request_content() {
         let proto_request = ank_base::Request {
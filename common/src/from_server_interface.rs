@@ -74,6 +74,11 @@ pub trait FromServerInterface {
         added_workloads: Vec<String>,
         deleted_workloads: Vec<String>,
     ) -> Result<(), FromServerInterfaceError>;
+    async fn revisions(
+        &self,
+        request_id: String,
+        revisions: Vec<ank_base::Revision>,
+    ) -> Result<(), FromServerInterfaceError>;
     async fn logs_request(
         &self,
         request_id: String,
@@ -100,11 +105,25 @@ pub trait FromServerInterface {
         &self,
         request_id: String,
     ) -> Result<(), FromServerInterfaceError>;
+    async fn event_response(
+        &self,
+        request_id: String,
+        event_response: ank_base::EventResponse,
+    ) -> Result<(), FromServerInterfaceError>;
+    async fn events_cancel_request_accepted(
+        &self,
+        request_id: String,
+    ) -> Result<(), FromServerInterfaceError>;
     async fn error(
         &self,
         request_id: String,
         message: String,
     ) -> Result<(), FromServerInterfaceError>;
+    async fn conflict_error(
+        &self,
+        request_id: String,
+        message: String,
+    ) -> Result<(), FromServerInterfaceError>;
     async fn stop(&self) -> Result<(), FromServerInterfaceError>;
 }
 
@@ -191,6 +210,22 @@ impl FromServerInterface for FromServerSender {
             .await?)
     }
 
+    async fn revisions(
+        &self,
+        request_id: String,
+        revisions: Vec<ank_base::Revision>,
+    ) -> Result<(), FromServerInterfaceError> {
+        Ok(self
+            .send(FromServer::Response(ank_base::Response {
+                request_id,
+                response_content: ank_base::response::ResponseContent::Revisions(
+                    ank_base::Revisions { revisions },
+                )
+                .into(),
+            }))
+            .await?)
+    }
+
     async fn logs_request(
         &self,
         request_id: String,
@@ -274,6 +309,37 @@ impl FromServerInterface for FromServerSender {
         Ok(())
     }
 
+    async fn event_response(
+        &self,
+        request_id: String,
+        event_response: ank_base::EventResponse,
+    ) -> Result<(), FromServerInterfaceError> {
+        Ok(self
+            .send(FromServer::Response(ank_base::Response {
+                request_id,
+                response_content: ank_base::response::ResponseContent::EventResponse(
+                    event_response,
+                )
+                .into(),
+            }))
+            .await?)
+    }
+
+    async fn events_cancel_request_accepted(
+        &self,
+        request_id: String,
+    ) -> Result<(), FromServerInterfaceError> {
+        Ok(self
+            .send(FromServer::Response(ank_base::Response {
+                request_id,
+                response_content: ank_base::response::ResponseContent::EventsCancelAccepted(
+                    ank_base::EventsCancelAccepted {},
+                )
+                .into(),
+            }))
+            .await?)
+    }
+
     async fn error(
         &self,
         request_id: String,
@@ -284,6 +350,24 @@ impl FromServerInterface for FromServerSender {
                 request_id,
                 response_content: ank_base::response::ResponseContent::Error(ank_base::Error {
                     message,
+                    ..Default::default()
+                })
+                .into(),
+            }))
+            .await?)
+    }
+
+    async fn conflict_error(
+        &self,
+        request_id: String,
+        message: String,
+    ) -> Result<(), FromServerInterfaceError> {
+        Ok(self
+            .send(FromServer::Response(ank_base::Response {
+                request_id,
+                response_content: ank_base::response::ResponseContent::Error(ank_base::Error {
+                    message,
+                    code: ank_base::ErrorCode::Conflict.into(),
                 })
                 .into(),
             }))
@@ -429,6 +513,85 @@ mod tests {
         )
     }
 
+    // [utest->swdd~from-server-channel~1]
+    #[tokio::test]
+    async fn utest_to_server_send_revisions() {
+        let (tx, mut rx): (FromServerSender, FromServerReceiver) =
+            tokio::sync::mpsc::channel(TEST_CHANNEL_CAPA);
+
+        let revisions = vec![ank_base::Revision {
+            revision: 1,
+            requester: "cli-conn-1".to_string(),
+            added_workloads: vec![WORKLOAD_NAME_1.to_string()],
+            ..Default::default()
+        }];
+        assert!(
+            tx.revisions(REQUEST_ID.to_string(), revisions.clone())
+                .await
+                .is_ok()
+        );
+
+        assert_eq!(
+            rx.recv().await.unwrap(),
+            FromServer::Response(ank_base::Response {
+                request_id: REQUEST_ID.to_string(),
+                response_content: Some(ank_base::response::ResponseContent::Revisions(
+                    ank_base::Revisions { revisions },
+                )),
+            })
+        )
+    }
+
+    // [utest->swdd~from-server-channel~1]
+    #[tokio::test]
+    async fn utest_to_server_send_event_response() {
+        let (tx, mut rx): (FromServerSender, FromServerReceiver) =
+            tokio::sync::mpsc::channel(TEST_CHANNEL_CAPA);
+
+        let event_response = ank_base::EventResponse {
+            complete_state: Some(ank_base::CompleteState::default()),
+            changed_field_masks: vec!["workloadStates".to_string()],
+        };
+        assert!(
+            tx.event_response(REQUEST_ID.to_string(), event_response.clone())
+                .await
+                .is_ok()
+        );
+
+        assert_eq!(
+            rx.recv().await.unwrap(),
+            FromServer::Response(ank_base::Response {
+                request_id: REQUEST_ID.to_string(),
+                response_content: Some(ank_base::response::ResponseContent::EventResponse(
+                    event_response
+                )),
+            })
+        )
+    }
+
+    // [utest->swdd~from-server-channel~1]
+    #[tokio::test]
+    async fn utest_to_server_send_events_cancel_request_accepted() {
+        let (tx, mut rx): (FromServerSender, FromServerReceiver) =
+            tokio::sync::mpsc::channel(TEST_CHANNEL_CAPA);
+
+        assert!(
+            tx.events_cancel_request_accepted(REQUEST_ID.to_string())
+                .await
+                .is_ok()
+        );
+
+        assert_eq!(
+            rx.recv().await.unwrap(),
+            FromServer::Response(ank_base::Response {
+                request_id: REQUEST_ID.to_string(),
+                response_content: Some(ank_base::response::ResponseContent::EventsCancelAccepted(
+                    ank_base::EventsCancelAccepted {}
+                )),
+            })
+        )
+    }
+
     // [utest->swdd~from-server-channel~1]
     #[tokio::test]
     async fn utest_to_server_send_error() {
@@ -437,6 +600,7 @@ mod tests {
 
         let error = ank_base::Error {
             message: "error".to_string(),
+            ..Default::default()
         };
         assert!(
             tx.error(REQUEST_ID.to_string(), error.message.clone())
@@ -453,6 +617,32 @@ mod tests {
         )
     }
 
+    // [utest->swdd~from-server-channel~1]
+    #[tokio::test]
+    async fn utest_to_server_send_conflict_error() {
+        let (tx, mut rx): (FromServerSender, FromServerReceiver) =
+            tokio::sync::mpsc::channel(TEST_CHANNEL_CAPA);
+
+        assert!(
+            tx.conflict_error(REQUEST_ID.to_string(), "conflict".to_string())
+                .await
+                .is_ok()
+        );
+
+        assert_eq!(
+            rx.recv().await.unwrap(),
+            FromServer::Response(ank_base::Response {
+                request_id: REQUEST_ID.to_string(),
+                response_content: Some(ank_base::response::ResponseContent::Error(
+                    ank_base::Error {
+                        message: "conflict".to_string(),
+                        code: ank_base::ErrorCode::Conflict.into(),
+                    }
+                )),
+            })
+        )
+    }
+
     #[tokio::test]
     async fn utest_logs_request_success() {
         let (tx, mut rx): (FromServerSender, FromServerReceiver) =
@@ -543,7 +733,8 @@ mod tests {
                                 agent_name: AGENT_NAME.into(),
                                 id: "1".into()
                             }),
-                            message: "message_1".into()
+                            message: "message_1".into(),
+                            ..Default::default()
                         },
                         ank_base::LogEntry {
                             workload_name: Some(ank_base::WorkloadInstanceName {
@@ -551,7 +742,8 @@ mod tests {
                                 agent_name: AGENT_NAME.into(),
                                 id: "2".into()
                             }),
-                            message: "message_2".into()
+                            message: "message_2".into(),
+                            ..Default::default()
                         }
                     ]
                 }
@@ -573,7 +765,8 @@ mod tests {
                                     agent_name: AGENT_NAME.into(),
                                     id: "1".into()
                                 }),
-                                message: "message_1".into()
+                                message: "message_1".into(),
+                                ..Default::default()
                             },
                             ank_base::LogEntry {
                                 workload_name: Some(ank_base::WorkloadInstanceName {
@@ -581,7 +774,8 @@ mod tests {
                                     agent_name: AGENT_NAME.into(),
                                     id: "2".into()
                                 }),
-                                message: "message_2".into()
+                                message: "message_2".into(),
+                                ..Default::default()
                             }
                         ]
                     }
@@ -605,7 +799,8 @@ mod tests {
                             agent_name: AGENT_NAME.into(),
                             id: "1".into()
                         }),
-                        message: "message_1".into()
+                        message: "message_1".into(),
+                        ..Default::default()
                     }]
                 }
             )
@@ -63,6 +63,39 @@ pub fn check_version_compatibility(version: impl AsRef<str>) -> Result<(), Strin
     ))
 }
 
+// Formats seconds since the UNIX epoch as an RFC3339 timestamp in UTC, e.g. '2024-03-01T12:30:00Z'.
+// The conversion of days to a civil date follows the algorithm of Howard Hinnant.
+pub fn format_unix_timestamp(seconds: u64) -> String {
+    const SECONDS_PER_DAY: u64 = 86_400;
+    const DAYS_PER_ERA: u64 = 146_097;
+    // days between 0000-03-01 and 1970-01-01
+    const UNIX_EPOCH_DAYS_OFFSET: u64 = 719_468;
+
+    let time_of_day = seconds % SECONDS_PER_DAY;
+    let days = seconds / SECONDS_PER_DAY + UNIX_EPOCH_DAYS_OFFSET;
+
+    let era = days / DAYS_PER_ERA;
+    let day_of_era = days % DAYS_PER_ERA;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        time_of_day / 3600,
+        time_of_day % 3600 / 60,
+        time_of_day % 60
+    )
+}
+
 //////////////////////////////////////////////////////////////////////////////
 //                 ########  #######    #########  #########                //
 //                    ##     ##        ##             ##                    //
@@ -75,7 +108,7 @@ pub fn check_version_compatibility(version: impl AsRef<str>) -> Result<(), Strin
 mod tests {
     use semver::Version;
 
-    use crate::{check_version_compatibility, ANKAIOS_VERSION};
+    use crate::{check_version_compatibility, helpers::format_unix_timestamp, ANKAIOS_VERSION};
 
     // [utest->swdd~common-version-checking~1]
     #[test]
@@ -109,4 +142,12 @@ mod tests {
         assert_eq!(0, version.major);
         assert!(check_version_compatibility(version.to_string()).is_err())
     }
+
+    #[test]
+    fn utest_format_unix_timestamp() {
+        assert_eq!(format_unix_timestamp(0), "1970-01-01T00:00:00Z");
+        assert_eq!(format_unix_timestamp(951_782_400), "2000-02-29T00:00:00Z");
+        assert_eq!(format_unix_timestamp(1_700_000_000), "2023-11-14T22:13:20Z");
+        assert_eq!(format_unix_timestamp(4_102_444_799), "2099-12-31T23:59:59Z");
+    }
 }
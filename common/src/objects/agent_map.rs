@@ -14,10 +14,13 @@
 
 use api::ank_base;
 use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
 use std::collections::{hash_map::Entry, HashMap};
 
 use crate::commands;
 
+use super::{MAX_CPU_USAGE, ResourceRequests};
+
 type AgentName = String;
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
@@ -42,6 +45,47 @@ pub struct FreeMemory {
 pub struct AgentAttributes {
     pub cpu_usage: Option<CpuUsage>,
     pub free_memory: Option<FreeMemory>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub labels: HashMap<String, String>,
+}
+
+impl AgentAttributes {
+    // [impl->swdd~agent-map-matches-agent-selector~1]
+    pub fn matches_selector(&self, agent_selector: &HashMap<String, String>) -> bool {
+        agent_selector
+            .iter()
+            .all(|(key, value)| self.labels.get(key) == Some(value))
+    }
+
+    // [impl->swdd~agent-map-checks-resource-headroom~1]
+    // An agent that has not reported its load yet is not known to lack resources.
+    pub fn has_headroom_for(
+        &self,
+        resource_requests: &ResourceRequests,
+        reserved_resources: Option<&ResourceRequests>,
+    ) -> bool {
+        let (reserved_cpu, reserved_memory) =
+            reserved_resources.map_or((0, 0), |reserved| (reserved.cpu, reserved.memory));
+        let cpu_usage = self.cpu_usage_with(reserved_cpu);
+
+        cpu_usage.saturating_add(resource_requests.cpu) <= MAX_CPU_USAGE
+            && self
+                .free_memory_with(reserved_memory)
+                .is_none_or(|free_memory| free_memory >= resource_requests.memory)
+    }
+
+    fn cpu_usage_with(&self, reserved_cpu: u32) -> u32 {
+        self.cpu_usage
+            .as_ref()
+            .map_or(0, |usage| usage.cpu_usage)
+            .saturating_add(reserved_cpu)
+    }
+
+    fn free_memory_with(&self, reserved_memory: u64) -> Option<u64> {
+        self.free_memory
+            .as_ref()
+            .map(|memory| memory.free_memory.saturating_sub(reserved_memory))
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
@@ -65,6 +109,49 @@ impl AgentMap {
         self.0.remove(key);
     }
 
+    // [impl->swdd~agent-map-matches-agent-selector~1]
+    pub fn is_selected_by(
+        &self,
+        agent_name: &str,
+        agent_selector: &HashMap<String, String>,
+    ) -> bool {
+        self.0
+            .get(agent_name)
+            .is_some_and(|attributes| attributes.matches_selector(agent_selector))
+    }
+
+    // [impl->swdd~agent-map-matches-agent-selector~1]
+    // [impl->swdd~agent-map-checks-resource-headroom~1]
+    pub fn find_selected_agent(
+        &self,
+        agent_selector: &HashMap<String, String>,
+        resource_requests: Option<&ResourceRequests>,
+        reserved_resources: &HashMap<AgentName, ResourceRequests>,
+    ) -> Option<&str> {
+        self.0
+            .iter()
+            .filter(|(_, attributes)| attributes.matches_selector(agent_selector))
+            .filter(|(agent_name, attributes)| {
+                resource_requests.is_none_or(|requests| {
+                    attributes.has_headroom_for(requests, reserved_resources.get(*agent_name))
+                })
+            })
+            // prefer the least loaded agent, the name only breaks ties
+            .min_by_key(|(agent_name, attributes)| {
+                let reserved = reserved_resources.get(*agent_name);
+                (
+                    attributes.cpu_usage_with(reserved.map_or(0, |reserved| reserved.cpu)),
+                    Reverse(
+                        attributes
+                            .free_memory_with(reserved.map_or(0, |reserved| reserved.memory))
+                            .unwrap_or(u64::MAX),
+                    ),
+                    agent_name.as_str(),
+                )
+            })
+            .map(|(agent_name, _)| agent_name.as_str())
+    }
+
     pub fn update_resource_availability(&mut self, agent_load_status: commands::AgentLoadStatus) {
         self.0.entry(agent_load_status.agent_name).and_modify(|e| {
             e.cpu_usage = Some(agent_load_status.cpu_usage);
@@ -108,12 +195,9 @@ impl From<ank_base::FreeMemory> for FreeMemory {
 impl From<AgentAttributes> for ank_base::AgentAttributes {
     fn from(item: AgentAttributes) -> ank_base::AgentAttributes {
         ank_base::AgentAttributes {
-            cpu_usage: Some(ank_base::CpuUsage {
-                cpu_usage: item.cpu_usage.unwrap_or_default().cpu_usage,
-            }),
-            free_memory: Some(ank_base::FreeMemory {
-                free_memory: item.free_memory.unwrap_or_default().free_memory,
-            }),
+            cpu_usage: item.cpu_usage.map(Into::into),
+            free_memory: item.free_memory.map(Into::into),
+            labels: item.labels,
         }
     }
 }
@@ -121,12 +205,9 @@ impl From<AgentAttributes> for ank_base::AgentAttributes {
 impl From<ank_base::AgentAttributes> for AgentAttributes {
     fn from(item: ank_base::AgentAttributes) -> Self {
         AgentAttributes {
-            cpu_usage: Some(CpuUsage {
-                cpu_usage: item.cpu_usage.unwrap_or_default().cpu_usage,
-            }),
-            free_memory: Some(FreeMemory {
-                free_memory: item.free_memory.unwrap_or_default().free_memory,
-            }),
+            cpu_usage: item.cpu_usage.map(Into::into),
+            free_memory: item.free_memory.map(Into::into),
+            labels: item.labels,
         }
     }
 }
@@ -174,6 +255,7 @@ pub fn generate_test_agent_map(agent_name: impl Into<String>) -> AgentMap {
         .or_insert(AgentAttributes {
             cpu_usage: Some(CpuUsage { cpu_usage: 42 }),
             free_memory: Some(FreeMemory { free_memory: 42 }),
+            labels: HashMap::new(),
         });
     agent_map
 }
@@ -189,7 +271,198 @@ pub fn generate_test_agent_map_from_specs(workloads: &[crate::objects::WorkloadS
                 .or_insert(AgentAttributes {
                     cpu_usage: Some(CpuUsage { cpu_usage: 42 }),
                     free_memory: Some(FreeMemory { free_memory: 42 }),
+                    labels: HashMap::new(),
                 });
             agent_map
         })
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{AgentAttributes, AgentMap, CpuUsage, FreeMemory};
+    use crate::objects::ResourceRequests;
+
+    const AGENT_A: &str = "agent_A";
+    const AGENT_B: &str = "agent_B";
+
+    fn generate_agent_map_with_labels() -> AgentMap {
+        let mut agent_map = AgentMap::new();
+        agent_map
+            .entry(AGENT_B.to_owned())
+            .or_insert(AgentAttributes {
+                labels: HashMap::from([
+                    ("arch".to_owned(), "arm64".to_owned()),
+                    ("gpu".to_owned(), "true".to_owned()),
+                ]),
+                ..Default::default()
+            });
+        agent_map
+            .entry(AGENT_A.to_owned())
+            .or_insert(AgentAttributes {
+                labels: HashMap::from([("arch".to_owned(), "arm64".to_owned())]),
+                ..Default::default()
+            });
+        agent_map
+    }
+
+    // [utest->swdd~agent-map-matches-agent-selector~1]
+    #[test]
+    fn utest_agent_map_is_selected_by() {
+        let agent_map = generate_agent_map_with_labels();
+        let agent_selector = HashMap::from([("gpu".to_owned(), "true".to_owned())]);
+
+        assert!(agent_map.is_selected_by(AGENT_B, &agent_selector));
+        assert!(!agent_map.is_selected_by(AGENT_A, &agent_selector));
+        assert!(!agent_map.is_selected_by("unknown_agent", &agent_selector));
+    }
+
+    // [utest->swdd~agent-map-matches-agent-selector~1]
+    #[test]
+    fn utest_agent_map_find_selected_agent_breaks_ties_by_name() {
+        let agent_map = generate_agent_map_with_labels();
+
+        assert_eq!(
+            agent_map.find_selected_agent(
+                &HashMap::from([("arch".to_owned(), "arm64".to_owned())]),
+                None,
+                &HashMap::new()
+            ),
+            Some(AGENT_A)
+        );
+        assert_eq!(
+            agent_map.find_selected_agent(
+                &HashMap::from([("gpu".to_owned(), "true".to_owned())]),
+                None,
+                &HashMap::new()
+            ),
+            Some(AGENT_B)
+        );
+        assert_eq!(
+            agent_map.find_selected_agent(
+                &HashMap::from([("arch".to_owned(), "x86_64".to_owned())]),
+                None,
+                &HashMap::new()
+            ),
+            None
+        );
+    }
+
+    // [utest->swdd~agent-map-checks-resource-headroom~1]
+    #[test]
+    fn utest_agent_attributes_has_headroom_for() {
+        let agent_attributes = AgentAttributes {
+            cpu_usage: Some(CpuUsage { cpu_usage: 70 }),
+            free_memory: Some(FreeMemory { free_memory: 1024 }),
+            ..Default::default()
+        };
+
+        assert!(agent_attributes.has_headroom_for(
+            &ResourceRequests {
+                cpu: 30,
+                memory: 1024
+            },
+            None
+        ));
+        assert!(!agent_attributes.has_headroom_for(&ResourceRequests { cpu: 31, memory: 0 }, None));
+        assert!(!agent_attributes.has_headroom_for(
+            &ResourceRequests {
+                cpu: 0,
+                memory: 1025
+            },
+            None
+        ));
+        assert!(!agent_attributes.has_headroom_for(
+            &ResourceRequests { cpu: 20, memory: 0 },
+            Some(&ResourceRequests { cpu: 20, memory: 0 })
+        ));
+    }
+
+    // [utest->swdd~agent-map-checks-resource-headroom~1]
+    #[test]
+    fn utest_agent_attributes_without_load_report_has_unknown_headroom() {
+        let agent_attributes = AgentAttributes::default();
+
+        assert!(agent_attributes.has_headroom_for(
+            &ResourceRequests {
+                cpu: 100,
+                memory: u64::MAX
+            },
+            None
+        ));
+    }
+
+    // [utest->swdd~agent-map-checks-resource-headroom~1]
+    #[test]
+    fn utest_agent_map_find_selected_agent_skips_agents_without_headroom() {
+        let mut agent_map = generate_agent_map_with_labels();
+        agent_map.update_resource_availability(crate::commands::AgentLoadStatus {
+            agent_name: AGENT_A.to_owned(),
+            cpu_usage: CpuUsage { cpu_usage: 90 },
+            free_memory: FreeMemory { free_memory: 2048 },
+            workload_metrics: Default::default(),
+        });
+        agent_map.update_resource_availability(crate::commands::AgentLoadStatus {
+            agent_name: AGENT_B.to_owned(),
+            cpu_usage: CpuUsage { cpu_usage: 10 },
+            free_memory: FreeMemory { free_memory: 2048 },
+            workload_metrics: Default::default(),
+        });
+        let resource_requests = ResourceRequests {
+            cpu: 20,
+            memory: 1024,
+        };
+
+        assert_eq!(
+            agent_map.find_selected_agent(
+                &HashMap::new(),
+                Some(&resource_requests),
+                &HashMap::new()
+            ),
+            Some(AGENT_B)
+        );
+
+        let reserved_resources =
+            HashMap::from([(AGENT_B.to_owned(), ResourceRequests { cpu: 80, memory: 0 })]);
+        assert_eq!(
+            agent_map.find_selected_agent(
+                &HashMap::new(),
+                Some(&resource_requests),
+                &reserved_resources
+            ),
+            None
+        );
+    }
+
+    // [utest->swdd~agent-map-matches-agent-selector~1]
+    #[test]
+    fn utest_agent_map_find_selected_agent_prefers_least_loaded_agent() {
+        let mut agent_map = generate_agent_map_with_labels();
+        agent_map.update_resource_availability(crate::commands::AgentLoadStatus {
+            agent_name: AGENT_A.to_owned(),
+            cpu_usage: CpuUsage { cpu_usage: 50 },
+            free_memory: FreeMemory { free_memory: 2048 },
+            workload_metrics: Default::default(),
+        });
+        agent_map.update_resource_availability(crate::commands::AgentLoadStatus {
+            agent_name: AGENT_B.to_owned(),
+            cpu_usage: CpuUsage { cpu_usage: 10 },
+            free_memory: FreeMemory { free_memory: 2048 },
+            workload_metrics: Default::default(),
+        });
+        let agent_selector = HashMap::from([("arch".to_owned(), "arm64".to_owned())]);
+
+        assert_eq!(
+            agent_map.find_selected_agent(&agent_selector, None, &HashMap::new()),
+            Some(AGENT_B)
+        );
+
+        let reserved_resources =
+            HashMap::from([(AGENT_B.to_owned(), ResourceRequests { cpu: 50, memory: 0 })]);
+        assert_eq!(
+            agent_map.find_selected_agent(&agent_selector, None, &reserved_resources),
+            Some(AGENT_A)
+        );
+    }
+}
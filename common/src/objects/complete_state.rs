@@ -15,7 +15,7 @@
 use api::ank_base;
 use serde::{Deserialize, Serialize};
 
-use super::{AgentMap, State, WorkloadStatesMap};
+use super::{AgentMap, State, WorkloadMetricsMap, WorkloadStatesMap};
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
@@ -26,6 +26,8 @@ pub struct CompleteState {
     pub workload_states: WorkloadStatesMap,
     #[serde(default)]
     pub agents: AgentMap,
+    #[serde(default, skip_serializing_if = "WorkloadMetricsMap::is_empty")]
+    pub workload_metrics: WorkloadMetricsMap,
 }
 
 impl From<CompleteState> for ank_base::CompleteState {
@@ -34,6 +36,11 @@ impl From<CompleteState> for ank_base::CompleteState {
             desired_state: Some(ank_base::State::from(item.desired_state)),
             workload_states: item.workload_states.into(),
             agents: item.agents.into(),
+            revision: None,
+            workload_metrics: Option::<ank_base::WorkloadMetricsMap>::from(item.workload_metrics)
+                .map(Box::new),
+            rendered_workloads: None,
+            workload_state_history: None,
         }
     }
 }
@@ -46,6 +53,7 @@ impl TryFrom<ank_base::CompleteState> for CompleteState {
             desired_state: item.desired_state.unwrap_or_default().try_into()?,
             workload_states: item.workload_states.unwrap_or_default().into(),
             agents: item.agents.unwrap_or_default().into(),
+            workload_metrics: (*item.workload_metrics.unwrap_or_default()).into(),
         })
     }
 }
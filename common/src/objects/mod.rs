@@ -52,7 +52,7 @@ pub use workload_state::{
 };
 pub use workload_state::{
     ExecutionState, ExecutionStateEnum, FailedSubstate, PendingSubstate, RunningSubstate,
-    StoppingSubstate, SucceededSubstate, WorkloadState, NO_MORE_RETRIES_MSG,
+    StoppingSubstate, SucceededSubstate, WorkloadState, NO_MORE_RESTARTS_MSG, NO_MORE_RETRIES_MSG,
 };
 
 mod workload_spec;
@@ -72,6 +72,24 @@ pub use workload_spec::{
     WorkloadSpec,
 };
 
+mod resource_requests;
+pub use resource_requests::{ResourceRequests, MAX_CPU_USAGE};
+
+mod update_strategy;
+pub use update_strategy::{RollingUpdate, UpdateOrdering, UpdateStrategy};
+
+mod probes;
+pub use probes::{ExecProbe, HttpGetProbe, Probe, Probes, TcpSocketProbe};
+
+mod restart_backoff;
+pub use restart_backoff::RestartBackoff;
+
+mod resource_limits;
+pub use resource_limits::ResourceLimits;
+
+mod workload_metrics;
+pub use workload_metrics::{WorkloadMetrics, WorkloadMetricsMap};
+
 mod tag;
 pub use tag::Tag;
 
@@ -0,0 +1,328 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use api::ank_base;
+use serde::{Deserialize, Serialize};
+
+const DEFAULT_PERIOD_SECONDS: u32 = 10;
+const DEFAULT_TIMEOUT_SECONDS: u32 = 1;
+const DEFAULT_FAILURE_THRESHOLD: u32 = 3;
+const DEFAULT_HTTP_PATH: &str = "/";
+const MAX_PORT: u32 = 65535;
+
+// [impl->swdd~common-workload-health-probes~1]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Probes {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub liveness: Option<Probe>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub readiness: Option<Probe>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Probe {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exec: Option<ExecProbe>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tcp_socket: Option<TcpSocketProbe>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http_get: Option<HttpGetProbe>,
+    /// The time in seconds to wait after the workload is running before the first probe.
+    #[serde(default)]
+    pub initial_delay_seconds: u32,
+    /// The time in seconds between two probes.
+    #[serde(default = "default_period_seconds")]
+    pub period_seconds: u32,
+    /// The time in seconds after which a probe counts as failed.
+    #[serde(default = "default_timeout_seconds")]
+    pub timeout_seconds: u32,
+    /// The number of consecutive failed probes after which the probe counts as failed.
+    #[serde(default = "default_failure_threshold")]
+    pub failure_threshold: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecProbe {
+    pub command: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TcpSocketProbe {
+    pub port: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct HttpGetProbe {
+    pub port: u32,
+    #[serde(default = "default_http_path")]
+    pub path: String,
+}
+
+fn default_period_seconds() -> u32 {
+    DEFAULT_PERIOD_SECONDS
+}
+
+fn default_timeout_seconds() -> u32 {
+    DEFAULT_TIMEOUT_SECONDS
+}
+
+fn default_failure_threshold() -> u32 {
+    DEFAULT_FAILURE_THRESHOLD
+}
+
+fn default_http_path() -> String {
+    DEFAULT_HTTP_PATH.to_string()
+}
+
+impl Default for Probe {
+    fn default() -> Self {
+        Probe {
+            exec: None,
+            tcp_socket: None,
+            http_get: None,
+            initial_delay_seconds: 0,
+            period_seconds: DEFAULT_PERIOD_SECONDS,
+            timeout_seconds: DEFAULT_TIMEOUT_SECONDS,
+            failure_threshold: DEFAULT_FAILURE_THRESHOLD,
+        }
+    }
+}
+
+impl Probes {
+    pub fn verify_fields(&self) -> Result<(), String> {
+        if let Some(liveness) = &self.liveness {
+            liveness
+                .verify_fields()
+                .map_err(|err| format!("Unsupported liveness probe. {err}"))?;
+        }
+        if let Some(readiness) = &self.readiness {
+            readiness
+                .verify_fields()
+                .map_err(|err| format!("Unsupported readiness probe. {err}"))?;
+        }
+        Ok(())
+    }
+
+    pub fn has_exec_probe(&self) -> bool {
+        [&self.liveness, &self.readiness]
+            .into_iter()
+            .flatten()
+            .any(|probe| probe.exec.is_some())
+    }
+}
+
+impl Probe {
+    fn verify_fields(&self) -> Result<(), String> {
+        let actions = [
+            self.exec.is_some(),
+            self.tcp_socket.is_some(),
+            self.http_get.is_some(),
+        ];
+        if actions.iter().filter(|is_set| **is_set).count() != 1 {
+            return Err(
+                "Expected exactly one of the fields exec, tcpSocket or httpGet".to_string(),
+            );
+        }
+        if self
+            .exec
+            .as_ref()
+            .is_some_and(|exec| exec.command.is_empty())
+        {
+            return Err("The command of the exec probe is empty".to_string());
+        }
+        if let Some(port) = self
+            .tcp_socket
+            .as_ref()
+            .map(|tcp_socket| tcp_socket.port)
+            .or(self.http_get.as_ref().map(|http_get| http_get.port))
+            .filter(|port| *port == 0 || *port > MAX_PORT)
+        {
+            return Err(format!(
+                "Received the port '{port}', expected a value between 1 and {MAX_PORT}"
+            ));
+        }
+        if self.period_seconds == 0 || self.timeout_seconds == 0 || self.failure_threshold == 0 {
+            return Err(
+                "The fields periodSeconds, timeoutSeconds and failureThreshold must be at least 1"
+                    .to_string(),
+            );
+        }
+        Ok(())
+    }
+}
+
+impl From<ank_base::Probes> for Probes {
+    fn from(item: ank_base::Probes) -> Self {
+        Probes {
+            liveness: item.liveness.map(Into::into),
+            readiness: item.readiness.map(Into::into),
+        }
+    }
+}
+
+impl From<Probes> for ank_base::Probes {
+    fn from(item: Probes) -> Self {
+        ank_base::Probes {
+            liveness: item.liveness.map(Into::into),
+            readiness: item.readiness.map(Into::into),
+        }
+    }
+}
+
+impl From<ank_base::Probe> for Probe {
+    fn from(item: ank_base::Probe) -> Self {
+        Probe {
+            exec: item.exec.map(|exec| ExecProbe {
+                command: exec.command,
+            }),
+            tcp_socket: item.tcp_socket.map(|tcp_socket| TcpSocketProbe {
+                port: tcp_socket.port,
+            }),
+            http_get: item.http_get.map(|http_get| HttpGetProbe {
+                port: http_get.port,
+                path: http_get.path.unwrap_or_else(default_http_path),
+            }),
+            initial_delay_seconds: item.initial_delay_seconds.unwrap_or_default(),
+            period_seconds: item.period_seconds.unwrap_or(DEFAULT_PERIOD_SECONDS),
+            timeout_seconds: item.timeout_seconds.unwrap_or(DEFAULT_TIMEOUT_SECONDS),
+            failure_threshold: item.failure_threshold.unwrap_or(DEFAULT_FAILURE_THRESHOLD),
+        }
+    }
+}
+
+impl From<Probe> for ank_base::Probe {
+    fn from(item: Probe) -> Self {
+        ank_base::Probe {
+            exec: item.exec.map(|exec| ank_base::ExecProbe {
+                command: exec.command,
+            }),
+            tcp_socket: item.tcp_socket.map(|tcp_socket| ank_base::TcpSocketProbe {
+                port: tcp_socket.port,
+            }),
+            http_get: item.http_get.map(|http_get| ank_base::HttpGetProbe {
+                port: http_get.port,
+                path: Some(http_get.path),
+            }),
+            initial_delay_seconds: Some(item.initial_delay_seconds),
+            period_seconds: Some(item.period_seconds),
+            timeout_seconds: Some(item.timeout_seconds),
+            failure_threshold: Some(item.failure_threshold),
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{ExecProbe, HttpGetProbe, Probe, Probes, TcpSocketProbe};
+    use api::ank_base;
+
+    // [utest->swdd~common-workload-health-probes~1]
+    #[test]
+    fn utest_probes_from_proto_sets_defaults() {
+        let proto_probes = ank_base::Probes {
+            liveness: Some(ank_base::Probe {
+                http_get: Some(ank_base::HttpGetProbe {
+                    port: 8080,
+                    path: None,
+                }),
+                ..Default::default()
+            }),
+            readiness: None,
+        };
+
+        assert_eq!(
+            Probes::from(proto_probes),
+            Probes {
+                liveness: Some(Probe {
+                    http_get: Some(HttpGetProbe {
+                        port: 8080,
+                        path: "/".to_string(),
+                    }),
+                    ..Default::default()
+                }),
+                readiness: None,
+            }
+        );
+    }
+
+    // [utest->swdd~common-workload-health-probes~1]
+    #[test]
+    fn utest_probes_verify_fields() {
+        let tcp_probe = Probe {
+            tcp_socket: Some(TcpSocketProbe { port: 5432 }),
+            ..Default::default()
+        };
+        assert!(
+            Probes {
+                liveness: Some(tcp_probe.clone()),
+                readiness: Some(Probe {
+                    exec: Some(ExecProbe {
+                        command: vec!["true".to_string()],
+                    }),
+                    ..Default::default()
+                }),
+            }
+            .verify_fields()
+            .is_ok()
+        );
+
+        let no_action = Probes {
+            liveness: Some(Probe::default()),
+            readiness: None,
+        };
+        assert!(no_action.verify_fields().is_err());
+
+        let two_actions = Probes {
+            liveness: None,
+            readiness: Some(Probe {
+                exec: Some(ExecProbe {
+                    command: vec!["true".to_string()],
+                }),
+                ..tcp_probe.clone()
+            }),
+        };
+        assert!(two_actions.verify_fields().is_err());
+
+        let invalid_port = Probes {
+            liveness: Some(Probe {
+                tcp_socket: Some(TcpSocketProbe { port: 70000 }),
+                ..Default::default()
+            }),
+            readiness: None,
+        };
+        assert!(invalid_port.verify_fields().is_err());
+
+        let zero_period = Probes {
+            liveness: Some(Probe {
+                period_seconds: 0,
+                ..tcp_probe
+            }),
+            readiness: None,
+        };
+        assert!(zero_period.verify_fields().is_err());
+    }
+}
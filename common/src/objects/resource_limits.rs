@@ -0,0 +1,147 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt;
+
+use api::ank_base;
+use serde::{Deserialize, Serialize};
+
+// [impl->swdd~common-workload-resource-limits~1]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceLimits {
+    /// The maximum CPU usage in thousandths of a CPU, e.g. 1500 for one and a half CPUs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpu_quota: Option<u32>,
+    /// The maximum memory in bytes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory_limit: Option<u64>,
+    /// The maximum number of processes and threads.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pids_limit: Option<u32>,
+}
+
+impl ResourceLimits {
+    pub fn verify_fields(&self) -> Result<(), String> {
+        verify_greater_than_zero("cpuQuota", self.cpu_quota.map(u64::from))?;
+        verify_greater_than_zero("memoryLimit", self.memory_limit)?;
+        verify_greater_than_zero("pidsLimit", self.pids_limit.map(u64::from))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cpu_quota.is_none() && self.memory_limit.is_none() && self.pids_limit.is_none()
+    }
+
+    /// The CPU quota as a decimal number of CPUs as expected by container runtimes.
+    pub fn cpus(&self) -> Option<String> {
+        self.cpu_quota
+            .map(|cpu_quota| format!("{}.{:03}", cpu_quota / 1000, cpu_quota % 1000))
+    }
+}
+
+fn verify_greater_than_zero(field_name: &str, value: Option<u64>) -> Result<(), String> {
+    if value == Some(0) {
+        return Err(format!(
+            "Unsupported resource limit. Received '0' for {field_name}, expected a value greater than 0"
+        ));
+    }
+    Ok(())
+}
+
+impl fmt::Display for ResourceLimits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let limits = [
+            self.cpus().map(|cpus| format!("cpus={cpus}")),
+            self.memory_limit.map(|memory| format!("memory={memory}")),
+            self.pids_limit.map(|pids| format!("pids={pids}")),
+        ];
+        write!(
+            f,
+            "{}",
+            limits.into_iter().flatten().collect::<Vec<_>>().join(" ")
+        )
+    }
+}
+
+impl From<ank_base::ResourceLimits> for ResourceLimits {
+    fn from(item: ank_base::ResourceLimits) -> Self {
+        ResourceLimits {
+            cpu_quota: item.cpu_quota,
+            memory_limit: item.memory_limit,
+            pids_limit: item.pids_limit,
+        }
+    }
+}
+
+impl From<ResourceLimits> for ank_base::ResourceLimits {
+    fn from(item: ResourceLimits) -> Self {
+        ank_base::ResourceLimits {
+            cpu_quota: item.cpu_quota,
+            memory_limit: item.memory_limit,
+            pids_limit: item.pids_limit,
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::ResourceLimits;
+
+    // [utest->swdd~common-workload-resource-limits~1]
+    #[test]
+    fn utest_resource_limits_verify_fields_rejects_zero_limits() {
+        let resource_limits = ResourceLimits {
+            cpu_quota: Some(500),
+            memory_limit: Some(1024),
+            pids_limit: Some(10),
+        };
+        assert!(resource_limits.verify_fields().is_ok());
+        assert!(ResourceLimits::default().verify_fields().is_ok());
+
+        let zero_memory_limit = ResourceLimits {
+            memory_limit: Some(0),
+            ..Default::default()
+        };
+        assert_eq!(
+            zero_memory_limit.verify_fields(),
+            Err(
+                "Unsupported resource limit. Received '0' for memoryLimit, expected a value greater than 0"
+                    .to_string()
+            )
+        );
+    }
+
+    // [utest->swdd~common-workload-resource-limits~1]
+    #[test]
+    fn utest_resource_limits_display() {
+        let resource_limits = ResourceLimits {
+            cpu_quota: Some(1500),
+            memory_limit: None,
+            pids_limit: Some(100),
+        };
+
+        assert_eq!(resource_limits.cpus(), Some("1.500".to_string()));
+        assert_eq!(resource_limits.to_string(), "cpus=1.500 pids=100");
+        assert_eq!(ResourceLimits::default().to_string(), "");
+        assert!(ResourceLimits::default().is_empty());
+    }
+}
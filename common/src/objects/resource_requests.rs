@@ -0,0 +1,110 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use api::ank_base;
+use serde::{Deserialize, Serialize};
+
+pub const MAX_CPU_USAGE: u32 = 100;
+
+// [impl->swdd~common-workload-resource-requests~1]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceRequests {
+    /// The requested CPU usage in percent of the total CPU capacity of an agent.
+    #[serde(default)]
+    pub cpu: u32,
+    /// The requested memory in bytes.
+    #[serde(default)]
+    pub memory: u64,
+}
+
+impl ResourceRequests {
+    pub fn verify_fields(&self) -> Result<(), String> {
+        if self.cpu > MAX_CPU_USAGE {
+            return Err(format!(
+                "Unsupported CPU resource request. Received '{}', expected a value between 0 and {MAX_CPU_USAGE}",
+                self.cpu
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl From<ank_base::ResourceRequests> for ResourceRequests {
+    fn from(item: ank_base::ResourceRequests) -> Self {
+        ResourceRequests {
+            cpu: item.cpu.unwrap_or_default(),
+            memory: item.memory.unwrap_or_default(),
+        }
+    }
+}
+
+impl From<ResourceRequests> for ank_base::ResourceRequests {
+    fn from(item: ResourceRequests) -> Self {
+        ank_base::ResourceRequests {
+            cpu: Some(item.cpu),
+            memory: Some(item.memory),
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{MAX_CPU_USAGE, ResourceRequests};
+    use api::ank_base;
+
+    // [utest->swdd~common-workload-resource-requests~1]
+    #[test]
+    fn utest_resource_requests_from_proto_defaults_missing_fields() {
+        let proto_requests = ank_base::ResourceRequests {
+            cpu: None,
+            memory: Some(1024),
+        };
+
+        assert_eq!(
+            ResourceRequests::from(proto_requests),
+            ResourceRequests {
+                cpu: 0,
+                memory: 1024
+            }
+        );
+    }
+
+    // [utest->swdd~common-workload-resource-requests~1]
+    #[test]
+    fn utest_resource_requests_verify_fields_rejects_cpu_above_maximum() {
+        let resource_requests = ResourceRequests {
+            cpu: MAX_CPU_USAGE + 1,
+            memory: 0,
+        };
+
+        assert!(resource_requests.verify_fields().is_err());
+        assert!(
+            ResourceRequests {
+                cpu: MAX_CPU_USAGE,
+                memory: 0
+            }
+            .verify_fields()
+            .is_ok()
+        );
+    }
+}
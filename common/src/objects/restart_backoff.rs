@@ -0,0 +1,171 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use api::ank_base;
+use serde::{Deserialize, Serialize};
+
+const DEFAULT_INITIAL_DELAY_MILLIS: u64 = 500;
+const DEFAULT_MAX_DELAY_MILLIS: u64 = 300000;
+const DEFAULT_RESET_AFTER_MILLIS: u64 = 600000;
+
+// [impl->swdd~common-workload-restart-backoff~2]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RestartBackoff {
+    /// The maximum delay before the first restart, doubled with each further restart.
+    #[serde(default = "default_initial_delay_millis")]
+    pub initial_delay_millis: u64,
+    /// The upper limit for the delay before a restart.
+    #[serde(default = "default_max_delay_millis")]
+    pub max_delay_millis: u64,
+    /// The maximum number of restarts, unlimited if not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_restarts: Option<u32>,
+    /// The time the workload has to keep running until its restarts are reset.
+    #[serde(default = "default_reset_after_millis")]
+    pub reset_after_millis: u64,
+}
+
+fn default_initial_delay_millis() -> u64 {
+    DEFAULT_INITIAL_DELAY_MILLIS
+}
+
+fn default_max_delay_millis() -> u64 {
+    DEFAULT_MAX_DELAY_MILLIS
+}
+
+fn default_reset_after_millis() -> u64 {
+    DEFAULT_RESET_AFTER_MILLIS
+}
+
+impl Default for RestartBackoff {
+    fn default() -> Self {
+        RestartBackoff {
+            initial_delay_millis: DEFAULT_INITIAL_DELAY_MILLIS,
+            max_delay_millis: DEFAULT_MAX_DELAY_MILLIS,
+            max_restarts: None,
+            reset_after_millis: DEFAULT_RESET_AFTER_MILLIS,
+        }
+    }
+}
+
+impl RestartBackoff {
+    pub fn verify_fields(&self) -> Result<(), String> {
+        if self.initial_delay_millis > self.max_delay_millis {
+            return Err(format!(
+                "Unsupported restart backoff. Received '{}' for initialDelayMillis, expected a value not greater than maxDelayMillis '{}'",
+                self.initial_delay_millis, self.max_delay_millis
+            ));
+        }
+        if self.reset_after_millis == 0 {
+            return Err(
+                "Unsupported restart backoff. Received '0' for resetAfterMillis, expected a value greater than 0"
+                    .to_string(),
+            );
+        }
+        Ok(())
+    }
+
+    pub fn restart_limit_reached(&self, restart_count: u32) -> bool {
+        self.max_restarts
+            .is_some_and(|max_restarts| restart_count >= max_restarts)
+    }
+}
+
+impl From<ank_base::RestartBackoff> for RestartBackoff {
+    fn from(item: ank_base::RestartBackoff) -> Self {
+        RestartBackoff {
+            initial_delay_millis: item
+                .initial_delay_millis
+                .unwrap_or(DEFAULT_INITIAL_DELAY_MILLIS),
+            max_delay_millis: item.max_delay_millis.unwrap_or(DEFAULT_MAX_DELAY_MILLIS),
+            max_restarts: item.max_restarts,
+            reset_after_millis: item
+                .reset_after_millis
+                .unwrap_or(DEFAULT_RESET_AFTER_MILLIS),
+        }
+    }
+}
+
+impl From<RestartBackoff> for ank_base::RestartBackoff {
+    fn from(item: RestartBackoff) -> Self {
+        ank_base::RestartBackoff {
+            initial_delay_millis: Some(item.initial_delay_millis),
+            max_delay_millis: Some(item.max_delay_millis),
+            max_restarts: item.max_restarts,
+            reset_after_millis: Some(item.reset_after_millis),
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::RestartBackoff;
+    use api::ank_base;
+
+    // [utest->swdd~common-workload-restart-backoff~2]
+    #[test]
+    fn utest_restart_backoff_from_proto_sets_defaults() {
+        let proto_backoff = ank_base::RestartBackoff {
+            initial_delay_millis: None,
+            max_delay_millis: None,
+            max_restarts: Some(5),
+            reset_after_millis: None,
+        };
+
+        assert_eq!(
+            RestartBackoff::from(proto_backoff),
+            RestartBackoff {
+                initial_delay_millis: 500,
+                max_delay_millis: 300000,
+                max_restarts: Some(5),
+                reset_after_millis: 600000,
+            }
+        );
+    }
+
+    // [utest->swdd~common-workload-restart-backoff~2]
+    #[test]
+    fn utest_restart_backoff_verify_fields_and_limit() {
+        let restart_backoff = RestartBackoff {
+            max_restarts: Some(2),
+            ..Default::default()
+        };
+        assert!(restart_backoff.verify_fields().is_ok());
+        assert!(!restart_backoff.restart_limit_reached(1));
+        assert!(restart_backoff.restart_limit_reached(2));
+        assert!(!RestartBackoff::default().restart_limit_reached(u32::MAX));
+
+        let inverted_delays = RestartBackoff {
+            initial_delay_millis: 1000,
+            max_delay_millis: 100,
+            ..Default::default()
+        };
+        assert!(inverted_delays.verify_fields().is_err());
+
+        let zero_reset_after = RestartBackoff {
+            reset_after_millis: 0,
+            ..Default::default()
+        };
+        assert!(zero_reset_after.verify_fields().is_err());
+    }
+}
@@ -21,7 +21,8 @@ use serde::{Deserialize, Serialize};
 use crate::helpers::serialize_to_ordered_map;
 
 use super::{
-    AddCondition, RestartPolicy, Tag, WorkloadInstanceName, WorkloadSpec,
+    AddCondition, Probes, ResourceLimits, ResourceRequests, RestartBackoff, RestartPolicy, Tag,
+    UpdateStrategy, WorkloadInstanceName, WorkloadSpec,
     control_interface_access::ControlInterfaceAccess, file::File,
 };
 
@@ -30,13 +31,38 @@ pub const STR_RE_CONFIG_REFERENCES: &str = r"^[a-zA-Z0-9_-]*$";
 #[derive(Debug, Serialize, Default, Deserialize, Clone, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct StoredWorkloadSpec {
+    #[serde(default)]
     pub agent: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub agents: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "HashMap::is_empty",
+        serialize_with = "serialize_to_ordered_map"
+    )]
+    pub agent_selector: HashMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resource_requests: Option<ResourceRequests>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resources: Option<ResourceLimits>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update_strategy: Option<UpdateStrategy>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub probes: Option<Probes>,
     #[serde(default)]
     pub tags: Vec<Tag>,
     #[serde(default, serialize_with = "serialize_to_ordered_map")]
     pub dependencies: HashMap<String, AddCondition>,
+    #[serde(
+        default,
+        skip_serializing_if = "HashMap::is_empty",
+        serialize_with = "serialize_to_ordered_map"
+    )]
+    pub dependency_timeouts: HashMap<String, u32>,
     #[serde(default)]
     pub restart_policy: RestartPolicy,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub restart_backoff: Option<RestartBackoff>,
     pub runtime: String,
     pub runtime_config: String,
     #[serde(default)]
@@ -68,14 +94,144 @@ impl StoredWorkloadSpec {
         }
         Ok(())
     }
+
+    // [impl->swdd~common-workload-agent-or-agent-selector~1]
+    pub fn verify_agent_selector(&self) -> Result<(), String> {
+        if !self.agent.is_empty() && !self.agent_selector.is_empty() {
+            return Err(
+                "The fields agent and agentSelector are mutually exclusive, set only one of them"
+                    .to_string(),
+            );
+        }
+        if self.agent.is_empty()
+            && self.agents.is_empty()
+            && self.agent_selector.is_empty()
+            && self.resource_requests.is_none()
+        {
+            return Err(
+                "The workload needs one of the fields agent, agents, agentSelector or resourceRequests"
+                    .to_string(),
+            );
+        }
+        Ok(())
+    }
+
+    // [impl->swdd~common-workload-resource-requests~1]
+    pub fn verify_resource_requests(&self) -> Result<(), String> {
+        self.resource_requests
+            .as_ref()
+            .map_or(Ok(()), ResourceRequests::verify_fields)
+    }
+
+    // [impl->swdd~common-workload-update-strategy~3]
+    pub fn verify_update_strategy(&self) -> Result<(), String> {
+        self.update_strategy
+            .as_ref()
+            .map_or(Ok(()), UpdateStrategy::verify_fields)
+    }
+
+    // [impl->swdd~common-workload-health-probes~1]
+    pub fn verify_probes(&self) -> Result<(), String> {
+        self.probes.as_ref().map_or(Ok(()), Probes::verify_fields)
+    }
+
+    // [impl->swdd~common-workload-restart-backoff~2]
+    pub fn verify_restart_backoff(&self) -> Result<(), String> {
+        self.restart_backoff
+            .as_ref()
+            .map_or(Ok(()), RestartBackoff::verify_fields)
+    }
+
+    // [impl->swdd~common-workload-dependency-timeouts~1]
+    pub fn verify_dependency_timeouts(&self) -> Result<(), String> {
+        for (dependency_name, timeout) in &self.dependency_timeouts {
+            if !self.dependencies.contains_key(dependency_name) {
+                return Err(format!(
+                    "The dependency timeout for '{dependency_name}' does not refer to a dependency of the workload"
+                ));
+            }
+            if *timeout == 0 {
+                return Err(format!(
+                    "Unsupported dependency timeout. Received '0' for '{dependency_name}', expected a value greater than 0"
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    // [impl->swdd~common-workload-agents-for-replicas~1]
+    pub fn verify_agents(&self) -> Result<(), String> {
+        if self.agents.is_empty() {
+            return Ok(());
+        }
+        if !self.agent.is_empty() || !self.agent_selector.is_empty() {
+            return Err(
+                "The field agents is mutually exclusive with the fields agent and agentSelector, set only one of them"
+                    .to_string(),
+            );
+        }
+        if let Some(duplicate_agent) = self
+            .agents
+            .iter()
+            .enumerate()
+            .find_map(|(index, agent)| self.agents[..index].contains(agent).then_some(agent))
+        {
+            return Err(format!(
+                "The field agents contains the agent '{duplicate_agent}' more than once"
+            ));
+        }
+        Ok(())
+    }
+
+    // [impl->swdd~common-workload-agents-for-replicas~1]
+    // A workload with a list of agents is expanded into one replica per agent.
+    pub fn replicas(&self) -> Vec<StoredWorkloadSpec> {
+        if self.agents.is_empty() {
+            return vec![self.clone()];
+        }
+        self.agents
+            .iter()
+            .map(|agent| StoredWorkloadSpec {
+                agent: agent.clone(),
+                agents: Vec::new(),
+                ..self.clone()
+            })
+            .collect()
+    }
+
+    // A workload without an agent is placed by the server if it selects agents by labels or requests resources.
+    pub fn is_placed_by_server(&self) -> bool {
+        self.agent.is_empty()
+            && self.agents.is_empty()
+            && (!self.agent_selector.is_empty() || self.resource_requests.is_some())
+    }
 }
 
 impl TryFrom<ank_base::Workload> for StoredWorkloadSpec {
     type Error = String;
 
     fn try_from(value: ank_base::Workload) -> Result<Self, String> {
+        let agent_selector = value.agent_selector.unwrap_or_default().labels;
+        let resource_requests = value.resource_requests.map(Into::into);
+        let agents = value.agents.unwrap_or_default().agents;
+        let agent = match value.agent {
+            Some(agent) => agent,
+            None if !agent_selector.is_empty()
+                || resource_requests.is_some()
+                || !agents.is_empty() =>
+            {
+                String::new()
+            }
+            None => return Err("Missing field agent".to_string()),
+        };
         Ok(StoredWorkloadSpec {
-            agent: value.agent.ok_or("Missing field agent")?,
+            agent,
+            agents,
+            agent_selector,
+            resource_requests,
+            resources: value.resources.map(Into::into),
+            update_strategy: value.update_strategy.map(TryInto::try_into).transpose()?,
+            probes: value.probes.map(Into::into),
             tags: value
                 .tags
                 .unwrap_or_default()
@@ -90,7 +246,12 @@ impl TryFrom<ank_base::Workload> for StoredWorkloadSpec {
                 .into_iter()
                 .map(|(k, v)| Ok((k, v.try_into()?)))
                 .collect::<Result<HashMap<String, AddCondition>, String>>()?,
+            dependency_timeouts: value
+                .dependency_timeouts
+                .unwrap_or_default()
+                .dependency_timeouts,
             restart_policy: value.restart_policy.unwrap_or_default().try_into()?,
+            restart_backoff: value.restart_backoff.map(Into::into),
             runtime: value.runtime.ok_or("Missing field runtime")?,
             runtime_config: value.runtime_config.ok_or("Missing field runtimeConfig")?,
             control_interface_access: value
@@ -111,8 +272,11 @@ impl TryFrom<ank_base::Workload> for StoredWorkloadSpec {
 
 impl From<StoredWorkloadSpec> for ank_base::Workload {
     fn from(workload: StoredWorkloadSpec) -> Self {
+        // a workload placed by the server or running on a list of agents has no single agent
+        let agent = (!workload.is_placed_by_server() && workload.agents.is_empty())
+            .then_some(workload.agent);
         ank_base::Workload {
-            agent: workload.agent.into(),
+            agent,
             dependencies: Some(ank_base::Dependencies {
                 dependencies: workload
                     .dependencies
@@ -133,6 +297,24 @@ impl From<StoredWorkloadSpec> for ank_base::Workload {
             files: Some(ank_base::Files {
                 files: workload.files.into_iter().map(Into::into).collect(),
             }),
+            agent_selector: (!workload.agent_selector.is_empty()).then_some(
+                ank_base::AgentSelector {
+                    labels: workload.agent_selector,
+                },
+            ),
+            resource_requests: workload.resource_requests.map(Into::into),
+            agents: (!workload.agents.is_empty()).then_some(ank_base::Agents {
+                agents: workload.agents,
+            }),
+            update_strategy: workload.update_strategy.map(Into::into),
+            probes: workload.probes.map(Into::into),
+            restart_backoff: workload.restart_backoff.map(Into::into),
+            dependency_timeouts: (!workload.dependency_timeouts.is_empty()).then_some(
+                ank_base::DependencyTimeouts {
+                    dependency_timeouts: workload.dependency_timeouts,
+                },
+            ),
+            resources: workload.resources.map(Into::into),
         }
     }
 }
@@ -147,11 +329,19 @@ impl From<(String, StoredWorkloadSpec)> for WorkloadSpec {
                 .build(),
             tags: spec.tags,
             dependencies: spec.dependencies,
+            dependency_timeouts: spec.dependency_timeouts,
             restart_policy: spec.restart_policy,
             runtime: spec.runtime,
             runtime_config: spec.runtime_config,
             files: spec.files,
             control_interface_access: spec.control_interface_access,
+            probes: spec.probes,
+            restart_backoff: spec.restart_backoff,
+            resources: spec.resources,
+            update_ordering: spec
+                .update_strategy
+                .map(|update_strategy| update_strategy.ordering)
+                .unwrap_or_default(),
         }
     }
 }
@@ -161,8 +351,16 @@ impl From<WorkloadSpec> for StoredWorkloadSpec {
         StoredWorkloadSpec {
             runtime: value.runtime,
             agent: value.instance_name.agent_name().to_owned(),
+            agent_selector: Default::default(),
+            resource_requests: None,
+            resources: value.resources,
+            update_strategy: None,
+            probes: value.probes,
+            agents: Default::default(),
             restart_policy: value.restart_policy,
+            restart_backoff: value.restart_backoff,
             dependencies: value.dependencies,
+            dependency_timeouts: value.dependency_timeouts,
             tags: value.tags,
             runtime_config: value.runtime_config,
             control_interface_access: value.control_interface_access,
@@ -188,11 +386,19 @@ pub fn generate_test_stored_workload_spec_with_config(
 ) -> crate::objects::StoredWorkloadSpec {
     StoredWorkloadSpec {
         agent: agent.into(),
+        agent_selector: HashMap::new(),
+        resource_requests: None,
+        resources: None,
+        update_strategy: None,
+        probes: None,
+        agents: vec![],
         dependencies: HashMap::from([
             (String::from("workload_A"), AddCondition::AddCondRunning),
             (String::from("workload_C"), AddCondition::AddCondSucceeded),
         ]),
+        dependency_timeouts: HashMap::new(),
         restart_policy: RestartPolicy::Always,
+        restart_backoff: None,
         runtime: runtime_name.into(),
         tags: vec![Tag {
             key: "key".into(),
@@ -236,9 +442,15 @@ pub fn generate_test_stored_workload_spec(
 // [utest->swdd~common-object-serialization~1]
 #[cfg(test)]
 mod tests {
-    use super::StoredWorkloadSpec;
+    use super::{
+        AddCondition, ResourceRequests, StoredWorkloadSpec, generate_test_stored_workload_spec,
+    };
+    use api::ank_base;
     use std::collections::HashMap;
 
+    const AGENT_NAME: &str = "agent_A";
+    const RUNTIME_NAME: &str = "runtime_X";
+
     // one test for a failing case, other cases are tested on the caller side to not repeat test code
     // [utest->swdd~common-config-aliases-and-config-reference-keys-naming-convention~1]
     #[test]
@@ -258,4 +470,195 @@ mod tests {
             ))
         );
     }
+
+    // [utest->swdd~common-workload-agent-or-agent-selector~1]
+    #[test]
+    fn utest_verify_agent_selector_rejects_agent_and_agent_selector() {
+        let mut workload = generate_test_stored_workload_spec(AGENT_NAME, RUNTIME_NAME);
+        assert_eq!(workload.verify_agent_selector(), Ok(()));
+
+        workload.agent_selector = HashMap::from([("arch".to_owned(), "arm64".to_owned())]);
+        assert!(workload.verify_agent_selector().is_err());
+
+        workload.agent.clear();
+        assert_eq!(workload.verify_agent_selector(), Ok(()));
+    }
+
+    // [utest->swdd~common-workload-agent-or-agent-selector~1]
+    #[test]
+    fn utest_verify_agent_selector_rejects_workload_without_placement() {
+        let mut workload = generate_test_stored_workload_spec(AGENT_NAME, RUNTIME_NAME);
+        workload.agent.clear();
+        assert!(workload.verify_agent_selector().is_err());
+
+        workload.agents = vec![AGENT_NAME.to_owned()];
+        assert_eq!(workload.verify_agent_selector(), Ok(()));
+
+        workload.agents.clear();
+        workload.resource_requests = Some(Default::default());
+        assert_eq!(workload.verify_agent_selector(), Ok(()));
+    }
+
+    // [utest->swdd~common-workload-agent-or-agent-selector~1]
+    #[test]
+    fn utest_stored_workload_spec_with_agent_selector_from_and_to_proto() {
+        let mut workload = generate_test_stored_workload_spec("", RUNTIME_NAME);
+        workload.agent_selector = HashMap::from([("arch".to_owned(), "arm64".to_owned())]);
+
+        let proto_workload: ank_base::Workload = workload.clone().into();
+        assert_eq!(proto_workload.agent, None);
+        assert_eq!(
+            proto_workload.agent_selector,
+            Some(ank_base::AgentSelector {
+                labels: workload.agent_selector.clone()
+            })
+        );
+
+        assert_eq!(StoredWorkloadSpec::try_from(proto_workload), Ok(workload));
+    }
+
+    // [utest->swdd~common-workload-resource-requests~1]
+    #[test]
+    fn utest_stored_workload_spec_with_resource_requests_from_and_to_proto() {
+        let mut workload = generate_test_stored_workload_spec("", RUNTIME_NAME);
+        workload.resource_requests = Some(ResourceRequests {
+            cpu: 20,
+            memory: 1024,
+        });
+
+        let proto_workload: ank_base::Workload = workload.clone().into();
+        assert_eq!(proto_workload.agent, None);
+        assert_eq!(
+            proto_workload.resource_requests,
+            Some(ank_base::ResourceRequests {
+                cpu: Some(20),
+                memory: Some(1024),
+            })
+        );
+
+        assert_eq!(StoredWorkloadSpec::try_from(proto_workload), Ok(workload));
+    }
+
+    // [utest->swdd~common-workload-agents-for-replicas~1]
+    #[test]
+    fn utest_stored_workload_spec_with_agents_from_and_to_proto() {
+        let mut workload = generate_test_stored_workload_spec("", RUNTIME_NAME);
+        workload.agents = vec![AGENT_NAME.to_owned(), "agent_B".to_owned()];
+
+        let proto_workload: ank_base::Workload = workload.clone().into();
+        assert_eq!(proto_workload.agent, None);
+        assert_eq!(
+            proto_workload.agents,
+            Some(ank_base::Agents {
+                agents: workload.agents.clone()
+            })
+        );
+
+        assert_eq!(StoredWorkloadSpec::try_from(proto_workload), Ok(workload));
+    }
+
+    // [utest->swdd~common-workload-agents-for-replicas~1]
+    #[test]
+    fn utest_verify_agents() {
+        let mut workload = generate_test_stored_workload_spec(AGENT_NAME, RUNTIME_NAME);
+        assert_eq!(workload.verify_agents(), Ok(()));
+
+        workload.agents = vec![AGENT_NAME.to_owned(), "agent_B".to_owned()];
+        assert!(workload.verify_agents().is_err());
+
+        workload.agent.clear();
+        assert_eq!(workload.verify_agents(), Ok(()));
+
+        workload.agent_selector = HashMap::from([("arch".to_owned(), "arm64".to_owned())]);
+        assert!(workload.verify_agents().is_err());
+
+        workload.agent_selector.clear();
+        workload.agents.push(AGENT_NAME.to_owned());
+        assert_eq!(
+            workload.verify_agents(),
+            Err(format!(
+                "The field agents contains the agent '{AGENT_NAME}' more than once"
+            ))
+        );
+    }
+
+    // [utest->swdd~common-workload-agents-for-replicas~1]
+    #[test]
+    fn utest_stored_workload_spec_replicas() {
+        let workload = generate_test_stored_workload_spec(AGENT_NAME, RUNTIME_NAME);
+        assert_eq!(workload.replicas(), vec![workload.clone()]);
+
+        let mut replicated_workload = workload.clone();
+        replicated_workload.agent.clear();
+        replicated_workload.agents = vec![AGENT_NAME.to_owned(), "agent_B".to_owned()];
+
+        let replicas = replicated_workload.replicas();
+        assert_eq!(replicas.len(), 2);
+        assert_eq!(replicas[0], workload);
+        assert_eq!(replicas[1].agent, "agent_B");
+        assert!(replicas[1].agents.is_empty());
+    }
+
+    // [utest->swdd~common-workload-dependency-timeouts~1]
+    #[test]
+    fn utest_stored_workload_spec_with_dependency_timeouts_from_and_to_proto() {
+        let mut workload = generate_test_stored_workload_spec(AGENT_NAME, RUNTIME_NAME);
+        workload.dependency_timeouts = HashMap::from([("workload_A".to_owned(), 30)]);
+        assert_eq!(workload.verify_dependency_timeouts(), Ok(()));
+
+        let proto_workload: ank_base::Workload = workload.clone().into();
+        assert_eq!(
+            proto_workload.dependency_timeouts,
+            Some(ank_base::DependencyTimeouts {
+                dependency_timeouts: workload.dependency_timeouts.clone()
+            })
+        );
+
+        assert_eq!(StoredWorkloadSpec::try_from(proto_workload), Ok(workload));
+    }
+
+    // [utest->swdd~common-workload-dependency-timeouts~1]
+    #[test]
+    fn utest_verify_dependency_timeouts() {
+        let mut workload = generate_test_stored_workload_spec(AGENT_NAME, RUNTIME_NAME);
+        workload.dependencies =
+            HashMap::from([("workload_A".to_owned(), AddCondition::AddCondReady)]);
+
+        workload.dependency_timeouts = HashMap::from([("workload_A".to_owned(), 0)]);
+        assert!(workload.verify_dependency_timeouts().is_err());
+
+        workload.dependency_timeouts = HashMap::from([("workload_B".to_owned(), 10)]);
+        assert_eq!(
+            workload.verify_dependency_timeouts(),
+            Err(
+                "The dependency timeout for 'workload_B' does not refer to a dependency of the workload"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn utest_stored_workload_spec_is_placed_by_server() {
+        let mut workload = generate_test_stored_workload_spec(AGENT_NAME, RUNTIME_NAME);
+        workload.resource_requests = Some(ResourceRequests::default());
+        assert!(!workload.is_placed_by_server());
+
+        workload.agent.clear();
+        assert!(workload.is_placed_by_server());
+
+        workload.resource_requests = None;
+        assert!(!workload.is_placed_by_server());
+    }
+
+    #[test]
+    fn utest_stored_workload_spec_from_proto_fails_without_agent_and_agent_selector() {
+        let mut proto_workload: ank_base::Workload =
+            generate_test_stored_workload_spec(AGENT_NAME, RUNTIME_NAME).into();
+        proto_workload.agent = None;
+
+        assert_eq!(
+            StoredWorkloadSpec::try_from(proto_workload),
+            Err("Missing field agent".to_string())
+        );
+    }
 }
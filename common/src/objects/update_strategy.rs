@@ -0,0 +1,236 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use api::ank_base;
+use serde::{Deserialize, Serialize};
+
+const DEFAULT_MAX_UNAVAILABLE: u32 = 1;
+const DEFAULT_REPLICA_TIMEOUT_MILLIS: u64 = 300_000;
+
+// [impl->swdd~common-workload-update-strategy~3]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateStrategy {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rolling: Option<RollingUpdate>,
+    #[serde(default)]
+    pub ordering: UpdateOrdering,
+}
+
+// [impl->swdd~common-workload-update-ordering~1]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum UpdateOrdering {
+    /// The old instance of a replica is deleted before the new one is created.
+    #[default]
+    AtMostOnce = 0,
+    /// The new instance of a replica is created and the old one is deleted once the new one is running.
+    AtLeastOnce = 1,
+}
+
+impl TryFrom<i32> for UpdateOrdering {
+    type Error = String;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            x if x == UpdateOrdering::AtMostOnce as i32 => Ok(UpdateOrdering::AtMostOnce),
+            x if x == UpdateOrdering::AtLeastOnce as i32 => Ok(UpdateOrdering::AtLeastOnce),
+            _ => Err(format!(
+                "Received an unknown value '{value}' as update ordering."
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RollingUpdate {
+    /// The maximum number of replicas updated at the same time.
+    #[serde(default = "default_max_unavailable")]
+    pub max_unavailable: u32,
+    /// The time in milliseconds after which a new replica that is not running rolls back the update.
+    #[serde(default = "default_replica_timeout_millis")]
+    pub replica_timeout_millis: u64,
+}
+
+fn default_max_unavailable() -> u32 {
+    DEFAULT_MAX_UNAVAILABLE
+}
+
+fn default_replica_timeout_millis() -> u64 {
+    DEFAULT_REPLICA_TIMEOUT_MILLIS
+}
+
+impl Default for RollingUpdate {
+    fn default() -> Self {
+        RollingUpdate {
+            max_unavailable: DEFAULT_MAX_UNAVAILABLE,
+            replica_timeout_millis: DEFAULT_REPLICA_TIMEOUT_MILLIS,
+        }
+    }
+}
+
+impl UpdateStrategy {
+    pub fn verify_fields(&self) -> Result<(), String> {
+        let Some(rolling) = self.rolling.as_ref() else {
+            return Ok(());
+        };
+        if rolling.max_unavailable == 0 {
+            return Err(
+                "Unsupported rolling update. Received '0' for maxUnavailable, expected a value of at least 1"
+                    .to_string(),
+            );
+        }
+        if rolling.replica_timeout_millis == 0 {
+            return Err(
+                "Unsupported rolling update. Received '0' for replicaTimeoutMillis, expected a value of at least 1"
+                    .to_string(),
+            );
+        }
+        Ok(())
+    }
+
+    pub fn max_unavailable(&self) -> Option<u32> {
+        self.rolling.as_ref().map(|rolling| rolling.max_unavailable)
+    }
+}
+
+impl TryFrom<ank_base::UpdateStrategy> for UpdateStrategy {
+    type Error = String;
+
+    fn try_from(item: ank_base::UpdateStrategy) -> Result<Self, Self::Error> {
+        Ok(UpdateStrategy {
+            rolling: item.rolling.map(|rolling| RollingUpdate {
+                max_unavailable: rolling.max_unavailable.unwrap_or(DEFAULT_MAX_UNAVAILABLE),
+                replica_timeout_millis: rolling
+                    .replica_timeout_millis
+                    .unwrap_or(DEFAULT_REPLICA_TIMEOUT_MILLIS),
+            }),
+            ordering: item.ordering.unwrap_or_default().try_into()?,
+        })
+    }
+}
+
+impl From<UpdateStrategy> for ank_base::UpdateStrategy {
+    fn from(item: UpdateStrategy) -> Self {
+        ank_base::UpdateStrategy {
+            rolling: item.rolling.map(|rolling| ank_base::RollingUpdate {
+                max_unavailable: Some(rolling.max_unavailable),
+                replica_timeout_millis: Some(rolling.replica_timeout_millis),
+            }),
+            ordering: Some(item.ordering as i32),
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{RollingUpdate, UpdateOrdering, UpdateStrategy};
+    use api::ank_base;
+
+    // [utest->swdd~common-workload-update-strategy~3]
+    #[test]
+    fn utest_update_strategy_from_proto_defaults_max_unavailable() {
+        let proto_strategy = ank_base::UpdateStrategy {
+            rolling: Some(ank_base::RollingUpdate {
+                max_unavailable: None,
+                replica_timeout_millis: None,
+            }),
+            ordering: None,
+        };
+
+        let update_strategy = UpdateStrategy::try_from(proto_strategy).unwrap();
+        assert_eq!(update_strategy.max_unavailable(), Some(1));
+        assert_eq!(
+            update_strategy
+                .rolling
+                .as_ref()
+                .unwrap()
+                .replica_timeout_millis,
+            300_000
+        );
+        assert_eq!(update_strategy.ordering, UpdateOrdering::AtMostOnce);
+        assert_eq!(
+            ank_base::UpdateStrategy::from(update_strategy),
+            ank_base::UpdateStrategy {
+                rolling: Some(ank_base::RollingUpdate {
+                    max_unavailable: Some(1),
+                    replica_timeout_millis: Some(300_000),
+                }),
+                ordering: Some(ank_base::UpdateOrdering::AtMostOnce as i32),
+            }
+        );
+    }
+
+    // [utest->swdd~common-workload-update-strategy~3]
+    #[test]
+    fn utest_update_strategy_verify_fields_rejects_zero_max_unavailable() {
+        let update_strategy = UpdateStrategy {
+            rolling: Some(RollingUpdate {
+                max_unavailable: 0,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        assert!(update_strategy.verify_fields().is_err());
+        assert!(UpdateStrategy::default().verify_fields().is_ok());
+        assert_eq!(UpdateStrategy::default().max_unavailable(), None);
+    }
+
+    // [utest->swdd~common-workload-update-strategy~3]
+    #[test]
+    fn utest_update_strategy_verify_fields_rejects_zero_replica_timeout() {
+        let update_strategy = UpdateStrategy {
+            rolling: Some(RollingUpdate {
+                replica_timeout_millis: 0,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        assert!(update_strategy.verify_fields().is_err());
+    }
+
+    // [utest->swdd~common-workload-update-ordering~1]
+    #[test]
+    fn utest_update_strategy_ordering_from_proto_and_manifest() {
+        let update_strategy = UpdateStrategy::try_from(ank_base::UpdateStrategy {
+            rolling: None,
+            ordering: Some(ank_base::UpdateOrdering::AtLeastOnce as i32),
+        })
+        .unwrap();
+        assert_eq!(update_strategy.ordering, UpdateOrdering::AtLeastOnce);
+
+        assert!(
+            UpdateStrategy::try_from(ank_base::UpdateStrategy {
+                rolling: None,
+                ordering: Some(42),
+            })
+            .is_err()
+        );
+
+        let update_strategy: UpdateStrategy =
+            serde_yaml::from_str("ordering: AT_LEAST_ONCE").unwrap();
+        assert_eq!(update_strategy.ordering, UpdateOrdering::AtLeastOnce);
+    }
+}
@@ -0,0 +1,216 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use api::ank_base;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+type AgentName = String;
+type WorkloadName = String;
+
+// [impl->swdd~common-workload-metrics~1]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkloadMetrics {
+    /// The CPU usage in percent of one CPU, not available for all runtimes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpu_usage: Option<u32>,
+    /// The used memory in bytes, not available for all runtimes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory_usage: Option<u64>,
+    /// The number of restarts since the last update of the workload.
+    #[serde(default)]
+    pub restart_count: u32,
+}
+
+// [impl->swdd~common-workload-metrics-map~1]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+pub struct WorkloadMetricsMap(HashMap<AgentName, HashMap<WorkloadName, WorkloadMetrics>>);
+
+impl WorkloadMetricsMap {
+    pub fn new() -> Self {
+        Self(HashMap::new())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn get(&self, agent_name: &str, workload_name: &str) -> Option<&WorkloadMetrics> {
+        self.0
+            .get(agent_name)
+            .and_then(|workload_metrics| workload_metrics.get(workload_name))
+    }
+
+    // The agent always reports the metrics of all of its workloads, so the old ones are replaced.
+    pub fn update_agent_metrics(
+        &mut self,
+        agent_name: &str,
+        workload_metrics: HashMap<WorkloadName, WorkloadMetrics>,
+    ) {
+        if workload_metrics.is_empty() {
+            self.0.remove(agent_name);
+        } else {
+            self.0.insert(agent_name.to_owned(), workload_metrics);
+        }
+    }
+
+    pub fn remove_agent(&mut self, agent_name: &str) {
+        self.0.remove(agent_name);
+    }
+}
+
+impl From<WorkloadMetrics> for ank_base::WorkloadMetrics {
+    fn from(item: WorkloadMetrics) -> Self {
+        ank_base::WorkloadMetrics {
+            cpu_usage: item.cpu_usage,
+            memory_usage: item.memory_usage,
+            restart_count: item.restart_count,
+        }
+    }
+}
+
+impl From<ank_base::WorkloadMetrics> for WorkloadMetrics {
+    fn from(item: ank_base::WorkloadMetrics) -> Self {
+        WorkloadMetrics {
+            cpu_usage: item.cpu_usage,
+            memory_usage: item.memory_usage,
+            restart_count: item.restart_count,
+        }
+    }
+}
+
+impl From<WorkloadMetricsMap> for Option<ank_base::WorkloadMetricsMap> {
+    fn from(item: WorkloadMetricsMap) -> Option<ank_base::WorkloadMetricsMap> {
+        if item.0.is_empty() {
+            return None;
+        }
+
+        Some(ank_base::WorkloadMetricsMap {
+            agent_metrics_map: item
+                .0
+                .into_iter()
+                .map(|(agent_name, workload_metrics)| {
+                    (
+                        agent_name,
+                        ank_base::WorkloadMetricsOfAgent {
+                            wl_name_metrics_map: workload_metrics
+                                .into_iter()
+                                .map(|(workload_name, metrics)| (workload_name, metrics.into()))
+                                .collect(),
+                        },
+                    )
+                })
+                .collect(),
+        })
+    }
+}
+
+impl From<ank_base::WorkloadMetricsMap> for WorkloadMetricsMap {
+    fn from(item: ank_base::WorkloadMetricsMap) -> Self {
+        WorkloadMetricsMap(
+            item.agent_metrics_map
+                .into_iter()
+                .map(|(agent_name, workload_metrics)| {
+                    (
+                        agent_name,
+                        workload_metrics
+                            .wl_name_metrics_map
+                            .into_iter()
+                            .map(|(workload_name, metrics)| (workload_name, metrics.into()))
+                            .collect(),
+                    )
+                })
+                .collect(),
+        )
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{WorkloadMetrics, WorkloadMetricsMap};
+    use api::ank_base;
+    use std::collections::HashMap;
+
+    const AGENT_A: &str = "agent_A";
+    const AGENT_B: &str = "agent_B";
+    const WORKLOAD_1: &str = "workload_1";
+
+    fn generate_test_metrics(restart_count: u32) -> HashMap<String, WorkloadMetrics> {
+        HashMap::from([(
+            WORKLOAD_1.to_owned(),
+            WorkloadMetrics {
+                cpu_usage: Some(12),
+                memory_usage: Some(4096),
+                restart_count,
+            },
+        )])
+    }
+
+    // [utest->swdd~common-workload-metrics-map~1]
+    #[test]
+    fn utest_workload_metrics_map_replaces_metrics_of_agent() {
+        let mut metrics_map = WorkloadMetricsMap::new();
+        metrics_map.update_agent_metrics(AGENT_A, generate_test_metrics(0));
+        metrics_map.update_agent_metrics(AGENT_B, generate_test_metrics(0));
+        metrics_map.update_agent_metrics(AGENT_A, generate_test_metrics(3));
+
+        assert_eq!(
+            metrics_map
+                .get(AGENT_A, WORKLOAD_1)
+                .map(|metrics| metrics.restart_count),
+            Some(3)
+        );
+        assert!(metrics_map.get(AGENT_B, WORKLOAD_1).is_some());
+
+        metrics_map.update_agent_metrics(AGENT_A, HashMap::new());
+        assert!(metrics_map.get(AGENT_A, WORKLOAD_1).is_none());
+
+        metrics_map.remove_agent(AGENT_B);
+        assert!(metrics_map.is_empty());
+    }
+
+    // [utest->swdd~common-workload-metrics~1]
+    // [utest->swdd~common-workload-metrics-map~1]
+    #[test]
+    fn utest_workload_metrics_map_converts_to_and_from_proto() {
+        assert_eq!(
+            Option::<ank_base::WorkloadMetricsMap>::from(WorkloadMetricsMap::new()),
+            None
+        );
+
+        let mut metrics_map = WorkloadMetricsMap::new();
+        metrics_map.update_agent_metrics(AGENT_A, generate_test_metrics(1));
+
+        let proto_metrics_map = Option::<ank_base::WorkloadMetricsMap>::from(metrics_map.clone())
+            .expect("metrics map shall not be empty");
+        assert_eq!(
+            proto_metrics_map.agent_metrics_map[AGENT_A].wl_name_metrics_map[WORKLOAD_1],
+            ank_base::WorkloadMetrics {
+                cpu_usage: Some(12),
+                memory_usage: Some(4096),
+                restart_count: 1,
+            }
+        );
+        assert_eq!(WorkloadMetricsMap::from(proto_metrics_map), metrics_map);
+    }
+}
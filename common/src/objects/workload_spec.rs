@@ -20,6 +20,10 @@ use crate::helpers::serialize_to_ordered_map;
 use crate::objects::Tag;
 
 use super::ExecutionState;
+use super::Probes;
+use super::ResourceLimits;
+use super::RestartBackoff;
+use super::UpdateOrdering;
 use super::WorkloadInstanceName;
 use super::control_interface_access::ControlInterfaceAccess;
 use super::file::File;
@@ -96,11 +100,17 @@ pub struct WorkloadSpec {
     pub tags: Vec<Tag>,
     #[serde(serialize_with = "serialize_to_ordered_map")]
     pub dependencies: HashMap<String, AddCondition>,
+    #[serde(serialize_with = "serialize_to_ordered_map")]
+    pub dependency_timeouts: HashMap<String, u32>,
     pub restart_policy: RestartPolicy,
     pub runtime: String,
     pub runtime_config: String,
     pub files: Vec<File>,
     pub control_interface_access: ControlInterfaceAccess,
+    pub probes: Option<Probes>,
+    pub restart_backoff: Option<RestartBackoff>,
+    pub resources: Option<ResourceLimits>,
+    pub update_ordering: UpdateOrdering,
 }
 
 // [impl->swdd~common-workload-needs-control-interface~1]
@@ -117,10 +127,14 @@ impl WorkloadSpec {
     // [impl->swdd~common-workload-naming-convention~1]
     // [impl->swdd~common-agent-naming-convention~3]
     // [impl->swdd~common-access-rules-filter-mask-convention~1]
+    // [impl->swdd~common-workload-resource-limits~1]
     pub fn verify_fields_format(&self) -> Result<(), String> {
         verify_workload_name_format(self.instance_name.workload_name())?;
         verify_agent_name_format(self.instance_name.agent_name())?;
         self.control_interface_access.verify_format()?;
+        self.resources
+            .as_ref()
+            .map_or(Ok(()), ResourceLimits::verify_fields)?;
         Ok(())
     }
 }
@@ -201,22 +215,24 @@ pub trait FulfilledBy<T> {
     fn fulfilled_by(&self, other: &T) -> bool;
 }
 
-// [impl->swdd~workload-add-conditions-for-dependencies~1]
+// [impl->swdd~workload-add-conditions-for-dependencies~2]
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum AddCondition {
     AddCondRunning = 0,
     AddCondSucceeded = 1,
     AddCondFailed = 2,
+    AddCondReady = 3,
 }
 
 impl FulfilledBy<ExecutionState> for AddCondition {
-    // [impl->swdd~execution-states-of-workload-dependencies-fulfill-add-conditions~1]
+    // [impl->swdd~execution-states-of-workload-dependencies-fulfill-add-conditions~2]
     fn fulfilled_by(&self, other: &ExecutionState) -> bool {
         match self {
-            AddCondition::AddCondRunning => (*other).is_running(),
+            AddCondition::AddCondRunning => (*other).is_running() || (*other).is_not_ready(),
             AddCondition::AddCondSucceeded => (*other).is_succeeded(),
             AddCondition::AddCondFailed => (*other).is_failed(),
+            AddCondition::AddCondReady => (*other).is_running(),
         }
     }
 }
@@ -229,6 +245,7 @@ impl TryFrom<i32> for AddCondition {
             x if x == AddCondition::AddCondRunning as i32 => Ok(AddCondition::AddCondRunning),
             x if x == AddCondition::AddCondSucceeded as i32 => Ok(AddCondition::AddCondSucceeded),
             x if x == AddCondition::AddCondFailed as i32 => Ok(AddCondition::AddCondFailed),
+            x if x == AddCondition::AddCondReady as i32 => Ok(AddCondition::AddCondReady),
             _ => Err(format!(
                 "Received an unknown value '{value}' as AddCondition."
             )),
@@ -330,6 +347,7 @@ pub fn generate_test_workload_spec_with_runtime_config(
     WorkloadSpec {
         instance_name,
         dependencies: generate_test_dependencies(),
+        dependency_timeouts: HashMap::new(),
         restart_policy: RestartPolicy::Always,
         runtime: runtime_name,
         tags: vec![Tag {
@@ -339,6 +357,10 @@ pub fn generate_test_workload_spec_with_runtime_config(
         runtime_config,
         control_interface_access: Default::default(),
         files: Default::default(),
+        probes: None,
+        restart_backoff: None,
+        resources: None,
+        update_ordering: Default::default(),
     }
 }
 
@@ -475,7 +497,7 @@ mod tests {
         assert_eq!(workload3.instance_name.workload_name(), "workload 9");
     }
 
-    // [utest->swdd~workload-add-conditions-for-dependencies~1]
+    // [utest->swdd~workload-add-conditions-for-dependencies~2]
     #[test]
     fn utest_add_condition_from_int() {
         assert_eq!(
@@ -490,6 +512,10 @@ mod tests {
             AddCondition::try_from(2).unwrap(),
             AddCondition::AddCondFailed
         );
+        assert_eq!(
+            AddCondition::try_from(3).unwrap(),
+            AddCondition::AddCondReady
+        );
         assert_eq!(
             AddCondition::try_from(100),
             Err::<AddCondition, String>(
@@ -538,7 +564,7 @@ mod tests {
         );
     }
 
-    // [utest->swdd~execution-states-of-workload-dependencies-fulfill-add-conditions~1]
+    // [utest->swdd~execution-states-of-workload-dependencies-fulfill-add-conditions~2]
     #[test]
     fn utest_add_condition_fulfilled_by_fulfilled() {
         let add_condition = AddCondition::AddCondRunning;
@@ -551,6 +577,20 @@ mod tests {
         assert!(add_condition.fulfilled_by(&ExecutionState::failed("some failure".to_string())));
     }
 
+    // [utest->swdd~execution-states-of-workload-dependencies-fulfill-add-conditions~2]
+    #[test]
+    fn utest_add_condition_ready_requires_successful_readiness_probe() {
+        let not_ready = ExecutionState::running_not_ready("Readiness probe did not succeed yet");
+
+        assert!(AddCondition::AddCondRunning.fulfilled_by(&not_ready));
+        assert!(!AddCondition::AddCondReady.fulfilled_by(&not_ready));
+        assert!(AddCondition::AddCondReady.fulfilled_by(&ExecutionState::running()));
+        assert!(
+            !AddCondition::AddCondReady
+                .fulfilled_by(&ExecutionState::running_unhealthy("Liveness probe failed"))
+        );
+    }
+
     // [utest->swdd~execution-states-of-workload-dependencies-fulfill-delete-conditions~1]
     #[test]
     fn utest_delete_condition_fulfilled_by() {
@@ -668,6 +708,24 @@ mod tests {
         assert!(spec_with_wrong_agent_name.verify_fields_format().is_ok());
     }
 
+    // [utest->swdd~common-workload-resource-limits~1]
+    #[test]
+    fn utest_workload_verify_fields_invalid_resource_limits() {
+        let mut workload_spec = generate_test_workload_spec();
+        workload_spec.resources = Some(ResourceLimits {
+            pids_limit: Some(0),
+            ..Default::default()
+        });
+
+        assert_eq!(
+            workload_spec.verify_fields_format(),
+            Err(
+                "Unsupported resource limit. Received '0' for pidsLimit, expected a value greater than 0"
+                    .into()
+            )
+        );
+    }
+
     // [utest->swdd~common-workload-naming-convention~1]
     #[test]
     fn utest_verify_workload_name_format_inordinately_long_workload_name() {
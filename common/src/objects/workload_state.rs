@@ -24,6 +24,7 @@ use super::WorkloadInstanceName;
 
 const TRIGGERED_MSG: &str = "Triggered at runtime.";
 pub const NO_MORE_RETRIES_MSG: &str = "No more retries";
+pub const NO_MORE_RESTARTS_MSG: &str = "No more restarts";
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum PendingSubstate {
@@ -58,11 +59,17 @@ impl Display for PendingSubstate {
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum RunningSubstate {
     Ok = 0,
+    Unhealthy = 1,
+    NotReady = 2,
 }
 
 impl From<i32> for RunningSubstate {
-    fn from(_x: i32) -> Self {
-        RunningSubstate::Ok
+    fn from(x: i32) -> Self {
+        match x {
+            x if x == RunningSubstate::Unhealthy as i32 => RunningSubstate::Unhealthy,
+            x if x == RunningSubstate::NotReady as i32 => RunningSubstate::NotReady,
+            _ => RunningSubstate::Ok,
+        }
     }
 }
 
@@ -70,6 +77,8 @@ impl Display for RunningSubstate {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             RunningSubstate::Ok => write!(f, "Ok"),
+            RunningSubstate::Unhealthy => write!(f, "Unhealthy"),
+            RunningSubstate::NotReady => write!(f, "NotReady"),
         }
     }
 }
@@ -130,6 +139,8 @@ pub enum FailedSubstate {
     ExecFailed = 0,
     Unknown = 1,
     Lost = 2,
+    CrashLoop = 3,
+    DependencyTimeout = 4,
 }
 
 impl From<i32> for FailedSubstate {
@@ -138,6 +149,8 @@ impl From<i32> for FailedSubstate {
             x if x == FailedSubstate::ExecFailed as i32 => FailedSubstate::ExecFailed,
             x if x == FailedSubstate::Unknown as i32 => FailedSubstate::Unknown,
             x if x == FailedSubstate::Lost as i32 => FailedSubstate::Lost,
+            x if x == FailedSubstate::CrashLoop as i32 => FailedSubstate::CrashLoop,
+            x if x == FailedSubstate::DependencyTimeout as i32 => FailedSubstate::DependencyTimeout,
             _ => FailedSubstate::Unknown,
         }
     }
@@ -149,6 +162,8 @@ impl Display for FailedSubstate {
             FailedSubstate::ExecFailed => write!(f, "ExecFailed"),
             FailedSubstate::Unknown => write!(f, "Unknown"),
             FailedSubstate::Lost => write!(f, "Lost"),
+            FailedSubstate::CrashLoop => write!(f, "CrashLoop"),
+            FailedSubstate::DependencyTimeout => write!(f, "DependencyTimeout"),
         }
     }
 }
@@ -174,7 +189,7 @@ impl ExecutionState {
             (
                 ExecutionStateEnum::Stopping(StoppingSubstate::RequestedAtRuntime)
                 | ExecutionStateEnum::Stopping(StoppingSubstate::WaitingToStop),
-                ExecutionStateEnum::Running(RunningSubstate::Ok)
+                ExecutionStateEnum::Running(_)
                 | ExecutionStateEnum::Succeeded(SucceededSubstate::Ok)
                 | ExecutionStateEnum::Failed(FailedSubstate::ExecFailed)
                 | ExecutionStateEnum::Failed(FailedSubstate::Lost)
@@ -254,7 +269,7 @@ impl From<ank_base::execution_state::ExecutionStateEnum> for ExecutionStateEnum
     }
 }
 
-// [impl->swdd~common-workload-states-supported-states~1]
+// [impl->swdd~common-workload-states-supported-states~4]
 #[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(default, rename_all = "camelCase")]
 pub struct ExecutionState {
@@ -281,6 +296,15 @@ impl ExecutionState {
         ExecutionStateEnum::Running(RunningSubstate::Ok) == self.state
     }
 
+    // [impl->swdd~common-workload-states-running-unhealthy~1]
+    pub fn is_unhealthy(&self) -> bool {
+        ExecutionStateEnum::Running(RunningSubstate::Unhealthy) == self.state
+    }
+
+    pub fn is_not_ready(&self) -> bool {
+        ExecutionStateEnum::Running(RunningSubstate::NotReady) == self.state
+    }
+
     pub fn is_succeeded(&self) -> bool {
         ExecutionStateEnum::Succeeded(SucceededSubstate::Ok) == self.state
     }
@@ -290,7 +314,7 @@ impl ExecutionState {
     }
 
     pub fn is_not_pending_nor_running(&self) -> bool {
-        !self.is_pending() && !self.is_running()
+        !self.is_pending() && !matches!(self.state, ExecutionStateEnum::Running(_))
     }
 
     pub fn is_waiting_to_start(&self) -> bool {
@@ -329,6 +353,39 @@ impl ExecutionState {
         }
     }
 
+    // [impl->swdd~common-workload-states-failed-crash-loop~1]
+    pub fn crash_loop_backoff(restart_count: u32, additional_info: impl ToString) -> Self {
+        ExecutionState {
+            state: ExecutionStateEnum::Failed(FailedSubstate::CrashLoop),
+            additional_info: format!(
+                "Waiting for restart {}: {}",
+                restart_count,
+                additional_info.to_string()
+            ),
+        }
+    }
+
+    // [impl->swdd~common-workload-states-failed-crash-loop~1]
+    pub fn crash_loop_no_restart(restart_count: u32, additional_info: impl ToString) -> Self {
+        ExecutionState {
+            state: ExecutionStateEnum::Failed(FailedSubstate::CrashLoop),
+            additional_info: format!(
+                "{} after {} restarts: {}",
+                NO_MORE_RESTARTS_MSG,
+                restart_count,
+                additional_info.to_string()
+            ),
+        }
+    }
+
+    // [impl->swdd~common-workload-states-failed-dependency-timeout~1]
+    pub fn dependency_timeout(additional_info: impl ToString) -> Self {
+        ExecutionState {
+            state: ExecutionStateEnum::Failed(FailedSubstate::DependencyTimeout),
+            additional_info: additional_info.to_string(),
+        }
+    }
+
     pub fn removed() -> Self {
         ExecutionState {
             state: ExecutionStateEnum::Removed,
@@ -378,6 +435,22 @@ impl ExecutionState {
         }
     }
 
+    // [impl->swdd~common-workload-states-running-unhealthy~1]
+    pub fn running_unhealthy(additional_info: impl ToString) -> Self {
+        ExecutionState {
+            state: ExecutionStateEnum::Running(RunningSubstate::Unhealthy),
+            additional_info: additional_info.to_string(),
+        }
+    }
+
+    // [impl->swdd~common-workload-states-running-not-ready~1]
+    pub fn running_not_ready(additional_info: impl ToString) -> Self {
+        ExecutionState {
+            state: ExecutionStateEnum::Running(RunningSubstate::NotReady),
+            additional_info: additional_info.to_string(),
+        }
+    }
+
     pub fn stopping(additional_info: impl ToString) -> Self {
         ExecutionState {
             state: ExecutionStateEnum::Stopping(StoppingSubstate::Stopping),
@@ -433,6 +506,13 @@ impl ExecutionState {
             ..Default::default()
         }
     }
+
+    pub fn not_scheduled_with_reason(additional_info: impl ToString) -> Self {
+        ExecutionState {
+            state: ExecutionStateEnum::NotScheduled,
+            additional_info: additional_info.to_string(),
+        }
+    }
 }
 
 impl From<ExecutionState> for ank_base::ExecutionState {
@@ -570,7 +650,8 @@ mod tests {
     use api::ank_base::{self};
 
     use crate::objects::{
-        ExecutionState, WorkloadInstanceName, WorkloadState, workload_state::NO_MORE_RETRIES_MSG,
+        ExecutionState, ExecutionStateEnum, FailedSubstate, WorkloadInstanceName, WorkloadState,
+        workload_state::{NO_MORE_RESTARTS_MSG, NO_MORE_RETRIES_MSG},
     };
 
     // [utest->swdd~common-workload-state-transitions~1]
@@ -680,7 +761,7 @@ mod tests {
     }
 
     // [utest->swdd~common-workload-state-additional-information~1]
-    // [utest->swdd~common-workload-states-supported-states~1]
+    // [utest->swdd~common-workload-states-supported-states~4]
     #[test]
     fn utest_execution_state_to_proto_mapping() {
         let additional_info = "some additional info";
@@ -785,7 +866,9 @@ mod tests {
     }
 
     // [utest->swdd~common-workload-state-additional-information~1]
-    // [utest->swdd~common-workload-states-supported-states~1]
+    // [utest->swdd~common-workload-states-supported-states~4]
+    // [utest->swdd~common-workload-states-running-unhealthy~1]
+    // [utest->swdd~common-workload-states-running-not-ready~1]
     #[test]
     fn utest_execution_state_from_proto_mapping() {
         let additional_info = "some additional info";
@@ -875,6 +958,26 @@ mod tests {
             }
             .into(),
         );
+        assert_eq!(
+            ExecutionState::running_unhealthy(additional_info),
+            ank_base::ExecutionState {
+                additional_info: additional_info.to_string(),
+                execution_state_enum: Some(ank_base::execution_state::ExecutionStateEnum::Running(
+                    ank_base::Running::Unhealthy.into(),
+                )),
+            }
+            .into(),
+        );
+        assert_eq!(
+            ExecutionState::running_not_ready(additional_info),
+            ank_base::ExecutionState {
+                additional_info: additional_info.to_string(),
+                execution_state_enum: Some(ank_base::execution_state::ExecutionStateEnum::Running(
+                    ank_base::Running::NotReady.into(),
+                )),
+            }
+            .into(),
+        );
         assert_eq!(
             ExecutionState::stopping(additional_info),
             ank_base::ExecutionState {
@@ -900,7 +1003,7 @@ mod tests {
     }
 
     // [utest->swdd~common-workload-state-additional-information~1]
-    // [utest->swdd~common-workload-states-supported-states~1]
+    // [utest->swdd~common-workload-states-supported-states~4]
     #[test]
     fn utest_execution_state_to_string_basic_mapping() {
         let additional_info = "some additional info";
@@ -937,6 +1040,14 @@ mod tests {
             ExecutionState::running().to_string(),
             String::from("Running(Ok)")
         );
+        assert_eq!(
+            ExecutionState::running_unhealthy(additional_info).to_string(),
+            format!("Running(Unhealthy): '{additional_info}'")
+        );
+        assert_eq!(
+            ExecutionState::running_not_ready(additional_info).to_string(),
+            format!("Running(NotReady): '{additional_info}'")
+        );
         assert_eq!(
             ExecutionState::stopping(additional_info).to_string(),
             format!("Stopping(Stopping): '{additional_info}'")
@@ -946,4 +1057,58 @@ mod tests {
             String::from("Failed(Lost)")
         );
     }
+
+    // [utest->swdd~common-workload-states-supported-states~4]
+    // [utest->swdd~common-workload-states-failed-crash-loop~1]
+    #[test]
+    fn utest_execution_state_crash_loop_mapping() {
+        let additional_info = "some additional info";
+
+        assert_eq!(
+            ExecutionState::crash_loop_backoff(2, additional_info).to_string(),
+            format!("Failed(CrashLoop): 'Waiting for restart 2: {additional_info}'")
+        );
+        assert_eq!(
+            ExecutionState::crash_loop_no_restart(5, additional_info).to_string(),
+            format!(
+                "Failed(CrashLoop): '{NO_MORE_RESTARTS_MSG} after 5 restarts: {additional_info}'"
+            )
+        );
+        assert_eq!(
+            ExecutionState::from(ank_base::ExecutionState {
+                additional_info: additional_info.to_string(),
+                execution_state_enum: Some(ank_base::execution_state::ExecutionStateEnum::Failed(
+                    ank_base::Failed::CrashLoop.into(),
+                )),
+            }),
+            ExecutionState {
+                state: ExecutionStateEnum::Failed(FailedSubstate::CrashLoop),
+                additional_info: additional_info.to_string(),
+            }
+        );
+        assert!(!ExecutionState::crash_loop_backoff(1, additional_info).is_failed());
+    }
+
+    // [utest->swdd~common-workload-states-supported-states~4]
+    // [utest->swdd~common-workload-states-failed-dependency-timeout~1]
+    #[test]
+    fn utest_execution_state_dependency_timeout_mapping() {
+        let additional_info = "some additional info";
+
+        assert_eq!(
+            ExecutionState::dependency_timeout(additional_info).to_string(),
+            format!("Failed(DependencyTimeout): '{additional_info}'")
+        );
+        assert_eq!(
+            ExecutionState::from(ank_base::ExecutionState {
+                additional_info: additional_info.to_string(),
+                execution_state_enum: Some(ank_base::execution_state::ExecutionStateEnum::Failed(
+                    ank_base::Failed::DependencyTimeout.into(),
+                )),
+            }),
+            ExecutionState::dependency_timeout(additional_info)
+        );
+        assert!(!ExecutionState::dependency_timeout(additional_info).is_failed());
+        assert!(ExecutionState::dependency_timeout(additional_info).is_not_pending_nor_running());
+    }
 }
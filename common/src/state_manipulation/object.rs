@@ -281,6 +281,40 @@ impl Object {
     pub fn check_if_provided_path_exists(&self, path: &Path) -> bool {
         self.get(path).is_some()
     }
+
+    // Replaces each wildcard part of the path with the keys of the mapping at this position.
+    // A path without wildcards is returned as it is, even if it does not exist.
+    pub fn expand_wildcards(&self, path: &Path) -> Vec<Path> {
+        let mut expanded_paths: Vec<Vec<String>> = vec![Vec::new()];
+        for part in path.parts() {
+            if part != ankaios::WILDCARD_SYMBOL {
+                expanded_paths
+                    .iter_mut()
+                    .for_each(|expanded_path| expanded_path.push(part.to_owned()));
+                continue;
+            }
+
+            expanded_paths = expanded_paths
+                .into_iter()
+                .flat_map(|expanded_path| {
+                    let keys: Vec<String> = match self.get(&expanded_path.clone().into()) {
+                        Some(Value::Mapping(mapping)) => mapping
+                            .keys()
+                            .filter_map(Value::as_str)
+                            .map(str::to_owned)
+                            .collect(),
+                        _ => Vec::new(),
+                    };
+                    keys.into_iter().map(move |key| {
+                        let mut expanded_path = expanded_path.clone();
+                        expanded_path.push(key);
+                        expanded_path
+                    })
+                })
+                .collect();
+        }
+        expanded_paths.into_iter().map(Path::from).collect()
+    }
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -303,7 +337,7 @@ mod tests {
     };
     use serde_yaml::Value;
 
-    use super::Object;
+    use super::{Object, Path};
     #[test]
     fn utest_object_from_state() {
         let state: State = generate_test_state_from_workloads(vec![
@@ -362,6 +396,7 @@ mod tests {
                 ExecutionState::running(),
             ),
             agents: agent_map,
+            workload_metrics: Default::default(),
         };
 
         let expected = Object {
@@ -396,6 +431,7 @@ mod tests {
                 ExecutionState::running(),
             ),
             agents: agent_map,
+            workload_metrics: Default::default(),
         };
         let actual: CompleteState = object.try_into().unwrap();
 
@@ -672,6 +708,39 @@ mod tests {
         assert!(res.is_some());
     }
 
+    #[test]
+    fn utest_object_expand_wildcards() {
+        let data = Object {
+            data: object::generate_test_complete_state().into(),
+        };
+
+        let res = data.expand_wildcards(&"desiredState.workloads.*.agent".into());
+
+        assert_eq!(res, vec![Path::from("desiredState.workloads.name.agent")]);
+    }
+
+    #[test]
+    fn utest_object_expand_wildcards_without_wildcard() {
+        let data = Object {
+            data: object::generate_test_complete_state().into(),
+        };
+
+        let res = data.expand_wildcards(&"desiredState.non_existing".into());
+
+        assert_eq!(res, vec![Path::from("desiredState.non_existing")]);
+    }
+
+    #[test]
+    fn utest_object_expand_wildcards_not_map() {
+        let data = Object {
+            data: object::generate_test_complete_state().into(),
+        };
+
+        let res = data.expand_wildcards(&"desiredState.apiVersion.*".into());
+
+        assert!(res.is_empty());
+    }
+
     #[test]
     fn utest_generate_paths_from_yaml_node_leaf_nodes_only() {
         let data: Value = object::generate_test_value_object();
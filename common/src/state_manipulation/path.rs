@@ -62,6 +62,12 @@ impl From<&String> for Path {
     }
 }
 
+impl From<Vec<String>> for Path {
+    fn from(parts: Vec<String>) -> Self {
+        Path { parts }
+    }
+}
+
 impl From<Path> for String {
     fn from(value: Path) -> Self {
         (&value).into()
@@ -70,6 +70,10 @@ pub fn generate_test_proto_complete_state(
         }),
         workload_states: None,
         agents: None,
+        revision: None,
+        workload_metrics: None,
+        rendered_workloads: None,
+        workload_state_history: None,
     }
 }
 
@@ -92,6 +96,7 @@ pub fn generate_test_complete_state(workloads: Vec<WorkloadSpec>) -> crate::obje
         },
         workload_states: generate_test_workload_states_map_from_specs(workloads),
         agents,
+        workload_metrics: Default::default(),
     }
 }
 
@@ -201,6 +206,14 @@ pub fn generate_test_proto_workload_with_param(
             ("ref2".into(), "config_2".into()),
         ].into()}),
         files: Some(generate_test_proto_workload_files()),
+        agent_selector: None,
+        resource_requests: None,
+        agents: None,
+        update_strategy: None,
+        probes: None,
+        restart_backoff: None,
+        dependency_timeouts: None,
+        resources: None,
     }
 }
 
@@ -222,6 +235,14 @@ pub fn generate_test_proto_workload() -> ank_base::Workload {
             ("ref2".into(), "config_2".into()),
         ].into()}),
         files: Some(generate_test_proto_workload_files()),
+        agent_selector: None,
+        resource_requests: None,
+        agents: None,
+        update_strategy: None,
+        probes: None,
+        restart_backoff: None,
+        dependency_timeouts: None,
+        resources: None,
     }
 }
 
@@ -18,7 +18,7 @@ use crate::{
 };
 use api::ank_base;
 use async_trait::async_trait;
-use std::fmt;
+use std::{collections::HashMap, fmt};
 use tokio::sync::mpsc::error::SendError;
 
 #[allow(clippy::large_enum_variant)]
@@ -53,7 +53,11 @@ impl fmt::Display for ToServerError {
 // [impl->swdd~to-server-channel~1]
 #[async_trait]
 pub trait ToServerInterface {
-    async fn agent_hello(&self, agent_name: String) -> Result<(), ToServerError>;
+    async fn agent_hello(
+        &self,
+        agent_name: String,
+        labels: HashMap<String, String>,
+    ) -> Result<(), ToServerError>;
     async fn agent_load_status(
         &self,
         agent_resource: commands::AgentLoadStatus,
@@ -64,6 +68,14 @@ pub trait ToServerInterface {
         request_id: String,
         state: CompleteState,
         update_mask: Vec<String>,
+        expected_revision: Option<u64>,
+    ) -> Result<(), ToServerError>;
+    async fn dry_run_update_state(
+        &self,
+        request_id: String,
+        state: CompleteState,
+        update_mask: Vec<String>,
+        expected_revision: Option<u64>,
     ) -> Result<(), ToServerError>;
     async fn update_workload_state(
         &self,
@@ -74,12 +86,23 @@ pub trait ToServerInterface {
         request_id: String,
         request_complete_state: commands::CompleteStateRequest,
     ) -> Result<(), ToServerError>;
+    async fn revisions_request(
+        &self,
+        request_id: String,
+        revisions_request: commands::RevisionsRequest,
+    ) -> Result<(), ToServerError>;
     async fn logs_request(
         &self,
         request_id: String,
         logs_request: LogsRequest,
     ) -> Result<(), ToServerError>;
     async fn logs_cancel_request(&self, request_id: String) -> Result<(), ToServerError>;
+    async fn events_subscribe_request(
+        &self,
+        request_id: String,
+        events_subscribe_request: commands::EventsSubscribeRequest,
+    ) -> Result<(), ToServerError>;
+    async fn events_cancel_request(&self, request_id: String) -> Result<(), ToServerError>;
     async fn log_entries_response(
         &self,
         request_id: String,
@@ -99,9 +122,16 @@ pub type ToServerReceiver = tokio::sync::mpsc::Receiver<ToServer>;
 
 #[async_trait]
 impl ToServerInterface for ToServerSender {
-    async fn agent_hello(&self, agent_name: String) -> Result<(), ToServerError> {
+    async fn agent_hello(
+        &self,
+        agent_name: String,
+        labels: HashMap<String, String>,
+    ) -> Result<(), ToServerError> {
         Ok(self
-            .send(ToServer::AgentHello(commands::AgentHello { agent_name }))
+            .send(ToServer::AgentHello(commands::AgentHello {
+                agent_name,
+                labels,
+            }))
             .await?)
     }
 
@@ -125,12 +155,40 @@ impl ToServerInterface for ToServerSender {
         request_id: String,
         state: CompleteState,
         update_mask: Vec<String>,
+        expected_revision: Option<u64>,
+    ) -> Result<(), ToServerError> {
+        Ok(self
+            .send(ToServer::Request(commands::Request {
+                request_id,
+                request_content: commands::RequestContent::UpdateStateRequest(Box::new(
+                    commands::UpdateStateRequest {
+                        state,
+                        update_mask,
+                        dry_run: false,
+                        expected_revision,
+                    },
+                )),
+            }))
+            .await?)
+    }
+
+    async fn dry_run_update_state(
+        &self,
+        request_id: String,
+        state: CompleteState,
+        update_mask: Vec<String>,
+        expected_revision: Option<u64>,
     ) -> Result<(), ToServerError> {
         Ok(self
             .send(ToServer::Request(commands::Request {
                 request_id,
                 request_content: commands::RequestContent::UpdateStateRequest(Box::new(
-                    commands::UpdateStateRequest { state, update_mask },
+                    commands::UpdateStateRequest {
+                        state,
+                        update_mask,
+                        dry_run: true,
+                        expected_revision,
+                    },
                 )),
             }))
             .await?)
@@ -166,6 +224,19 @@ impl ToServerInterface for ToServerSender {
             .await?)
     }
 
+    async fn revisions_request(
+        &self,
+        request_id: String,
+        revisions_request: commands::RevisionsRequest,
+    ) -> Result<(), ToServerError> {
+        Ok(self
+            .send(ToServer::Request(commands::Request {
+                request_id,
+                request_content: RequestContent::RevisionsRequest(revisions_request),
+            }))
+            .await?)
+    }
+
     async fn logs_request(
         &self,
         request_id: String,
@@ -188,6 +259,28 @@ impl ToServerInterface for ToServerSender {
             .await?)
     }
 
+    async fn events_subscribe_request(
+        &self,
+        request_id: String,
+        events_subscribe_request: commands::EventsSubscribeRequest,
+    ) -> Result<(), ToServerError> {
+        Ok(self
+            .send(ToServer::Request(commands::Request {
+                request_id,
+                request_content: RequestContent::EventsSubscribeRequest(events_subscribe_request),
+            }))
+            .await?)
+    }
+
+    async fn events_cancel_request(&self, request_id: String) -> Result<(), ToServerError> {
+        Ok(self
+            .send(ToServer::Request(commands::Request {
+                request_id,
+                request_content: RequestContent::EventsCancelRequest,
+            }))
+            .await?)
+    }
+
     async fn log_entries_response(
         &self,
         request_id: String,
@@ -230,6 +323,7 @@ impl ToServerInterface for ToServerSender {
 #[cfg(test)]
 mod tests {
     use api::ank_base::{self, LogEntriesResponse, LogEntry};
+    use std::collections::HashMap;
 
     use crate::{
         commands::{self, AgentLoadStatus, RequestContent},
@@ -248,6 +342,7 @@ mod tests {
     const AGENT_NAME: &str = "agent_A";
     const REQUEST_ID: &str = "emkw489ejf89ml";
     const FIELD_MASK: &str = "desiredState.bla_bla";
+    const EXPECTED_REVISION: u64 = 42;
     const CPU_USAGE: CpuUsage = CpuUsage { cpu_usage: 42 };
     const FREE_MEMORY: FreeMemory = FreeMemory { free_memory: 42 };
 
@@ -257,12 +352,18 @@ mod tests {
         let (tx, mut rx): (ToServerSender, ToServerReceiver) =
             tokio::sync::mpsc::channel(TEST_CHANNEL_CAPA);
 
-        assert!(tx.agent_hello(AGENT_NAME.to_string()).await.is_ok());
+        let labels = HashMap::from([("arch".to_owned(), "arm64".to_owned())]);
+        assert!(
+            tx.agent_hello(AGENT_NAME.to_string(), labels.clone())
+                .await
+                .is_ok()
+        );
 
         assert_eq!(
             rx.recv().await.unwrap(),
             ToServer::AgentHello(commands::AgentHello {
-                agent_name: AGENT_NAME.to_string()
+                agent_name: AGENT_NAME.to_string(),
+                labels,
             })
         )
     }
@@ -278,6 +379,7 @@ mod tests {
                 agent_name: AGENT_NAME.to_string(),
                 cpu_usage: CPU_USAGE.clone(),
                 free_memory: FREE_MEMORY.clone(),
+                workload_metrics: Default::default(),
             })
             .await
             .is_ok());
@@ -288,6 +390,7 @@ mod tests {
                 agent_name: AGENT_NAME.to_string(),
                 cpu_usage: CPU_USAGE.clone(),
                 free_memory: FREE_MEMORY.clone(),
+                workload_metrics: Default::default(),
             }))
         )
     }
@@ -320,7 +423,8 @@ mod tests {
             .update_state(
                 REQUEST_ID.to_string(),
                 complete_state.clone(),
-                vec![FIELD_MASK.to_string()]
+                vec![FIELD_MASK.to_string()],
+                Some(EXPECTED_REVISION)
             )
             .await
             .is_ok());
@@ -332,7 +436,43 @@ mod tests {
                 request_content: commands::RequestContent::UpdateStateRequest(Box::new(
                     commands::UpdateStateRequest {
                         state: complete_state,
-                        update_mask: vec![FIELD_MASK.to_string()]
+                        update_mask: vec![FIELD_MASK.to_string()],
+                        dry_run: false,
+                        expected_revision: Some(EXPECTED_REVISION),
+                    },
+                )),
+            })
+        )
+    }
+
+    // [utest->swdd~to-server-channel~1]
+    #[tokio::test]
+    async fn utest_to_server_send_dry_run_update_state() {
+        let (tx, mut rx): (ToServerSender, ToServerReceiver) =
+            tokio::sync::mpsc::channel(TEST_CHANNEL_CAPA);
+
+        let workload1 = generate_test_workload_spec();
+        let complete_state = generate_test_complete_state(vec![workload1]);
+        assert!(tx
+            .dry_run_update_state(
+                REQUEST_ID.to_string(),
+                complete_state.clone(),
+                vec![FIELD_MASK.to_string()],
+                Some(EXPECTED_REVISION)
+            )
+            .await
+            .is_ok());
+
+        assert_eq!(
+            rx.recv().await.unwrap(),
+            ToServer::Request(commands::Request {
+                request_id: REQUEST_ID.to_string(),
+                request_content: commands::RequestContent::UpdateStateRequest(Box::new(
+                    commands::UpdateStateRequest {
+                        state: complete_state,
+                        update_mask: vec![FIELD_MASK.to_string()],
+                        dry_run: true,
+                        expected_revision: Some(EXPECTED_REVISION),
                     },
                 )),
             })
@@ -410,6 +550,27 @@ mod tests {
         )
     }
 
+    #[tokio::test]
+    async fn utest_to_server_send_revisions_request() {
+        let (tx, mut rx): (ToServerSender, ToServerReceiver) =
+            tokio::sync::mpsc::channel(TEST_CHANNEL_CAPA);
+
+        let revisions_request = commands::RevisionsRequest { revision: Some(2) };
+        assert!(
+            tx.revisions_request(REQUEST_ID.into(), revisions_request.clone())
+                .await
+                .is_ok()
+        );
+
+        assert_eq!(
+            rx.recv().await.unwrap(),
+            ToServer::Request(commands::Request {
+                request_id: REQUEST_ID.to_string(),
+                request_content: RequestContent::RevisionsRequest(revisions_request),
+            })
+        )
+    }
+
     #[tokio::test]
     async fn utest_to_server_send_logs_cancel_request() {
         let (tx, mut rx): (ToServerSender, ToServerReceiver) =
@@ -427,6 +588,45 @@ mod tests {
         )
     }
 
+    #[tokio::test]
+    async fn utest_to_server_send_events_subscribe_request() {
+        let (tx, mut rx): (ToServerSender, ToServerReceiver) =
+            tokio::sync::mpsc::channel(TEST_CHANNEL_CAPA);
+
+        let events_subscribe_request = commands::EventsSubscribeRequest {
+            field_masks: vec!["workloadStates".to_string()],
+        };
+        assert!(
+            tx.events_subscribe_request(REQUEST_ID.into(), events_subscribe_request.clone())
+                .await
+                .is_ok()
+        );
+
+        assert_eq!(
+            rx.recv().await.unwrap(),
+            ToServer::Request(commands::Request {
+                request_id: REQUEST_ID.to_string(),
+                request_content: RequestContent::EventsSubscribeRequest(events_subscribe_request),
+            })
+        )
+    }
+
+    #[tokio::test]
+    async fn utest_to_server_send_events_cancel_request() {
+        let (tx, mut rx): (ToServerSender, ToServerReceiver) =
+            tokio::sync::mpsc::channel(TEST_CHANNEL_CAPA);
+
+        assert!(tx.events_cancel_request(REQUEST_ID.into()).await.is_ok());
+
+        assert_eq!(
+            rx.recv().await.unwrap(),
+            ToServer::Request(commands::Request {
+                request_id: REQUEST_ID.to_string(),
+                request_content: RequestContent::EventsCancelRequest,
+            })
+        )
+    }
+
     #[tokio::test]
     async fn utest_to_server_send_logs_response() {
         let (tx, mut rx): (ToServerSender, ToServerReceiver) =
@@ -440,6 +640,7 @@ mod tests {
                     id: "id".into(),
                 }),
                 message: "message".into(),
+                ..Default::default()
             }],
         };
 
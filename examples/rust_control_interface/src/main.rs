@@ -13,12 +13,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use api::ank_base::{
-    request::RequestContent, response::ResponseContent, CompleteState, CompleteStateRequest,
-    Dependencies, Request, RestartPolicy, State, Tag, Tags, UpdateStateRequest, Workload,
-    WorkloadMap,
+    CompleteState, CompleteStateRequest, Dependencies, Request, RestartPolicy, State, Tag, Tags,
+    UpdateStateRequest, Workload, WorkloadMap, request::RequestContent, response::ResponseContent,
 };
 use api::control_api::{
-    from_ankaios::FromAnkaiosEnum, to_ankaios::ToAnkaiosEnum, FromAnkaios, Hello, ToAnkaios,
+    FromAnkaios, Hello, ToAnkaios, from_ankaios::FromAnkaiosEnum, to_ankaios::ToAnkaiosEnum,
 };
 
 use prost::Message;
@@ -99,6 +98,8 @@ fn create_request_to_add_new_workload() -> ToAnkaios {
                         ..Default::default()
                     }),
                     update_mask: vec!["desiredState.workloads.dynamic_nginx".to_string()],
+                    dry_run: false,
+                    expected_revision: None,
                 },
             ))),
         })),
@@ -30,6 +30,7 @@ use common::std_extensions::IllegalStateResult;
 use common::to_server_interface::ToServerReceiver;
 
 use regex::Regex;
+use std::collections::HashMap;
 use tokio::select;
 use tokio::sync::mpsc::Receiver;
 use tokio_stream::wrappers::ReceiverStream;
@@ -47,6 +48,7 @@ enum ConnectionType {
 
 pub struct GRPCCommunicationsClient {
     name: String,
+    labels: HashMap<String, String>,
     server_address: String,
     connection_type: ConnectionType,
     tls_config: Option<TLSConfig>,
@@ -73,6 +75,7 @@ fn verify_address_format(server_address: &String) -> Result<(), CommunicationMid
 impl GRPCCommunicationsClient {
     pub fn new_agent_communication(
         name: String,
+        labels: HashMap<String, String>,
         server_address: String,
         tls_config: Option<TLSConfig>,
     ) -> Result<Self, CommunicationMiddlewareError> {
@@ -80,6 +83,7 @@ impl GRPCCommunicationsClient {
 
         Ok(Self {
             name,
+            labels,
             server_address: get_server_url(&server_address, &tls_config),
             connection_type: ConnectionType::Agent,
             tls_config,
@@ -95,6 +99,7 @@ impl GRPCCommunicationsClient {
 
         Ok(Self {
             name,
+            labels: HashMap::new(),
             server_address: get_server_url(&server_address, &tls_config),
             connection_type: ConnectionType::Cli,
             tls_config,
@@ -182,7 +187,10 @@ impl GRPCCommunicationsClient {
             ConnectionType::Agent => {
                 grpc_tx
                     .send(grpc_api::ToServer {
-                        to_server_enum: Some(ToServerEnum::AgentHello(AgentHello::new(&self.name))),
+                        to_server_enum: Some(ToServerEnum::AgentHello(AgentHello::new(
+                            &self.name,
+                            self.labels.clone(),
+                        ))),
                     })
                     .await?;
             }
@@ -119,6 +119,7 @@ impl AgentConnection for GRPCAgentConnection {
             ToServerEnum::AgentHello(grpc_api::AgentHello {
                 agent_name,
                 protocol_version,
+                labels,
             }) => {
                 log::trace!("Received a hello from '{agent_name}'");
 
@@ -135,7 +136,10 @@ impl AgentConnection for GRPCAgentConnection {
                     self.agent_senders
                         .insert(&agent_name, new_agent_sender.to_owned());
                     // [impl->swdd~grpc-agent-connection-forwards-hello-to-ankaios-server~1]
-                    if let Err(error) = self.to_ankaios_server.agent_hello(agent_name.clone()).await
+                    if let Err(error) = self
+                        .to_ankaios_server
+                        .agent_hello(agent_name.clone(), labels)
+                        .await
                     {
                         log::error!("Could not send agent hello: '{error}'");
                     }
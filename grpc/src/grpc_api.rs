@@ -20,10 +20,11 @@ use std::collections::HashMap;
 tonic::include_proto!("grpc_api"); // The string specified here must match the proto package name
 
 impl AgentHello {
-    pub fn new(agent_name: impl Into<String>) -> Self {
+    pub fn new(agent_name: impl Into<String>, labels: HashMap<String, String>) -> Self {
         AgentHello {
             agent_name: agent_name.into(),
             protocol_version: common::ANKAIOS_VERSION.into(),
+            labels,
         }
     }
 }
@@ -42,6 +43,11 @@ impl From<AgentLoadStatus> for commands::AgentLoadStatus {
             agent_name: item.agent_name,
             cpu_usage: item.cpu_usage.unwrap_or_default().into(),
             free_memory: item.free_memory.unwrap_or_default().into(),
+            workload_metrics: item
+                .workload_metrics
+                .into_iter()
+                .map(|(workload_name, metrics)| (workload_name, metrics.into()))
+                .collect(),
         }
     }
 }
@@ -52,6 +58,11 @@ impl From<commands::AgentLoadStatus> for AgentLoadStatus {
             agent_name: item.agent_name,
             cpu_usage: Some(item.cpu_usage.into()),
             free_memory: Some(item.free_memory.into()),
+            workload_metrics: item
+                .workload_metrics
+                .into_iter()
+                .map(|(workload_name, metrics)| (workload_name, metrics.into()))
+                .collect(),
         }
     }
 }
@@ -113,6 +124,7 @@ impl TryFrom<AddedWorkload> for objects::WorkloadSpec {
                 .into_iter()
                 .map(|(k, v)| Ok((k, v.try_into()?)))
                 .collect::<Result<HashMap<String, objects::AddCondition>, String>>()?,
+            dependency_timeouts: workload.dependency_timeouts,
             restart_policy: workload.restart_policy.try_into()?,
             runtime: workload.runtime,
             instance_name: workload.instance_name.ok_or("No instance name")?.into(),
@@ -127,6 +139,10 @@ impl TryFrom<AddedWorkload> for objects::WorkloadSpec {
                 .into_iter()
                 .map(TryInto::try_into)
                 .collect::<Result<_, _>>()?,
+            probes: workload.probes.map(Into::into),
+            restart_backoff: workload.restart_backoff.map(Into::into),
+            resources: workload.resources.map(Into::into),
+            update_ordering: workload.update_ordering.try_into()?,
         })
     }
 }
@@ -147,6 +163,11 @@ impl From<objects::WorkloadSpec> for AddedWorkload {
             tags: workload.tags.into_iter().map(|x| x.into()).collect(),
             files: workload.files.into_iter().map(Into::into).collect(),
             control_interface_access: workload.control_interface_access.into(),
+            probes: workload.probes.map(Into::into),
+            restart_backoff: workload.restart_backoff.map(Into::into),
+            dependency_timeouts: workload.dependency_timeouts,
+            resources: workload.resources.map(Into::into),
+            update_ordering: workload.update_ordering as i32,
         }
     }
 }
@@ -286,6 +307,11 @@ mod tests {
                     )),
                 },
             ],
+            probes: None,
+            restart_backoff: None,
+            dependency_timeouts: HashMap::new(),
+            resources: None,
+            update_ordering: ank_base::UpdateOrdering::AtMostOnce.into(),
         };
 
         assert_eq!(AddedWorkload::from(workload_spec), proto_workload);
@@ -314,6 +340,11 @@ mod tests {
             runtime_config: String::from("some config"),
             control_interface_access: Default::default(),
             files: generate_test_rendered_workload_files(),
+            probes: None,
+            restart_backoff: None,
+            dependency_timeouts: HashMap::new(),
+            resources: None,
+            update_ordering: ankaios::UpdateOrdering::AtLeastOnce,
         };
 
         let proto_workload = AddedWorkload {
@@ -349,6 +380,11 @@ mod tests {
                     )),
                 },
             ],
+            probes: None,
+            restart_backoff: None,
+            dependency_timeouts: HashMap::new(),
+            resources: None,
+            update_ordering: ank_base::UpdateOrdering::AtLeastOnce.into(),
         };
 
         assert_eq!(
@@ -381,6 +417,11 @@ mod tests {
             tags: vec![],
             control_interface_access: Default::default(),
             files: Default::default(),
+            probes: None,
+            restart_backoff: None,
+            dependency_timeouts: HashMap::new(),
+            resources: None,
+            update_ordering: ank_base::UpdateOrdering::AtMostOnce.into(),
         };
 
         assert!(ankaios::WorkloadSpec::try_from(proto_workload).is_err());
@@ -75,12 +75,28 @@ pub async fn forward_from_proto_to_ankaios(
                         let UpdateStateRequest {
                             new_state,
                             update_mask,
+                            dry_run,
+                            expected_revision,
                         } = *update_state_request;
                         log::debug!("Received UpdateStateRequest from '{agent_name}'");
                         match new_state.unwrap_or_default().try_into() {
+                            Ok(new_state) if dry_run => {
+                                sink.dry_run_update_state(
+                                    request_id,
+                                    new_state,
+                                    update_mask,
+                                    expected_revision,
+                                )
+                                .await?;
+                            }
                             Ok(new_state) => {
-                                sink.update_state(request_id, new_state, update_mask)
-                                    .await?;
+                                sink.update_state(
+                                    request_id,
+                                    new_state,
+                                    update_mask,
+                                    expected_revision,
+                                )
+                                .await?;
                             }
                             Err(error) => {
                                 return Err(GrpcMiddlewareError::ConversionError(format!(
@@ -105,6 +121,20 @@ pub async fn forward_from_proto_to_ankaios(
                         log::trace!("Received LogsCancelRequest from '{agent_name}'");
                         sink.logs_cancel_request(request_id).await?;
                     }
+                    RequestContent::RevisionsRequest(revisions_request) => {
+                        log::trace!("Received RevisionsRequest from '{agent_name}'");
+                        sink.revisions_request(request_id, revisions_request.into())
+                            .await?;
+                    }
+                    RequestContent::EventsSubscribeRequest(events_subscribe_request) => {
+                        log::trace!("Received EventsSubscribeRequest from '{agent_name}'");
+                        sink.events_subscribe_request(request_id, events_subscribe_request.into())
+                            .await?;
+                    }
+                    RequestContent::EventsCancelRequest(_events_cancel_request) => {
+                        log::trace!("Received EventsCancelRequest from '{agent_name}'");
+                        sink.events_cancel_request(request_id).await?;
+                    }
                 }
             }
 
@@ -230,6 +260,7 @@ pub async fn forward_from_ankaios_to_proto(
                                 agent_name: status.agent_name,
                                 cpu_usage: status.cpu_usage,
                                 free_memory: status.free_memory,
+                                workload_metrics: status.workload_metrics,
                             }
                             .into(),
                         )),
@@ -302,7 +333,7 @@ pub async fn forward_from_ankaios_to_proto(
 #[cfg(test)]
 mod tests {
 
-    use std::collections::LinkedList;
+    use std::collections::{HashMap, LinkedList};
 
     use super::{GRPCStreaming, forward_from_ankaios_to_proto, forward_from_proto_to_ankaios};
     use async_trait::async_trait;
@@ -360,6 +391,7 @@ mod tests {
             agent_name: AGENT_A_NAME.to_string(),
             cpu_usage: CpuUsage { cpu_usage: 42 },
             free_memory: FreeMemory { free_memory: 42 },
+            workload_metrics: Default::default(),
         };
 
         let agent_resource_result = server_tx.agent_load_status(agent_load_status.clone()).await;
@@ -378,6 +410,7 @@ mod tests {
             agent_name: AGENT_A_NAME.to_string(),
             cpu_usage: Some(ank_base::CpuUsage { cpu_usage: 42 }),
             free_memory: Some(ank_base::FreeMemory { free_memory: 42 }),
+            workload_metrics: Default::default(),
         });
 
         assert_eq!(result.to_server_enum, Some(expected));
@@ -390,6 +423,7 @@ mod tests {
             agent_name: AGENT_A_NAME.to_string(),
             cpu_usage: CpuUsage { cpu_usage: 42 },
             free_memory: FreeMemory { free_memory: 42 },
+            workload_metrics: Default::default(),
         };
 
         let (server_tx, mut server_rx) = mpsc::channel::<ToServer>(common::CHANNEL_CAPACITY);
@@ -441,6 +475,7 @@ mod tests {
                 REQUEST_ID.to_owned(),
                 input_state.clone(),
                 update_mask.clone(),
+                None,
             )
             .await;
         assert!(update_state_result.is_ok());
@@ -601,6 +636,8 @@ mod tests {
                                 ank_base::UpdateStateRequest {
                                     new_state: Some(ankaios_state),
                                     update_mask: ankaios_update_mask.clone(),
+                                    dry_run: false,
+                                    expected_revision: None,
                                 },
                             )),
                         ),
@@ -644,6 +681,8 @@ mod tests {
                                 ank_base::UpdateStateRequest {
                                     new_state: Some(ankaios_state.clone().into()),
                                     update_mask: ankaios_update_mask.clone(),
+                                    dry_run: false,
+                                    expected_revision: None,
                                 },
                             )),
                         ),
@@ -675,6 +714,64 @@ mod tests {
             if request_id == expected_prefixed_my_request_id && update_request.state == ankaios_state && update_request.update_mask == ankaios_update_mask));
     }
 
+    // [utest->swdd~grpc-agent-connection-forwards-commands-to-server~1]
+    #[tokio::test]
+    async fn utest_to_server_command_forward_from_proto_to_ankaios_dry_run_update_workload() {
+        let (server_tx, mut server_rx) = mpsc::channel::<ToServer>(common::CHANNEL_CAPACITY);
+
+        let ankaios_state =
+            generate_test_complete_state(vec![generate_test_workload_spec_with_param(
+                AGENT_A_NAME.to_string(),
+                WORKLOAD_1_NAME.to_string(),
+                RUNTIME_NAME.to_string(),
+            )]);
+
+        let ankaios_update_mask = vec!["bla".into()];
+
+        // simulate the reception of an update workload state grpc from server message
+        let mut mock_grpc_ex_request_streaming =
+            MockGRPCToServerStreaming::new(LinkedList::from([
+                Some(grpc_api::ToServer {
+                    to_server_enum: Some(ToServerEnum::Request(ank_base::Request {
+                        request_id: REQUEST_ID.to_owned(),
+                        request_content: Some(
+                            ank_base::request::RequestContent::UpdateStateRequest(Box::new(
+                                ank_base::UpdateStateRequest {
+                                    new_state: Some(ankaios_state.clone().into()),
+                                    update_mask: ankaios_update_mask.clone(),
+                                    dry_run: true,
+                                    expected_revision: None,
+                                },
+                            )),
+                        ),
+                    })),
+                }),
+                None,
+            ]));
+
+        // forwards from proto to ankaios
+        let forward_result = forward_from_proto_to_ankaios(
+            AGENT_A_NAME.to_string(),
+            &mut mock_grpc_ex_request_streaming,
+            server_tx,
+        )
+        .await;
+
+        assert!(forward_result.is_ok());
+
+        // pick received from server message
+        let result = server_rx.recv().await.unwrap();
+        let expected_prefixed_my_request_id = format!("{AGENT_A_NAME}@{REQUEST_ID}");
+
+        assert!(matches!(
+            result,
+            ToServer::Request(common::commands::Request {
+                request_id,
+                request_content: common::commands::RequestContent::UpdateStateRequest(update_request),
+            })
+            if request_id == expected_prefixed_my_request_id && update_request.state == ankaios_state && update_request.update_mask == ankaios_update_mask && update_request.dry_run));
+    }
+
     // [utest->swdd~grpc-agent-connection-forwards-commands-to-server~1]
     #[tokio::test]
     async fn utest_to_server_command_forward_from_proto_to_ankaios_update_workload_state() {
@@ -916,6 +1013,105 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn utest_to_server_command_forward_from_proto_to_ankaios_request_revisions() {
+        let (server_tx, mut server_rx) = mpsc::channel::<ToServer>(common::CHANNEL_CAPACITY);
+
+        let mut mock_grpc_ex_request_streaming =
+            MockGRPCToServerStreaming::new(LinkedList::from([
+                Some(grpc_api::ToServer {
+                    to_server_enum: Some(ToServerEnum::Request(ank_base::Request {
+                        request_id: REQUEST_ID.to_string(),
+                        request_content: Some(ank_base::request::RequestContent::RevisionsRequest(
+                            ank_base::RevisionsRequest { revision: Some(2) },
+                        )),
+                    })),
+                }),
+                None,
+            ]));
+
+        let forward_result = forward_from_proto_to_ankaios(
+            AGENT_A_NAME.to_string(),
+            &mut mock_grpc_ex_request_streaming,
+            server_tx,
+        )
+        .await;
+        assert!(forward_result.is_ok());
+
+        let result = server_rx.recv().await.unwrap();
+        let expected_prefixed_my_request_id = format!("{AGENT_A_NAME}@{REQUEST_ID}");
+
+        assert!(matches!(
+            result,
+            common::to_server_interface::ToServer::Request(common::commands::Request {
+                request_id,
+                request_content: common::commands::RequestContent::RevisionsRequest(
+                    common::commands::RevisionsRequest { revision: Some(2) }
+                ),
+            }) if request_id == expected_prefixed_my_request_id
+        ));
+    }
+
+    #[tokio::test]
+    async fn utest_to_server_command_forward_from_proto_to_ankaios_events_subscribe_and_cancel() {
+        let (server_tx, mut server_rx) = mpsc::channel::<ToServer>(common::CHANNEL_CAPACITY);
+
+        let mut mock_grpc_ex_request_streaming =
+            MockGRPCToServerStreaming::new(LinkedList::from([
+                Some(grpc_api::ToServer {
+                    to_server_enum: Some(ToServerEnum::Request(ank_base::Request {
+                        request_id: REQUEST_ID.to_string(),
+                        request_content: Some(
+                            ank_base::request::RequestContent::EventsSubscribeRequest(
+                                ank_base::EventsSubscribeRequest {
+                                    field_masks: vec!["workloadStates".to_string()],
+                                },
+                            ),
+                        ),
+                    })),
+                }),
+                Some(grpc_api::ToServer {
+                    to_server_enum: Some(ToServerEnum::Request(ank_base::Request {
+                        request_id: REQUEST_ID.to_string(),
+                        request_content: Some(
+                            ank_base::request::RequestContent::EventsCancelRequest(
+                                ank_base::EventsCancelRequest {},
+                            ),
+                        ),
+                    })),
+                }),
+                None,
+            ]));
+
+        let forward_result = forward_from_proto_to_ankaios(
+            AGENT_A_NAME.to_string(),
+            &mut mock_grpc_ex_request_streaming,
+            server_tx,
+        )
+        .await;
+        assert!(forward_result.is_ok());
+
+        let expected_prefixed_my_request_id = format!("{AGENT_A_NAME}@{REQUEST_ID}");
+        assert_eq!(
+            server_rx.recv().await.unwrap(),
+            common::to_server_interface::ToServer::Request(common::commands::Request {
+                request_id: expected_prefixed_my_request_id.clone(),
+                request_content: common::commands::RequestContent::EventsSubscribeRequest(
+                    common::commands::EventsSubscribeRequest {
+                        field_masks: vec!["workloadStates".to_string()]
+                    }
+                ),
+            })
+        );
+        assert_eq!(
+            server_rx.recv().await.unwrap(),
+            common::to_server_interface::ToServer::Request(common::commands::Request {
+                request_id: expected_prefixed_my_request_id,
+                request_content: common::commands::RequestContent::EventsCancelRequest,
+            })
+        );
+    }
+
     #[tokio::test]
     async fn utest_to_server_command_forward_to_ankaios_to_proto_logs() {
         let (server_tx, mut server_rx) = mpsc::channel::<ToServer>(common::CHANNEL_CAPACITY);
@@ -935,6 +1131,7 @@ mod tests {
                                             id: WORKLOAD_ID_1.to_string(),
                                         }),
                                         message: LOG_MESSAGE_1.to_string(),
+                                        ..Default::default()
                                     },
                                     LogEntry {
                                         workload_name: Some(WorkloadInstanceName {
@@ -943,6 +1140,7 @@ mod tests {
                                             id: WORKLOAD_ID_2.to_string(),
                                         }),
                                         message: LOG_MESSAGE_2.to_string(),
+                                        ..Default::default()
                                     },
                                 ],
                             }),
@@ -971,8 +1169,8 @@ mod tests {
                 ank_base::LogEntriesResponse { log_entries }
             ) if request_id == REQUEST_ID
                  && matches!(log_entries.as_slice(),
-                            [ank_base::LogEntry{ workload_name: Some(ank_base::WorkloadInstanceName{ workload_name: workload_name_1, agent_name: agent_name_1, id: id_1 }), message: message_1 },
-                             ank_base::LogEntry{ workload_name: Some(ank_base::WorkloadInstanceName{ workload_name: workload_name_2, agent_name: agent_name_2, id: id_2 }), message: message_2 }]
+                            [ank_base::LogEntry{ workload_name: Some(ank_base::WorkloadInstanceName{ workload_name: workload_name_1, agent_name: agent_name_1, id: id_1 }), message: message_1, .. },
+                             ank_base::LogEntry{ workload_name: Some(ank_base::WorkloadInstanceName{ workload_name: workload_name_2, agent_name: agent_name_2, id: id_2 }), message: message_2, .. }]
                             if workload_name_1 == WORKLOAD_1_NAME && agent_name_1 == AGENT_B_NAME && id_1 == WORKLOAD_ID_1 && message_1 == LOG_MESSAGE_1
                                && workload_name_2 == WORKLOAD_2_NAME && agent_name_2 == AGENT_B_NAME && id_2 == WORKLOAD_ID_2 && message_2 == LOG_MESSAGE_2)
         ));
@@ -1028,6 +1226,7 @@ mod tests {
                                 id: WORKLOAD_ID_1.to_string(),
                             }),
                             message: LOG_MESSAGE_1.to_string(),
+                            ..Default::default()
                         },
                         LogEntry {
                             workload_name: Some(WorkloadInstanceName {
@@ -1036,6 +1235,7 @@ mod tests {
                                 id: WORKLOAD_ID_2.to_string(),
                             }),
                             message: LOG_MESSAGE_2.to_string(),
+                            ..Default::default()
                         },
                     ],
                 },
@@ -1060,8 +1260,8 @@ mod tests {
                 log_entries_response: Some(LogEntriesResponse { log_entries })
             })) if request_id == REQUEST_ID
                     && matches!(log_entries.as_slice(),
-                                [ank_base::LogEntry{ workload_name: Some(ank_base:: WorkloadInstanceName{ workload_name: workload_name_1, agent_name: agent_name_1, id: id_1 }), message: message_1 },
-                                 ank_base::LogEntry{ workload_name: Some(ank_base:: WorkloadInstanceName{ workload_name: workload_name_2, agent_name: agent_name_2, id: id_2 }), message: message_2 }]
+                                [ank_base::LogEntry{ workload_name: Some(ank_base:: WorkloadInstanceName{ workload_name: workload_name_1, agent_name: agent_name_1, id: id_1 }), message: message_1, .. },
+                                 ank_base::LogEntry{ workload_name: Some(ank_base:: WorkloadInstanceName{ workload_name: workload_name_2, agent_name: agent_name_2, id: id_2 }), message: message_2, .. }]
                                 if workload_name_1 == WORKLOAD_1_NAME && agent_name_1 == AGENT_B_NAME && id_1 == WORKLOAD_ID_1 && message_1 == LOG_MESSAGE_1
                                    && workload_name_2 == WORKLOAD_2_NAME && agent_name_2 == AGENT_B_NAME && id_2 == WORKLOAD_ID_2 && message_2 == LOG_MESSAGE_2)
         ));
@@ -1200,6 +1400,7 @@ mod tests {
                     to_server_enum: Some(ToServerEnum::AgentHello(crate::AgentHello {
                         agent_name: AGENT_A_NAME.to_string(),
                         protocol_version: common::ANKAIOS_VERSION.into(),
+                        labels: HashMap::new(),
                     })),
                 }),
                 Some(grpc_api::ToServer {
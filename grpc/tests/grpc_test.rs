@@ -16,6 +16,7 @@
 mod grpc_tests {
 
     use std::{
+        collections::HashMap,
         fs::File,
         io::{self, Write},
         os::unix::fs::PermissionsExt,
@@ -41,6 +42,9 @@ mod grpc_tests {
     use tempfile::TempDir;
     use tokio::time::timeout;
 
+    const TEST_LABEL_KEY: &str = "arch";
+    const TEST_LABEL_VALUE: &str = "arm64";
+
     /* 10 years validity issued at 08/16/2024 check validity if tests are failing */
     static TEST_CA_PEM_CONTENT: &str = r#"-----BEGIN CERTIFICATE-----
 MIHkMIGXAhRMeqnC4+qqaKLagAUS/RggNDVchjAFBgMrZXAwFTETMBEGA1UEAwwK
@@ -220,6 +224,7 @@ MC4CAQAwBQYDK2VwBCIEILwDB7W+KEw+UkzfOQA9ghy70Em4ubdS42DLkDmdmYyb
             ),
             CommunicationType::Agent => GRPCCommunicationsClient::new_agent_communication(
                 test_request_id.to_owned(),
+                HashMap::from([(TEST_LABEL_KEY.to_owned(), TEST_LABEL_VALUE.to_owned())]),
                 url,
                 tls_config,
             ),
@@ -408,6 +413,7 @@ MC4CAQAwBQYDK2VwBCIEILwDB7W+KEw+UkzfOQA9ghy70Em4ubdS42DLkDmdmYyb
                     ..Default::default()
                 },
                 vec![],
+                None,
             )
             .await;
         assert!(update_state_result.is_ok());
@@ -440,6 +446,7 @@ MC4CAQAwBQYDK2VwBCIEILwDB7W+KEw+UkzfOQA9ghy70Em4ubdS42DLkDmdmYyb
             result,
             Ok(Some(ToServer::AgentHello(commands::AgentHello {
                 agent_name: test_agent_name.to_owned(),
+                labels: HashMap::from([(TEST_LABEL_KEY.to_owned(), TEST_LABEL_VALUE.to_owned())]),
             })))
         );
     }
@@ -465,7 +472,8 @@ MC4CAQAwBQYDK2VwBCIEILwDB7W+KEw+UkzfOQA9ghy70Em4ubdS42DLkDmdmYyb
         assert_eq!(
             result,
             Ok(Some(ToServer::AgentHello(commands::AgentHello {
-                agent_name: test_agent_name.to_owned()
+                agent_name: test_agent_name.to_owned(),
+                labels: HashMap::from([(TEST_LABEL_KEY.to_owned(), TEST_LABEL_VALUE.to_owned())]),
             })))
         );
     }
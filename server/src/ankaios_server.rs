@@ -15,20 +15,26 @@
 mod config_renderer;
 mod cycle_check;
 mod delete_graph;
+mod event_subscription_store;
 mod log_campaign_store;
+mod revision_history;
+mod rolling_updates;
 mod server_state;
+mod workload_state_history;
 
 use api::ank_base;
-use common::commands::{Request, UpdateWorkload};
+use common::commands::{CompleteStateRequest, Request, UpdateWorkload};
 use common::from_server_interface::{FromServerReceiver, FromServerSender};
 use common::objects::{
     CompleteState, DeletedWorkload, ExecutionState, State, WorkloadInstanceName, WorkloadState,
     WorkloadStatesMap,
 };
 
+use common::state_manipulation::Object;
 use common::std_extensions::IllegalStateResult;
 use common::to_server_interface::{ToServerReceiver, ToServerSender};
 
+use server_state::AddedDeletedWorkloads;
 #[cfg_attr(test, mockall_double::double)]
 use server_state::ServerState;
 
@@ -39,6 +45,8 @@ use common::{
 
 use tokio::sync::mpsc::channel;
 
+const STARTUP_REQUESTER: &str = "startup";
+
 pub type ToServerChannel = (ToServerSender, ToServerReceiver);
 pub type FromServerChannel = (FromServerSender, FromServerReceiver);
 
@@ -52,10 +60,15 @@ pub fn create_from_server_channel(capacity: usize) -> FromServerChannel {
 #[cfg_attr(test, mockall_double::double)]
 use log_campaign_store::LogCampaignStore;
 
+use event_subscription_store::EventSubscriptionStore;
 use log_campaign_store::LogCollectorRequestId;
+use revision_history::RevisionHistory;
+use workload_state_history::WorkloadStateHistory;
 
 use std::collections::HashSet;
 
+use crate::state_store::{PersistedState, StateStore};
+
 pub struct AnkaiosServer {
     // [impl->swdd~server-uses-async-channels~1]
     receiver: ToServerReceiver,
@@ -64,6 +77,10 @@ pub struct AnkaiosServer {
     server_state: ServerState,
     workload_states_map: WorkloadStatesMap,
     log_campaign_store: LogCampaignStore,
+    event_subscription_store: EventSubscriptionStore,
+    state_store: Option<Box<dyn StateStore>>,
+    revision_history: RevisionHistory,
+    workload_state_history: WorkloadStateHistory,
 }
 
 impl AnkaiosServer {
@@ -74,38 +91,91 @@ impl AnkaiosServer {
             server_state: ServerState::default(),
             workload_states_map: WorkloadStatesMap::default(),
             log_campaign_store: LogCampaignStore::default(),
+            event_subscription_store: EventSubscriptionStore::default(),
+            state_store: None,
+            revision_history: RevisionHistory::default(),
+            workload_state_history: WorkloadStateHistory::default(),
         }
     }
 
+    pub fn with_state_store(mut self, state_store: Box<dyn StateStore>) -> Self {
+        self.state_store = Some(state_store);
+        self
+    }
+
     pub async fn start(&mut self, startup_state: Option<CompleteState>) -> Result<(), String> {
-        if let Some(state) = startup_state {
-            State::verify_api_version(&state.desired_state)?;
-
-            match self.server_state.update(state, vec![]) {
-                Ok(Some((added_workloads, deleted_workloads))) => {
-                    // [impl->swdd~server-sets-state-of-new-workloads-to-pending~1]
-                    self.workload_states_map.initial_state(&added_workloads);
-
-                    let from_server_command = FromServer::UpdateWorkload(UpdateWorkload {
-                        added_workloads,
-                        deleted_workloads,
-                    });
-                    log::info!("Starting...");
-                    self.to_agents
-                        .send(from_server_command)
-                        .await
-                        .unwrap_or_illegal_state();
+        // [impl->swdd~server-restores-persisted-desired-state-on-start~1]
+        let restored_update = self.load_persisted_state().and_then(|persisted_state| {
+            match self.apply_initial_state(CompleteState {
+                desired_state: persisted_state.desired_state.clone(),
+                ..Default::default()
+            }) {
+                Ok(update) => {
+                    // [impl->swdd~server-restores-current-revision-on-start~2]
+                    self.revision_history.restore(
+                        persisted_state.revision,
+                        STARTUP_REQUESTER,
+                        &persisted_state.desired_state,
+                    );
+                    Some(update)
                 }
-                Ok(None) => log::info!("No initial workloads to send to agents."),
                 Err(err) => {
-                    // [impl->swdd~server-fails-on-invalid-startup-state~1]
-                    return Err(err.to_string());
+                    log::error!(
+                        "Could not apply the persisted desired state: '{err}' -> falling back to the startup manifest"
+                    );
+                    // [impl->swdd~server-restores-current-revision-on-start~2]
+                    self.revision_history
+                        .continue_after(persisted_state.revision);
+                    None
                 }
             }
-        } else {
-            // [impl->swdd~server-starts-without-startup-config~1]
-            log::info!("No startup manifest provided -> waiting for new workloads from the CLI");
+        });
+
+        let initial_update = match (restored_update, startup_state) {
+            (Some(update), _) => Some(update),
+            // [impl->swdd~server-fails-on-invalid-startup-state~1]
+            (None, Some(state)) => Some(self.apply_initial_state(state)?),
+            (None, None) => None,
+        };
+
+        if initial_update.is_some()
+            && self.record_revision(STARTUP_REQUESTER, vec![])
+            && let Err(err) = self.persist_desired_state()
+        {
+            log::error!("{err}");
+        }
+
+        match initial_update {
+            Some(Some((added_workloads, deleted_workloads))) => {
+                // [impl->swdd~server-sets-state-of-new-workloads-to-pending~1]
+                self.workload_states_map.initial_state(&added_workloads);
+
+                // [impl->swdd~server-reports-reason-of-not-scheduled-workloads~1]
+                self.update_not_scheduled_reasons();
+
+                let from_server_command = FromServer::UpdateWorkload(UpdateWorkload {
+                    added_workloads,
+                    deleted_workloads,
+                });
+                log::info!("Starting...");
+                self.to_agents
+                    .send(from_server_command)
+                    .await
+                    .unwrap_or_illegal_state();
+            }
+            Some(None) => {
+                log::info!("No initial workloads to send to agents.");
+            }
+            None => {
+                // [impl->swdd~server-starts-without-startup-config~1]
+                log::info!(
+                    "No startup manifest provided -> waiting for new workloads from the CLI"
+                );
+            }
         }
+        // [impl->swdd~server-records-workload-state-transitions~1]
+        self.workload_state_history
+            .update(&self.workload_states_map);
         self.listen_to_agents().await;
         Ok(())
     }
@@ -119,6 +189,41 @@ impl AnkaiosServer {
 
                     let agent_name = method_obj.agent_name;
 
+                    // [impl->swdd~server-stores-newly-connected-agent~2]
+                    let placed_workloads = self
+                        .server_state
+                        .add_agent(agent_name.clone(), method_obj.labels);
+
+                    // [impl->swdd~server-places-selected-workloads-on-newly-connected-agent~1]
+                    let mut placed_update = None;
+                    if let Some((added_workloads, deleted_workloads)) = placed_workloads {
+                        log::info!(
+                            "Placing {} workloads after agent '{agent_name}' connected",
+                            added_workloads.len()
+                        );
+                        self.workload_states_map.initial_state(&added_workloads);
+                        let retained_deleted_workloads = self
+                            .handle_not_started_deleted_workloads(deleted_workloads)
+                            .await;
+
+                        // the workloads of the connected agent are sent with the ServerHello
+                        let added_workloads_of_other_agents: Vec<_> = added_workloads
+                            .into_iter()
+                            .filter(|workload| workload.instance_name.agent_name() != agent_name)
+                            .collect();
+                        if !added_workloads_of_other_agents.is_empty()
+                            || !retained_deleted_workloads.is_empty()
+                        {
+                            placed_update = Some(UpdateWorkload {
+                                added_workloads: added_workloads_of_other_agents,
+                                deleted_workloads: retained_deleted_workloads,
+                            });
+                        }
+                    }
+
+                    // [impl->swdd~server-reports-reason-of-not-scheduled-workloads~1]
+                    self.update_not_scheduled_reasons();
+
                     // [impl->swdd~server-informs-a-newly-connected-agent-workload-states~1]
                     let workload_states = self
                         .workload_states_map
@@ -147,12 +252,19 @@ impl AnkaiosServer {
 
                     // [impl->swdd~server-sends-all-workloads-on-start~2]
                     self.to_agents
-                        .server_hello(Some(agent_name.clone()), added_workloads)
+                        .server_hello(Some(agent_name), added_workloads)
                         .await
                         .unwrap_or_illegal_state();
 
-                    // [impl->swdd~server-stores-newly-connected-agent~1]
-                    self.server_state.add_agent(agent_name);
+                    // [impl->swdd~server-places-selected-workloads-on-newly-connected-agent~1]
+                    if let Some(placed_update) = placed_update {
+                        self.to_agents
+                            .send(FromServer::UpdateWorkload(placed_update))
+                            .await
+                            .unwrap_or_illegal_state();
+                    }
+
+                    self.send_events().await;
                 }
                 // [impl->swdd~server-receives-resource-availability~1]
                 ToServer::AgentLoadStatus(method_obj) => {
@@ -163,8 +275,38 @@ impl AnkaiosServer {
                         method_obj.free_memory.free_memory,
                     );
 
-                    self.server_state
+                    let placed_workloads = self
+                        .server_state
                         .update_agent_resource_availability(method_obj);
+
+                    // [impl->swdd~server-places-pending-workloads-on-resource-availability-update~1]
+                    if let Some((added_workloads, deleted_workloads)) = placed_workloads {
+                        log::info!(
+                            "Placing {} pending workloads after a resource availability update",
+                            added_workloads.len()
+                        );
+                        self.workload_states_map.initial_state(&added_workloads);
+                        let retained_deleted_workloads = self
+                            .handle_not_started_deleted_workloads(deleted_workloads)
+                            .await;
+
+                        self.to_agents
+                            .send(FromServer::UpdateWorkload(UpdateWorkload {
+                                added_workloads,
+                                deleted_workloads: retained_deleted_workloads,
+                            }))
+                            .await
+                            .unwrap_or_illegal_state();
+                    }
+
+                    // [impl->swdd~server-reports-reason-of-not-scheduled-workloads~1]
+                    self.update_not_scheduled_reasons();
+
+                    // the periodic load status of the agents drives the timeout of rolling updates
+                    // [impl->swdd~server-continues-rolling-updates~1]
+                    self.continue_rolling_updates(&[]).await;
+
+                    self.send_events().await;
                 }
                 ToServer::AgentGone(method_obj) => {
                     log::debug!("Received AgentGone from '{}'", method_obj.agent_name);
@@ -178,14 +320,18 @@ impl AnkaiosServer {
 
                     // communicate the workload execution states to other agents
                     // [impl->swdd~server-distribute-workload-state-on-disconnect~1]
+                    let disconnected_workload_states = self
+                        .workload_states_map
+                        .get_workload_state_for_agent(&agent_name);
                     self.to_agents
-                        .update_workload_state(
-                            self.workload_states_map
-                                .get_workload_state_for_agent(&agent_name),
-                        )
+                        .update_workload_state(disconnected_workload_states.clone())
                         .await
                         .unwrap_or_illegal_state();
 
+                    // [impl->swdd~server-continues-rolling-updates~1]
+                    self.continue_rolling_updates(&disconnected_workload_states)
+                        .await;
+
                     // [impl->swdd~server-handles-log-campaign-for-disconnected-agent~1]
                     let removed_log_requests = self
                         .log_campaign_store
@@ -201,6 +347,12 @@ impl AnkaiosServer {
                         removed_log_requests.disconnected_log_providers,
                     )
                     .await;
+
+                    // [impl->swdd~server-removes-event-subscriptions-of-disconnected-connection~1]
+                    self.event_subscription_store
+                        .remove_subscriptions_of(&agent_name);
+
+                    self.send_events().await;
                 }
                 // [impl->swdd~server-provides-update-desired-state-interface~1]
                 ToServer::Request(Request {
@@ -220,12 +372,17 @@ impl AnkaiosServer {
                         match self.server_state.get_complete_state_by_field_mask(
                             complete_state_request,
                             &self.workload_states_map,
+                            &self.workload_state_history,
                         ) {
-                            Ok(complete_state) => self
-                                .to_agents
-                                .complete_state(request_id, complete_state)
-                                .await
-                                .unwrap_or_illegal_state(),
+                            Ok(mut complete_state) => {
+                                // [impl->swdd~server-provides-current-revision~1]
+                                complete_state.revision =
+                                    Some(self.revision_history.current_revision());
+                                self.to_agents
+                                    .complete_state(request_id, complete_state)
+                                    .await
+                                    .unwrap_or_illegal_state()
+                            }
                             Err(error) => {
                                 log::error!("Failed to get complete state: '{error}'");
                                 self.to_agents
@@ -267,8 +424,41 @@ impl AnkaiosServer {
                             continue;
                         }
 
+                        // [impl->swdd~server-rejects-update-state-with-unexpected-revision~1]
+                        if let Some(expected_revision) = update_state_request.expected_revision {
+                            let current_revision = self.revision_history.current_revision();
+                            if expected_revision != current_revision {
+                                log::warn!(
+                                    "The update expects revision '{expected_revision}' of the desired state, but the current revision is '{current_revision}' -> rejecting the request"
+                                );
+
+                                self.to_agents
+                                    .conflict_error(
+                                        request_id,
+                                        format!(
+                                            "Update rejected: the desired state was changed in the meantime (expected revision '{expected_revision}', current revision '{current_revision}')"
+                                        ),
+                                    )
+                                    .await
+                                    .unwrap_or_illegal_state();
+                                continue;
+                            }
+                        }
+
+                        // [impl->swdd~server-handles-dry-run-update-state-request~1]
+                        if update_state_request.dry_run {
+                            self.handle_dry_run_update_state(
+                                request_id,
+                                update_state_request.state,
+                                update_state_request.update_mask,
+                            )
+                            .await;
+                            continue;
+                        }
+
                         // [impl->swdd~update-desired-state-with-update-mask~1]
                         // [impl->swdd~update-desired-state-empty-update-mask~1]
+                        let update_mask = update_state_request.update_mask.clone();
                         match self
                             .server_state
                             .update(update_state_request.state, update_state_request.update_mask)
@@ -280,9 +470,19 @@ impl AnkaiosServer {
                                     deleted_workloads.len()
                                 );
 
+                                let persist_result =
+                                    if self.record_revision(&request_id, update_mask) {
+                                        self.persist_desired_state()
+                                    } else {
+                                        Ok(())
+                                    };
+
                                 // [impl->swdd~server-sets-state-of-new-workloads-to-pending~1]
                                 self.workload_states_map.initial_state(&added_workloads);
 
+                                // [impl->swdd~server-reports-reason-of-not-scheduled-workloads~1]
+                                self.update_not_scheduled_reasons();
+
                                 let added_workloads_names = added_workloads
                                     .iter()
                                     .map(|x| x.instance_name.to_string())
@@ -296,6 +496,11 @@ impl AnkaiosServer {
                                 self.cancel_log_requests_of_deleted_workloads(&deleted_workloads)
                                     .await;
 
+                                // [impl->swdd~server-removes-event-subscriptions-of-deleted-workloads~1]
+                                self.remove_event_subscriptions_of_deleted_workloads(
+                                    &deleted_workloads,
+                                );
+
                                 // [impl->swdd~server-handles-not-started-deleted-workloads~1]
                                 let retained_deleted_workloads = self
                                     .handle_not_started_deleted_workloads(deleted_workloads)
@@ -310,25 +515,33 @@ impl AnkaiosServer {
                                     .send(from_server_command)
                                     .await
                                     .unwrap_or_illegal_state();
-                                log::debug!("Send UpdateStateSuccess for request '{request_id}'");
-                                // [impl->swdd~server-update-state-success-response~1]
-                                self.to_agents
-                                    .update_state_success(
-                                        request_id,
-                                        added_workloads_names,
-                                        deleted_workloads_names,
-                                    )
-                                    .await
-                                    .unwrap_or_illegal_state();
+                                self.respond_to_accepted_update(
+                                    request_id,
+                                    persist_result,
+                                    added_workloads_names,
+                                    deleted_workloads_names,
+                                )
+                                .await;
+
+                                self.send_events().await;
                             }
                             Ok(None) => {
                                 log::debug!(
                                     "The current state and new state are identical -> nothing to do"
                                 );
-                                self.to_agents
-                                    .update_state_success(request_id, vec![], vec![])
-                                    .await
-                                    .unwrap_or_illegal_state();
+                                let persist_result =
+                                    if self.record_revision(&request_id, update_mask) {
+                                        self.persist_desired_state()
+                                    } else {
+                                        Ok(())
+                                    };
+                                self.respond_to_accepted_update(
+                                    request_id,
+                                    persist_result,
+                                    vec![],
+                                    vec![],
+                                )
+                                .await;
                             }
                             Err(error_msg) => {
                                 // [impl->swdd~server-continues-on-invalid-updated-state~1]
@@ -340,6 +553,35 @@ impl AnkaiosServer {
                             }
                         }
                     }
+                    // [impl->swdd~server-provides-revisions~1]
+                    common::commands::RequestContent::RevisionsRequest(revisions_request) => {
+                        log::debug!(
+                            "Received RevisionsRequest with ID '{request_id}' for revision '{:?}'",
+                            revisions_request.revision
+                        );
+
+                        let revisions = match revisions_request.revision {
+                            Some(number) => match self.revision_history.get_revision(number) {
+                                Some(revision) => vec![revision],
+                                None => {
+                                    self.to_agents
+                                        .error(
+                                            request_id,
+                                            format!("Revision '{number}' is not available"),
+                                        )
+                                        .await
+                                        .unwrap_or_illegal_state();
+                                    continue;
+                                }
+                            },
+                            None => self.revision_history.get_revisions(),
+                        };
+
+                        self.to_agents
+                            .revisions(request_id, revisions)
+                            .await
+                            .unwrap_or_illegal_state();
+                    }
                     // [impl->swdd~server-handles-logs-request-message~1]
                     common::commands::RequestContent::LogsRequest(mut logs_request) => {
                         log::debug!(
@@ -387,6 +629,39 @@ impl AnkaiosServer {
                             .await
                             .unwrap_or_illegal_state();
                     }
+                    // [impl->swdd~server-handles-events-subscribe-request~1]
+                    common::commands::RequestContent::EventsSubscribeRequest(
+                        events_subscribe_request,
+                    ) => {
+                        log::debug!(
+                            "Got events subscribe request with ID '{}' and field masks: '{:?}'",
+                            request_id,
+                            events_subscribe_request.field_masks
+                        );
+
+                        self.event_subscription_store
+                            .subscribe(&request_id, events_subscribe_request.field_masks);
+
+                        if let Some(field_masks) =
+                            self.event_subscription_store.field_masks(&request_id)
+                        {
+                            self.send_events_with_field_masks(field_masks, vec![request_id])
+                                .await;
+                        }
+                    }
+                    // [impl->swdd~server-handles-events-cancel-request~1]
+                    common::commands::RequestContent::EventsCancelRequest => {
+                        log::debug!("Got events cancel request with ID: {request_id}");
+
+                        if !self.event_subscription_store.unsubscribe(&request_id) {
+                            log::debug!("No event subscription with ID '{request_id}' to cancel.");
+                        }
+
+                        self.to_agents
+                            .events_cancel_request_accepted(request_id)
+                            .await
+                            .unwrap_or_illegal_state();
+                    }
                 },
                 ToServer::UpdateWorkloadState(method_obj) => {
                     log::debug!(
@@ -403,9 +678,15 @@ impl AnkaiosServer {
 
                     // [impl->swdd~server-forwards-workload-state~1]
                     self.to_agents
-                        .update_workload_state(method_obj.workload_states)
+                        .update_workload_state(method_obj.workload_states.clone())
                         .await
                         .unwrap_or_illegal_state();
+
+                    // [impl->swdd~server-continues-rolling-updates~1]
+                    self.continue_rolling_updates(&method_obj.workload_states)
+                        .await;
+
+                    self.send_events().await;
                 }
                 // [impl->swdd~server-forwards-logs-entries-response-messages~1]
                 ToServer::LogEntriesResponse(request_id, logs_response) => {
@@ -435,6 +716,10 @@ impl AnkaiosServer {
                         removed_cli_log_requests,
                     )
                     .await;
+
+                    // [impl->swdd~server-removes-event-subscriptions-of-disconnected-connection~1]
+                    self.event_subscription_store
+                        .remove_subscriptions_of(&goodbye.connection_name);
                 }
                 ToServer::Stop(_method_obj) => {
                     log::debug!("Received Stop from communications server");
@@ -442,7 +727,204 @@ impl AnkaiosServer {
                     break;
                 }
             }
+
+            // [impl->swdd~server-records-workload-state-transitions~1]
+            self.workload_state_history
+                .update(&self.workload_states_map);
+        }
+    }
+
+    fn apply_initial_state(
+        &mut self,
+        state: CompleteState,
+    ) -> Result<AddedDeletedWorkloads, String> {
+        State::verify_api_version(&state.desired_state)?;
+        self.server_state
+            .update(state, vec![])
+            .map_err(|err| err.to_string())
+    }
+
+    // [impl->swdd~server-restores-persisted-desired-state-on-start~1]
+    fn load_persisted_state(&self) -> Option<PersistedState> {
+        let state_store = self.state_store.as_ref()?;
+        match state_store.load() {
+            Ok(Some(persisted_state)) => {
+                log::info!(
+                    "Restoring revision '{}' of the desired state persisted before the last shutdown.",
+                    persisted_state.revision
+                );
+                Some(persisted_state)
+            }
+            Ok(None) => {
+                log::debug!("No persisted desired state found.");
+                None
+            }
+            Err(err) => {
+                log::error!("Could not restore the persisted desired state: {err}");
+                None
+            }
+        }
+    }
+
+    // [impl->swdd~server-state-store-persists-desired-state~1]
+    // [impl->swdd~server-state-store-persists-current-revision~1]
+    fn persist_desired_state(&mut self) -> Result<(), String> {
+        let Some(state_store) = self.state_store.as_mut() else {
+            return Ok(());
+        };
+        let persisted_state = PersistedState {
+            revision: self.revision_history.current_revision(),
+            desired_state: self.server_state.get_desired_state().clone(),
+        };
+        state_store
+            .store(&persisted_state)
+            .map_err(|err| format!("Could not persist the desired state: {err}"))
+    }
+
+    // [impl->swdd~server-update-state-success-response~1]
+    // [impl->swdd~server-reports-failed-persistence-of-desired-state~1]
+    async fn respond_to_accepted_update(
+        &mut self,
+        request_id: String,
+        persist_result: Result<(), String>,
+        added_workloads_names: Vec<String>,
+        deleted_workloads_names: Vec<String>,
+    ) {
+        match persist_result {
+            Ok(()) => {
+                log::debug!("Send UpdateStateSuccess for request '{request_id}'");
+                self.to_agents
+                    .update_state_success(
+                        request_id,
+                        added_workloads_names,
+                        deleted_workloads_names,
+                    )
+                    .await
+                    .unwrap_or_illegal_state();
+            }
+            Err(err) => {
+                log::error!("{err}");
+                self.to_agents
+                    .error(
+                        request_id,
+                        format!(
+                            "Update applied, but not persisted: '{err}'. The update is lost on a restart of the server."
+                        ),
+                    )
+                    .await
+                    .unwrap_or_illegal_state();
+            }
+        }
+    }
+
+    // [impl->swdd~server-handles-dry-run-update-state-request~1]
+    async fn handle_dry_run_update_state(
+        &mut self,
+        request_id: String,
+        new_state: CompleteState,
+        update_mask: Vec<String>,
+    ) {
+        match self.server_state.dry_run_update(new_state, update_mask) {
+            Ok(added_and_deleted_workloads) => {
+                let (added_workloads_names, deleted_workloads_names) = added_and_deleted_workloads
+                    .map(|(added_workloads, deleted_workloads)| {
+                        (
+                            added_workloads
+                                .iter()
+                                .map(|x| x.instance_name.to_string())
+                                .collect(),
+                            deleted_workloads
+                                .iter()
+                                .map(|x| x.instance_name.to_string())
+                                .collect(),
+                        )
+                    })
+                    .unwrap_or_default();
+                log::debug!("Send UpdateStateSuccess for dry run request '{request_id}'");
+                self.to_agents
+                    .update_state_success(
+                        request_id,
+                        added_workloads_names,
+                        deleted_workloads_names,
+                    )
+                    .await
+                    .unwrap_or_illegal_state();
+            }
+            Err(error_msg) => {
+                log::info!("Dry run update rejected: '{error_msg}'");
+                self.to_agents
+                    .error(request_id, format!("Update rejected: '{error_msg}'"))
+                    .await
+                    .unwrap_or_illegal_state();
+            }
+        }
+    }
+
+    // [impl->swdd~server-continues-rolling-updates~1]
+    async fn continue_rolling_updates(&mut self, workload_states: &[WorkloadState]) {
+        let (added_deleted_workloads, rolled_back_workloads) =
+            self.server_state.continue_rolling_updates(workload_states);
+
+        // [impl->swdd~server-records-rolled-back-rolling-updates~1]
+        for workload_name in rolled_back_workloads {
+            self.revision_history.mark_rolled_back(&workload_name);
         }
+
+        let Some((added_workloads, deleted_workloads)) = added_deleted_workloads else {
+            return;
+        };
+
+        log::info!(
+            "Continuing rolling updates with {} new and {} deleted replicas",
+            added_workloads.len(),
+            deleted_workloads.len()
+        );
+        self.workload_states_map.initial_state(&added_workloads);
+        self.cancel_log_requests_of_deleted_workloads(&deleted_workloads)
+            .await;
+        self.remove_event_subscriptions_of_deleted_workloads(&deleted_workloads);
+        let retained_deleted_workloads = self
+            .handle_not_started_deleted_workloads(deleted_workloads)
+            .await;
+        self.to_agents
+            .send(FromServer::UpdateWorkload(UpdateWorkload {
+                added_workloads,
+                deleted_workloads: retained_deleted_workloads,
+            }))
+            .await
+            .unwrap_or_illegal_state();
+    }
+
+    // Returns whether a new revision has been recorded, i.e., the desired state has changed.
+    // [impl->swdd~server-records-revision-on-desired-state-change~2]
+    fn record_revision(&mut self, request_id: &str, update_mask: Vec<String>) -> bool {
+        self.revision_history.record(
+            request_id,
+            update_mask,
+            self.server_state.get_desired_state(),
+        )
+    }
+
+    // [impl->swdd~server-reports-reason-of-not-scheduled-workloads~1]
+    fn update_not_scheduled_reasons(&mut self) {
+        // workloads without an agent are stored with an empty agent name
+        let not_scheduled_states: Vec<WorkloadState> = self
+            .workload_states_map
+            .get_workload_state_for_agent("")
+            .into_iter()
+            .filter_map(|workload_state| {
+                let reason = self
+                    .server_state
+                    .get_not_scheduled_reason(workload_state.instance_name.workload_name())?;
+                Some(WorkloadState {
+                    instance_name: workload_state.instance_name,
+                    execution_state: ExecutionState::not_scheduled_with_reason(reason),
+                })
+            })
+            .collect();
+
+        self.workload_states_map
+            .process_new_states(not_scheduled_states);
     }
 
     // [impl->swdd~server-handles-not-started-deleted-workloads~1]
@@ -529,6 +1011,88 @@ impl AnkaiosServer {
         }
     }
 
+    // [impl->swdd~server-removes-event-subscriptions-of-deleted-workloads~1]
+    fn remove_event_subscriptions_of_deleted_workloads(
+        &mut self,
+        deleted_workloads: &[DeletedWorkload],
+    ) {
+        for deleted_workload in deleted_workloads {
+            let request_ids = self
+                .event_subscription_store
+                .remove_subscriptions_of_workload(&deleted_workload.instance_name);
+            if !request_ids.is_empty() {
+                log::debug!(
+                    "Removed event subscriptions of deleted workload '{}': '{request_ids:?}'",
+                    deleted_workload.instance_name
+                );
+            }
+        }
+    }
+
+    // [impl->swdd~server-sends-events-on-state-change~1]
+    async fn send_events(&mut self) {
+        // [impl->swdd~server-records-workload-state-transitions~1]
+        self.workload_state_history
+            .update(&self.workload_states_map);
+
+        for (field_masks, request_ids) in self.event_subscription_store.request_ids_by_field_masks()
+        {
+            self.send_events_with_field_masks(field_masks, request_ids)
+                .await;
+        }
+    }
+
+    // The complete state is filtered and converted only once for all subscriptions with the same field masks.
+    // [impl->swdd~server-sends-events-on-state-change~1]
+    async fn send_events_with_field_masks(
+        &mut self,
+        field_masks: Vec<String>,
+        request_ids: Vec<String>,
+    ) {
+        let complete_state = match self.server_state.get_complete_state_by_field_mask(
+            CompleteStateRequest {
+                field_mask: field_masks,
+            },
+            &self.workload_states_map,
+            &self.workload_state_history,
+        ) {
+            Ok(complete_state) => complete_state,
+            Err(error) => {
+                log::error!(
+                    "Failed to get complete state for event subscriptions '{request_ids:?}': '{error}'"
+                );
+                return;
+            }
+        };
+        let state_object: Object = complete_state.clone().try_into().unwrap_or_illegal_state();
+
+        for request_id in request_ids {
+            let changed_field_masks = self
+                .event_subscription_store
+                .changed_field_masks(&request_id, &state_object);
+            if changed_field_masks.is_empty() {
+                continue;
+            }
+
+            log::debug!(
+                "Sending event for subscription '{request_id}' with changed field masks: '{changed_field_masks:?}'"
+            );
+            self.to_agents
+                .event_response(
+                    request_id,
+                    ank_base::EventResponse {
+                        complete_state: Some(ank_base::CompleteState {
+                            revision: Some(self.revision_history.current_revision()),
+                            ..complete_state.clone()
+                        }),
+                        changed_field_masks,
+                    },
+                )
+                .await
+                .unwrap_or_illegal_state();
+        }
+    }
+
     // [impl->swdd~server-handles-log-campaign-for-disconnected-agent~1]
     async fn send_log_stop_response_for_disconnected_agent(
         &mut self,
@@ -566,12 +1130,13 @@ mod tests {
     use crate::ankaios_server::log_campaign_store::RemovedLogRequests;
     use crate::ankaios_server::server_state::{MockServerState, UpdateStateError};
     use crate::ankaios_server::{create_from_server_channel, create_to_server_channel};
+    use crate::state_store::{MockStateStore, PersistedState, StateStoreError};
 
     use super::ank_base;
     use api::ank_base::{LogsStopResponse, WorkloadMap};
     use common::commands::{
-        AgentLoadStatus, CompleteStateRequest, LogsRequest, ServerHello, UpdateWorkload,
-        UpdateWorkloadState,
+        AgentLoadStatus, CompleteStateRequest, EventsSubscribeRequest, LogsRequest,
+        RevisionsRequest, ServerHello, UpdateWorkload, UpdateWorkloadState,
     };
     use common::from_server_interface::FromServer;
     use common::objects::{
@@ -593,6 +1158,7 @@ mod tests {
     const WORKLOAD_NAME_3: &str = "workload_3";
     const RUNTIME_NAME: &str = "runtime";
     const REQUEST_ID: &str = "request_1";
+    const PERSISTED_REVISION: u64 = 3;
     const REQUEST_ID_A: &str = "agent_A@workload_1@request_1";
     const REQUEST_ID_A2: &str = "agent_A@workload_2@request_2";
     const INSTANCE_ID: &str = "instance_id";
@@ -732,6 +1298,9 @@ mod tests {
                 deleted_workloads.clone(),
             ))));
 
+        mock_server_state
+            .expect_get_desired_state()
+            .return_const(State::default());
         server.server_state = mock_server_state;
 
         let server_task = tokio::spawn(async move { server.start(None).await });
@@ -742,7 +1311,8 @@ mod tests {
                 .update_state(
                     REQUEST_ID_A.to_string(),
                     new_state.clone(),
-                    update_mask.clone()
+                    update_mask.clone(),
+                    None
                 )
                 .await
                 .is_ok()
@@ -759,7 +1329,12 @@ mod tests {
         // send the update with the new clean state again
         assert!(
             to_server
-                .update_state(REQUEST_ID_A.to_string(), fixed_state.clone(), update_mask)
+                .update_state(
+                    REQUEST_ID_A.to_string(),
+                    fixed_state.clone(),
+                    update_mask,
+                    None
+                )
                 .await
                 .is_ok()
         );
@@ -834,6 +1409,9 @@ mod tests {
                 deleted_workloads.clone(),
             ))));
 
+        mock_server_state
+            .expect_get_desired_state()
+            .return_const(State::default());
         server.server_state = mock_server_state;
 
         let server_handle = server.start(Some(startup_state));
@@ -866,71 +1444,478 @@ mod tests {
         assert!(comm_middle_ware_receiver.try_recv().is_err());
     }
 
-    // [utest->swdd~server-uses-async-channels~1]
-    // [utest->swdd~server-sends-all-workloads-on-start~2]
-    // [utest->swdd~agent-from-agent-field~1]
-    // [utest->swdd~server-starts-without-startup-config~1]
-    // [utest->swdd~server-stores-newly-connected-agent~1]
+    // [utest->swdd~server-restores-persisted-desired-state-on-start~1]
+    // [utest->swdd~server-restores-current-revision-on-start~2]
+    // [utest->swdd~server-state-store-persists-desired-state~1]
     #[tokio::test]
-    async fn utest_server_sends_workloads_and_workload_states() {
+    async fn utest_server_start_restores_persisted_state_instead_of_startup_manifest() {
         let _ = env_logger::builder().is_test(true).try_init();
         let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
         let (to_agents, mut comm_middle_ware_receiver) =
             create_from_server_channel(common::CHANNEL_CAPACITY);
 
-        let mut server = AnkaiosServer::new(server_receiver, to_agents);
-
-        let w1 = generate_test_workload_spec_with_param(
-            AGENT_A.to_owned(),
-            WORKLOAD_NAME_1.to_owned(),
+        let startup_workload = generate_test_workload_spec_with_param(
+            AGENT_A.to_string(),
+            WORKLOAD_NAME_1.to_string(),
             RUNTIME_NAME.to_string(),
         );
-
-        let w2 = generate_test_workload_spec_with_param(
-            AGENT_B.to_owned(),
-            WORKLOAD_NAME_2.to_owned(),
+        let persisted_workload = generate_test_workload_spec_with_param(
+            AGENT_A.to_string(),
+            WORKLOAD_NAME_2.to_string(),
             RUNTIME_NAME.to_string(),
         );
 
-        let mut mock_server_state = MockServerState::new();
-
-        mock_server_state.expect_cleanup_state().return_const(());
+        let startup_state = CompleteState {
+            desired_state: State {
+                workloads: HashMap::from([(WORKLOAD_NAME_1.to_owned(), startup_workload.into())]),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let persisted_desired_state = State {
+            workloads: HashMap::from([(
+                WORKLOAD_NAME_2.to_owned(),
+                persisted_workload.clone().into(),
+            )]),
+            ..Default::default()
+        };
 
-        let mut seq = mockall::Sequence::new();
-        mock_server_state
-            .expect_get_workloads_for_agent()
-            .with(mockall::predicate::eq(AGENT_A.to_string()))
+        let mut mock_state_store = MockStateStore::new();
+        mock_state_store
+            .expect_load()
             .once()
-            .in_sequence(&mut seq)
-            .return_const(vec![w1.clone()]);
+            .return_const(Ok(Some(PersistedState {
+                revision: PERSISTED_REVISION,
+                desired_state: persisted_desired_state.clone(),
+            })));
+        mock_state_store.expect_store().never();
 
+        let mut mock_server_state = MockServerState::new();
         mock_server_state
-            .expect_add_agent()
-            .with(predicate::eq(AGENT_A.to_owned()))
+            .expect_update()
+            .with(
+                predicate::eq(CompleteState {
+                    desired_state: persisted_desired_state.clone(),
+                    ..Default::default()
+                }),
+                predicate::eq(vec![]),
+            )
             .once()
-            .in_sequence(&mut seq)
-            .return_const(());
-
+            .return_const(Ok(Some((vec![persisted_workload.clone()], vec![]))));
+        mock_server_state
+            .expect_get_desired_state()
+            .return_const(persisted_desired_state);
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents)
+            .with_state_store(Box::new(mock_state_store));
+        server.server_state = mock_server_state;
+
+        drop(to_server);
+        assert!(server.start(Some(startup_state)).await.is_ok());
+
+        assert_eq!(
+            comm_middle_ware_receiver.recv().await.unwrap(),
+            FromServer::UpdateWorkload(UpdateWorkload {
+                added_workloads: vec![persisted_workload],
+                deleted_workloads: vec![],
+            })
+        );
+        assert!(comm_middle_ware_receiver.try_recv().is_err());
+        assert_eq!(
+            server.revision_history.current_revision(),
+            PERSISTED_REVISION
+        );
+    }
+
+    // [utest->swdd~server-restores-persisted-desired-state-on-start~1]
+    #[tokio::test]
+    async fn utest_server_start_uses_startup_manifest_on_corrupted_persisted_state() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, _comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let startup_state = CompleteState {
+            desired_state: State {
+                workloads: HashMap::from([(
+                    WORKLOAD_NAME_1.to_owned(),
+                    generate_test_stored_workload_spec(AGENT_A, RUNTIME_NAME),
+                )]),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let mut mock_state_store = MockStateStore::new();
+        mock_state_store
+            .expect_load()
+            .once()
+            .return_const(Err(StateStoreError::Corrupted("checksum mismatch".into())));
+        mock_state_store
+            .expect_store()
+            .with(predicate::eq(PersistedState {
+                revision: 1,
+                desired_state: startup_state.desired_state.clone(),
+            }))
+            .once()
+            .return_const(Ok(()));
+
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state
+            .expect_update()
+            .with(predicate::eq(startup_state.clone()), predicate::eq(vec![]))
+            .once()
+            .return_const(Ok(None));
+        mock_server_state
+            .expect_get_desired_state()
+            .return_const(startup_state.desired_state.clone());
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents)
+            .with_state_store(Box::new(mock_state_store));
+        server.server_state = mock_server_state;
+
+        drop(to_server);
+        assert!(server.start(Some(startup_state)).await.is_ok());
+    }
+
+    // [utest->swdd~server-restores-persisted-desired-state-on-start~1]
+    // [utest->swdd~server-restores-current-revision-on-start~2]
+    #[tokio::test]
+    async fn utest_server_start_uses_startup_manifest_on_rejected_persisted_state() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, _comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let startup_state = CompleteState {
+            desired_state: State {
+                workloads: HashMap::from([(
+                    WORKLOAD_NAME_1.to_owned(),
+                    generate_test_stored_workload_spec(AGENT_A, RUNTIME_NAME),
+                )]),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let persisted_desired_state = State {
+            api_version: "v0.0".to_owned(),
+            ..Default::default()
+        };
+
+        let mut mock_state_store = MockStateStore::new();
+        mock_state_store
+            .expect_load()
+            .once()
+            .return_const(Ok(Some(PersistedState {
+                revision: PERSISTED_REVISION,
+                desired_state: persisted_desired_state,
+            })));
+        mock_state_store
+            .expect_store()
+            .with(predicate::eq(PersistedState {
+                revision: PERSISTED_REVISION + 1,
+                desired_state: startup_state.desired_state.clone(),
+            }))
+            .once()
+            .return_const(Ok(()));
+
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state
+            .expect_update()
+            .with(predicate::eq(startup_state.clone()), predicate::eq(vec![]))
+            .once()
+            .return_const(Ok(None));
+        mock_server_state
+            .expect_get_desired_state()
+            .return_const(startup_state.desired_state.clone());
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents)
+            .with_state_store(Box::new(mock_state_store));
+        server.server_state = mock_server_state;
+
+        drop(to_server);
+        assert!(server.start(Some(startup_state)).await.is_ok());
+
+        assert_eq!(
+            server.revision_history.current_revision(),
+            PERSISTED_REVISION + 1
+        );
+        assert!(
+            server
+                .revision_history
+                .get_revision(PERSISTED_REVISION)
+                .is_none()
+        );
+    }
+
+    // [utest->swdd~server-state-store-persists-desired-state~1]
+    #[tokio::test]
+    async fn utest_server_does_not_persist_unchanged_desired_state() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let update_state = CompleteState {
+            desired_state: State {
+                workloads: HashMap::from([(
+                    WORKLOAD_NAME_1.to_owned(),
+                    generate_test_stored_workload_spec(AGENT_A, RUNTIME_NAME),
+                )]),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let update_mask = vec![format!("desiredState.workloads.{}", WORKLOAD_NAME_1)];
+
+        let mut mock_state_store = MockStateStore::new();
+        mock_state_store.expect_load().once().return_const(Ok(None));
+        mock_state_store.expect_store().never();
+
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state
+            .expect_update()
+            .once()
+            .return_const(Ok(None));
+        mock_server_state
+            .expect_get_desired_state()
+            .return_const(update_state.desired_state.clone());
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents)
+            .with_state_store(Box::new(mock_state_store));
+        server.server_state = mock_server_state;
+        server.revision_history.record(
+            super::STARTUP_REQUESTER,
+            vec![],
+            &update_state.desired_state,
+        );
+        let server_task = tokio::spawn(async move { server.start(None).await });
+
+        assert!(
+            to_server
+                .update_state(REQUEST_ID_A.to_string(), update_state, update_mask, None)
+                .await
+                .is_ok()
+        );
+
+        assert!(matches!(
+            comm_middle_ware_receiver.recv().await.unwrap(),
+            FromServer::Response(ank_base::Response {
+                response_content: Some(ank_base::response::ResponseContent::UpdateStateSuccess(_)),
+                ..
+            })
+        ));
+
+        drop(to_server);
+        assert!(server_task.await.unwrap().is_ok());
+    }
+
+    // [utest->swdd~server-state-store-persists-desired-state~1]
+    #[tokio::test]
+    async fn utest_server_persists_desired_state_on_accepted_update() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let update_state = CompleteState {
+            desired_state: State {
+                workloads: HashMap::from([(
+                    WORKLOAD_NAME_1.to_owned(),
+                    generate_test_stored_workload_spec(AGENT_A, RUNTIME_NAME),
+                )]),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let update_mask = vec![format!("desiredState.workloads.{}", WORKLOAD_NAME_1)];
+
+        let mut mock_state_store = MockStateStore::new();
+        mock_state_store.expect_load().once().return_const(Ok(None));
+        mock_state_store
+            .expect_store()
+            .with(predicate::eq(PersistedState {
+                revision: 1,
+                desired_state: update_state.desired_state.clone(),
+            }))
+            .once()
+            .return_const(Ok(()));
+
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state
+            .expect_update()
+            .with(
+                predicate::eq(update_state.clone()),
+                predicate::eq(update_mask.clone()),
+            )
+            .once()
+            .return_const(Ok(None));
+        mock_server_state
+            .expect_get_desired_state()
+            .return_const(update_state.desired_state.clone());
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents)
+            .with_state_store(Box::new(mock_state_store));
+        server.server_state = mock_server_state;
+        let server_task = tokio::spawn(async move { server.start(None).await });
+
+        assert!(
+            to_server
+                .update_state(REQUEST_ID_A.to_string(), update_state, update_mask, None)
+                .await
+                .is_ok()
+        );
+
+        assert!(matches!(
+            comm_middle_ware_receiver.recv().await.unwrap(),
+            FromServer::Response(ank_base::Response {
+                response_content: Some(ank_base::response::ResponseContent::UpdateStateSuccess(_)),
+                ..
+            })
+        ));
+
+        drop(to_server);
+        assert!(server_task.await.unwrap().is_ok());
+    }
+
+    // [utest->swdd~server-reports-failed-persistence-of-desired-state~1]
+    #[tokio::test]
+    async fn utest_server_reports_failed_persistence_of_desired_state() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let update_state = CompleteState {
+            desired_state: State {
+                workloads: HashMap::from([(
+                    WORKLOAD_NAME_1.to_owned(),
+                    generate_test_stored_workload_spec(AGENT_A, RUNTIME_NAME),
+                )]),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let update_mask = vec![format!("desiredState.workloads.{}", WORKLOAD_NAME_1)];
+
+        let mut mock_state_store = MockStateStore::new();
+        mock_state_store.expect_load().once().return_const(Ok(None));
+        mock_state_store
+            .expect_store()
+            .once()
+            .return_const(Err(StateStoreError::Io("disk full".into())));
+
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state
+            .expect_update()
+            .once()
+            .return_const(Ok(None));
+        mock_server_state
+            .expect_get_desired_state()
+            .return_const(update_state.desired_state.clone());
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents)
+            .with_state_store(Box::new(mock_state_store));
+        server.server_state = mock_server_state;
+        let server_task = tokio::spawn(async move { server.start(None).await });
+
+        assert!(
+            to_server
+                .update_state(REQUEST_ID_A.to_string(), update_state, update_mask, None)
+                .await
+                .is_ok()
+        );
+
+        let FromServer::Response(ank_base::Response {
+            request_id,
+            response_content: Some(ank_base::response::ResponseContent::Error(error)),
+        }) = comm_middle_ware_receiver.recv().await.unwrap()
+        else {
+            panic!("Expected an error response");
+        };
+        assert_eq!(request_id, REQUEST_ID_A);
+        assert!(error.message.contains("disk full"));
+
+        drop(to_server);
+        assert!(server_task.await.unwrap().is_ok());
+    }
+
+    // [utest->swdd~server-uses-async-channels~1]
+    // [utest->swdd~server-sends-all-workloads-on-start~2]
+    // [utest->swdd~agent-from-agent-field~1]
+    // [utest->swdd~server-starts-without-startup-config~1]
+    // [utest->swdd~server-stores-newly-connected-agent~2]
+    #[tokio::test]
+    async fn utest_server_sends_workloads_and_workload_states() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+
+        let w1 = generate_test_workload_spec_with_param(
+            AGENT_A.to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            RUNTIME_NAME.to_string(),
+        );
+
+        let w2 = generate_test_workload_spec_with_param(
+            AGENT_B.to_owned(),
+            WORKLOAD_NAME_2.to_owned(),
+            RUNTIME_NAME.to_string(),
+        );
+
+        let mut mock_server_state = MockServerState::new();
+
+        mock_server_state.expect_cleanup_state().return_const(());
+        mock_server_state
+            .expect_continue_rolling_updates()
+            .return_const((None, vec![]));
+
+        let mut seq = mockall::Sequence::new();
+        mock_server_state
+            .expect_add_agent()
+            .with(
+                predicate::eq(AGENT_A.to_owned()),
+                predicate::eq(HashMap::new()),
+            )
+            .once()
+            .in_sequence(&mut seq)
+            .return_const(None);
+
         mock_server_state
             .expect_get_workloads_for_agent()
-            .with(mockall::predicate::eq(AGENT_B.to_string()))
+            .with(mockall::predicate::eq(AGENT_A.to_string()))
             .once()
             .in_sequence(&mut seq)
-            .return_const(vec![w2.clone()]);
+            .return_const(vec![w1.clone()]);
 
         mock_server_state
             .expect_add_agent()
-            .with(predicate::eq(AGENT_B.to_owned()))
+            .with(
+                predicate::eq(AGENT_B.to_owned()),
+                predicate::eq(HashMap::new()),
+            )
             .once()
             .in_sequence(&mut seq)
-            .return_const(());
+            .return_const(None);
+
+        mock_server_state
+            .expect_get_workloads_for_agent()
+            .with(mockall::predicate::eq(AGENT_B.to_string()))
+            .once()
+            .in_sequence(&mut seq)
+            .return_const(vec![w2.clone()]);
 
         server.server_state = mock_server_state;
 
         let server_task = tokio::spawn(async move { server.start(None).await });
 
         // first agent connects to the server
-        let agent_hello_result = to_server.agent_hello(AGENT_A.to_string()).await;
+        let agent_hello_result = to_server
+            .agent_hello(AGENT_A.to_string(), HashMap::new())
+            .await;
         assert!(agent_hello_result.is_ok());
 
         let from_server_command = comm_middle_ware_receiver.recv().await.unwrap();
@@ -964,7 +1949,9 @@ mod tests {
             from_server_command
         );
 
-        let agent_hello_result = to_server.agent_hello(AGENT_B.to_owned()).await;
+        let agent_hello_result = to_server
+            .agent_hello(AGENT_B.to_owned(), HashMap::new())
+            .await;
         assert!(agent_hello_result.is_ok());
 
         let from_server_command = comm_middle_ware_receiver.recv().await.unwrap();
@@ -1025,7 +2012,156 @@ mod tests {
             from_server_command
         );
 
-        server_task.abort();
+        server_task.abort();
+        assert!(comm_middle_ware_receiver.try_recv().is_err());
+    }
+
+    // [utest->swdd~server-stores-newly-connected-agent~2]
+    // [utest->swdd~server-places-selected-workloads-on-newly-connected-agent~1]
+    #[tokio::test]
+    async fn utest_server_places_selected_workloads_on_newly_connected_agent() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let labels = HashMap::from([("arch".to_owned(), "arm64".to_owned())]);
+
+        let not_scheduled_workload = generate_test_workload_spec_with_param(
+            "".to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            RUNTIME_NAME.to_string(),
+        );
+        let placed_workload = generate_test_workload_spec_with_param(
+            AGENT_A.to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            RUNTIME_NAME.to_string(),
+        );
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state
+            .expect_add_agent()
+            .with(
+                predicate::eq(AGENT_A.to_owned()),
+                predicate::eq(labels.clone()),
+            )
+            .once()
+            .return_const(Some((
+                vec![placed_workload.clone()],
+                vec![DeletedWorkload {
+                    instance_name: not_scheduled_workload.instance_name.clone(),
+                    ..Default::default()
+                }],
+            )));
+        mock_server_state
+            .expect_get_workloads_for_agent()
+            .with(predicate::eq(AGENT_A.to_owned()))
+            .once()
+            .return_const(vec![placed_workload.clone()]);
+        server.server_state = mock_server_state;
+
+        let agent_hello_result = to_server.agent_hello(AGENT_A.to_owned(), labels).await;
+        assert!(agent_hello_result.is_ok());
+
+        let server_handle = server.start(None);
+
+        // The receiver in the server receives the messages and terminates the infinite waiting-loop
+        drop(to_server);
+        tokio::join!(server_handle).0.unwrap();
+
+        // the workload that was not scheduled before is reported as removed
+        assert_eq!(
+            FromServer::UpdateWorkloadState(UpdateWorkloadState {
+                workload_states: vec![WorkloadState {
+                    instance_name: not_scheduled_workload.instance_name,
+                    execution_state: ExecutionState::removed()
+                }]
+            }),
+            comm_middle_ware_receiver.recv().await.unwrap()
+        );
+
+        assert_eq!(
+            FromServer::ServerHello(ServerHello {
+                agent_name: Some(AGENT_A.to_owned()),
+                added_workloads: vec![placed_workload],
+            }),
+            comm_middle_ware_receiver.recv().await.unwrap()
+        );
+
+        assert!(comm_middle_ware_receiver.try_recv().is_err());
+    }
+
+    // [utest->swdd~server-places-selected-workloads-on-newly-connected-agent~1]
+    #[tokio::test]
+    async fn utest_server_sends_workloads_placed_on_other_agents_on_agent_hello() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let workload_of_connected_agent = generate_test_workload_spec_with_param(
+            AGENT_A.to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            RUNTIME_NAME.to_string(),
+        );
+        let workload_of_other_agent = generate_test_workload_spec_with_param(
+            AGENT_B.to_owned(),
+            WORKLOAD_NAME_2.to_owned(),
+            RUNTIME_NAME.to_string(),
+        );
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state
+            .expect_add_agent()
+            .once()
+            .return_const(Some((
+                vec![
+                    workload_of_connected_agent.clone(),
+                    workload_of_other_agent.clone(),
+                ],
+                vec![],
+            )));
+        mock_server_state
+            .expect_get_workloads_for_agent()
+            .with(predicate::eq(AGENT_A.to_owned()))
+            .once()
+            .return_const(vec![workload_of_connected_agent.clone()]);
+        server.server_state = mock_server_state;
+
+        let agent_hello_result = to_server
+            .agent_hello(AGENT_A.to_owned(), HashMap::new())
+            .await;
+        assert!(agent_hello_result.is_ok());
+
+        let server_handle = server.start(None);
+
+        // The receiver in the server receives the messages and terminates the infinite waiting-loop
+        drop(to_server);
+        tokio::join!(server_handle).0.unwrap();
+
+        assert!(matches!(
+            comm_middle_ware_receiver.recv().await.unwrap(),
+            FromServer::UpdateWorkloadState(_)
+        ));
+
+        assert_eq!(
+            FromServer::ServerHello(ServerHello {
+                agent_name: Some(AGENT_A.to_owned()),
+                added_workloads: vec![workload_of_connected_agent],
+            }),
+            comm_middle_ware_receiver.recv().await.unwrap()
+        );
+
+        assert_eq!(
+            FromServer::UpdateWorkload(UpdateWorkload {
+                added_workloads: vec![workload_of_other_agent],
+                deleted_workloads: vec![],
+            }),
+            comm_middle_ware_receiver.recv().await.unwrap()
+        );
+
         assert!(comm_middle_ware_receiver.try_recv().is_err());
     }
 
@@ -1075,12 +2211,15 @@ mod tests {
                 added_workloads.clone(),
                 deleted_workloads.clone(),
             ))));
+        mock_server_state
+            .expect_get_desired_state()
+            .return_const(State::default());
         server.server_state = mock_server_state;
         let server_task = tokio::spawn(async move { server.start(None).await });
 
         // send new state to server
         let update_state_result = to_server
-            .update_state(REQUEST_ID_A.to_string(), update_state, update_mask)
+            .update_state(REQUEST_ID_A.to_string(), update_state, update_mask, None)
             .await;
         assert!(update_state_result.is_ok());
 
@@ -1145,19 +2284,342 @@ mod tests {
         let mut server = AnkaiosServer::new(server_receiver, to_agents);
         let mut mock_server_state = MockServerState::new();
         mock_server_state
-            .expect_update()
-            .with(
-                mockall::predicate::eq(update_state.clone()),
-                mockall::predicate::eq(update_mask.clone()),
-            )
+            .expect_update()
+            .with(
+                mockall::predicate::eq(update_state.clone()),
+                mockall::predicate::eq(update_mask.clone()),
+            )
+            .once()
+            .return_const(Ok(None));
+        mock_server_state
+            .expect_get_desired_state()
+            .return_const(State::default());
+        server.server_state = mock_server_state;
+        let server_task = tokio::spawn(async move { server.start(None).await });
+
+        // send new state to server
+        let update_state_result = to_server
+            .update_state(REQUEST_ID_A.to_string(), update_state, update_mask, None)
+            .await;
+        assert!(update_state_result.is_ok());
+
+        assert!(matches!(
+            comm_middle_ware_receiver.recv().await.unwrap(),
+            FromServer::Response(ank_base::Response {
+                request_id,
+                response_content: Some(ank_base::response::ResponseContent::UpdateStateSuccess(ank_base::UpdateStateSuccess {
+                    added_workloads,
+                    deleted_workloads
+                }))
+            }) if request_id == REQUEST_ID_A && added_workloads.is_empty() && deleted_workloads.is_empty()
+        ));
+
+        assert!(
+            tokio::time::timeout(
+                tokio::time::Duration::from_millis(200),
+                comm_middle_ware_receiver.recv()
+            )
+            .await
+            .is_err()
+        );
+
+        server_task.abort();
+        assert!(comm_middle_ware_receiver.try_recv().is_err());
+    }
+
+    // [utest->swdd~server-records-revision-on-desired-state-change~2]
+    // [utest->swdd~server-provides-revisions~1]
+    #[tokio::test]
+    async fn utest_server_provides_revisions_of_accepted_updates() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let updated_desired_state = State {
+            workloads: HashMap::from([(
+                WORKLOAD_NAME_1.to_owned(),
+                generate_test_stored_workload_spec(AGENT_A, RUNTIME_NAME),
+            )]),
+            ..Default::default()
+        };
+        let update_mask = vec![format!("desiredState.workloads.{}", WORKLOAD_NAME_1)];
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state
+            .expect_update()
+            .once()
+            .return_const(Ok(None));
+        mock_server_state
+            .expect_get_desired_state()
+            .return_const(updated_desired_state.clone());
+        server.server_state = mock_server_state;
+        let server_task = tokio::spawn(async move { server.start(None).await });
+
+        assert!(
+            to_server
+                .update_state(
+                    REQUEST_ID_A.to_string(),
+                    CompleteState::default(),
+                    update_mask.clone(),
+                    None
+                )
+                .await
+                .is_ok()
+        );
+        assert!(matches!(
+            comm_middle_ware_receiver.recv().await.unwrap(),
+            FromServer::Response(ank_base::Response {
+                response_content: Some(ank_base::response::ResponseContent::UpdateStateSuccess(_)),
+                ..
+            })
+        ));
+
+        assert!(
+            to_server
+                .revisions_request(REQUEST_ID.to_string(), RevisionsRequest { revision: None })
+                .await
+                .is_ok()
+        );
+        let Some(FromServer::Response(ank_base::Response {
+            request_id,
+            response_content: Some(ank_base::response::ResponseContent::Revisions(revisions)),
+        })) = comm_middle_ware_receiver.recv().await
+        else {
+            panic!("Expected a Revisions response");
+        };
+        assert_eq!(request_id, REQUEST_ID);
+        assert_eq!(revisions.revisions.len(), 1);
+        assert_eq!(revisions.revisions[0].revision, 1);
+        assert_eq!(revisions.revisions[0].requester, "agent_A@workload_1");
+        assert_eq!(revisions.revisions[0].update_mask, update_mask);
+        assert_eq!(
+            revisions.revisions[0].added_workloads,
+            vec![WORKLOAD_NAME_1.to_string()]
+        );
+        assert!(revisions.revisions[0].desired_state.is_none());
+
+        assert!(
+            to_server
+                .revisions_request(
+                    REQUEST_ID.to_string(),
+                    RevisionsRequest { revision: Some(1) }
+                )
+                .await
+                .is_ok()
+        );
+        let Some(FromServer::Response(ank_base::Response {
+            response_content: Some(ank_base::response::ResponseContent::Revisions(revisions)),
+            ..
+        })) = comm_middle_ware_receiver.recv().await
+        else {
+            panic!("Expected a Revisions response");
+        };
+        assert_eq!(
+            revisions.revisions[0].desired_state,
+            Some(updated_desired_state.into())
+        );
+
+        server_task.abort();
+        assert!(comm_middle_ware_receiver.try_recv().is_err());
+    }
+
+    // [utest->swdd~server-provides-revisions~1]
+    #[tokio::test]
+    async fn utest_server_sends_error_on_unknown_revision() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        let server_task = tokio::spawn(async move { server.start(None).await });
+
+        assert!(
+            to_server
+                .revisions_request(
+                    REQUEST_ID.to_string(),
+                    RevisionsRequest { revision: Some(42) }
+                )
+                .await
+                .is_ok()
+        );
+
+        assert!(matches!(
+            comm_middle_ware_receiver.recv().await.unwrap(),
+            FromServer::Response(ank_base::Response {
+                request_id,
+                response_content: Some(ank_base::response::ResponseContent::Error(_)),
+            }) if request_id == REQUEST_ID
+        ));
+
+        server_task.abort();
+        assert!(comm_middle_ware_receiver.try_recv().is_err());
+    }
+
+    // [utest->swdd~server-uses-async-channels~1]
+    // [utest->swdd~server-provides-update-desired-state-interface~1]
+    // [utest->swdd~server-starts-without-startup-config~1]
+    #[tokio::test]
+    async fn utest_server_sends_workloads_and_workload_states_when_requested_update_state_error() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let w1 = generate_test_stored_workload_spec(AGENT_A.to_owned(), RUNTIME_NAME.to_string());
+
+        let update_state = CompleteState {
+            desired_state: State {
+                workloads: vec![(WORKLOAD_NAME_1.to_owned(), w1.clone())]
+                    .into_iter()
+                    .collect(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let update_mask = vec![format!("desiredState.workloads.{}", WORKLOAD_NAME_1)];
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state
+            .expect_update()
+            .with(
+                mockall::predicate::eq(update_state.clone()),
+                mockall::predicate::eq(update_mask.clone()),
+            )
+            .once()
+            .return_const(Err(UpdateStateError::ResultInvalid(
+                "some update error.".to_string(),
+            )));
+        server.server_state = mock_server_state;
+        let server_task = tokio::spawn(async move { server.start(None).await });
+
+        // send new state to server
+        let update_state_result = to_server
+            .update_state(REQUEST_ID_A.to_string(), update_state, update_mask, None)
+            .await;
+        assert!(update_state_result.is_ok());
+
+        assert!(matches!(
+            comm_middle_ware_receiver.recv().await.unwrap(),
+            FromServer::Response(ank_base::Response {
+                request_id,
+                response_content: Some(ank_base::response::ResponseContent::Error(_))
+            }) if request_id == REQUEST_ID_A
+        ));
+
+        assert!(
+            tokio::time::timeout(
+                tokio::time::Duration::from_millis(200),
+                comm_middle_ware_receiver.recv()
+            )
+            .await
+            .is_err()
+        );
+
+        server_task.abort();
+        assert!(comm_middle_ware_receiver.try_recv().is_err());
+    }
+
+    // [utest->swdd~server-handles-dry-run-update-state-request~1]
+    #[tokio::test]
+    async fn utest_server_dry_run_update_state_sends_planned_changes_without_applying() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let w1 = generate_test_workload_spec_with_param(
+            AGENT_A.to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            RUNTIME_NAME.to_string(),
+        );
+        let w2 = generate_test_workload_spec_with_param(
+            AGENT_A.to_owned(),
+            WORKLOAD_NAME_2.to_owned(),
+            RUNTIME_NAME.to_string(),
+        );
+
+        let update_state = CompleteState {
+            desired_state: State {
+                workloads: vec![(WORKLOAD_NAME_1.to_owned(), w1.clone().into())]
+                    .into_iter()
+                    .collect(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let deleted_workload = DeletedWorkload {
+            instance_name: w2.instance_name.clone(),
+            ..Default::default()
+        };
+
+        let update_mask = vec![];
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state
+            .expect_dry_run_update()
+            .with(
+                mockall::predicate::eq(update_state.clone()),
+                mockall::predicate::eq(update_mask.clone()),
+            )
+            .once()
+            .return_const(Ok(Some((vec![w1.clone()], vec![deleted_workload]))));
+        mock_server_state.expect_update().never();
+        server.server_state = mock_server_state;
+        let server_task = tokio::spawn(async move { server.start(None).await });
+
+        let update_state_result = to_server
+            .dry_run_update_state(REQUEST_ID_A.to_string(), update_state, update_mask, None)
+            .await;
+        assert!(update_state_result.is_ok());
+
+        assert_eq!(
+            FromServer::Response(ank_base::Response {
+                request_id: REQUEST_ID_A.to_string(),
+                response_content: Some(ank_base::response::ResponseContent::UpdateStateSuccess(
+                    ank_base::UpdateStateSuccess {
+                        added_workloads: vec![w1.instance_name.to_string()],
+                        deleted_workloads: vec![w2.instance_name.to_string()],
+                    }
+                ))
+            }),
+            comm_middle_ware_receiver.recv().await.unwrap()
+        );
+
+        server_task.abort();
+        assert!(comm_middle_ware_receiver.try_recv().is_err());
+    }
+
+    // [utest->swdd~server-handles-dry-run-update-state-request~1]
+    #[tokio::test]
+    async fn utest_server_dry_run_update_state_sends_error_on_invalid_new_state() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let update_mask = vec![format!("desiredState.workloads.{}", WORKLOAD_NAME_1)];
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state
+            .expect_dry_run_update()
             .once()
-            .return_const(Ok(None));
+            .return_const(Err(UpdateStateError::CycleInDependencies(
+                WORKLOAD_NAME_1.to_string(),
+            )));
+        mock_server_state.expect_update().never();
         server.server_state = mock_server_state;
         let server_task = tokio::spawn(async move { server.start(None).await });
 
-        // send new state to server
         let update_state_result = to_server
-            .update_state(REQUEST_ID_A.to_string(), update_state, update_mask)
+            .dry_run_update_state(
+                REQUEST_ID_A.to_string(),
+                CompleteState::default(),
+                update_mask,
+                None,
+            )
             .await;
         assert!(update_state_result.is_ok());
 
@@ -1165,66 +2627,37 @@ mod tests {
             comm_middle_ware_receiver.recv().await.unwrap(),
             FromServer::Response(ank_base::Response {
                 request_id,
-                response_content: Some(ank_base::response::ResponseContent::UpdateStateSuccess(ank_base::UpdateStateSuccess {
-                    added_workloads,
-                    deleted_workloads
-                }))
-            }) if request_id == REQUEST_ID_A && added_workloads.is_empty() && deleted_workloads.is_empty()
+                response_content: Some(ank_base::response::ResponseContent::Error(error))
+            }) if request_id == REQUEST_ID_A && error.message.contains(WORKLOAD_NAME_1)
         ));
 
-        assert!(
-            tokio::time::timeout(
-                tokio::time::Duration::from_millis(200),
-                comm_middle_ware_receiver.recv()
-            )
-            .await
-            .is_err()
-        );
-
         server_task.abort();
         assert!(comm_middle_ware_receiver.try_recv().is_err());
     }
 
-    // [utest->swdd~server-uses-async-channels~1]
-    // [utest->swdd~server-provides-update-desired-state-interface~1]
-    // [utest->swdd~server-starts-without-startup-config~1]
+    // [utest->swdd~server-rejects-update-state-with-unexpected-revision~1]
     #[tokio::test]
-    async fn utest_server_sends_workloads_and_workload_states_when_requested_update_state_error() {
+    async fn utest_server_update_state_rejects_unexpected_revision() {
         let _ = env_logger::builder().is_test(true).try_init();
         let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
         let (to_agents, mut comm_middle_ware_receiver) =
             create_from_server_channel(common::CHANNEL_CAPACITY);
 
-        let w1 = generate_test_stored_workload_spec(AGENT_A.to_owned(), RUNTIME_NAME.to_string());
-
-        let update_state = CompleteState {
-            desired_state: State {
-                workloads: vec![(WORKLOAD_NAME_1.to_owned(), w1.clone())]
-                    .into_iter()
-                    .collect(),
-                ..Default::default()
-            },
-            ..Default::default()
-        };
         let update_mask = vec![format!("desiredState.workloads.{}", WORKLOAD_NAME_1)];
         let mut server = AnkaiosServer::new(server_receiver, to_agents);
         let mut mock_server_state = MockServerState::new();
-        mock_server_state
-            .expect_update()
-            .with(
-                mockall::predicate::eq(update_state.clone()),
-                mockall::predicate::eq(update_mask.clone()),
-            )
-            .once()
-            .return_const(Err(UpdateStateError::ResultInvalid(
-                "some update error.".to_string(),
-            )));
+        mock_server_state.expect_update().never();
+        mock_server_state.expect_dry_run_update().never();
         server.server_state = mock_server_state;
         let server_task = tokio::spawn(async move { server.start(None).await });
 
-        // send new state to server
         let update_state_result = to_server
-            .update_state(REQUEST_ID_A.to_string(), update_state, update_mask)
+            .update_state(
+                REQUEST_ID_A.to_string(),
+                CompleteState::default(),
+                update_mask,
+                Some(1),
+            )
             .await;
         assert!(update_state_result.is_ok());
 
@@ -1232,19 +2665,12 @@ mod tests {
             comm_middle_ware_receiver.recv().await.unwrap(),
             FromServer::Response(ank_base::Response {
                 request_id,
-                response_content: Some(ank_base::response::ResponseContent::Error(_))
+                response_content: Some(ank_base::response::ResponseContent::Error(error))
             }) if request_id == REQUEST_ID_A
+                && error.code() == ank_base::ErrorCode::Conflict
+                && error.message.contains("expected revision '1', current revision '0'")
         ));
 
-        assert!(
-            tokio::time::timeout(
-                tokio::time::Duration::from_millis(200),
-                comm_middle_ware_receiver.recv()
-            )
-            .await
-            .is_err()
-        );
-
         server_task.abort();
         assert!(comm_middle_ware_receiver.try_recv().is_err());
     }
@@ -1418,6 +2844,7 @@ mod tests {
     // [utest->swdd~server-provides-interface-get-complete-state~2]
     // [utest->swdd~server-includes-id-in-control-interface-response~1]
     // [utest->swdd~server-starts-without-startup-config~1]
+    // [utest->swdd~server-provides-current-revision~1]
     #[tokio::test]
     async fn utest_server_returns_complete_state_when_received_request_complete_state() {
         let _ = env_logger::builder().is_test(true).try_init();
@@ -1456,6 +2883,7 @@ mod tests {
                     request_complete_state == &CompleteStateRequest { field_mask: vec![] }
                 }),
                 mockall::predicate::always(),
+                mockall::predicate::always(),
             )
             .once()
             .return_const(Ok(current_complete_state.clone()));
@@ -1474,12 +2902,16 @@ mod tests {
 
         let from_server_command = comm_middle_ware_receiver.recv().await.unwrap();
 
+        let expected_complete_state = ank_base::CompleteState {
+            revision: Some(0),
+            ..current_complete_state
+        };
         assert_eq!(
             from_server_command,
             common::from_server_interface::FromServer::Response(ank_base::Response {
                 request_id,
                 response_content: Some(ank_base::response::ResponseContent::CompleteState(
-                    current_complete_state
+                    expected_complete_state
                 ))
             })
         );
@@ -1508,6 +2940,7 @@ mod tests {
                     request_complete_state == &CompleteStateRequest { field_mask: vec![] }
                 }),
                 mockall::predicate::always(),
+                mockall::predicate::always(),
             )
             .once()
             .return_const(Err("complete state error.".to_string()));
@@ -1570,6 +3003,9 @@ mod tests {
             .expect_cleanup_state()
             .once()
             .return_const(());
+        mock_server_state
+            .expect_continue_rolling_updates()
+            .return_const((None, vec![]));
 
         mock_server_state
             .expect_remove_agent()
@@ -1652,6 +3088,9 @@ mod tests {
             });
 
         let mut mock_server_state = MockServerState::new();
+        mock_server_state
+            .expect_continue_rolling_updates()
+            .return_const((None, vec![]));
         mock_server_state.expect_cleanup_state().never();
 
         mock_server_state
@@ -1715,6 +3154,9 @@ mod tests {
             });
 
         let mut mock_server_state = MockServerState::new();
+        mock_server_state
+            .expect_continue_rolling_updates()
+            .return_const((None, vec![]));
         mock_server_state.expect_cleanup_state().never();
 
         mock_server_state
@@ -1827,7 +3269,7 @@ mod tests {
         mock_server_state
             .expect_add_agent()
             .times(2)
-            .return_const(());
+            .return_const(None);
 
         mock_server_state
             .expect_get_workloads_for_agent()
@@ -1845,6 +3287,9 @@ mod tests {
             .once()
             .in_sequence(&mut seq)
             .return_const(Ok(Some((added_workloads, deleted_workloads))));
+        mock_server_state
+            .expect_get_desired_state()
+            .return_const(State::default());
         server.server_state = mock_server_state;
 
         server
@@ -1852,14 +3297,23 @@ mod tests {
             .expect_remove_collector_campaign_entry()
             .return_const(HashSet::new());
 
-        let agent_hello1_result = to_server.agent_hello(AGENT_A.to_owned()).await;
+        let agent_hello1_result = to_server
+            .agent_hello(AGENT_A.to_owned(), HashMap::new())
+            .await;
         assert!(agent_hello1_result.is_ok());
 
-        let agent_hello2_result = to_server.agent_hello(AGENT_B.to_owned()).await;
+        let agent_hello2_result = to_server
+            .agent_hello(AGENT_B.to_owned(), HashMap::new())
+            .await;
         assert!(agent_hello2_result.is_ok());
 
         let update_state_result = to_server
-            .update_state(REQUEST_ID_A.to_string(), update_state, update_mask.clone())
+            .update_state(
+                REQUEST_ID_A.to_string(),
+                update_state,
+                update_mask.clone(),
+                None,
+            )
             .await;
         assert!(update_state_result.is_ok());
 
@@ -1976,7 +3430,8 @@ mod tests {
                                 agent_name: AGENT_A.into(),
                                 id: INSTANCE_ID.into()
                             }),
-                            message: MESSAGE.into()
+                            message: MESSAGE.into(),
+                            ..Default::default()
                         }]
                     }
                 )
@@ -1996,7 +3451,8 @@ mod tests {
                                 agent_name: AGENT_A.into(),
                                 id: INSTANCE_ID.into()
                             }),
-                            message: MESSAGE.into()
+                            message: MESSAGE.into(),
+                            ..Default::default()
                         },]
                     }
                 ))
@@ -2026,7 +3482,12 @@ mod tests {
 
         // send new state to server
         let update_state_result = to_server
-            .update_state(REQUEST_ID_A.to_string(), update_state.clone(), update_mask)
+            .update_state(
+                REQUEST_ID_A.to_string(),
+                update_state.clone(),
+                update_mask,
+                None,
+            )
             .await;
         assert!(update_state_result.is_ok());
 
@@ -2040,7 +3501,8 @@ mod tests {
                 request_id: REQUEST_ID_A.to_string(),
                 response_content: Some(ank_base::response::ResponseContent::Error(
                     ank_base::Error {
-                        message: error_message
+                        message: error_message,
+                        ..Default::default()
                     }
                 )),
             }),
@@ -2074,6 +3536,7 @@ mod tests {
                 REQUEST_ID_A.to_string(),
                 update_state_ankaios_no_version.clone(),
                 update_mask,
+                None,
             )
             .await;
         assert!(update_state_result.is_ok());
@@ -2088,7 +3551,8 @@ mod tests {
                 request_id: REQUEST_ID_A.to_string(),
                 response_content: Some(ank_base::response::ResponseContent::Error(
                     ank_base::Error {
-                        message: error_message
+                        message: error_message,
+                        ..Default::default()
                     }
                 )),
             }),
@@ -2120,16 +3584,121 @@ mod tests {
             .expect_cleanup_state()
             .with(mockall::predicate::eq(workload_states.clone()))
             .return_const(());
+        mock_server_state
+            .expect_continue_rolling_updates()
+            .return_const((None, vec![]));
+        server.server_state = mock_server_state;
+
+        let server_task = tokio::spawn(async move { server.start(None).await });
+
+        let update_workload_state_result = to_server.update_workload_state(workload_states).await;
+        assert!(update_workload_state_result.is_ok());
+
+        server_task.abort();
+    }
+
+    // [utest->swdd~server-continues-rolling-updates~1]
+    #[tokio::test]
+    async fn utest_server_sends_next_replica_updates_of_rolling_update() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+
+        let running_replica = generate_test_workload_spec_with_param(
+            AGENT_A.to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            RUNTIME_NAME.to_string(),
+        );
+        let next_replica = generate_test_workload_spec_with_param(
+            AGENT_B.to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            RUNTIME_NAME.to_string(),
+        );
+        let replaced_replica = DeletedWorkload {
+            instance_name: WorkloadInstanceName::builder()
+                .workload_name(WORKLOAD_NAME_1)
+                .agent_name(AGENT_B)
+                .config(&"old config".to_owned())
+                .build(),
+            ..Default::default()
+        };
+        let workload_states = vec![WorkloadState {
+            instance_name: running_replica.instance_name.clone(),
+            execution_state: ExecutionState::running(),
+        }];
+
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state.expect_cleanup_state().return_const(());
+        mock_server_state
+            .expect_continue_rolling_updates()
+            .with(mockall::predicate::eq(workload_states.clone()))
+            .once()
+            .return_const((
+                Some((vec![next_replica.clone()], vec![replaced_replica.clone()])),
+                vec![],
+            ));
+        mock_server_state
+            .expect_contains_connected_agent()
+            .return_const(true);
         server.server_state = mock_server_state;
 
+        server
+            .log_campaign_store
+            .expect_remove_collector_campaign_entry()
+            .return_const(HashSet::new());
+
         let server_task = tokio::spawn(async move { server.start(None).await });
 
         let update_workload_state_result = to_server.update_workload_state(workload_states).await;
         assert!(update_workload_state_result.is_ok());
 
+        assert!(matches!(
+            comm_middle_ware_receiver.recv().await.unwrap(),
+            FromServer::UpdateWorkloadState(_)
+        ));
+        assert_eq!(
+            comm_middle_ware_receiver.recv().await.unwrap(),
+            FromServer::UpdateWorkload(UpdateWorkload {
+                added_workloads: vec![next_replica],
+                deleted_workloads: vec![replaced_replica],
+            })
+        );
+
         server_task.abort();
     }
 
+    // [utest->swdd~server-records-rolled-back-rolling-updates~1]
+    #[tokio::test]
+    async fn utest_server_records_rolled_back_rolling_update() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (_to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        server
+            .revision_history
+            .record(REQUEST_ID, vec![], &State::default());
+
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state
+            .expect_continue_rolling_updates()
+            .once()
+            .return_const((None, vec![WORKLOAD_NAME_1.to_owned()]));
+        server.server_state = mock_server_state;
+
+        server.continue_rolling_updates(&[]).await;
+
+        assert_eq!(
+            server.revision_history.get_revisions()[0].rolled_back_workloads,
+            vec![WORKLOAD_NAME_1.to_owned()]
+        );
+        assert!(comm_middle_ware_receiver.try_recv().is_err());
+    }
+
     // [utest->swdd~server-handles-not-started-deleted-workloads~1]
     #[tokio::test]
     async fn utest_server_handles_deleted_workload_on_empty_agent() {
@@ -2177,6 +3746,9 @@ mod tests {
             .expect_update()
             .once()
             .return_const(Ok(Some((vec![], deleted_workloads.clone()))));
+        mock_server_state
+            .expect_get_desired_state()
+            .return_const(State::default());
         server.server_state = mock_server_state;
 
         server
@@ -2185,7 +3757,12 @@ mod tests {
             .return_const(HashSet::new());
 
         let update_state_result = to_server
-            .update_state(REQUEST_ID_A.to_string(), update_state, update_mask.clone())
+            .update_state(
+                REQUEST_ID_A.to_string(),
+                update_state,
+                update_mask.clone(),
+                None,
+            )
             .await;
         assert!(update_state_result.is_ok());
 
@@ -2260,6 +3837,9 @@ mod tests {
             .expect_update()
             .once()
             .return_const(Ok(Some((vec![], deleted_workloads.clone()))));
+        mock_server_state
+            .expect_get_desired_state()
+            .return_const(State::default());
         server.server_state = mock_server_state;
 
         let logs_request_id = format!(
@@ -2281,7 +3861,12 @@ mod tests {
             .return_const(HashSet::from([logs_request_id.to_owned()]));
 
         let update_state_result = to_server
-            .update_state(REQUEST_ID_A.to_string(), update_state, update_mask.clone())
+            .update_state(
+                REQUEST_ID_A.to_string(),
+                update_state,
+                update_mask.clone(),
+                None,
+            )
             .await;
         assert!(update_state_result.is_ok());
 
@@ -2298,44 +3883,179 @@ mod tests {
             from_server_command
         );
 
-        let from_server_command = comm_middle_ware_receiver.recv().await.unwrap();
+        let from_server_command = comm_middle_ware_receiver.recv().await.unwrap();
+        assert_eq!(
+            FromServer::UpdateWorkload(UpdateWorkload {
+                added_workloads: vec![],
+                deleted_workloads: vec![deleted_workload_with_agent.clone()],
+            }),
+            from_server_command
+        );
+
+        // ignore UpdateStateSuccessful response
+        assert!(matches!(
+            comm_middle_ware_receiver.recv().await.unwrap(),
+            FromServer::Response(_)
+        ));
+
+        assert!(comm_middle_ware_receiver.try_recv().is_err());
+    }
+
+    // [utest->swdd~server-receives-resource-availability~1]
+    #[tokio::test]
+    async fn utest_server_receives_agent_status_load() {
+        let payload = AgentLoadStatus {
+            agent_name: AGENT_A.to_string(),
+            cpu_usage: CpuUsage { cpu_usage: 42 },
+            free_memory: FreeMemory { free_memory: 42 },
+            workload_metrics: Default::default(),
+        };
+
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, _comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state
+            .expect_continue_rolling_updates()
+            .return_const((None, vec![]));
+        mock_server_state
+            .expect_update_agent_resource_availability()
+            .with(mockall::predicate::eq(payload.clone()))
+            .return_const(None);
+        server.server_state = mock_server_state;
+
+        let agent_resource_result = to_server.agent_load_status(payload).await;
+        assert!(agent_resource_result.is_ok());
+
+        drop(to_server);
+        let result = server.start(None).await;
+
+        assert!(result.is_ok());
+    }
+
+    // [utest->swdd~server-places-pending-workloads-on-resource-availability-update~1]
+    #[tokio::test]
+    async fn utest_server_places_pending_workloads_on_resource_availability_update() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let payload = AgentLoadStatus {
+            agent_name: AGENT_A.to_string(),
+            cpu_usage: CpuUsage { cpu_usage: 42 },
+            free_memory: FreeMemory { free_memory: 42 },
+            workload_metrics: Default::default(),
+        };
+
+        let not_scheduled_workload = generate_test_workload_spec_with_param(
+            "".to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            RUNTIME_NAME.to_string(),
+        );
+        let placed_workload = generate_test_workload_spec_with_param(
+            AGENT_A.to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            RUNTIME_NAME.to_string(),
+        );
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state
+            .expect_continue_rolling_updates()
+            .return_const((None, vec![]));
+        mock_server_state
+            .expect_update_agent_resource_availability()
+            .with(predicate::eq(payload.clone()))
+            .once()
+            .return_const(Some((
+                vec![placed_workload.clone()],
+                vec![DeletedWorkload {
+                    instance_name: not_scheduled_workload.instance_name.clone(),
+                    ..Default::default()
+                }],
+            )));
+        server.server_state = mock_server_state;
+
+        let agent_resource_result = to_server.agent_load_status(payload).await;
+        assert!(agent_resource_result.is_ok());
+
+        let server_handle = server.start(None);
+
+        // The receiver in the server receives the messages and terminates the infinite waiting-loop
+        drop(to_server);
+        tokio::join!(server_handle).0.unwrap();
+
+        assert_eq!(
+            FromServer::UpdateWorkloadState(UpdateWorkloadState {
+                workload_states: vec![WorkloadState {
+                    instance_name: not_scheduled_workload.instance_name,
+                    execution_state: ExecutionState::removed()
+                }]
+            }),
+            comm_middle_ware_receiver.recv().await.unwrap()
+        );
+
         assert_eq!(
             FromServer::UpdateWorkload(UpdateWorkload {
-                added_workloads: vec![],
-                deleted_workloads: vec![deleted_workload_with_agent.clone()],
+                added_workloads: vec![placed_workload.clone()],
+                deleted_workloads: vec![],
             }),
-            from_server_command
+            comm_middle_ware_receiver.recv().await.unwrap()
         );
 
-        // ignore UpdateStateSuccessful response
-        assert!(matches!(
-            comm_middle_ware_receiver.recv().await.unwrap(),
-            FromServer::Response(_)
-        ));
-
+        assert_eq!(
+            server
+                .workload_states_map
+                .get_workload_state_for_workload(&placed_workload.instance_name),
+            Some(&ExecutionState::initial())
+        );
         assert!(comm_middle_ware_receiver.try_recv().is_err());
     }
 
-    // [utest->swdd~server-receives-resource-availability~1]
+    // [utest->swdd~server-reports-reason-of-not-scheduled-workloads~1]
     #[tokio::test]
-    async fn utest_server_receives_agent_status_load() {
+    async fn utest_server_reports_reason_of_not_scheduled_workloads() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, _comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
         let payload = AgentLoadStatus {
             agent_name: AGENT_A.to_string(),
             cpu_usage: CpuUsage { cpu_usage: 42 },
             free_memory: FreeMemory { free_memory: 42 },
+            workload_metrics: Default::default(),
         };
+        let reason = "No connected agent has enough free resources";
 
-        let _ = env_logger::builder().is_test(true).try_init();
-        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
-        let (to_agents, _comm_middle_ware_receiver) =
-            create_from_server_channel(common::CHANNEL_CAPACITY);
+        let not_scheduled_workload = generate_test_workload_spec_with_param(
+            "".to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            RUNTIME_NAME.to_string(),
+        );
 
         let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        server
+            .workload_states_map
+            .initial_state(&vec![not_scheduled_workload.clone()]);
+
         let mut mock_server_state = MockServerState::new();
+        mock_server_state
+            .expect_continue_rolling_updates()
+            .return_const((None, vec![]));
         mock_server_state
             .expect_update_agent_resource_availability()
-            .with(mockall::predicate::eq(payload.clone()))
-            .return_const(());
+            .once()
+            .return_const(None);
+        mock_server_state
+            .expect_get_not_scheduled_reason()
+            .with(predicate::eq(WORKLOAD_NAME_1.to_owned()))
+            .once()
+            .return_const(Some(reason.to_owned()));
         server.server_state = mock_server_state;
 
         let agent_resource_result = to_server.agent_load_status(payload).await;
@@ -2343,8 +4063,14 @@ mod tests {
 
         drop(to_server);
         let result = server.start(None).await;
-
         assert!(result.is_ok());
+
+        assert_eq!(
+            server
+                .workload_states_map
+                .get_workload_state_for_workload(&not_scheduled_workload.instance_name),
+            Some(&ExecutionState::not_scheduled_with_reason(reason))
+        );
     }
 
     // [utest->swdd~server-handles-not-started-deleted-workloads~1]
@@ -2522,4 +4248,294 @@ mod tests {
         server_task.abort();
         assert!(comm_middle_ware_receiver.try_recv().is_err());
     }
+
+    // [utest->swdd~server-handles-events-subscribe-request~1]
+    // [utest->swdd~server-sends-events-on-state-change~1]
+    #[tokio::test]
+    async fn utest_server_sends_events_on_workload_state_change() {
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let field_mask = "workloadStates".to_string();
+        let initial_complete_state = ank_base::CompleteState {
+            workload_states: generate_test_workload_states_map_with_data(
+                AGENT_A,
+                WORKLOAD_NAME_1,
+                INSTANCE_ID,
+                ExecutionState::running(),
+            )
+            .into(),
+            ..Default::default()
+        };
+        let updated_complete_state = ank_base::CompleteState {
+            workload_states: generate_test_workload_states_map_with_data(
+                AGENT_A,
+                WORKLOAD_NAME_1,
+                INSTANCE_ID,
+                ExecutionState::succeeded(),
+            )
+            .into(),
+            ..Default::default()
+        };
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state.expect_cleanup_state().return_const(());
+        mock_server_state
+            .expect_continue_rolling_updates()
+            .return_const((None, vec![]));
+        let mut seq = mockall::Sequence::new();
+        mock_server_state
+            .expect_get_complete_state_by_field_mask()
+            .with(
+                predicate::eq(CompleteStateRequest {
+                    field_mask: vec![field_mask.clone()],
+                }),
+                predicate::always(),
+                predicate::always(),
+            )
+            .once()
+            .in_sequence(&mut seq)
+            .return_const(Ok(initial_complete_state.clone()));
+        mock_server_state
+            .expect_get_complete_state_by_field_mask()
+            .once()
+            .in_sequence(&mut seq)
+            .return_const(Ok(updated_complete_state.clone()));
+        server.server_state = mock_server_state;
+        let server_task = tokio::spawn(async move { server.start(None).await });
+
+        let result = to_server
+            .events_subscribe_request(
+                REQUEST_ID.to_string(),
+                EventsSubscribeRequest {
+                    field_masks: vec![field_mask.clone()],
+                },
+            )
+            .await;
+        assert!(result.is_ok());
+
+        assert_eq!(
+            comm_middle_ware_receiver.recv().await.unwrap(),
+            FromServer::Response(ank_base::Response {
+                request_id: REQUEST_ID.to_string(),
+                response_content: Some(ank_base::response::ResponseContent::EventResponse(
+                    ank_base::EventResponse {
+                        complete_state: Some(ank_base::CompleteState {
+                            revision: Some(0),
+                            ..initial_complete_state
+                        }),
+                        changed_field_masks: vec![field_mask.clone()],
+                    }
+                )),
+            })
+        );
+
+        let workload_state = common::objects::generate_test_workload_state_with_agent(
+            WORKLOAD_NAME_1,
+            AGENT_A,
+            ExecutionState::succeeded(),
+        );
+        let result = to_server
+            .update_workload_state(vec![workload_state.clone()])
+            .await;
+        assert!(result.is_ok());
+
+        assert_eq!(
+            comm_middle_ware_receiver.recv().await.unwrap(),
+            FromServer::UpdateWorkloadState(UpdateWorkloadState {
+                workload_states: vec![workload_state],
+            })
+        );
+        assert_eq!(
+            comm_middle_ware_receiver.recv().await.unwrap(),
+            FromServer::Response(ank_base::Response {
+                request_id: REQUEST_ID.to_string(),
+                response_content: Some(ank_base::response::ResponseContent::EventResponse(
+                    ank_base::EventResponse {
+                        complete_state: Some(ank_base::CompleteState {
+                            revision: Some(0),
+                            ..updated_complete_state
+                        }),
+                        changed_field_masks: vec![field_mask],
+                    }
+                )),
+            })
+        );
+
+        server_task.abort();
+        assert!(comm_middle_ware_receiver.try_recv().is_err());
+    }
+
+    // [utest->swdd~server-handles-events-cancel-request~1]
+    #[tokio::test]
+    async fn utest_server_events_cancel_request() {
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        server
+            .event_subscription_store
+            .subscribe(REQUEST_ID, vec!["workloadStates".to_string()]);
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state.expect_cleanup_state().return_const(());
+        mock_server_state
+            .expect_continue_rolling_updates()
+            .return_const((None, vec![]));
+        mock_server_state
+            .expect_get_complete_state_by_field_mask()
+            .never();
+        server.server_state = mock_server_state;
+        let server_task = tokio::spawn(async move { server.start(None).await });
+
+        let result = to_server
+            .events_cancel_request(REQUEST_ID.to_string())
+            .await;
+        assert!(result.is_ok());
+
+        assert_eq!(
+            comm_middle_ware_receiver.recv().await.unwrap(),
+            FromServer::Response(ank_base::Response {
+                request_id: REQUEST_ID.to_string(),
+                response_content: Some(ank_base::response::ResponseContent::EventsCancelAccepted(
+                    ank_base::EventsCancelAccepted {}
+                )),
+            })
+        );
+
+        // no events are sent for the canceled subscription
+        let result = to_server.update_workload_state(vec![]).await;
+        assert!(result.is_ok());
+        assert_eq!(
+            comm_middle_ware_receiver.recv().await.unwrap(),
+            FromServer::UpdateWorkloadState(UpdateWorkloadState {
+                workload_states: vec![],
+            })
+        );
+
+        server_task.abort();
+        assert!(comm_middle_ware_receiver.try_recv().is_err());
+    }
+
+    // [utest->swdd~server-removes-event-subscriptions-of-disconnected-connection~1]
+    #[tokio::test]
+    async fn utest_server_removes_event_subscriptions_on_cli_disconnect() {
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let cli_connection_name = "cli-conn-1234".to_string();
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        server.event_subscription_store.subscribe(
+            &format!("{cli_connection_name}@cli-request-id-1"),
+            vec!["workloadStates".to_string()],
+        );
+        server
+            .log_campaign_store
+            .expect_remove_cli_log_campaign_entry()
+            .return_const(HashSet::new());
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state.expect_cleanup_state().return_const(());
+        mock_server_state
+            .expect_continue_rolling_updates()
+            .return_const((None, vec![]));
+        mock_server_state
+            .expect_get_complete_state_by_field_mask()
+            .never();
+        server.server_state = mock_server_state;
+        let server_task = tokio::spawn(async move { server.start(None).await });
+
+        let result = to_server.goodbye(cli_connection_name).await;
+        assert!(result.is_ok());
+
+        let result = to_server.update_workload_state(vec![]).await;
+        assert!(result.is_ok());
+        assert_eq!(
+            comm_middle_ware_receiver.recv().await.unwrap(),
+            FromServer::UpdateWorkloadState(UpdateWorkloadState {
+                workload_states: vec![],
+            })
+        );
+
+        server_task.abort();
+        assert!(comm_middle_ware_receiver.try_recv().is_err());
+    }
+
+    // [utest->swdd~server-removes-event-subscriptions-of-deleted-workloads~1]
+    #[tokio::test]
+    async fn utest_server_removes_event_subscriptions_of_deleted_workload() {
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let subscribing_workload = generate_test_workload_spec_with_param(
+            AGENT_B.to_owned(),
+            WORKLOAD_NAME_2.to_owned(),
+            RUNTIME_NAME.to_string(),
+        );
+        let deleted_workload = DeletedWorkload {
+            instance_name: subscribing_workload.instance_name.clone(),
+            ..Default::default()
+        };
+        let event_request_id = format!(
+            "{}@{}@{}",
+            subscribing_workload.instance_name.agent_name(),
+            subscribing_workload.instance_name.workload_name(),
+            "uuid2"
+        );
+
+        let mut server: AnkaiosServer = AnkaiosServer::new(server_receiver, to_agents);
+        server
+            .event_subscription_store
+            .subscribe(&event_request_id, vec!["workloadStates".to_string()]);
+        server
+            .log_campaign_store
+            .expect_remove_collector_campaign_entry()
+            .return_const(HashSet::new());
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state
+            .expect_contains_connected_agent()
+            .return_const(true);
+        mock_server_state
+            .expect_update()
+            .once()
+            .return_const(Ok(Some((vec![], vec![deleted_workload.clone()]))));
+        mock_server_state
+            .expect_get_desired_state()
+            .return_const(State::default());
+        // no event is built for the removed subscription
+        mock_server_state
+            .expect_get_complete_state_by_field_mask()
+            .never();
+        server.server_state = mock_server_state;
+
+        let update_state_result = to_server
+            .update_state(
+                REQUEST_ID_A.to_string(),
+                CompleteState::default(),
+                vec!["desiredState.workloads".to_string()],
+                None,
+            )
+            .await;
+        assert!(update_state_result.is_ok());
+
+        drop(to_server);
+        server.start(None).await.unwrap();
+
+        assert!(
+            server
+                .event_subscription_store
+                .field_masks(&event_request_id)
+                .is_none()
+        );
+        assert_eq!(
+            comm_middle_ware_receiver.recv().await.unwrap(),
+            FromServer::UpdateWorkload(UpdateWorkload {
+                added_workloads: vec![],
+                deleted_workloads: vec![deleted_workload],
+            })
+        );
+    }
 }
@@ -61,6 +61,20 @@ impl ConfigRenderError {
     }
 }
 
+// [impl->swdd~config-renderer-expands-workload-replicas~1]
+// A workload name must not contain a dot, so the key of a replica does not collide with another workload.
+fn rendered_workload_key(
+    workload_name: &str,
+    stored_workload: &StoredWorkloadSpec,
+    replica: &StoredWorkloadSpec,
+) -> String {
+    if stored_workload.agents.is_empty() {
+        workload_name.to_owned()
+    } else {
+        format!("{workload_name}.{}", replica.agent)
+    }
+}
+
 // [impl->swdd~server-delegate-template-render-to-external-library~1]
 pub struct ConfigRenderer {
     template_engine: Handlebars<'static>,
@@ -89,19 +103,31 @@ impl ConfigRenderer {
     ) -> Result<RenderedWorkloads, ConfigRenderError> {
         let mut rendered_workloads = HashMap::new();
         for (workload_name, stored_workload) in workloads {
-            let workload_spec = if stored_workload.configs.is_empty() {
+            let wl_config_map = if stored_workload.configs.is_empty() {
                 log::debug!(
                     "Skipping to render workload '{workload_name}' as no config is assigned to the workload"
                 );
-                WorkloadSpec::from((workload_name.to_owned(), stored_workload.clone()))
+                None
             } else {
                 let wl_config_map =
                     self.create_config_map_for_workload(stored_workload, configs)?;
                 log::debug!("Rendering workload '{workload_name}' with config '{wl_config_map:?}'");
-                self.render_workload_fields(workload_name, stored_workload, &wl_config_map)?
+                Some(wl_config_map)
             };
 
-            rendered_workloads.insert(workload_name.clone(), workload_spec);
+            // [impl->swdd~config-renderer-expands-workload-replicas~1]
+            for replica in stored_workload.replicas() {
+                let rendered_workload_key =
+                    rendered_workload_key(workload_name, stored_workload, &replica);
+                let workload_spec = match &wl_config_map {
+                    None => WorkloadSpec::from((workload_name.to_owned(), replica)),
+                    Some(wl_config_map) => {
+                        self.render_workload_fields(workload_name, &replica, wl_config_map)?
+                    }
+                };
+
+                rendered_workloads.insert(rendered_workload_key, workload_spec);
+            }
         }
         log::trace!("Rendered CompleteState: {rendered_workloads:?}");
         Ok(rendered_workloads)
@@ -153,9 +179,19 @@ impl ConfigRenderer {
             runtime_config: rendered_runtime_config,
             tags: workload.tags.clone(),
             dependencies: workload.dependencies.clone(),
+            dependency_timeouts: workload.dependency_timeouts.clone(),
             restart_policy: workload.restart_policy.clone(),
             files: rendered_files,
             control_interface_access: workload.control_interface_access.clone(),
+            probes: workload.probes.clone(),
+            restart_backoff: workload.restart_backoff.clone(),
+            resources: workload.resources.clone(),
+            // [impl->swdd~common-workload-update-ordering~1]
+            update_ordering: workload
+                .update_strategy
+                .as_ref()
+                .map(|update_strategy| update_strategy.ordering)
+                .unwrap_or_default(),
         })
     }
 
@@ -217,8 +253,9 @@ mod tests {
     use std::collections::HashMap;
 
     use common::objects::{
-        Base64Data, ConfigItem, Data, File, FileContent, generate_test_configs,
-        generate_test_rendered_workload_files, generate_test_stored_workload_spec_with_config,
+        Base64Data, ConfigItem, Data, File, FileContent, UpdateOrdering, UpdateStrategy,
+        generate_test_configs, generate_test_rendered_workload_files,
+        generate_test_stored_workload_spec_with_config,
         generate_test_stored_workload_spec_with_files,
         generate_test_workload_spec_with_rendered_files,
         generate_test_workload_spec_with_runtime_config,
@@ -226,6 +263,7 @@ mod tests {
 
     const WORKLOAD_NAME_1: &str = "workload_1";
     const AGENT_A: &str = "agent_A";
+    const AGENT_B: &str = "agent_B";
     const RUNTIME: &str = "runtime";
 
     fn generate_test_templated_workload_files() -> Vec<File> {
@@ -279,6 +317,71 @@ mod tests {
         );
     }
 
+    // [utest->swdd~config-renderer-expands-workload-replicas~1]
+    #[test]
+    fn utest_render_workloads_expands_workload_replicas() {
+        let templated_runtime_config =
+            "some_value_1: {{ref1.values.value_1}}\nsome_value_2: {{ref1.values.value_2.0}}";
+        let mut stored_workload =
+            generate_test_stored_workload_spec_with_config("", RUNTIME, templated_runtime_config);
+        stored_workload.agents = vec![AGENT_A.to_owned(), AGENT_B.to_owned()];
+
+        let workloads = HashMap::from([(WORKLOAD_NAME_1.to_owned(), stored_workload)]);
+        let configs = generate_test_configs();
+        let renderer = ConfigRenderer::default();
+
+        let rendered_runtime_config = "some_value_1: value123\nsome_value_2: list_value_1";
+        let expected_replica_a = generate_test_workload_spec_with_runtime_config(
+            AGENT_A.to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            RUNTIME.to_owned(),
+            rendered_runtime_config.to_owned(),
+        );
+        let expected_replica_b = generate_test_workload_spec_with_runtime_config(
+            AGENT_B.to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            RUNTIME.to_owned(),
+            rendered_runtime_config.to_owned(),
+        );
+
+        let result = renderer.render_workloads(&workloads, &configs);
+
+        assert_eq!(
+            Ok(RenderedWorkloads::from([
+                (format!("{WORKLOAD_NAME_1}.{AGENT_A}"), expected_replica_a),
+                (format!("{WORKLOAD_NAME_1}.{AGENT_B}"), expected_replica_b),
+            ])),
+            result
+        );
+    }
+
+    // [utest->swdd~common-workload-update-ordering~1]
+    #[test]
+    fn utest_render_workloads_provides_update_ordering_to_replicas() {
+        let mut stored_workload =
+            generate_test_stored_workload_spec_with_config("", RUNTIME, "some config");
+        stored_workload.agents = vec![AGENT_A.to_owned(), AGENT_B.to_owned()];
+        stored_workload.configs.clear();
+        stored_workload.update_strategy = Some(UpdateStrategy {
+            ordering: UpdateOrdering::AtLeastOnce,
+            ..Default::default()
+        });
+
+        let workloads = HashMap::from([(WORKLOAD_NAME_1.to_owned(), stored_workload)]);
+        let renderer = ConfigRenderer::default();
+
+        let rendered_workloads = renderer
+            .render_workloads(&workloads, &HashMap::new())
+            .unwrap();
+
+        assert_eq!(rendered_workloads.len(), 2);
+        assert!(
+            rendered_workloads
+                .values()
+                .all(|replica| replica.update_ordering == UpdateOrdering::AtLeastOnce)
+        );
+    }
+
     // [utest->swdd~config-renderer-renders-workload-configuration~2]
     #[test]
     fn utest_render_workloads_render_files_fields_successfully() {
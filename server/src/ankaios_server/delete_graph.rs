@@ -26,13 +26,16 @@ pub struct DeleteGraph {
 
 #[cfg_attr(test, automock)]
 impl DeleteGraph {
-    // [impl->swdd~server-state-stores-delete-condition~1]
+    // [impl->swdd~server-state-stores-delete-condition~2]
     pub fn insert(&mut self, new_workloads: &[WorkloadSpec]) {
         for workload_spec in new_workloads {
             for (dependency_name, add_condition) in workload_spec.dependencies.iter() {
-                /* currently for other add conditions besides AddCondRunning
+                /* currently for other add conditions besides AddCondRunning and AddCondReady
                 the workload can be deleted immediately and does not need a delete condition */
-                if add_condition == &AddCondition::AddCondRunning {
+                if matches!(
+                    add_condition,
+                    AddCondition::AddCondRunning | AddCondition::AddCondReady
+                ) {
                     let workload_name = workload_spec.instance_name.workload_name().to_owned();
                     self.delete_graph
                         .entry(dependency_name.clone())
@@ -98,7 +101,7 @@ mod tests {
     const WORKLOAD_NAME_6: &str = "workload_6";
     const RUNTIME: &str = "runtime";
 
-    // [utest->swdd~server-state-stores-delete-condition~1]
+    // [utest->swdd~server-state-stores-delete-condition~2]
     #[test]
     fn utest_delete_graph_insert() {
         /*
@@ -107,12 +110,13 @@ mod tests {
             R = ADD_COND_RUNNING
             S = ADD_COND_SUCCEEDED
             F = ADD_COND_FAILED
+            D = ADD_COND_READY
 
                                           =>    2 --> 1 (DelCondNotPendingNorRunning)
             4 --> 1 --> 2                       5 --> 3 (DelCondNotPendingNorRunning)
                F     R
             3 --> 5
-               R
+               D
             6 (workload without dependencies)
         */
         let _ = env_logger::builder().is_test(true).try_init();
@@ -162,7 +166,7 @@ mod tests {
 
         workload_3.dependencies = HashMap::from([(
             workload_5.instance_name.workload_name().to_owned(),
-            AddCondition::AddCondRunning,
+            AddCondition::AddCondReady,
         )]);
 
         workload_4.dependencies = HashMap::from([(
@@ -203,7 +207,7 @@ mod tests {
         assert_eq!(expected_delete_graph, delete_graph.delete_graph);
     }
 
-    // [utest->swdd~server-state-stores-delete-condition~1]
+    // [utest->swdd~server-state-stores-delete-condition~2]
     // [utest->swdd~server-state-adds-delete-conditions-to-deleted-workload~1]
     #[test]
     fn utest_delete_graph_apply_delete_conditions() {
@@ -0,0 +1,386 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use common::{
+    objects::{WILDCARD_SYMBOL, WorkloadInstanceName},
+    state_manipulation::{Object, Path},
+};
+use std::collections::HashMap;
+
+// The parts of the complete state that are watched if a subscription has no field masks. The agents are
+// not watched by default as their resource availability changes with every load status of an agent.
+const DEFAULT_FIELD_MASKS: [&str; 2] = ["desiredState", "workloadStates"];
+const REQUEST_ID_SEPARATOR: char = '@';
+
+struct EventSubscription {
+    field_masks: Vec<String>,
+    last_state: Option<Object>,
+}
+
+// [impl->swdd~server-event-subscription-store-holds-subscriptions~1]
+#[derive(Default)]
+pub struct EventSubscriptionStore {
+    subscriptions: HashMap<String, EventSubscription>,
+}
+
+impl EventSubscriptionStore {
+    pub fn subscribe(&mut self, request_id: &str, field_masks: Vec<String>) {
+        let field_masks = if field_masks.is_empty() {
+            DEFAULT_FIELD_MASKS.map(String::from).to_vec()
+        } else {
+            field_masks
+        };
+        self.subscriptions.insert(
+            request_id.to_owned(),
+            EventSubscription {
+                field_masks,
+                last_state: None,
+            },
+        );
+    }
+
+    pub fn unsubscribe(&mut self, request_id: &str) -> bool {
+        self.subscriptions.remove(request_id).is_some()
+    }
+
+    // The request ids of a CLI connection start with its connection name and the ones of workloads with their
+    // agent name, so all subscriptions of a CLI or of all workloads of an agent can be removed together.
+    pub fn remove_subscriptions_of(&mut self, connection_name: &str) -> Vec<String> {
+        let removed_request_ids: Vec<String> = self
+            .subscriptions
+            .keys()
+            .filter(|request_id| {
+                request_id
+                    .split(REQUEST_ID_SEPARATOR)
+                    .next()
+                    .is_some_and(|name| name == connection_name)
+            })
+            .cloned()
+            .collect();
+        for request_id in &removed_request_ids {
+            self.subscriptions.remove(request_id);
+        }
+        removed_request_ids
+    }
+
+    // The request ids of workloads start with the agent name followed by the workload name.
+    // [impl->swdd~server-removes-event-subscriptions-of-deleted-workloads~1]
+    pub fn remove_subscriptions_of_workload(
+        &mut self,
+        instance_name: &WorkloadInstanceName,
+    ) -> Vec<String> {
+        let request_id_prefix = format!(
+            "{}{REQUEST_ID_SEPARATOR}{}{REQUEST_ID_SEPARATOR}",
+            instance_name.agent_name(),
+            instance_name.workload_name()
+        );
+        let removed_request_ids: Vec<String> = self
+            .subscriptions
+            .keys()
+            .filter(|request_id| request_id.starts_with(&request_id_prefix))
+            .cloned()
+            .collect();
+        for request_id in &removed_request_ids {
+            self.subscriptions.remove(request_id);
+        }
+        removed_request_ids
+    }
+
+    // Subscriptions with the same field masks share the filtered complete state they are compared with.
+    pub fn request_ids_by_field_masks(&self) -> HashMap<Vec<String>, Vec<String>> {
+        let mut request_ids_by_field_masks: HashMap<Vec<String>, Vec<String>> = HashMap::new();
+        for (request_id, subscription) in &self.subscriptions {
+            request_ids_by_field_masks
+                .entry(subscription.field_masks.clone())
+                .or_default()
+                .push(request_id.clone());
+        }
+        request_ids_by_field_masks
+    }
+
+    pub fn field_masks(&self, request_id: &str) -> Option<Vec<String>> {
+        self.subscriptions
+            .get(request_id)
+            .map(|subscription| subscription.field_masks.clone())
+    }
+
+    // [impl->swdd~server-event-subscription-store-detects-changed-field-masks~1]
+    pub fn changed_field_masks(&mut self, request_id: &str, new_state: &Object) -> Vec<String> {
+        let Some(subscription) = self.subscriptions.get_mut(request_id) else {
+            return Vec::new();
+        };
+
+        let changed_field_masks = subscription
+            .field_masks
+            .iter()
+            .filter(|field_mask| {
+                let path = Self::path_before_wildcard(field_mask);
+                subscription
+                    .last_state
+                    .as_ref()
+                    .is_none_or(|last_state| last_state.get(&path) != new_state.get(&path))
+            })
+            .cloned()
+            .collect();
+        subscription.last_state = Some(new_state.clone());
+        changed_field_masks
+    }
+
+    // The parts of a field mask starting with a wildcard are compared as a whole, as the new
+    // state only contains the requested fields of the expanded field masks.
+    // [impl->swdd~server-event-subscription-store-detects-changed-field-masks-with-wildcards~1]
+    fn path_before_wildcard(field_mask: &str) -> Path {
+        field_mask
+            .split('.')
+            .take_while(|part| *part != WILDCARD_SYMBOL)
+            .map(str::to_owned)
+            .collect::<Vec<String>>()
+            .into()
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::EventSubscriptionStore;
+    use common::objects::{CompleteState, generate_test_workload_states_map_with_data};
+    use common::objects::{
+        ExecutionState, State, WorkloadInstanceNameBuilder, generate_test_stored_workload_spec,
+    };
+    use common::state_manipulation::Object;
+
+    const CLI_REQUEST_ID: &str = "cli-conn-1@request_1";
+    const OTHER_CLI_REQUEST_ID: &str = "cli-conn-2@request_2";
+    const WORKLOAD_REQUEST_ID: &str = "agent_A@workload_1@request_3";
+    const OTHER_WORKLOAD_REQUEST_ID: &str = "agent_A@workload_10@request_4";
+    const AGENT_A: &str = "agent_A";
+    const WORKLOAD_NAME_1: &str = "workload_1";
+    const RUNTIME: &str = "runtime";
+    const DESIRED_STATE_FIELD_MASK: &str = "desiredState";
+    const WORKLOAD_STATES_FIELD_MASK: &str = "workloadStates";
+
+    fn complete_state(with_workload: bool, execution_state: ExecutionState) -> Object {
+        let mut desired_state = State::default();
+        if with_workload {
+            desired_state.workloads.insert(
+                WORKLOAD_NAME_1.to_string(),
+                generate_test_stored_workload_spec(AGENT_A, RUNTIME),
+            );
+        }
+        CompleteState {
+            desired_state,
+            workload_states: generate_test_workload_states_map_with_data(
+                AGENT_A,
+                WORKLOAD_NAME_1,
+                "id_1",
+                execution_state,
+            ),
+            ..Default::default()
+        }
+        .try_into()
+        .unwrap()
+    }
+
+    // [utest->swdd~server-event-subscription-store-holds-subscriptions~1]
+    #[test]
+    fn utest_event_subscription_store_subscribe_without_field_masks_watches_default_masks() {
+        let mut store = EventSubscriptionStore::default();
+
+        store.subscribe(CLI_REQUEST_ID, vec![]);
+
+        assert_eq!(
+            store.field_masks(CLI_REQUEST_ID),
+            Some(vec![
+                "desiredState".to_string(),
+                "workloadStates".to_string()
+            ])
+        );
+    }
+
+    // [utest->swdd~server-event-subscription-store-holds-subscriptions~1]
+    #[test]
+    fn utest_event_subscription_store_unsubscribe() {
+        let mut store = EventSubscriptionStore::default();
+        store.subscribe(CLI_REQUEST_ID, vec![DESIRED_STATE_FIELD_MASK.to_string()]);
+
+        assert!(store.unsubscribe(CLI_REQUEST_ID));
+        assert!(!store.unsubscribe(CLI_REQUEST_ID));
+        assert!(store.field_masks(CLI_REQUEST_ID).is_none());
+    }
+
+    // [utest->swdd~server-event-subscription-store-holds-subscriptions~1]
+    #[test]
+    fn utest_event_subscription_store_remove_subscriptions_of_connection() {
+        let mut store = EventSubscriptionStore::default();
+        store.subscribe(CLI_REQUEST_ID, vec![]);
+        store.subscribe(OTHER_CLI_REQUEST_ID, vec![]);
+        store.subscribe(WORKLOAD_REQUEST_ID, vec![]);
+
+        assert_eq!(
+            store.remove_subscriptions_of(AGENT_A),
+            vec![WORKLOAD_REQUEST_ID.to_string()]
+        );
+        assert_eq!(
+            store.remove_subscriptions_of("cli-conn-1"),
+            vec![CLI_REQUEST_ID.to_string()]
+        );
+        assert_eq!(
+            store
+                .request_ids_by_field_masks()
+                .into_values()
+                .collect::<Vec<_>>(),
+            vec![vec![OTHER_CLI_REQUEST_ID.to_string()]]
+        );
+    }
+
+    // [utest->swdd~server-removes-event-subscriptions-of-deleted-workloads~1]
+    #[test]
+    fn utest_event_subscription_store_remove_subscriptions_of_workload() {
+        let mut store = EventSubscriptionStore::default();
+        store.subscribe(CLI_REQUEST_ID, vec![]);
+        store.subscribe(WORKLOAD_REQUEST_ID, vec![]);
+        store.subscribe(OTHER_WORKLOAD_REQUEST_ID, vec![]);
+
+        let instance_name = WorkloadInstanceNameBuilder::default()
+            .agent_name(AGENT_A)
+            .workload_name(WORKLOAD_NAME_1)
+            .config(&"config".to_string())
+            .build();
+
+        assert_eq!(
+            store.remove_subscriptions_of_workload(&instance_name),
+            vec![WORKLOAD_REQUEST_ID.to_string()]
+        );
+        assert!(store.field_masks(WORKLOAD_REQUEST_ID).is_none());
+        assert!(store.field_masks(OTHER_WORKLOAD_REQUEST_ID).is_some());
+        assert!(store.field_masks(CLI_REQUEST_ID).is_some());
+    }
+
+    // [utest->swdd~server-event-subscription-store-detects-changed-field-masks~1]
+    #[test]
+    fn utest_event_subscription_store_changed_field_masks() {
+        let mut store = EventSubscriptionStore::default();
+        store.subscribe(
+            CLI_REQUEST_ID,
+            vec![
+                DESIRED_STATE_FIELD_MASK.to_string(),
+                WORKLOAD_STATES_FIELD_MASK.to_string(),
+            ],
+        );
+
+        let initial_state = complete_state(true, ExecutionState::running());
+        assert_eq!(
+            store.changed_field_masks(CLI_REQUEST_ID, &initial_state),
+            vec![
+                DESIRED_STATE_FIELD_MASK.to_string(),
+                WORKLOAD_STATES_FIELD_MASK.to_string()
+            ]
+        );
+        assert!(
+            store
+                .changed_field_masks(CLI_REQUEST_ID, &initial_state)
+                .is_empty()
+        );
+        assert_eq!(
+            store.changed_field_masks(
+                CLI_REQUEST_ID,
+                &complete_state(true, ExecutionState::succeeded())
+            ),
+            vec![WORKLOAD_STATES_FIELD_MASK.to_string()]
+        );
+        assert_eq!(
+            store.changed_field_masks(
+                CLI_REQUEST_ID,
+                &complete_state(false, ExecutionState::succeeded())
+            ),
+            vec![DESIRED_STATE_FIELD_MASK.to_string()]
+        );
+    }
+
+    // [utest->swdd~server-event-subscription-store-detects-changed-field-masks-with-wildcards~1]
+    #[test]
+    fn utest_event_subscription_store_changed_field_masks_with_wildcard() {
+        const WILDCARD_FIELD_MASK: &str = "desiredState.workloads.*.agent";
+        let mut store = EventSubscriptionStore::default();
+        store.subscribe(CLI_REQUEST_ID, vec![WILDCARD_FIELD_MASK.to_string()]);
+
+        let initial_state = complete_state(true, ExecutionState::running());
+        assert_eq!(
+            store.changed_field_masks(CLI_REQUEST_ID, &initial_state),
+            vec![WILDCARD_FIELD_MASK.to_string()]
+        );
+        assert!(
+            store
+                .changed_field_masks(CLI_REQUEST_ID, &initial_state)
+                .is_empty()
+        );
+        assert_eq!(
+            store.changed_field_masks(
+                CLI_REQUEST_ID,
+                &complete_state(false, ExecutionState::running())
+            ),
+            vec![WILDCARD_FIELD_MASK.to_string()]
+        );
+    }
+
+    // [utest->swdd~server-event-subscription-store-holds-subscriptions~1]
+    #[test]
+    fn utest_event_subscription_store_groups_request_ids_by_field_masks() {
+        let mut store = EventSubscriptionStore::default();
+        store.subscribe(CLI_REQUEST_ID, vec![]);
+        store.subscribe(OTHER_CLI_REQUEST_ID, vec![]);
+        store.subscribe(
+            WORKLOAD_REQUEST_ID,
+            vec![DESIRED_STATE_FIELD_MASK.to_string()],
+        );
+
+        let mut request_ids_by_field_masks = store.request_ids_by_field_masks();
+        assert_eq!(request_ids_by_field_masks.len(), 2);
+
+        let mut default_request_ids = request_ids_by_field_masks
+            .remove(&vec![
+                DESIRED_STATE_FIELD_MASK.to_string(),
+                WORKLOAD_STATES_FIELD_MASK.to_string(),
+            ])
+            .unwrap();
+        default_request_ids.sort();
+        assert_eq!(
+            default_request_ids,
+            vec![CLI_REQUEST_ID.to_string(), OTHER_CLI_REQUEST_ID.to_string()]
+        );
+        assert_eq!(
+            request_ids_by_field_masks.remove(&vec![DESIRED_STATE_FIELD_MASK.to_string()]),
+            Some(vec![WORKLOAD_REQUEST_ID.to_string()])
+        );
+    }
+
+    // [utest->swdd~server-event-subscription-store-detects-changed-field-masks~1]
+    #[test]
+    fn utest_event_subscription_store_changed_field_masks_unknown_subscription() {
+        let mut store = EventSubscriptionStore::default();
+
+        assert!(
+            store
+                .changed_field_masks(CLI_REQUEST_ID, &Object::default())
+                .is_empty()
+        );
+    }
+}
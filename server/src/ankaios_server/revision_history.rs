@@ -0,0 +1,487 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use api::ank_base;
+use common::objects::State;
+use std::collections::VecDeque;
+
+#[cfg(not(test))]
+fn current_timestamp() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+fn current_timestamp() -> u64 {
+    tests::TIMESTAMP
+}
+
+const REQUEST_ID_SEPARATOR: char = '@';
+
+#[derive(Debug, Clone, PartialEq)]
+struct Revision {
+    number: u64,
+    timestamp: u64,
+    requester: String,
+    update_mask: Vec<String>,
+    added_workloads: Vec<String>,
+    updated_workloads: Vec<String>,
+    deleted_workloads: Vec<String>,
+    rolled_back_workloads: Vec<String>,
+    desired_state: State,
+}
+
+impl Revision {
+    fn to_proto(&self, with_desired_state: bool) -> ank_base::Revision {
+        ank_base::Revision {
+            revision: self.number,
+            timestamp: self.timestamp,
+            requester: self.requester.clone(),
+            update_mask: self.update_mask.clone(),
+            added_workloads: self.added_workloads.clone(),
+            updated_workloads: self.updated_workloads.clone(),
+            deleted_workloads: self.deleted_workloads.clone(),
+            desired_state: with_desired_state.then(|| self.desired_state.clone().into()),
+            rolled_back_workloads: self.rolled_back_workloads.clone(),
+        }
+    }
+}
+
+// [impl->swdd~server-keeps-bounded-revision-history~1]
+pub struct RevisionHistory {
+    revisions: VecDeque<Revision>,
+    max_revisions: usize,
+    last_revision: u64,
+}
+
+impl Default for RevisionHistory {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_MAX_REVISIONS)
+    }
+}
+
+impl RevisionHistory {
+    pub const DEFAULT_MAX_REVISIONS: usize = 20;
+
+    pub fn new(max_revisions: usize) -> Self {
+        RevisionHistory {
+            revisions: VecDeque::with_capacity(max_revisions),
+            max_revisions: max_revisions.max(1),
+            last_revision: 0,
+        }
+    }
+
+    /// Records a new revision if the desired state changed and returns whether it did.
+    // [impl->swdd~server-records-revision-on-desired-state-change~2]
+    pub fn record(
+        &mut self,
+        request_id: &str,
+        update_mask: Vec<String>,
+        desired_state: &State,
+    ) -> bool {
+        let empty_state = State::default();
+        let previous_state = self
+            .revisions
+            .back()
+            .map_or(&empty_state, |revision| &revision.desired_state);
+
+        if !self.revisions.is_empty() && previous_state == desired_state {
+            log::debug!("The desired state did not change -> no new revision is recorded");
+            return false;
+        }
+
+        let mut added_workloads = Vec::new();
+        let mut updated_workloads = Vec::new();
+        for (workload_name, workload) in &desired_state.workloads {
+            match previous_state.workloads.get(workload_name) {
+                None => added_workloads.push(workload_name.clone()),
+                Some(previous_workload) if previous_workload != workload => {
+                    updated_workloads.push(workload_name.clone())
+                }
+                Some(_) => {}
+            }
+        }
+        let mut deleted_workloads: Vec<String> = previous_state
+            .workloads
+            .keys()
+            .filter(|workload_name| !desired_state.workloads.contains_key(*workload_name))
+            .cloned()
+            .collect();
+
+        // sort to ensure a consistent order independent of the map implementation
+        added_workloads.sort();
+        updated_workloads.sort();
+        deleted_workloads.sort();
+
+        // the replicas of a rolled back workload still do not match an unchanged workload
+        let rolled_back_workloads = self
+            .revisions
+            .back()
+            .map(|revision| {
+                revision
+                    .rolled_back_workloads
+                    .iter()
+                    .filter(|workload_name| {
+                        desired_state.workloads.get(*workload_name)
+                            == previous_state.workloads.get(*workload_name)
+                    })
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        self.last_revision += 1;
+        if self.revisions.len() == self.max_revisions {
+            self.revisions.pop_front();
+        }
+        self.revisions.push_back(Revision {
+            number: self.last_revision,
+            timestamp: current_timestamp(),
+            requester: requester_from_request_id(request_id),
+            update_mask,
+            added_workloads,
+            updated_workloads,
+            deleted_workloads,
+            rolled_back_workloads,
+            desired_state: desired_state.clone(),
+        });
+        log::debug!("Recorded revision '{}'", self.last_revision);
+        true
+    }
+
+    /// Starts the history again with the given revision of a persisted desired state.
+    // [impl->swdd~server-restores-current-revision-on-start~2]
+    pub fn restore(&mut self, revision: u64, request_id: &str, desired_state: &State) {
+        self.revisions.clear();
+        self.last_revision = revision.saturating_sub(1);
+        self.record(request_id, vec![], desired_state);
+    }
+
+    /// Continues the numbering after the given revision of a rejected persisted desired state.
+    // [impl->swdd~server-restores-current-revision-on-start~2]
+    pub fn continue_after(&mut self, revision: u64) {
+        self.revisions.clear();
+        self.last_revision = revision;
+    }
+
+    /// Marks the rolling update of the workload to the current revision as rolled back.
+    // [impl->swdd~server-records-rolled-back-rolling-updates~1]
+    pub fn mark_rolled_back(&mut self, workload_name: &str) {
+        let Some(revision) = self.revisions.back_mut() else {
+            return;
+        };
+        if !revision
+            .rolled_back_workloads
+            .iter()
+            .any(|rolled_back| rolled_back == workload_name)
+        {
+            revision
+                .rolled_back_workloads
+                .push(workload_name.to_owned());
+            revision.rolled_back_workloads.sort();
+        }
+    }
+
+    // [impl->swdd~server-provides-current-revision~1]
+    pub fn current_revision(&self) -> u64 {
+        self.last_revision
+    }
+
+    // [impl->swdd~server-provides-revisions~1]
+    pub fn get_revisions(&self) -> Vec<ank_base::Revision> {
+        self.revisions
+            .iter()
+            .map(|revision| revision.to_proto(false))
+            .collect()
+    }
+
+    // [impl->swdd~server-provides-revisions~1]
+    pub fn get_revision(&self, number: u64) -> Option<ank_base::Revision> {
+        self.revisions
+            .iter()
+            .find(|revision| revision.number == number)
+            .map(|revision| revision.to_proto(true))
+    }
+}
+
+// The request id of a CLI is prefixed with its connection name and the one of a workload with the
+// agent and workload name. The prefix identifies the requester.
+fn requester_from_request_id(request_id: &str) -> String {
+    request_id
+        .rsplit_once(REQUEST_ID_SEPARATOR)
+        .map_or(request_id, |(requester, _)| requester)
+        .to_string()
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{RevisionHistory, requester_from_request_id};
+    use common::objects::{State, generate_test_stored_workload_spec};
+
+    pub const TIMESTAMP: u64 = 1_700_000_000;
+
+    const CLI_REQUEST_ID: &str = "cli-conn-1@request_1";
+    const WORKLOAD_REQUEST_ID: &str = "agent_A@workload_1@request_2";
+    const AGENT_A: &str = "agent_A";
+    const WORKLOAD_NAME_1: &str = "workload_1";
+    const WORKLOAD_NAME_2: &str = "workload_2";
+    const WORKLOAD_NAME_3: &str = "workload_3";
+    const RUNTIME: &str = "runtime";
+
+    fn state_with_workloads(workloads: &[(&str, &str)]) -> State {
+        let mut state = State::default();
+        for (workload_name, agent_name) in workloads {
+            state.workloads.insert(
+                workload_name.to_string(),
+                generate_test_stored_workload_spec(*agent_name, RUNTIME),
+            );
+        }
+        state
+    }
+
+    // [utest->swdd~server-records-revision-on-desired-state-change~2]
+    #[test]
+    fn utest_revision_history_records_workload_diff() {
+        let mut revision_history = RevisionHistory::default();
+
+        let first_state =
+            state_with_workloads(&[(WORKLOAD_NAME_1, AGENT_A), (WORKLOAD_NAME_2, AGENT_A)]);
+        let second_state =
+            state_with_workloads(&[(WORKLOAD_NAME_2, "agent_B"), (WORKLOAD_NAME_3, AGENT_A)]);
+
+        revision_history.record(CLI_REQUEST_ID, vec![], &first_state);
+        revision_history.record(
+            WORKLOAD_REQUEST_ID,
+            vec!["desiredState.workloads".to_string()],
+            &second_state,
+        );
+
+        let revisions = revision_history.get_revisions();
+        assert_eq!(revisions.len(), 2);
+
+        assert_eq!(revisions[0].revision, 1);
+        assert_eq!(revisions[0].timestamp, TIMESTAMP);
+        assert_eq!(revisions[0].requester, "cli-conn-1");
+        assert_eq!(
+            revisions[0].added_workloads,
+            vec![WORKLOAD_NAME_1.to_string(), WORKLOAD_NAME_2.to_string()]
+        );
+        assert!(revisions[0].desired_state.is_none());
+
+        assert_eq!(revisions[1].revision, 2);
+        assert_eq!(revisions[1].requester, "agent_A@workload_1");
+        assert_eq!(
+            revisions[1].update_mask,
+            vec!["desiredState.workloads".to_string()]
+        );
+        assert_eq!(
+            revisions[1].added_workloads,
+            vec![WORKLOAD_NAME_3.to_string()]
+        );
+        assert_eq!(
+            revisions[1].updated_workloads,
+            vec![WORKLOAD_NAME_2.to_string()]
+        );
+        assert_eq!(
+            revisions[1].deleted_workloads,
+            vec![WORKLOAD_NAME_1.to_string()]
+        );
+    }
+
+    // [utest->swdd~server-restores-current-revision-on-start~2]
+    #[test]
+    fn utest_revision_history_continues_with_restored_revision() {
+        let mut revision_history = RevisionHistory::default();
+        let restored_state = state_with_workloads(&[(WORKLOAD_NAME_1, AGENT_A)]);
+        let new_state =
+            state_with_workloads(&[(WORKLOAD_NAME_1, AGENT_A), (WORKLOAD_NAME_2, AGENT_A)]);
+
+        revision_history.restore(7, CLI_REQUEST_ID, &restored_state);
+        assert_eq!(revision_history.current_revision(), 7);
+
+        assert!(!revision_history.record(CLI_REQUEST_ID, vec![], &restored_state));
+        assert!(revision_history.record(CLI_REQUEST_ID, vec![], &new_state));
+
+        assert_eq!(revision_history.current_revision(), 8);
+        let revisions = revision_history.get_revisions();
+        assert_eq!(revisions.len(), 2);
+        assert_eq!(revisions[0].revision, 7);
+        assert_eq!(
+            revisions[1].added_workloads,
+            vec![WORKLOAD_NAME_2.to_string()]
+        );
+    }
+
+    // [utest->swdd~server-restores-current-revision-on-start~2]
+    #[test]
+    fn utest_revision_history_continues_after_rejected_revision() {
+        let mut revision_history = RevisionHistory::default();
+        let new_state = state_with_workloads(&[(WORKLOAD_NAME_1, AGENT_A)]);
+
+        revision_history.continue_after(7);
+        assert_eq!(revision_history.current_revision(), 7);
+        assert!(revision_history.get_revisions().is_empty());
+
+        assert!(revision_history.record(CLI_REQUEST_ID, vec![], &new_state));
+
+        assert_eq!(revision_history.current_revision(), 8);
+        let revisions = revision_history.get_revisions();
+        assert_eq!(revisions.len(), 1);
+        assert_eq!(revisions[0].revision, 8);
+    }
+
+    // [utest->swdd~server-records-revision-on-desired-state-change~2]
+    #[test]
+    fn utest_revision_history_skips_unchanged_desired_state() {
+        let mut revision_history = RevisionHistory::default();
+        let state = state_with_workloads(&[(WORKLOAD_NAME_1, AGENT_A)]);
+
+        assert!(revision_history.record(CLI_REQUEST_ID, vec![], &state));
+        assert!(!revision_history.record(CLI_REQUEST_ID, vec![], &state));
+
+        assert_eq!(revision_history.get_revisions().len(), 1);
+    }
+
+    // [utest->swdd~server-records-revision-on-desired-state-change~2]
+    #[test]
+    fn utest_revision_history_records_initial_empty_state() {
+        let mut revision_history = RevisionHistory::default();
+
+        revision_history.record(CLI_REQUEST_ID, vec![], &State::default());
+
+        assert_eq!(revision_history.get_revisions().len(), 1);
+    }
+
+    // [utest->swdd~server-records-rolled-back-rolling-updates~1]
+    #[test]
+    fn utest_revision_history_keeps_rolled_back_workloads_until_changed() {
+        let mut revision_history = RevisionHistory::default();
+        let first_state =
+            state_with_workloads(&[(WORKLOAD_NAME_1, AGENT_A), (WORKLOAD_NAME_2, AGENT_A)]);
+        let second_state = state_with_workloads(&[
+            (WORKLOAD_NAME_1, AGENT_A),
+            (WORKLOAD_NAME_2, AGENT_A),
+            (WORKLOAD_NAME_3, AGENT_A),
+        ]);
+        let third_state = state_with_workloads(&[
+            (WORKLOAD_NAME_1, AGENT_A),
+            (WORKLOAD_NAME_2, "agent_B"),
+            (WORKLOAD_NAME_3, AGENT_A),
+        ]);
+
+        revision_history.record(CLI_REQUEST_ID, vec![], &first_state);
+        revision_history.mark_rolled_back(WORKLOAD_NAME_2);
+        revision_history.mark_rolled_back(WORKLOAD_NAME_1);
+        revision_history.mark_rolled_back(WORKLOAD_NAME_2);
+        revision_history.record(CLI_REQUEST_ID, vec![], &second_state);
+        revision_history.record(CLI_REQUEST_ID, vec![], &third_state);
+
+        let rolled_back_workloads: Vec<Vec<String>> = revision_history
+            .get_revisions()
+            .into_iter()
+            .map(|revision| revision.rolled_back_workloads)
+            .collect();
+        assert_eq!(
+            rolled_back_workloads,
+            vec![
+                vec![WORKLOAD_NAME_1.to_string(), WORKLOAD_NAME_2.to_string()],
+                vec![WORKLOAD_NAME_1.to_string(), WORKLOAD_NAME_2.to_string()],
+                vec![WORKLOAD_NAME_1.to_string()],
+            ]
+        );
+    }
+
+    // [utest->swdd~server-keeps-bounded-revision-history~1]
+    #[test]
+    fn utest_revision_history_drops_oldest_revision_when_full() {
+        let mut revision_history = RevisionHistory::new(2);
+
+        revision_history.record(
+            CLI_REQUEST_ID,
+            vec![],
+            &state_with_workloads(&[(WORKLOAD_NAME_1, AGENT_A)]),
+        );
+        revision_history.record(
+            CLI_REQUEST_ID,
+            vec![],
+            &state_with_workloads(&[(WORKLOAD_NAME_2, AGENT_A)]),
+        );
+        revision_history.record(
+            CLI_REQUEST_ID,
+            vec![],
+            &state_with_workloads(&[(WORKLOAD_NAME_3, AGENT_A)]),
+        );
+
+        let revision_numbers: Vec<u64> = revision_history
+            .get_revisions()
+            .iter()
+            .map(|revision| revision.revision)
+            .collect();
+        assert_eq!(revision_numbers, vec![2, 3]);
+        assert!(revision_history.get_revision(1).is_none());
+    }
+
+    // [utest->swdd~server-provides-current-revision~1]
+    #[test]
+    fn utest_revision_history_current_revision() {
+        let mut revision_history = RevisionHistory::new(1);
+        assert_eq!(revision_history.current_revision(), 0);
+
+        revision_history.record(
+            CLI_REQUEST_ID,
+            vec![],
+            &state_with_workloads(&[(WORKLOAD_NAME_1, AGENT_A)]),
+        );
+        revision_history.record(
+            CLI_REQUEST_ID,
+            vec![],
+            &state_with_workloads(&[(WORKLOAD_NAME_2, AGENT_A)]),
+        );
+
+        assert_eq!(revision_history.current_revision(), 2);
+    }
+
+    // [utest->swdd~server-provides-revisions~1]
+    #[test]
+    fn utest_revision_history_get_revision_contains_desired_state() {
+        let mut revision_history = RevisionHistory::default();
+        let state = state_with_workloads(&[(WORKLOAD_NAME_1, AGENT_A)]);
+
+        revision_history.record(CLI_REQUEST_ID, vec![], &state);
+
+        let revision = revision_history.get_revision(1).unwrap();
+        assert_eq!(revision.desired_state, Some(state.into()));
+        assert!(revision_history.get_revision(2).is_none());
+    }
+
+    #[test]
+    fn utest_requester_from_request_id() {
+        assert_eq!(requester_from_request_id(CLI_REQUEST_ID), "cli-conn-1");
+        assert_eq!(
+            requester_from_request_id(WORKLOAD_REQUEST_ID),
+            "agent_A@workload_1"
+        );
+        assert_eq!(requester_from_request_id("startup"), "startup");
+    }
+}
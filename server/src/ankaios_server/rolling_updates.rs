@@ -0,0 +1,682 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use super::config_renderer::RenderedWorkloads;
+use common::objects::{
+    DeletedWorkload, ExecutionStateEnum, RestartPolicy, StoredWorkloadSpec, WorkloadInstanceName,
+    WorkloadSpec, WorkloadState,
+};
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+
+// The update of a single replica replaces the old instance on an agent with the new one.
+struct ReplicaUpdate {
+    added_workload: WorkloadSpec,
+    deleted_workload: DeletedWorkload,
+    // the old instance is sent to the agent again if the rollout is rolled back
+    replaced_workload: Option<WorkloadSpec>,
+}
+
+struct StartedReplicaUpdate {
+    replica_update: ReplicaUpdate,
+    started_at: Instant,
+}
+
+#[derive(Default)]
+struct Rollout {
+    max_unavailable: usize,
+    // a new replica that is not running within this time rolls back the rollout
+    replica_timeout: Duration,
+    pending: VecDeque<ReplicaUpdate>,
+    in_progress: Vec<StartedReplicaUpdate>,
+}
+
+impl Rollout {
+    fn get_in_progress(&self, instance_name: &WorkloadInstanceName) -> Option<&WorkloadSpec> {
+        self.in_progress
+            .iter()
+            .map(|started| &started.replica_update.added_workload)
+            .find(|added_workload| &added_workload.instance_name == instance_name)
+    }
+
+    fn start(&mut self, replica_update: ReplicaUpdate, now: Instant) {
+        self.in_progress.push(StartedReplicaUpdate {
+            replica_update,
+            started_at: now,
+        });
+    }
+}
+
+// The workloads to send to the agents for the next step of the rollouts.
+#[derive(Debug, Default, PartialEq)]
+pub struct RolloutStep {
+    pub added_workloads: Vec<WorkloadSpec>,
+    pub deleted_workloads: Vec<DeletedWorkload>,
+    // the old instances that replace the new ones in the rendered workloads after a rollback
+    pub restored_workloads: Vec<WorkloadSpec>,
+    pub rolled_back_workloads: Vec<String>,
+}
+
+// [impl->swdd~server-state-sequences-rolling-updates~1]
+#[derive(Default)]
+pub struct RollingUpdates {
+    rollouts: HashMap<String, Rollout>,
+}
+
+impl RollingUpdates {
+    pub fn sequence(
+        &mut self,
+        workloads: &HashMap<String, StoredWorkloadSpec>,
+        current_workloads: &RenderedWorkloads,
+        added_workloads: Vec<WorkloadSpec>,
+        mut deleted_workloads: Vec<DeletedWorkload>,
+    ) -> (Vec<WorkloadSpec>, Vec<DeletedWorkload>) {
+        // A pending new replica was never sent to its agent, so the old instance is the one to delete.
+        let mut replaced_workloads_of_pending = Vec::new();
+        for deleted_workload in deleted_workloads.iter_mut() {
+            let workload_name = deleted_workload.instance_name.workload_name().to_owned();
+            if let Some(rollout) = self.rollouts.get_mut(&workload_name) {
+                rollout.in_progress.retain(|started| {
+                    started.replica_update.added_workload.instance_name
+                        != deleted_workload.instance_name
+                });
+                if let Some(replaced_update) = rollout
+                    .pending
+                    .iter()
+                    .position(|update| {
+                        update.added_workload.instance_name == deleted_workload.instance_name
+                    })
+                    .and_then(|index| rollout.pending.remove(index))
+                {
+                    *deleted_workload = replaced_update.deleted_workload;
+                    replaced_workloads_of_pending.extend(replaced_update.replaced_workload);
+                }
+            }
+        }
+
+        let mut immediate_added_workloads = Vec::new();
+        let mut replica_updates = Vec::new();
+        for added_workload in added_workloads {
+            let workload_name = added_workload.instance_name.workload_name();
+            let rolling = workloads
+                .get(workload_name)
+                .and_then(|workload| workload.update_strategy.as_ref())
+                .and_then(|update_strategy| update_strategy.rolling.as_ref());
+            let replaced_index = deleted_workloads.iter().position(|deleted_workload| {
+                deleted_workload.instance_name.workload_name() == workload_name
+                    && deleted_workload.instance_name.agent_name()
+                        == added_workload.instance_name.agent_name()
+            });
+
+            match (rolling, replaced_index) {
+                (Some(rolling), Some(index)) => {
+                    let rollout = self.rollouts.entry(workload_name.to_owned()).or_default();
+                    rollout.max_unavailable = rolling.max_unavailable as usize;
+                    rollout.replica_timeout = Duration::from_millis(rolling.replica_timeout_millis);
+                    let deleted_workload = deleted_workloads.swap_remove(index);
+                    let replaced_workload = replaced_workloads_of_pending
+                        .iter()
+                        .chain(current_workloads.values())
+                        .find(|workload| workload.instance_name == deleted_workload.instance_name)
+                        .cloned();
+                    replica_updates.push(ReplicaUpdate {
+                        deleted_workload,
+                        added_workload,
+                        replaced_workload,
+                    });
+                }
+                _ => immediate_added_workloads.push(added_workload),
+            }
+        }
+
+        replica_updates.sort_by(|left, right| {
+            left.added_workload
+                .instance_name
+                .workload_name()
+                .cmp(right.added_workload.instance_name.workload_name())
+                .then_with(|| {
+                    left.added_workload
+                        .instance_name
+                        .agent_name()
+                        .cmp(right.added_workload.instance_name.agent_name())
+                })
+        });
+        for replica_update in replica_updates {
+            if let Some(rollout) = self
+                .rollouts
+                .get_mut(replica_update.added_workload.instance_name.workload_name())
+            {
+                rollout.pending.push_back(replica_update);
+            }
+        }
+
+        let step = self.start_next_replica_updates(Instant::now());
+        immediate_added_workloads.extend(step.added_workloads);
+        deleted_workloads.extend(step.deleted_workloads);
+        (immediate_added_workloads, deleted_workloads)
+    }
+
+    // [impl->swdd~server-state-continues-rolling-updates~2]
+    // [impl->swdd~server-state-rolls-back-stalled-rolling-updates~2]
+    pub fn continue_with(
+        &mut self,
+        workload_states: &[WorkloadState],
+        now: Instant,
+    ) -> RolloutStep {
+        let mut rolled_back = Vec::new();
+        for workload_state in workload_states {
+            let workload_name = workload_state.instance_name.workload_name();
+            let Some(rollout) = self.rollouts.get_mut(workload_name) else {
+                continue;
+            };
+            let Some(added_workload) = rollout.get_in_progress(&workload_state.instance_name)
+            else {
+                continue;
+            };
+
+            // a workload which is not restarted always is expected to run to completion
+            let runs_to_completion = added_workload.restart_policy != RestartPolicy::Always;
+            match workload_state.execution_state.state {
+                _ if workload_state.execution_state.is_running() => {
+                    rollout.in_progress.retain(|started| {
+                        started.replica_update.added_workload.instance_name
+                            != workload_state.instance_name
+                    });
+                }
+                ExecutionStateEnum::Succeeded(_) if runs_to_completion => {
+                    rollout.in_progress.retain(|started| {
+                        started.replica_update.added_workload.instance_name
+                            != workload_state.instance_name
+                    });
+                }
+                ExecutionStateEnum::Failed(_) | ExecutionStateEnum::AgentDisconnected => {
+                    log::warn!(
+                        "Rolling back the update of workload '{workload_name}' as its new replica on agent '{}' is '{}'",
+                        workload_state.instance_name.agent_name(),
+                        workload_state.execution_state.state
+                    );
+                    rolled_back.push(workload_name.to_owned());
+                }
+                _ => {}
+            }
+        }
+
+        for (workload_name, rollout) in &self.rollouts {
+            if rollout
+                .in_progress
+                .iter()
+                .any(|started| now.duration_since(started.started_at) >= rollout.replica_timeout)
+            {
+                log::warn!(
+                    "Rolling back the update of workload '{workload_name}' as its new replica is not running after {} milliseconds",
+                    rollout.replica_timeout.as_millis()
+                );
+                rolled_back.push(workload_name.clone());
+            }
+        }
+
+        let mut step = RolloutStep::default();
+        for workload_name in rolled_back {
+            if let Some(rollout) = self.rollouts.remove(&workload_name) {
+                Self::roll_back(rollout, &mut step);
+                step.rolled_back_workloads.push(workload_name);
+            }
+        }
+
+        let next_step = self.start_next_replica_updates(now);
+        step.added_workloads.extend(next_step.added_workloads);
+        step.deleted_workloads.extend(next_step.deleted_workloads);
+        step
+    }
+
+    // A connecting agent receives the new replicas with the server hello, so its pending updates
+    // are started as long as the rollout has budget left.
+    pub fn start_pending_of_agent(&mut self, agent_name: &str) {
+        let now = Instant::now();
+        for rollout in self.rollouts.values_mut() {
+            while rollout.in_progress.len() < rollout.max_unavailable {
+                let Some(index) = rollout.pending.iter().position(|update| {
+                    update.added_workload.instance_name.agent_name() == agent_name
+                }) else {
+                    break;
+                };
+                if let Some(replica_update) = rollout.pending.remove(index) {
+                    rollout.start(replica_update, now);
+                }
+            }
+        }
+    }
+
+    // A pending new replica is not sent to its agent yet, so the agent keeps the old instance.
+    pub fn get_replaced_workload_of_pending(
+        &self,
+        instance_name: &WorkloadInstanceName,
+    ) -> Option<&WorkloadSpec> {
+        self.rollouts
+            .get(instance_name.workload_name())?
+            .pending
+            .iter()
+            .find(|update| &update.added_workload.instance_name == instance_name)?
+            .replaced_workload
+            .as_ref()
+    }
+
+    // The started replicas go back to their old instance, the pending ones never left it.
+    fn roll_back(rollout: Rollout, step: &mut RolloutStep) {
+        for started in rollout.in_progress {
+            let replica_update = started.replica_update;
+            if let Some(replaced_workload) = replica_update.replaced_workload {
+                step.deleted_workloads.push(DeletedWorkload {
+                    instance_name: replica_update.added_workload.instance_name,
+                    ..Default::default()
+                });
+                step.added_workloads.push(replaced_workload.clone());
+                step.restored_workloads.push(replaced_workload);
+            }
+        }
+        step.restored_workloads.extend(
+            rollout
+                .pending
+                .into_iter()
+                .filter_map(|replica_update| replica_update.replaced_workload),
+        );
+    }
+
+    fn start_next_replica_updates(&mut self, now: Instant) -> RolloutStep {
+        let mut step = RolloutStep::default();
+        for rollout in self.rollouts.values_mut() {
+            while rollout.in_progress.len() < rollout.max_unavailable {
+                let Some(replica_update) = rollout.pending.pop_front() else {
+                    break;
+                };
+                step.added_workloads
+                    .push(replica_update.added_workload.clone());
+                step.deleted_workloads
+                    .push(replica_update.deleted_workload.clone());
+                rollout.start(replica_update, now);
+            }
+        }
+        self.rollouts
+            .retain(|_, rollout| !rollout.pending.is_empty() || !rollout.in_progress.is_empty());
+        step
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{RollingUpdates, RolloutStep};
+    use crate::ankaios_server::config_renderer::RenderedWorkloads;
+    use common::objects::{
+        DeletedWorkload, ExecutionState, RestartPolicy, RollingUpdate, StoredWorkloadSpec,
+        UpdateStrategy, WorkloadInstanceName, WorkloadSpec,
+        generate_test_workload_spec_with_runtime_config,
+        generate_test_workload_state_with_workload_spec,
+    };
+    use std::collections::HashMap;
+    use std::time::{Duration, Instant};
+
+    const AGENT_A: &str = "agent_A";
+    const AGENT_B: &str = "agent_B";
+    const AGENT_C: &str = "agent_C";
+    const WORKLOAD_NAME: &str = "workload_1";
+    const RUNTIME: &str = "runtime";
+    const REPLICA_TIMEOUT_MILLIS: u64 = 1000;
+
+    fn generate_replica(agent_name: &str, runtime_config: &str) -> WorkloadSpec {
+        generate_test_workload_spec_with_runtime_config(
+            agent_name.to_owned(),
+            WORKLOAD_NAME.to_owned(),
+            RUNTIME.to_owned(),
+            runtime_config.to_owned(),
+        )
+    }
+
+    fn deleted(workload: &WorkloadSpec) -> DeletedWorkload {
+        DeletedWorkload {
+            instance_name: workload.instance_name.clone(),
+            ..Default::default()
+        }
+    }
+
+    fn rendered(workloads: &[&WorkloadSpec]) -> RenderedWorkloads {
+        workloads
+            .iter()
+            .map(|workload| {
+                (
+                    format!("{WORKLOAD_NAME}.{}", workload.instance_name.agent_name()),
+                    (*workload).clone(),
+                )
+            })
+            .collect()
+    }
+
+    fn in_progress(rolling_updates: &RollingUpdates) -> Option<Vec<WorkloadInstanceName>> {
+        rolling_updates.rollouts.get(WORKLOAD_NAME).map(|rollout| {
+            rollout
+                .in_progress
+                .iter()
+                .map(|started| started.replica_update.added_workload.instance_name.clone())
+                .collect()
+        })
+    }
+
+    fn generate_rolling_workloads(max_unavailable: u32) -> HashMap<String, StoredWorkloadSpec> {
+        HashMap::from([(
+            WORKLOAD_NAME.to_owned(),
+            StoredWorkloadSpec {
+                agents: vec![AGENT_A.into(), AGENT_B.into(), AGENT_C.into()],
+                update_strategy: Some(UpdateStrategy {
+                    rolling: Some(RollingUpdate {
+                        max_unavailable,
+                        replica_timeout_millis: REPLICA_TIMEOUT_MILLIS,
+                    }),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+        )])
+    }
+
+    // [utest->swdd~server-state-sequences-rolling-updates~1]
+    // [utest->swdd~server-state-continues-rolling-updates~2]
+    #[test]
+    fn utest_rolling_updates_start_next_replica_update_when_new_replica_is_running() {
+        let mut rolling_updates = RollingUpdates::default();
+        let agents = [AGENT_A, AGENT_B, AGENT_C];
+        let old_replicas: Vec<WorkloadSpec> = agents
+            .iter()
+            .map(|agent| generate_replica(agent, "old config"))
+            .collect();
+        let new_replicas: Vec<WorkloadSpec> = agents
+            .iter()
+            .map(|agent| generate_replica(agent, "new config"))
+            .collect();
+
+        let (added_workloads, deleted_workloads) = rolling_updates.sequence(
+            &generate_rolling_workloads(1),
+            &rendered(&old_replicas.iter().collect::<Vec<_>>()),
+            new_replicas.iter().rev().cloned().collect(),
+            old_replicas.iter().map(deleted).collect(),
+        );
+        assert_eq!(added_workloads, vec![new_replicas[0].clone()]);
+        assert_eq!(deleted_workloads, vec![deleted(&old_replicas[0])]);
+
+        let step = rolling_updates.continue_with(
+            &[generate_test_workload_state_with_workload_spec(
+                &new_replicas[0],
+                ExecutionState::starting("starting"),
+            )],
+            Instant::now(),
+        );
+        assert_eq!(step, RolloutStep::default());
+
+        let step = rolling_updates.continue_with(
+            &[generate_test_workload_state_with_workload_spec(
+                &new_replicas[0],
+                ExecutionState::running(),
+            )],
+            Instant::now(),
+        );
+        assert_eq!(
+            step,
+            RolloutStep {
+                added_workloads: vec![new_replicas[1].clone()],
+                deleted_workloads: vec![deleted(&old_replicas[1])],
+                ..Default::default()
+            }
+        );
+    }
+
+    // [utest->swdd~server-state-sequences-rolling-updates~1]
+    #[test]
+    fn utest_rolling_updates_start_replica_updates_up_to_max_unavailable() {
+        let mut rolling_updates = RollingUpdates::default();
+        let old_replica_a = generate_replica(AGENT_A, "old config");
+        let old_replica_b = generate_replica(AGENT_B, "old config");
+        let new_replica_a = generate_replica(AGENT_A, "new config");
+        let new_replica_b = generate_replica(AGENT_B, "new config");
+        let added_replica_c = generate_replica(AGENT_C, "new config");
+
+        let (added_workloads, deleted_workloads) = rolling_updates.sequence(
+            &generate_rolling_workloads(2),
+            &rendered(&[&old_replica_a, &old_replica_b]),
+            vec![
+                new_replica_b.clone(),
+                added_replica_c.clone(),
+                new_replica_a.clone(),
+            ],
+            vec![deleted(&old_replica_a), deleted(&old_replica_b)],
+        );
+
+        assert_eq!(
+            added_workloads,
+            vec![added_replica_c, new_replica_a, new_replica_b]
+        );
+        assert_eq!(
+            deleted_workloads,
+            vec![deleted(&old_replica_a), deleted(&old_replica_b)]
+        );
+    }
+
+    // [utest->swdd~server-state-sequences-rolling-updates~1]
+    #[test]
+    fn utest_rolling_updates_delete_old_replica_instead_of_pending_new_replica() {
+        let mut rolling_updates = RollingUpdates::default();
+        let old_replica_a = generate_replica(AGENT_A, "old config");
+        let old_replica_b = generate_replica(AGENT_B, "old config");
+        let new_replica_a = generate_replica(AGENT_A, "new config");
+        let new_replica_b = generate_replica(AGENT_B, "new config");
+
+        rolling_updates.sequence(
+            &generate_rolling_workloads(1),
+            &rendered(&[&old_replica_a, &old_replica_b]),
+            vec![new_replica_a.clone(), new_replica_b.clone()],
+            vec![deleted(&old_replica_a), deleted(&old_replica_b)],
+        );
+
+        let (added_workloads, deleted_workloads) = rolling_updates.sequence(
+            &HashMap::new(),
+            &rendered(&[&new_replica_a, &new_replica_b]),
+            vec![],
+            vec![deleted(&new_replica_a), deleted(&new_replica_b)],
+        );
+
+        assert!(added_workloads.is_empty());
+        assert_eq!(
+            deleted_workloads,
+            vec![deleted(&new_replica_a), deleted(&old_replica_b)]
+        );
+        assert!(rolling_updates.rollouts.is_empty());
+    }
+
+    // [utest->swdd~server-state-sequences-rolling-updates~1]
+    #[test]
+    fn utest_rolling_updates_start_pending_updates_of_connecting_agent_within_max_unavailable() {
+        let mut rolling_updates = RollingUpdates::default();
+        let old_replica_a = generate_replica(AGENT_A, "old config");
+        let old_replica_b = generate_replica(AGENT_B, "old config");
+        let new_replica_a = generate_replica(AGENT_A, "new config");
+        let new_replica_b = generate_replica(AGENT_B, "new config");
+
+        rolling_updates.sequence(
+            &generate_rolling_workloads(1),
+            &rendered(&[&old_replica_a, &old_replica_b]),
+            vec![new_replica_a.clone(), new_replica_b.clone()],
+            vec![deleted(&old_replica_a), deleted(&old_replica_b)],
+        );
+
+        // the update of agent_A uses the whole budget, so agent_B keeps its old replica
+        rolling_updates.start_pending_of_agent(AGENT_B);
+        assert_eq!(
+            in_progress(&rolling_updates),
+            Some(vec![new_replica_a.instance_name.clone()])
+        );
+        assert_eq!(
+            rolling_updates.get_replaced_workload_of_pending(&new_replica_b.instance_name),
+            Some(&old_replica_b)
+        );
+
+        let step = rolling_updates.continue_with(
+            &[generate_test_workload_state_with_workload_spec(
+                &new_replica_a,
+                ExecutionState::running(),
+            )],
+            Instant::now(),
+        );
+        assert_eq!(step.added_workloads, vec![new_replica_b.clone()]);
+        assert_eq!(
+            in_progress(&rolling_updates),
+            Some(vec![new_replica_b.instance_name])
+        );
+    }
+
+    // [utest->swdd~server-state-rolls-back-stalled-rolling-updates~2]
+    #[test]
+    fn utest_rolling_updates_roll_back_when_new_replica_does_not_run() {
+        let old_replica_a = generate_replica(AGENT_A, "old config");
+        let old_replica_b = generate_replica(AGENT_B, "old config");
+        let new_replica_a = generate_replica(AGENT_A, "new config");
+        let new_replica_b = generate_replica(AGENT_B, "new config");
+
+        for execution_state in [
+            ExecutionState::failed("failed"),
+            ExecutionState::agent_disconnected(),
+        ] {
+            let mut rolling_updates = RollingUpdates::default();
+            rolling_updates.sequence(
+                &generate_rolling_workloads(1),
+                &rendered(&[&old_replica_a, &old_replica_b]),
+                vec![new_replica_a.clone(), new_replica_b.clone()],
+                vec![deleted(&old_replica_a), deleted(&old_replica_b)],
+            );
+
+            let step = rolling_updates.continue_with(
+                &[generate_test_workload_state_with_workload_spec(
+                    &new_replica_a,
+                    execution_state,
+                )],
+                Instant::now(),
+            );
+
+            assert_eq!(
+                step,
+                RolloutStep {
+                    added_workloads: vec![old_replica_a.clone()],
+                    deleted_workloads: vec![deleted(&new_replica_a)],
+                    restored_workloads: vec![old_replica_a.clone(), old_replica_b.clone()],
+                    rolled_back_workloads: vec![WORKLOAD_NAME.to_owned()],
+                }
+            );
+            assert!(rolling_updates.rollouts.is_empty());
+        }
+    }
+
+    // [utest->swdd~server-state-continues-rolling-updates~2]
+    // [utest->swdd~server-state-rolls-back-stalled-rolling-updates~2]
+    #[test]
+    fn utest_rolling_updates_succeeded_replica_depends_on_restart_policy() {
+        let old_replica_a = generate_replica(AGENT_A, "old config");
+        let old_replica_b = generate_replica(AGENT_B, "old config");
+        let mut new_replica_a = generate_replica(AGENT_A, "new config");
+        let mut new_replica_b = generate_replica(AGENT_B, "new config");
+
+        // a succeeded replica that is restarted always is neither done nor rolled back
+        let mut rolling_updates = RollingUpdates::default();
+        rolling_updates.sequence(
+            &generate_rolling_workloads(1),
+            &rendered(&[&old_replica_a, &old_replica_b]),
+            vec![new_replica_a.clone(), new_replica_b.clone()],
+            vec![deleted(&old_replica_a), deleted(&old_replica_b)],
+        );
+        let step = rolling_updates.continue_with(
+            &[generate_test_workload_state_with_workload_spec(
+                &new_replica_a,
+                ExecutionState::succeeded(),
+            )],
+            Instant::now(),
+        );
+        assert_eq!(step, RolloutStep::default());
+        assert_eq!(
+            in_progress(&rolling_updates),
+            Some(vec![new_replica_a.instance_name.clone()])
+        );
+
+        // a succeeded replica that runs to completion is done
+        new_replica_a.restart_policy = RestartPolicy::OnFailure;
+        new_replica_b.restart_policy = RestartPolicy::OnFailure;
+        let mut rolling_updates = RollingUpdates::default();
+        rolling_updates.sequence(
+            &generate_rolling_workloads(1),
+            &rendered(&[&old_replica_a, &old_replica_b]),
+            vec![new_replica_a.clone(), new_replica_b.clone()],
+            vec![deleted(&old_replica_a), deleted(&old_replica_b)],
+        );
+        let step = rolling_updates.continue_with(
+            &[generate_test_workload_state_with_workload_spec(
+                &new_replica_a,
+                ExecutionState::succeeded(),
+            )],
+            Instant::now(),
+        );
+        assert_eq!(
+            step,
+            RolloutStep {
+                added_workloads: vec![new_replica_b.clone()],
+                deleted_workloads: vec![deleted(&old_replica_b)],
+                ..Default::default()
+            }
+        );
+    }
+
+    // [utest->swdd~server-state-rolls-back-stalled-rolling-updates~2]
+    #[test]
+    fn utest_rolling_updates_roll_back_when_replica_update_times_out() {
+        let mut rolling_updates = RollingUpdates::default();
+        let old_replica_a = generate_replica(AGENT_A, "old config");
+        let new_replica_a = generate_replica(AGENT_A, "new config");
+
+        rolling_updates.sequence(
+            &generate_rolling_workloads(1),
+            &rendered(&[&old_replica_a]),
+            vec![new_replica_a.clone()],
+            vec![deleted(&old_replica_a)],
+        );
+
+        let step = rolling_updates.continue_with(&[], Instant::now());
+        assert_eq!(step, RolloutStep::default());
+
+        let step = rolling_updates.continue_with(
+            &[],
+            Instant::now() + Duration::from_millis(REPLICA_TIMEOUT_MILLIS),
+        );
+        assert_eq!(
+            step,
+            RolloutStep {
+                added_workloads: vec![old_replica_a.clone()],
+                deleted_workloads: vec![deleted(&new_replica_a)],
+                restored_workloads: vec![old_replica_a],
+                rolled_back_workloads: vec![WORKLOAD_NAME.to_owned()],
+            }
+        );
+        assert!(rolling_updates.rollouts.is_empty());
+    }
+}
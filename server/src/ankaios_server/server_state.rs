@@ -22,9 +22,11 @@ use super::config_renderer::ConfigRenderer;
 use super::cycle_check;
 #[cfg_attr(test, mockall_double::double)]
 use super::delete_graph::DeleteGraph;
+use super::rolling_updates::{RollingUpdates, RolloutStep};
+use super::workload_state_history::WorkloadStateHistory;
 use common::objects::{
-    AgentAttributes, CpuUsage, FreeMemory, State, WorkloadInstanceName, WorkloadState,
-    WorkloadStatesMap,
+    AgentAttributes, ResourceRequests, State, StoredWorkloadSpec, WorkloadInstanceName,
+    WorkloadState, WorkloadStatesMap,
 };
 use common::std_extensions::IllegalStateResult;
 use common::{
@@ -32,7 +34,9 @@ use common::{
     objects::{CompleteState, DeletedWorkload, WorkloadSpec},
     state_manipulation::{Object, Path},
 };
+use std::collections::HashMap;
 use std::fmt::Display;
+use std::time::Instant;
 
 #[cfg(test)]
 use mockall::automock;
@@ -112,14 +116,24 @@ pub struct ServerState {
     rendered_workloads: RenderedWorkloads,
     delete_graph: DeleteGraph,
     config_renderer: ConfigRenderer,
+    rolling_updates: RollingUpdates,
+    reserved_resources: ReservedResources,
 }
 
+// The resources requested by the workloads placed on an agent since its last load report.
+type ReservedResources = HashMap<String, ResourceRequests>;
+
 pub type AddedDeletedWorkloads = Option<(Vec<WorkloadSpec>, Vec<DeletedWorkload>)>;
 
+// The next replica updates of the rolling updates and the names of the rolled back workloads.
+pub type RollingUpdatesStep = (AddedDeletedWorkloads, Vec<String>);
+
 #[cfg_attr(test, automock)]
 impl ServerState {
     const API_VERSION_FILTER_MASK: &'static str = "desiredState.apiVersion";
     const DESIRED_STATE_FIELD_MASK_PART: &'static str = "desiredState";
+    const RENDERED_WORKLOADS_FIELD_MASK_PART: &'static str = "renderedWorkloads";
+    const WORKLOAD_STATE_HISTORY_FIELD_MASK_PART: &'static str = "workloadStateHistory";
 
     // [impl->swdd~server-provides-interface-get-complete-state~2]
     // [impl->swdd~server-filters-get-complete-state-result~2]
@@ -127,11 +141,13 @@ impl ServerState {
         &self,
         request_complete_state: CompleteStateRequest,
         workload_states_map: &WorkloadStatesMap,
+        workload_state_history: &WorkloadStateHistory,
     ) -> Result<ank_base::CompleteState, String> {
-        let current_complete_state: ank_base::CompleteState = CompleteState {
+        let mut current_complete_state: ank_base::CompleteState = CompleteState {
             desired_state: self.state.desired_state.clone(),
             workload_states: workload_states_map.clone(),
             agents: self.state.agents.clone(),
+            workload_metrics: self.state.workload_metrics.clone(),
         }
         .into();
 
@@ -144,21 +160,43 @@ impl ServerState {
                 filters.push(Self::API_VERSION_FILTER_MASK.to_owned());
             }
 
+            // [impl->swdd~server-provides-rendered-workloads~1]
+            if filters
+                .iter()
+                .any(|field| field.starts_with(Self::RENDERED_WORKLOADS_FIELD_MASK_PART))
+            {
+                current_complete_state.rendered_workloads =
+                    self.get_rendered_workloads().map(Box::new);
+            }
+
+            // [impl->swdd~server-provides-workload-state-history~1]
+            if filters
+                .iter()
+                .any(|field| field.starts_with(Self::WORKLOAD_STATE_HISTORY_FIELD_MASK_PART))
+            {
+                current_complete_state.workload_state_history =
+                    workload_state_history.to_proto().map(Box::new);
+            }
+
             let current_complete_state: Object =
                 current_complete_state.try_into().unwrap_or_illegal_state();
             let mut return_state = Object::default();
 
             log::debug!("Current state: {current_complete_state:?}");
-            for field in &filters {
-                if let Some(value) = current_complete_state.get(&field.into()) {
-                    return_state.set(&field.into(), value.to_owned())?;
+            // [impl->swdd~server-filters-get-complete-state-result-with-wildcards~1]
+            for field in filters
+                .iter()
+                .flat_map(|field| current_complete_state.expand_wildcards(&field.into()))
+            {
+                if let Some(value) = current_complete_state.get(&field) {
+                    return_state.set(&field, value.to_owned())?;
                 } else {
                     log::debug!(
                         concat!(
                             "Result for CompleteState incomplete, as requested field does not exist:\n",
                             "   field: {}"
                         ),
-                        field
+                        String::from(field)
                     );
                     continue;
                 };
@@ -172,23 +210,68 @@ impl ServerState {
         }
     }
 
+    fn get_rendered_workloads(&self) -> Option<ank_base::RenderedWorkloadMap> {
+        if self.rendered_workloads.is_empty() {
+            return None;
+        }
+
+        let mut rendered_workload_map = ank_base::RenderedWorkloadMap::default();
+        for workload in self.rendered_workloads.values() {
+            rendered_workload_map
+                .workloads
+                .entry(workload.instance_name.workload_name().to_owned())
+                .or_default()
+                .replicas
+                .push(ank_base::RenderedWorkload {
+                    agent: workload.instance_name.agent_name().to_owned(),
+                    runtime_config: workload.runtime_config.clone(),
+                    files: Some(ank_base::Files {
+                        files: workload.files.iter().cloned().map(Into::into).collect(),
+                    }),
+                });
+        }
+
+        // sort to ensure a consistent order independent of the map implementation
+        rendered_workload_map
+            .workloads
+            .values_mut()
+            .for_each(|rendered_workloads| {
+                rendered_workloads
+                    .replicas
+                    .sort_by(|left, right| left.agent.cmp(&right.agent))
+            });
+        Some(rendered_workload_map)
+    }
+
     // [impl->swdd~agent-from-agent-field~1]
     pub fn get_workloads_for_agent(&self, agent_name: &str) -> Vec<WorkloadSpec> {
         self.rendered_workloads
             .iter()
             .filter(|(_, workload)| workload.instance_name.agent_name().eq(agent_name))
-            .map(|(_, workload)| workload.clone())
+            // [impl->swdd~server-state-continues-rolling-updates~2]
+            .map(|(_, workload)| {
+                self.rolling_updates
+                    .get_replaced_workload_of_pending(&workload.instance_name)
+                    .unwrap_or(workload)
+                    .clone()
+            })
             .collect()
     }
 
+    // [impl->swdd~server-state-store-persists-desired-state~1]
+    pub fn get_desired_state(&self) -> &State {
+        &self.state.desired_state
+    }
+
     // [impl->swdd~server-handles-logs-request-message~1]
     pub fn desired_state_contains_instance_name(
         &self,
         instance_name: &WorkloadInstanceName,
     ) -> bool {
+        // the replicas of a workload share the workload name
         self.rendered_workloads
-            .get(instance_name.workload_name())
-            .is_some_and(|workload_spec| workload_spec.instance_name == *instance_name)
+            .values()
+            .any(|workload_spec| workload_spec.instance_name == *instance_name)
     }
 
     pub fn update(
@@ -196,84 +279,169 @@ impl ServerState {
         new_state: CompleteState,
         update_mask: Vec<String>,
     ) -> Result<AddedDeletedWorkloads, UpdateStateError> {
+        let (new_desired_state, new_rendered_workloads, new_reserved_resources, cmd) =
+            self.compute_update(new_state, update_mask)?;
+
+        if let Some((added_workloads, deleted_workloads)) = cmd {
+            // [impl->swdd~server-state-sequences-rolling-updates~1]
+            let (added_workloads, mut deleted_workloads) = self.rolling_updates.sequence(
+                &new_desired_state.workloads,
+                &self.rendered_workloads,
+                added_workloads,
+                deleted_workloads,
+            );
+
+            // [impl->swdd~server-state-stores-delete-condition~2]
+            self.delete_graph.insert(&added_workloads);
+
+            // [impl->swdd~server-state-adds-delete-conditions-to-deleted-workload~1]
+            self.delete_graph
+                .apply_delete_conditions_to(&mut deleted_workloads);
+
+            self.set_desired_state(new_desired_state);
+            self.rendered_workloads = new_rendered_workloads;
+            self.reserved_resources = new_reserved_resources;
+            Ok(Some((added_workloads, deleted_workloads)))
+        } else {
+            // update state with changed fields not affecting workloads, e.g. config items
+            // [impl->swdd~server-state-updates-state-on-unmodified-workloads~1]
+            self.set_desired_state(new_desired_state);
+            Ok(None)
+        }
+    }
+
+    // [impl->swdd~server-state-computes-update-without-applying-it~1]
+    pub fn dry_run_update(
+        &self,
+        new_state: CompleteState,
+        update_mask: Vec<String>,
+    ) -> Result<AddedDeletedWorkloads, UpdateStateError> {
+        let (_, _, _, cmd) = self.compute_update(new_state, update_mask)?;
+        Ok(cmd)
+    }
+
+    fn compute_update(
+        &self,
+        new_state: CompleteState,
+        update_mask: Vec<String>,
+    ) -> Result<
+        (
+            State,
+            RenderedWorkloads,
+            ReservedResources,
+            AddedDeletedWorkloads,
+        ),
+        UpdateStateError,
+    > {
         // [impl->swdd~update-desired-state-with-update-mask~1]
         // [impl->swdd~update-desired-state-empty-update-mask~1]
-        match self.generate_new_state(new_state, update_mask) {
-            Ok(new_templated_state) => {
-                // [impl->swdd~server-state-triggers-configuration-rendering-of-workloads~1]
-                let new_rendered_workloads = self
-                    .config_renderer
-                    .render_workloads(
-                        &new_templated_state.desired_state.workloads,
-                        &new_templated_state.desired_state.configs,
-                    )
-                    .map_err(|err| UpdateStateError::ResultInvalid(err.to_string()))?;
-
-                // [impl->swdd~server-state-triggers-validation-of-workload-fields~1]
-                self.verify_workload_fields_format(&new_rendered_workloads)?;
-
-                // [impl->swdd~server-state-compares-rendered-workloads~1]
-                let cmd = extract_added_and_deleted_workloads(
-                    &self.rendered_workloads,
-                    &new_rendered_workloads,
-                );
-
-                if let Some((added_workloads, mut deleted_workloads)) = cmd {
-                    let start_nodes: Vec<&str> = added_workloads
-                        .iter()
-                        .filter_map(|w| {
-                            if !w.dependencies.is_empty() {
-                                Some(w.instance_name.workload_name())
-                            } else {
-                                None
-                            }
-                        })
-                        .collect();
-
-                    // [impl->swdd~server-state-rejects-state-with-cyclic-dependencies~1]
-                    if let Some(workload_part_of_cycle) =
-                        cycle_check::dfs(&new_templated_state.desired_state, Some(start_nodes))
-                    {
-                        return Err(UpdateStateError::CycleInDependencies(
-                            workload_part_of_cycle,
-                        ));
-                    }
+        let new_templated_state = self.generate_new_state(new_state, update_mask)?;
+
+        // [impl->swdd~common-workload-agent-or-agent-selector~1]
+        // [impl->swdd~common-workload-resource-requests~1]
+        // [impl->swdd~common-workload-agents-for-replicas~1]
+        // [impl->swdd~common-workload-update-strategy~3]
+        // [impl->swdd~common-workload-health-probes~1]
+        // [impl->swdd~common-workload-restart-backoff~2]
+        // [impl->swdd~common-workload-dependency-timeouts~1]
+        for workload in new_templated_state.desired_state.workloads.values() {
+            workload
+                .verify_agent_selector()
+                .and_then(|_| workload.verify_resource_requests())
+                .and_then(|_| workload.verify_agents())
+                .and_then(|_| workload.verify_update_strategy())
+                .and_then(|_| workload.verify_probes())
+                .and_then(|_| workload.verify_restart_backoff())
+                .and_then(|_| workload.verify_dependency_timeouts())
+                .map_err(UpdateStateError::ResultInvalid)?;
+        }
 
-                    // [impl->swdd~server-state-stores-delete-condition~1]
-                    self.delete_graph.insert(&added_workloads);
+        // [impl->swdd~server-state-triggers-configuration-rendering-of-workloads~1]
+        let mut new_rendered_workloads = self
+            .config_renderer
+            .render_workloads(
+                &new_templated_state.desired_state.workloads,
+                &new_templated_state.desired_state.configs,
+            )
+            .map_err(|err| UpdateStateError::ResultInvalid(err.to_string()))?;
+
+        // [impl->swdd~server-state-places-workloads-with-agent-selector~1]
+        // [impl->swdd~server-state-places-workloads-with-resource-requests~1]
+        let mut new_reserved_resources = self.reserved_resources.clone();
+        self.place_workloads(
+            &new_templated_state.desired_state.workloads,
+            &mut new_rendered_workloads,
+            &mut new_reserved_resources,
+        );
 
-                    // [impl->swdd~server-state-adds-delete-conditions-to-deleted-workload~1]
-                    self.delete_graph
-                        .apply_delete_conditions_to(&mut deleted_workloads);
+        // [impl->swdd~server-state-triggers-validation-of-workload-fields~1]
+        self.verify_workload_fields_format(&new_rendered_workloads)?;
 
-                    self.set_desired_state(new_templated_state.desired_state);
-                    self.rendered_workloads = new_rendered_workloads;
-                    Ok(Some((added_workloads, deleted_workloads)))
-                } else {
-                    // update state with changed fields not affecting workloads, e.g. config items
-                    // [impl->swdd~server-state-updates-state-on-unmodified-workloads~1]
-                    self.set_desired_state(new_templated_state.desired_state);
-                    Ok(None)
-                }
+        // [impl->swdd~server-state-compares-rendered-workloads~1]
+        let cmd =
+            extract_added_and_deleted_workloads(&self.rendered_workloads, &new_rendered_workloads);
+
+        if let Some((added_workloads, _)) = &cmd {
+            let start_nodes: Vec<&str> = added_workloads
+                .iter()
+                .filter_map(|w| {
+                    if !w.dependencies.is_empty() {
+                        Some(w.instance_name.workload_name())
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+
+            // [impl->swdd~server-state-rejects-state-with-cyclic-dependencies~1]
+            if let Some(workload_part_of_cycle) =
+                cycle_check::dfs(&new_templated_state.desired_state, Some(start_nodes))
+            {
+                return Err(UpdateStateError::CycleInDependencies(
+                    workload_part_of_cycle,
+                ));
             }
-            Err(error) => Err(error),
         }
+
+        Ok((
+            new_templated_state.desired_state,
+            new_rendered_workloads,
+            new_reserved_resources,
+            cmd,
+        ))
     }
 
-    // [impl->swdd~server-state-stores-agent-in-complete-state~1]
-    pub fn add_agent(&mut self, agent_name: String) {
+    // [impl->swdd~server-state-stores-agent-in-complete-state~2]
+    pub fn add_agent(
+        &mut self,
+        agent_name: String,
+        labels: HashMap<String, String>,
+    ) -> AddedDeletedWorkloads {
+        // [impl->swdd~server-state-continues-rolling-updates~2]
+        self.rolling_updates.start_pending_of_agent(&agent_name);
+
+        // a reconnecting agent can announce different labels
         self.state
             .agents
             .entry(agent_name)
+            .and_modify(|agent_attributes| agent_attributes.labels.clone_from(&labels))
+            // the load of the agent is unknown until its first load report
             .or_insert(AgentAttributes {
-                cpu_usage: Some(CpuUsage::default()),
-                free_memory: Some(FreeMemory::default()),
+                cpu_usage: None,
+                free_memory: None,
+                labels,
             });
+
+        // [impl->swdd~server-state-places-selected-workloads-on-connected-agent~2]
+        self.place_pending_workloads()
     }
 
     // [impl->swdd~server-state-removes-agent-from-complete-state~1]
     pub fn remove_agent(&mut self, agent_name: &str) {
         self.state.agents.remove(agent_name);
+        self.reserved_resources.remove(agent_name);
+        // [impl->swdd~server-state-removes-workload-metrics-of-disconnected-agent~1]
+        self.state.workload_metrics.remove_agent(agent_name);
     }
 
     // [impl->swdd~server-state-provides-connected-agent-exists-check~1]
@@ -284,11 +452,95 @@ impl ServerState {
     // [impl->swdd~server-updates-resource-availability~1]
     pub fn update_agent_resource_availability(
         &mut self,
-        agent_load_status: commands::AgentLoadStatus,
-    ) {
+        mut agent_load_status: commands::AgentLoadStatus,
+    ) -> AddedDeletedWorkloads {
+        // [impl->swdd~server-state-stores-workload-metrics~1]
+        if self.contains_connected_agent(&agent_load_status.agent_name) {
+            self.state.workload_metrics.update_agent_metrics(
+                &agent_load_status.agent_name,
+                std::mem::take(&mut agent_load_status.workload_metrics),
+            );
+        }
+
+        // [impl->swdd~server-state-places-workloads-with-resource-requests~1]
+        self.reserved_resources
+            .remove(&agent_load_status.agent_name);
         self.state
             .agents
             .update_resource_availability(agent_load_status);
+
+        // [impl->swdd~server-state-retries-placement-on-resource-availability-update~1]
+        self.place_pending_workloads()
+    }
+
+    // [impl->swdd~server-state-provides-not-scheduled-reason~1]
+    pub fn get_not_scheduled_reason(&self, workload_name: &str) -> Option<String> {
+        let workload = self
+            .state
+            .desired_state
+            .workloads
+            .get(workload_name)
+            .filter(|workload| workload.is_placed_by_server())?;
+
+        if self
+            .state
+            .agents
+            .find_selected_agent(&workload.agent_selector, None, &self.reserved_resources)
+            .is_none()
+        {
+            let reason = if workload.agent_selector.is_empty() {
+                "No agent is connected"
+            } else {
+                "No connected agent matches the agent selector"
+            };
+            return Some(reason.to_string());
+        }
+
+        workload.resource_requests.as_ref().map(|resource_requests| {
+            format!(
+                "No connected agent has enough free resources for the requested CPU usage of {}% and memory of {} bytes",
+                resource_requests.cpu, resource_requests.memory
+            )
+        })
+    }
+
+    // [impl->swdd~server-state-continues-rolling-updates~2]
+    pub fn continue_rolling_updates(
+        &mut self,
+        new_workload_states: &[WorkloadState],
+    ) -> RollingUpdatesStep {
+        let RolloutStep {
+            added_workloads,
+            mut deleted_workloads,
+            restored_workloads,
+            rolled_back_workloads,
+        } = self
+            .rolling_updates
+            .continue_with(new_workload_states, Instant::now());
+
+        // [impl->swdd~server-state-rolls-back-stalled-rolling-updates~2]
+        for restored_workload in restored_workloads {
+            if let Some(rendered_workload) = self.rendered_workloads.values_mut().find(|workload| {
+                workload.instance_name.workload_name()
+                    == restored_workload.instance_name.workload_name()
+                    && workload.instance_name.agent_name()
+                        == restored_workload.instance_name.agent_name()
+            }) {
+                *rendered_workload = restored_workload;
+            }
+        }
+
+        if added_workloads.is_empty() && deleted_workloads.is_empty() {
+            return (None, rolled_back_workloads);
+        }
+
+        self.delete_graph.insert(&added_workloads);
+        self.delete_graph
+            .apply_delete_conditions_to(&mut deleted_workloads);
+        (
+            Some((added_workloads, deleted_workloads)),
+            rolled_back_workloads,
+        )
     }
 
     // [impl->swdd~server-cleans-up-state~1]
@@ -299,7 +551,7 @@ impl ServerState {
     }
 
     fn generate_new_state(
-        &mut self,
+        &self,
         updated_state: CompleteState,
         update_mask: Vec<String>,
     ) -> Result<CompleteState, UpdateStateError> {
@@ -332,6 +584,105 @@ impl ServerState {
         })
     }
 
+    fn place_pending_workloads(&mut self) -> AddedDeletedWorkloads {
+        let mut new_rendered_workloads = self.rendered_workloads.clone();
+        let mut new_reserved_resources = self.reserved_resources.clone();
+        self.place_workloads(
+            &self.state.desired_state.workloads,
+            &mut new_rendered_workloads,
+            &mut new_reserved_resources,
+        );
+
+        let (added_workloads, mut deleted_workloads) =
+            extract_added_and_deleted_workloads(&self.rendered_workloads, &new_rendered_workloads)?;
+
+        self.delete_graph.insert(&added_workloads);
+        self.delete_graph
+            .apply_delete_conditions_to(&mut deleted_workloads);
+        self.rendered_workloads = new_rendered_workloads;
+        self.reserved_resources = new_reserved_resources;
+        Some((added_workloads, deleted_workloads))
+    }
+
+    // [impl->swdd~server-state-places-workloads-with-agent-selector~1]
+    // [impl->swdd~server-state-places-workloads-with-resource-requests~1]
+    fn place_workloads(
+        &self,
+        workloads: &HashMap<String, StoredWorkloadSpec>,
+        rendered_workloads: &mut RenderedWorkloads,
+        reserved_resources: &mut ReservedResources,
+    ) {
+        let mut workload_names: Vec<String> = rendered_workloads.keys().cloned().collect();
+        workload_names.sort();
+
+        for workload_name in workload_names {
+            let Some(workload) = workloads
+                .get(&workload_name)
+                .filter(|workload| workload.is_placed_by_server())
+            else {
+                continue;
+            };
+
+            let agent_name = self
+                .get_current_agent(&workload_name, &workload.agent_selector)
+                .or_else(|| {
+                    let agent_name = self
+                        .state
+                        .agents
+                        .find_selected_agent(
+                            &workload.agent_selector,
+                            workload.resource_requests.as_ref(),
+                            reserved_resources,
+                        )?
+                        .to_owned();
+                    // [impl->swdd~server-state-places-workloads-with-resource-requests~1]
+                    if let Some(resource_requests) = &workload.resource_requests {
+                        let reserved = reserved_resources.entry(agent_name.clone()).or_default();
+                        reserved.cpu = reserved.cpu.saturating_add(resource_requests.cpu);
+                        reserved.memory = reserved.memory.saturating_add(resource_requests.memory);
+                    }
+                    Some(agent_name)
+                })
+                .unwrap_or_default();
+            if agent_name.is_empty() {
+                log::debug!("No connected agent fits the placement of workload '{workload_name}'");
+            }
+
+            if let Some(rendered_workload) = rendered_workloads.get_mut(&workload_name) {
+                rendered_workload.instance_name = WorkloadInstanceName::builder()
+                    .workload_name(&workload_name)
+                    .agent_name(agent_name)
+                    .config(&rendered_workload.runtime_config)
+                    .build();
+            }
+        }
+    }
+
+    // A workload keeps its agent as long as the agent is disconnected or still matches the selector.
+    // The resources of a placed workload are already part of the load reported by its agent.
+    fn get_current_agent(
+        &self,
+        workload_name: &str,
+        agent_selector: &HashMap<String, String>,
+    ) -> Option<String> {
+        let current_agent = self
+            .rendered_workloads
+            .get(workload_name)
+            .map(|workload| workload.instance_name.agent_name())
+            .filter(|agent_name| !agent_name.is_empty());
+
+        if let Some(current_agent) = current_agent
+            && (!self.state.agents.contains_key(current_agent)
+                || self
+                    .state
+                    .agents
+                    .is_selected_by(current_agent, agent_selector))
+        {
+            return Some(current_agent.to_owned());
+        }
+        None
+    }
+
     fn set_desired_state(&mut self, new_desired_state: State) {
         self.state.desired_state = new_desired_state;
     }
@@ -365,11 +716,14 @@ mod tests {
     use common::{
         commands::{AgentLoadStatus, CompleteStateRequest},
         objects::{
-            AgentMap, CompleteState, ConfigItem, CpuUsage, DeletedWorkload, FreeMemory, State,
-            WorkloadSpec, WorkloadStatesMap, generate_test_agent_map, generate_test_configs,
-            generate_test_stored_workload_spec,
+            AgentMap, CompleteState, ConfigItem, CpuUsage, DeletedWorkload, ExecutionState,
+            FreeMemory, MAX_CPU_USAGE, ResourceRequests, RollingUpdate, State, StoredWorkloadSpec,
+            UpdateStrategy, WorkloadMetrics, WorkloadSpec, WorkloadStatesMap,
+            generate_test_agent_map, generate_test_configs, generate_test_stored_workload_spec,
             generate_test_workload_spec_with_control_interface_access,
             generate_test_workload_spec_with_param,
+            generate_test_workload_state_with_workload_spec,
+            generate_test_workload_states_map_with_data,
         },
         test_utils::{self, generate_test_complete_state},
     };
@@ -379,6 +733,7 @@ mod tests {
         config_renderer::{ConfigRenderError, MockConfigRenderer, RenderedWorkloads},
         delete_graph::MockDeleteGraph,
         server_state::UpdateStateError,
+        workload_state_history::WorkloadStateHistory,
     };
 
     use super::ServerState;
@@ -389,6 +744,8 @@ mod tests {
     const WORKLOAD_NAME_3: &str = "workload_3";
     const WORKLOAD_NAME_4: &str = "workload_4";
     const RUNTIME: &str = "runtime";
+    const LABEL_KEY: &str = "arch";
+    const LABEL_VALUE: &str = "arm64";
 
     fn generate_rendered_workloads_from_state(state: &State) -> RenderedWorkloads {
         state
@@ -436,7 +793,11 @@ mod tests {
         workload_state_db.process_new_states(server_state.state.workload_states.clone().into());
 
         let received_complete_state = server_state
-            .get_complete_state_by_field_mask(request_complete_state, &workload_state_db)
+            .get_complete_state_by_field_mask(
+                request_complete_state,
+                &workload_state_db,
+                &WorkloadStateHistory::default(),
+            )
             .unwrap();
 
         let expected_complete_state = ank_base::CompleteState::from(server_state.state);
@@ -469,13 +830,21 @@ mod tests {
         workload_state_map.process_new_states(server_state.state.workload_states.clone().into());
 
         let received_complete_state = server_state
-            .get_complete_state_by_field_mask(request_complete_state, &workload_state_map)
+            .get_complete_state_by_field_mask(
+                request_complete_state,
+                &workload_state_map,
+                &WorkloadStateHistory::default(),
+            )
             .unwrap();
 
         let mut expected_complete_state = ank_base::CompleteState {
             desired_state: Some(server_state.state.desired_state.clone().into()),
             workload_states: None,
             agents: None,
+            revision: None,
+            workload_metrics: None,
+            rendered_workloads: None,
+            workload_state_history: None,
         };
         if let Some(expected_desired_state) = &mut expected_complete_state.desired_state {
             expected_desired_state.configs = None;
@@ -522,7 +891,11 @@ mod tests {
         workload_state_map.process_new_states(server_state.state.workload_states.clone().into());
 
         let complete_state = server_state
-            .get_complete_state_by_field_mask(request_complete_state, &workload_state_map)
+            .get_complete_state_by_field_mask(
+                request_complete_state,
+                &workload_state_map,
+                &WorkloadStateHistory::default(),
+            )
             .unwrap();
 
         let expected_workloads = [
@@ -538,6 +911,14 @@ mod tests {
                     control_interface_access: None,
                     configs: None,
                     files: None,
+                    agent_selector: None,
+                    resource_requests: None,
+                    agents: None,
+                    update_strategy: None,
+                    probes: None,
+                    restart_backoff: None,
+                    dependency_timeouts: None,
+                    resources: None,
                 },
             ),
             (
@@ -562,6 +943,14 @@ mod tests {
                     files: Some(ank_base::Files {
                         files: w1.files.into_iter().map(Into::into).collect(),
                     }),
+                    agent_selector: None,
+                    resource_requests: None,
+                    agents: None,
+                    update_strategy: None,
+                    probes: None,
+                    restart_backoff: None,
+                    dependency_timeouts: None,
+                    resources: None,
                 },
             ),
         ];
@@ -574,6 +963,189 @@ mod tests {
         assert_eq!(expected_complete_state, complete_state);
     }
 
+    // [utest->swdd~server-filters-get-complete-state-result-with-wildcards~1]
+    #[test]
+    fn utest_server_state_get_complete_state_by_field_mask_with_wildcard() {
+        let w1 = generate_test_workload_spec_with_param(
+            AGENT_A.to_string(),
+            WORKLOAD_NAME_1.to_string(),
+            RUNTIME.to_string(),
+        );
+
+        let w2 = generate_test_workload_spec_with_param(
+            AGENT_B.to_string(),
+            WORKLOAD_NAME_2.to_string(),
+            RUNTIME.to_string(),
+        );
+
+        let server_state = ServerState {
+            state: generate_test_complete_state(vec![w1.clone(), w2.clone()]),
+            ..Default::default()
+        };
+
+        let request_complete_state = CompleteStateRequest {
+            field_mask: vec!["desiredState.workloads.*.agent".to_owned()],
+        };
+
+        let complete_state = server_state
+            .get_complete_state_by_field_mask(
+                request_complete_state,
+                &WorkloadStatesMap::default(),
+                &WorkloadStateHistory::default(),
+            )
+            .unwrap();
+
+        let workloads = complete_state
+            .desired_state
+            .and_then(|desired_state| desired_state.workloads)
+            .unwrap()
+            .workloads;
+        assert_eq!(workloads.len(), 2);
+        assert_eq!(
+            workloads[WORKLOAD_NAME_1],
+            ank_base::Workload {
+                agent: Some(AGENT_A.to_string()),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            workloads[WORKLOAD_NAME_2],
+            ank_base::Workload {
+                agent: Some(AGENT_B.to_string()),
+                ..Default::default()
+            }
+        );
+    }
+
+    // [utest->swdd~server-filters-get-complete-state-result~2]
+    #[test]
+    fn utest_server_state_get_complete_state_by_field_mask_agent_without_labels_and_load() {
+        let mut server_state = ServerState::default();
+        server_state.add_agent(AGENT_A.to_string(), HashMap::new());
+
+        let request_complete_state = CompleteStateRequest {
+            field_mask: vec!["agents".to_owned()],
+        };
+
+        let complete_state = server_state
+            .get_complete_state_by_field_mask(
+                request_complete_state,
+                &WorkloadStatesMap::default(),
+                &WorkloadStateHistory::default(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            complete_state.agents.unwrap().agents,
+            HashMap::from([(AGENT_A.to_string(), ank_base::AgentAttributes::default())])
+        );
+    }
+
+    // [utest->swdd~server-provides-rendered-workloads~1]
+    // [utest->swdd~server-provides-workload-state-history~1]
+    #[test]
+    fn utest_server_state_get_complete_state_by_field_mask_rendered_workloads_and_history() {
+        let w1 = generate_test_workload_spec_with_param(
+            AGENT_A.to_string(),
+            WORKLOAD_NAME_1.to_string(),
+            RUNTIME.to_string(),
+        );
+        let mut w1_replica = generate_test_workload_spec_with_param(
+            AGENT_B.to_string(),
+            WORKLOAD_NAME_1.to_string(),
+            RUNTIME.to_string(),
+        );
+        w1_replica.runtime_config = "rendered runtime config".to_string();
+        let w2 = generate_test_workload_spec_with_param(
+            AGENT_A.to_string(),
+            WORKLOAD_NAME_2.to_string(),
+            RUNTIME.to_string(),
+        );
+
+        let server_state = ServerState {
+            state: generate_test_complete_state(vec![w1.clone(), w2.clone()]),
+            rendered_workloads: HashMap::from([
+                (format!("{WORKLOAD_NAME_1}.{AGENT_B}"), w1_replica.clone()),
+                (WORKLOAD_NAME_1.to_string(), w1.clone()),
+                (WORKLOAD_NAME_2.to_string(), w2),
+            ]),
+            ..Default::default()
+        };
+
+        let workload_state_map = generate_test_workload_states_map_with_data(
+            AGENT_A,
+            WORKLOAD_NAME_1,
+            w1.instance_name.id(),
+            ExecutionState::running(),
+        );
+        let mut workload_state_history = WorkloadStateHistory::default();
+        workload_state_history.update(&workload_state_map);
+
+        let request_complete_state = CompleteStateRequest {
+            field_mask: vec![
+                format!("renderedWorkloads.{WORKLOAD_NAME_1}"),
+                format!("workloadStateHistory.{WORKLOAD_NAME_1}"),
+            ],
+        };
+
+        let complete_state = server_state
+            .get_complete_state_by_field_mask(
+                request_complete_state,
+                &workload_state_map,
+                &workload_state_history,
+            )
+            .unwrap();
+
+        let to_rendered_workload = |workload: WorkloadSpec| ank_base::RenderedWorkload {
+            agent: workload.instance_name.agent_name().to_string(),
+            runtime_config: workload.runtime_config,
+            files: Some(ank_base::Files {
+                files: workload.files.into_iter().map(Into::into).collect(),
+            }),
+        };
+        let expected_complete_state = ank_base::CompleteState {
+            rendered_workloads: Some(Box::new(ank_base::RenderedWorkloadMap {
+                workloads: HashMap::from([(
+                    WORKLOAD_NAME_1.to_string(),
+                    ank_base::RenderedWorkloads {
+                        replicas: vec![to_rendered_workload(w1), to_rendered_workload(w1_replica)],
+                    },
+                )]),
+            })),
+            workload_state_history: workload_state_history.to_proto().map(Box::new),
+            ..Default::default()
+        };
+
+        assert_eq!(expected_complete_state, complete_state);
+    }
+
+    // [utest->swdd~server-provides-rendered-workloads~1]
+    #[test]
+    fn utest_server_state_get_complete_state_by_field_mask_skips_rendered_workloads_by_default() {
+        let w1 = generate_test_workload_spec_with_param(
+            AGENT_A.to_string(),
+            WORKLOAD_NAME_1.to_string(),
+            RUNTIME.to_string(),
+        );
+
+        let server_state = ServerState {
+            state: generate_test_complete_state(vec![w1.clone()]),
+            rendered_workloads: HashMap::from([(WORKLOAD_NAME_1.to_string(), w1)]),
+            ..Default::default()
+        };
+
+        let complete_state = server_state
+            .get_complete_state_by_field_mask(
+                CompleteStateRequest { field_mask: vec![] },
+                &WorkloadStatesMap::default(),
+                &WorkloadStateHistory::default(),
+            )
+            .unwrap();
+
+        assert!(complete_state.rendered_workloads.is_none());
+        assert!(complete_state.workload_state_history.is_none());
+    }
+
     // [utest->swdd~agent-from-agent-field~1]
     #[test]
     fn utest_server_state_get_workloads_per_agent() {
@@ -709,6 +1281,7 @@ mod tests {
             rendered_workloads: generate_rendered_workloads_from_state(&old_state.desired_state),
             delete_graph: delete_graph_mock,
             config_renderer: mock_config_renderer,
+            ..Default::default()
         };
 
         let result = server_state.update(rejected_new_state, vec![]);
@@ -755,6 +1328,7 @@ mod tests {
             rendered_workloads: generate_rendered_workloads_from_state(&old_state.desired_state),
             delete_graph: delete_graph_mock,
             config_renderer: mock_config_renderer,
+            ..Default::default()
         };
 
         server_state
@@ -810,6 +1384,7 @@ mod tests {
             rendered_workloads: generate_rendered_workloads_from_state(&old_state.desired_state),
             delete_graph: delete_graph_mock,
             config_renderer: mock_config_renderer,
+            ..Default::default()
         };
         server_state.update(update_state, update_mask).unwrap();
 
@@ -866,6 +1441,7 @@ mod tests {
             rendered_workloads: generate_rendered_workloads_from_state(&old_state.desired_state),
             delete_graph: delete_graph_mock,
             config_renderer: mock_config_renderer,
+            ..Default::default()
         };
         server_state.update(update_state, update_mask).unwrap();
 
@@ -910,6 +1486,7 @@ mod tests {
             rendered_workloads: generate_rendered_workloads_from_state(&old_state.desired_state),
             delete_graph: delete_graph_mock,
             config_renderer: mock_config_renderer,
+            ..Default::default()
         };
 
         let expected = state_with_updated_config.clone();
@@ -973,6 +1550,7 @@ mod tests {
             rendered_workloads: generate_rendered_workloads_from_state(&old_state.desired_state),
             delete_graph: delete_graph_mock,
             config_renderer: mock_config_renderer,
+            ..Default::default()
         };
 
         let mut expected = updated_state.clone();
@@ -1039,6 +1617,7 @@ mod tests {
             rendered_workloads: generate_rendered_workloads_from_state(&old_state.desired_state),
             delete_graph: delete_graph_mock,
             config_renderer: mock_config_renderer,
+            ..Default::default()
         };
 
         let expected = updated_state.clone();
@@ -1103,6 +1682,7 @@ mod tests {
             rendered_workloads: generate_rendered_workloads_from_state(&old_state.desired_state),
             delete_graph: delete_graph_mock,
             config_renderer: mock_config_renderer,
+            ..Default::default()
         };
 
         let result = server_state.update(updated_state, update_mask);
@@ -1152,6 +1732,7 @@ mod tests {
             rendered_workloads: generate_rendered_workloads_from_state(&old_state.desired_state),
             delete_graph: delete_graph_mock,
             config_renderer: mock_config_renderer,
+            ..Default::default()
         };
         server_state.update(update_state, update_mask).unwrap();
 
@@ -1190,6 +1771,7 @@ mod tests {
             rendered_workloads: generate_rendered_workloads_from_state(&old_state.desired_state),
             delete_graph: delete_graph_mock,
             config_renderer: mock_config_renderer,
+            ..Default::default()
         };
         server_state.update(update_state, update_mask).unwrap();
 
@@ -1217,6 +1799,7 @@ mod tests {
             rendered_workloads: generate_rendered_workloads_from_state(&old_state.desired_state),
             delete_graph: delete_graph_mock,
             config_renderer: mock_config_renderer,
+            ..Default::default()
         };
         let result = server_state.update(update_state, update_mask);
 
@@ -1372,6 +1955,7 @@ mod tests {
                 &current_complete_state.desired_state,
             ),
             config_renderer: mock_config_renderer,
+            ..Default::default()
         };
 
         let added_deleted_workloads = server_state.update(update_state, update_mask).unwrap();
@@ -1453,6 +2037,7 @@ mod tests {
             ),
             delete_graph: delete_graph_mock,
             config_renderer: mock_config_renderer,
+            ..Default::default()
         };
 
         let added_deleted_workloads = server_state
@@ -1475,7 +2060,7 @@ mod tests {
         assert_eq!(server_state.state, new_complete_state);
     }
 
-    // [utest->swdd~server-state-stores-delete-condition~1]
+    // [utest->swdd~server-state-stores-delete-condition~2]
     // [utest->swdd~server-state-adds-delete-conditions-to-deleted-workload~1]
     // [utest->swdd~server-state-triggers-configuration-rendering-of-workloads~1]
     #[test]
@@ -1549,6 +2134,7 @@ mod tests {
             state: current_complete_state,
             delete_graph: delete_graph_mock,
             config_renderer: mock_config_renderer,
+            ..Default::default()
         };
 
         let added_deleted_workloads = server_state
@@ -1557,38 +2143,141 @@ mod tests {
         assert!(added_deleted_workloads.is_some());
     }
 
-    // [utest->swdd~server-updates-resource-availability~1]
+    // [utest->swdd~server-state-computes-update-without-applying-it~1]
     #[test]
-    fn utest_server_state_update_agent_resource_availability() {
-        let w1 = generate_test_workload_spec_with_param(
-            AGENT_A.to_string(),
-            WORKLOAD_NAME_1.to_string(),
-            RUNTIME.to_string(),
-        );
+    fn utest_server_state_dry_run_update_does_not_change_state() {
+        let _ = env_logger::builder().is_test(true).try_init();
 
-        let mut server_state = ServerState {
-            state: generate_test_complete_state(vec![w1.clone()]),
-            ..Default::default()
-        };
-        let cpu_usage = CpuUsage { cpu_usage: 42 };
-        let free_memory = FreeMemory { free_memory: 42 };
-        server_state.update_agent_resource_availability(AgentLoadStatus {
-            agent_name: AGENT_A.to_string(),
-            cpu_usage: cpu_usage.clone(),
-            free_memory: free_memory.clone(),
-        });
+        let new_state = generate_test_update_state();
 
-        let stored_state = server_state
-            .state
-            .agents
-            .entry(AGENT_A.to_string())
-            .or_default()
-            .to_owned();
+        let mut delete_graph_mock = MockDeleteGraph::new();
+        delete_graph_mock.expect_insert().never();
+        delete_graph_mock
+            .expect_apply_delete_conditions_to()
+            .never();
+
+        let mut mock_config_renderer = MockConfigRenderer::new();
+        let new_state_clone = new_state.desired_state.clone();
+        mock_config_renderer
+            .expect_render_workloads()
+            .once()
+            .returning(move |_, _| Ok(generate_rendered_workloads_from_state(&new_state_clone)));
+
+        let server_state = ServerState {
+            delete_graph: delete_graph_mock,
+            config_renderer: mock_config_renderer,
+            ..Default::default()
+        };
+
+        let added_deleted_workloads = server_state
+            .dry_run_update(new_state.clone(), vec![])
+            .unwrap();
+
+        let (added_workloads, deleted_workloads) = added_deleted_workloads.unwrap();
+        assert_eq!(
+            added_workloads.len(),
+            new_state.desired_state.workloads.len()
+        );
+        assert!(deleted_workloads.is_empty());
+        assert_eq!(server_state.state, CompleteState::default());
+        assert!(server_state.rendered_workloads.is_empty());
+    }
+
+    // [utest->swdd~server-state-computes-update-without-applying-it~1]
+    #[test]
+    fn utest_server_state_dry_run_update_fails_from_non_map() {
+        let old_state = generate_test_old_state();
+        let server_state = ServerState {
+            state: old_state.clone(),
+            ..Default::default()
+        };
+
+        let result = server_state.dry_run_update(
+            generate_test_update_state(),
+            vec!["desiredState.workloads.workload_2.tags.x".into()],
+        );
+
+        assert!(result.is_err());
+        assert_eq!(server_state.state, old_state);
+    }
+
+    // [utest->swdd~server-updates-resource-availability~1]
+    #[test]
+    fn utest_server_state_update_agent_resource_availability() {
+        let w1 = generate_test_workload_spec_with_param(
+            AGENT_A.to_string(),
+            WORKLOAD_NAME_1.to_string(),
+            RUNTIME.to_string(),
+        );
+
+        let mut server_state = ServerState {
+            state: generate_test_complete_state(vec![w1.clone()]),
+            ..Default::default()
+        };
+        let cpu_usage = CpuUsage { cpu_usage: 42 };
+        let free_memory = FreeMemory { free_memory: 42 };
+        server_state.update_agent_resource_availability(AgentLoadStatus {
+            agent_name: AGENT_A.to_string(),
+            cpu_usage: cpu_usage.clone(),
+            free_memory: free_memory.clone(),
+            workload_metrics: Default::default(),
+        });
+
+        let stored_state = server_state
+            .state
+            .agents
+            .entry(AGENT_A.to_string())
+            .or_default()
+            .to_owned();
 
         assert_eq!(stored_state.cpu_usage, Some(cpu_usage));
         assert_eq!(stored_state.free_memory, Some(free_memory));
     }
 
+    // [utest->swdd~server-state-stores-workload-metrics~1]
+    // [utest->swdd~server-state-removes-workload-metrics-of-disconnected-agent~1]
+    #[test]
+    fn utest_server_state_stores_workload_metrics_of_connected_agents() {
+        let mut server_state = ServerState::default();
+        server_state.add_agent(AGENT_A.to_string(), HashMap::new());
+
+        let workload_metrics = HashMap::from([(
+            WORKLOAD_NAME_1.to_owned(),
+            WorkloadMetrics {
+                cpu_usage: Some(12),
+                memory_usage: Some(4096),
+                restart_count: 2,
+            },
+        )]);
+        for agent_name in [AGENT_A, AGENT_B] {
+            server_state.update_agent_resource_availability(AgentLoadStatus {
+                agent_name: agent_name.to_string(),
+                cpu_usage: CpuUsage { cpu_usage: 42 },
+                free_memory: FreeMemory { free_memory: 42 },
+                workload_metrics: workload_metrics.clone(),
+            });
+        }
+
+        assert_eq!(
+            server_state
+                .state
+                .workload_metrics
+                .get(AGENT_A, WORKLOAD_NAME_1),
+            workload_metrics.get(WORKLOAD_NAME_1)
+        );
+        assert!(
+            server_state
+                .state
+                .workload_metrics
+                .get(AGENT_B, WORKLOAD_NAME_1)
+                .is_none()
+        );
+
+        server_state.remove_agent(AGENT_A);
+
+        assert!(server_state.state.workload_metrics.is_empty());
+    }
+
     // [utest->swdd~server-removes-obsolete-delete-graph-entires~1]
     #[test]
     fn utest_remove_deleted_workloads_from_delete_graph() {
@@ -1608,20 +2297,605 @@ mod tests {
         server_state.cleanup_state(&workload_states);
     }
 
-    // [utest->swdd~server-state-stores-agent-in-complete-state~1]
+    // [utest->swdd~server-state-stores-agent-in-complete-state~2]
     #[test]
     fn utest_add_agent() {
         let mut server_state = ServerState::default();
-        server_state.add_agent(AGENT_A.to_string());
+        let added_deleted_workloads = server_state.add_agent(AGENT_A.to_string(), HashMap::new());
         server_state.update_agent_resource_availability(AgentLoadStatus {
             agent_name: AGENT_A.to_string(),
             cpu_usage: CpuUsage { cpu_usage: 42 },
             free_memory: FreeMemory { free_memory: 42 },
+            workload_metrics: Default::default(),
         });
 
         let expected_agent_map = generate_test_agent_map(AGENT_A);
 
         assert_eq!(server_state.state.agents, expected_agent_map);
+        assert!(added_deleted_workloads.is_none());
+    }
+
+    // [utest->swdd~server-state-stores-agent-in-complete-state~2]
+    #[test]
+    fn utest_add_agent_stores_labels() {
+        let labels = HashMap::from([(LABEL_KEY.to_owned(), LABEL_VALUE.to_owned())]);
+        let mut server_state = ServerState::default();
+        server_state.add_agent(AGENT_A.to_string(), labels.clone());
+
+        assert_eq!(
+            server_state
+                .state
+                .agents
+                .entry(AGENT_A.to_owned())
+                .or_default()
+                .labels,
+            labels
+        );
+    }
+
+    // [utest->swdd~server-state-stores-agent-in-complete-state~2]
+    #[test]
+    fn utest_add_agent_refreshes_labels_of_reconnecting_agent() {
+        let new_labels = HashMap::from([(LABEL_KEY.to_owned(), "new_value".to_owned())]);
+        let mut server_state = ServerState::default();
+        server_state.add_agent(
+            AGENT_A.to_string(),
+            HashMap::from([(LABEL_KEY.to_owned(), LABEL_VALUE.to_owned())]),
+        );
+        server_state.add_agent(AGENT_A.to_string(), new_labels.clone());
+
+        assert_eq!(
+            server_state
+                .state
+                .agents
+                .entry(AGENT_A.to_owned())
+                .or_default()
+                .labels,
+            new_labels
+        );
+    }
+
+    // [utest->swdd~server-state-places-workloads-with-agent-selector~1]
+    #[test]
+    fn utest_server_state_update_state_places_workload_with_agent_selector() {
+        let _ = env_logger::builder().is_test(true).try_init();
+
+        let new_state = generate_test_state_with_agent_selector();
+
+        let mut delete_graph_mock = MockDeleteGraph::new();
+        delete_graph_mock.expect_insert().once().return_const(());
+        delete_graph_mock
+            .expect_apply_delete_conditions_to()
+            .once()
+            .return_const(());
+
+        let mut mock_config_renderer = MockConfigRenderer::new();
+        let new_state_clone = new_state.desired_state.clone();
+        mock_config_renderer
+            .expect_render_workloads()
+            .once()
+            .returning(move |_, _| Ok(generate_rendered_workloads_from_state(&new_state_clone)));
+
+        let mut server_state = ServerState {
+            delete_graph: delete_graph_mock,
+            config_renderer: mock_config_renderer,
+            ..Default::default()
+        };
+        server_state.add_agent(AGENT_A.to_owned(), HashMap::new());
+        server_state.add_agent(
+            AGENT_B.to_owned(),
+            HashMap::from([(LABEL_KEY.to_owned(), LABEL_VALUE.to_owned())]),
+        );
+
+        let (added_workloads, deleted_workloads) =
+            server_state.update(new_state, vec![]).unwrap().unwrap();
+
+        assert_eq!(added_workloads.len(), 1);
+        assert_eq!(added_workloads[0].instance_name.agent_name(), AGENT_B);
+        assert!(deleted_workloads.is_empty());
+        assert_eq!(
+            server_state.get_workloads_for_agent(AGENT_B),
+            added_workloads
+        );
+    }
+
+    // [utest->swdd~server-state-places-workloads-with-agent-selector~1]
+    // [utest->swdd~server-state-places-selected-workloads-on-connected-agent~2]
+    #[test]
+    fn utest_add_agent_places_pending_workload_with_agent_selector() {
+        let _ = env_logger::builder().is_test(true).try_init();
+
+        let new_state = generate_test_state_with_agent_selector();
+
+        let mut delete_graph_mock = MockDeleteGraph::new();
+        delete_graph_mock.expect_insert().times(2).return_const(());
+        delete_graph_mock
+            .expect_apply_delete_conditions_to()
+            .times(2)
+            .return_const(());
+
+        let mut mock_config_renderer = MockConfigRenderer::new();
+        let new_state_clone = new_state.desired_state.clone();
+        mock_config_renderer
+            .expect_render_workloads()
+            .once()
+            .returning(move |_, _| Ok(generate_rendered_workloads_from_state(&new_state_clone)));
+
+        let mut server_state = ServerState {
+            delete_graph: delete_graph_mock,
+            config_renderer: mock_config_renderer,
+            ..Default::default()
+        };
+
+        let (added_workloads, _) = server_state.update(new_state, vec![]).unwrap().unwrap();
+        assert_eq!(added_workloads[0].instance_name.agent_name(), "");
+
+        assert!(
+            server_state
+                .add_agent(AGENT_A.to_owned(), HashMap::new())
+                .is_none()
+        );
+
+        let (added_workloads, deleted_workloads) = server_state
+            .add_agent(
+                AGENT_B.to_owned(),
+                HashMap::from([(LABEL_KEY.to_owned(), LABEL_VALUE.to_owned())]),
+            )
+            .unwrap();
+
+        assert_eq!(added_workloads.len(), 1);
+        assert_eq!(added_workloads[0].instance_name.agent_name(), AGENT_B);
+        assert_eq!(deleted_workloads.len(), 1);
+        assert_eq!(deleted_workloads[0].instance_name.agent_name(), "");
+        assert_eq!(
+            server_state.get_workloads_for_agent(AGENT_B),
+            added_workloads
+        );
+    }
+
+    // [utest->swdd~common-workload-agent-or-agent-selector~1]
+    #[test]
+    fn utest_server_state_update_state_rejects_agent_and_agent_selector() {
+        let mut new_state = generate_test_state_with_agent_selector();
+        new_state
+            .desired_state
+            .workloads
+            .values_mut()
+            .for_each(|workload| workload.agent = AGENT_A.to_owned());
+
+        let mut server_state = ServerState::default();
+
+        let result = server_state.update(new_state, vec![]);
+        assert!(matches!(result, Err(UpdateStateError::ResultInvalid(_))));
+        assert!(server_state.rendered_workloads.is_empty());
+    }
+
+    // [utest->swdd~server-state-compares-rendered-workloads~1]
+    // [utest->swdd~common-workload-agents-for-replicas~1]
+    #[test]
+    fn utest_server_state_update_state_deletes_only_replica_of_removed_agent() {
+        let _ = env_logger::builder().is_test(true).try_init();
+
+        let mut old_workload = generate_test_stored_workload_spec("", RUNTIME);
+        old_workload.agents = vec![AGENT_A.to_owned(), AGENT_B.to_owned()];
+        old_workload.dependencies.clear();
+        old_workload.configs.clear();
+        let mut new_workload = old_workload.clone();
+        new_workload.agents = vec![AGENT_A.to_owned()];
+
+        let new_state = CompleteState {
+            desired_state: State {
+                workloads: HashMap::from([(WORKLOAD_NAME_1.to_owned(), new_workload.clone())]),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let mut delete_graph_mock = MockDeleteGraph::new();
+        delete_graph_mock.expect_insert().once().return_const(());
+        delete_graph_mock
+            .expect_apply_delete_conditions_to()
+            .once()
+            .return_const(());
+
+        let mut mock_config_renderer = MockConfigRenderer::new();
+        let new_rendered_workloads = generate_rendered_replicas(&new_workload);
+        mock_config_renderer
+            .expect_render_workloads()
+            .once()
+            .returning(move |_, _| Ok(new_rendered_workloads.clone()));
+
+        let old_rendered_workloads = generate_rendered_replicas(&old_workload);
+        let removed_replica_instance_name = old_rendered_workloads
+            .values()
+            .find(|workload| workload.instance_name.agent_name() == AGENT_B)
+            .unwrap()
+            .instance_name
+            .clone();
+
+        let mut server_state = ServerState {
+            rendered_workloads: old_rendered_workloads,
+            delete_graph: delete_graph_mock,
+            config_renderer: mock_config_renderer,
+            ..Default::default()
+        };
+
+        let (added_workloads, deleted_workloads) =
+            server_state.update(new_state, vec![]).unwrap().unwrap();
+
+        assert!(added_workloads.is_empty());
+        assert_eq!(
+            deleted_workloads,
+            vec![DeletedWorkload {
+                instance_name: removed_replica_instance_name.clone(),
+                ..Default::default()
+            }]
+        );
+        assert!(!server_state.desired_state_contains_instance_name(&removed_replica_instance_name));
+        assert_eq!(server_state.get_workloads_for_agent(AGENT_A).len(), 1);
+    }
+
+    // [utest->swdd~server-state-sequences-rolling-updates~1]
+    // [utest->swdd~server-state-continues-rolling-updates~2]
+    #[test]
+    fn utest_server_state_update_state_rolls_out_replica_updates_one_after_another() {
+        let _ = env_logger::builder().is_test(true).try_init();
+
+        let mut old_workload = generate_test_stored_workload_spec("", RUNTIME);
+        old_workload.agents = vec![AGENT_A.to_owned(), AGENT_B.to_owned()];
+        old_workload.dependencies.clear();
+        old_workload.configs.clear();
+        old_workload.update_strategy = Some(UpdateStrategy {
+            rolling: Some(RollingUpdate {
+                max_unavailable: 1,
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        let mut new_workload = old_workload.clone();
+        new_workload.runtime_config = "updated runtime config".to_owned();
+
+        let new_state = CompleteState {
+            desired_state: State {
+                workloads: HashMap::from([(WORKLOAD_NAME_1.to_owned(), new_workload.clone())]),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let mut delete_graph_mock = MockDeleteGraph::new();
+        delete_graph_mock.expect_insert().times(2).return_const(());
+        delete_graph_mock
+            .expect_apply_delete_conditions_to()
+            .times(2)
+            .return_const(());
+
+        let mut mock_config_renderer = MockConfigRenderer::new();
+        let new_rendered_workloads = generate_rendered_replicas(&new_workload);
+        let new_replicas = new_rendered_workloads.clone();
+        mock_config_renderer
+            .expect_render_workloads()
+            .once()
+            .returning(move |_, _| Ok(new_rendered_workloads.clone()));
+
+        let old_replicas = generate_rendered_replicas(&old_workload);
+        let replica_on = |replicas: &RenderedWorkloads, agent_name: &str| {
+            replicas
+                .get(&format!("{WORKLOAD_NAME_1}.{agent_name}"))
+                .unwrap()
+                .clone()
+        };
+
+        let mut server_state = ServerState {
+            rendered_workloads: old_replicas.clone(),
+            delete_graph: delete_graph_mock,
+            config_renderer: mock_config_renderer,
+            ..Default::default()
+        };
+
+        let (added_workloads, deleted_workloads) =
+            server_state.update(new_state, vec![]).unwrap().unwrap();
+
+        assert_eq!(added_workloads, vec![replica_on(&new_replicas, AGENT_A)]);
+        assert_eq!(
+            deleted_workloads,
+            vec![DeletedWorkload {
+                instance_name: replica_on(&old_replicas, AGENT_A).instance_name,
+                ..Default::default()
+            }]
+        );
+
+        let (added_deleted_workloads, rolled_back_workloads) = server_state
+            .continue_rolling_updates(&[generate_test_workload_state_with_workload_spec(
+                &replica_on(&new_replicas, AGENT_A),
+                ExecutionState::running(),
+            )]);
+        let (added_workloads, deleted_workloads) = added_deleted_workloads.unwrap();
+        assert!(rolled_back_workloads.is_empty());
+
+        assert_eq!(added_workloads, vec![replica_on(&new_replicas, AGENT_B)]);
+        assert_eq!(
+            deleted_workloads,
+            vec![DeletedWorkload {
+                instance_name: replica_on(&old_replicas, AGENT_B).instance_name,
+                ..Default::default()
+            }]
+        );
+        assert_eq!(
+            server_state.continue_rolling_updates(&[
+                generate_test_workload_state_with_workload_spec(
+                    &replica_on(&new_replicas, AGENT_B),
+                    ExecutionState::running(),
+                )
+            ]),
+            (None, vec![])
+        );
+    }
+
+    // [utest->swdd~server-state-continues-rolling-updates~2]
+    // [utest->swdd~server-state-rolls-back-stalled-rolling-updates~2]
+    #[test]
+    fn utest_server_state_rolls_back_failed_rolling_update() {
+        let _ = env_logger::builder().is_test(true).try_init();
+
+        let mut old_workload = generate_test_stored_workload_spec("", RUNTIME);
+        old_workload.agents = vec![AGENT_A.to_owned(), AGENT_B.to_owned()];
+        old_workload.dependencies.clear();
+        old_workload.configs.clear();
+        old_workload.update_strategy = Some(UpdateStrategy {
+            rolling: Some(RollingUpdate {
+                max_unavailable: 1,
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        let mut new_workload = old_workload.clone();
+        new_workload.runtime_config = "updated runtime config".to_owned();
+
+        let new_state = CompleteState {
+            desired_state: State {
+                workloads: HashMap::from([(WORKLOAD_NAME_1.to_owned(), new_workload.clone())]),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let mut delete_graph_mock = MockDeleteGraph::new();
+        delete_graph_mock.expect_insert().times(2).return_const(());
+        delete_graph_mock
+            .expect_apply_delete_conditions_to()
+            .times(2)
+            .return_const(());
+
+        let mut mock_config_renderer = MockConfigRenderer::new();
+        let new_rendered_workloads = generate_rendered_replicas(&new_workload);
+        let new_replicas = new_rendered_workloads.clone();
+        mock_config_renderer
+            .expect_render_workloads()
+            .once()
+            .returning(move |_, _| Ok(new_rendered_workloads.clone()));
+
+        let old_replicas = generate_rendered_replicas(&old_workload);
+        let replica_on = |replicas: &RenderedWorkloads, agent_name: &str| {
+            replicas
+                .get(&format!("{WORKLOAD_NAME_1}.{agent_name}"))
+                .unwrap()
+                .clone()
+        };
+
+        let mut server_state = ServerState {
+            rendered_workloads: old_replicas.clone(),
+            delete_graph: delete_graph_mock,
+            config_renderer: mock_config_renderer,
+            ..Default::default()
+        };
+
+        server_state.update(new_state, vec![]).unwrap().unwrap();
+
+        // the pending replica update is not sent to a connecting agent
+        assert_eq!(
+            server_state.get_workloads_for_agent(AGENT_B),
+            vec![replica_on(&old_replicas, AGENT_B)]
+        );
+
+        let (added_deleted_workloads, rolled_back_workloads) = server_state
+            .continue_rolling_updates(&[generate_test_workload_state_with_workload_spec(
+                &replica_on(&new_replicas, AGENT_A),
+                ExecutionState::failed("failed"),
+            )]);
+        let (added_workloads, deleted_workloads) = added_deleted_workloads.unwrap();
+        assert_eq!(rolled_back_workloads, vec![WORKLOAD_NAME_1.to_owned()]);
+
+        assert_eq!(added_workloads, vec![replica_on(&old_replicas, AGENT_A)]);
+        assert_eq!(
+            deleted_workloads,
+            vec![DeletedWorkload {
+                instance_name: replica_on(&new_replicas, AGENT_A).instance_name,
+                ..Default::default()
+            }]
+        );
+        assert_eq!(server_state.rendered_workloads, old_replicas);
+    }
+
+    // [utest->swdd~server-state-places-workloads-with-resource-requests~1]
+    #[test]
+    fn utest_server_state_update_state_places_workload_with_resource_requests() {
+        let _ = env_logger::builder().is_test(true).try_init();
+
+        let new_state = generate_test_state_with_resource_requests();
+
+        let mut delete_graph_mock = MockDeleteGraph::new();
+        delete_graph_mock.expect_insert().once().return_const(());
+        delete_graph_mock
+            .expect_apply_delete_conditions_to()
+            .once()
+            .return_const(());
+
+        let mut mock_config_renderer = MockConfigRenderer::new();
+        let new_state_clone = new_state.desired_state.clone();
+        mock_config_renderer
+            .expect_render_workloads()
+            .once()
+            .returning(move |_, _| Ok(generate_rendered_workloads_from_state(&new_state_clone)));
+
+        let mut server_state = ServerState {
+            delete_graph: delete_graph_mock,
+            config_renderer: mock_config_renderer,
+            ..Default::default()
+        };
+        add_agent_with_load(&mut server_state, AGENT_A, 90, 2048);
+        add_agent_with_load(&mut server_state, AGENT_B, 10, 2048);
+
+        let (added_workloads, deleted_workloads) =
+            server_state.update(new_state, vec![]).unwrap().unwrap();
+
+        assert_eq!(added_workloads.len(), 1);
+        assert_eq!(added_workloads[0].instance_name.agent_name(), AGENT_B);
+        assert!(deleted_workloads.is_empty());
+    }
+
+    // [utest->swdd~server-state-retries-placement-on-resource-availability-update~1]
+    // [utest->swdd~server-state-provides-not-scheduled-reason~1]
+    #[test]
+    fn utest_update_agent_resource_availability_places_pending_workload_with_resource_requests() {
+        let _ = env_logger::builder().is_test(true).try_init();
+
+        let new_state = generate_test_state_with_resource_requests();
+
+        let mut delete_graph_mock = MockDeleteGraph::new();
+        delete_graph_mock.expect_insert().times(2).return_const(());
+        delete_graph_mock
+            .expect_apply_delete_conditions_to()
+            .times(2)
+            .return_const(());
+
+        let mut mock_config_renderer = MockConfigRenderer::new();
+        let new_state_clone = new_state.desired_state.clone();
+        mock_config_renderer
+            .expect_render_workloads()
+            .once()
+            .returning(move |_, _| Ok(generate_rendered_workloads_from_state(&new_state_clone)));
+
+        let mut server_state = ServerState {
+            delete_graph: delete_graph_mock,
+            config_renderer: mock_config_renderer,
+            ..Default::default()
+        };
+        add_agent_with_load(&mut server_state, AGENT_A, 90, 2048);
+
+        let (added_workloads, _) = server_state.update(new_state, vec![]).unwrap().unwrap();
+        assert_eq!(added_workloads[0].instance_name.agent_name(), "");
+        assert_eq!(
+            server_state.get_not_scheduled_reason(WORKLOAD_NAME_1),
+            Some(
+                "No connected agent has enough free resources for the requested CPU usage of 20% and memory of 1024 bytes"
+                    .to_owned()
+            )
+        );
+
+        let (added_workloads, deleted_workloads) = server_state
+            .update_agent_resource_availability(AgentLoadStatus {
+                agent_name: AGENT_A.to_owned(),
+                cpu_usage: CpuUsage { cpu_usage: 10 },
+                free_memory: FreeMemory { free_memory: 2048 },
+                workload_metrics: Default::default(),
+            })
+            .unwrap();
+
+        assert_eq!(added_workloads.len(), 1);
+        assert_eq!(added_workloads[0].instance_name.agent_name(), AGENT_A);
+        assert_eq!(deleted_workloads.len(), 1);
+        assert_eq!(deleted_workloads[0].instance_name.agent_name(), "");
+    }
+
+    // [utest->swdd~server-state-places-workloads-with-resource-requests~1]
+    #[test]
+    fn utest_server_state_keeps_reserved_resources_until_next_load_report() {
+        let _ = env_logger::builder().is_test(true).try_init();
+
+        let new_state = generate_test_state_with_resource_requests();
+
+        let mut delete_graph_mock = MockDeleteGraph::new();
+        delete_graph_mock.expect_insert().return_const(());
+        delete_graph_mock
+            .expect_apply_delete_conditions_to()
+            .return_const(());
+
+        let mut mock_config_renderer = MockConfigRenderer::new();
+        let new_state_clone = new_state.desired_state.clone();
+        mock_config_renderer
+            .expect_render_workloads()
+            .once()
+            .returning(move |_, _| Ok(generate_rendered_workloads_from_state(&new_state_clone)));
+
+        let mut server_state = ServerState {
+            delete_graph: delete_graph_mock,
+            config_renderer: mock_config_renderer,
+            ..Default::default()
+        };
+        // the agent has not reported its load yet
+        server_state.add_agent(AGENT_A.to_owned(), HashMap::new());
+
+        let (added_workloads, _) = server_state.update(new_state, vec![]).unwrap().unwrap();
+        assert_eq!(added_workloads[0].instance_name.agent_name(), AGENT_A);
+        assert_eq!(
+            server_state.reserved_resources.get(AGENT_A),
+            Some(&ResourceRequests {
+                cpu: 20,
+                memory: 1024,
+            })
+        );
+
+        server_state.update_agent_resource_availability(AgentLoadStatus {
+            agent_name: AGENT_A.to_owned(),
+            cpu_usage: CpuUsage { cpu_usage: 30 },
+            free_memory: FreeMemory { free_memory: 1024 },
+            workload_metrics: Default::default(),
+        });
+        assert!(server_state.reserved_resources.is_empty());
+    }
+
+    // [utest->swdd~server-state-provides-not-scheduled-reason~1]
+    #[test]
+    fn utest_get_not_scheduled_reason_without_matching_agent() {
+        let mut state = generate_test_state_with_agent_selector();
+        state.desired_state.workloads.insert(
+            WORKLOAD_NAME_2.to_owned(),
+            generate_test_stored_workload_spec("", RUNTIME),
+        );
+        let mut server_state = ServerState {
+            state,
+            ..Default::default()
+        };
+        server_state.add_agent(AGENT_A.to_owned(), HashMap::new());
+
+        assert_eq!(
+            server_state.get_not_scheduled_reason(WORKLOAD_NAME_1),
+            Some("No connected agent matches the agent selector".to_owned())
+        );
+        assert_eq!(server_state.get_not_scheduled_reason(WORKLOAD_NAME_2), None);
+    }
+
+    // [utest->swdd~common-workload-resource-requests~1]
+    #[test]
+    fn utest_server_state_update_state_rejects_invalid_cpu_resource_request() {
+        let mut new_state = generate_test_state_with_resource_requests();
+        new_state
+            .desired_state
+            .workloads
+            .values_mut()
+            .for_each(|workload| {
+                workload.resource_requests = Some(ResourceRequests {
+                    cpu: MAX_CPU_USAGE + 1,
+                    memory: 0,
+                })
+            });
+
+        let mut server_state = ServerState::default();
+
+        let result = server_state.update(new_state, vec![]);
+        assert!(matches!(result, Err(UpdateStateError::ResultInvalid(_))));
+        assert!(server_state.rendered_workloads.is_empty());
     }
 
     // [utest->swdd~server-state-removes-agent-from-complete-state~1]
@@ -1656,6 +2930,21 @@ mod tests {
         assert!(!server_state.contains_connected_agent(AGENT_B));
     }
 
+    // [utest->swdd~server-state-store-persists-desired-state~1]
+    #[test]
+    fn utest_get_desired_state() {
+        let complete_state = generate_test_old_state();
+        let server_state = ServerState {
+            state: complete_state.clone(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            server_state.get_desired_state(),
+            &complete_state.desired_state
+        );
+    }
+
     fn generate_test_old_state() -> CompleteState {
         generate_test_complete_state(vec![
             generate_test_workload_spec_with_param(
@@ -1676,6 +2965,67 @@ mod tests {
         ])
     }
 
+    fn generate_test_state_with_agent_selector() -> CompleteState {
+        let mut workload = generate_test_stored_workload_spec("", RUNTIME);
+        workload.agent_selector = HashMap::from([(LABEL_KEY.to_owned(), LABEL_VALUE.to_owned())]);
+        workload.dependencies.clear();
+        workload.configs.clear();
+
+        CompleteState {
+            desired_state: State {
+                workloads: HashMap::from([(WORKLOAD_NAME_1.to_owned(), workload)]),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    fn generate_rendered_replicas(workload: &StoredWorkloadSpec) -> RenderedWorkloads {
+        workload
+            .replicas()
+            .into_iter()
+            .map(|replica| {
+                (
+                    format!("{WORKLOAD_NAME_1}.{}", replica.agent),
+                    WorkloadSpec::from((WORKLOAD_NAME_1.to_owned(), replica)),
+                )
+            })
+            .collect()
+    }
+
+    fn generate_test_state_with_resource_requests() -> CompleteState {
+        let mut workload = generate_test_stored_workload_spec("", RUNTIME);
+        workload.resource_requests = Some(ResourceRequests {
+            cpu: 20,
+            memory: 1024,
+        });
+        workload.dependencies.clear();
+        workload.configs.clear();
+
+        CompleteState {
+            desired_state: State {
+                workloads: HashMap::from([(WORKLOAD_NAME_1.to_owned(), workload)]),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    fn add_agent_with_load(
+        server_state: &mut ServerState,
+        agent_name: &str,
+        cpu_usage: u32,
+        free_memory: u64,
+    ) {
+        server_state.add_agent(agent_name.to_owned(), HashMap::new());
+        server_state.update_agent_resource_availability(AgentLoadStatus {
+            agent_name: agent_name.to_owned(),
+            cpu_usage: CpuUsage { cpu_usage },
+            free_memory: FreeMemory { free_memory },
+            workload_metrics: Default::default(),
+        });
+    }
+
     fn generate_test_update_state() -> CompleteState {
         generate_test_complete_state(vec![
             generate_test_workload_spec_with_param(
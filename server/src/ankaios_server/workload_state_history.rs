@@ -0,0 +1,263 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use api::ank_base;
+use common::objects::{ExecutionState, WorkloadInstanceName, WorkloadState, WorkloadStatesMap};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+#[cfg(not(test))]
+fn current_timestamp() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+fn current_timestamp() -> u64 {
+    tests::TIMESTAMP
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Transition {
+    agent_name: String,
+    execution_state: ExecutionState,
+    timestamp: u64,
+}
+
+impl From<&Transition> for ank_base::ExecutionStateTransition {
+    fn from(transition: &Transition) -> Self {
+        ank_base::ExecutionStateTransition {
+            agent: transition.agent_name.clone(),
+            execution_state: Some(transition.execution_state.clone().into()),
+            timestamp: transition.timestamp,
+        }
+    }
+}
+
+// [impl->swdd~server-keeps-bounded-workload-state-history~1]
+pub struct WorkloadStateHistory {
+    last_states: HashMap<WorkloadInstanceName, ExecutionState>,
+    transitions: HashMap<String, VecDeque<Transition>>,
+    max_transitions: usize,
+}
+
+impl Default for WorkloadStateHistory {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_MAX_TRANSITIONS)
+    }
+}
+
+impl WorkloadStateHistory {
+    pub const DEFAULT_MAX_TRANSITIONS: usize = 10;
+
+    pub fn new(max_transitions: usize) -> Self {
+        WorkloadStateHistory {
+            last_states: HashMap::new(),
+            transitions: HashMap::new(),
+            max_transitions: max_transitions.max(1),
+        }
+    }
+
+    // The execution states are changed at many places of the server. Instead of recording
+    // every single change, the history compares the current states with the last known ones.
+    // [impl->swdd~server-records-workload-state-transitions~1]
+    pub fn update(&mut self, workload_states_map: &WorkloadStatesMap) {
+        let current_states: Vec<WorkloadState> = workload_states_map.clone().into();
+
+        for workload_state in &current_states {
+            if self.last_states.get(&workload_state.instance_name)
+                == Some(&workload_state.execution_state)
+            {
+                continue;
+            }
+
+            let workload_transitions = self
+                .transitions
+                .entry(workload_state.instance_name.workload_name().to_owned())
+                .or_default();
+            if workload_transitions.len() == self.max_transitions {
+                workload_transitions.pop_front();
+            }
+            workload_transitions.push_back(Transition {
+                agent_name: workload_state.instance_name.agent_name().to_owned(),
+                execution_state: workload_state.execution_state.clone(),
+                timestamp: current_timestamp(),
+            });
+        }
+
+        // the history of a workload is dropped as soon as none of its instances is left
+        let current_workload_names: HashSet<&str> = current_states
+            .iter()
+            .map(|workload_state| workload_state.instance_name.workload_name())
+            .collect();
+        self.transitions
+            .retain(|workload_name, _| current_workload_names.contains(workload_name.as_str()));
+
+        self.last_states = current_states
+            .into_iter()
+            .map(|workload_state| (workload_state.instance_name, workload_state.execution_state))
+            .collect();
+    }
+
+    // [impl->swdd~server-provides-workload-state-history~1]
+    pub fn to_proto(&self) -> Option<ank_base::WorkloadStateHistoryMap> {
+        if self.transitions.is_empty() {
+            return None;
+        }
+        Some(ank_base::WorkloadStateHistoryMap {
+            workloads: self
+                .transitions
+                .iter()
+                .map(|(workload_name, transitions)| {
+                    (
+                        workload_name.clone(),
+                        ank_base::WorkloadStateHistory {
+                            transitions: transitions.iter().map(Into::into).collect(),
+                        },
+                    )
+                })
+                .collect(),
+        })
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::WorkloadStateHistory;
+    use api::ank_base;
+    use common::objects::{
+        ExecutionState, WorkloadStatesMap, generate_test_workload_states_map_with_data,
+    };
+
+    pub const TIMESTAMP: u64 = 1_700_000_000;
+
+    const AGENT_A: &str = "agent_A";
+    const AGENT_B: &str = "agent_B";
+    const WORKLOAD_NAME_1: &str = "workload_1";
+    const WORKLOAD_NAME_2: &str = "workload_2";
+    const INSTANCE_ID: &str = "id_1";
+
+    fn states_map(agent_name: &str, execution_state: ExecutionState) -> WorkloadStatesMap {
+        generate_test_workload_states_map_with_data(
+            agent_name,
+            WORKLOAD_NAME_1,
+            INSTANCE_ID,
+            execution_state,
+        )
+    }
+
+    fn transitions_of(
+        history: &WorkloadStateHistory,
+        workload_name: &str,
+    ) -> Vec<ank_base::ExecutionStateTransition> {
+        history
+            .to_proto()
+            .and_then(|mut history_map| history_map.workloads.remove(workload_name))
+            .map(|workload_history| workload_history.transitions)
+            .unwrap_or_default()
+    }
+
+    // [utest->swdd~server-records-workload-state-transitions~1]
+    #[test]
+    fn utest_workload_state_history_records_changed_states_only() {
+        let mut history = WorkloadStateHistory::default();
+
+        history.update(&states_map(AGENT_A, ExecutionState::initial()));
+        history.update(&states_map(AGENT_A, ExecutionState::initial()));
+        history.update(&states_map(AGENT_A, ExecutionState::running()));
+
+        assert_eq!(
+            transitions_of(&history, WORKLOAD_NAME_1),
+            vec![
+                ank_base::ExecutionStateTransition {
+                    agent: AGENT_A.to_string(),
+                    execution_state: Some(ExecutionState::initial().into()),
+                    timestamp: TIMESTAMP,
+                },
+                ank_base::ExecutionStateTransition {
+                    agent: AGENT_A.to_string(),
+                    execution_state: Some(ExecutionState::running().into()),
+                    timestamp: TIMESTAMP,
+                },
+            ]
+        );
+    }
+
+    // [utest->swdd~server-records-workload-state-transitions~1]
+    #[test]
+    fn utest_workload_state_history_records_transitions_per_agent() {
+        let mut history = WorkloadStateHistory::default();
+
+        history.update(&states_map(AGENT_A, ExecutionState::running()));
+        history.update(&states_map(AGENT_B, ExecutionState::running()));
+
+        let agents: Vec<String> = transitions_of(&history, WORKLOAD_NAME_1)
+            .into_iter()
+            .map(|transition| transition.agent)
+            .collect();
+        assert_eq!(agents, vec![AGENT_A.to_string(), AGENT_B.to_string()]);
+    }
+
+    // [utest->swdd~server-records-workload-state-transitions~1]
+    #[test]
+    fn utest_workload_state_history_drops_history_of_removed_workloads() {
+        let mut history = WorkloadStateHistory::default();
+
+        history.update(&states_map(AGENT_A, ExecutionState::running()));
+        history.update(&generate_test_workload_states_map_with_data(
+            AGENT_A,
+            WORKLOAD_NAME_2,
+            INSTANCE_ID,
+            ExecutionState::running(),
+        ));
+
+        assert!(transitions_of(&history, WORKLOAD_NAME_1).is_empty());
+        assert_eq!(transitions_of(&history, WORKLOAD_NAME_2).len(), 1);
+
+        history.update(&WorkloadStatesMap::new());
+        assert!(history.to_proto().is_none());
+    }
+
+    // [utest->swdd~server-keeps-bounded-workload-state-history~1]
+    #[test]
+    fn utest_workload_state_history_drops_oldest_transition_when_full() {
+        let mut history = WorkloadStateHistory::new(2);
+
+        history.update(&states_map(AGENT_A, ExecutionState::initial()));
+        history.update(&states_map(AGENT_A, ExecutionState::running()));
+        history.update(&states_map(AGENT_A, ExecutionState::succeeded()));
+
+        let execution_states: Vec<Option<ank_base::ExecutionState>> =
+            transitions_of(&history, WORKLOAD_NAME_1)
+                .into_iter()
+                .map(|transition| transition.execution_state)
+                .collect();
+        assert_eq!(
+            execution_states,
+            vec![
+                Some(ExecutionState::running().into()),
+                Some(ExecutionState::succeeded().into()),
+            ]
+        );
+    }
+}
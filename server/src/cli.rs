@@ -49,6 +49,10 @@ pub struct Arguments {
     #[clap(long = "key_pem", env = "ANKSERVER_KEY_PEM")]
     /// Path to server key pem file.
     pub key_pem: Option<String>,
+    #[clap(long = "state-store-dir", env = "ANKSERVER_STATE_STORE_DIR")]
+    /// Path to the directory in which the desired state is persisted across server restarts.
+    /// By default, the desired state is kept in memory only.
+    pub state_store_dir: Option<String>,
 }
 // Note: this code is intentionally without unit tests.
 // There is no business logic which can be tested, here we have only a config and a call of "clap" crate.
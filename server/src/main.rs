@@ -15,6 +15,7 @@
 mod ankaios_server;
 mod cli;
 mod server_config;
+mod state_store;
 
 use std::fs;
 use std::path::PathBuf;
@@ -27,6 +28,7 @@ use common::std_extensions::GracefulExitResult;
 
 use ankaios_server::{AnkaiosServer, create_from_server_channel, create_to_server_channel};
 use server_config::{DEFAULT_SERVER_CONFIG_FILE_PATH, ServerConfig};
+use state_store::FileStateStore;
 
 use grpc::{security::TLSConfig, server::GRPCCommunicationsServer};
 
@@ -130,6 +132,14 @@ async fn main() {
     );
     let mut server = AnkaiosServer::new(server_receiver, to_agents.clone());
 
+    // [impl->swdd~server-state-store-persists-desired-state~1]
+    if let Some(state_store_dir) = &server_config.state_store_dir {
+        log::info!("Persisting the desired state in '{state_store_dir}'");
+        let state_store =
+            FileStateStore::new(state_store_dir).unwrap_or_exit("Could not open the state store");
+        server = server.with_state_store(Box::new(state_store));
+    }
+
     tokio::select! {
         // [impl->swdd~server-default-communication-grpc~1]
         communication_result = communications_server.start(agents_receiver, server_config.address) => {
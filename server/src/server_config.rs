@@ -84,6 +84,7 @@ pub struct ServerConfig {
     pub ca_pem_content: Option<String>,
     pub crt_pem_content: Option<String>,
     pub key_pem_content: Option<String>,
+    pub state_store_dir: Option<String>,
 }
 
 impl Default for ServerConfig {
@@ -99,6 +100,7 @@ impl Default for ServerConfig {
             ca_pem_content: None,
             crt_pem_content: None,
             key_pem_content: None,
+            state_store_dir: None,
         }
     }
 }
@@ -155,6 +157,10 @@ impl ServerConfig {
             self.insecure = Some(insecure);
         }
 
+        if let Some(state_store_dir) = &args.state_store_dir {
+            self.state_store_dir = Some(state_store_dir.to_owned());
+        }
+
         if let Some(ca_pem_path) = &args.ca_pem {
             self.ca_pem = Some(ca_pem_path.to_owned());
             let ca_pem_content = read_pem_file(ca_pem_path, false).unwrap_or_default();
@@ -200,6 +206,7 @@ mod tests {
     const CA_PEM_PATH: &str = "some_path_to_ca_pem/ca.pem";
     const CRT_PEM_PATH: &str = "some_path_to_crt_pem/crt.pem";
     const KEY_PEM_PATH: &str = "some_path_to_key_pem/key.pem";
+    const STATE_STORE_DIR: &str = "some_path_to_state_store";
     const CA_PEM_CONTENT: &str = r"the content of the
         ca.pem file is stored in here";
     const CRT_PEM_CONTENT: &str = r"the content of the
@@ -274,6 +281,7 @@ mod tests {
             ca_pem: Some(CA_PEM_PATH.to_string()),
             crt_pem: Some(CRT_PEM_PATH.to_string()),
             key_pem: Some(KEY_PEM_PATH.to_string()),
+            state_store_dir: Some(STATE_STORE_DIR.to_string()),
         };
 
         server_config.update_with_args(&args);
@@ -290,6 +298,10 @@ mod tests {
         assert_eq!(server_config.ca_pem, Some(CA_PEM_PATH.to_string()));
         assert_eq!(server_config.crt_pem, Some(CRT_PEM_PATH.to_string()));
         assert_eq!(server_config.key_pem, Some(KEY_PEM_PATH.to_string()));
+        assert_eq!(
+            server_config.state_store_dir,
+            Some(STATE_STORE_DIR.to_string())
+        );
     }
 
     // [utest->swdd~server-loads-config-file~1]
@@ -317,6 +329,7 @@ mod tests {
             ca_pem: None,
             crt_pem: None,
             key_pem: None,
+            state_store_dir: None,
         };
 
         server_config.update_with_args(&args);
@@ -347,6 +360,7 @@ mod tests {
         ca_pem_content = '''{CA_PEM_CONTENT}'''
         crt_pem_content = '''{CRT_PEM_CONTENT}'''
         key_pem_content = '''{KEY_PEM_CONTENT}'''
+        state_store_dir = '/var/lib/ankaios/server'
         #"
         );
 
@@ -380,5 +394,9 @@ mod tests {
             server_config.startup_manifest,
             Some("/workspaces/ankaios/server/resources/startConfig.yaml".to_string())
         );
+        assert_eq!(
+            server_config.state_store_dir,
+            Some("/var/lib/ankaios/server".to_string())
+        );
     }
 }
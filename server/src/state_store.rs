@@ -0,0 +1,709 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use common::objects::State;
+use serde::{Deserialize, Serialize};
+use serde_yaml::{Mapping, Value};
+use std::fmt::{self, Display};
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+#[cfg(test)]
+use mockall::automock;
+
+const SNAPSHOT_FILE_NAME: &str = "desired_state.yaml";
+const TEMPORARY_SNAPSHOT_FILE_NAME: &str = "desired_state.yaml.tmp";
+const JOURNAL_FILE_NAME: &str = "desired_state.journal";
+const SNAPSHOT_HEADER_PREFIX: &str = "# ankaios-desired-state v2 sha256:";
+const JOURNAL_ENTRY_SEPARATOR: char = ' ';
+// The journal is compacted into a new snapshot after this number of entries to bound the replay on start.
+const MAX_JOURNAL_ENTRIES: usize = 100;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StateStoreError {
+    Io(String),
+    Corrupted(String),
+    Serialization(String),
+}
+
+impl Display for StateStoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StateStoreError::Io(msg) => {
+                write!(f, "Could not access the state store: '{msg}'")
+            }
+            StateStoreError::Corrupted(msg) => {
+                write!(f, "The persisted state is corrupted: '{msg}'")
+            }
+            StateStoreError::Serialization(msg) => {
+                write!(f, "Could not serialize the state: '{msg}'")
+            }
+        }
+    }
+}
+
+// [impl->swdd~server-state-store-persists-desired-state~1]
+// [impl->swdd~server-state-store-persists-current-revision~1]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersistedState {
+    pub revision: u64,
+    pub desired_state: State,
+}
+
+// [impl->swdd~server-state-store-persists-desired-state~1]
+#[cfg_attr(test, automock)]
+pub trait StateStore: Send {
+    /// Returns the last persisted state or `None` if nothing has been persisted yet.
+    fn load(&self) -> Result<Option<PersistedState>, StateStoreError>;
+
+    /// Persists the given state replacing the previously stored one.
+    fn store(&mut self, persisted_state: &PersistedState) -> Result<(), StateStoreError>;
+}
+
+// A single change of the desired state at the path of mapping keys.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "operation", rename_all = "camelCase")]
+enum JournalChange {
+    Set { path: Vec<String>, value: Value },
+    Remove { path: Vec<String> },
+}
+
+// An entry of the journal belongs to the snapshot with the given checksum.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JournalEntry {
+    snapshot: String,
+    revision: u64,
+    changes: Vec<JournalChange>,
+}
+
+/// Stores the desired state as a checksummed snapshot and an append-only journal of the
+/// changes since this snapshot. Every store appends one entry to the journal. The first store
+/// after the creation, the first store after a failed write and every store on a full journal
+/// compact the journal into a new snapshot instead.
+// [impl->swdd~server-file-state-store-appends-journal-entries~1]
+pub struct FileStateStore {
+    directory: PathBuf,
+    // The state stored last by this instance, used to determine the changes of the next store.
+    last_state: Option<PersistedState>,
+    snapshot_checksum: String,
+    journal_entries: usize,
+}
+
+impl FileStateStore {
+    pub fn new(directory: impl Into<PathBuf>) -> Result<Self, StateStoreError> {
+        let directory = directory.into();
+        fs::create_dir_all(&directory).map_err(|err| {
+            StateStoreError::Io(format!(
+                "could not create directory '{}': {err}",
+                directory.display()
+            ))
+        })?;
+        Ok(FileStateStore {
+            directory,
+            last_state: None,
+            snapshot_checksum: String::new(),
+            journal_entries: 0,
+        })
+    }
+
+    fn snapshot_path(&self) -> PathBuf {
+        self.directory.join(SNAPSHOT_FILE_NAME)
+    }
+
+    fn temporary_snapshot_path(&self) -> PathBuf {
+        self.directory.join(TEMPORARY_SNAPSHOT_FILE_NAME)
+    }
+
+    fn journal_path(&self) -> PathBuf {
+        self.directory.join(JOURNAL_FILE_NAME)
+    }
+
+    fn read_to_string(path: &Path) -> Result<Option<String>, StateStoreError> {
+        match fs::read_to_string(path) {
+            Ok(content) => Ok(Some(content)),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(StateStoreError::Io(format!(
+                "could not read '{}': {err}",
+                path.display()
+            ))),
+        }
+    }
+
+    // Returns the checksum of the snapshot together with the persisted state.
+    fn read_snapshot(path: &Path) -> Result<Option<(String, PersistedState)>, StateStoreError> {
+        let Some(content) = Self::read_to_string(path)? else {
+            return Ok(None);
+        };
+
+        let (header, body) = content.split_once('\n').ok_or_else(|| {
+            StateStoreError::Corrupted(format!("'{}' has no content", path.display()))
+        })?;
+        let expected_checksum = header.strip_prefix(SNAPSHOT_HEADER_PREFIX).ok_or_else(|| {
+            StateStoreError::Corrupted(format!("'{}' has an invalid header", path.display()))
+        })?;
+
+        if sha256::digest(body) != expected_checksum {
+            return Err(StateStoreError::Corrupted(format!(
+                "checksum mismatch in '{}'",
+                path.display()
+            )));
+        }
+
+        let persisted_state = serde_yaml::from_str(body).map_err(|err| {
+            StateStoreError::Corrupted(format!("could not parse '{}': {err}", path.display()))
+        })?;
+        Ok(Some((expected_checksum.to_owned(), persisted_state)))
+    }
+
+    // Returns the valid entries of the journal in the order they have been written.
+    // The journal is only valid up to the first incomplete or corrupted entry.
+    fn read_journal(path: &Path) -> Result<Vec<JournalEntry>, StateStoreError> {
+        let Some(content) = Self::read_to_string(path)? else {
+            return Ok(Vec::new());
+        };
+
+        let mut entries = Vec::new();
+        for line in content.split_inclusive('\n') {
+            let Some(line) = line.strip_suffix('\n') else {
+                log::warn!("Ignoring the incomplete last entry of the journal.");
+                break;
+            };
+            match line
+                .split_once(JOURNAL_ENTRY_SEPARATOR)
+                .filter(|(checksum, body)| sha256::digest(*body) == *checksum)
+                .and_then(|(_, body)| serde_json::from_str(body).ok())
+            {
+                Some(entry) => entries.push(entry),
+                None => {
+                    log::warn!(
+                        "Ignoring the corrupted journal from entry '{}' on.",
+                        entries.len()
+                    );
+                    break;
+                }
+            }
+        }
+        Ok(entries)
+    }
+
+    fn write_synced(path: &Path, content: &str) -> std::io::Result<()> {
+        let mut file = File::create(path)?;
+        file.write_all(content.as_bytes())?;
+        file.sync_all()
+    }
+
+    fn sync_directory(&self) -> std::io::Result<()> {
+        File::open(&self.directory)?.sync_all()
+    }
+
+    // [impl->swdd~server-file-state-store-compacts-journal~1]
+    // [impl->swdd~server-file-state-store-writes-snapshots-atomically~2]
+    fn write_snapshot(&mut self, persisted_state: &PersistedState) -> Result<(), StateStoreError> {
+        let body = serde_yaml::to_string(persisted_state)
+            .map_err(|err| StateStoreError::Serialization(err.to_string()))?;
+        let checksum = sha256::digest(&body);
+        let content = format!("{SNAPSHOT_HEADER_PREFIX}{checksum}\n{body}");
+
+        let io_error = |err: std::io::Error| StateStoreError::Io(err.to_string());
+
+        Self::write_synced(&self.temporary_snapshot_path(), &content).map_err(io_error)?;
+        fs::rename(self.temporary_snapshot_path(), self.snapshot_path()).map_err(io_error)?;
+        // Entries left in the journal after a crash at this point belong to the replaced snapshot
+        // and are skipped on replay.
+        Self::write_synced(&self.journal_path(), "").map_err(io_error)?;
+        self.sync_directory().map_err(io_error)?;
+
+        self.snapshot_checksum = checksum;
+        self.journal_entries = 0;
+        Ok(())
+    }
+
+    // [impl->swdd~server-file-state-store-appends-journal-entries~1]
+    fn append_journal_entry(
+        &mut self,
+        last_state: &PersistedState,
+        persisted_state: &PersistedState,
+    ) -> Result<(), StateStoreError> {
+        let mut changes = Vec::new();
+        collect_changes(
+            &mut Vec::new(),
+            &to_yaml_value(&last_state.desired_state)?,
+            &to_yaml_value(&persisted_state.desired_state)?,
+            &mut changes,
+        );
+        let entry = JournalEntry {
+            snapshot: self.snapshot_checksum.clone(),
+            revision: persisted_state.revision,
+            changes,
+        };
+        let body = serde_json::to_string(&entry)
+            .map_err(|err| StateStoreError::Serialization(err.to_string()))?;
+        let line = format!("{}{JOURNAL_ENTRY_SEPARATOR}{body}\n", sha256::digest(&body));
+
+        let io_error = |err: std::io::Error| StateStoreError::Io(err.to_string());
+
+        let mut journal = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.journal_path())
+            .map_err(io_error)?;
+        journal.write_all(line.as_bytes()).map_err(io_error)?;
+        journal.sync_data().map_err(io_error)?;
+
+        self.journal_entries += 1;
+        Ok(())
+    }
+}
+
+impl StateStore for FileStateStore {
+    // [impl->swdd~server-file-state-store-replays-journal~1]
+    fn load(&self) -> Result<Option<PersistedState>, StateStoreError> {
+        let Some((snapshot_checksum, snapshot)) = Self::read_snapshot(&self.snapshot_path())?
+        else {
+            return Ok(None);
+        };
+
+        let mut revision = snapshot.revision;
+        let mut desired_state = to_yaml_value(&snapshot.desired_state)?;
+        for entry in Self::read_journal(&self.journal_path())? {
+            if entry.snapshot != snapshot_checksum {
+                log::debug!("Skipping a journal entry of a replaced snapshot.");
+                continue;
+            }
+            for change in &entry.changes {
+                apply_change(&mut desired_state, change).map_err(StateStoreError::Corrupted)?;
+            }
+            revision = entry.revision;
+        }
+
+        let desired_state = serde_yaml::from_value(desired_state).map_err(|err| {
+            StateStoreError::Corrupted(format!("could not replay the journal: {err}"))
+        })?;
+        Ok(Some(PersistedState {
+            revision,
+            desired_state,
+        }))
+    }
+
+    fn store(&mut self, persisted_state: &PersistedState) -> Result<(), StateStoreError> {
+        let result = match self.last_state.take() {
+            Some(last_state) if self.journal_entries < MAX_JOURNAL_ENTRIES => {
+                self.append_journal_entry(&last_state, persisted_state)
+            }
+            _ => self.write_snapshot(persisted_state),
+        };
+        // After a failed write, the next store starts over with a new snapshot.
+        if result.is_ok() {
+            self.last_state = Some(persisted_state.clone());
+        }
+        result
+    }
+}
+
+fn to_yaml_value(desired_state: &State) -> Result<Value, StateStoreError> {
+    serde_yaml::to_value(desired_state)
+        .map_err(|err| StateStoreError::Serialization(err.to_string()))
+}
+
+fn string_keys(mapping: &Mapping) -> Option<Vec<&str>> {
+    mapping.keys().map(Value::as_str).collect()
+}
+
+// Collects the changes turning the old value into the new one. Mappings are only descended
+// into if all their keys are strings, otherwise the whole mapping is replaced.
+fn collect_changes(
+    path: &mut Vec<String>,
+    old: &Value,
+    new: &Value,
+    changes: &mut Vec<JournalChange>,
+) {
+    if let (Value::Mapping(old_mapping), Value::Mapping(new_mapping)) = (old, new)
+        && let (Some(old_keys), Some(new_keys)) =
+            (string_keys(old_mapping), string_keys(new_mapping))
+    {
+        for key in &new_keys {
+            path.push(key.to_string());
+            match old_mapping.get(*key) {
+                Some(old_value) => collect_changes(path, old_value, &new_mapping[*key], changes),
+                None => changes.push(JournalChange::Set {
+                    path: path.clone(),
+                    value: new_mapping[*key].clone(),
+                }),
+            }
+            path.pop();
+        }
+        for key in old_keys
+            .iter()
+            .filter(|key| !new_mapping.contains_key(**key))
+        {
+            let mut removed_path = path.clone();
+            removed_path.push(key.to_string());
+            changes.push(JournalChange::Remove { path: removed_path });
+        }
+        return;
+    }
+
+    if old != new {
+        changes.push(JournalChange::Set {
+            path: path.clone(),
+            value: new.clone(),
+        });
+    }
+}
+
+fn apply_change(root: &mut Value, change: &JournalChange) -> Result<(), String> {
+    let path = match change {
+        JournalChange::Set { path, .. } | JournalChange::Remove { path } => path,
+    };
+    let Some((last, parents)) = path.split_last() else {
+        return match change {
+            JournalChange::Set { value, .. } => {
+                *root = value.clone();
+                Ok(())
+            }
+            JournalChange::Remove { .. } => Err("the journal removes the whole state".into()),
+        };
+    };
+
+    let mut current = root;
+    for key in parents {
+        current = current
+            .get_mut(key.as_str())
+            .ok_or_else(|| format!("the journal changes the missing field '{}'", path.join(".")))?;
+    }
+    let mapping = current.as_mapping_mut().ok_or_else(|| {
+        format!(
+            "the journal changes the field '{}' of no mapping",
+            path.join(".")
+        )
+    })?;
+
+    match change {
+        JournalChange::Set { value, .. } => {
+            mapping.insert(Value::from(last.as_str()), value.clone());
+        }
+        JournalChange::Remove { .. } => {
+            mapping.remove(last.as_str());
+        }
+    }
+    Ok(())
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        FileStateStore, JOURNAL_FILE_NAME, MAX_JOURNAL_ENTRIES, PersistedState, SNAPSHOT_FILE_NAME,
+        StateStore, StateStoreError, TEMPORARY_SNAPSHOT_FILE_NAME,
+    };
+    use common::objects::{State, generate_test_stored_workload_spec};
+    use std::collections::HashMap;
+    use std::fs;
+
+    const AGENT_A: &str = "agent_A";
+    const AGENT_B: &str = "agent_B";
+    const WORKLOAD_NAME_1: &str = "workload_1";
+    const WORKLOAD_NAME_2: &str = "workload_2";
+    const RUNTIME: &str = "runtime";
+
+    fn generate_test_state(workload_names: &[&str]) -> PersistedState {
+        generate_test_state_on_agent(workload_names, AGENT_A)
+    }
+
+    fn generate_test_state_on_agent(workload_names: &[&str], agent_name: &str) -> PersistedState {
+        PersistedState {
+            revision: workload_names.len() as u64,
+            desired_state: State {
+                workloads: workload_names
+                    .iter()
+                    .map(|name| {
+                        (
+                            name.to_string(),
+                            generate_test_stored_workload_spec(agent_name, RUNTIME),
+                        )
+                    })
+                    .collect::<HashMap<_, _>>(),
+                ..Default::default()
+            },
+        }
+    }
+
+    fn journal_lines(directory: &tempfile::TempDir) -> Vec<String> {
+        fs::read_to_string(directory.path().join(JOURNAL_FILE_NAME))
+            .unwrap()
+            .lines()
+            .map(ToOwned::to_owned)
+            .collect()
+    }
+
+    // [utest->swdd~server-state-store-persists-desired-state~1]
+    #[test]
+    fn utest_file_state_store_load_without_snapshot_returns_none() {
+        let directory = tempfile::tempdir().unwrap();
+        let state_store = FileStateStore::new(directory.path()).unwrap();
+
+        assert_eq!(state_store.load(), Ok(None));
+    }
+
+    // [utest->swdd~server-state-store-persists-desired-state~1]
+    // [utest->swdd~server-state-store-persists-current-revision~1]
+    // [utest->swdd~server-file-state-store-compacts-journal~1]
+    // [utest->swdd~server-file-state-store-writes-snapshots-atomically~2]
+    #[test]
+    fn utest_file_state_store_store_and_load() {
+        let directory = tempfile::tempdir().unwrap();
+        let mut state_store = FileStateStore::new(directory.path()).unwrap();
+        let state = generate_test_state(&[WORKLOAD_NAME_1]);
+
+        state_store.store(&state).unwrap();
+
+        assert_eq!(state_store.load(), Ok(Some(state)));
+        assert!(journal_lines(&directory).is_empty());
+        assert!(!directory.path().join(TEMPORARY_SNAPSHOT_FILE_NAME).exists());
+    }
+
+    // [utest->swdd~server-file-state-store-appends-journal-entries~1]
+    // [utest->swdd~server-file-state-store-replays-journal~1]
+    #[test]
+    fn utest_file_state_store_appends_changes_to_journal() {
+        let directory = tempfile::tempdir().unwrap();
+        let mut state_store = FileStateStore::new(directory.path()).unwrap();
+        let old_state = generate_test_state(&[WORKLOAD_NAME_1]);
+        let new_state = generate_test_state(&[WORKLOAD_NAME_1, WORKLOAD_NAME_2]);
+
+        state_store.store(&old_state).unwrap();
+        let snapshot = fs::read_to_string(directory.path().join(SNAPSHOT_FILE_NAME)).unwrap();
+        state_store.store(&new_state).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(directory.path().join(SNAPSHOT_FILE_NAME)).unwrap(),
+            snapshot
+        );
+        assert_eq!(journal_lines(&directory).len(), 1);
+        // only the added workload is journaled
+        assert!(!journal_lines(&directory)[0].contains(WORKLOAD_NAME_1));
+        assert_eq!(state_store.load(), Ok(Some(new_state)));
+    }
+
+    // [utest->swdd~server-file-state-store-appends-journal-entries~1]
+    // [utest->swdd~server-file-state-store-replays-journal~1]
+    #[test]
+    fn utest_file_state_store_replays_updated_and_deleted_workloads() {
+        let directory = tempfile::tempdir().unwrap();
+        let mut state_store = FileStateStore::new(directory.path()).unwrap();
+        let updated_state = generate_test_state_on_agent(&[WORKLOAD_NAME_1], AGENT_B);
+        let deleted_state = PersistedState {
+            revision: 3,
+            ..generate_test_state(&[])
+        };
+
+        state_store
+            .store(&generate_test_state(&[WORKLOAD_NAME_1, WORKLOAD_NAME_2]))
+            .unwrap();
+        state_store.store(&updated_state).unwrap();
+        assert_eq!(state_store.load(), Ok(Some(updated_state)));
+
+        state_store.store(&deleted_state).unwrap();
+        assert_eq!(journal_lines(&directory).len(), 2);
+        assert_eq!(state_store.load(), Ok(Some(deleted_state)));
+    }
+
+    // [utest->swdd~server-file-state-store-compacts-journal~1]
+    #[test]
+    fn utest_file_state_store_compacts_journal_on_first_store() {
+        let directory = tempfile::tempdir().unwrap();
+        let mut state_store = FileStateStore::new(directory.path()).unwrap();
+        state_store
+            .store(&generate_test_state(&[WORKLOAD_NAME_1]))
+            .unwrap();
+        state_store
+            .store(&generate_test_state(&[WORKLOAD_NAME_1, WORKLOAD_NAME_2]))
+            .unwrap();
+        assert_eq!(journal_lines(&directory).len(), 1);
+
+        let mut restarted_state_store = FileStateStore::new(directory.path()).unwrap();
+        let state = generate_test_state(&[WORKLOAD_NAME_2]);
+        restarted_state_store.store(&state).unwrap();
+
+        assert!(journal_lines(&directory).is_empty());
+        assert_eq!(restarted_state_store.load(), Ok(Some(state)));
+    }
+
+    // [utest->swdd~server-file-state-store-compacts-journal~1]
+    #[test]
+    fn utest_file_state_store_compacts_full_journal() {
+        let directory = tempfile::tempdir().unwrap();
+        let mut state_store = FileStateStore::new(directory.path()).unwrap();
+        let mut state = generate_test_state(&[WORKLOAD_NAME_1]);
+
+        for revision in 0..=MAX_JOURNAL_ENTRIES as u64 {
+            state.revision = revision;
+            state_store.store(&state).unwrap();
+        }
+        assert_eq!(journal_lines(&directory).len(), MAX_JOURNAL_ENTRIES);
+
+        state.revision += 1;
+        state_store.store(&state).unwrap();
+
+        assert!(journal_lines(&directory).is_empty());
+        assert_eq!(state_store.load(), Ok(Some(state)));
+    }
+
+    // [utest->swdd~server-file-state-store-replays-journal~1]
+    #[test]
+    fn utest_file_state_store_ignores_partially_written_journal_entry() {
+        let directory = tempfile::tempdir().unwrap();
+        let mut state_store = FileStateStore::new(directory.path()).unwrap();
+        let old_state = generate_test_state(&[WORKLOAD_NAME_1]);
+        let new_state = generate_test_state(&[WORKLOAD_NAME_1, WORKLOAD_NAME_2]);
+
+        state_store.store(&old_state).unwrap();
+        state_store.store(&new_state).unwrap();
+        let complete_journal =
+            fs::read_to_string(directory.path().join(JOURNAL_FILE_NAME)).unwrap();
+        state_store.store(&generate_test_state(&[])).unwrap();
+
+        let journal_path = directory.path().join(JOURNAL_FILE_NAME);
+        let content = fs::read_to_string(&journal_path).unwrap();
+        let last_entry = &content[complete_journal.len()..];
+        fs::write(
+            &journal_path,
+            format!("{complete_journal}{}", &last_entry[..last_entry.len() / 2]),
+        )
+        .unwrap();
+
+        assert_eq!(state_store.load(), Ok(Some(new_state)));
+    }
+
+    // [utest->swdd~server-file-state-store-replays-journal~1]
+    #[test]
+    fn utest_file_state_store_stops_replay_at_corrupted_journal_entry() {
+        let directory = tempfile::tempdir().unwrap();
+        let mut state_store = FileStateStore::new(directory.path()).unwrap();
+        let old_state = generate_test_state(&[WORKLOAD_NAME_1]);
+
+        state_store.store(&old_state).unwrap();
+        state_store
+            .store(&generate_test_state(&[WORKLOAD_NAME_1, WORKLOAD_NAME_2]))
+            .unwrap();
+        state_store
+            .store(&generate_test_state(&[WORKLOAD_NAME_2]))
+            .unwrap();
+
+        let journal_path = directory.path().join(JOURNAL_FILE_NAME);
+        let content = fs::read_to_string(&journal_path).unwrap();
+        fs::write(
+            &journal_path,
+            content.replacen(WORKLOAD_NAME_2, WORKLOAD_NAME_1, 1),
+        )
+        .unwrap();
+
+        assert_eq!(state_store.load(), Ok(Some(old_state)));
+    }
+
+    // [utest->swdd~server-file-state-store-replays-journal~1]
+    #[test]
+    fn utest_file_state_store_skips_journal_entries_of_replaced_snapshot() {
+        let directory = tempfile::tempdir().unwrap();
+        let mut state_store = FileStateStore::new(directory.path()).unwrap();
+        state_store
+            .store(&generate_test_state(&[WORKLOAD_NAME_1]))
+            .unwrap();
+        state_store
+            .store(&generate_test_state(&[WORKLOAD_NAME_1, WORKLOAD_NAME_2]))
+            .unwrap();
+        let journal_path = directory.path().join(JOURNAL_FILE_NAME);
+        let stale_journal = fs::read_to_string(&journal_path).unwrap();
+
+        let mut restarted_state_store = FileStateStore::new(directory.path()).unwrap();
+        let state = generate_test_state(&[WORKLOAD_NAME_2]);
+        restarted_state_store.store(&state).unwrap();
+
+        // simulate a crash between writing the new snapshot and truncating the journal
+        fs::write(&journal_path, stale_journal).unwrap();
+
+        assert_eq!(restarted_state_store.load(), Ok(Some(state)));
+    }
+
+    // [utest->swdd~server-file-state-store-replays-journal~1]
+    #[test]
+    fn utest_file_state_store_corrupted_snapshot_fails() {
+        let directory = tempfile::tempdir().unwrap();
+        let state_store = FileStateStore::new(directory.path()).unwrap();
+
+        fs::write(
+            directory.path().join(SNAPSHOT_FILE_NAME),
+            "some garbage\nworkloads: {",
+        )
+        .unwrap();
+
+        assert!(matches!(
+            state_store.load(),
+            Err(StateStoreError::Corrupted(_))
+        ));
+    }
+
+    // [utest->swdd~server-file-state-store-replays-journal~1]
+    #[test]
+    fn utest_file_state_store_tampered_snapshot_fails_checksum() {
+        let directory = tempfile::tempdir().unwrap();
+        let mut state_store = FileStateStore::new(directory.path()).unwrap();
+        state_store
+            .store(&generate_test_state(&[WORKLOAD_NAME_1]))
+            .unwrap();
+
+        let snapshot_path = directory.path().join(SNAPSHOT_FILE_NAME);
+        let content = fs::read_to_string(&snapshot_path).unwrap();
+        fs::write(
+            &snapshot_path,
+            content.replace(WORKLOAD_NAME_1, WORKLOAD_NAME_2),
+        )
+        .unwrap();
+
+        assert!(matches!(
+            state_store.load(),
+            Err(StateStoreError::Corrupted(_))
+        ));
+    }
+
+    // [utest->swdd~server-file-state-store-writes-snapshots-atomically~2]
+    #[test]
+    fn utest_file_state_store_ignores_leftover_temporary_snapshot() {
+        let directory = tempfile::tempdir().unwrap();
+        let mut state_store = FileStateStore::new(directory.path()).unwrap();
+        let state = generate_test_state(&[WORKLOAD_NAME_1]);
+        state_store.store(&state).unwrap();
+
+        fs::write(
+            directory.path().join(TEMPORARY_SNAPSHOT_FILE_NAME),
+            "# ankaios-desired-state v2 sha256:",
+        )
+        .unwrap();
+
+        assert_eq!(state_store.load(), Ok(Some(state.clone())));
+        let mut restarted_state_store = FileStateStore::new(directory.path()).unwrap();
+        restarted_state_store.store(&state).unwrap();
+        assert_eq!(restarted_state_store.load(), Ok(Some(state)));
+    }
+}
@@ -307,6 +307,8 @@ impl Connection {
                 UpdateStateRequest {
                     new_state: Some(state.into()),
                     update_mask: update_state_command.update_mask,
+                    dry_run: false,
+                    expected_revision: None,
                 }
                 .try_into()
                 .map_err(CommandError::GenericError)?,